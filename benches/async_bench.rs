@@ -1,5 +1,15 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use ovsm::runtime::LispEvaluator;
+use solisp::runtime::{LispEvaluator, Value};
+use solisp::{SExprParser, SExprScanner};
+
+/// Scan, parse and execute `code` on `evaluator`
+fn eval_source(evaluator: &mut LispEvaluator, code: &str) -> solisp::Result<Value> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    evaluator.execute(&program)
+}
 
 /// Benchmark sequential vs concurrent execution
 fn bench_sequential_vs_concurrent(c: &mut Criterion) {
@@ -24,7 +34,7 @@ fn bench_sequential_vs_concurrent(c: &mut Criterion) {
                     size + 1
                 );
 
-                black_box(evaluator.eval_str(&code).unwrap())
+                black_box(eval_source(&mut evaluator, &code).unwrap())
             });
         });
 
@@ -50,7 +60,7 @@ fn bench_sequential_vs_concurrent(c: &mut Criterion) {
                     size + 1
                 );
 
-                black_box(evaluator.eval_str(&code).unwrap())
+                black_box(eval_source(&mut evaluator, &code).unwrap())
             });
         });
     }
@@ -70,7 +80,7 @@ fn bench_async_overhead(c: &mut Criterion) {
   (defun noop (x) x)
   (async noop 42))
 "#;
-            black_box(evaluator.eval_str(code).unwrap())
+            black_box(eval_source(&mut evaluator, code).unwrap())
         });
     });
 
@@ -83,7 +93,7 @@ fn bench_async_overhead(c: &mut Criterion) {
   (define h (async noop 42))
   (await h))
 "#;
-            black_box(evaluator.eval_str(code).unwrap())
+            black_box(eval_source(&mut evaluator, code).unwrap())
         });
     });
 
@@ -106,7 +116,7 @@ fn bench_batch_processing(c: &mut Criterion) {
 (do
   (defun process (x)
     (do
-      (sleep 5)
+      (sleep 0.005)
       (* x 2)))
 
   (define handles [])
@@ -122,7 +132,7 @@ fn bench_batch_processing(c: &mut Criterion) {
                         size + 1
                     );
 
-                    black_box(evaluator.eval_str(&code).unwrap())
+                    black_box(eval_source(&mut evaluator, &code).unwrap())
                 });
             },
         );
@@ -173,7 +183,7 @@ fn bench_map_reduce(c: &mut Criterion) {
   total)
 "#;
 
-            black_box(evaluator.eval_str(code).unwrap())
+            black_box(eval_source(&mut evaluator, code).unwrap())
         });
     });
 }
@@ -201,7 +211,7 @@ fn bench_factorial_concurrent(c: &mut Criterion) {
   (length results))
 "#;
 
-            black_box(evaluator.eval_str(code).unwrap())
+            black_box(eval_source(&mut evaluator, code).unwrap())
         });
     });
 }
@@ -215,7 +225,7 @@ fn bench_fire_and_forget(c: &mut Criterion) {
 (do
   (defun background-work (id)
     (do
-      (sleep 1)
+      (sleep 0.001)
       (* id 2)))
 
   (for (i (range 1 101))
@@ -224,7 +234,7 @@ fn bench_fire_and_forget(c: &mut Criterion) {
   "done")
 "#;
 
-            black_box(evaluator.eval_str(code).unwrap())
+            black_box(eval_source(&mut evaluator, code).unwrap())
         });
     });
 }
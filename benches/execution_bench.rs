@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use ovsm::Scanner;
+use solisp::Scanner;
 
 fn lexer_benchmark(c: &mut Criterion) {
     let source = r#"
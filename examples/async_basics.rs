@@ -2,7 +2,7 @@
 //!
 //! Run with: cargo run --example async_basics
 
-use ovsm::{Evaluator, Parser, Result, Scanner, Value};
+use solisp::{Evaluator, Parser, Result, Scanner, Value};
 
 /// Helper function to execute OVSM code
 fn execute_ovsm(evaluator: &mut Evaluator, code: &str) -> Result<Value> {
@@ -27,7 +27,7 @@ fn main() {
 (do
   (defun compute (x y)
     (do
-      (sleep 50)
+      (sleep 0.05)
       (* x y)))
 
   (define handle (async compute 5 7))
@@ -83,7 +83,7 @@ fn main() {
 (do
   (defun background-task (id)
     (do
-      (sleep 100)
+      (sleep 0.1)
       (println (str "Background task " id " completed"))))
 
   ;; Launch tasks without awaiting
@@ -109,7 +109,7 @@ fn main() {
 
   (define process (lambda (x)
     (do
-      (sleep 30)
+      (sleep 0.03)
       (* x multiplier))))
 
   (define handles [])
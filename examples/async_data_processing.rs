@@ -4,7 +4,7 @@
 //!
 //! Run with: cargo run --example async_data_processing
 
-use ovsm::{Evaluator, Parser, Result, Scanner, Value};
+use solisp::{Evaluator, Parser, Result, Scanner, Value};
 
 /// Helper function to execute OVSM code
 fn execute_ovsm(evaluator: &mut Evaluator, code: &str) -> Result<Value> {
@@ -29,7 +29,7 @@ fn main() {
 (do
   (defun process-record (record)
     (do
-      (sleep 20)  ;; Simulate I/O
+      (sleep 0.02)  ;; Simulate I/O
       {:id (get record :id)
        :value (* (get record :value) 2)
        :status "processed"}))
@@ -73,7 +73,7 @@ fn main() {
 (do
   (defun mapper (chunk-id data-chunk)
     (do
-      (sleep 30)  ;; Simulate computation
+      (sleep 0.03)  ;; Simulate computation
       (define sum 0)
       (for (val data-chunk)
         (set! sum (+ sum val)))
@@ -129,17 +129,17 @@ fn main() {
 (do
   (defun stage1-fetch (id)
     (do
-      (sleep 20)
+      (sleep 0.02)
       {:id id :data (str "raw-" id)}))
 
   (defun stage2-transform (item)
     (do
-      (sleep 15)
+      (sleep 0.015)
       {:id (get item :id) :data (str "transformed-" (get item :data))}))
 
   (defun stage3-validate (item)
     (do
-      (sleep 10)
+      (sleep 0.01)
       {:id (get item :id) :data (get item :data) :valid true}))
 
   (define ids [1 2 3 4 5])
@@ -193,7 +193,7 @@ fn main() {
 (do
   (defun worker (worker-id tasks)
     (do
-      (sleep (* worker-id 10))  ;; Varying delays
+      (sleep (* worker-id 0.01))  ;; Varying delays
       (define completed 0)
       (for (task tasks)
         (set! completed (+ completed 1)))
@@ -40,7 +40,7 @@ fn parse_and_report(name: &str, path: &str) {
             // Check function registry
             let function_registry = executable.get_function_registry();
             println!("\n📞 Function registry:");
-            for (hash, value) in function_registry.iter().take(5) {
+            for (hash, _value) in function_registry.iter().take(5) {
                 println!("    Hash: 0x{:08x}, Value: (pc bytes, flags)", hash);
             }
         }
@@ -1,5 +1,5 @@
 //! Compile OVSM to sBPF and deploy to localnet
-use ovsm::compiler::{debug_compile, CompileOptions, Compiler};
+use solisp::compiler::{debug_compile, CompileOptions, Compiler};
 use std::process::Command;
 
 fn main() {
@@ -1,4 +1,4 @@
-use ovsm::{Evaluator, Parser, Scanner};
+use solisp::{Evaluator, Parser, Scanner};
 
 fn main() {
     println!("=== OVSM Interpreter - Complete Demo ===\n");
@@ -112,7 +112,7 @@ fn main() {
     println!("=== All examples completed successfully! ===");
 }
 
-fn execute_ovsm(source: &str) -> Result<ovsm::Value, ovsm::Error> {
+fn execute_ovsm(source: &str) -> Result<solisp::Value, solisp::Error> {
     let mut scanner = Scanner::new(source);
     let tokens = scanner.scan_tokens()?;
     let mut parser = Parser::new(tokens);
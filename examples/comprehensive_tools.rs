@@ -1,4 +1,4 @@
-use ovsm::{Evaluator, Parser, Scanner};
+use solisp::{Evaluator, Parser, Scanner};
 
 fn main() {
     println!("=== Comprehensive OVSM Tools Demo ===\n");
@@ -277,7 +277,7 @@ fn main() {
     }
 
     // Show tool count
-    let registry = ovsm::ToolRegistry::new();
+    let registry = solisp::ToolRegistry::new();
     println!("\n=== Summary ===");
     println!("Tests run: {}", success + failed);
     println!("✓ Passed: {}", success);
@@ -363,7 +363,7 @@ fn main() {
     );
 }
 
-fn execute_ovsm(source: &str) -> Result<ovsm::Value, ovsm::Error> {
+fn execute_ovsm(source: &str) -> Result<solisp::Value, solisp::Error> {
     let mut scanner = Scanner::new(source);
     let tokens = scanner.scan_tokens()?;
     let mut parser = Parser::new(tokens);
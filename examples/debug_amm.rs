@@ -1,4 +1,4 @@
-use ovsm::compiler::debug_compile;
+use solisp::compiler::debug_compile;
 
 fn main() {
     let amm = r#"
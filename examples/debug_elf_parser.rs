@@ -1,5 +1,4 @@
 // Detailed ELF parser debugging
-use std::mem;
 
 fn main() {
     let elf_path = std::env::args()
@@ -110,7 +110,7 @@ fn main() {
     // Check relocation entries
     println!("📊 .rel.dyn section at offset 0x1122:");
     let reldyn_offset = 0x1122;
-    let reldyn_size = 16; // One relocation entry
+    let _reldyn_size = 16; // One relocation entry
 
     let r_offset = u64::from_le_bytes(
         elf_bytes[reldyn_offset..reldyn_offset + 8]
@@ -28,7 +28,7 @@ fn main() {
             0
         };
         let dst = regs & 0xf;
-        let src = (regs >> 4) & 0xf;
+        let _src = (regs >> 4) & 0xf;
 
         // Read immediate values based on instruction class
         let imm = if offset + 4 < elf_bytes.len() {
@@ -1,4 +1,4 @@
-use ovsm::{Evaluator, Parser, Scanner};
+use solisp::{Evaluator, Parser, Scanner};
 
 fn main() {
     let source = "$x = true AND false\nRETURN $x";
@@ -67,7 +67,7 @@ fn main() {
             let ptr = slice.as_ptr();
             let alignment = mem::align_of::<u64>();
 
-            if (ptr as usize) % alignment != 0 {
+            if !(ptr as usize).is_multiple_of(alignment) {
                 println!("  ❌ FAIL: Pointer not aligned!");
                 println!("     ptr=0x{:x}, alignment={}", ptr as usize, alignment);
                 return;
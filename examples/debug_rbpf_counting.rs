@@ -48,7 +48,7 @@ fn main() {
                 println!("           → Jump offset would be: {}", imm);
                 println!(
                     "           → Target would be instruction: {}",
-                    instr_count as i32 + imm as i32 + 1
+                    instr_count + imm as i32 + 1
                 );
             }
         } else {
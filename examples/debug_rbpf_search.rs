@@ -77,14 +77,14 @@ fn main() {
             continue;
         }
 
-        let sh_name =
+        let _sh_name =
             u32::from_le_bytes(elf_bytes[shdr_offset..shdr_offset + 4].try_into().unwrap());
         let sh_type = u32::from_le_bytes(
             elf_bytes[shdr_offset + 4..shdr_offset + 8]
                 .try_into()
                 .unwrap(),
         );
-        let sh_flags = u64::from_le_bytes(
+        let _sh_flags = u64::from_le_bytes(
             elf_bytes[shdr_offset + 8..shdr_offset + 16]
                 .try_into()
                 .unwrap(),
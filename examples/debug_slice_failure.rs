@@ -78,7 +78,7 @@ fn main() {
             let ptr = bytes_slice.as_ptr();
             let alignment = mem::align_of::<u64>(); // Elf64Dyn contains u64s
 
-            if (ptr as usize) % alignment != 0 {
+            if !(ptr as usize).is_multiple_of(alignment) {
                 println!("  ❌ FAIL: Pointer not aligned!");
                 println!(
                     "     ptr=0x{:x}, alignment={}, remainder={}",
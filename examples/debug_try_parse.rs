@@ -1,4 +1,4 @@
-use ovsm::{Parser, Scanner};
+use solisp::{Parser, Scanner};
 
 fn main() {
     let code = r#"
@@ -1,6 +1,6 @@
 //! Debug VC generation
-use ovsm::compiler::lean::{LeanCodegen, VerificationProperties};
-use ovsm::{SExprParser as Parser, SExprScanner as Scanner};
+use solisp::compiler::lean::{LeanCodegen, VerificationProperties};
+use solisp::{SExprParser as Parser, SExprScanner as Scanner};
 
 fn main() {
     let source = r#"
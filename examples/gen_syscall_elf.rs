@@ -1,5 +1,5 @@
 // Compile syscall test and regenerate ELF
-use ovsm::compiler::{CompileOptions, Compiler};
+use solisp::compiler::{CompileOptions, Compiler};
 
 fn main() {
     let source = r#";; Test syscall
@@ -13,7 +13,7 @@ fn main() {
         ..Default::default()
     };
 
-    let mut compiler = Compiler::new(opts);
+    let compiler = Compiler::new(opts);
     match compiler.compile(source) {
         Ok(result) => {
             std::fs::write("/tmp/hello_final.so", &result.elf_bytes).expect("Failed to write ELF");
@@ -259,9 +259,7 @@ fn main() {
     elf.extend_from_slice(b".shstrtab\0"); // 49
 
     // Pad with extra bytes so RBPF can safely read up to 16 bytes from any offset
-    for _ in 0..32 {
-        elf.push(0);
-    }
+    elf.extend_from_slice(&[0; 32]);
 
     // Write the file
     fs::write("/tmp/minimal_sbpf.so", &elf).expect("Failed to write ELF");
@@ -275,6 +273,7 @@ fn main() {
     println!("  .rel.dyn:  0x480-0x4a0");
 }
 
+#[allow(clippy::too_many_arguments)]
 fn write_section_header(
     elf: &mut Vec<u8>,
     sh_name: u32,
@@ -251,6 +251,7 @@ fn main() {
     println!("\nThis ELF closely mirrors Solana's syscall_reloc_64_32.so structure");
 }
 
+#[allow(clippy::too_many_arguments)]
 fn write_section_header(
     elf: &mut Vec<u8>,
     sh_name: u32,
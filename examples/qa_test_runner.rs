@@ -8,7 +8,7 @@
 //! Usage:
 //!   cargo run --example qa_test_runner -- path/to/qa_file.md
 
-use ovsm::{Evaluator, Parser, Scanner, Value};
+use solisp::{Evaluator, Parser, Scanner, Value};
 use std::env;
 use std::fs;
 
@@ -135,6 +135,8 @@ fn format_value(value: &Value) -> String {
     match value {
         Value::Int(n) => format!("Int({})", n),
         Value::Float(f) => format!("Float({})", f),
+        Value::Complex { re, im } => format!("Complex({}, {})", re, im),
+        Value::Instant(millis) => format!("Instant({})", millis),
         Value::String(s) => format!("String(\"{}\")", s),
         Value::Bool(b) => format!("Bool({})", b),
         Value::Null => "Null".to_string(),
@@ -156,6 +158,8 @@ fn format_value(value: &Value) -> String {
             format!("Multiple([{}])", items.join(", "))
         }
         Value::Macro { params, .. } => format!("Macro({} params)", params.len()),
+        Value::Memoized { .. } => "Memoized".to_string(),
+        Value::LazySeq(_) => "LazySeq".to_string(),
         Value::AsyncHandle { .. } => "AsyncHandle".to_string(),
         Value::Thread { .. } => "Thread".to_string(),
         Value::Lock { .. } => "Lock".to_string(),
@@ -163,5 +167,8 @@ fn format_value(value: &Value) -> String {
         Value::ConditionVariable { .. } => "ConditionVariable".to_string(),
         Value::Semaphore { .. } => "Semaphore".to_string(),
         Value::AtomicInteger { .. } => "AtomicInteger".to_string(),
+        Value::Channel { .. } => "Channel".to_string(),
+        Value::Promise { .. } => "Promise".to_string(),
+        Value::AtomicRef { .. } => "AtomicRef".to_string(),
     }
 }
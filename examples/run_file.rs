@@ -2,7 +2,7 @@
 //!
 //! Usage: cargo run --example run_file <script.ovsm>
 
-use ovsm::{Evaluator, Parser, Scanner, Value};
+use solisp::{Evaluator, Parser, Scanner, Value};
 use std::env;
 use std::fs;
 use std::process;
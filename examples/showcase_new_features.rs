@@ -1,4 +1,4 @@
-use ovsm::{Evaluator, Parser, Scanner};
+use solisp::{Evaluator, Parser, Scanner};
 
 fn main() {
     println!("🎉 OVSM Interpreter - New Features Showcase\n");
@@ -2,7 +2,7 @@
 //!
 //! Usage: cargo run --example simple_repl
 
-use ovsm::{Evaluator, Parser, Scanner};
+use solisp::{Evaluator, Parser, Scanner};
 use std::io::{self, Write};
 
 fn main() {
@@ -72,7 +72,7 @@ fn main() {
 fn execute_line(
     evaluator: &mut Evaluator,
     code: &str,
-) -> Result<ovsm::Value, Box<dyn std::error::Error>> {
+) -> Result<solisp::Value, Box<dyn std::error::Error>> {
     // Tokenize
     let mut scanner = Scanner::new(code);
     let tokens = scanner.scan_tokens()?;
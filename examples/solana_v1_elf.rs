@@ -288,6 +288,7 @@ fn main() {
     println!("\nThis ELF closely matches Solana's working syscall_reloc_64_32.so");
 }
 
+#[allow(clippy::too_many_arguments)]
 fn write_section_header(
     elf: &mut Vec<u8>,
     sh_name: u32,
@@ -1,5 +1,5 @@
 //! Test formal verification on AEA Protocol
-use ovsm::compiler::{CompileOptions, Compiler, VerificationMode};
+use solisp::compiler::{CompileOptions, Compiler, VerificationMode};
 
 fn main() {
     let source = std::fs::read_to_string("../../examples/ovsm_scripts/aea/aea_protocol.ovsm")
@@ -18,8 +18,10 @@ fn main() {
     );
 
     // First try with Warn mode to see all VCs
-    let mut options = CompileOptions::default();
-    options.verification_mode = VerificationMode::Warn;
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Warn,
+        ..Default::default()
+    };
 
     let compiler = Compiler::new(options);
 
@@ -105,9 +107,8 @@ fn main() {
                     entry.2 += 1;
                 }
                 let mut cats: Vec<_> = by_category.iter().collect();
-                cats.sort_by(|a, b| (b.1 .0 + b.1 .1 + b.1 .2).cmp(&(a.1 .0 + a.1 .1 + a.1 .2)));
+                cats.sort_by_key(|c| std::cmp::Reverse(c.1 .0 + c.1 .1 + c.1 .2));
                 for (cat, (proved, failed, unknown)) in cats {
-                    let total = proved + failed + unknown;
                     let status = if *failed > 0 {
                         "✗"
                     } else if *unknown > 0 {
@@ -1,11 +1,13 @@
-use ovsm::compiler::{CompileOptions, Compiler, VerificationMode};
+use solisp::compiler::{CompileOptions, Compiler, VerificationMode};
 use std::fs;
 
 fn main() {
     let source = fs::read_to_string("/tmp/test_assume.ovsm").expect("Failed to read file");
 
-    let mut opts = CompileOptions::default();
-    opts.verification_mode = VerificationMode::Require;
+    let opts = CompileOptions {
+        verification_mode: VerificationMode::Require,
+        ..Default::default()
+    };
 
     let compiler = Compiler::new(opts);
 
@@ -1,5 +1,5 @@
 //! Test formal verification on real OVSM programs
-use ovsm::compiler::{CompileOptions, Compiler, VerificationMode};
+use solisp::compiler::{CompileOptions, Compiler, VerificationMode};
 
 fn main() {
     let source = std::fs::read_to_string("examples/real_world/sol_transfer.ovsm")
@@ -8,8 +8,10 @@ fn main() {
     println!("=== Testing Formal Verification on sol_transfer.ovsm ===\n");
 
     // First try with Require mode
-    let mut options = CompileOptions::default();
-    options.verification_mode = VerificationMode::Require;
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Require,
+        ..Default::default()
+    };
 
     let compiler = Compiler::new(options);
 
@@ -1,4 +1,4 @@
-use ovsm::{Evaluator, Parser, Scanner};
+use solisp::{Evaluator, Parser, Scanner};
 
 fn main() {
     println!("Testing GUARD clauses...\n");
@@ -20,7 +20,7 @@ fn main() {
 
     println!("Test 1 - Guard passes ($x = 10, guard $x > 0)");
     println!("  Result: {:?}", result);
-    assert_eq!(result, ovsm::Value::Int(10));
+    assert_eq!(result, solisp::Value::Int(10));
     println!("  ✅ Passed\n");
 
     // Test 2: Guard fails
@@ -40,7 +40,7 @@ fn main() {
 
     println!("Test 2 - Guard fails ($x = -5, guard $x > 0)");
     println!("  Result: {:?}", result);
-    assert_eq!(result, ovsm::Value::Int(-1));
+    assert_eq!(result, solisp::Value::Int(-1));
     println!("  ✅ Passed\n");
 
     // Test 3: Multiple guards
@@ -66,7 +66,7 @@ fn main() {
 
     println!("Test 3 - Multiple guards (both pass)");
     println!("  Result: {:?}", result);
-    assert_eq!(result, ovsm::Value::Int(30));
+    assert_eq!(result, solisp::Value::Int(30));
     println!("  ✅ Passed\n");
 
     // Test 4: Second guard fails
@@ -92,7 +92,7 @@ fn main() {
 
     println!("Test 4 - Second guard fails ($y = 5, guard $y > 15)");
     println!("  Result: {:?}", result);
-    assert_eq!(result, ovsm::Value::Int(-2));
+    assert_eq!(result, solisp::Value::Int(-2));
     println!("  ✅ Passed\n");
 
     println!("✅ All GUARD clause tests passed!");
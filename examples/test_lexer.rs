@@ -1,4 +1,4 @@
-use ovsm::{Scanner, TokenKind};
+use solisp::{Scanner, TokenKind};
 
 fn main() {
     let source = r#"
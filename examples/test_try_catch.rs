@@ -1,4 +1,4 @@
-use ovsm::{Evaluator, Parser, Scanner};
+use solisp::{Evaluator, Parser, Scanner};
 
 fn main() {
     println!("Testing TRY-CATCH blocks...\n");
@@ -21,7 +21,7 @@ fn main() {
 
     println!("Test 1 - Catch division by zero");
     println!("  Result: {:?}", result);
-    assert_eq!(result, ovsm::Value::Int(-1));
+    assert_eq!(result, solisp::Value::Int(-1));
     println!("  ✅ Passed\n");
 
     // Test 2: No error, TRY succeeds
@@ -42,7 +42,7 @@ fn main() {
 
     println!("Test 2 - No error, TRY succeeds");
     println!("  Result: {:?}", result);
-    assert_eq!(result, ovsm::Value::Int(5));
+    assert_eq!(result, solisp::Value::Int(5));
     println!("  ✅ Passed\n");
 
     // Test 3: Catch undefined variable error
@@ -63,7 +63,7 @@ fn main() {
 
     println!("Test 3 - Catch undefined variable");
     println!("  Result: {:?}", result);
-    assert_eq!(result, ovsm::Value::String("caught error".to_string()));
+    assert_eq!(result, solisp::Value::String("caught error".to_string()));
     println!("  ✅ Passed\n");
 
     // Test 4: Catch tool error
@@ -85,7 +85,7 @@ fn main() {
 
     println!("Test 4 - Catch tool error (empty collection)");
     println!("  Result: {:?}", result);
-    assert_eq!(result, ovsm::Value::String("empty".to_string()));
+    assert_eq!(result, solisp::Value::String("empty".to_string()));
     println!("  ✅ Passed\n");
 
     // Test 5: Nested TRY-CATCH
@@ -110,7 +110,7 @@ fn main() {
 
     println!("Test 5 - Nested TRY-CATCH");
     println!("  Result: {:?}", result);
-    assert_eq!(result, ovsm::Value::Int(99));
+    assert_eq!(result, solisp::Value::Int(99));
     println!("  ✅ Passed\n");
 
     println!("✅ All TRY-CATCH tests passed!");
@@ -1,4 +1,4 @@
-use ovsm::{Evaluator, Parser, Scanner};
+use solisp::{Evaluator, Parser, Scanner};
 
 fn main() {
     println!("=== OVSM Tools Demo ===\n");
@@ -154,13 +154,13 @@ fn main() {
     }
 
     // Show tool count
-    let registry = ovsm::ToolRegistry::new();
+    let registry = solisp::ToolRegistry::new();
     println!("=== Summary ===");
     println!("Total tools available: {}", registry.count());
     println!("Tools: {}", registry.list_tools().join(", "));
 }
 
-fn execute_ovsm(source: &str) -> Result<ovsm::Value, ovsm::Error> {
+fn execute_ovsm(source: &str) -> Result<solisp::Value, solisp::Error> {
     let mut scanner = Scanner::new(source);
     let tokens = scanner.scan_tokens()?;
     let mut parser = Parser::new(tokens);
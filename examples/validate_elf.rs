@@ -1,9 +1,5 @@
 //! Validate ELF with solana_rbpf
-use solana_rbpf::{
-    elf::Executable,
-    program::{BuiltinProgram, SBPFVersion},
-    verifier::RequisiteVerifier,
-};
+use solana_rbpf::{elf::Executable, program::BuiltinProgram, verifier::RequisiteVerifier};
 
 #[derive(Debug)]
 struct TestContext {
@@ -139,6 +139,23 @@ impl ElfWriter {
     pub fn write(
         &mut self,
         program: &[SbpfInstruction],
+        debug_info: bool,
+        sbpf_version: super::SbpfVersion,
+    ) -> Result<Vec<u8>> {
+        self.write_static(program, &[], &[], debug_info, sbpf_version)
+    }
+
+    /// Write sBPF program whose syscalls are already resolved in the call
+    /// immediates (SBPFv2 static syscalls)
+    ///
+    /// No dynamic sections or relocations are emitted; string loads are
+    /// patched with absolute addresses into a `.rodata` section loaded
+    /// right after `.text`.
+    pub fn write_static(
+        &mut self,
+        program: &[SbpfInstruction],
+        string_loads: &[StringLoadRef],
+        rodata: &[u8],
         _debug_info: bool,
         sbpf_version: super::SbpfVersion,
     ) -> Result<Vec<u8>> {
@@ -157,6 +174,11 @@ impl ElfWriter {
 
         let _shstrtab_name = self.add_shstrtab(".shstrtab");
         let text_name = self.add_shstrtab(".text");
+        let rodata_name = if rodata.is_empty() {
+            0
+        } else {
+            self.add_shstrtab(".rodata")
+        };
         let strtab_name = self.add_shstrtab(".strtab");
         let symtab_name = self.add_shstrtab(".symtab");
         let stack_name = self.add_shstrtab(".bss.stack");
@@ -167,6 +189,7 @@ impl ElfWriter {
         // [Program Headers: 1 * 56 bytes]
         // [Padding to 0x120]
         // [.text section at 0x120]
+        // [.rodata section, if any]
         // [.strtab section]
         // [.symtab section]
         // [.shstrtab section]
@@ -175,14 +198,31 @@ impl ElfWriter {
         let ehdr_size = 64usize;
         let phdr_size = 56usize;
         let shdr_size = 64usize;
-        let num_phdrs = 1usize; // Just .text PT_LOAD
-        let num_sections = 5usize; // NULL, .text, .strtab, .symtab, .shstrtab
+        let num_phdrs = 1usize; // Just .text PT_LOAD (covering .rodata)
+                                // NULL, .text, [.rodata,] .strtab, .symtab, .shstrtab
+        let num_sections = if rodata.is_empty() { 5usize } else { 6usize };
 
         let phdr_offset = ehdr_size;
         let text_offset = 0x120usize; // Match Solana's working ELFs
         let text_size = text_section.len();
 
-        let strtab_offset = text_offset + text_size;
+        let rodata_offset = text_offset + text_size;
+        let rodata_vaddr = TEXT_VADDR + text_size as u64;
+
+        // Static programs have no relocations, so string pointers must hold
+        // the final runtime address (see write_with_syscalls)
+        for load_site in string_loads {
+            let abs_addr = MM_PROGRAM_START + rodata_vaddr + load_site.rodata_offset as u64;
+            let offset = load_site.offset;
+            if offset + 16 <= text_section.len() {
+                text_section[offset + 4..offset + 8]
+                    .copy_from_slice(&((abs_addr & 0xFFFF_FFFF) as u32).to_le_bytes());
+                text_section[offset + 12..offset + 16]
+                    .copy_from_slice(&((abs_addr >> 32) as u32).to_le_bytes());
+            }
+        }
+
+        let strtab_offset = rodata_offset + rodata.len();
         let strtab_size = self.strtab.len();
 
         // Symbol table: one NULL entry + one entrypoint entry
@@ -235,7 +275,7 @@ impl ElfWriter {
             PF_R | PF_X,
             text_offset,
             TEXT_VADDR,
-            text_size,
+            text_size + rodata.len(),
         );
 
         // Padding to 0x120 for .text section
@@ -246,6 +286,9 @@ impl ElfWriter {
         // ==================== .text Section ====================
         elf.extend_from_slice(&text_section);
 
+        // ==================== .rodata Section ====================
+        elf.extend_from_slice(rodata);
+
         // ==================== .strtab Section ====================
         elf.extend_from_slice(&self.strtab);
 
@@ -287,7 +330,23 @@ impl ElfWriter {
             0,
         );
 
-        // 2: .strtab
+        if !rodata.is_empty() {
+            self.write_shdr(
+                &mut elf,
+                rodata_name,
+                SHT_PROGBITS,
+                SHF_ALLOC,
+                rodata_vaddr,
+                rodata_offset,
+                rodata.len(),
+                0,
+                0,
+                1,
+                0,
+            );
+        }
+
+        // .strtab
         self.write_shdr(
             &mut elf,
             strtab_name,
@@ -302,7 +361,7 @@ impl ElfWriter {
             0,
         );
 
-        // 3: .symtab
+        // .symtab (linked to .strtab, just before it)
         self.write_shdr(
             &mut elf,
             symtab_name,
@@ -311,13 +370,13 @@ impl ElfWriter {
             0,
             symtab_offset,
             symtab_size,
-            2,
+            (num_sections - 3) as u32,
             1,
             8,
             symtab_entry_size,
         );
 
-        // 4: .shstrtab
+        // .shstrtab
         self.write_shdr(
             &mut elf,
             1,
@@ -3,7 +3,8 @@
 //! Implements the classic graph coloring algorithm for register allocation:
 //! 1. Build interference graph (edges between simultaneously live values)
 //! 2. Simplify graph by removing nodes with degree < K (available regs)
-//! 3. Spill nodes with degree >= K
+//! 3. Spill nodes with degree >= K, cheapest first (uses weighted by loop
+//!    depth, divided by degree)
 //! 4. Assign colors (physical registers) by popping from simplify stack
 //!
 //! This allocator properly handles:
@@ -13,6 +14,7 @@
 //! - Proper spill/reload for high register pressure
 
 use super::ir::{IrInstruction, IrProgram, IrReg};
+use super::regalloc_analyzer::RegAllocAnalyzer;
 use super::sbpf_codegen::SbpfReg;
 use std::collections::{HashMap, HashSet, VecDeque};
 
@@ -25,6 +27,9 @@ pub struct LiveRange {
     pub last_use: usize,
     /// True if this holds a 64-bit constant (requires lddw instruction)
     pub is_large_const: bool,
+    /// Estimated cost of spilling: every def and use weighted by
+    /// 10^loop depth, since each one would become a stack access
+    pub spill_cost: u64,
 }
 
 /// Interference graph edge
@@ -46,7 +51,7 @@ impl Edge {
 /// Graph coloring register allocator
 pub struct GraphColoringAllocator {
     /// Available physical registers for allocation
-    /// R3, R4, R5, R8, R9 (5 registers)
+    /// R3, R4, R8, R9 (4 registers)
     available_regs: Vec<SbpfReg>,
 
     /// Number of colors (physical registers)
@@ -72,6 +77,9 @@ pub struct GraphColoringAllocator {
 
     /// Registers that need spill/reload code
     spill_code_needed: HashSet<IrReg>,
+
+    /// Weight spill costs by loop depth (off only to compare against in tests)
+    weight_loops: bool,
 }
 
 impl GraphColoringAllocator {
@@ -85,15 +93,15 @@ impl GraphColoringAllocator {
         precolored.insert(IrReg(7), SbpfReg::R7);
 
         Self {
-            // Order: callee-saved first (survive syscalls), then caller-saved
+            // Order: callee-saved first (survive syscalls), then caller-saved.
+            // R5 is left out: codegen reloads spilled operands into it.
             available_regs: vec![
                 SbpfReg::R9,
                 SbpfReg::R8, // Callee-saved
-                SbpfReg::R5,
                 SbpfReg::R4,
                 SbpfReg::R3, // Caller-saved
             ],
-            k: 5,
+            k: 4,
             live_ranges: HashMap::new(),
             interference: HashMap::new(),
             precolored,
@@ -101,6 +109,7 @@ impl GraphColoringAllocator {
             spills: HashMap::new(),
             next_spill_offset: -8,
             spill_code_needed: HashSet::new(),
+            weight_loops: true,
         }
     }
 
@@ -131,10 +140,23 @@ impl GraphColoringAllocator {
         let mut defs: HashMap<IrReg, usize> = HashMap::new();
         let mut uses: HashMap<IrReg, usize> = HashMap::new();
         let mut is_large_const: HashMap<IrReg, bool> = HashMap::new();
+        let mut spill_costs: HashMap<IrReg, u64> = HashMap::new();
+        let loop_depths = RegAllocAnalyzer::loop_depths(program);
 
         for (idx, instr) in instructions.iter().enumerate() {
             let (def_regs, use_regs, large_const) = Self::extract_regs(instr);
 
+            let depth = if self.weight_loops {
+                loop_depths[idx]
+            } else {
+                0
+            };
+            let weight = 10u64.saturating_pow(depth as u32);
+            for reg in def_regs.iter().chain(&use_regs) {
+                let cost = spill_costs.entry(*reg).or_insert(0);
+                *cost = cost.saturating_add(weight);
+            }
+
             for reg in def_regs {
                 // First definition
                 defs.entry(reg).or_insert(idx);
@@ -158,9 +180,30 @@ impl GraphColoringAllocator {
                     def_point: *def_idx,
                     last_use,
                     is_large_const: is_large_const.get(reg).copied().unwrap_or(false),
+                    spill_cost: spill_costs.get(reg).copied().unwrap_or(0),
                 },
             );
         }
+
+        // A value live on entry to a loop header is needed again on the next
+        // iteration, so it stays live until the back edge. Repeat until
+        // stable so extensions propagate through nested loops.
+        let loops = RegAllocAnalyzer::loops(program);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for range in self.live_ranges.values_mut() {
+                for &(header, back_edge) in &loops {
+                    if range.def_point < header
+                        && range.last_use >= header
+                        && range.last_use < back_edge
+                    {
+                        range.last_use = back_edge;
+                        changed = true;
+                    }
+                }
+            }
+        }
     }
 
     /// Extract defined and used registers from an IR instruction
@@ -398,30 +441,24 @@ impl GraphColoringAllocator {
     fn pick_spill(&self, candidates: &[IrReg], remaining: &HashSet<IrReg>) -> usize {
         // Heuristic: prefer to spill:
         // 1. Large constants (can be rematerialized)
-        // 2. High-degree nodes (frees more colors)
-        // 3. Short live ranges (less spill/reload cost)
+        // 2. The lowest spill cost per interference edge removed, where the
+        //    cost counts uses weighted by loop depth (from RegAllocAnalyzer)
+        // Ties go to the lower register number so allocation is deterministic.
 
-        let mut best_idx = 0;
-        let mut best_score = i64::MIN;
-
-        for (idx, &reg) in candidates.iter().enumerate() {
+        let key = |reg: IrReg| {
             let range = self.live_ranges.get(&reg);
             let is_large_const = range.map(|r| r.is_large_const).unwrap_or(false);
-            let live_length = range
-                .map(|r| (r.last_use - r.def_point) as i64)
-                .unwrap_or(0);
-            let degree = self.degree(reg, remaining) as i64;
-
-            // Score: prefer large constants, high degree, short ranges
-            let score = if is_large_const { 1000 } else { 0 } + degree * 10 - live_length;
-
-            if score > best_score {
-                best_score = score;
-                best_idx = idx;
-            }
-        }
-
-        best_idx
+            let cost = range.map(|r| r.spill_cost).unwrap_or(0) as f64;
+            let degree = self.degree(reg, remaining).max(1) as f64;
+            (!is_large_const, cost / degree, reg.0)
+        };
+
+        candidates
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| key(**a).partial_cmp(&key(**b)).unwrap())
+            .map(|(idx, _)| idx)
+            .unwrap_or(0)
     }
 
     /// Check if a register is spilled
@@ -536,7 +573,7 @@ mod tests {
 
         let result = allocator.allocate(&program);
 
-        // With 10 values live simultaneously and only 5 registers,
+        // With 10 values live simultaneously and only 4 registers,
         // we need spills. The allocator should detect this.
         // Note: The graph coloring may find clever solutions, but with
         // enough pressure, some spills should occur.
@@ -545,4 +582,110 @@ mod tests {
             "Should allocate all registers"
         );
     }
+
+    #[test]
+    fn test_r5_is_never_allocated() {
+        // Codegen reloads spilled operands into R5, so handing it out as a
+        // color would let a reload clobber a live value
+        let mut allocator = GraphColoringAllocator::new();
+        let mut instructions = vec![];
+        for i in 10..18 {
+            instructions.push(IrInstruction::ConstI64(IrReg(i), i as i64));
+        }
+        let mut acc = IrReg(10);
+        for i in 11..18 {
+            instructions.push(IrInstruction::Add(IrReg(i + 10), acc, IrReg(i)));
+            acc = IrReg(i + 10);
+        }
+
+        let result = allocator.allocate(&make_program(instructions));
+
+        assert!(!result.spills.is_empty());
+        assert!(result.allocation.values().all(|&reg| reg != SbpfReg::R5));
+    }
+
+    #[test]
+    fn test_value_read_at_loop_head_lives_through_loop() {
+        // r10 is last read by the exit test at the loop head, but the back
+        // edge reads it again, so it must stay live across the body where r11
+        // is defined
+        let instructions = vec![
+            IrInstruction::ConstI64(IrReg(10), 3),
+            IrInstruction::ConstI64(IrReg(12), 0),
+            IrInstruction::Label("loop".to_string()),
+            IrInstruction::Ge(IrReg(13), IrReg(12), IrReg(10)),
+            IrInstruction::JumpIf(IrReg(13), "done".to_string()),
+            IrInstruction::ConstI64(IrReg(11), 1),
+            IrInstruction::Add(IrReg(12), IrReg(12), IrReg(11)),
+            IrInstruction::Jump("loop".to_string()),
+            IrInstruction::Label("done".to_string()),
+            IrInstruction::Return(Some(IrReg(12))),
+        ];
+
+        let mut allocator = GraphColoringAllocator::new();
+        allocator.allocate(&make_program(instructions));
+
+        assert_eq!(allocator.live_ranges[&IrReg(10)].last_use, 7);
+        assert!(allocator.interference[&IrReg(10)].contains(&IrReg(11)));
+    }
+
+    #[test]
+    fn test_hot_loop_register_is_not_spilled() {
+        // r20 lives across the whole program and is touched once inside a
+        // loop, so it interferes with everything but has few static uses
+        let mut instructions = vec![];
+        for i in 10..15 {
+            instructions.push(IrInstruction::ConstI64(IrReg(i), i as i64));
+        }
+        instructions.push(IrInstruction::ConstI64(IrReg(20), 1));
+        instructions.push(IrInstruction::Label("loop".to_string()));
+        instructions.push(IrInstruction::Add(IrReg(20), IrReg(20), IrReg(20)));
+        instructions.push(IrInstruction::JumpIf(IrReg(20), "loop".to_string()));
+        instructions.push(IrInstruction::Add(IrReg(21), IrReg(10), IrReg(11)));
+        for _ in 0..3 {
+            for i in 10..15 {
+                instructions.push(IrInstruction::Add(IrReg(21), IrReg(21), IrReg(i)));
+            }
+        }
+        for i in 15..20 {
+            instructions.push(IrInstruction::ConstI64(IrReg(i), i as i64));
+        }
+        instructions.push(IrInstruction::Add(IrReg(22), IrReg(15), IrReg(16)));
+        for _ in 0..3 {
+            for i in 15..20 {
+                instructions.push(IrInstruction::Add(IrReg(22), IrReg(22), IrReg(i)));
+            }
+        }
+        instructions.push(IrInstruction::Add(IrReg(22), IrReg(22), IrReg(21)));
+        instructions.push(IrInstruction::Add(IrReg(22), IrReg(22), IrReg(20)));
+        instructions.push(IrInstruction::Return(Some(IrReg(22))));
+
+        let program = make_program(instructions);
+
+        let mut allocator = GraphColoringAllocator::new();
+        let result = allocator.allocate(&program);
+
+        let mut unweighted = GraphColoringAllocator::new();
+        unweighted.weight_loops = false;
+        let unweighted_result = unweighted.allocate(&program);
+
+        // Counting every access once makes r20 the cheapest spill; the
+        // loop-weighted cost keeps it in a register and spills the
+        // straight-line values instead
+        assert!(unweighted_result.is_spilled(IrReg(20)));
+        assert!(!result.is_spilled(IrReg(20)), "{:?}", result.spills);
+
+        // Stack accesses each choice costs at run time, with loop bodies
+        // counted by their weight
+        let spill_cost = |spills: &HashMap<IrReg, i16>| -> u64 {
+            spills
+                .keys()
+                .map(|reg| allocator.live_ranges[reg].spill_cost)
+                .sum()
+        };
+        let spills_new = spill_cost(&result.spills);
+        let spills_old = spill_cost(&unweighted_result.spills);
+        assert!(spills_new < spills_old, "{} > {}", spills_new, spills_old);
+        assert!(spills_new < allocator.live_ranges[&IrReg(20)].spill_cost);
+    }
 }
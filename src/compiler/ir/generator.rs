@@ -7019,6 +7019,40 @@ impl IrGenerator {
         self.emit(IrInstruction::Mul(table_offset, counter, eight));
         let table_addr = self.alloc_reg();
         self.emit(IrInstruction::Add(table_addr, heap_base, table_offset));
+
+        // A duplicate entry is just [u8 index][7 padding]: reuse the original
+        // account's offset so indexed access resolves to the same account
+        let new_label = self.new_label("acct_new");
+        let next_label = self.new_label("acct_next");
+        let account_addr = self.alloc_reg();
+        self.emit(IrInstruction::Add(
+            account_addr,
+            accounts_ptr,
+            current_offset,
+        ));
+        let dup_marker = self.alloc_reg();
+        self.emit(IrInstruction::Load1(dup_marker, account_addr, 0));
+        let non_dup = self.alloc_reg();
+        self.emit(IrInstruction::ConstI64(non_dup, 0xff));
+        let is_new = self.alloc_reg();
+        self.emit(IrInstruction::Eq(is_new, dup_marker, non_dup));
+        self.emit(IrInstruction::JumpIf(is_new, new_label.clone()));
+
+        let original_offset = self.alloc_reg();
+        self.emit(IrInstruction::Mul(original_offset, dup_marker, eight));
+        let original_addr = self.alloc_reg();
+        self.emit(IrInstruction::Add(
+            original_addr,
+            heap_base,
+            original_offset,
+        ));
+        let original = self.alloc_reg();
+        self.emit(IrInstruction::Load(original, original_addr, 0));
+        self.emit(IrInstruction::Store(table_addr, original, 0));
+        self.emit(IrInstruction::Add(current_offset, current_offset, eight));
+        self.emit(IrInstruction::Jump(next_label.clone()));
+
+        self.emit(IrInstruction::Label(new_label));
         self.emit(IrInstruction::Store(table_addr, current_offset, 0));
 
         // Read data_len at current_offset + 80 (offset to data_len within account)
@@ -7061,6 +7095,7 @@ impl IrGenerator {
 
         // Update current_offset for next iteration
         self.emit(IrInstruction::Move(current_offset, next_offset));
+        self.emit(IrInstruction::Label(next_label));
 
         // Increment counter
         let one = self.alloc_reg();
@@ -12,7 +12,7 @@ impl IrReg {
 }
 
 /// IR instruction (three-address code)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum IrInstruction {
     // Constants
     /// Load 64-bit integer constant into register
@@ -116,3 +116,39 @@ pub enum IrInstruction {
     /// No operation (placeholder instruction, removed during optimization)
     Nop,
 }
+
+impl IrInstruction {
+    /// Register written by this instruction, if any
+    pub fn defined_reg(&self) -> Option<IrReg> {
+        match self {
+            Self::ConstI64(dst, _)
+            | Self::ConstF64(dst, _)
+            | Self::ConstBool(dst, _)
+            | Self::ConstNull(dst)
+            | Self::ConstString(dst, _)
+            | Self::Add(dst, _, _)
+            | Self::Sub(dst, _, _)
+            | Self::Mul(dst, _, _)
+            | Self::Div(dst, _, _)
+            | Self::Mod(dst, _, _)
+            | Self::Eq(dst, _, _)
+            | Self::Ne(dst, _, _)
+            | Self::Lt(dst, _, _)
+            | Self::Le(dst, _, _)
+            | Self::Gt(dst, _, _)
+            | Self::Ge(dst, _, _)
+            | Self::And(dst, _, _)
+            | Self::Or(dst, _, _)
+            | Self::Neg(dst, _)
+            | Self::Not(dst, _)
+            | Self::Move(dst, _)
+            | Self::Load(dst, _, _)
+            | Self::Load1(dst, _, _)
+            | Self::Load2(dst, _, _)
+            | Self::Load4(dst, _, _)
+            | Self::Alloc(dst, _) => Some(*dst),
+            Self::Call(dst, _, _) | Self::Syscall(dst, _, _) => *dst,
+            _ => None,
+        }
+    }
+}
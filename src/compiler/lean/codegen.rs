@@ -13,6 +13,8 @@ use std::collections::HashMap;
 pub enum VCCategory {
     /// Division by zero check
     DivisionSafety,
+    /// Modulo/remainder by zero check (`%`, `mod`, `rem`)
+    ModuloSafety,
     /// Array bounds check
     ArrayBounds,
     /// Arithmetic overflow check (a + b, a * b overflow u64)
@@ -105,6 +107,7 @@ impl std::fmt::Display for VCCategory {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             VCCategory::DivisionSafety => write!(f, "division_safety"),
+            VCCategory::ModuloSafety => write!(f, "modulo_safety"),
             VCCategory::ArrayBounds => write!(f, "array_bounds"),
             VCCategory::ArithmeticOverflow => write!(f, "overflow"),
             VCCategory::ArithmeticUnderflow => write!(f, "underflow"),
@@ -152,6 +155,35 @@ impl std::fmt::Display for VCCategory {
     }
 }
 
+impl VCCategory {
+    /// Standard Lean 4 tactic used to discharge VCs of this category
+    ///
+    /// Arithmetic and bounds properties are linear over `Nat`/`Int` and go to
+    /// `omega`; literal refinements are closed by `decide`; Solana account
+    /// facts are established by runtime guards and are closed from the
+    /// hypotheses with `assumption`.
+    pub fn lean_tactic(&self) -> &'static str {
+        match self {
+            VCCategory::DivisionSafety
+            | VCCategory::ModuloSafety
+            | VCCategory::ArrayBounds
+            | VCCategory::ArithmeticOverflow
+            | VCCategory::ArithmeticUnderflow
+            | VCCategory::InstructionDataBounds
+            | VCCategory::IntegerTruncation
+            | VCCategory::AccountDataBounds
+            | VCCategory::LoopInvariant
+            | VCCategory::BufferOverflowCheck
+            | VCCategory::BufferUnderrunCheck
+            | VCCategory::AccountRealloc
+            | VCCategory::CPIDepthCheck
+            | VCCategory::ArithmeticPrecision => "omega",
+            VCCategory::RefinementType => "decide",
+            _ => "assumption",
+        }
+    }
+}
+
 /// A verification condition to be proved
 #[derive(Debug, Clone)]
 pub struct VerificationCondition {
@@ -605,6 +637,43 @@ impl LeanCodegen {
         Ok(())
     }
 
+    /// Divisor-non-zero condition for a modulo/remainder operation.
+    ///
+    /// The property is always `divisor ≠ 0`, so a literal divisor is decided
+    /// directly by the solver (and a literal zero is disproved).
+    fn modulo_safety_vc(
+        &self,
+        divisor: &Expression,
+        ctx: &mut VCContext,
+        line: usize,
+    ) -> VerificationCondition {
+        let divisor_lean = self.expr_to_lean(divisor);
+        let is_literal = matches!(divisor, Expression::IntLiteral(_));
+        let is_literal_zero = matches!(divisor, Expression::IntLiteral(0));
+
+        VerificationCondition {
+            id: ctx.next_id(&VCCategory::ModuloSafety),
+            category: VCCategory::ModuloSafety,
+            description: if is_literal_zero {
+                "Modulo by literal zero is always unsafe!".to_string()
+            } else {
+                format!("Modulo divisor '{}' must be non-zero", divisor_lean)
+            },
+            location: Some(SourceLocation {
+                file: ctx.source_file.clone(),
+                line,
+                column: 1,
+            }),
+            property: format!("{} ≠ 0", divisor_lean),
+            assumptions: ctx.clone_assumptions(),
+            tactic: if is_literal {
+                "decide".to_string()
+            } else {
+                "ovsm_div_safe".to_string()
+            },
+        }
+    }
+
     /// Generate verification conditions for an expression
     fn generate_expr_vcs(
         &self,
@@ -616,9 +685,24 @@ impl LeanCodegen {
         let line = expected_line.unwrap_or(1); // TODO: get actual line from AST
 
         match expr {
+            // Modulo safety
+            Expression::Binary {
+                op: BinaryOp::Mod,
+                left,
+                right,
+            } => {
+                if self.properties.division_safety {
+                    vcs.push(self.modulo_safety_vc(right, ctx, line));
+                }
+
+                // Recurse into operands
+                self.generate_expr_vcs(left, ctx, vcs, expected_line)?;
+                self.generate_expr_vcs(right, ctx, vcs, expected_line)?;
+            }
+
             // Division safety
             Expression::Binary {
-                op: BinaryOp::Div | BinaryOp::Mod,
+                op: BinaryOp::Div,
                 left,
                 right,
             } => {
@@ -928,11 +1012,16 @@ impl LeanCodegen {
                     vcs.push(vc);
                 }
 
-                // Division functions
+                // Modulo functions
                 if self.properties.division_safety
-                    && (name == "/" || name == "%")
+                    && matches!(name.as_str(), "%" | "mod" | "rem")
                     && args.len() >= 2
                 {
+                    vcs.push(self.modulo_safety_vc(&args[1].value, ctx, line));
+                }
+
+                // Division functions
+                if self.properties.division_safety && name == "/" && args.len() >= 2 {
                     let divisor_lean = self.expr_to_lean(&args[1].value);
 
                     let vc = VerificationCondition {
@@ -3066,12 +3155,8 @@ impl LeanCodegen {
         code.push_str("open OVSM OVSM.Tactics\n\n");
 
         // Namespace
-        let ns_name = std::path::Path::new(source_file)
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("Program")
-            .replace(['-', '.', ' '], "_");
-        code.push_str(&format!("namespace VC_{}\n\n", ns_name));
+        let ns_name = vc_namespace(source_file);
+        code.push_str(&format!("namespace {}\n\n", ns_name));
 
         // Generate each VC as a theorem
         for vc in vcs {
@@ -3099,12 +3184,22 @@ impl LeanCodegen {
         }
 
         // Close namespace
-        code.push_str(&format!("end VC_{}\n", ns_name));
+        code.push_str(&format!("end {}\n", ns_name));
 
         Ok(code)
     }
 }
 
+/// Lean namespace for the VCs of a source file (`foo-bar.ovsm` -> `VC_foo_bar`)
+pub(crate) fn vc_namespace(source_file: &str) -> String {
+    let stem = std::path::Path::new(source_file)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("Program")
+        .replace(['-', '.', ' '], "_");
+    format!("VC_{}", stem)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
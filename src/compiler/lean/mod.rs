@@ -397,7 +397,7 @@ impl ProofCoverageReport {
         result.push_str("─────────────────────────────────────────────────\n");
 
         let mut categories: Vec<_> = self.vcs_by_category.iter().collect();
-        categories.sort_by(|(_, a), (_, b)| b.count.cmp(&a.count)); // Sort by count descending
+        categories.sort_by_key(|(_, c)| std::cmp::Reverse(c.count)); // Sort by count descending
 
         for (name, stats) in categories {
             let rate = if stats.count == 0 {
@@ -460,6 +460,108 @@ impl ProofCoverageReport {
         })
         .to_string()
     }
+
+    /// Export as a standalone HTML page
+    ///
+    /// Renders `source` with each line colored by proof status (green =
+    /// proved, red = unproved, gray = no VCs), followed by the category
+    /// breakdown table. Line numbers are 1-based, matching VC locations.
+    pub fn to_html(&self, source: &str) -> String {
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!(
+            "<title>Proof Coverage: {}</title>\n",
+            html_escape(&self.source_file)
+        ));
+        html.push_str(
+            "<style>\n\
+             body { font-family: sans-serif; }\n\
+             pre { line-height: 1.4; }\n\
+             .proved { background: #d4f7d4; }\n\
+             .unproved { background: #f7d4d4; }\n\
+             .uncovered { color: #888888; }\n\
+             .lineno { color: #888888; user-select: none; }\n\
+             table { border-collapse: collapse; }\n\
+             th, td { border: 1px solid #cccccc; padding: 2px 8px; text-align: right; }\n\
+             th:first-child, td:first-child { text-align: left; }\n\
+             </style>\n</head>\n<body>\n",
+        );
+
+        html.push_str(&format!(
+            "<h1>Proof Coverage: {}</h1>\n",
+            html_escape(&self.source_file)
+        ));
+        html.push_str(&format!(
+            "<p>{} of {} VCs proved ({:.1}%), line coverage {:.1}%</p>\n",
+            self.proved_vcs,
+            self.total_vcs,
+            self.vc_proof_rate(),
+            self.line_coverage_percent()
+        ));
+
+        html.push_str("<pre>\n");
+        for (i, line) in source.lines().enumerate() {
+            let line_no = i + 1;
+            let class = if self.unproved_line_set.contains(&line_no) {
+                "unproved"
+            } else if self.proved_line_set.contains(&line_no) {
+                "proved"
+            } else {
+                "uncovered"
+            };
+            html.push_str(&format!(
+                "<span class=\"{}\"><span class=\"lineno\">{:4}</span>  {}</span>\n",
+                class,
+                line_no,
+                html_escape(line)
+            ));
+        }
+        html.push_str("</pre>\n");
+
+        html.push_str("<h2>VCs by Category</h2>\n<table>\n");
+        html.push_str("<tr><th>Category</th><th>Proved</th><th>Total</th><th>Rate</th></tr>\n");
+        let mut categories: Vec<_> = self.vcs_by_category.iter().collect();
+        categories.sort_by_key(|(name, c)| (std::cmp::Reverse(c.count), *name));
+        for (name, stats) in categories {
+            let rate = if stats.count == 0 {
+                100.0
+            } else {
+                (stats.proved as f64 / stats.count as f64) * 100.0
+            };
+            let class = if stats.unproved == 0 {
+                "proved"
+            } else {
+                "unproved"
+            };
+            html.push_str(&format!(
+                "<tr class=\"{}\"><td>{}</td><td>{}</td><td>{}</td><td>{:.1}%</td></tr>\n",
+                class,
+                html_escape(name),
+                stats.proved,
+                stats.count,
+                rate
+            ));
+        }
+        html.push_str("</table>\n</body>\n</html>\n");
+
+        html
+    }
+}
+
+/// Escape text for inclusion in HTML element content or attribute values
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 impl VerificationResult {
@@ -523,6 +625,9 @@ pub struct FailedVC {
     pub error: String,
     /// Suggested fix (if available)
     pub suggestion: Option<String>,
+    /// Smallest concrete inputs that violate the property, as an object of
+    /// variable name to value (built-in verifier only)
+    pub counterexample: Option<crate::Value>,
 }
 
 /// A verification condition with unknown status
@@ -643,6 +748,7 @@ impl LeanVerifier {
                     });
                 }
                 ProofResult::Disproved { counterexample } => {
+                    let witness = builtin.minimal_counterexample(&vc);
                     failed.push(FailedVC {
                         id: vc.id,
                         category: vc.category.clone(),
@@ -650,6 +756,7 @@ impl LeanVerifier {
                         location: vc.location,
                         error: format!("Counterexample: {}", counterexample),
                         suggestion: self.generate_suggestion(&vc.category),
+                        counterexample: witness,
                     });
                 }
                 ProofResult::Unknown { reason } => {
@@ -737,8 +844,10 @@ impl LeanVerifier {
 
     /// Export Lean 4 proof certificates for external verification
     ///
-    /// This generates a `.lean` file containing all verification conditions
-    /// with their proofs that can be independently checked by Lean 4.
+    /// This generates a `.lean` file with one theorem per verification
+    /// condition. Conditions the built-in verifier proves carry its tactic
+    /// script; the rest fall back to the category's standard tactic, so
+    /// running `lean` on the file re-checks every condition independently.
     pub fn export_proofs(
         &self,
         program: &Program,
@@ -748,31 +857,45 @@ impl LeanVerifier {
         // Generate VCs
         let vcs = self.codegen.generate(program, source_file)?;
 
-        // Generate base Lean code
-        let mut lean_code = self.codegen.to_lean_code(&vcs, source_file)?;
-
-        // Add proof annotations from built-in verifier
         let builtin = BuiltinVerifier::new();
-        let mut proof_comments = String::new();
+        let ns_name = codegen::vc_namespace(source_file);
+        let mut proved = 0;
 
+        let mut theorems = String::new();
         for vc in &vcs {
-            let proof_result = builtin.prove(vc);
-            if let ProofResult::Proved {
-                lean_proof,
-                explanation,
-            } = proof_result
-            {
-                proof_comments.push_str(&format!(
-                    "/-- {} proof: {} -/\n/-- Explanation: {} -/\n\n",
-                    vc.id, lean_proof, explanation
-                ));
-            }
+            let (tactic, explanation) = match builtin.prove(vc) {
+                ProofResult::Proved {
+                    lean_proof,
+                    explanation,
+                } => {
+                    proved += 1;
+                    (lean_proof, explanation)
+                }
+                ProofResult::Disproved { counterexample } => (
+                    vc.category.lean_tactic().to_string(),
+                    format!("Disproved by built-in verifier: {}", counterexample),
+                ),
+                ProofResult::Unknown { reason } => (
+                    vc.category.lean_tactic().to_string(),
+                    format!("Not proved by built-in verifier: {}", reason),
+                ),
+            };
+            theorems.push_str(&certificate_theorem(vc, &tactic, &explanation));
         }
 
-        // Prepend proof comments
-        lean_code = format!(
-            "/-!\n# OVSM Verification Certificates\n\nGenerated from: {}\n\n{}-/\n\n{}",
-            source_file, proof_comments, lean_code
+        // Imports must precede every other command, including module docs
+        let lean_code = format!(
+            "import OVSM\nopen OVSM OVSM.Tactics\n\n\
+             /-!\n# OVSM Verification Certificates\n\n\
+             Generated from: {}\n\n\
+             {} of {} conditions proved by the built-in verifier.\n-/\n\n\
+             namespace {}\n\n{}end {}\n",
+            source_file,
+            proved,
+            vcs.len(),
+            ns_name,
+            theorems,
+            ns_name
         );
 
         std::fs::write(output_path, lean_code)
@@ -852,6 +975,7 @@ impl LeanVerifier {
                             location: vc.location,
                             error: error_msg,
                             suggestion: self.generate_suggestion(&vc.category),
+                            counterexample: None,
                         });
                     } else {
                         proved.push(ProvedVC {
@@ -914,6 +1038,9 @@ impl LeanVerifier {
             VCCategory::DivisionSafety => Some(
                 "Add a check before division: (if (= divisor 0) (error \"Division by zero\") (/ x divisor))".to_string()
             ),
+            VCCategory::ModuloSafety => Some(
+                "Add a check before taking the remainder: (if (= divisor 0) (error \"Modulo by zero\") (% x divisor))".to_string()
+            ),
             VCCategory::ArrayBounds => Some(
                 "Add a bounds check: (if (>= idx (len arr)) (error \"Index out of bounds\") (get arr idx))".to_string()
             ),
@@ -1088,6 +1215,34 @@ pub enum ProofStatus {
     },
 }
 
+/// Render one VC as a Lean 4 theorem closed by `tactic`
+///
+/// Assumptions become named hypotheses `h0`, `h1`, ... The built-in
+/// verifier refers to guards by descriptive names that do not exist in the
+/// exported file, so `exact <name>` is replaced with `assumption`, and
+/// `;`-separated scripts are split into one tactic per line.
+fn certificate_theorem(vc: &VerificationCondition, tactic: &str, explanation: &str) -> String {
+    let mut theorem = format!("/-- {}: {} -/\n", vc.description, explanation);
+
+    theorem.push_str(&format!("theorem {}", vc.id));
+    for (i, assumption) in vc.assumptions.iter().enumerate() {
+        theorem.push_str(&format!(" (h{} : {})", i, assumption));
+    }
+    theorem.push_str(&format!(" : {} := by\n", vc.property));
+
+    let script = tactic.trim().strip_prefix("by ").unwrap_or(tactic.trim());
+    for step in script.split("; ") {
+        let step = if step.starts_with("exact ") {
+            "assumption"
+        } else {
+            step.trim()
+        };
+        theorem.push_str(&format!("  {}\n", step));
+    }
+    theorem.push('\n');
+    theorem
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
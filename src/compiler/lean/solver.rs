@@ -259,6 +259,91 @@ pub enum PathConstraint {
     LtVar(String),
 }
 
+/// One side of a comparison: a sum of constants and variables
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    Const(i128),
+    Var(String),
+}
+
+impl Term {
+    /// Parse `a + 8 + b.toNat` into its atoms
+    fn parse_sum(text: &str) -> Option<Vec<Term>> {
+        text.split(" + ")
+            .map(|atom| {
+                let atom = atom
+                    .trim()
+                    .trim_start_matches('(')
+                    .trim_end_matches(')')
+                    .trim_end_matches(".toNat");
+                if atom.is_empty() {
+                    None
+                } else if let Ok(c) = atom.parse::<i128>() {
+                    Some(Term::Const(c))
+                } else if atom
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || "_-.".contains(c))
+                {
+                    Some(Term::Var(atom.to_string()))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn eval(sum: &[Term], env: &HashMap<&str, i128>) -> i128 {
+        sum.iter()
+            .map(|atom| match atom {
+                Term::Const(c) => *c,
+                Term::Var(v) => env.get(v.as_str()).copied().unwrap_or(0),
+            })
+            .fold(0i128, |acc, v| acc.saturating_add(v))
+    }
+}
+
+/// Comparison operator of a VC property
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Comparison {
+    /// Split `lhs OP rhs` into its parts
+    fn parse(property: &str) -> Option<(Vec<Term>, Comparison, Vec<Term>)> {
+        const OPS: [(&str, Comparison); 8] = [
+            (" ≤ ", Comparison::Le),
+            (" <= ", Comparison::Le),
+            (" ≥ ", Comparison::Ge),
+            (" >= ", Comparison::Ge),
+            (" ≠ ", Comparison::Ne),
+            (" < ", Comparison::Lt),
+            (" > ", Comparison::Gt),
+            (" = ", Comparison::Eq),
+        ];
+        let (lhs, op, rhs) = OPS
+            .iter()
+            .find_map(|(text, op)| property.split_once(text).map(|(lhs, rhs)| (lhs, *op, rhs)))?;
+        Some((Term::parse_sum(lhs)?, op, Term::parse_sum(rhs)?))
+    }
+
+    fn holds(self, a: i128, b: i128) -> bool {
+        match self {
+            Comparison::Lt => a < b,
+            Comparison::Le => a <= b,
+            Comparison::Gt => a > b,
+            Comparison::Ge => a >= b,
+            Comparison::Eq => a == b,
+            Comparison::Ne => a != b,
+        }
+    }
+}
+
 /// The built-in verification engine
 pub struct BuiltinVerifier {
     /// Known variable values/ranges
@@ -788,7 +873,7 @@ impl BuiltinVerifier {
         let verifier = self.clone_with_assumptions(&vc.assumptions);
 
         match &vc.category {
-            VCCategory::DivisionSafety => {
+            VCCategory::DivisionSafety | VCCategory::ModuloSafety => {
                 // Extract variable/literal from property "var ≠ 0"
                 let expr = vc
                     .property
@@ -2099,6 +2184,170 @@ impl BuiltinVerifier {
         }
     }
 
+    /// Find the smallest concrete inputs that violate a VC's property.
+    ///
+    /// The property must be a single comparison (`a < b`, `x.toNat ≥ y.toNat`,
+    /// `d ≠ 0`, `off + 8 ≤ data_len`, ...). Each variable's feasible interval
+    /// comes from the environment, array sizes and path conditions (including
+    /// the VC's assumptions); the search then tries the interval ends and the
+    /// values next to every constant in play, returning the violating
+    /// assignment with the smallest magnitude as an object of variable name
+    /// to value. Returns `None` when no violation is found.
+    pub fn minimal_counterexample(
+        &self,
+        vc: &super::VerificationCondition,
+    ) -> Option<crate::Value> {
+        let verifier = self.clone_with_assumptions(&vc.assumptions);
+        let (lhs, op, rhs) = Comparison::parse(&vc.property)?;
+
+        let mut vars: Vec<String> = Vec::new();
+        for atom in lhs.iter().chain(rhs.iter()) {
+            if let Term::Var(name) = atom {
+                if !vars.contains(name) {
+                    vars.push(name.clone());
+                }
+            }
+        }
+        if vars.is_empty() || vars.len() > 3 {
+            return None;
+        }
+
+        let bounds: Vec<(i128, i128)> =
+            vars.iter().map(|v| verifier.feasible_interval(v)).collect();
+        if bounds.iter().any(|(lo, hi)| lo > hi) {
+            return None;
+        }
+
+        // Every constant the property can be compared against
+        let mut pivots: Vec<i128> = lhs
+            .iter()
+            .chain(rhs.iter())
+            .filter_map(|atom| match atom {
+                Term::Const(c) => Some(*c),
+                Term::Var(_) => None,
+            })
+            .collect();
+        pivots.push(0);
+        for (lo, hi) in &bounds {
+            pivots.push(*lo);
+            pivots.push(*hi);
+        }
+
+        let candidates: Vec<Vec<i128>> = bounds
+            .iter()
+            .map(|&(lo, hi)| {
+                let mut values: Vec<i128> = pivots
+                    .iter()
+                    .flat_map(|&p| [p.saturating_sub(1), p, p.saturating_add(1)])
+                    .chain([lo, lo.saturating_add(1)])
+                    .map(|v| v.clamp(lo, hi))
+                    .collect();
+                values.sort();
+                values.dedup();
+                values
+            })
+            .collect();
+
+        let mut best: Option<(i128, Vec<i128>)> = None;
+        let mut assignment = vec![0i128; vars.len()];
+        Self::search_assignments(&candidates, 0, &mut assignment, &mut |values| {
+            let env: HashMap<&str, i128> = vars
+                .iter()
+                .map(String::as_str)
+                .zip(values.iter().copied())
+                .collect();
+            if op.holds(Term::eval(&lhs, &env), Term::eval(&rhs, &env)) {
+                return;
+            }
+            let cost = values.iter().map(|v| v.abs()).sum::<i128>();
+            if best
+                .as_ref()
+                .is_none_or(|(c, b)| (cost, values) < (*c, b.as_slice()))
+            {
+                best = Some((cost, values.to_vec()));
+            }
+        });
+
+        let (_, values) = best?;
+        let fields = vars
+            .into_iter()
+            .zip(values)
+            .map(|(name, v)| {
+                (
+                    name,
+                    crate::Value::Int(v.clamp(i64::MIN as i128, i64::MAX as i128) as i64),
+                )
+            })
+            .collect();
+        Some(crate::Value::object(fields))
+    }
+
+    /// Enumerate the cartesian product of per-variable candidate values
+    fn search_assignments(
+        candidates: &[Vec<i128>],
+        depth: usize,
+        assignment: &mut Vec<i128>,
+        visit: &mut dyn FnMut(&[i128]),
+    ) {
+        if depth == candidates.len() {
+            visit(assignment);
+            return;
+        }
+        for &v in &candidates[depth] {
+            assignment[depth] = v;
+            Self::search_assignments(candidates, depth + 1, assignment, visit);
+        }
+    }
+
+    /// Interval a variable can take given everything known about it.
+    /// Unconstrained values are assumed non-negative (u64 semantics).
+    fn feasible_interval(&self, var: &str) -> (i128, i128) {
+        let (mut lo, mut hi) = (0, u64::MAX as i128);
+
+        if let Some(arr) = var.strip_suffix(".size") {
+            if let Some(&size) = self.array_sizes.get(arr) {
+                return (size as i128, size as i128);
+            }
+        }
+
+        match self.lookup(var) {
+            SymbolicValue::Constant(c) => return (c, c),
+            SymbolicValue::Range { lo: l, hi: h } => {
+                lo = l;
+                hi = h;
+            }
+            SymbolicValue::Symbol { constraints, .. } => {
+                for c in constraints {
+                    match c {
+                        Constraint::GeqConst(v) => lo = lo.max(v),
+                        Constraint::LtConst(v) => hi = hi.min(v - 1),
+                        Constraint::InRange { lo: l, hi: h } => {
+                            lo = lo.max(l);
+                            hi = hi.min(h);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            SymbolicValue::Unknown => {}
+        }
+
+        for pc in self.path_conditions.iter().filter(|pc| pc.var == var) {
+            match pc.condition {
+                PathConstraint::Geq(v) => lo = lo.max(v),
+                PathConstraint::Lt(v) => hi = hi.min(v - 1),
+                PathConstraint::Eq(v) => {
+                    lo = lo.max(v);
+                    hi = hi.min(v);
+                }
+                PathConstraint::IsNonZero if lo == 0 => lo = 1,
+                _ => {}
+            }
+        }
+
+        (lo, hi)
+    }
+
     /// Create a clone of this verifier with additional path conditions from VC assumptions
     fn clone_with_assumptions(&self, assumptions: &[String]) -> Self {
         let mut verifier = Self {
@@ -3023,4 +3272,75 @@ mod tests {
             result
         );
     }
+
+    // =========================================================================
+    // Counterexample Minimization Tests
+    // =========================================================================
+
+    fn field(value: &crate::Value, key: &str) -> Option<i64> {
+        match value {
+            crate::Value::Object(map) => match map.get(key) {
+                Some(crate::Value::Int(n)) => Some(*n),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn vc(
+        category: super::super::VCCategory,
+        property: &str,
+    ) -> super::super::VerificationCondition {
+        super::super::VerificationCondition {
+            id: "test".to_string(),
+            category,
+            description: "test".to_string(),
+            location: None,
+            property: property.to_string(),
+            assumptions: vec![],
+            tactic: "omega".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_minimal_counterexample_array_index() {
+        use super::super::VCCategory;
+
+        let mut v = BuiltinVerifier::new();
+        v.define_array("arr", 10);
+        let vc = vc(VCCategory::ArrayBounds, "i < arr.size");
+
+        let witness = v.minimal_counterexample(&vc).unwrap();
+        assert_eq!(field(&witness, "i"), Some(10));
+        assert_eq!(field(&witness, "arr.size"), Some(10));
+    }
+
+    #[test]
+    fn test_minimal_counterexample_underflow() {
+        use super::super::VCCategory;
+
+        let mut v = BuiltinVerifier::new();
+        v.define("balance", SymbolicValue::Constant(100));
+        let vc = vc(
+            VCCategory::ArithmeticUnderflow,
+            "balance.toNat ≥ amount.toNat",
+        );
+
+        let witness = v.minimal_counterexample(&vc).unwrap();
+        assert_eq!(field(&witness, "balance"), Some(100));
+        assert_eq!(field(&witness, "amount"), Some(101));
+    }
+
+    #[test]
+    fn test_minimal_counterexample_respects_guards() {
+        use super::super::VCCategory;
+
+        let v = BuiltinVerifier::new();
+        let mut vc = vc(VCCategory::DivisionSafety, "d ≠ 0");
+        assert_eq!(field(&v.minimal_counterexample(&vc).unwrap(), "d"), Some(0));
+
+        // A guard that rules out zero leaves nothing to report
+        vc.assumptions = vec!["d > 0".to_string()];
+        assert!(v.minimal_counterexample(&vc).is_none());
+    }
 }
@@ -103,6 +103,10 @@ pub struct CompileOptions {
     pub verification_mode: VerificationMode,
     /// Formal verification options (when verification_mode != Skip)
     pub verification_options: lean::VerificationOptions,
+    /// Report every failed/unknown VC as a structured `Diagnostic` (in
+    /// `CompileResult::diagnostics`, or `Error::VerificationFailed` when
+    /// `Require` blocks compilation)
+    pub collect_all_diagnostics: bool,
 }
 
 impl Default for CompileOptions {
@@ -113,10 +117,11 @@ impl Default for CompileOptions {
             debug_info: false,
             source_map: false,
             sbpf_version: SbpfVersion::V1, // V1 with relocations for comparison
-            enable_solana_abi: false,      // Temporarily disabled while fixing opcode issues
+            enable_solana_abi: false,      // Opt-in: R2 setup for loaders that only pass R1
             type_check_mode: TypeCheckMode::Legacy, // Use existing checker by default
             verification_mode: VerificationMode::Require, // Require formal verification by default
             verification_options: lean::VerificationOptions::default(),
+            collect_all_diagnostics: false,
         }
     }
 }
@@ -140,6 +145,57 @@ pub struct CompileResult {
     pub type_errors: Vec<String>,
     /// Formal verification result (Lean 4 theorem proving)
     pub formal_verification: Option<lean::VerificationResult>,
+    /// Unproved VCs (only when `collect_all_diagnostics` is set)
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Severity of a compile diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    /// The property was disproved (definitely unsafe)
+    Error,
+    /// The property could not be proved either way
+    Warning,
+}
+
+/// A single verification problem, for tools that show all issues at once
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// Error for disproved VCs, warning for unknown ones
+    pub severity: DiagnosticSeverity,
+    /// Category of the verification condition
+    pub category: lean::VCCategory,
+    /// Human-readable description of the condition
+    pub message: String,
+    /// Why the condition failed or could not be proved
+    pub reason: String,
+    /// Source location, when known
+    pub location: Option<lean::SourceLocation>,
+    /// Suggested fix (if available)
+    pub suggestion: Option<String>,
+}
+
+impl Diagnostic {
+    /// Collect one diagnostic per failed and unknown VC, failures first
+    pub fn from_verification(result: &lean::VerificationResult) -> Vec<Diagnostic> {
+        let failed = result.failed.iter().map(|vc| Diagnostic {
+            severity: DiagnosticSeverity::Error,
+            category: vc.category.clone(),
+            message: vc.description.clone(),
+            reason: vc.error.clone(),
+            location: vc.location.clone(),
+            suggestion: vc.suggestion.clone(),
+        });
+        let unknown = result.unknown.iter().map(|vc| Diagnostic {
+            severity: DiagnosticSeverity::Warning,
+            category: vc.category.clone(),
+            message: vc.description.clone(),
+            reason: vc.reason.clone(),
+            location: vc.location.clone(),
+            suggestion: None,
+        });
+        failed.chain(unknown).collect()
+    }
 }
 
 /// OVSM to sBPF Compiler
@@ -292,9 +348,12 @@ impl Compiler {
                 )?
             }
             SbpfVersion::V2 => {
-                // V2: No relocations needed, syscalls are embedded
-                elf_writer.write(
+                // V2: No relocations needed, syscall hashes are in the call
+                // immediates; only string pointers need patching
+                elf_writer.write_static(
                     &sbpf_program,
+                    &string_load_refs,
+                    &codegen.rodata,
                     self.options.debug_info,
                     self.options.sbpf_version,
                 )?
@@ -315,6 +374,11 @@ impl Compiler {
             }
         }
 
+        let diagnostics = match &formal_verification {
+            Some(fv) if self.options.collect_all_diagnostics => Diagnostic::from_verification(fv),
+            _ => Vec::new(),
+        };
+
         Ok(CompileResult {
             elf_bytes,
             estimated_cu: verification.stats.estimated_cu,
@@ -324,6 +388,7 @@ impl Compiler {
             verification: Some(verification),
             type_errors,
             formal_verification,
+            diagnostics,
         })
     }
 
@@ -404,6 +469,12 @@ impl Compiler {
                     }
 
                     error_msg.push_str("To compile anyway, use --verification-mode=warn or --verification-mode=skip\n");
+                    if self.options.collect_all_diagnostics {
+                        return Err(Error::VerificationFailed {
+                            message: error_msg,
+                            diagnostics: Diagnostic::from_verification(&result),
+                        });
+                    }
                     return Err(Error::compiler(error_msg));
                 }
             }
@@ -513,9 +584,12 @@ impl Compiler {
                 )?
             }
             SbpfVersion::V2 => {
-                // V2: No relocations needed, syscalls are embedded
-                elf_writer.write(
+                // V2: No relocations needed, syscall hashes are in the call
+                // immediates; only string pointers need patching
+                elf_writer.write_static(
                     &sbpf_program,
+                    &string_load_refs,
+                    &codegen.rodata,
                     self.options.debug_info,
                     self.options.sbpf_version,
                 )?
@@ -535,6 +609,11 @@ impl Compiler {
             }
         }
 
+        let diagnostics = match &formal_verification {
+            Some(fv) if self.options.collect_all_diagnostics => Diagnostic::from_verification(fv),
+            _ => Vec::new(),
+        };
+
         Ok(CompileResult {
             elf_bytes,
             estimated_cu: verification.stats.estimated_cu,
@@ -544,6 +623,7 @@ impl Compiler {
             verification: Some(verification),
             type_errors,
             formal_verification,
+            diagnostics,
         })
     }
 }
@@ -557,4 +637,53 @@ mod tests {
         let compiler = Compiler::new(CompileOptions::default());
         assert_eq!(compiler.options.opt_level, 2);
     }
+
+    #[test]
+    fn test_v2_embeds_static_syscall_hash() {
+        let options = CompileOptions {
+            sbpf_version: SbpfVersion::V2,
+            verification_mode: VerificationMode::Skip,
+            ..Default::default()
+        };
+        let result = Compiler::new(options)
+            .compile(r#"(sol_log_ "hello v2")"#)
+            .unwrap();
+        let elf = &result.elf_bytes;
+
+        let (_, text) = extract_text_section(elf).expect("text section");
+        assert!(
+            validate_sbpf(&text).is_empty(),
+            "{:?}",
+            validate_sbpf(&text)
+        );
+
+        let calls: Vec<&[u8]> = text.chunks(8).filter(|i| i[0] == 0x85).collect();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0][1] >> 4, 0, "static syscalls use src=0");
+        let imm = u32::from_le_bytes(calls[0][4..8].try_into().unwrap());
+        assert_eq!(imm, syscall_hash(SolanaSymbols::SOL_LOG));
+
+        // No dynamic symbol table or relocations
+        let shoff = u64::from_le_bytes(elf[40..48].try_into().unwrap()) as usize;
+        let shnum = u16::from_le_bytes(elf[60..62].try_into().unwrap()) as usize;
+        for i in 0..shnum {
+            let sh_type = u32::from_le_bytes(elf[shoff + i * 64 + 4..][..4].try_into().unwrap());
+            assert!(
+                ![6, 9, 11].contains(&sh_type),
+                "dynamic section {}",
+                sh_type
+            );
+        }
+
+        // The string pointer was patched to the absolute .rodata address
+        let patched = (0..text.len() / 8)
+            .filter(|i| text[i * 8] == 0x18)
+            .any(|i| {
+                let imm = |at: usize| u32::from_le_bytes(text[at..at + 4].try_into().unwrap());
+                let addr = ((imm(i * 8 + 12) as u64) << 32) | imm(i * 8 + 4) as u64;
+                let file_offset = addr.wrapping_sub(0x100000000) as usize;
+                elf.get(file_offset..file_offset + 8) == Some(b"hello v2".as_slice())
+            });
+        assert!(patched, "no lddw points at the string literal");
+    }
 }
@@ -1,12 +1,13 @@
 //! # IR Optimizer for Solisp Compilation
 //!
 //! Optimization passes for the IR:
-//! - Constant folding
+//! - Constant folding (including branches on constant conditions)
+//! - Unreachable basic block elimination
 //! - Dead code elimination
 //! - Common subexpression elimination
 //! - Peephole optimizations
 
-use super::ir::{IrInstruction, IrProgram, IrReg};
+use super::ir::{BasicBlock, IrInstruction, IrProgram, IrReg};
 use std::collections::{HashMap, HashSet};
 
 /// Optimizer with configurable optimization level
@@ -23,8 +24,15 @@ impl Optimizer {
     /// Run all optimization passes
     pub fn optimize(&mut self, program: &mut IrProgram) {
         if self.level >= 1 {
-            self.constant_folding(program);
-            self.dead_code_elimination(program);
+            // Folding exposes dead constants, and removing them can make a
+            // register single-definition (and so foldable) again
+            loop {
+                self.constant_folding(program);
+                let pruned = self.unreachable_block_elimination(program);
+                if !self.dead_code_elimination(program) && !pruned {
+                    break;
+                }
+            }
         }
 
         if self.level >= 2 {
@@ -43,8 +51,13 @@ impl Optimizer {
     }
 
     /// Constant folding - evaluate constant expressions at compile time
+    ///
+    /// Within a basic block every constant-valued register is tracked. Across
+    /// labels only registers with a single definition in the whole program
+    /// keep their value, which is what `define`d constants compile to.
     fn constant_folding(&mut self, program: &mut IrProgram) {
-        let mut constants: HashMap<IrReg, i64> = HashMap::new();
+        let global = Self::single_def_constants(program);
+        let mut constants = global.clone();
 
         for instr in program.instructions.iter_mut() {
             match instr {
@@ -56,79 +69,56 @@ impl Optimizer {
                     constants.insert(*dst, if *value { 1 } else { 0 });
                 }
 
-                IrInstruction::Add(dst, src1, src2) => {
-                    let (dst, src1, src2) = (*dst, *src1, *src2);
-                    if let (Some(&v1), Some(&v2)) = (constants.get(&src1), constants.get(&src2)) {
-                        let result = v1.wrapping_add(v2);
-                        *instr = IrInstruction::ConstI64(dst, result);
-                        constants.insert(dst, result);
-                    }
-                }
-
-                IrInstruction::Sub(dst, src1, src2) => {
-                    let (dst, src1, src2) = (*dst, *src1, *src2);
-                    if let (Some(&v1), Some(&v2)) = (constants.get(&src1), constants.get(&src2)) {
-                        let result = v1.wrapping_sub(v2);
-                        *instr = IrInstruction::ConstI64(dst, result);
-                        constants.insert(dst, result);
-                    }
-                }
-
-                IrInstruction::Mul(dst, src1, src2) => {
-                    let (dst, src1, src2) = (*dst, *src1, *src2);
-                    if let (Some(&v1), Some(&v2)) = (constants.get(&src1), constants.get(&src2)) {
-                        let result = v1.wrapping_mul(v2);
-                        *instr = IrInstruction::ConstI64(dst, result);
-                        constants.insert(dst, result);
-                    }
-                }
-
-                IrInstruction::Div(dst, src1, src2) => {
-                    let (dst, src1, src2) = (*dst, *src1, *src2);
-                    if let (Some(&v1), Some(&v2)) = (constants.get(&src1), constants.get(&src2)) {
-                        if v2 != 0 {
-                            let result = v1 / v2;
-                            *instr = IrInstruction::ConstI64(dst, result);
-                            constants.insert(dst, result);
+                IrInstruction::Move(dst, src) => {
+                    let (dst, src) = (*dst, *src);
+                    match constants.get(&src) {
+                        Some(&value) => {
+                            *instr = IrInstruction::ConstI64(dst, value);
+                            constants.insert(dst, value);
+                        }
+                        None => {
+                            constants.remove(&dst);
                         }
                     }
                 }
 
-                IrInstruction::Mod(dst, src1, src2) => {
-                    let (dst, src1, src2) = (*dst, *src1, *src2);
-                    if let (Some(&v1), Some(&v2)) = (constants.get(&src1), constants.get(&src2)) {
-                        if v2 != 0 {
-                            let result = v1 % v2;
+                IrInstruction::Add(dst, src1, src2)
+                | IrInstruction::Sub(dst, src1, src2)
+                | IrInstruction::Mul(dst, src1, src2)
+                | IrInstruction::Div(dst, src1, src2)
+                | IrInstruction::Mod(dst, src1, src2)
+                | IrInstruction::Eq(dst, src1, src2)
+                | IrInstruction::Ne(dst, src1, src2)
+                | IrInstruction::Lt(dst, src1, src2)
+                | IrInstruction::Le(dst, src1, src2)
+                | IrInstruction::Gt(dst, src1, src2)
+                | IrInstruction::Ge(dst, src1, src2)
+                | IrInstruction::And(dst, src1, src2)
+                | IrInstruction::Or(dst, src1, src2) => {
+                    let dst = *dst;
+                    let folded = match (constants.get(src1), constants.get(src2)) {
+                        (Some(&v1), Some(&v2)) => Self::eval_binary(instr, v1, v2),
+                        _ => None,
+                    };
+                    match folded {
+                        Some(result) => {
                             *instr = IrInstruction::ConstI64(dst, result);
                             constants.insert(dst, result);
                         }
-                    }
-                }
-
-                IrInstruction::Eq(dst, src1, src2) => {
-                    let (dst, src1, src2) = (*dst, *src1, *src2);
-                    if let (Some(&v1), Some(&v2)) = (constants.get(&src1), constants.get(&src2)) {
-                        let result = if v1 == v2 { 1 } else { 0 };
-                        *instr = IrInstruction::ConstI64(dst, result);
-                        constants.insert(dst, result);
-                    }
-                }
-
-                IrInstruction::Lt(dst, src1, src2) => {
-                    let (dst, src1, src2) = (*dst, *src1, *src2);
-                    if let (Some(&v1), Some(&v2)) = (constants.get(&src1), constants.get(&src2)) {
-                        let result = if v1 < v2 { 1 } else { 0 };
-                        *instr = IrInstruction::ConstI64(dst, result);
-                        constants.insert(dst, result);
+                        None => {
+                            constants.remove(&dst);
+                        }
                     }
                 }
 
                 IrInstruction::Neg(dst, src) => {
                     let (dst, src) = (*dst, *src);
                     if let Some(&v) = constants.get(&src) {
-                        let result = -v;
+                        let result = v.wrapping_neg();
                         *instr = IrInstruction::ConstI64(dst, result);
                         constants.insert(dst, result);
+                    } else {
+                        constants.remove(&dst);
                     }
                 }
 
@@ -138,28 +128,230 @@ impl Optimizer {
                         let result = if v == 0 { 1 } else { 0 };
                         *instr = IrInstruction::ConstI64(dst, result);
                         constants.insert(dst, result);
+                    } else {
+                        constants.remove(&dst);
                     }
                 }
 
-                // Labels and jumps invalidate our constant tracking
-                IrInstruction::Label(_)
-                | IrInstruction::Jump(_)
-                | IrInstruction::JumpIf(_, _)
-                | IrInstruction::JumpIfNot(_, _) => {
-                    constants.clear();
+                // Branches on a known condition become a jump or fall through
+                IrInstruction::JumpIf(cond, target) | IrInstruction::JumpIfNot(cond, target) => {
+                    let (cond, target) = (*cond, target.clone());
+                    if let Some(&value) = constants.get(&cond) {
+                        let taken = (value != 0) == matches!(instr, IrInstruction::JumpIf(..));
+                        *instr = if taken {
+                            IrInstruction::Jump(target)
+                        } else {
+                            IrInstruction::Nop
+                        };
+                    }
+                    constants.clone_from(&global);
                 }
 
-                _ => {}
+                // Labels and jumps invalidate block-local constant tracking
+                IrInstruction::Label(_) | IrInstruction::Jump(_) => {
+                    constants.clone_from(&global);
+                }
+
+                // Anything else that writes a register makes it unknown
+                other => {
+                    if let Some(dst) = other.defined_reg() {
+                        constants.remove(&dst);
+                    }
+                }
             }
         }
     }
 
-    /// Dead code elimination - remove instructions whose results are never used
-    fn dead_code_elimination(&mut self, program: &mut IrProgram) {
+    /// Evaluate a binary instruction on constant operands, with the same
+    /// semantics as the generated sBPF (wrapping arithmetic, unsigned
+    /// division and comparisons)
+    fn eval_binary(instr: &IrInstruction, v1: i64, v2: i64) -> Option<i64> {
+        let (u1, u2) = (v1 as u64, v2 as u64);
+        let result = match instr {
+            IrInstruction::Add(..) => v1.wrapping_add(v2),
+            IrInstruction::Sub(..) => v1.wrapping_sub(v2),
+            IrInstruction::Mul(..) => v1.wrapping_mul(v2),
+            IrInstruction::Div(..) if u2 != 0 => (u1 / u2) as i64,
+            IrInstruction::Mod(..) if u2 != 0 => (u1 % u2) as i64,
+            IrInstruction::Eq(..) => (u1 == u2) as i64,
+            IrInstruction::Ne(..) => (u1 != u2) as i64,
+            IrInstruction::Lt(..) => (u1 < u2) as i64,
+            IrInstruction::Le(..) => (u1 <= u2) as i64,
+            IrInstruction::Gt(..) => (u1 > u2) as i64,
+            IrInstruction::Ge(..) => (u1 >= u2) as i64,
+            IrInstruction::And(..) => v1 & v2,
+            IrInstruction::Or(..) => v1 | v2,
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    /// Registers defined exactly once in the program, by a constant load
+    fn single_def_constants(program: &IrProgram) -> HashMap<IrReg, i64> {
+        let mut def_counts: HashMap<IrReg, usize> = HashMap::new();
+        for instr in &program.instructions {
+            if let Some(dst) = instr.defined_reg() {
+                *def_counts.entry(dst).or_insert(0) += 1;
+            }
+        }
+
+        program
+            .instructions
+            .iter()
+            .filter_map(|instr| match instr {
+                IrInstruction::ConstI64(dst, value) => Some((*dst, *value)),
+                IrInstruction::ConstBool(dst, value) => Some((*dst, *value as i64)),
+                _ => None,
+            })
+            .filter(|(dst, _)| def_counts.get(dst) == Some(&1))
+            .collect()
+    }
+
+    /// Unreachable block elimination - drop basic blocks that no path from
+    /// the entry reaches, then rebuild `program.blocks`. Returns true if
+    /// anything was removed.
+    ///
+    /// Calls and syscalls (including CPIs) return to the next instruction, so
+    /// they never end a block and code after them stays reachable.
+    fn unreachable_block_elimination(&mut self, program: &mut IrProgram) -> bool {
+        let instructions: Vec<IrInstruction> = std::mem::take(&mut program.instructions)
+            .into_iter()
+            .filter(|instr| !matches!(instr, IrInstruction::Nop))
+            .collect();
+        let before = instructions.len();
+
+        let mut blocks = Self::split_blocks(instructions, &program.entry_label);
+        let index: HashMap<String, usize> = blocks
+            .iter()
+            .enumerate()
+            .map(|(i, block)| (block.label.clone(), i))
+            .collect();
+
+        // Worklist reachability from the entry block
+        let mut reachable = vec![false; blocks.len()];
+        let mut worklist = vec![0];
+        while let Some(i) = worklist.pop() {
+            if i >= blocks.len() || reachable[i] {
+                continue;
+            }
+            reachable[i] = true;
+            for succ in &blocks[i].successors {
+                if let Some(&j) = index.get(succ) {
+                    worklist.push(j);
+                }
+            }
+        }
+
+        let mut kept: Vec<BasicBlock> = blocks
+            .drain(..)
+            .zip(reachable)
+            .filter_map(|(block, live)| live.then_some(block))
+            .collect();
+
+        // A jump to the label that immediately follows is a fall-through
+        for i in 0..kept.len().saturating_sub(1) {
+            let next = kept[i + 1].label.clone();
+            if let Some(IrInstruction::Jump(target)) = kept[i].instructions.last() {
+                if *target == next {
+                    kept[i].instructions.pop();
+                }
+            }
+        }
+
+        program.instructions = kept
+            .iter()
+            .flat_map(|block| block.instructions.iter().cloned())
+            .collect();
+        let changed = program.instructions.len() != before;
+
+        // Rebuild the CFG from what is left
+        let mut predecessors: HashMap<String, Vec<String>> = HashMap::new();
+        for block in &kept {
+            for succ in &block.successors {
+                predecessors
+                    .entry(succ.clone())
+                    .or_default()
+                    .push(block.label.clone());
+            }
+        }
+        program.blocks = kept
+            .into_iter()
+            .map(|mut block| {
+                block.predecessors = predecessors.remove(&block.label).unwrap_or_default();
+                (block.label.clone(), block)
+            })
+            .collect();
+
+        changed
+    }
+
+    /// Split a linear instruction list into basic blocks with successors.
+    ///
+    /// Blocks start at labels and after jumps and returns; a block without a
+    /// leading label can only be the entry, which takes `entry_label`.
+    fn split_blocks(instructions: Vec<IrInstruction>, entry_label: &str) -> Vec<BasicBlock> {
+        let mut blocks: Vec<BasicBlock> = Vec::new();
+        let mut current: Option<BasicBlock> = None;
+        let mut unlabeled = 0;
+
+        for instr in instructions {
+            if let IrInstruction::Label(label) = &instr {
+                if let Some(block) = current.take() {
+                    blocks.push(block);
+                }
+                current = Some(BasicBlock::new(label));
+            }
+
+            let block = current.get_or_insert_with(|| {
+                let label = if blocks.is_empty() {
+                    entry_label.to_string()
+                } else {
+                    unlabeled += 1;
+                    format!("__unlabeled_{}", unlabeled)
+                };
+                BasicBlock::new(&label)
+            });
+
+            let ends_block = matches!(
+                instr,
+                IrInstruction::Jump(_)
+                    | IrInstruction::JumpIf(..)
+                    | IrInstruction::JumpIfNot(..)
+                    | IrInstruction::Return(_)
+            );
+            block.instructions.push(instr);
+            if ends_block {
+                blocks.extend(current.take());
+            }
+        }
+        blocks.extend(current);
+
+        // Successors: branch targets, plus the next block on fall-through
+        let labels: Vec<String> = blocks.iter().map(|b| b.label.clone()).collect();
+        for (i, block) in blocks.iter_mut().enumerate() {
+            let fall_through = labels.get(i + 1).cloned();
+            block.successors = match block.instructions.last() {
+                Some(IrInstruction::Jump(target)) => vec![target.clone()],
+                Some(IrInstruction::JumpIf(_, target))
+                | Some(IrInstruction::JumpIfNot(_, target)) => std::iter::once(target.clone())
+                    .chain(fall_through)
+                    .collect(),
+                Some(IrInstruction::Return(_)) => Vec::new(),
+                _ => fall_through.into_iter().collect(),
+            };
+        }
+
+        blocks
+    }
+
+    /// Dead code elimination - remove pure instructions whose results are
+    /// never used. Returns true if anything was removed.
+    ///
+    /// Calls, syscalls, loads, stores and allocations are always kept.
+    fn dead_code_elimination(&mut self, program: &mut IrProgram) -> bool {
         // Find all used registers
         let mut used_regs: HashSet<IrReg> = HashSet::new();
 
-        // First pass: find all registers that are used
         for instr in &program.instructions {
             match instr {
                 IrInstruction::Add(_, src1, src2)
@@ -203,10 +395,21 @@ impl Optimizer {
                     }
                 }
 
-                IrInstruction::Load(_, base, _) | IrInstruction::Store(base, _, _) => {
+                IrInstruction::Load(_, base, _)
+                | IrInstruction::Load1(_, base, _)
+                | IrInstruction::Load2(_, base, _)
+                | IrInstruction::Load4(_, base, _) => {
                     used_regs.insert(*base);
                 }
 
+                IrInstruction::Store(base, src, _)
+                | IrInstruction::Store1(base, src, _)
+                | IrInstruction::Store2(base, src, _)
+                | IrInstruction::Store4(base, src, _) => {
+                    used_regs.insert(*base);
+                    used_regs.insert(*src);
+                }
+
                 IrInstruction::Alloc(_, size) => {
                     used_regs.insert(*size);
                 }
@@ -216,6 +419,7 @@ impl Optimizer {
         }
 
         // Second pass: mark dead instructions as Nop
+        let mut changed = false;
         for instr in program.instructions.iter_mut() {
             let dst = match instr {
                 IrInstruction::ConstI64(dst, _)
@@ -246,9 +450,11 @@ impl Optimizer {
             if let Some(dst_reg) = dst {
                 if !used_regs.contains(&dst_reg) {
                     *instr = IrInstruction::Nop;
+                    changed = true;
                 }
             }
         }
+        changed
     }
 
     /// Common subexpression elimination
@@ -415,4 +621,231 @@ mod tests {
             panic!("Expected constant folding to work");
         }
     }
+
+    #[test]
+    fn test_define_constants_fold_across_labels() {
+        // (define a 2) (define b 3) (while ...) (* a b)
+        let mut program = IrProgram::new();
+        program.instructions = vec![
+            IrInstruction::ConstI64(IrReg(0), 2),
+            IrInstruction::ConstI64(IrReg(1), 3),
+            IrInstruction::Label("loop".to_string()),
+            IrInstruction::Mul(IrReg(2), IrReg(0), IrReg(1)),
+            IrInstruction::Syscall(None, "sol_log_64_".to_string(), vec![IrReg(2)]),
+            IrInstruction::Return(Some(IrReg(2))),
+        ];
+
+        Optimizer::new(1).optimize(&mut program);
+
+        assert_eq!(
+            program.instructions,
+            vec![
+                IrInstruction::Label("loop".to_string()),
+                IrInstruction::ConstI64(IrReg(2), 6),
+                IrInstruction::Syscall(None, "sol_log_64_".to_string(), vec![IrReg(2)]),
+                IrInstruction::Return(Some(IrReg(2))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reassigned_registers_do_not_fold_across_labels() {
+        let mut program = IrProgram::new();
+        program.instructions = vec![
+            IrInstruction::ConstI64(IrReg(0), 0),
+            IrInstruction::ConstI64(IrReg(1), 1),
+            IrInstruction::Label("loop".to_string()),
+            IrInstruction::Add(IrReg(0), IrReg(0), IrReg(1)),
+            IrInstruction::Jump("loop".to_string()),
+        ];
+        let expected = program.instructions.clone();
+
+        Optimizer::new(1).optimize(&mut program);
+
+        assert_eq!(program.instructions, expected);
+    }
+
+    #[test]
+    fn test_comparisons_fold_unsigned() {
+        let mut program = IrProgram::new();
+        program.instructions = vec![
+            IrInstruction::ConstI64(IrReg(0), -1),
+            IrInstruction::ConstI64(IrReg(1), 1),
+            IrInstruction::Lt(IrReg(2), IrReg(0), IrReg(1)),
+            IrInstruction::Return(Some(IrReg(2))),
+        ];
+
+        Optimizer::new(1).optimize(&mut program);
+
+        // u64::MAX < 1 is false, matching the generated `jlt`
+        assert_eq!(
+            program.instructions[0],
+            IrInstruction::ConstI64(IrReg(2), 0)
+        );
+    }
+
+    #[test]
+    fn test_dead_code_elimination_keeps_stored_values() {
+        let mut program = IrProgram::new();
+        program.instructions = vec![
+            IrInstruction::Load(IrReg(0), IrReg(9), 0),
+            IrInstruction::Load(IrReg(1), IrReg(9), 8),
+            IrInstruction::Add(IrReg(2), IrReg(0), IrReg(1)),
+            IrInstruction::Store1(IrReg(9), IrReg(2), 16),
+        ];
+        let expected = program.instructions.clone();
+
+        Optimizer::new(1).optimize(&mut program);
+
+        assert_eq!(program.instructions, expected);
+    }
+
+    #[test]
+    fn test_constant_false_branch_is_removed() {
+        let mut program = IrProgram::new();
+        program.instructions = vec![
+            IrInstruction::Label("entry".to_string()),
+            IrInstruction::ConstBool(IrReg(0), false),
+            IrInstruction::JumpIf(IrReg(0), "then".to_string()),
+            IrInstruction::Jump("else".to_string()),
+            IrInstruction::Label("then".to_string()),
+            IrInstruction::Syscall(None, "sol_log_".to_string(), vec![]),
+            IrInstruction::Jump("end".to_string()),
+            IrInstruction::Label("else".to_string()),
+            IrInstruction::Syscall(None, "sol_log_64_".to_string(), vec![]),
+            IrInstruction::Label("end".to_string()),
+            IrInstruction::Return(None),
+        ];
+
+        Optimizer::new(1).optimize(&mut program);
+
+        assert_eq!(
+            program.instructions,
+            vec![
+                IrInstruction::Label("entry".to_string()),
+                IrInstruction::Label("else".to_string()),
+                IrInstruction::Syscall(None, "sol_log_64_".to_string(), vec![]),
+                IrInstruction::Label("end".to_string()),
+                IrInstruction::Return(None),
+            ]
+        );
+        assert!(!program.blocks.contains_key("then"));
+        assert_eq!(program.blocks["entry"].successors, vec!["else".to_string()]);
+        assert_eq!(program.blocks["end"].predecessors, vec!["else".to_string()]);
+    }
+
+    #[test]
+    fn test_code_after_return_is_removed() {
+        let mut program = IrProgram::new();
+        program.instructions = vec![
+            IrInstruction::ConstI64(IrReg(0), 0),
+            IrInstruction::Return(Some(IrReg(0))),
+            IrInstruction::ConstI64(IrReg(1), 7),
+            IrInstruction::Return(Some(IrReg(1))),
+        ];
+
+        Optimizer::new(1).optimize(&mut program);
+
+        assert_eq!(program.instructions.len(), 2);
+        assert_eq!(program.blocks.len(), 1);
+    }
+
+    #[test]
+    fn test_unreachable_branch_absent_from_sbpf() {
+        use crate::compiler::{CompileOptions, Compiler, VerificationMode};
+        use crate::decompiler::Disassembler;
+
+        // Only the second syscall can ever execute
+        let source = "(define x 1)
+                      (if (> x 2) (sol_log_64_ 1 2 3 4 5) 0)
+                      (sol_log_64_ 9 9 9 9 9)";
+        let syscalls = |opt_level| {
+            let compiled = Compiler::new(CompileOptions {
+                opt_level,
+                verification_mode: VerificationMode::Skip,
+                ..Default::default()
+            })
+            .compile(source)
+            .unwrap();
+            Disassembler::new()
+                .disassemble(&compiled.elf_bytes)
+                .unwrap()
+                .iter()
+                .filter(|instr| instr.mnemonic == "call")
+                .count()
+        };
+
+        assert_eq!(syscalls(0), 2);
+        assert_eq!(syscalls(1), 1);
+    }
+
+    #[test]
+    fn test_defined_product_compiles_to_one_constant_load() {
+        use crate::compiler::{CompileOptions, Compiler, VerificationMode};
+        use crate::decompiler::{DisassembledInstr, Disassembler};
+
+        // Log the product so dead code elimination keeps it
+        let source = "(define a 2) (define b 3) (sol_log_64_ (* a b) 0 0 0 0)";
+        let compile = |opt_level| {
+            let compiled = Compiler::new(CompileOptions {
+                opt_level,
+                verification_mode: VerificationMode::Skip,
+                ..Default::default()
+            })
+            .compile(source)
+            .unwrap();
+            let instrs = Disassembler::new()
+                .disassemble(&compiled.elf_bytes)
+                .unwrap();
+            (compiled.sbpf_instruction_count, instrs)
+        };
+        let loads = |instrs: &[DisassembledInstr], imm: i64| {
+            instrs
+                .iter()
+                .filter(|instr| instr.mnemonic == "mov64" && instr.src == 0 && instr.imm64 == imm)
+                .count()
+        };
+        let muls = |instrs: &[DisassembledInstr]| {
+            instrs
+                .iter()
+                .filter(|instr| instr.mnemonic == "mul64")
+                .count()
+        };
+
+        let (unoptimized_count, unoptimized) = compile(0);
+        let (optimized_count, optimized) = compile(1);
+
+        assert_eq!((loads(&unoptimized, 2), loads(&unoptimized, 3)), (1, 1));
+        assert_eq!(
+            (
+                loads(&optimized, 2),
+                loads(&optimized, 3),
+                loads(&optimized, 6)
+            ),
+            (0, 0, 1)
+        );
+        // The entrypoint wrapper's multiplies stay; only the product goes
+        assert_eq!(muls(&optimized), muls(&unoptimized) - 1);
+        assert!(optimized_count < unoptimized_count);
+    }
+
+    #[test]
+    fn test_folding_reduces_compiled_size() {
+        use crate::compiler::{CompileOptions, Compiler, VerificationMode};
+
+        let compile = |opt_level| {
+            Compiler::new(CompileOptions {
+                opt_level,
+                verification_mode: VerificationMode::Skip,
+                ..Default::default()
+            })
+            .compile("(define a 2) (define b 3) (* a b)")
+            .unwrap()
+        };
+        let unoptimized = compile(0);
+        let optimized = compile(1);
+
+        assert!(optimized.ir_instruction_count < unoptimized.ir_instruction_count);
+        assert!(optimized.sbpf_instruction_count < unoptimized.sbpf_instruction_count);
+    }
 }
@@ -36,6 +36,8 @@ pub struct InstructionAnalysis {
     pub causes_spill: bool,
     /// Whether this instruction is a syscall (clobbers R1-R5)
     pub is_syscall: bool,
+    /// Number of loops enclosing this instruction (0 = straight-line code)
+    pub loop_depth: usize,
     /// Human-readable description
     pub description: String,
 }
@@ -353,6 +355,8 @@ impl RegAllocAnalyzer {
             live_out[i] = live_at;
         }
 
+        let loop_depths = Self::loop_depths(program);
+
         // Third pass: generate analysis with liveness info
         for (idx, ir) in program.instructions.iter().enumerate() {
             let defs = all_defs[idx].clone();
@@ -436,6 +440,7 @@ impl RegAllocAnalyzer {
                 pressure,
                 causes_spill,
                 is_syscall,
+                loop_depth: loop_depths[idx],
                 description: desc,
             });
         }
@@ -473,6 +478,49 @@ impl RegAllocAnalyzer {
         }
     }
 
+    /// Loops in the program as `(header, back_edge)` instruction indices
+    ///
+    /// A jump back to an earlier (or the same) label closes a loop spanning
+    /// the label up to the jump.
+    pub fn loops(program: &IrProgram) -> Vec<(usize, usize)> {
+        let labels: HashMap<&str, usize> = program
+            .instructions
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, ir)| match ir {
+                IrInstruction::Label(name) => Some((name.as_str(), idx)),
+                _ => None,
+            })
+            .collect();
+
+        program
+            .instructions
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, ir)| match ir {
+                IrInstruction::Jump(target)
+                | IrInstruction::JumpIf(_, target)
+                | IrInstruction::JumpIfNot(_, target) => labels
+                    .get(target.as_str())
+                    .filter(|&&header| header <= idx)
+                    .map(|&header| (header, idx)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Loop nesting depth of every instruction in the program: the number of
+    /// loops (see [`Self::loops`]) containing it
+    pub fn loop_depths(program: &IrProgram) -> Vec<usize> {
+        let mut depths = vec![0; program.instructions.len()];
+        for (header, back_edge) in Self::loops(program) {
+            for depth in &mut depths[header..=back_edge] {
+                *depth += 1;
+            }
+        }
+        depths
+    }
+
     /// Analyze a single IR instruction
     fn analyze_instruction(
         &self,
@@ -726,12 +726,12 @@ impl RegisterAllocator {
 
         Self {
             allocation: alloc,
-            // Use R3-R5, R8-R9 for allocation (R0=return, R1-R2=ABI, R6-R7=saved builtins, R10=FP)
+            // Use R3-R4, R8-R9 for allocation (R0=return, R1-R2=ABI, R5=spill reload scratch,
+            // R6-R7=saved builtins, R10=FP)
             // IMPORTANT: Callee-saved (R8-R9) first to avoid clobbering by syscalls
             available: vec![
                 SbpfReg::R9,
                 SbpfReg::R8, // Callee-saved: safe across syscalls
-                SbpfReg::R5,
                 SbpfReg::R4,
                 SbpfReg::R3, // Caller-saved: clobbered by syscalls
             ],
@@ -1166,7 +1166,16 @@ impl SbpfCodegen {
             }
 
             // Memory - with spill handling
-            IrInstruction::Load(dst, base, offset) => {
+            IrInstruction::Load(dst, base, offset)
+            | IrInstruction::Load1(dst, base, offset)
+            | IrInstruction::Load2(dst, base, offset)
+            | IrInstruction::Load4(dst, base, offset) => {
+                let width = match ir {
+                    IrInstruction::Load1(..) => size::B,
+                    IrInstruction::Load2(..) => size::H,
+                    IrInstruction::Load4(..) => size::W,
+                    _ => size::DW,
+                };
                 let base_reg = self.get_reg(*base, SbpfReg::R5);
                 let dst_phys = self.reg_alloc.allocate(*dst);
                 let actual_dst = if self.reg_alloc.is_spilled(*dst) {
@@ -1175,7 +1184,7 @@ impl SbpfCodegen {
                     dst_phys
                 };
                 self.emit(SbpfInstruction::ldx(
-                    size::DW,
+                    width,
                     actual_dst as u8,
                     base_reg as u8,
                     *offset as i16,
@@ -1183,11 +1192,20 @@ impl SbpfCodegen {
                 self.store_if_spilled(*dst, actual_dst);
             }
 
-            IrInstruction::Store(base, src, offset) => {
+            IrInstruction::Store(base, src, offset)
+            | IrInstruction::Store1(base, src, offset)
+            | IrInstruction::Store2(base, src, offset)
+            | IrInstruction::Store4(base, src, offset) => {
+                let width = match ir {
+                    IrInstruction::Store1(..) => size::B,
+                    IrInstruction::Store2(..) => size::H,
+                    IrInstruction::Store4(..) => size::W,
+                    _ => size::DW,
+                };
                 let base_reg = self.get_reg(*base, SbpfReg::R5);
                 let src_reg = self.get_reg(*src, SbpfReg::R0);
                 self.emit(SbpfInstruction::stx(
-                    size::DW,
+                    width,
                     base_reg as u8,
                     src_reg as u8,
                     *offset as i16,
@@ -1471,11 +1489,14 @@ impl SbpfCodegen {
         // Use version-aware syscall encoding
         let hash = self.get_syscall_hash(&solana_name);
         self.emit(SbpfInstruction::call_syscall(hash, self.sbpf_version));
-        // Record call sites for V1 relocations with normalized name
-        self.syscall_sites.push(SyscallCallSite {
-            offset,
-            name: solana_name,
-        });
+        // Record call sites for V1 relocations with normalized name; V2
+        // calls already carry the hash and need no relocation
+        if self.sbpf_version == super::SbpfVersion::V1 {
+            self.syscall_sites.push(SyscallCallSite {
+                offset,
+                name: solana_name,
+            });
+        }
     }
 
     fn current_offset(&self) -> usize {
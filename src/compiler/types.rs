@@ -397,6 +397,7 @@ impl TypeChecker {
                 match name.as_str() {
                     "length" => Ok(OvsmType::I64),
                     "range" => Ok(OvsmType::Array(Box::new(OvsmType::I64))),
+                    "linspace" => Ok(OvsmType::Array(Box::new(OvsmType::F64))),
                     "get" => Ok(OvsmType::Any),
                     "log" => Ok(OvsmType::Null),
                     "now" => Ok(OvsmType::I64),
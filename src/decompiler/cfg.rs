@@ -3,7 +3,7 @@
 //! Recovers the control flow graph from disassembled sBPF instructions.
 
 use super::disassembler::DisassembledInstr;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 /// A basic block in the control flow graph
 #[derive(Debug, Clone)]
@@ -38,6 +38,35 @@ impl BasicBlock {
     }
 }
 
+/// A natural loop: a header plus every block that can reach a back edge
+/// into it without passing through the header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NaturalLoop {
+    /// Loop header block ID (target of the back edges)
+    pub header: usize,
+    /// Blocks whose back edge jumps to the header
+    pub latches: Vec<usize>,
+    /// All block IDs in the loop, including the header
+    pub body: BTreeSet<usize>,
+}
+
+impl NaturalLoop {
+    /// Edges leaving the loop as (inside block, outside block) pairs
+    pub fn exits(&self, cfg: &ControlFlowGraph) -> Vec<(usize, usize)> {
+        let mut exits = Vec::new();
+        for &id in &self.body {
+            if let Some(block) = cfg.blocks.get(&id) {
+                for &succ in &block.successors {
+                    if !self.body.contains(&succ) {
+                        exits.push((id, succ));
+                    }
+                }
+            }
+        }
+        exits
+    }
+}
+
 /// Control Flow Graph
 #[derive(Debug, Clone, Default)]
 pub struct ControlFlowGraph {
@@ -221,13 +250,141 @@ impl ControlFlowGraph {
 
     /// Check if this is a loop header
     pub fn is_loop_header(&self, block_id: usize) -> bool {
-        if let Some(block) = self.blocks.get(&block_id) {
-            // A block is a loop header if any predecessor has a higher block ID
-            // (back edge from later block)
-            block.predecessors.iter().any(|&pred| pred > block_id)
-        } else {
-            false
+        let dominators = self.dominators();
+        self.blocks.get(&block_id).is_some_and(|block| {
+            block
+                .predecessors
+                .iter()
+                .any(|pred| Self::dominates(&dominators, block_id, *pred))
+        })
+    }
+
+    /// Compute the dominator set of every block reachable from the entry
+    pub fn dominators(&self) -> HashMap<usize, HashSet<usize>> {
+        let order = self.blocks_topo_order();
+        let all: HashSet<usize> = order.iter().copied().collect();
+
+        let mut doms: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for &id in &order {
+            if id == self.entry {
+                doms.insert(id, HashSet::from([id]));
+            } else {
+                doms.insert(id, all.clone());
+            }
+        }
+
+        // Iterate to a fixed point: dom(b) = {b} ∪ ⋂ dom(p) over reachable preds
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &id in &order {
+                if id == self.entry {
+                    continue;
+                }
+                let Some(block) = self.blocks.get(&id) else {
+                    continue;
+                };
+                let mut new_set: Option<HashSet<usize>> = None;
+                for pred in &block.predecessors {
+                    if let Some(pred_doms) = doms.get(pred) {
+                        new_set = Some(match new_set {
+                            None => pred_doms.clone(),
+                            Some(acc) => acc.intersection(pred_doms).copied().collect(),
+                        });
+                    }
+                }
+                let mut new_set = new_set.unwrap_or_default();
+                new_set.insert(id);
+                if doms.get(&id) != Some(&new_set) {
+                    doms.insert(id, new_set);
+                    changed = true;
+                }
+            }
         }
+
+        doms
+    }
+
+    /// Does block `a` dominate block `b`?
+    fn dominates(dominators: &HashMap<usize, HashSet<usize>>, a: usize, b: usize) -> bool {
+        dominators.get(&b).is_some_and(|set| set.contains(&a))
+    }
+
+    /// Find the natural loops of the graph (back edges to a dominating header).
+    /// Loops sharing a header are merged. Sorted by header ID.
+    pub fn natural_loops(&self) -> Vec<NaturalLoop> {
+        let dominators = self.dominators();
+        let mut loops: HashMap<usize, NaturalLoop> = HashMap::new();
+
+        let mut ids: Vec<usize> = dominators.keys().copied().collect();
+        ids.sort();
+        for tail in ids {
+            let Some(block) = self.blocks.get(&tail) else {
+                continue;
+            };
+            for &header in &block.successors {
+                if !Self::dominates(&dominators, header, tail) {
+                    continue;
+                }
+
+                let entry = loops.entry(header).or_insert_with(|| NaturalLoop {
+                    header,
+                    latches: Vec::new(),
+                    body: BTreeSet::from([header]),
+                });
+                entry.latches.push(tail);
+
+                // Walk predecessors backwards from the latch until the header
+                let mut stack = vec![tail];
+                while let Some(id) = stack.pop() {
+                    if entry.body.insert(id) {
+                        if let Some(b) = self.blocks.get(&id) {
+                            stack.extend(b.predecessors.iter().copied());
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut result: Vec<NaturalLoop> = loops.into_values().collect();
+        result.sort_by_key(|l| l.header);
+        result
+    }
+
+    /// A graph is reducible when every retreating edge found by a depth-first
+    /// walk from the entry is a back edge to a dominating header
+    pub fn is_reducible(&self) -> bool {
+        let dominators = self.dominators();
+        let mut on_stack: HashSet<usize> = HashSet::new();
+        let mut visited: HashSet<usize> = HashSet::new();
+
+        fn dfs(
+            cfg: &ControlFlowGraph,
+            id: usize,
+            dominators: &HashMap<usize, HashSet<usize>>,
+            visited: &mut HashSet<usize>,
+            on_stack: &mut HashSet<usize>,
+        ) -> bool {
+            visited.insert(id);
+            on_stack.insert(id);
+            if let Some(block) = cfg.blocks.get(&id) {
+                for &succ in &block.successors {
+                    if on_stack.contains(&succ) {
+                        if !ControlFlowGraph::dominates(dominators, succ, id) {
+                            return false;
+                        }
+                    } else if !visited.contains(&succ)
+                        && !dfs(cfg, succ, dominators, visited, on_stack)
+                    {
+                        return false;
+                    }
+                }
+            }
+            on_stack.remove(&id);
+            true
+        }
+
+        self.blocks.is_empty() || dfs(self, self.entry, &dominators, &mut visited, &mut on_stack)
     }
 
     /// Get the loop body for a header
@@ -262,6 +419,46 @@ mod tests {
     use super::*;
     use crate::decompiler::disassembler::Disassembler;
 
+    fn instr(idx: usize, opcode: u8, dst: u8, src: u8, off: i16, imm: i32) -> DisassembledInstr {
+        DisassembledInstr {
+            offset: idx * 8,
+            opcode,
+            dst,
+            src,
+            off,
+            imm,
+            imm64: imm as i64,
+            mnemonic: String::new(),
+            operands: String::new(),
+        }
+    }
+
+    /// r7 = sum of 0..10, counted in r6
+    fn counted_loop() -> Vec<DisassembledInstr> {
+        vec![
+            instr(0, 0xb7, 6, 0, 0, 0),  // mov64 r6, 0
+            instr(1, 0xb7, 7, 0, 0, 0),  // mov64 r7, 0
+            instr(2, 0x35, 6, 0, 3, 10), // jge r6, 10, +3
+            instr(3, 0x0f, 7, 6, 0, 0),  // add64 r7, r6
+            instr(4, 0x07, 6, 0, 0, 1),  // add64 r6, 1
+            instr(5, 0x05, 0, 0, -4, 0), // ja -4
+            instr(6, 0xbf, 0, 7, 0, 0),  // mov64 r0, r7
+            instr(7, 0x95, 0, 0, 0, 0),  // exit
+        ]
+    }
+
+    /// A cycle between two blocks that can each be entered from the entry
+    fn irreducible() -> Vec<DisassembledInstr> {
+        vec![
+            instr(0, 0x15, 1, 0, 2, 0),  // jeq r1, 0, +2
+            instr(1, 0xb7, 0, 0, 0, 1),  // mov64 r0, 1
+            instr(2, 0x15, 0, 0, 2, 5),  // jeq r0, 5, +2
+            instr(3, 0xb7, 0, 0, 0, 2),  // mov64 r0, 2
+            instr(4, 0x05, 0, 0, -4, 0), // ja -4
+            instr(5, 0x95, 0, 0, 0, 0),  // exit
+        ]
+    }
+
     #[test]
     fn test_empty_cfg() {
         let cfg = ControlFlowGraph::build(&[]);
@@ -287,6 +484,7 @@ mod tests {
                 src: 0,
                 off: 0,
                 imm: 42,
+                imm64: 42,
                 mnemonic: "mov64".into(),
                 operands: "r0, 42".into(),
             },
@@ -297,6 +495,7 @@ mod tests {
                 src: 0,
                 off: 0,
                 imm: 0,
+                imm64: 0,
                 mnemonic: "exit".into(),
                 operands: String::new(),
             },
@@ -308,4 +507,32 @@ mod tests {
         assert_eq!(cfg.blocks.len(), 1);
         assert_eq!(cfg.exits.len(), 1);
     }
+
+    #[test]
+    fn test_natural_loop_detection() {
+        let cfg = ControlFlowGraph::build(&counted_loop());
+        assert!(cfg.is_reducible());
+
+        let loops = cfg.natural_loops();
+        assert_eq!(loops.len(), 1);
+        let header = cfg.offset_to_block[&16];
+        let body = cfg.offset_to_block[&24];
+        assert_eq!(loops[0].header, header);
+        assert_eq!(loops[0].latches, vec![body]);
+        assert_eq!(loops[0].body, BTreeSet::from([header, body]));
+        assert!(cfg.is_loop_header(header));
+        assert!(!cfg.is_loop_header(body));
+
+        let exit = cfg.offset_to_block[&48];
+        assert_eq!(loops[0].exits(&cfg), vec![(header, exit)]);
+        assert!(cfg.dominators()[&exit].contains(&header));
+    }
+
+    #[test]
+    fn test_irreducible_cfg() {
+        let cfg = ControlFlowGraph::build(&irreducible());
+        assert!(!cfg.is_reducible());
+        // Neither block of the cycle dominates the other, so there is no natural loop
+        assert!(cfg.natural_loops().is_empty());
+    }
 }
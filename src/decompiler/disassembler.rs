@@ -19,6 +19,8 @@ pub struct DisassembledInstr {
     pub off: i16,
     /// Immediate value
     pub imm: i32,
+    /// Full 64-bit immediate (lddw spans two slots; otherwise `imm` sign-extended)
+    pub imm64: i64,
     /// Human-readable mnemonic
     pub mnemonic: String,
     /// Operand string
@@ -37,22 +39,10 @@ impl DisassembledInstr {
 
     /// Check if this is a jump instruction
     pub fn is_jump(&self) -> bool {
-        matches!(
-            self.opcode,
-            0x05 | 0x15
-                | 0x1d
-                | 0x25
-                | 0x2d
-                | 0x35
-                | 0x3d
-                | 0x45
-                | 0x55
-                | 0x5d
-                | 0xa5
-                | 0xad
-                | 0xb5
-                | 0xbd
-        )
+        // JMP class, excluding call/callx (0x80) and exit (0x90)
+        self.opcode == 0x05
+            || (self.opcode & 0x07 == 0x05
+                && matches!(self.opcode & 0xf0, 0x10..=0x70 | 0xa0..=0xd0))
     }
 
     /// Check if this is a return/exit
@@ -200,8 +190,14 @@ impl Disassembler {
         let src = (dst_src >> 4) & 0x0f;
         let off = i16::from_le_bytes([bytes[2], bytes[3]]);
         let imm = i32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        let imm64 = if opcode == 0x18 && bytes.len() >= 16 {
+            let hi = u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]);
+            ((hi as u64) << 32 | imm as u32 as u64) as i64
+        } else {
+            imm as i64
+        };
 
-        let (mnemonic, operands) = self.format_instruction(opcode, dst, src, off, imm);
+        let (mnemonic, operands) = self.format_instruction(opcode, dst, src, off, imm, imm64);
 
         Ok(DisassembledInstr {
             offset,
@@ -210,6 +206,7 @@ impl Disassembler {
             src,
             off,
             imm,
+            imm64,
             mnemonic,
             operands,
         })
@@ -223,139 +220,114 @@ impl Disassembler {
         src: u8,
         off: i16,
         imm: i32,
+        imm64: i64,
     ) -> (String, String) {
         let reg_name = |r: u8| format!("r{}", r);
+        // BPF_X: the second operand is `src` rather than `imm`
+        let operand = if opcode & 0x08 != 0 {
+            reg_name(src)
+        } else {
+            imm.to_string()
+        };
+        let unknown = || {
+            (
+                format!("unknown_{:02x}", opcode),
+                format!("{} {} {} {}", dst, src, off, imm),
+            )
+        };
 
         match opcode {
-            // ALU64 immediate
-            0x07 => ("add64".into(), format!("{}, {}", reg_name(dst), imm)),
-            0x17 => ("sub64".into(), format!("{}, {}", reg_name(dst), imm)),
-            0x27 => ("mul64".into(), format!("{}, {}", reg_name(dst), imm)),
-            0x37 => ("div64".into(), format!("{}, {}", reg_name(dst), imm)),
-            0x47 => ("or64".into(), format!("{}, {}", reg_name(dst), imm)),
-            0x57 => ("and64".into(), format!("{}, {}", reg_name(dst), imm)),
-            0x97 => ("mod64".into(), format!("{}, {}", reg_name(dst), imm)),
-            0xa7 => ("xor64".into(), format!("{}, {}", reg_name(dst), imm)),
-            0xb7 => ("mov64".into(), format!("{}, {}", reg_name(dst), imm)),
-
-            // ALU64 register
-            0x0f => (
-                "add64".into(),
-                format!("{}, {}", reg_name(dst), reg_name(src)),
-            ),
-            0x1f => (
-                "sub64".into(),
-                format!("{}, {}", reg_name(dst), reg_name(src)),
-            ),
-            0x2f => (
-                "mul64".into(),
-                format!("{}, {}", reg_name(dst), reg_name(src)),
-            ),
-            0x3f => (
-                "div64".into(),
-                format!("{}, {}", reg_name(dst), reg_name(src)),
-            ),
-            0x4f => (
-                "or64".into(),
-                format!("{}, {}", reg_name(dst), reg_name(src)),
-            ),
-            0x5f => (
-                "and64".into(),
-                format!("{}, {}", reg_name(dst), reg_name(src)),
+            0x18 => (
+                "lddw".into(),
+                format!("{}, {}", reg_name(dst), imm64 as u64),
             ),
-            0x9f => (
-                "mod64".into(),
-                format!("{}, {}", reg_name(dst), reg_name(src)),
-            ),
-            0xaf => (
-                "xor64".into(),
-                format!("{}, {}", reg_name(dst), reg_name(src)),
-            ),
-            0xbf => (
-                "mov64".into(),
-                format!("{}, {}", reg_name(dst), reg_name(src)),
-            ),
-            0x87 => ("neg64".into(), reg_name(dst)),
 
-            // Memory
-            0x79 => (
-                "ldxdw".into(),
-                format!("{}, [{}+{}]", reg_name(dst), reg_name(src), off),
-            ),
-            0x7b => (
-                "stxdw".into(),
-                format!("[{}+{}], {}", reg_name(dst), off, reg_name(src)),
-            ),
-            0x18 => ("lddw".into(), format!("{}, {}", reg_name(dst), imm as u64)),
+            // Byte swaps: `imm` is the width in bits
+            0xd4 | 0xdc => {
+                let order = if opcode == 0xd4 { "le" } else { "be" };
+                (format!("{}{}", order, imm), reg_name(dst))
+            }
 
-            // Jump unconditional
-            0x05 => ("ja".into(), format!("+{}", off)),
+            // Call/Exit
+            0x85 => ("call".into(), format!("{}", imm)),
+            0x8d => ("callx".into(), reg_name(imm as u8)),
+            0x95 => ("exit".into(), String::new()),
 
-            // Jump conditional immediate
-            0x15 => (
-                "jeq".into(),
-                format!("{}, {}, +{}", reg_name(dst), imm, off),
-            ),
-            0x25 => (
-                "jgt".into(),
-                format!("{}, {}, +{}", reg_name(dst), imm, off),
-            ),
-            0x35 => (
-                "jge".into(),
-                format!("{}, {}, +{}", reg_name(dst), imm, off),
-            ),
-            0x45 => (
-                "jset".into(),
-                format!("{}, {}, +{}", reg_name(dst), imm, off),
-            ),
-            0x55 => (
-                "jne".into(),
-                format!("{}, {}, +{}", reg_name(dst), imm, off),
+            // Memory: loads into a register, stores of a register or immediate
+            0x61 | 0x69 | 0x71 | 0x79 => (
+                format!("ldx{}", Self::size_suffix(opcode)),
+                format!("{}, [{}+{}]", reg_name(dst), reg_name(src), off),
             ),
-            0xa5 => (
-                "jlt".into(),
-                format!("{}, {}, +{}", reg_name(dst), imm, off),
+            0x62 | 0x6a | 0x72 | 0x7a => (
+                format!("st{}", Self::size_suffix(opcode)),
+                format!("[{}+{}], {}", reg_name(dst), off, imm),
             ),
-            0xb5 => (
-                "jle".into(),
-                format!("{}, {}, +{}", reg_name(dst), imm, off),
+            0x63 | 0x6b | 0x73 | 0x7b => (
+                format!("stx{}", Self::size_suffix(opcode)),
+                format!("[{}+{}], {}", reg_name(dst), off, reg_name(src)),
             ),
 
-            // Jump conditional register
-            0x1d => (
-                "jeq".into(),
-                format!("{}, {}, +{}", reg_name(dst), reg_name(src), off),
-            ),
-            0x2d => (
-                "jgt".into(),
-                format!("{}, {}, +{}", reg_name(dst), reg_name(src), off),
-            ),
-            0x3d => (
-                "jge".into(),
-                format!("{}, {}, +{}", reg_name(dst), reg_name(src), off),
-            ),
-            0x5d => (
-                "jne".into(),
-                format!("{}, {}, +{}", reg_name(dst), reg_name(src), off),
-            ),
-            0xad => (
-                "jlt".into(),
-                format!("{}, {}, +{}", reg_name(dst), reg_name(src), off),
-            ),
-            0xbd => (
-                "jle".into(),
-                format!("{}, {}, +{}", reg_name(dst), reg_name(src), off),
-            ),
+            // ALU32 / ALU64
+            _ if matches!(opcode & 0x07, 0x04 | 0x07) => {
+                let width = if opcode & 0x07 == 0x07 { 64 } else { 32 };
+                let name = match opcode & 0xf0 {
+                    0x00 => "add",
+                    0x10 => "sub",
+                    0x20 => "mul",
+                    0x30 => "div",
+                    0x40 => "or",
+                    0x50 => "and",
+                    0x60 => "lsh",
+                    0x70 => "rsh",
+                    0x80 if opcode & 0x08 == 0 => {
+                        return (format!("neg{}", width), reg_name(dst));
+                    }
+                    0x90 => "mod",
+                    0xa0 => "xor",
+                    0xb0 => "mov",
+                    0xc0 => "arsh",
+                    _ => return unknown(),
+                };
+                (
+                    format!("{}{}", name, width),
+                    format!("{}, {}", reg_name(dst), operand),
+                )
+            }
 
-            // Call/Exit
-            0x85 => ("call".into(), format!("{}", imm)),
-            0x95 => ("exit".into(), String::new()),
+            // Jumps
+            0x05 => ("ja".into(), format!("+{}", off)),
+            _ if opcode & 0x07 == 0x05 => {
+                let name = match opcode & 0xf0 {
+                    0x10 => "jeq",
+                    0x20 => "jgt",
+                    0x30 => "jge",
+                    0x40 => "jset",
+                    0x50 => "jne",
+                    0x60 => "jsgt",
+                    0x70 => "jsge",
+                    0xa0 => "jlt",
+                    0xb0 => "jle",
+                    0xc0 => "jslt",
+                    0xd0 => "jsle",
+                    _ => return unknown(),
+                };
+                (
+                    name.into(),
+                    format!("{}, {}, +{}", reg_name(dst), operand, off),
+                )
+            }
 
-            // Unknown
-            _ => (
-                format!("unknown_{:02x}", opcode),
-                format!("{} {} {} {}", dst, src, off, imm),
-            ),
+            _ => unknown(),
+        }
+    }
+
+    /// Mnemonic suffix for the access width of a load/store opcode
+    fn size_suffix(opcode: u8) -> &'static str {
+        match opcode & 0x18 {
+            0x00 => "w",
+            0x08 => "h",
+            0x10 => "b",
+            _ => "dw",
         }
     }
 }
@@ -394,4 +366,86 @@ mod tests {
         assert!(instr.is_exit());
         assert!(!instr.is_call());
     }
+
+    #[test]
+    fn test_lddw_full_immediate() {
+        let disasm = Disassembler::new();
+
+        // lddw r1, 0x300000000 (low word in the first slot, high word in the second)
+        let bytes = [
+            0x18, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+            0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        ];
+        let instr = disasm.decode_instruction(&bytes, 0).unwrap();
+
+        assert_eq!(instr.imm, 0);
+        assert_eq!(instr.imm64, 0x3_0000_0000);
+        assert_eq!(instr.to_asm(), "lddw r1, 12884901888");
+    }
+
+    #[test]
+    fn test_every_opcode_class_decodes() {
+        let mut opcodes = Vec::new();
+        // ALU32 (0x04) and ALU64 (0x07), immediate and register forms
+        for class in [0x04u8, 0x07] {
+            for op in [
+                0x00u8, 0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x90, 0xa0, 0xb0, 0xc0,
+            ] {
+                opcodes.push(op | class);
+                opcodes.push(op | class | 0x08);
+            }
+            opcodes.push(0x80 | class); // neg
+        }
+        // Conditional jumps, immediate and register forms
+        for op in [
+            0x10u8, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0xa0, 0xb0, 0xc0, 0xd0,
+        ] {
+            opcodes.push(op | 0x05);
+            opcodes.push(op | 0x0d);
+        }
+        // Loads and stores of every width, byte swaps, ja, call, callx, exit
+        opcodes.extend([
+            0x61, 0x69, 0x71, 0x79, 0x62, 0x6a, 0x72, 0x7a, 0x63, 0x6b, 0x73, 0x7b, 0xd4, 0xdc,
+            0x05, 0x85, 0x8d, 0x95,
+        ]);
+
+        // No section headers: the code follows a bare 64-byte ELF header
+        let mut elf = vec![0u8; 64];
+        for &opcode in &opcodes {
+            elf.extend_from_slice(&[opcode, 0x21, 0x04, 0x00, 0x10, 0x00, 0x00, 0x00]);
+        }
+        // lddw r1, 5
+        elf.extend_from_slice(&[0x18, 0x01, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let instructions = Disassembler::new().disassemble(&elf).unwrap();
+        assert_eq!(instructions.len(), opcodes.len() + 1);
+        for instr in &instructions {
+            assert!(
+                !instr.mnemonic.starts_with("unknown"),
+                "opcode {:#04x} decoded as {}",
+                instr.opcode,
+                instr.to_asm()
+            );
+        }
+
+        let asm: Vec<String> = instructions.iter().map(|i| i.to_asm()).collect();
+        for expected in [
+            "add32 r1, 16",
+            "arsh64 r1, r2",
+            "neg32 r1",
+            "jsle r1, r2, +4",
+            "jset r1, 16, +4",
+            "ldxh r1, [r2+4]",
+            "stb [r1+4], 16",
+            "stxw [r1+4], r2",
+            "be16 r1",
+            "lddw r1, 5",
+        ] {
+            assert!(asm.iter().any(|a| a == expected), "missing {}", expected);
+        }
+        assert!(instructions
+            .iter()
+            .filter(|i| i.opcode & 0x07 == 0x05 && !matches!(i.opcode, 0x85 | 0x8d | 0x95))
+            .all(|i| i.is_jump()));
+    }
 }
@@ -9,6 +9,7 @@
 //! - Recover control flow graphs
 //! - Generate readable OVSM LISP
 //! - Use Anchor IDL for semantic naming
+//! - Verify fidelity by recompiling the output (`decompile_verified`)
 //!
 //! ## Usage
 //!
@@ -25,12 +26,15 @@ pub mod cfg;
 pub mod disassembler;
 pub mod idl;
 pub mod ovsm_emitter;
+pub mod roundtrip;
 
 pub use cfg::{BasicBlock, ControlFlowGraph};
 pub use disassembler::{DisassembledInstr, Disassembler};
 pub use idl::{AnchorIdl, IdlAccount, IdlInstruction};
 pub use ovsm_emitter::OvsmEmitter;
+pub use roundtrip::{Divergence, RoundTripReport};
 
+use crate::compiler::{CompileOptions, Compiler};
 use crate::{Error, Result};
 
 /// Decompiler options
@@ -59,6 +63,8 @@ pub struct DecompileResult {
     pub idl: Option<AnchorIdl>,
     /// Warnings during decompilation
     pub warnings: Vec<String>,
+    /// Recompilation comparison (only from `decompile_verified`)
+    pub roundtrip: Option<RoundTripReport>,
 }
 
 /// sBPF to OVSM Decompiler
@@ -113,9 +119,36 @@ impl Decompiler {
             cfg,
             idl,
             warnings,
+            roundtrip: None,
         })
     }
 
+    /// Decompile, then recompile the emitted source with `compile_options`
+    /// and diff the resulting text section against the input.
+    ///
+    /// Divergent instruction ranges are reported in `roundtrip` and
+    /// summarized in `warnings`; a recompilation failure is reported the
+    /// same way rather than as an error.
+    pub fn decompile_verified(
+        &self,
+        elf_bytes: &[u8],
+        compile_options: CompileOptions,
+    ) -> Result<DecompileResult> {
+        let mut result = self.decompile(elf_bytes)?;
+
+        let report = match Compiler::new(compile_options).compile(&result.source) {
+            Ok(compiled) => match Disassembler::new().disassemble(&compiled.elf_bytes) {
+                Ok(recompiled) => RoundTripReport::compare(&result.instructions, &recompiled),
+                Err(e) => RoundTripReport::failed(result.instructions.len(), e.to_string()),
+            },
+            Err(e) => RoundTripReport::failed(result.instructions.len(), e.to_string()),
+        };
+
+        result.warnings.extend(report.warnings());
+        result.roundtrip = Some(report);
+        Ok(result)
+    }
+
     /// Decompile from file path
     pub fn decompile_file(&self, path: &str) -> Result<DecompileResult> {
         let elf_bytes = std::fs::read(path)
@@ -3,12 +3,256 @@
 //! Generates readable OVSM LISP code from disassembled sBPF instructions.
 
 use super::{
-    cfg::{BasicBlock, ControlFlowGraph},
-    idl::AnchorIdl,
+    cfg::{BasicBlock, ControlFlowGraph, NaturalLoop},
+    idl::{AnchorIdl, IdlInstruction},
     DecompileOptions, DisassembledInstr,
 };
 use crate::{Error, Result};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// How a basic block is laid out by `emit_block`
+struct BlockStyle<'s> {
+    /// Nesting depth inside loop forms
+    depth: usize,
+    /// Instruction indices already represented by an enclosing form
+    skip: &'s HashSet<usize>,
+    /// Emit block labels and explicit gotos (irreducible fallback)
+    labeled: bool,
+    /// Mark the block as a loop header
+    loop_header: bool,
+}
+
+/// Comparison performed by a conditional jump
+struct BranchCondition {
+    op: &'static str,
+    lhs: String,
+    rhs: String,
+}
+
+impl BranchCondition {
+    fn render(&self) -> String {
+        format!("({} {} {})", self.op, self.lhs, self.rhs)
+    }
+
+    /// The opposite comparison, taken when the jump falls through
+    fn negate(&self) -> BranchCondition {
+        let op = match self.op {
+            "=" => "!=",
+            "!=" => "=",
+            ">" => "<=",
+            ">=" => "<",
+            "<" => ">=",
+            _ => ">",
+        };
+        BranchCondition {
+            op,
+            lhs: self.lhs.clone(),
+            rhs: self.rhs.clone(),
+        }
+    }
+}
+
+/// Loop continuation test recovered from the loop's exit branch
+struct LoopTest {
+    /// Block ending with the exit branch
+    exit_block: usize,
+    /// Instruction index of the exit branch
+    jump_idx: usize,
+    /// Condition under which the loop keeps iterating
+    condition: String,
+    /// Whether taking the branch stays inside the loop
+    stay_when_taken: bool,
+}
+
+/// Base address of the account offset table the compiler builds in the
+/// entrypoint (one 8-byte input offset per account, see `emit_get_account_offset`)
+const ACCOUNT_TABLE_BASE: i64 = 0x3_0000_0000;
+
+/// Upper bound on account indices recognized in the offset table
+const MAX_ACCOUNTS: i64 = 256;
+
+/// Whole-program facts shared by every emitted block
+struct EmitContext<'c> {
+    cfg: &'c ControlFlowGraph,
+    instructions: &'c [DisassembledInstr],
+    /// Natural loops of the CFG
+    loops: Vec<NaturalLoop>,
+    /// Register holding the program input pointer (`mov64 rX, r1` at entry)
+    input_reg: Option<u8>,
+    /// Discriminator compares (block, instruction index, matched IDL
+    /// instruction) in address order
+    dispatch: Vec<(usize, usize, &'c IdlInstruction)>,
+    /// Handler assumed when no discriminator compare precedes a block
+    default_handler: Option<&'c IdlInstruction>,
+}
+
+impl<'c> EmitContext<'c> {
+    /// Symbolic registers on entry to a block
+    fn block_regs(&self, block_id: usize) -> RegState {
+        let mut input = Vec::new();
+        if block_id == self.cfg.entry {
+            input.push(1);
+        }
+        input.extend(self.input_reg);
+        RegState::new(&input)
+    }
+
+    /// IDL instruction whose handler a block belongs to: the closest
+    /// discriminator compare at or before it in address order
+    fn handler_for(&self, block_id: usize) -> Option<&'c IdlInstruction> {
+        self.dispatch
+            .iter()
+            .rev()
+            .find(|(b, _, _)| *b <= block_id)
+            .map(|(_, _, ix)| *ix)
+            .or(self.default_handler)
+    }
+}
+
+/// Symbolic register contents, tracked through a basic block to recognize
+/// account accesses
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RegValue {
+    Unknown,
+    Const(i64),
+    /// The program input pointer
+    Input,
+    /// Offset of account `index` within the input, plus `field` bytes
+    AccountOffset {
+        index: usize,
+        field: i64,
+    },
+    /// Pointer to byte `field` of account `index`
+    AccountPtr {
+        index: usize,
+        field: i64,
+    },
+}
+
+impl RegValue {
+    fn add(self, other: RegValue) -> RegValue {
+        use RegValue::*;
+        match (self, other) {
+            (Const(a), Const(b)) => Const(a.wrapping_add(b)),
+            (AccountOffset { index, field }, Const(c))
+            | (Const(c), AccountOffset { index, field }) => AccountOffset {
+                index,
+                field: field + c,
+            },
+            (Input, AccountOffset { index, field }) | (AccountOffset { index, field }, Input) => {
+                AccountPtr { index, field }
+            }
+            (AccountPtr { index, field }, Const(c)) | (Const(c), AccountPtr { index, field }) => {
+                AccountPtr {
+                    index,
+                    field: field + c,
+                }
+            }
+            _ => Unknown,
+        }
+    }
+}
+
+/// Register file of symbolic values, plus the stack slots spilled
+/// registers are saved to
+struct RegState {
+    regs: [RegValue; 11],
+    /// Doubleword stack slots keyed by their offset from the frame pointer
+    stack: HashMap<i16, RegValue>,
+}
+
+impl RegState {
+    fn new(input_regs: &[u8]) -> Self {
+        let mut regs = [RegValue::Unknown; 11];
+        for &r in input_regs {
+            regs[r as usize] = RegValue::Input;
+        }
+        Self {
+            regs,
+            stack: HashMap::new(),
+        }
+    }
+
+    fn get(&self, r: u8) -> RegValue {
+        self.regs
+            .get(r as usize)
+            .copied()
+            .unwrap_or(RegValue::Unknown)
+    }
+
+    fn set(&mut self, r: u8, value: RegValue) {
+        if let Some(slot) = self.regs.get_mut(r as usize) {
+            *slot = value;
+        }
+    }
+
+    /// Symbolic address `[base + off]`
+    fn address(&self, base: u8, off: i16) -> RegValue {
+        self.get(base).add(RegValue::Const(off as i64))
+    }
+
+    /// Apply one instruction's effect on the registers
+    fn step(&mut self, instr: &DisassembledInstr) {
+        let dst = self.get(instr.dst);
+        let value = match instr.opcode {
+            0xb7 => RegValue::Const(instr.imm as i64),
+            0x18 => RegValue::Const(instr.imm64),
+            0xbf => self.get(instr.src),
+            0x07 => dst.add(RegValue::Const(instr.imm as i64)),
+            0x0f => dst.add(self.get(instr.src)),
+            0x27 | 0x2f => {
+                let rhs = if instr.opcode == 0x27 {
+                    RegValue::Const(instr.imm as i64)
+                } else {
+                    self.get(instr.src)
+                };
+                match (dst, rhs) {
+                    (RegValue::Const(a), RegValue::Const(b)) => RegValue::Const(a.wrapping_mul(b)),
+                    _ => RegValue::Unknown,
+                }
+            }
+            // Reloading a spilled register restores its value
+            0x79 if instr.src == 10 => self
+                .stack
+                .get(&instr.off)
+                .copied()
+                .unwrap_or(RegValue::Unknown),
+            // Loading an entry of the account offset table yields that account's offset
+            0x79 => match self.address(instr.src, instr.off) {
+                RegValue::Const(addr)
+                    if (ACCOUNT_TABLE_BASE..ACCOUNT_TABLE_BASE + MAX_ACCOUNTS * 8)
+                        .contains(&addr)
+                        && (addr - ACCOUNT_TABLE_BASE) % 8 == 0 =>
+                {
+                    RegValue::AccountOffset {
+                        index: ((addr - ACCOUNT_TABLE_BASE) / 8) as usize,
+                        field: 0,
+                    }
+                }
+                _ => RegValue::Unknown,
+            },
+            0x7b if instr.dst == 10 => {
+                let value = self.get(instr.src);
+                self.stack.insert(instr.off, value);
+                return;
+            }
+            0x85 => {
+                // Calls clobber the return and argument registers
+                for r in 0..=5 {
+                    self.set(r, RegValue::Unknown);
+                }
+                return;
+            }
+            _ => {
+                if let Some(r) = OvsmEmitter::writes_register(instr) {
+                    self.set(r, RegValue::Unknown);
+                }
+                return;
+            }
+        };
+        self.set(instr.dst, value);
+    }
+}
 
 /// OVSM code emitter
 pub struct OvsmEmitter<'a> {
@@ -55,13 +299,33 @@ impl<'a> OvsmEmitter<'a> {
         // Emit main entrypoint
         output.push_str("  (entrypoint (accounts instruction-data)\n");
 
-        // Process blocks in order
-        let block_order = cfg.blocks_topo_order();
+        let ctx = self.context(cfg, instructions);
 
-        for block_id in block_order {
-            if let Some(block) = cfg.get_block(block_id) {
-                let block_code = self.emit_block(block, instructions, cfg)?;
-                output.push_str(&block_code);
+        if cfg.is_reducible() {
+            // Structured emission: natural loops become while/for forms,
+            // everything else is laid out in address order
+            let mut block_order = cfg.blocks_topo_order();
+            block_order.sort();
+            let region = self.emit_region(&ctx, &block_order)?;
+            output.push_str(&region);
+        } else {
+            // Irreducible control flow has no loop nesting to recover,
+            // so keep explicit jumps between labeled blocks
+            let headers: HashSet<usize> = ctx.loops.iter().map(|l| l.header).collect();
+            for block_id in cfg.blocks_topo_order() {
+                if let Some(block) = cfg.get_block(block_id) {
+                    let block_code = self.emit_block(
+                        &ctx,
+                        block,
+                        &BlockStyle {
+                            depth: 0,
+                            skip: &HashSet::new(),
+                            labeled: true,
+                            loop_header: headers.contains(&block_id),
+                        },
+                    )?;
+                    output.push_str(&block_code);
+                }
             }
         }
 
@@ -70,66 +334,542 @@ impl<'a> OvsmEmitter<'a> {
         Ok(output)
     }
 
+    /// Gather loop, input-pointer and IDL dispatch facts for emission
+    fn context<'c>(
+        &'c self,
+        cfg: &'c ControlFlowGraph,
+        instructions: &'c [DisassembledInstr],
+    ) -> EmitContext<'c> {
+        // The entrypoint copies the input pointer out of r1 into a callee-saved
+        // register; trust it only if nothing else ever writes that register
+        let input_reg = cfg
+            .get_block(cfg.entry)
+            .and_then(|entry| {
+                entry
+                    .instructions
+                    .iter()
+                    .map(|&idx| &instructions[idx])
+                    .find(|i| i.opcode == 0xbf && i.src == 1 && (6..=9).contains(&i.dst))
+            })
+            .map(|i| i.dst)
+            .filter(|&reg| {
+                instructions
+                    .iter()
+                    .filter(|i| Self::writes_register(i) == Some(reg))
+                    .count()
+                    == 1
+            });
+
+        let mut ctx = EmitContext {
+            cfg,
+            instructions,
+            loops: cfg.natural_loops(),
+            input_reg,
+            dispatch: Vec::new(),
+            default_handler: None,
+        };
+
+        let idl = match self.idl {
+            Some(idl) if self.options.use_idl_names => idl,
+            _ => return ctx,
+        };
+        if idl.instructions.len() == 1 {
+            ctx.default_handler = idl.instructions.first();
+        }
+
+        // Equality tests against a constant equal to an 8-byte discriminator
+        let mut block_order = cfg.blocks_topo_order();
+        block_order.sort();
+        for block_id in block_order {
+            let Some(block) = cfg.get_block(block_id) else {
+                continue;
+            };
+            let mut regs = ctx.block_regs(block_id);
+            for &idx in &block.instructions {
+                let instr = &instructions[idx];
+                if matches!(instr.opcode, 0x1d | 0x5d) {
+                    for reg in [instr.dst, instr.src] {
+                        if let RegValue::Const(c) = regs.get(reg) {
+                            if let Some(ix) =
+                                idl.find_instruction_by_discriminator(&c.to_le_bytes())
+                            {
+                                ctx.dispatch.push((block_id, idx, ix));
+                            }
+                        }
+                    }
+                }
+                regs.step(instr);
+            }
+        }
+
+        ctx
+    }
+
+    /// Emit the top-level blocks (in address order), folding every outermost
+    /// natural loop into a loop form
+    fn emit_region(&self, ctx: &EmitContext<'_>, blocks: &[usize]) -> Result<String> {
+        let mut output = String::new();
+        let mut done: HashSet<usize> = HashSet::new();
+        let no_skip = HashSet::new();
+
+        for &block_id in blocks {
+            if done.contains(&block_id) {
+                continue;
+            }
+
+            if let Some(lp) = ctx.loops.iter().find(|l| l.header == block_id) {
+                output.push_str(&self.emit_loop(ctx, lp, 0)?);
+                done.extend(lp.body.iter().copied());
+                continue;
+            }
+
+            if let Some(block) = ctx.cfg.get_block(block_id) {
+                output.push_str(&self.emit_block(
+                    ctx,
+                    block,
+                    &BlockStyle {
+                        depth: 0,
+                        skip: &no_skip,
+                        labeled: false,
+                        loop_header: false,
+                    },
+                )?);
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Emit a natural loop as `(for ...)` when it is a simple counted loop,
+    /// `(while cond ...)` when it has a single conditional exit, and
+    /// `(while true ...)` otherwise
+    fn emit_loop(&self, ctx: &EmitContext<'_>, lp: &NaturalLoop, depth: usize) -> Result<String> {
+        let pad = Self::pad(depth);
+        let body_ids: Vec<usize> = lp.body.iter().copied().collect();
+        let mut output = String::new();
+
+        if self.options.show_addresses {
+            if let Some(header) = ctx.cfg.get_block(lp.header) {
+                output.push_str(&format!(
+                    "{};; Loop at block {} (offset 0x{:x})\n",
+                    pad, lp.header, header.start_offset
+                ));
+            }
+        }
+
+        // The back-edge jumps are implied by the loop form
+        let mut skip: HashSet<usize> = HashSet::new();
+        for latch in &lp.latches {
+            if let Some(&last) = ctx
+                .cfg
+                .get_block(*latch)
+                .and_then(|b| b.instructions.last())
+            {
+                if ctx.instructions.get(last).is_some_and(|i| i.opcode == 0x05) {
+                    skip.insert(last);
+                }
+            }
+        }
+
+        let exits = lp.exits(ctx.cfg);
+        let exit_blocks: BTreeSet<usize> = exits.iter().map(|(from, _)| *from).collect();
+        let test = match exit_blocks.iter().next() {
+            Some(&exit_id) if exit_blocks.len() == 1 => self.loop_test(ctx, exit_id, lp),
+            _ => None,
+        };
+
+        let Some(test) = test else {
+            // Multiple or unconditional exits: keep the body intact and note the exits
+            output.push_str(&format!("{}(while true\n", pad));
+            for (from, to) in &exits {
+                output.push_str(&format!(
+                    "{}  ;; block {} exits loop to block {}\n",
+                    pad, from, to
+                ));
+            }
+            output.push_str(&self.emit_loop_region(ctx, &body_ids, lp, depth + 1, &skip)?);
+            output.push_str(&format!("{})\n", pad));
+            return Ok(output);
+        };
+
+        skip.insert(test.jump_idx);
+        let cond_ids: Vec<usize> = body_ids
+            .iter()
+            .copied()
+            .filter(|&id| id <= test.exit_block)
+            .collect();
+        let rest_ids: Vec<usize> = body_ids
+            .iter()
+            .copied()
+            .filter(|&id| id > test.exit_block)
+            .collect();
+
+        // Counted loop: the header only tests `rI < bound` and a single latch bumps rI
+        if cond_ids == [lp.header] && self.block_is_only(ctx, lp.header, test.jump_idx) {
+            if let Some((counter, bound, step, incr_idx)) = self.counted_loop(ctx, lp, &test) {
+                skip.insert(incr_idx);
+                let range = if step == 1 {
+                    format!("(range {} {})", counter, bound)
+                } else {
+                    format!("(range {} {} {})", counter, bound, step)
+                };
+                output.push_str(&format!("{}(for ({} {})\n", pad, counter, range));
+                output.push_str(&self.emit_loop_region(ctx, &rest_ids, lp, depth + 1, &skip)?);
+                output.push_str(&format!("{})\n", pad));
+                return Ok(output);
+            }
+        }
+
+        let cond_code = self.emit_loop_region(ctx, &cond_ids, lp, depth + 2, &skip)?;
+        if cond_code.is_empty() {
+            output.push_str(&format!("{}(while {}\n", pad, test.condition));
+        } else {
+            // Statements recomputed before every test go into the condition
+            output.push_str(&format!("{}(while (do\n", pad));
+            output.push_str(&cond_code);
+            output.push_str(&format!("{}    {})\n", pad, test.condition));
+        }
+        output.push_str(&self.emit_loop_region(ctx, &rest_ids, lp, depth + 1, &skip)?);
+        output.push_str(&format!("{})\n", pad));
+        Ok(output)
+    }
+
+    /// Emit blocks belonging to a loop, skipping consumed branch instructions
+    fn emit_loop_region(
+        &self,
+        ctx: &EmitContext<'_>,
+        blocks: &[usize],
+        lp: &NaturalLoop,
+        depth: usize,
+        skip: &HashSet<usize>,
+    ) -> Result<String> {
+        let mut output = String::new();
+        let mut done: HashSet<usize> = HashSet::new();
+
+        for &block_id in blocks {
+            if done.contains(&block_id) {
+                continue;
+            }
+
+            // Inner loops nest inside this one
+            if let Some(inner) = ctx
+                .loops
+                .iter()
+                .find(|l| l.header == block_id && l.header != lp.header)
+            {
+                output.push_str(&self.emit_loop(ctx, inner, depth)?);
+                done.extend(inner.body.iter().copied());
+                continue;
+            }
+
+            if let Some(block) = ctx.cfg.get_block(block_id) {
+                output.push_str(&self.emit_block(
+                    ctx,
+                    block,
+                    &BlockStyle {
+                        depth,
+                        skip,
+                        labeled: false,
+                        loop_header: false,
+                    },
+                )?);
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Work out the continuation test of a loop whose only exit is the
+    /// conditional jump ending `exit_block`
+    fn loop_test(
+        &self,
+        ctx: &EmitContext<'_>,
+        exit_block: usize,
+        lp: &NaturalLoop,
+    ) -> Option<LoopTest> {
+        let block = ctx.cfg.get_block(exit_block)?;
+        let jump_idx = *block.instructions.last()?;
+        let jump = ctx.instructions.get(jump_idx)?;
+        if !jump.is_jump() || jump.opcode == 0x05 {
+            return None;
+        }
+
+        let branch = Self::branch_condition(jump)?;
+        // Successor 0 is the jump target, successor 1 the fall-through
+        let target = *block.successors.first()?;
+        let (condition, stay_when_taken) = if lp.body.contains(&target) {
+            (branch.render(), true)
+        } else {
+            (branch.negate().render(), false)
+        };
+
+        Some(LoopTest {
+            exit_block,
+            jump_idx,
+            condition,
+            stay_when_taken,
+        })
+    }
+
+    /// Recognize `rI < bound` loops whose single latch adds a positive
+    /// constant to rI, which is otherwise left alone by the loop
+    fn counted_loop(
+        &self,
+        ctx: &EmitContext<'_>,
+        lp: &NaturalLoop,
+        test: &LoopTest,
+    ) -> Option<(String, String, i32, usize)> {
+        let instructions = ctx.instructions;
+        let jump = &instructions[test.jump_idx];
+        // Staying in the loop must mean rI < bound
+        let stays_below = match jump.opcode {
+            0xa5 | 0xad => test.stay_when_taken,  // jlt
+            0x35 | 0x3d => !test.stay_when_taken, // jge
+            _ => false,
+        };
+        if !stays_below || lp.latches.len() != 1 {
+            return None;
+        }
+
+        let counter = jump.dst;
+        let bound_reg = if jump.opcode & 0x08 != 0 {
+            Some(jump.src)
+        } else {
+            None
+        };
+
+        let latch = ctx.cfg.get_block(lp.latches[0])?;
+        let incr_idx = latch.instructions.iter().rev().copied().find(|&idx| {
+            let i = &instructions[idx];
+            i.opcode == 0x07 && i.dst == counter && i.imm > 0
+        })?;
+
+        // Neither the counter nor a bound register may be written elsewhere
+        for id in &lp.body {
+            for &idx in &ctx.cfg.get_block(*id)?.instructions {
+                if idx == incr_idx {
+                    continue;
+                }
+                let i = &instructions[idx];
+                let writes = Self::writes_register(i);
+                if writes == Some(counter) || (bound_reg.is_some() && writes == bound_reg) {
+                    return None;
+                }
+            }
+        }
+
+        let bound = match bound_reg {
+            Some(r) => Self::reg_name(r),
+            None => jump.imm.to_string(),
+        };
+        Some((
+            Self::reg_name(counter),
+            bound,
+            instructions[incr_idx].imm,
+            incr_idx,
+        ))
+    }
+
+    /// Whether `block_id` consists of nothing but the instruction `only`
+    fn block_is_only(&self, ctx: &EmitContext<'_>, block_id: usize, only: usize) -> bool {
+        ctx.cfg
+            .get_block(block_id)
+            .is_some_and(|b| b.instructions.as_slice() == [only])
+    }
+
+    /// Destination register written by an ALU, move or load instruction
+    fn writes_register(instr: &DisassembledInstr) -> Option<u8> {
+        match instr.opcode & 0x07 {
+            // LD, LDX, ALU32, ALU64
+            0x00 | 0x01 | 0x04 | 0x07 => Some(instr.dst),
+            _ => None,
+        }
+    }
+
+    /// Whitespace prefix for an emitted line at the given nesting depth
+    fn pad(depth: usize) -> String {
+        format!("      {}", "  ".repeat(depth))
+    }
+
     fn emit_block(
         &self,
+        ctx: &EmitContext<'_>,
         block: &BasicBlock,
-        instructions: &[DisassembledInstr],
-        cfg: &ControlFlowGraph,
+        style: &BlockStyle<'_>,
     ) -> Result<String> {
+        let instructions = ctx.instructions;
         let mut output = String::new();
-        let indent = "    ";
+        let pad = Self::pad(style.depth);
 
         // Block label comment
-        if self.options.show_addresses {
+        if style.labeled {
+            let label = block
+                .label
+                .clone()
+                .unwrap_or_else(|| format!("block_{}", block.id));
+            output.push_str(&format!("{};; {}:\n", pad, label));
+        } else if self.options.show_addresses {
             output.push_str(&format!(
-                "{}  ;; Block {} (offset 0x{:x})\n",
-                indent, block.id, block.start_offset
+                "{};; Block {} (offset 0x{:x})\n",
+                pad, block.id, block.start_offset
             ));
         }
 
-        // Check if this is a loop header
-        if cfg.is_loop_header(block.id) {
-            output.push_str(&format!("{}  ;; Loop header\n", indent));
+        if style.loop_header {
+            output.push_str(&format!("{};; Loop header\n", pad));
         }
 
+        let handler = ctx.handler_for(block.id);
+        let mut regs = ctx.block_regs(block.id);
+
         // Emit instructions
         for &instr_idx in &block.instructions {
             if instr_idx >= instructions.len() {
                 continue;
             }
-
             let instr = &instructions[instr_idx];
-            let ovsm = self.emit_instruction(instr)?;
+            let account_access = self.emit_account_access(instr, &regs, handler);
+            regs.step(instr);
+            if style.skip.contains(&instr_idx) {
+                continue;
+            }
+
+            if let Some((_, _, ix)) = ctx.dispatch.iter().find(|(_, i, _)| *i == instr_idx) {
+                output.push_str(&format!("{};; dispatch: {}\n", pad, ix.name));
+            }
+
+            let ovsm = if let Some(access) = account_access {
+                access
+            } else if style.labeled && instr.is_jump() {
+                // Labeled mode: jumps name their target block
+                let target = block.successors.first().map(|t| format!("block_{}", t));
+                match (target, Self::branch_condition(instr)) {
+                    (Some(t), Some(cond)) => format!("(if {} (goto {}))", cond.render(), t),
+                    (Some(t), None) => format!("(goto {})", t),
+                    (None, _) => self.emit_instruction(instr)?,
+                }
+            } else {
+                self.emit_instruction(instr)?
+            };
 
             if !ovsm.is_empty() {
                 if self.options.show_addresses {
                     output.push_str(&format!(
-                        "{}  ;; 0x{:04x}: {}\n",
-                        indent,
+                        "{};; 0x{:04x}: {}\n",
+                        pad,
                         instr.offset,
                         instr.to_asm()
                     ));
                 }
-                output.push_str(&format!("{}  {}\n", indent, ovsm));
+                output.push_str(&format!("{}{}\n", pad, ovsm));
             }
         }
 
         Ok(output)
     }
 
-    fn emit_instruction(&self, instr: &DisassembledInstr) -> Result<String> {
-        let reg_name = |r: u8| -> String {
-            match r {
-                0 => "result".into(),
-                1 => "arg1".into(),
-                2 => "arg2".into(),
-                3 => "arg3".into(),
-                4 => "arg4".into(),
-                5 => "arg5".into(),
-                10 => "frame-ptr".into(),
-                r => format!("r{}", r),
-            }
+    /// Render a load or store through an account pointer with the
+    /// matching account accessor
+    fn emit_account_access(
+        &self,
+        instr: &DisassembledInstr,
+        regs: &RegState,
+        handler: Option<&IdlInstruction>,
+    ) -> Option<String> {
+        let is_load = matches!(instr.opcode, 0x61 | 0x69 | 0x71 | 0x79);
+        let is_store = matches!(instr.opcode, 0x63 | 0x6b | 0x73 | 0x7b);
+        let base = if is_load {
+            instr.src
+        } else if is_store {
+            instr.dst
+        } else {
+            return None;
+        };
+        let RegValue::AccountPtr { index, field } = regs.address(base, instr.off) else {
+            return None;
         };
+        let account = self.account_name(index, handler);
+
+        if is_load {
+            let value = match (instr.opcode, field) {
+                (0x71, 1) => format!("(account-is-signer {})", account),
+                (0x71, 2) => format!("(account-is-writable {})", account),
+                (0x71, 3) => format!("(account-executable {})", account),
+                (0x79, 72) => format!("(account-lamports {})", account),
+                (0x79, 80) => format!("(account-data-len {})", account),
+                (_, f) if f >= 88 => {
+                    format!("(mem-load (account-data-ptr {}) {})", account, f - 88)
+                }
+                (_, f) => format!("(mem-load {} {})", account, f),
+            };
+            Some(format!("(define {} {})", Self::reg_name(instr.dst), value))
+        } else {
+            let src = Self::reg_name(instr.src);
+            Some(match field {
+                72 if instr.opcode == 0x7b => format!("(set-lamports {} {})", account, src),
+                f if f >= 88 => format!(
+                    "(mem-store (account-data-ptr {}) {} {})",
+                    account,
+                    f - 88,
+                    src
+                ),
+                f => format!("(mem-store {} {} {})", account, f, src),
+            })
+        }
+    }
+
+    /// Name of account `index`: the IDL account name of the handling
+    /// instruction when available, `account[index]` otherwise
+    fn account_name(&self, index: usize, handler: Option<&IdlInstruction>) -> String {
+        handler
+            .filter(|_| self.options.use_idl_names)
+            .and_then(|ix| ix.accounts.get(index))
+            .map(|acct| acct.name.clone())
+            .unwrap_or_else(|| format!("account[{}]", index))
+    }
+
+    /// Readable name for an sBPF register
+    fn reg_name(r: u8) -> String {
+        match r {
+            0 => "result".into(),
+            1 => "arg1".into(),
+            2 => "arg2".into(),
+            3 => "arg3".into(),
+            4 => "arg4".into(),
+            5 => "arg5".into(),
+            10 => "frame-ptr".into(),
+            r => format!("r{}", r),
+        }
+    }
+
+    /// Comparison tested by a conditional jump (None for `ja` and unknown opcodes)
+    fn branch_condition(instr: &DisassembledInstr) -> Option<BranchCondition> {
+        let op = match instr.opcode & 0xf0 {
+            0x10 => "=",
+            0x50 => "!=",
+            0x20 => ">",
+            0x30 => ">=",
+            0xa0 => "<",
+            0xb0 => "<=",
+            _ => return None,
+        };
+        if instr.opcode & 0x07 != 0x05 {
+            return None;
+        }
+        let rhs = if instr.opcode & 0x08 != 0 {
+            Self::reg_name(instr.src)
+        } else {
+            instr.imm.to_string()
+        };
+        Some(BranchCondition {
+            op,
+            lhs: Self::reg_name(instr.dst),
+            rhs,
+        })
+    }
+
+    fn emit_instruction(&self, instr: &DisassembledInstr) -> Result<String> {
+        let reg_name = Self::reg_name;
 
         match instr.opcode {
             // MOV immediate
@@ -242,6 +982,12 @@ impl<'a> OvsmEmitter<'a> {
                 Ok(format!("(set! {} (- 0 {}))", dst, dst))
             }
 
+            // Load 64-bit immediate
+            0x18 => {
+                let dst = reg_name(instr.dst);
+                Ok(format!("(define {} {})", dst, instr.imm64))
+            }
+
             // Load double-word
             0x79 => {
                 let dst = reg_name(instr.dst);
@@ -268,71 +1014,11 @@ impl<'a> OvsmEmitter<'a> {
             0x05 => Ok(format!(";; jump +{}", instr.off)),
 
             // Conditional jumps
-            0x15 => {
-                // jeq imm
-                let reg = reg_name(instr.dst);
-                Ok(format!("(if (= {} {}) ...)", reg, instr.imm))
-            }
-            0x1d => {
-                // jeq reg
-                let r1 = reg_name(instr.dst);
-                let r2 = reg_name(instr.src);
-                Ok(format!("(if (= {} {}) ...)", r1, r2))
-            }
-            0x55 => {
-                // jne imm
-                let reg = reg_name(instr.dst);
-                Ok(format!("(if (!= {} {}) ...)", reg, instr.imm))
-            }
-            0x5d => {
-                // jne reg
-                let r1 = reg_name(instr.dst);
-                let r2 = reg_name(instr.src);
-                Ok(format!("(if (!= {} {}) ...)", r1, r2))
-            }
-            0x25 => {
-                // jgt imm
-                let reg = reg_name(instr.dst);
-                Ok(format!("(if (> {} {}) ...)", reg, instr.imm))
-            }
-            0x2d => {
-                // jgt reg
-                let r1 = reg_name(instr.dst);
-                let r2 = reg_name(instr.src);
-                Ok(format!("(if (> {} {}) ...)", r1, r2))
-            }
-            0x35 => {
-                // jge imm
-                let reg = reg_name(instr.dst);
-                Ok(format!("(if (>= {} {}) ...)", reg, instr.imm))
-            }
-            0x3d => {
-                // jge reg
-                let r1 = reg_name(instr.dst);
-                let r2 = reg_name(instr.src);
-                Ok(format!("(if (>= {} {}) ...)", r1, r2))
-            }
-            0xa5 => {
-                // jlt imm
-                let reg = reg_name(instr.dst);
-                Ok(format!("(if (< {} {}) ...)", reg, instr.imm))
-            }
-            0xad => {
-                // jlt reg
-                let r1 = reg_name(instr.dst);
-                let r2 = reg_name(instr.src);
-                Ok(format!("(if (< {} {}) ...)", r1, r2))
-            }
-            0xb5 => {
-                // jle imm
-                let reg = reg_name(instr.dst);
-                Ok(format!("(if (<= {} {}) ...)", reg, instr.imm))
-            }
-            0xbd => {
-                // jle reg
-                let r1 = reg_name(instr.dst);
-                let r2 = reg_name(instr.src);
-                Ok(format!("(if (<= {} {}) ...)", r1, r2))
+            0x15 | 0x1d | 0x55 | 0x5d | 0x25 | 0x2d | 0x35 | 0x3d | 0xa5 | 0xad | 0xb5 | 0xbd => {
+                let cond = Self::branch_condition(instr)
+                    .map(|c| c.render())
+                    .unwrap_or_default();
+                Ok(format!("(if {} ...)", cond))
             }
 
             // Call
@@ -376,6 +1062,46 @@ impl<'a> OvsmEmitter<'a> {
 mod tests {
     use super::*;
 
+    fn instr(idx: usize, opcode: u8, dst: u8, src: u8, off: i16, imm: i32) -> DisassembledInstr {
+        DisassembledInstr {
+            offset: idx * 8,
+            opcode,
+            dst,
+            src,
+            off,
+            imm,
+            imm64: imm as i64,
+            mnemonic: String::new(),
+            operands: String::new(),
+        }
+    }
+
+    /// r7 = sum of 0..10, counted in r6
+    fn counted_loop() -> Vec<DisassembledInstr> {
+        vec![
+            instr(0, 0xb7, 6, 0, 0, 0),  // mov64 r6, 0
+            instr(1, 0xb7, 7, 0, 0, 0),  // mov64 r7, 0
+            instr(2, 0x35, 6, 0, 3, 10), // jge r6, 10, +3
+            instr(3, 0x0f, 7, 6, 0, 0),  // add64 r7, r6
+            instr(4, 0x07, 6, 0, 0, 1),  // add64 r6, 1
+            instr(5, 0x05, 0, 0, -4, 0), // ja -4
+            instr(6, 0xbf, 0, 7, 0, 0),  // mov64 r0, r7
+            instr(7, 0x95, 0, 0, 0, 0),  // exit
+        ]
+    }
+
+    /// A cycle between two blocks that can each be entered from the entry
+    fn irreducible() -> Vec<DisassembledInstr> {
+        vec![
+            instr(0, 0x15, 1, 0, 2, 0),  // jeq r1, 0, +2
+            instr(1, 0xb7, 0, 0, 0, 1),  // mov64 r0, 1
+            instr(2, 0x15, 0, 0, 2, 5),  // jeq r0, 5, +2
+            instr(3, 0xb7, 0, 0, 0, 2),  // mov64 r0, 2
+            instr(4, 0x05, 0, 0, -4, 0), // ja -4
+            instr(5, 0x95, 0, 0, 0, 0),  // exit
+        ]
+    }
+
     #[test]
     fn test_emitter_creation() {
         let options = DecompileOptions::default();
@@ -395,6 +1121,7 @@ mod tests {
             src: 0,
             off: 0,
             imm: 42,
+            imm64: 42,
             mnemonic: "mov64".into(),
             operands: "r0, 42".into(),
         };
@@ -415,6 +1142,7 @@ mod tests {
             src: 0,
             off: 0,
             imm: 0,
+            imm64: 0,
             mnemonic: "exit".into(),
             operands: String::new(),
         };
@@ -422,4 +1150,70 @@ mod tests {
         let ovsm = emitter.emit_instruction(&instr).unwrap();
         assert_eq!(ovsm, "(return result)");
     }
+
+    #[test]
+    fn test_emit_counted_loop_as_for() {
+        let options = DecompileOptions::default();
+        let emitter = OvsmEmitter::new(&options, None);
+        let instructions = counted_loop();
+        let cfg = ControlFlowGraph::build(&instructions);
+
+        let source = emitter.emit(&cfg, &instructions).unwrap();
+        assert!(source.contains("(for (r6 (range r6 10))"), "{}", source);
+        assert!(source.contains("(set! r7 (+ r7 r6))"));
+        // The increment and both jumps are folded into the loop form
+        assert!(!source.contains("(set! r6"));
+        assert!(!source.contains("jump"));
+        assert!(!source.contains("(if "));
+    }
+
+    #[test]
+    fn test_emit_while_loop() {
+        let options = DecompileOptions::default();
+        let emitter = OvsmEmitter::new(&options, None);
+        // Counter decremented by a register: not a counted loop
+        let mut instructions = counted_loop();
+        instructions[4] = instr(4, 0x1f, 6, 7, 0, 0); // sub64 r6, r7
+        let cfg = ControlFlowGraph::build(&instructions);
+
+        let source = emitter.emit(&cfg, &instructions).unwrap();
+        assert!(source.contains("(while (< r6 10)"), "{}", source);
+        assert!(source.contains("(set! r6 (- r6 r7))"));
+        assert!(!source.contains("jump"));
+    }
+
+    #[test]
+    fn test_emit_irreducible_uses_labels() {
+        let options = DecompileOptions::default();
+        let emitter = OvsmEmitter::new(&options, None);
+        let instructions = irreducible();
+        let cfg = ControlFlowGraph::build(&instructions);
+
+        let source = emitter.emit(&cfg, &instructions).unwrap();
+        assert!(!source.contains("(while"));
+        assert!(source.contains(";; block_1:"), "{}", source);
+        assert!(source.contains("(if (= arg1 0) (goto block_2))"));
+        assert!(source.contains("(goto block_1)"));
+    }
+
+    #[test]
+    fn test_spilled_register_is_restored_on_reload() {
+        let mut regs = RegState::new(&[1]);
+        for instr in [
+            instr(0, 0x7b, 10, 1, -8, 0),  // stxdw [r10-8], r1
+            instr(1, 0xb7, 3, 0, 0, 7),    // mov64 r3, 7
+            instr(2, 0x7b, 10, 3, -16, 0), // stxdw [r10-16], r3
+            instr(3, 0xb7, 1, 0, 0, 0),    // mov64 r1, 0
+            instr(4, 0xb7, 3, 0, 0, 0),    // mov64 r3, 0
+            instr(5, 0x79, 1, 10, -8, 0),  // ldxdw r1, [r10-8]
+            instr(6, 0x79, 3, 10, -16, 0), // ldxdw r3, [r10-16]
+            instr(7, 0x79, 4, 10, -24, 0), // ldxdw r4, [r10-24]
+        ] {
+            regs.step(&instr);
+        }
+
+        assert_eq!(regs.get(1), RegValue::Input);
+        assert_eq!(regs.get(3), RegValue::Const(7));
+        assert_eq!(regs.get(4), RegValue::Unknown);
+    }
 }
@@ -0,0 +1,161 @@
+//! # Round-Trip Verification
+//!
+//! Compares the text section of a program against the bytecode obtained by
+//! recompiling its decompiled source, so decompiler regressions show up as
+//! concrete divergent instruction ranges.
+
+use super::DisassembledInstr;
+
+/// A contiguous run of instructions that differ between the two programs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    /// First differing instruction index
+    pub start: usize,
+    /// One past the last differing instruction index
+    pub end: usize,
+    /// Original instructions in the range (assembly)
+    pub original: Vec<String>,
+    /// Recompiled instructions in the range (assembly)
+    pub recompiled: Vec<String>,
+}
+
+/// Outcome of recompiling decompiled source
+#[derive(Debug, Clone, Default)]
+pub struct RoundTripReport {
+    /// Instruction count of the input program
+    pub original_len: usize,
+    /// Instruction count of the recompiled program
+    pub recompiled_len: usize,
+    /// Divergent instruction ranges, in order
+    pub divergences: Vec<Divergence>,
+    /// Set when the decompiled source could not be recompiled
+    pub recompile_error: Option<String>,
+}
+
+impl RoundTripReport {
+    /// Compare two instruction streams position by position
+    pub fn compare(original: &[DisassembledInstr], recompiled: &[DisassembledInstr]) -> Self {
+        let mut divergences: Vec<Divergence> = Vec::new();
+        let len = original.len().max(recompiled.len());
+
+        for i in 0..len {
+            let a = original.get(i).map(|instr| instr.to_asm());
+            let b = recompiled.get(i).map(|instr| instr.to_asm());
+            if a == b {
+                continue;
+            }
+
+            match divergences.last_mut() {
+                Some(last) if last.end == i => last.end = i + 1,
+                _ => divergences.push(Divergence {
+                    start: i,
+                    end: i + 1,
+                    original: Vec::new(),
+                    recompiled: Vec::new(),
+                }),
+            }
+            let last = divergences.last_mut().expect("range just pushed");
+            last.original.extend(a);
+            last.recompiled.extend(b);
+        }
+
+        Self {
+            original_len: original.len(),
+            recompiled_len: recompiled.len(),
+            divergences,
+            recompile_error: None,
+        }
+    }
+
+    /// Report for source that failed to recompile
+    pub fn failed(original_len: usize, error: String) -> Self {
+        Self {
+            original_len,
+            recompile_error: Some(error),
+            ..Default::default()
+        }
+    }
+
+    /// True when the recompiled program matches the input exactly
+    pub fn is_exact(&self) -> bool {
+        self.recompile_error.is_none() && self.divergences.is_empty()
+    }
+
+    /// One warning line per problem, suitable for `DecompileResult.warnings`
+    pub fn warnings(&self) -> Vec<String> {
+        if let Some(err) = &self.recompile_error {
+            return vec![format!(
+                "round-trip: decompiled source does not recompile: {}",
+                err
+            )];
+        }
+
+        let mut warnings = Vec::new();
+        if self.original_len != self.recompiled_len {
+            warnings.push(format!(
+                "round-trip: instruction count changed from {} to {}",
+                self.original_len, self.recompiled_len
+            ));
+        }
+        for d in &self.divergences {
+            warnings.push(format!(
+                "round-trip: instructions {}..{} diverge (first: `{}` vs `{}`)",
+                d.start,
+                d.end,
+                d.original.first().map_or("<none>", String::as_str),
+                d.recompiled.first().map_or("<none>", String::as_str),
+            ));
+        }
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mov(dst: u8, imm: i32) -> DisassembledInstr {
+        DisassembledInstr {
+            offset: 0,
+            opcode: 0xb7,
+            dst,
+            src: 0,
+            off: 0,
+            imm,
+            imm64: imm as i64,
+            mnemonic: "mov64".into(),
+            operands: format!("r{}, {}", dst, imm),
+        }
+    }
+
+    #[test]
+    fn test_identical_programs_match() {
+        let program = vec![mov(0, 1), mov(1, 2)];
+        let report = RoundTripReport::compare(&program, &program);
+        assert!(report.is_exact());
+        assert!(report.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_divergent_ranges_are_merged() {
+        let original = vec![mov(0, 1), mov(1, 2), mov(2, 3), mov(3, 4)];
+        let recompiled = vec![mov(0, 1), mov(1, 9), mov(2, 9), mov(3, 4), mov(4, 5)];
+        let report = RoundTripReport::compare(&original, &recompiled);
+
+        assert_eq!(report.divergences.len(), 2);
+        assert_eq!(
+            (report.divergences[0].start, report.divergences[0].end),
+            (1, 3)
+        );
+        assert_eq!(
+            report.divergences[0].original,
+            vec!["mov64 r1, 2", "mov64 r2, 3"]
+        );
+        assert_eq!(
+            (report.divergences[1].start, report.divergences[1].end),
+            (4, 5)
+        );
+        assert!(report.divergences[1].original.is_empty());
+        assert_eq!(report.warnings().len(), 3);
+    }
+}
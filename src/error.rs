@@ -87,6 +87,17 @@ pub enum Error {
     #[error("Division by zero")]
     DivisionByZero,
 
+    /// Integer arithmetic overflowed i64
+    ///
+    /// **Triggered by:** `+`, `-` or `*` overflowing inside `(with-overflow-mode :checked ...)`
+    /// **Example:** `(with-overflow-mode :checked (* 9223372036854775807 2))`
+    /// **Prevention:** Use `:wrapping` or `:saturating` mode, or check operand ranges first
+    #[error("arithmetic-overflow: {op} overflowed i64")]
+    ArithmeticOverflow {
+        /// Operation name
+        op: String,
+    },
+
     /// Assertion failed
     ///
     /// **Triggered by:** Assertion condition evaluated to false
@@ -184,8 +195,12 @@ pub enum Error {
     #[error("Timeout after {0:?}")]
     Timeout(std::time::Duration),
 
-    /// Memory limit exceeded
-    #[error("Out of memory (limit: {0} bytes)")]
+    /// Evaluation stopped through its `CancellationToken`
+    #[error("Execution cancelled")]
+    Cancelled,
+
+    /// Allocation budget set by `LispEvaluator::set_allocation_budget` exceeded
+    #[error("Out of memory (allocation budget: {0} bytes)")]
     OutOfMemory(usize),
 
     /// Execution limit exceeded
@@ -254,6 +269,18 @@ pub enum Error {
     #[error("Compiler error: {0}")]
     CompilerError(String),
 
+    /// Formal verification blocked compilation, with every unproved condition
+    ///
+    /// **Triggered by:** `VerificationMode::Require` with
+    /// `CompileOptions::collect_all_diagnostics` set
+    #[error("Compiler error: {message}")]
+    VerificationFailed {
+        /// Same report as the plain compiler error
+        message: String,
+        /// One entry per failed or unverified condition
+        diagnostics: Vec<crate::compiler::Diagnostic>,
+    },
+
     // Control flow (catch/throw)
     /// Throw value for non-local exit (not really an error, used for control flow)
     /// This is caught by matching catch blocks
@@ -265,6 +292,17 @@ pub enum Error {
         value: Box<crate::runtime::Value>,
     },
 
+    /// `(break value)` leaving the nearest enclosing loop (control flow, not an error)
+    #[error("Break statement outside loop")]
+    LoopBreak {
+        /// Value the loop returns
+        value: Box<crate::runtime::Value>,
+    },
+
+    /// `(continue)` skipping to the next loop iteration (control flow, not an error)
+    #[error("Continue statement outside loop")]
+    LoopContinue,
+
     // Bordeaux Threads errors
     /// Thread-related error
     #[error("Thread error: {message}")]
@@ -319,6 +357,7 @@ impl Error {
     pub fn classify(&self) -> ErrorSeverity {
         match self {
             Error::DivisionByZero => ErrorSeverity::Fatal,
+            Error::ArithmeticOverflow { .. } => ErrorSeverity::Fatal,
             Error::AssertionFailed { .. } => ErrorSeverity::Fatal,
             Error::OutOfMemory(_) => ErrorSeverity::Fatal,
             Error::SyntaxError { .. } => ErrorSeverity::Fatal,
@@ -7,6 +7,8 @@ pub struct SExprScanner {
     source: Vec<char>,
     /// Accumulated tokens
     tokens: Vec<Token>,
+    /// Char offset where each entry of `tokens` starts
+    token_starts: Vec<usize>,
     /// Start position of current token
     start: usize,
     /// Current position in source
@@ -15,6 +17,8 @@ pub struct SExprScanner {
     line: usize,
     /// Current column number (1-indexed)
     column: usize,
+    /// `#;` datum comments as (index of the next token, line, column)
+    datum_comments: Vec<(usize, usize, usize)>,
 }
 
 impl SExprScanner {
@@ -23,15 +27,44 @@ impl SExprScanner {
         SExprScanner {
             source: source.chars().collect(),
             tokens: Vec::new(),
+            token_starts: Vec::new(),
             start: 0,
             current: 0,
             line: 1,
             column: 1,
+            datum_comments: Vec::new(),
         }
     }
 
     /// Scans all tokens from source code and returns them as a vector
     pub fn scan_tokens(&mut self) -> Result<Vec<Token>> {
+        self.scan_all(true)?;
+        Ok(self.tokens.clone())
+    }
+
+    /// Char offsets of the `(` and `)` that are code, in source order
+    ///
+    /// Strings and `;`, `#| ... |#` and `#;` comments are skipped exactly as
+    /// in [`scan_tokens`](Self::scan_tokens), except that a `#;` whose form
+    /// never closes is kept as code, so unbalanced input still reports every
+    /// paren that needs a match.
+    pub fn paren_offsets(&mut self) -> Result<Vec<(usize, char)>> {
+        self.scan_all(false)?;
+        Ok(self
+            .tokens
+            .iter()
+            .zip(&self.token_starts)
+            .filter_map(|(token, &start)| match token.kind {
+                TokenKind::LeftParen => Some((start, '(')),
+                TokenKind::RightParen => Some((start, ')')),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Scans the whole source and drops `#;` comments; `strict` rejects a
+    /// `#;` with no complete form after it
+    fn scan_all(&mut self, strict: bool) -> Result<()> {
         while !self.is_at_end() {
             self.start = self.current;
             self.scan_token()?;
@@ -43,8 +76,89 @@ impl SExprScanner {
             self.line,
             self.column,
         ));
+        self.token_starts.push(self.current);
 
-        Ok(self.tokens.clone())
+        if !self.datum_comments.is_empty() {
+            self.remove_commented_datums(strict)?;
+        }
+        Ok(())
+    }
+
+    /// Drops the form following each `#;`; stacked `#; #;` drop one form each
+    fn remove_commented_datums(&mut self, strict: bool) -> Result<()> {
+        let mut kept = Vec::with_capacity(self.tokens.len());
+        let mut kept_starts = Vec::with_capacity(self.tokens.len());
+        let mut comments = self.datum_comments.iter().peekable();
+        let mut pos = 0;
+        while pos < self.tokens.len() {
+            // Comments inside an already skipped form no longer apply
+            while comments.next_if(|(index, _, _)| *index < pos).is_some() {}
+            if let Some(&(_, line, column)) = comments.next_if(|(index, _, _)| *index == pos) {
+                let mut skip = 1;
+                while comments.next_if(|(index, _, _)| *index == pos).is_some() {
+                    skip += 1;
+                }
+                let mut end = pos;
+                for _ in 0..skip {
+                    match self.datum_end(end) {
+                        Some(next) => end = next,
+                        None if strict => {
+                            return Err(Error::ParseError(format!(
+                                "Expected a form after `#;` at line {}, column {}",
+                                line, column
+                            )))
+                        }
+                        None => break,
+                    }
+                }
+                if end > pos {
+                    pos = end;
+                    continue;
+                }
+            }
+            kept.push(self.tokens[pos].clone());
+            kept_starts.push(self.token_starts[pos]);
+            pos += 1;
+        }
+        self.tokens = kept;
+        self.token_starts = kept_starts;
+        Ok(())
+    }
+
+    /// Index just past the balanced form starting at `pos`, if there is one
+    fn datum_end(&self, pos: usize) -> Option<usize> {
+        match self.tokens.get(pos)?.kind {
+            TokenKind::Eof
+            | TokenKind::RightParen
+            | TokenKind::RightBracket
+            | TokenKind::RightBrace => None,
+            TokenKind::Quote
+            | TokenKind::Backtick
+            | TokenKind::SharpQuote
+            | TokenKind::Comma
+            | TokenKind::CommaAt
+            | TokenKind::Colon => self.datum_end(pos + 1),
+            TokenKind::LeftParen | TokenKind::LeftBracket | TokenKind::LeftBrace => {
+                let mut depth = 0usize;
+                for (offset, token) in self.tokens[pos..].iter().enumerate() {
+                    match token.kind {
+                        TokenKind::LeftParen | TokenKind::LeftBracket | TokenKind::LeftBrace => {
+                            depth += 1
+                        }
+                        TokenKind::RightParen | TokenKind::RightBracket | TokenKind::RightBrace => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Some(pos + offset + 1);
+                            }
+                        }
+                        TokenKind::Eof => return None,
+                        _ => {}
+                    }
+                }
+                None
+            }
+            _ => Some(pos + 1),
+        }
     }
 
     fn scan_token(&mut self) -> Result<()> {
@@ -63,6 +177,11 @@ impl SExprScanner {
             ';' => {
                 self.skip_line_comment();
             }
+            '#' if self.match_char('|') => self.skip_block_comment()?,
+            '#' if self.match_char(';') => {
+                self.datum_comments
+                    .push((self.tokens.len(), self.line, self.column - 2));
+            }
 
             // S-expression delimiters
             '(' => self.add_token(TokenKind::LeftParen),
@@ -75,6 +194,7 @@ impl SExprScanner {
             // Special LISP tokens
             '\'' => self.add_token(TokenKind::Quote),
             '`' => self.add_token(TokenKind::Backtick),
+            '#' if self.match_char('\'') => self.add_token(TokenKind::SharpQuote),
             '@' => self.add_token(TokenKind::At),
 
             ',' => {
@@ -93,7 +213,11 @@ impl SExprScanner {
                 if self.peek().is_ascii_digit() {
                     self.scan_number(true)?;
                 } else if self.match_char('>') {
-                    self.add_token(TokenKind::Arrow);
+                    if self.match_char('>') {
+                        self.add_token(TokenKind::Identifier("->>".to_string()));
+                    } else {
+                        self.add_token(TokenKind::Arrow);
+                    }
                 } else {
                     self.add_token(TokenKind::Minus);
                 }
@@ -185,12 +309,40 @@ impl SExprScanner {
         }
     }
 
+    /// Skips a `#| ... |#` comment, which may nest; the opening `#|` is consumed
+    fn skip_block_comment(&mut self) -> Result<()> {
+        let (start_line, start_column) = (self.line, self.column - 2);
+        let mut depth = 1;
+        while depth > 0 {
+            if self.is_at_end() {
+                return Err(Error::ParseError(format!(
+                    "Unterminated block comment starting at line {}, column {}",
+                    start_line, start_column
+                )));
+            }
+            match self.advance() {
+                '#' if self.match_char('|') => depth += 1,
+                '|' if self.match_char('#') => depth -= 1,
+                '\n' => {
+                    self.line += 1;
+                    self.column = 1;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     fn scan_string(&mut self) -> Result<()> {
         let mut value = String::new();
 
         while !self.is_at_end() && self.peek() != '"' {
             if self.peek() == '\\' {
+                let column = self.column;
                 self.advance();
+                if self.is_at_end() {
+                    break;
+                }
                 let escaped = self.advance();
                 match escaped {
                     'n' => value.push('\n'),
@@ -198,10 +350,11 @@ impl SExprScanner {
                     'r' => value.push('\r'),
                     '\\' => value.push('\\'),
                     '"' => value.push('"'),
+                    'u' => value.push(self.scan_unicode_escape(column)?),
                     _ => {
                         return Err(Error::ParseError(format!(
-                            "Invalid escape sequence \\{} at line {}",
-                            escaped, self.line
+                            "Invalid escape sequence \\{} at line {}, column {}",
+                            escaped, self.line, column
                         )));
                     }
                 }
@@ -227,6 +380,33 @@ impl SExprScanner {
         Ok(())
     }
 
+    /// Decodes the `{XXXX}` of a `\u{XXXX}` escape whose backslash is at `column`
+    fn scan_unicode_escape(&mut self, column: usize) -> Result<char> {
+        let invalid = |line: usize, detail: &str| {
+            Error::ParseError(format!(
+                "Invalid unicode escape at line {}, column {}: {}",
+                line, column, detail
+            ))
+        };
+        if !self.match_char('{') {
+            return Err(invalid(self.line, "expected `{` after \\u"));
+        }
+        let mut digits = String::new();
+        while self.peek().is_ascii_hexdigit() {
+            digits.push(self.advance());
+        }
+        if !self.match_char('}') {
+            return Err(invalid(self.line, "expected hex digits and a closing `}`"));
+        }
+        if digits.is_empty() || digits.len() > 6 {
+            return Err(invalid(self.line, "expected 1 to 6 hex digits"));
+        }
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| invalid(self.line, &format!("U+{} is not a character", digits)))
+    }
+
     fn scan_number(&mut self, _negative: bool) -> Result<()> {
         while self.peek().is_ascii_digit() {
             self.advance();
@@ -267,6 +447,8 @@ impl SExprScanner {
             || self.peek() == '?'
             || self.peek() == '!'
             || self.peek() == '&'
+            // `->` inside a name, as in cond-> and string->number
+            || (self.peek() == '>' && self.source[self.current - 1] == '-')
         {
             self.advance();
         }
@@ -277,6 +459,20 @@ impl SExprScanner {
             self.advance();
         }
 
+        // Package-qualified symbols: pkg:sym (exported) and pkg::sym (internal)
+        let after_colons = if self.peek_next() == ':' { 2 } else { 1 };
+        if self.peek() == ':'
+            && self
+                .source
+                .get(self.current + after_colons)
+                .is_some_and(|c| c.is_alphabetic())
+        {
+            for _ in 0..after_colons {
+                self.advance();
+            }
+            return self.scan_identifier_or_keyword();
+        }
+
         let text: String = self.source[self.start..self.current].iter().collect();
 
         // Check for boolean literals
@@ -340,6 +536,7 @@ impl SExprScanner {
         let lexeme: String = self.source[self.start..self.current].iter().collect();
         self.tokens
             .push(Token::new(kind, lexeme, self.line, self.column));
+        self.token_starts.push(self.start);
     }
 }
 
@@ -398,6 +595,33 @@ mod tests {
         assert!(tokens.iter().any(|t| matches!(t.kind, TokenKind::Colon)));
     }
 
+    #[test]
+    fn test_package_qualified_identifiers() {
+        let source = "(utils:add utils::helper (: x T))";
+        let mut scanner = SExprScanner::new(source);
+        let tokens = scanner.scan_tokens().unwrap();
+
+        assert_eq!(
+            tokens[1].kind,
+            TokenKind::Identifier("utils:add".to_string())
+        );
+        assert_eq!(
+            tokens[2].kind,
+            TokenKind::Identifier("utils::helper".to_string())
+        );
+        assert_eq!(tokens[4].kind, TokenKind::Colon);
+    }
+
+    #[test]
+    fn test_sharp_quote() {
+        let source = "(mapcar #'add xs)";
+        let mut scanner = SExprScanner::new(source);
+        let tokens = scanner.scan_tokens().unwrap();
+
+        assert_eq!(tokens[2].kind, TokenKind::SharpQuote);
+        assert_eq!(tokens[3].kind, TokenKind::Identifier("add".to_string()));
+    }
+
     #[test]
     fn test_comment() {
         let source = "; This is a comment\n(+ 1 2)";
@@ -408,4 +632,82 @@ mod tests {
         assert_eq!(tokens[0].kind, TokenKind::LeftParen);
         assert_eq!(tokens[1].kind, TokenKind::Plus);
     }
+
+    #[test]
+    fn test_block_comment() {
+        let source = "#| skipped (+ 1 |#\n(+ 1 2)";
+        let mut scanner = SExprScanner::new(source);
+        let tokens = scanner.scan_tokens().unwrap();
+
+        assert_eq!(tokens[0].kind, TokenKind::LeftParen);
+        assert_eq!(tokens[0].line, 2);
+        assert_eq!(tokens.len(), 6);
+    }
+
+    #[test]
+    fn test_nested_block_comment() {
+        let source = "(list 1 #| outer #| inner |# still-outer |# 2)";
+        let mut scanner = SExprScanner::new(source);
+        let tokens = scanner.scan_tokens().unwrap();
+
+        let kinds: Vec<_> = tokens.iter().map(|t| t.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::LeftParen,
+                TokenKind::Identifier("list".to_string()),
+                TokenKind::Integer(1),
+                TokenKind::Integer(2),
+                TokenKind::RightParen,
+                TokenKind::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unterminated_block_comment() {
+        let source = "(+ 1 2)\n  #| outer #| inner |#";
+        let mut scanner = SExprScanner::new(source);
+        let err = scanner.scan_tokens().unwrap_err().to_string();
+
+        assert!(err.contains("Unterminated block comment"), "{}", err);
+        assert!(err.contains("line 2, column 3"), "{}", err);
+    }
+
+    fn string_token(source: &str) -> Result<String> {
+        let tokens = SExprScanner::new(source).scan_tokens()?;
+        match &tokens[0].kind {
+            TokenKind::String(s) => Ok(s.clone()),
+            other => panic!("expected a string token, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        assert_eq!(string_token(r#""line1\nline2""#).unwrap(), "line1\nline2");
+        assert_eq!(string_token(r#""a\tb\r""#).unwrap(), "a\tb\r");
+        assert_eq!(
+            string_token(r#""say \"hi\" \\ bye""#).unwrap(),
+            "say \"hi\" \\ bye"
+        );
+    }
+
+    #[test]
+    fn test_unicode_escape() {
+        assert_eq!(
+            string_token(r#""smile \u{1F600}""#).unwrap(),
+            "smile \u{1F600}"
+        );
+        assert_eq!(string_token(r#""\u{41}\u{e9}""#).unwrap(), "A\u{e9}");
+        assert!(string_token(r#""\u{D800}""#).is_err());
+        assert!(string_token(r#""\u41""#).is_err());
+    }
+
+    #[test]
+    fn test_invalid_escape_reports_position() {
+        let err = string_token("(f\n  \"ok \\q\")").unwrap_err().to_string();
+        assert!(err.contains("\\q at line 2, column 7"), "{}", err);
+        // A backslash right before end of input is an unterminated string
+        assert!(string_token("\"\\").is_err());
+    }
 }
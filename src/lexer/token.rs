@@ -213,6 +213,8 @@ pub enum TokenKind {
     Quote,
     /// Backtick for LISP quasi-quote (`)
     Backtick,
+    /// Sharp-quote for LISP function references (#')
+    SharpQuote,
     /// At symbol for LISP splice (@)
     At,
     /// Comma-At for LISP unquote-splice (,@)
@@ -292,7 +292,7 @@ pub mod types;
 pub use error::{Error, Result};
 pub use lexer::{SExprScanner, Token, TokenKind};
 pub use parser::{BinaryOp, Expression, Program, SExprParser, Statement, UnaryOp};
-pub use runtime::{Environment, LispEvaluator, Value};
+pub use runtime::{CancellationToken, Environment, LispEvaluator, Value};
 pub use tools::{Tool, ToolRegistry};
 pub use types::{BidirectionalChecker, Type, TypeBridge, TypeChecker, TypeContext, TypeError};
 
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use super::ParenWarning;
+
 /// Complete OVSM program
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Program {
@@ -21,6 +23,9 @@ pub struct ProgramMetadata {
     pub confidence: Option<u8>,
     /// List of available tools
     pub available_tools: Vec<String>,
+    /// Parens inserted or removed when parsing with `ParenMode::Fix`
+    #[serde(default)]
+    pub paren_warnings: Vec<ParenWarning>,
 }
 
 /// Statements
@@ -623,6 +628,18 @@ pub enum IterationClause {
         /// Collection expression to iterate over
         collection: Box<Expression>,
     },
+    /// Hash table iteration: (loop for k being the hash-keys of table ...)
+    HashTable {
+        /// Iteration variable name
+        var: String,
+        /// Hash table expression to iterate over
+        table: Box<Expression>,
+        /// True if iterating 'hash-values' instead of 'hash-keys'
+        values: bool,
+        /// Variable bound to the other half of each entry by
+        /// 'using (hash-value v)' or 'using (hash-key k)'
+        using: Option<String>,
+    },
 }
 
 /// Accumulation clause for loop
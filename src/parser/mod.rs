@@ -21,5 +21,5 @@ pub use ast::{
     Statement,
     UnaryOp,
 };
-pub use paren_fixer::ParenFixer;
+pub use paren_fixer::{ParenEditKind, ParenFixer, ParenMode, ParenWarning};
 pub use sexpr_parser::SExprParser;
@@ -3,59 +3,191 @@
 //! This module provides automatic correction of missing or mismatched
 //! parentheses in Solisp code, similar to error recovery in modern compilers.
 
-use crate::error::Result;
-use crate::parser::SExprParser;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::parser::{Program, SExprParser};
 use crate::Scanner;
 
+/// How [`ParenFixer::parse`] treats unbalanced parentheses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParenMode {
+    /// Repair the source and record each edit in the program metadata
+    Fix,
+    /// Refuse to repair; report where the imbalance starts
+    Strict,
+}
+
+/// Whether a paren was added to or dropped from the source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParenEditKind {
+    /// A missing `)` was inserted
+    Inserted,
+    /// An unmatched `)` was removed
+    Removed,
+}
+
+/// One automatic paren edit, located in the original source (1-indexed)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParenWarning {
+    /// What was done
+    pub kind: ParenEditKind,
+    /// Line of the edit
+    pub line: usize,
+    /// Column of the edit
+    pub column: usize,
+}
+
+impl fmt::Display for ParenWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let action = match self.kind {
+            ParenEditKind::Inserted => "inserted",
+            ParenEditKind::Removed => "removed",
+        };
+        write!(
+            f,
+            "{} `)` at line {}, column {}",
+            action, self.line, self.column
+        )
+    }
+}
+
 /// Attempts to automatically fix missing or mismatched parentheses
 pub struct ParenFixer {
     /// Original source code
     source: String,
-    /// Lines of source code
-    lines: Vec<String>,
 }
 
 impl ParenFixer {
     /// Create a new parenthesis fixer
     pub fn new(source: impl Into<String>) -> Self {
-        let source = source.into();
-        let lines: Vec<String> = source.lines().map(|s| s.to_string()).collect();
-
-        Self { source, lines }
+        Self {
+            source: source.into(),
+        }
     }
 
     /// Analyze and fix parenthesis imbalances with smart iterative placement
     pub fn fix(&self) -> Result<String> {
+        Ok(self.fix_with_warnings().0)
+    }
+
+    /// Fix the source like [`fix`](Self::fix), listing every paren it edited
+    pub fn fix_with_warnings(&self) -> (String, Vec<ParenWarning>) {
         // Count parentheses
         let stats = self.count_parens();
 
         if stats.is_balanced() {
             // Already balanced - return original
-            return Ok(self.source.clone());
+            return (self.source.clone(), Vec::new());
         }
 
-        // Try to fix the imbalance with smart placement
         if stats.open_count > stats.close_count {
-            // Missing closing parens - try smart placement
+            // Missing closing parens - try smart placement, then the end
             let missing = stats.open_count - stats.close_count;
-
-            // Try smart iterative placement first
-            if let Some(fixed) = self.smart_fix_missing_parens(missing) {
-                return Ok(fixed);
-            }
-
-            // Fallback to simple end placement
-            Ok(self.add_closing_parens(missing))
+            let (pos, fixed) = self
+                .smart_fix_missing_parens(missing)
+                .unwrap_or_else(|| (self.source.len(), self.add_closing_parens(missing)));
+            let warning = self.warning_at(ParenEditKind::Inserted, pos);
+            (fixed, vec![warning; missing])
         } else {
             // Too many closing parens - remove extras
             let extra = stats.close_count - stats.open_count;
-            Ok(self.remove_extra_closing_parens(extra))
+            let (fixed, removed) = self.remove_extra_closing_parens(extra);
+            let warnings = removed
+                .into_iter()
+                .map(|pos| self.warning_at(ParenEditKind::Removed, pos))
+                .collect();
+            (fixed, warnings)
+        }
+    }
+
+    /// Refuse unbalanced input with the location where the imbalance starts
+    ///
+    /// An unmatched `)` is reported where it stands; missing `)` are
+    /// reported at the outermost `(` left open.
+    pub fn check_balanced(&self) -> Result<()> {
+        let mut open = Vec::new();
+
+        for (idx, ch) in self.parens()? {
+            if ch == '(' {
+                open.push(idx);
+            } else if open.pop().is_none() {
+                let (line, col) = self.location(idx);
+                return Err(Error::SyntaxError {
+                    line,
+                    col,
+                    message: "unbalanced: unexpected `)` with no matching `(`".to_string(),
+                });
+            }
+        }
+
+        match open.first() {
+            Some(&start) => {
+                let (line, col) = self.location(start);
+                Err(Error::SyntaxError {
+                    line,
+                    col,
+                    message: format!(
+                        "unbalanced: expected {} more `)` starting at line {}",
+                        open.len(),
+                        line
+                    ),
+                })
+            }
+            None => Ok(()),
         }
     }
 
+    /// Parse the source, fixing or rejecting unbalanced parens per `mode`
+    ///
+    /// In [`ParenMode::Fix`] the edits are listed in
+    /// `program.metadata.paren_warnings`.
+    pub fn parse(&self, mode: ParenMode) -> Result<Program> {
+        let (source, warnings) = match mode {
+            ParenMode::Fix => self.fix_with_warnings(),
+            ParenMode::Strict => {
+                self.check_balanced()?;
+                (self.source.clone(), Vec::new())
+            }
+        };
+        let tokens = Scanner::new(&source).scan_tokens()?;
+        let mut program = SExprParser::new(tokens).parse()?;
+        program.metadata.paren_warnings = warnings;
+        Ok(program)
+    }
+
+    /// Byte offsets of the parens that are code
+    ///
+    /// Comes from the scanner, so parens in strings and in line, nested
+    /// block and datum comments are left out just as the parser leaves them.
+    fn parens(&self) -> Result<Vec<(usize, char)>> {
+        let offsets = Scanner::new(&self.source).paren_offsets()?;
+        let bytes: Vec<usize> = self.source.char_indices().map(|(idx, _)| idx).collect();
+        Ok(offsets
+            .into_iter()
+            .map(|(offset, ch)| (bytes[offset], ch))
+            .collect())
+    }
+
+    fn warning_at(&self, kind: ParenEditKind, pos: usize) -> ParenWarning {
+        let (line, column) = self.location(pos);
+        ParenWarning { kind, line, column }
+    }
+
+    /// 1-indexed line and column of byte offset `pos` in the source
+    fn location(&self, pos: usize) -> (usize, usize) {
+        let before = &self.source[..pos.min(self.source.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
+
     /// Try to fix missing parens by iteratively trying different positions
-    /// Returns the first valid placement that parses successfully
-    fn smart_fix_missing_parens(&self, missing: usize) -> Option<String> {
+    /// Returns the first valid placement that parses successfully, with the
+    /// byte offset the parens were inserted at
+    fn smart_fix_missing_parens(&self, missing: usize) -> Option<(usize, String)> {
         // Find candidate positions where we can insert closing parens
         let candidates = self.find_insertion_candidates();
 
@@ -65,7 +197,7 @@ impl ParenFixer {
 
             // Validate by parsing
             if self.validates(&attempt) {
-                return Some(attempt);
+                return Some((pos, attempt));
             }
         }
 
@@ -76,47 +208,18 @@ impl ParenFixer {
     /// Returns positions sorted by likelihood of being correct
     fn find_insertion_candidates(&self) -> Vec<usize> {
         let mut candidates = Vec::new();
-        let mut in_string = false;
-        let mut escape_next = false;
+        let mut parens = self.parens().unwrap_or_default().into_iter().peekable();
         let mut depth = 0_i32;
 
-        for (idx, ch) in self.source.chars().enumerate() {
-            // Handle escape sequences
-            if escape_next {
-                escape_next = false;
-                continue;
-            }
-
-            if ch == '\\' && in_string {
-                escape_next = true;
-                continue;
-            }
-
-            // Handle strings
-            if ch == '"' {
-                in_string = !in_string;
-                continue;
+        for (idx, ch) in self.source.char_indices() {
+            // Track depth from the code parens before this position
+            while let Some((_, paren)) = parens.next_if(|&(pos, _)| pos < idx) {
+                depth += if paren == '(' { 1 } else { -1 };
             }
 
-            if in_string {
-                continue;
-            }
-
-            // Track depth and record positions
-            match ch {
-                '(' => {
-                    depth += 1;
-                }
-                ')' => {
-                    depth -= 1;
-                }
-                '\n' => {
-                    // End of line is a good candidate if we're in nested code
-                    if depth > 0 {
-                        candidates.push(idx + 1);
-                    }
-                }
-                _ => {}
+            // End of line is a good candidate if we're in nested code
+            if ch == '\n' && depth > 0 {
+                candidates.push(idx + 1);
             }
         }
 
@@ -172,61 +275,17 @@ impl ParenFixer {
         parser.parse().is_ok()
     }
 
-    /// Count all parentheses in the source
+    /// Count the code parentheses in the source
+    ///
+    /// Source that does not scan counts as balanced and is left for the
+    /// parser to report.
     fn count_parens(&self) -> ParenStats {
-        let mut open_count = 0;
-        let mut close_count = 0;
-        let mut in_string = false;
-        let mut escape_next = false;
-
-        for line in &self.lines {
-            let mut in_comment = false; // Comments are line-based
-
-            for ch in line.chars() {
-                // Handle escape sequences
-                if escape_next {
-                    escape_next = false;
-                    continue;
-                }
-
-                if ch == '\\' && in_string {
-                    escape_next = true;
-                    continue;
-                }
-
-                // Handle strings
-                if ch == '"' && !in_comment {
-                    in_string = !in_string;
-                    continue;
-                }
-
-                // Skip if we're in a string or comment
-                if in_string {
-                    continue;
-                }
-
-                // Handle comments
-                if ch == ';' {
-                    in_comment = true;
-                    continue;
-                }
-
-                if in_comment {
-                    continue;
-                }
-
-                // Count parentheses
-                match ch {
-                    '(' => open_count += 1,
-                    ')' => close_count += 1,
-                    _ => {}
-                }
-            }
-        }
+        let parens = self.parens().unwrap_or_default();
+        let open_count = parens.iter().filter(|(_, ch)| *ch == '(').count();
 
         ParenStats {
             open_count,
-            close_count,
+            close_count: parens.len() - open_count,
         }
     }
 
@@ -253,65 +312,35 @@ impl ParenFixer {
         fixed
     }
 
-    /// Remove extra closing parentheses (tries to be smart about it)
-    fn remove_extra_closing_parens(&self, extra: usize) -> String {
-        let mut fixed = String::new();
+    /// Remove extra closing parentheses (tries to be smart about it),
+    /// returning the byte offsets of the removed parens
+    fn remove_extra_closing_parens(&self, extra: usize) -> (String, Vec<usize>) {
+        let mut removed = Vec::new();
         let mut remaining_to_remove = extra;
         let mut depth = 0_i32; // Track nesting depth
 
-        let mut in_string = false;
-        let mut escape_next = false;
-
-        for ch in self.source.chars() {
-            // Handle escape sequences
-            if escape_next {
-                escape_next = false;
-                fixed.push(ch);
-                continue;
-            }
-
-            if ch == '\\' && in_string {
-                escape_next = true;
-                fixed.push(ch);
-                continue;
-            }
-
-            // Handle strings
-            if ch == '"' {
-                in_string = !in_string;
-                fixed.push(ch);
-                continue;
-            }
-
-            if in_string {
-                fixed.push(ch);
-                continue;
+        for (idx, ch) in self.parens().unwrap_or_default() {
+            if ch == '(' {
+                depth += 1;
+            } else if depth > 0 {
+                // Valid closing paren
+                depth -= 1;
+            } else if remaining_to_remove > 0 {
+                // Extra closing paren - drop it
+                remaining_to_remove -= 1;
+                removed.push(idx);
             }
+        }
 
-            // Track depth and remove extras
-            match ch {
-                '(' => {
-                    depth += 1;
-                    fixed.push(ch);
-                }
-                ')' => {
-                    if depth > 0 {
-                        // Valid closing paren
-                        depth -= 1;
-                        fixed.push(ch);
-                    } else if remaining_to_remove > 0 {
-                        // Extra closing paren - skip it
-                        remaining_to_remove -= 1;
-                    } else {
-                        // Shouldn't happen, but keep it
-                        fixed.push(ch);
-                    }
-                }
-                _ => fixed.push(ch),
-            }
+        let mut fixed = String::with_capacity(self.source.len());
+        let mut copied = 0;
+        for &idx in &removed {
+            fixed.push_str(&self.source[copied..idx]);
+            copied = idx + 1;
         }
+        fixed.push_str(&self.source[copied..]);
 
-        fixed
+        (fixed, removed)
     }
 
     /// Get the fixed code with a report
@@ -542,4 +571,149 @@ mod tests {
         // Note: Smart placement may not always find the perfect spot,
         // but it should at least balance the parens
     }
+
+    #[test]
+    fn test_fix_mode_warnings() {
+        let code = "(define x (+ 1 2)\n(define y (+ 3 4))";
+        let program = ParenFixer::new(code).parse(ParenMode::Fix).unwrap();
+
+        let warnings = program.metadata.paren_warnings;
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, ParenEditKind::Inserted);
+        assert_eq!(warnings[0].to_string(), "inserted `)` at line 2, column 1");
+
+        let (_, warnings) = ParenFixer::new("(+ 1 2))\n  (f))").fix_with_warnings();
+        assert_eq!(
+            warnings,
+            vec![
+                ParenWarning {
+                    kind: ParenEditKind::Removed,
+                    line: 1,
+                    column: 8
+                },
+                ParenWarning {
+                    kind: ParenEditKind::Removed,
+                    line: 2,
+                    column: 6
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_balanced_parse_has_no_warnings() {
+        let program = ParenFixer::new("(define x 1)")
+            .parse(ParenMode::Fix)
+            .unwrap();
+        assert!(program.metadata.paren_warnings.is_empty());
+    }
+
+    #[test]
+    fn test_strict_mode_missing_parens() {
+        let code = "(define x 1)\n  (define y (+ 1 (- 5 3";
+        let err = ParenFixer::new(code).parse(ParenMode::Strict).unwrap_err();
+        match err {
+            Error::SyntaxError { line, col, message } => {
+                assert_eq!((line, col), (2, 3));
+                assert_eq!(
+                    message,
+                    "unbalanced: expected 3 more `)` starting at line 2"
+                );
+            }
+            other => panic!("expected a syntax error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_strict_mode_extra_paren() {
+        let code = "(define s \")\") ; )\n(f))";
+        let err = ParenFixer::new(code).check_balanced().unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::SyntaxError {
+                    line: 2,
+                    col: 4,
+                    ..
+                }
+            ),
+            "{:?}",
+            err
+        );
+        assert!(ParenFixer::new("(f \"(\")").check_balanced().is_ok());
+    }
+
+    #[test]
+    fn test_parens_in_block_comments_are_ignored() {
+        // Nested block comment hiding an unbalanced `(` and `)`
+        let code = "#| outer ( #| inner ) ) |# still ( |#\n(define x 1)";
+        let fixer = ParenFixer::new(code);
+        assert!(fixer.check_balanced().is_ok());
+        assert!(fixer.parse(ParenMode::Strict).is_ok());
+        assert_eq!(fixer.fix_with_warnings(), (code.to_string(), Vec::new()));
+
+        let code = "(define x (+ 1 2) #| ) |#";
+        let err = ParenFixer::new(code).check_balanced().unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::SyntaxError {
+                    line: 1,
+                    col: 1,
+                    ..
+                }
+            ),
+            "{:?}",
+            err
+        );
+        let program = ParenFixer::new(code).parse(ParenMode::Fix).unwrap();
+        assert_eq!(program.metadata.paren_warnings.len(), 1);
+        assert_eq!(
+            program.metadata.paren_warnings[0].kind,
+            ParenEditKind::Inserted
+        );
+    }
+
+    #[test]
+    fn test_parens_in_datum_comments_are_ignored() {
+        // The commented form closes on `]`, leaving its `(` unmatched
+        let code = "(define x 1)\n#;[f (g]]\n(define y 2)";
+        let fixer = ParenFixer::new(code);
+        assert!(fixer.check_balanced().is_ok());
+        assert!(fixer.parse(ParenMode::Strict).is_ok());
+        assert_eq!(fixer.fix_with_warnings(), (code.to_string(), Vec::new()));
+
+        let code = "#;[(]]\n(f))";
+        let err = ParenFixer::new(code).parse(ParenMode::Strict).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::SyntaxError {
+                    line: 2,
+                    col: 4,
+                    ..
+                }
+            ),
+            "{:?}",
+            err
+        );
+        let (fixed, warnings) = ParenFixer::new(code).fix_with_warnings();
+        assert_eq!(fixed, "#;[(]]\n(f)");
+        assert_eq!(
+            warnings,
+            vec![ParenWarning {
+                kind: ParenEditKind::Removed,
+                line: 2,
+                column: 4
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unclosed_datum_comment_counts_as_code() {
+        let code = "#;(a (b)\n(define x 1)";
+        let (fixed, warnings) = ParenFixer::new(code).fix_with_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(ParenFixer::new(fixed).check_balanced().is_ok());
+    }
 }
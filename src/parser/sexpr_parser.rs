@@ -31,6 +31,17 @@ impl SExprParser {
         })
     }
 
+    /// Parses the next form as data, the way `quote` reads it
+    ///
+    /// Returns `None` once the tokens are exhausted, so a reader can tell
+    /// end of input apart from a malformed form.
+    pub fn parse_datum(&mut self) -> Result<Option<Expression>> {
+        if self.is_at_end() {
+            return Ok(None);
+        }
+        self.parse_quoted_datum().map(Some)
+    }
+
     /// Parse a single statement (which is an S-expression)
     fn parse_statement(&mut self) -> Result<Statement> {
         let expr = self.parse_expression()?;
@@ -46,6 +57,7 @@ impl SExprParser {
             TokenKind::LeftParen => self.parse_list(),
             TokenKind::Quote => self.parse_quoted(),
             TokenKind::Backtick => self.parse_quasiquote(),
+            TokenKind::SharpQuote => self.parse_function_reference(),
             TokenKind::Comma => self.parse_unquote(),
             TokenKind::CommaAt => self.parse_unquote_splice(),
             TokenKind::Colon => self.parse_keyword_literal(),
@@ -81,6 +93,23 @@ impl SExprParser {
             }
             TokenKind::LeftBracket => self.parse_array_literal(),
             TokenKind::LeftBrace => self.parse_object_literal(),
+            // Bare operator used as a function designator: (multiple-value-call + ...), (sort xs <)
+            TokenKind::Plus
+            | TokenKind::Minus
+            | TokenKind::Star
+            | TokenKind::Slash
+            | TokenKind::Percent
+            | TokenKind::Assign
+            | TokenKind::Eq
+            | TokenKind::NotEq
+            | TokenKind::Lt
+            | TokenKind::Gt
+            | TokenKind::LtEq
+            | TokenKind::GtEq => {
+                let name = Self::operator_symbol(&self.peek().kind).to_string();
+                self.advance();
+                Ok(Expression::Variable(name))
+            }
             _ => Err(self.syntax_error(format!(
                 "Unexpected token {} in expression.\n\n\
                  Help: Expected one of: number, string, boolean, null, identifier, \
@@ -110,22 +139,35 @@ impl SExprParser {
             TokenKind::Identifier(name) if name == "let*" => self.parse_let_star_expr(),
             TokenKind::Identifier(name) if name == "flet" => self.parse_flet_expr(),
             TokenKind::Identifier(name) if name == "labels" => self.parse_labels_expr(),
-            TokenKind::Identifier(name) if name == "case" => self.parse_case_expr(),
+            TokenKind::Identifier(name) if matches!(name.as_str(), "case" | "ecase" | "ccase") => {
+                self.parse_case_expr()
+            }
             TokenKind::Identifier(name) if name == "typecase" => self.parse_typecase_expr(),
             TokenKind::Identifier(name) if name == "const" => self.parse_const(),
             TokenKind::Identifier(name) if name == "define" => self.parse_define(),
+            TokenKind::Identifier(name) if name == "defpackage" => self.parse_defpackage(),
+            TokenKind::Identifier(name) if name == "defgeneric" => self.parse_defgeneric(),
+            TokenKind::Identifier(name) if name == "defmethod" => self.parse_defmethod(),
             TokenKind::Identifier(name) if name == "set!" => self.parse_set(),
             TokenKind::Identifier(name) if name == "while" => self.parse_while(),
             TokenKind::Identifier(name) if name == "for" => self.parse_for(),
             TokenKind::Identifier(name) if name == "loop" => self.parse_loop_expr(),
             TokenKind::Identifier(name) if name == "lambda" => self.parse_lambda(),
             TokenKind::Identifier(name) if name == "defn" => self.parse_defn(),
+            TokenKind::Identifier(name) if name == "do" && self.is_do_loop() => {
+                self.parse_do_loop(false)
+            }
             TokenKind::Identifier(name) if name == "do" => self.parse_do(),
+            TokenKind::Identifier(name) if name == "doloop" => self.parse_do_loop(false),
+            TokenKind::Identifier(name) if name == "do*" || name == "doloop*" => {
+                self.parse_do_loop(true)
+            }
             TokenKind::Identifier(name) if name == "when" => self.parse_when(),
             TokenKind::Identifier(name) if name == "cond" => self.parse_cond(),
             TokenKind::Identifier(name) if name == "catch" => self.parse_catch(),
             TokenKind::Identifier(name) if name == "throw" => self.parse_throw(),
             TokenKind::Identifier(name) if name == "destructuring-bind" => self.parse_destructuring_bind(),
+            TokenKind::Identifier(name) if name == "with-timeout" => self.parse_with_timeout(),
 
             // Protocol specification forms
             TokenKind::Identifier(name) if name == "defstate" => self.parse_defstate(),
@@ -141,7 +183,13 @@ impl SExprParser {
             TokenKind::Colon => self.parse_type_annotation(),
 
             // Function type form (-> param-types return-type)
-            TokenKind::Arrow => self.parse_function_type(),
+            TokenKind::Arrow if self.is_function_type() => self.parse_function_type(),
+
+            // Threading macros (-> x steps...) and (->> x steps...)
+            TokenKind::Arrow => self.parse_threading(false),
+            TokenKind::Identifier(name) if name == "->>" => self.parse_threading(true),
+            TokenKind::Identifier(name) if name == "cond->" => self.parse_cond_threading(),
+            TokenKind::Identifier(name) if name == "as->" => self.parse_as_threading(),
 
             // Operators
             TokenKind::Plus
@@ -194,12 +242,48 @@ impl SExprParser {
     /// Parse a quoted expression '(1 2 3)
     fn parse_quoted(&mut self) -> Result<Expression> {
         self.consume(TokenKind::Quote)?;
-        let expr = self.parse_expression()?;
+        self.parse_quoted_datum()
+    }
 
-        // Convert to a quoted list
-        // For now, just return the expression as-is
-        // In a full LISP implementation, we'd wrap this in a Quote expression
-        Ok(expr)
+    /// Parse #'name as (function name)
+    fn parse_function_reference(&mut self) -> Result<Expression> {
+        self.consume(TokenKind::SharpQuote)?;
+        let target = self.parse_expression()?;
+        Ok(Expression::ToolCall {
+            name: "function".to_string(),
+            args: vec![Argument::positional(target)],
+        })
+    }
+
+    /// Parse a quoted datum: symbols become their names and lists become arrays
+    fn parse_quoted_datum(&mut self) -> Result<Expression> {
+        // A quoted symbol evaluates to its name rather than its binding
+        if let TokenKind::Identifier(name) = &self.peek().kind {
+            let name = name.clone();
+            self.advance();
+            return Ok(Expression::StringLiteral(name));
+        }
+        let op = Self::operator_symbol(&self.peek().kind);
+        if !op.is_empty() {
+            self.advance();
+            return Ok(Expression::StringLiteral(op.to_string()));
+        }
+
+        // A quoted list is data, not a call: '(a 1 (b 2)) => ["a" 1 ["b" 2]]
+        if self.check(&TokenKind::LeftParen) {
+            self.advance();
+            let mut elements = Vec::new();
+            while !self.check(&TokenKind::RightParen) {
+                if self.is_at_end() {
+                    return Err(self.expected_error("`)` to close quoted list", None));
+                }
+                elements.push(self.parse_quoted_datum()?);
+            }
+            self.consume(TokenKind::RightParen)?;
+            return Ok(Expression::ArrayLiteral(elements));
+        }
+
+        self.parse_expression()
     }
 
     /// Parse (if condition then-expr else-expr)
@@ -448,22 +532,33 @@ impl SExprParser {
     }
 
     /// Parse (case expr (pattern result)... (else default)) - Pattern matching by value
+    ///
+    /// `ecase` and `ccase` share the syntax.
     fn parse_case_expr(&mut self) -> Result<Expression> {
-        self.advance(); // consume 'case'
+        let name = self.advance().lexeme; // consume 'case'
 
         // Parse test expression
         let test_expr = self.parse_expression()?;
 
-        // Parse clauses
+        // Parse clauses, plus ccase's `:store-value fn` restart
         let mut clauses = Vec::new();
+        let mut store_value = None;
         while !self.check(&TokenKind::RightParen) {
-            // Each clause is (pattern result)
+            if name == "ccase" && self.check(&TokenKind::Colon) {
+                self.advance(); // consume ':'
+                match &self.peek().kind {
+                    TokenKind::Identifier(keyword) if keyword == "store-value" => {
+                        self.advance();
+                        store_value = Some(self.parse_expression()?);
+                        continue;
+                    }
+                    _ => return Err(self.expected_error("`store-value`", None)),
+                }
+            }
+            // Each clause is (pattern result...)
             self.consume(TokenKind::LeftParen)?;
-            let pattern = self.parse_expression()?;
-            let result = self.parse_expression()?;
-            self.consume(TokenKind::RightParen)?;
-
-            clauses.push(Expression::ArrayLiteral(vec![pattern, result]));
+            let pattern = self.parse_case_keys()?;
+            clauses.push(self.parse_case_clause_body(pattern)?);
         }
         self.consume(TokenKind::RightParen)?;
 
@@ -472,11 +567,11 @@ impl SExprParser {
         for clause in clauses {
             args.push(Argument::positional(clause));
         }
+        if let Some(handler) = store_value {
+            args.push(Argument::named("store-value".to_string(), handler));
+        }
 
-        Ok(Expression::ToolCall {
-            name: "case".to_string(),
-            args,
-        })
+        Ok(Expression::ToolCall { name, args })
     }
 
     /// Parse (typecase expr (type result)... (else default)) - Pattern matching by type
@@ -489,13 +584,10 @@ impl SExprParser {
         // Parse clauses
         let mut clauses = Vec::new();
         while !self.check(&TokenKind::RightParen) {
-            // Each clause is (type result)
+            // Each clause is (type result...)
             self.consume(TokenKind::LeftParen)?;
-            let type_pattern = self.parse_expression()?;
-            let result = self.parse_expression()?;
-            self.consume(TokenKind::RightParen)?;
-
-            clauses.push(Expression::ArrayLiteral(vec![type_pattern, result]));
+            let type_pattern = self.parse_case_keys()?;
+            clauses.push(self.parse_case_clause_body(type_pattern)?);
         }
         self.consume(TokenKind::RightParen)?;
 
@@ -511,6 +603,38 @@ impl SExprParser {
         })
     }
 
+    /// Parse the key position of a case/typecase clause. A parenthesized
+    /// group `(1 2 3)` is never evaluated, so it becomes an array of keys.
+    fn parse_case_keys(&mut self) -> Result<Expression> {
+        if !self.check(&TokenKind::LeftParen) {
+            return self.parse_expression();
+        }
+        self.advance(); // consume '('
+        let mut keys = Vec::new();
+        while !self.check(&TokenKind::RightParen) {
+            keys.push(self.parse_expression()?);
+        }
+        self.consume(TokenKind::RightParen)?;
+        Ok(Expression::ArrayLiteral(keys))
+    }
+
+    /// Parse the result forms of a case/typecase clause up to its closing
+    /// paren, producing `[pattern form1 form2 ...]`
+    fn parse_case_clause_body(&mut self, pattern: Expression) -> Result<Expression> {
+        let mut clause = vec![pattern];
+        while !self.check(&TokenKind::RightParen) {
+            clause.push(self.parse_expression()?);
+        }
+        self.consume(TokenKind::RightParen)?;
+
+        if clause.len() < 2 {
+            return Err(Error::ParseError(
+                "case clause requires at least one result form".to_string(),
+            ));
+        }
+        Ok(Expression::ArrayLiteral(clause))
+    }
+
     /// Parse (const NAME value)
     fn parse_const(&mut self) -> Result<Expression> {
         self.advance(); // consume 'const'
@@ -551,18 +675,144 @@ impl SExprParser {
         };
         self.advance();
 
+        // Optional type annotation: (define x : u64 5) or (define x : {u64 | (< x 10)} 5)
+        let annotation = if self.check(&TokenKind::Colon) {
+            self.advance(); // consume ':'
+            Some(self.parse_define_type(&name)?)
+        } else {
+            None
+        };
+
         let value = self.parse_expression()?;
         self.consume(TokenKind::RightParen)?;
 
+        let mut args = vec![Argument::positional(Expression::Variable(name))];
+        if let Some(type_expr) = annotation {
+            args.push(Argument::positional(Expression::Variable(":".to_string())));
+            args.push(Argument::positional(type_expr));
+        }
+        args.push(Argument::positional(value));
+
         Ok(Expression::ToolCall {
             name: "define".to_string(),
-            args: vec![
-                Argument::positional(Expression::Variable(name)),
-                Argument::positional(value),
-            ],
+            args,
+        })
+    }
+
+    /// Parse the type after `:` in a definition. Besides plain types this
+    /// accepts `{T | predicate}`, a refinement whose bound variable is the
+    /// name being defined, and the explicit `{v : T | predicate}` form.
+    fn parse_define_type(&mut self, bound_name: &str) -> Result<Expression> {
+        if !self.check(&TokenKind::LeftBrace) {
+            return self.parse_expression();
+        }
+        self.advance(); // consume '{'
+
+        let mut var = bound_name.to_string();
+        if let TokenKind::Identifier(name) = &self.peek().kind {
+            let name = name.clone();
+            let saved_pos = self.current;
+            self.advance();
+            if self.check(&TokenKind::Colon) {
+                self.advance(); // consume ':'
+                var = name;
+            } else {
+                self.current = saved_pos;
+            }
+        }
+
+        let base_type = self.parse_expression()?;
+        self.consume(TokenKind::Pipe)?;
+        let predicate = self.parse_expression()?;
+        self.consume(TokenKind::RightBrace)?;
+
+        Ok(Expression::RefinedTypeExpr {
+            var,
+            base_type: Box::new(base_type),
+            predicate: Box::new(predicate),
+        })
+    }
+
+    /// Parse (defpackage :name (:export sym...) ...)
+    /// Option clauses are read as quoted data so their symbols stay unevaluated
+    fn parse_defpackage(&mut self) -> Result<Expression> {
+        self.advance(); // consume 'defpackage'
+
+        let mut args = Vec::new();
+        while !self.check(&TokenKind::RightParen) {
+            if self.is_at_end() {
+                return Err(self.expected_error("`)` to close defpackage", None));
+            }
+            args.push(Argument::positional(self.parse_quoted_datum()?));
+        }
+        self.consume(TokenKind::RightParen)?;
+
+        Ok(Expression::ToolCall {
+            name: "defpackage".to_string(),
+            args,
+        })
+    }
+
+    /// Parse (defgeneric name (params...) options...)
+    fn parse_defgeneric(&mut self) -> Result<Expression> {
+        self.advance(); // consume 'defgeneric'
+
+        let name = self.parse_definition_name("defgeneric", "(defgeneric area (shape))")?;
+        let mut args = vec![Argument::positional(Expression::Variable(name))];
+        while !self.check(&TokenKind::RightParen) {
+            if self.is_at_end() {
+                return Err(self.expected_error("`)` to close defgeneric", None));
+            }
+            args.push(Argument::positional(self.parse_quoted_datum()?));
+        }
+        self.consume(TokenKind::RightParen)?;
+
+        Ok(Expression::ToolCall {
+            name: "defgeneric".to_string(),
+            args,
         })
     }
 
+    /// Parse (defmethod name [qualifier] ((param type) params...) body...)
+    /// The specialized lambda list is read as quoted data
+    fn parse_defmethod(&mut self) -> Result<Expression> {
+        self.advance(); // consume 'defmethod'
+
+        let name = self.parse_definition_name("defmethod", "(defmethod area ((s circle)) body)")?;
+        let mut args = vec![Argument::positional(Expression::Variable(name))];
+        if self.check(&TokenKind::Colon) {
+            args.push(Argument::positional(self.parse_keyword_literal()?));
+        }
+        if !self.check(&TokenKind::LeftParen) {
+            return Err(self.expected_error("method lambda list", None));
+        }
+        args.push(Argument::positional(self.parse_quoted_datum()?));
+        while !self.check(&TokenKind::RightParen) {
+            if self.is_at_end() {
+                return Err(self.expected_error("`)` to close defmethod", None));
+            }
+            args.push(Argument::positional(self.parse_expression()?));
+        }
+        self.consume(TokenKind::RightParen)?;
+
+        Ok(Expression::ToolCall {
+            name: "defmethod".to_string(),
+            args,
+        })
+    }
+
+    /// Read the symbol naming a definition form
+    fn parse_definition_name(&mut self, form: &str, example: &str) -> Result<String> {
+        if let TokenKind::Identifier(n) = &self.peek().kind {
+            let name = n.clone();
+            self.advance();
+            Ok(name)
+        } else {
+            let help = format!("Syntax: {}", example);
+            Err(self.expected_error(&format!("{} name", form), Some(&help)))
+        }
+    }
+
     /// Parse (set! name value)
     fn parse_set(&mut self) -> Result<Expression> {
         self.advance(); // consume 'set!'
@@ -773,6 +1023,82 @@ impl SExprParser {
         })
     }
 
+    /// Whether the `do` at the current position is the iteration form
+    /// `(do ((var init step)...) (end-test result...) body...)`: its first
+    /// argument is a list of clauses or `()`, which no progn-style `do` starts with
+    fn is_do_loop(&self) -> bool {
+        let kind = |offset: usize| self.tokens.get(self.current + offset).map(|t| &t.kind);
+        matches!(kind(1), Some(TokenKind::LeftParen))
+            && matches!(
+                kind(2),
+                Some(TokenKind::LeftParen) | Some(TokenKind::RightParen)
+            )
+    }
+
+    /// Parse (do ((var init step)...) (end-test result...) body...)
+    /// `sequential` selects do* semantics. Produces a `doloop`/`doloop*` call
+    /// whose first two arguments are the clause and end-test lists.
+    fn parse_do_loop(&mut self, sequential: bool) -> Result<Expression> {
+        self.advance(); // consume 'do' / 'do*' / 'doloop'
+
+        let help = "Syntax: (do ((var init step)...) (end-test result...) body...)";
+        self.consume(TokenKind::LeftParen)
+            .map_err(|_| self.expected_error("variable clause list", Some(help)))?;
+        let mut clauses = Vec::new();
+        while !self.check(&TokenKind::RightParen) {
+            let clause = match &self.peek().kind {
+                TokenKind::Identifier(var) => {
+                    let var = var.clone();
+                    self.advance();
+                    vec![Expression::Variable(var)]
+                }
+                TokenKind::LeftParen => {
+                    self.advance();
+                    let var = if let TokenKind::Identifier(var) = &self.peek().kind {
+                        var.clone()
+                    } else {
+                        return Err(self.expected_error("loop variable name", Some(help)));
+                    };
+                    self.advance();
+                    let mut clause = vec![Expression::Variable(var)];
+                    while !self.check(&TokenKind::RightParen) && clause.len() < 3 {
+                        clause.push(self.parse_expression()?);
+                    }
+                    self.consume(TokenKind::RightParen)?;
+                    clause
+                }
+                _ => return Err(self.expected_error("variable clause", Some(help))),
+            };
+            clauses.push(Expression::ArrayLiteral(clause));
+        }
+        self.consume(TokenKind::RightParen)?;
+
+        self.consume(TokenKind::LeftParen)
+            .map_err(|_| self.expected_error("(end-test result...)", Some(help)))?;
+        let mut end = Vec::new();
+        while !self.check(&TokenKind::RightParen) {
+            end.push(self.parse_expression()?);
+        }
+        self.consume(TokenKind::RightParen)?;
+        if end.is_empty() {
+            return Err(self.expected_error("end test", Some(help)));
+        }
+
+        let mut args = vec![
+            Argument::positional(Expression::ArrayLiteral(clauses)),
+            Argument::positional(Expression::ArrayLiteral(end)),
+        ];
+        while !self.check(&TokenKind::RightParen) {
+            args.push(Argument::positional(self.parse_expression()?));
+        }
+        self.consume(TokenKind::RightParen)?;
+
+        Ok(Expression::ToolCall {
+            name: if sequential { "doloop*" } else { "doloop" }.to_string(),
+            args,
+        })
+    }
+
     /// Parse (when condition body...)
     fn parse_when(&mut self) -> Result<Expression> {
         self.advance(); // consume 'when'
@@ -796,6 +1122,33 @@ impl SExprParser {
         })
     }
 
+    /// Parse (with-timeout (seconds timeout-form) body...)
+    ///
+    /// The spec list usually starts with a number, so it is parsed here as a
+    /// two-element array rather than as a call.
+    fn parse_with_timeout(&mut self) -> Result<Expression> {
+        self.advance(); // consume 'with-timeout'
+
+        self.consume(TokenKind::LeftParen)?;
+        let seconds = self.parse_expression()?;
+        let timeout_form = self.parse_expression()?;
+        self.consume(TokenKind::RightParen)?;
+
+        let mut args = vec![Argument::positional(Expression::ArrayLiteral(vec![
+            seconds,
+            timeout_form,
+        ]))];
+        while !self.check(&TokenKind::RightParen) {
+            args.push(Argument::positional(self.parse_expression()?));
+        }
+        self.consume(TokenKind::RightParen)?;
+
+        Ok(Expression::ToolCall {
+            name: "with-timeout".to_string(),
+            args,
+        })
+    }
+
     /// Parse (cond (test result)... (else result))
     fn parse_cond(&mut self) -> Result<Expression> {
         self.advance(); // consume 'cond'
@@ -866,6 +1219,27 @@ impl SExprParser {
             return Ok(operands[0].clone());
         }
 
+        // Comparisons hold pairwise, (< 1 2 3) = (1 < 2) and (2 < 3), which
+        // nesting would get wrong; the evaluator checks each adjacent pair
+        let is_comparison = matches!(
+            op,
+            BinaryOp::Eq
+                | BinaryOp::NotEq
+                | BinaryOp::Lt
+                | BinaryOp::Gt
+                | BinaryOp::LtEq
+                | BinaryOp::GtEq
+        );
+        if is_comparison && operands.len() > 2 {
+            return Ok(Expression::ToolCall {
+                name: op_token.lexeme.clone(),
+                args: operands
+                    .into_iter()
+                    .map(|value| Argument { name: None, value })
+                    .collect(),
+            });
+        }
+
         let mut result = operands[0].clone();
         for operand in operands.iter().skip(1) {
             result = Expression::Binary {
@@ -1007,6 +1381,25 @@ impl SExprParser {
         Ok(Expression::ObjectLiteral(pairs))
     }
 
+    /// Source spelling of an arithmetic operator token
+    fn operator_symbol(kind: &TokenKind) -> &'static str {
+        match kind {
+            TokenKind::Plus => "+",
+            TokenKind::Minus => "-",
+            TokenKind::Star => "*",
+            TokenKind::Slash => "/",
+            TokenKind::Percent => "%",
+            TokenKind::Assign => "=",
+            TokenKind::Eq => "==",
+            TokenKind::NotEq => "!=",
+            TokenKind::Lt => "<",
+            TokenKind::Gt => ">",
+            TokenKind::LtEq => "<=",
+            TokenKind::GtEq => ">=",
+            _ => "",
+        }
+    }
+
     /// Convert token to binary operator
     fn token_to_binary_op(&self, token: &Token) -> Result<BinaryOp> {
         match &token.kind {
@@ -1105,6 +1498,189 @@ impl SExprParser {
         Ok(Expression::TypeAnnotation { expr, type_expr })
     }
 
+    /// Whether the `->` at the current token starts a function type rather
+    /// than a threading form: its first element must be a type name, a type
+    /// constructor such as `(Array u64)`, or a parameter list like `(i64 i64)`.
+    fn is_function_type(&self) -> bool {
+        let kind = |offset: usize| self.tokens.get(self.current + offset).map(|t| &t.kind);
+        let is_type_name = |kind: Option<&TokenKind>| match kind {
+            Some(TokenKind::Identifier(name)) => {
+                crate::types::Type::from_name(name).is_some()
+                    || name.starts_with(|c: char| c.is_ascii_uppercase())
+            }
+            Some(TokenKind::Arrow) | Some(TokenKind::RightParen) => true,
+            _ => false,
+        };
+        match kind(1) {
+            Some(TokenKind::LeftParen) => is_type_name(kind(2)),
+            other => is_type_name(other),
+        }
+    }
+
+    /// Parse (-> x step...) or (->> x step...)
+    ///
+    /// Threading is expanded on tokens: each list step `(f a b)` becomes
+    /// `(f acc a b)` (or `(f a b acc)` for `->>`) and any other step `f`
+    /// becomes `(f acc)`, where acc is the form built so far. The result is
+    /// then parsed like hand-written nested calls.
+    fn parse_threading(&mut self, last: bool) -> Result<Expression> {
+        let head = self.advance(); // consume '->' or '->>'
+
+        let forms = self.form_ranges()?;
+        let Some((initial, steps)) = forms.split_first() else {
+            return Err(Error::ParseError(format!(
+                "`({} ...)` requires an initial value",
+                head.lexeme
+            )));
+        };
+
+        let paren = |kind: TokenKind, lexeme: &str| Self::synthetic_token(kind, lexeme, &head);
+        let mut acc = self.tokens[initial.clone()].to_vec();
+        for step in steps {
+            let tokens = &self.tokens[step.clone()];
+            let mut threaded = vec![paren(TokenKind::LeftParen, "(")];
+            if matches!(tokens[0].kind, TokenKind::LeftParen) && tokens.len() > 2 {
+                // Split `( f args... )` after the function position
+                let args_start = match tokens[1].kind {
+                    TokenKind::LeftParen | TokenKind::LeftBracket | TokenKind::LeftBrace => {
+                        let mut sub_tokens = tokens.to_vec();
+                        sub_tokens.push(paren(TokenKind::Eof, ""));
+                        let mut sub = SExprParser::new(sub_tokens);
+                        sub.current = 1;
+                        sub.parse_expression()?;
+                        sub.current
+                    }
+                    _ => 2,
+                };
+                let args = args_start..tokens.len() - 1;
+                threaded.extend_from_slice(&tokens[1..args.start]);
+                if last {
+                    threaded.extend_from_slice(&tokens[args]);
+                    threaded.append(&mut acc);
+                } else {
+                    threaded.append(&mut acc);
+                    threaded.extend_from_slice(&tokens[args]);
+                }
+            } else {
+                threaded.extend_from_slice(tokens);
+                threaded.append(&mut acc);
+            }
+            threaded.push(paren(TokenKind::RightParen, ")"));
+            acc = threaded;
+        }
+
+        acc.push(paren(TokenKind::Eof, ""));
+        SExprParser::new(acc).parse_expression()
+    }
+
+    /// Parse (cond-> x test form...) - thread x through each form whose test
+    /// is truthy, expanding to
+    /// `(let* ((v x) (v (if test (-> v form) v)) ...) v)`
+    fn parse_cond_threading(&mut self) -> Result<Expression> {
+        let head = self.advance(); // consume 'cond->'
+
+        let forms = self.form_ranges()?;
+        let Some((initial, clauses)) = forms.split_first() else {
+            return Err(Error::ParseError(
+                "`(cond-> ...)` requires an initial value".to_string(),
+            ));
+        };
+        if clauses.len() % 2 != 0 {
+            return Err(Error::ParseError(
+                "`(cond-> x test form ...)` requires test/form pairs".to_string(),
+            ));
+        }
+
+        // A name with a space cannot clash with any symbol in the source
+        let var = Self::synthetic_token(
+            TokenKind::Identifier(" cond->".to_string()),
+            " cond->",
+            &head,
+        );
+        let tok = |kind: TokenKind, lexeme: &str| Self::synthetic_token(kind, lexeme, &head);
+        let open = || tok(TokenKind::LeftParen, "(");
+        let close = || tok(TokenKind::RightParen, ")");
+
+        let mut tokens = vec![
+            open(),
+            tok(TokenKind::Identifier("let*".to_string()), "let*"),
+            open(),
+            open(),
+            var.clone(),
+        ];
+        tokens.extend_from_slice(&self.tokens[initial.clone()]);
+        tokens.push(close());
+        for pair in clauses.chunks(2) {
+            tokens.extend([
+                open(),
+                var.clone(),
+                open(),
+                tok(TokenKind::Identifier("if".to_string()), "if"),
+            ]);
+            tokens.extend_from_slice(&self.tokens[pair[0].clone()]);
+            tokens.extend([open(), tok(TokenKind::Arrow, "->"), var.clone()]);
+            tokens.extend_from_slice(&self.tokens[pair[1].clone()]);
+            tokens.extend([close(), var.clone(), close(), close()]);
+        }
+        tokens.extend([close(), var, close(), tok(TokenKind::Eof, "")]);
+
+        SExprParser::new(tokens).parse_expression()
+    }
+
+    /// Parse (as-> x name form...) - bind name to x, then to each form's
+    /// value in turn, expanding to `(let* ((name x) (name form) ...) name)`
+    fn parse_as_threading(&mut self) -> Result<Expression> {
+        let head = self.advance(); // consume 'as->'
+
+        let forms = self.form_ranges()?;
+        let name = match forms.get(1).map(|range| &self.tokens[range.clone()]) {
+            Some([token]) if matches!(token.kind, TokenKind::Identifier(_)) => token.clone(),
+            _ => {
+                return Err(Error::ParseError(
+                    "`(as-> x name form ...)` requires an initial value and a name".to_string(),
+                ))
+            }
+        };
+
+        let tok = |kind: TokenKind, lexeme: &str| Self::synthetic_token(kind, lexeme, &head);
+        let mut tokens = vec![
+            tok(TokenKind::LeftParen, "("),
+            tok(TokenKind::Identifier("let*".to_string()), "let*"),
+            tok(TokenKind::LeftParen, "("),
+        ];
+        for range in std::iter::once(&forms[0]).chain(&forms[2..]) {
+            tokens.extend([tok(TokenKind::LeftParen, "("), name.clone()]);
+            tokens.extend_from_slice(&self.tokens[range.clone()]);
+            tokens.push(tok(TokenKind::RightParen, ")"));
+        }
+        tokens.extend([
+            tok(TokenKind::RightParen, ")"),
+            name,
+            tok(TokenKind::RightParen, ")"),
+            tok(TokenKind::Eof, ""),
+        ]);
+
+        SExprParser::new(tokens).parse_expression()
+    }
+
+    /// Token ranges of the remaining forms of the current list, consuming
+    /// the closing paren
+    fn form_ranges(&mut self) -> Result<Vec<std::ops::Range<usize>>> {
+        let mut forms = Vec::new();
+        while !self.check(&TokenKind::RightParen) {
+            let start = self.current;
+            self.parse_expression()?;
+            forms.push(start..self.current);
+        }
+        self.consume(TokenKind::RightParen)?;
+        Ok(forms)
+    }
+
+    /// Token for a macro expansion, positioned at the macro's head token
+    fn synthetic_token(kind: TokenKind, lexeme: &str, at: &Token) -> Token {
+        Token::new(kind, lexeme.to_string(), at.line, at.column)
+    }
+
     /// Parse (-> param-types return-type) - function type expression
     /// Syntax:
     ///   (-> ReturnType)                - Unit/void function
@@ -1700,6 +2276,7 @@ impl SExprParser {
             TokenKind::Colon => "`:`".to_string(),
             TokenKind::Quote => "`'`".to_string(),
             TokenKind::Backtick => "`` ` ``".to_string(),
+            TokenKind::SharpQuote => "`#'`".to_string(),
             TokenKind::Comma => "`,`".to_string(),
             TokenKind::CommaAt => "`,@`".to_string(),
             TokenKind::Dot => "`.`".to_string(),
@@ -1747,10 +2324,32 @@ impl SExprParser {
     /// Used in macros for code templates
     fn parse_quasiquote(&mut self) -> Result<Expression> {
         self.consume(TokenKind::Backtick)?;
-        let expr = self.parse_expression()?;
+        let expr = self.parse_quasiquoted_datum()?;
         Ok(Expression::Quasiquote(Box::new(expr)))
     }
 
+    /// Parse a quasiquote template: read as quoted data, except that `,x`
+    /// and `,@x` hold expressions to evaluate
+    fn parse_quasiquoted_datum(&mut self) -> Result<Expression> {
+        match self.peek().kind {
+            TokenKind::Comma => return self.parse_unquote(),
+            TokenKind::CommaAt => return self.parse_unquote_splice(),
+            TokenKind::LeftParen => {}
+            _ => return self.parse_quoted_datum(),
+        }
+
+        self.advance();
+        let mut elements = Vec::new();
+        while !self.check(&TokenKind::RightParen) {
+            if self.is_at_end() {
+                return Err(self.expected_error("`)` to close quasiquoted list", None));
+            }
+            elements.push(self.parse_quasiquoted_datum()?);
+        }
+        self.consume(TokenKind::RightParen)?;
+        Ok(Expression::ArrayLiteral(elements))
+    }
+
     /// Parse an unquote expression ,(...)
     /// Evaluates expression inside quasiquote
     fn parse_unquote(&mut self) -> Result<Expression> {
@@ -1822,7 +2421,7 @@ impl SExprParser {
         })))
     }
 
-    /// Parse: for var from/downfrom/in ...
+    /// Parse: for var from/downfrom/in/across/being ...
     fn parse_loop_for(&mut self) -> Result<IterationClause> {
         self.advance(); // consume 'for'
 
@@ -1832,9 +2431,10 @@ impl SExprParser {
             match keyword.as_str() {
                 "from" => self.parse_numeric_iteration(var, false),
                 "downfrom" => self.parse_numeric_iteration(var, true),
-                "in" => self.parse_collection_iteration(var),
+                "in" | "across" => self.parse_collection_iteration(var),
+                "being" => self.parse_hash_iteration(var),
                 _ => Err(Error::ParseError(format!(
-                    "Expected 'from', 'downfrom', or 'in', got '{}'",
+                    "Expected 'from', 'downfrom', 'in', 'across' or 'being', got '{}'",
                     keyword
                 ))),
             }
@@ -1889,6 +2489,60 @@ impl SExprParser {
         Ok(IterationClause::Collection { var, collection })
     }
 
+    /// Parse: being the/each hash-keys/hash-values of table [using (hash-value/hash-key var)]
+    fn parse_hash_iteration(&mut self, var: String) -> Result<IterationClause> {
+        self.advance(); // consume 'being'
+
+        let mut keyword = self.expect_identifier()?;
+        if keyword == "the" || keyword == "each" {
+            keyword = self.expect_identifier()?;
+        }
+        let values = match keyword.as_str() {
+            "hash-keys" | "hash-key" => false,
+            "hash-values" | "hash-value" => true,
+            _ => {
+                return Err(Error::ParseError(format!(
+                    "Expected 'hash-keys' or 'hash-values', got '{}'",
+                    keyword
+                )))
+            }
+        };
+
+        let of_keyword = self.expect_identifier()?;
+        if !matches!(of_keyword.as_str(), "of" | "in") {
+            return Err(Error::ParseError(format!(
+                "Expected 'of' or 'in', got '{}'",
+                of_keyword
+            )));
+        }
+        let table = Box::new(self.parse_expression()?);
+
+        let using = if self.peek_identifier_str().ok() == Some("using".to_string()) {
+            self.advance(); // consume 'using'
+            self.consume(TokenKind::LeftParen)?;
+            let other = self.expect_identifier()?;
+            let expected = if values { "hash-key" } else { "hash-value" };
+            if other != expected {
+                return Err(Error::ParseError(format!(
+                    "Expected 'using ({} var)', got '{}'",
+                    expected, other
+                )));
+            }
+            let other_var = self.expect_identifier()?;
+            self.consume(TokenKind::RightParen)?;
+            Some(other_var)
+        } else {
+            None
+        };
+
+        Ok(IterationClause::HashTable {
+            var,
+            table,
+            values,
+            using,
+        })
+    }
+
     /// Parse: sum [expr]
     fn parse_loop_sum(&mut self) -> Result<AccumulationClause> {
         self.advance(); // consume 'sum'
@@ -2075,6 +2729,39 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
     }
 
+    #[test]
+    fn test_datum_comment_skips_atom() {
+        let commented = parse_str("(+ 1 #;2 3)").unwrap();
+        assert_eq!(
+            commented.statements,
+            parse_str("(+ 1 3)").unwrap().statements
+        );
+    }
+
+    #[test]
+    fn test_datum_comment_skips_nested_list() {
+        let commented = parse_str("(+ 1 #;(* 2 (- 3 [4 5])) 4)").unwrap();
+        assert_eq!(
+            commented.statements,
+            parse_str("(+ 1 4)").unwrap().statements
+        );
+        let top_level = parse_str("#;(define x 1)\n(define y 2)").unwrap();
+        assert_eq!(
+            top_level.statements,
+            parse_str("(define y 2)").unwrap().statements
+        );
+    }
+
+    #[test]
+    fn test_stacked_datum_comments() {
+        let commented = parse_str("(list #; #; a 'b c #;:d)").unwrap();
+        assert_eq!(
+            commented.statements,
+            parse_str("(list c)").unwrap().statements
+        );
+        assert!(parse_str("(list 1 #;)").is_err());
+    }
+
     #[test]
     fn test_if_expression() {
         let program = parse_str("(if (== x 0) true false)").unwrap();
@@ -2178,6 +2865,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_chained_comparison_is_not_nested() {
+        // Two operands stay a binary comparison
+        let program = parse_str("(< a b)").unwrap();
+        assert!(matches!(
+            program.statements[0],
+            Statement::Expression(Expression::Binary {
+                op: BinaryOp::Lt,
+                ..
+            })
+        ));
+
+        // More become a call the evaluator checks pairwise
+        let program = parse_str("(<= a b c)").unwrap();
+        if let Statement::Expression(Expression::ToolCall { name, args }) = &program.statements[0] {
+            assert_eq!(name, "<=");
+            assert_eq!(args.len(), 3);
+        } else {
+            panic!("Expected ToolCall for chained comparison");
+        }
+
+        // Arithmetic still folds left
+        let program = parse_str("(+ a b c)").unwrap();
+        assert!(matches!(
+            program.statements[0],
+            Statement::Expression(Expression::Binary {
+                op: BinaryOp::Add,
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn test_function_type_syntax() {
         // Test (-> i64 i64) function type
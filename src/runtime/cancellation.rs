@@ -0,0 +1,46 @@
+//! Cooperative cancellation of running evaluations
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shared flag that stops an evaluation at its next function call or loop
+/// iteration
+///
+/// Clones share the flag, so a token handed to another thread can cancel
+/// an evaluator running elsewhere. Once cancelled it stays cancelled.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation of every evaluation using this token
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether `cancel` has been called
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_cancellation() {
+        let token = CancellationToken::new();
+        let remote = token.clone();
+        assert!(!token.is_cancelled());
+
+        remote.cancel();
+        assert!(token.is_cancelled());
+    }
+}
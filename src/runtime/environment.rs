@@ -16,6 +16,15 @@ pub struct Environment {
     dynamic_bindings: Vec<HashMap<String, Value>>,
 }
 
+/// Saved state of an `Environment`, taken by `snapshot` and rolled back
+/// to with `restore`
+#[derive(Debug, Clone)]
+pub struct EnvSnapshot {
+    scopes: Vec<Scope>,
+    constants: Arc<HashMap<String, Value>>,
+    dynamic_bindings: Vec<HashMap<String, Value>>,
+}
+
 /// Single scope in the environment
 #[derive(Debug, Clone)]
 struct Scope {
@@ -157,8 +166,28 @@ impl Environment {
         }
     }
 
-    /// Returns a snapshot of all variables and constants in all scopes
-    pub fn snapshot(&self) -> HashMap<String, Value> {
+    /// Saves the scope stack, constants and dynamic bindings so that
+    /// `restore` can undo every definition and assignment made afterwards
+    ///
+    /// Values are saved as they are bound, so in-place mutation of a shared
+    /// container value is not rolled back.
+    pub fn snapshot(&self) -> EnvSnapshot {
+        EnvSnapshot {
+            scopes: self.scopes.clone(),
+            constants: self.constants.clone(),
+            dynamic_bindings: self.dynamic_bindings.clone(),
+        }
+    }
+
+    /// Rolls the environment back to the state saved in `snapshot`
+    pub fn restore(&mut self, snapshot: &EnvSnapshot) {
+        self.scopes = snapshot.scopes.clone();
+        self.constants = snapshot.constants.clone();
+        self.dynamic_bindings = snapshot.dynamic_bindings.clone();
+    }
+
+    /// Returns all variables and constants in all scopes
+    pub fn bindings(&self) -> HashMap<String, Value> {
         let mut result = HashMap::new();
 
         // Add constants
@@ -176,12 +205,30 @@ impl Environment {
         result
     }
 
+    /// Returns the variables of all non-global scopes, innermost binding winning
+    pub fn local_snapshot(&self) -> HashMap<String, Value> {
+        let mut result = HashMap::new();
+        for scope in &self.scopes[1..] {
+            for (k, v) in &scope.variables {
+                result.insert(k.clone(), v.clone());
+            }
+        }
+        result
+    }
+
+    /// Checks if a variable is bound in any non-global scope
+    pub fn is_local(&self, name: &str) -> bool {
+        self.scopes[1..]
+            .iter()
+            .any(|scope| scope.variables.contains_key(name))
+    }
+
     /// Returns the current environment snapshot for creating closures
     /// This captures all accessible variables from the current point in scope chain
     pub fn current_env_snapshot(&self) -> HashMap<String, Value> {
         // For flet, we want to capture the environment BEFORE entering flet scope
-        // This is the same as the full snapshot
-        self.snapshot()
+        // This is the same as the full set of bindings
+        self.bindings()
     }
 
     /// Checks if a variable or constant exists in any scope
@@ -205,6 +252,17 @@ impl Environment {
         }
     }
 
+    /// Returns the names bound in the innermost scope, sorted
+    pub fn current_scope_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .scopes
+            .last()
+            .map(|scope| scope.variables.keys().cloned().collect())
+            .unwrap_or_default();
+        names.sort();
+        names
+    }
+
     /// Returns the current scope depth (1 for global scope)
     pub fn scope_depth(&self) -> usize {
         self.scopes.len()
@@ -371,16 +429,45 @@ mod tests {
     }
 
     #[test]
-    fn test_snapshot() {
+    fn test_bindings() {
         let mut env = Environment::new();
 
         env.define("x".to_string(), Value::Int(10));
         env.define("y".to_string(), Value::Int(20));
 
+        let bindings = env.bindings();
+        assert_eq!(bindings.len(), 2);
+        assert_eq!(bindings.get("x"), Some(&Value::Int(10)));
+        assert_eq!(bindings.get("y"), Some(&Value::Int(20)));
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let mut env = Environment::new();
+        env.define("x".to_string(), Value::Int(10));
+        env.defvar("*depth*".to_string(), Value::Int(1));
         let snapshot = env.snapshot();
-        assert_eq!(snapshot.len(), 2);
-        assert_eq!(snapshot.get("x"), Some(&Value::Int(10)));
-        assert_eq!(snapshot.get("y"), Some(&Value::Int(20)));
+
+        env.set("x", Value::Int(99)).unwrap();
+        env.define("y".to_string(), Value::Int(20));
+        env.set("*depth*", Value::Int(5)).unwrap();
+        env.define_constant("PI".to_string(), Value::Float(2.5))
+            .unwrap();
+        env.enter_scope();
+        env.define("z".to_string(), Value::Int(30));
+
+        env.restore(&snapshot);
+        assert_eq!(env.get("x").unwrap(), Value::Int(10));
+        assert!(!env.exists("y"));
+        assert!(!env.exists("z"));
+        assert!(env.get("PI").is_err());
+        assert_eq!(env.get("*depth*").unwrap(), Value::Int(1));
+        assert_eq!(env.scope_depth(), 1);
+
+        // A snapshot can be restored more than once
+        env.define("y".to_string(), Value::Int(1));
+        env.restore(&snapshot);
+        assert!(!env.exists("y"));
     }
 
     #[test]
@@ -402,6 +489,19 @@ mod tests {
         assert!(!env.exists("y")); // No longer accessible
     }
 
+    #[test]
+    fn test_current_scope_names() {
+        let mut env = Environment::new();
+        env.define("b".to_string(), Value::Int(1));
+        env.define("a".to_string(), Value::Int(2));
+        assert_eq!(env.current_scope_names(), vec!["a", "b"]);
+
+        env.enter_scope();
+        assert!(env.current_scope_names().is_empty());
+        env.define("c".to_string(), Value::Int(3));
+        assert_eq!(env.current_scope_names(), vec!["c"]);
+    }
+
     #[test]
     fn test_scope_depth() {
         let mut env = Environment::new();
@@ -0,0 +1,160 @@
+//! Values usable as hash keys
+
+use std::hash::{Hash, Hasher};
+
+use crate::error::{Error, Result};
+use crate::runtime::Value;
+
+/// A `Value` restricted to variants with well-defined equality and hashing
+///
+/// Allowed are null, booleans, integers, instants, strings (which also
+/// cover symbols, keywords and characters) and arrays of those, nested to
+/// any depth. Floats are rejected because `NaN != NaN` and `0.0 == -0.0`
+/// break the `Eq`/`Hash` contract; functions and other reference types have
+/// no useful structural identity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashableValue(Value);
+
+impl HashableValue {
+    /// Wraps `value`, or reports the first part of it that cannot be hashed
+    pub fn new(value: Value) -> Result<Self> {
+        Self::check(&value)?;
+        Ok(HashableValue(value))
+    }
+
+    /// The wrapped value
+    pub fn as_value(&self) -> &Value {
+        &self.0
+    }
+
+    /// Unwraps the value
+    pub fn into_value(self) -> Value {
+        self.0
+    }
+
+    fn check(value: &Value) -> Result<()> {
+        match value {
+            Value::Null | Value::Bool(_) | Value::Int(_) | Value::Instant(_) | Value::String(_) => {
+                Ok(())
+            }
+            Value::Array(items) => items.iter().try_for_each(Self::check),
+            other => Err(Error::TypeError {
+                expected: "hashable key (int, string, bool, null, instant or array of those)"
+                    .to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+}
+
+impl Eq for HashableValue {}
+
+impl Hash for HashableValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_value(&self.0, state);
+    }
+}
+
+fn hash_value<H: Hasher>(value: &Value, state: &mut H) {
+    std::mem::discriminant(value).hash(state);
+    match value {
+        Value::Bool(b) => b.hash(state),
+        Value::Int(n) | Value::Instant(n) => n.hash(state),
+        Value::String(s) => s.hash(state),
+        Value::Array(items) => {
+            items.len().hash(state);
+            for item in items.iter() {
+                hash_value(item, state);
+            }
+        }
+        // Null carries no data; other variants are rejected by `new`
+        _ => {}
+    }
+}
+
+impl TryFrom<Value> for HashableValue {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self> {
+        HashableValue::new(value)
+    }
+}
+
+impl From<HashableValue> for Value {
+    fn from(key: HashableValue) -> Self {
+        key.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn hash_of(key: &HashableValue) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn key(value: Value) -> HashableValue {
+        HashableValue::new(value).unwrap()
+    }
+
+    #[test]
+    fn test_equal_values_hash_equally() {
+        let pairs = [
+            (Value::Int(7), Value::Int(7)),
+            (Value::String(":kw".into()), Value::String(":kw".into())),
+            (Value::Bool(true), Value::Bool(true)),
+            (Value::Null, Value::Null),
+            (
+                Value::array(vec![Value::Int(1), Value::String("a".into())]),
+                // A separately allocated but equal array
+                Value::Array(Arc::new(vec![Value::Int(1), Value::String("a".into())])),
+            ),
+        ];
+        for (a, b) in pairs {
+            let (a, b) = (key(a), key(b));
+            assert_eq!(a, b);
+            assert_eq!(hash_of(&a), hash_of(&b));
+        }
+    }
+
+    #[test]
+    fn test_distinct_variants_are_distinct_keys() {
+        assert_ne!(key(Value::Int(1)), key(Value::Instant(1)));
+        assert_ne!(key(Value::Int(1)), key(Value::String("1".into())));
+        assert_ne!(
+            hash_of(&key(Value::array(vec![Value::Int(1), Value::Int(2)]))),
+            hash_of(&key(Value::array(vec![Value::Int(2), Value::Int(1)])))
+        );
+    }
+
+    #[test]
+    fn test_nested_array_key() {
+        let path = || {
+            Value::array(vec![
+                Value::String("accounts".into()),
+                Value::array(vec![Value::Int(0), Value::Bool(false)]),
+            ])
+        };
+        let mut table = HashMap::new();
+        table.insert(key(path()), "found");
+        assert_eq!(table.get(&key(path())), Some(&"found"));
+        assert_eq!(key(path()).into_value(), path());
+    }
+
+    #[test]
+    fn test_float_key_rejected() {
+        assert!(HashableValue::new(Value::Float(1.5)).is_err());
+        assert!(HashableValue::try_from(Value::array(vec![
+            Value::Int(1),
+            Value::array(vec![Value::Float(f64::NAN)]),
+        ]))
+        .is_err());
+        assert!(HashableValue::new(Value::Object(Arc::new(HashMap::new()))).is_err());
+    }
+}
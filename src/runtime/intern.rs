@@ -0,0 +1,104 @@
+//! String interning for repeated keys and symbols
+//!
+//! Deduplicates strings into shared `Arc<str>` instances so that equal
+//! strings come from a single allocation and can be compared by pointer.
+//! Object keys are `Arc<str>`, so every object built from a literal or
+//! decoded by `parse-json` holds the pooled instance of each key instead of
+//! its own copy; `(intern-stats)` reports how much repetition was folded.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Strings longer than this are not worth pooling
+pub const MAX_INTERNED_LEN: usize = 64;
+
+/// Pool of interned strings with hit/miss counters
+#[derive(Debug, Default, Clone)]
+pub struct Interner {
+    pool: HashSet<Arc<str>>,
+    hits: u64,
+    misses: u64,
+}
+
+/// Snapshot of interner usage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InternStats {
+    /// Distinct strings held by the pool
+    pub unique: usize,
+    /// Lookups answered by an existing entry
+    pub hits: u64,
+    /// Lookups that added a new entry
+    pub misses: u64,
+}
+
+impl Interner {
+    /// Creates an empty interner
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared instance for `s`, adding it on first use
+    /// Strings over `MAX_INTERNED_LEN` bytes get a fresh, unpooled allocation
+    pub fn intern(&mut self, s: &str) -> Arc<str> {
+        if s.len() > MAX_INTERNED_LEN {
+            return Arc::from(s);
+        }
+        if let Some(existing) = self.pool.get(s) {
+            self.hits += 1;
+            return existing.clone();
+        }
+        self.misses += 1;
+        let shared: Arc<str> = Arc::from(s);
+        self.pool.insert(shared.clone());
+        shared
+    }
+
+    /// Whether two interned strings are the same instance (pointer equality)
+    pub fn same(a: &Arc<str>, b: &Arc<str>) -> bool {
+        Arc::ptr_eq(a, b)
+    }
+
+    /// Current usage counters
+    pub fn stats(&self) -> InternStats {
+        InternStats {
+            unique: self.pool.len(),
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_strings_share_one_instance() {
+        let mut interner = Interner::new();
+        let first = interner.intern("lamports");
+        let copies: Vec<_> = (0..10_000).map(|_| interner.intern("lamports")).collect();
+
+        assert!(copies.iter().all(|c| Interner::same(c, &first)));
+        // pool + `first` + 10k copies
+        assert_eq!(Arc::strong_count(&first), 10_002);
+        assert_eq!(
+            interner.stats(),
+            InternStats {
+                unique: 1,
+                hits: 10_000,
+                misses: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_long_strings_are_not_pooled() {
+        let mut interner = Interner::new();
+        let long = "x".repeat(MAX_INTERNED_LEN + 1);
+        let a = interner.intern(&long);
+        let b = interner.intern(&long);
+
+        assert!(!Interner::same(&a, &b));
+        assert_eq!(interner.stats().unique, 0);
+    }
+}
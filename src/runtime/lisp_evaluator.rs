@@ -3,11 +3,13 @@ use crate::parser::{
     AccumulationClause, BinaryOp, ConditionClause, ExitClause, Expression, IterationClause,
     LoopData, Program, Statement, UnaryOp,
 };
-use crate::runtime::{Environment, Value};
-use crate::tools::ToolRegistry;
+use crate::runtime::{CancellationToken, Environment, LazyState, LazyThunk, Value};
+use crate::tools::stdlib::STDLIB_PACKAGE;
+use crate::tools::{ScriptRunner, ScriptTool, ToolRegistry};
 use base64::Engine;
 use sha2::{Digest, Sha256, Sha512};
 use std::collections::HashMap;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 
 /// LISP-specific evaluator that handles special forms
@@ -30,6 +32,735 @@ pub struct LispEvaluator {
     lazy_field_config: std::cell::RefCell<LazyFieldConfig>,
     /// Execution trace for debugging (variable_name -> value)
     execution_trace: std::cell::RefCell<Vec<(String, Value)>>,
+    /// Pooled HTTP client shared by all network builtins
+    http_client: reqwest::Client,
+    /// Whether the next expression evaluated is in tail position of a function body
+    tail_position: bool,
+    /// Tail call deferred to the enclosing `call_function` trampoline (func, args, name)
+    pending_tail_call: Option<(Value, Vec<Value>, String)>,
+    /// Integer overflow behavior for `+`, `-`, `*` (set by `with-overflow-mode`)
+    overflow_mode: OverflowMode,
+    /// Packages created by `defpackage`, mapped to their exported symbols
+    packages: HashMap<String, std::collections::HashSet<String>>,
+    /// Package selected by `in-package` (None for the base `cl-user` package)
+    current_package: Option<String>,
+    /// Methods registered on each generic function
+    generic_methods: HashMap<String, Vec<GenericMethod>>,
+    /// Remaining methods for each active generic call, used by `call-next-method`
+    next_methods: Vec<MethodChain>,
+    /// Pool of object keys shared by literals and parsed JSON, reported by `intern-stats`
+    interner: crate::runtime::intern::Interner,
+    /// Refinement annotations from `(define x : {T | pred} v)`, by variable name
+    refinements: HashMap<String, Expression>,
+    /// Whether refinement predicates are enforced (inside `with-refinement-checks`)
+    refinement_checks: bool,
+    /// Bytes of arrays and strings one `execute` may allocate in total
+    /// (None = unlimited)
+    allocation_budget: Option<usize>,
+    /// Approximate bytes allocated so far by the current `execute`
+    bytes_allocated: usize,
+    /// Checked at function calls and loop iterations to stop evaluation
+    cancellation: CancellationToken,
+    /// Deadline set by `execute_with_timeout`, with the timeout it came from
+    deadline: Option<(std::time::Instant, std::time::Duration)>,
+    /// Destination of `print`, `println`, `log` and `(format t ...)` output
+    /// (None = stdout)
+    output_sink: Option<Box<dyn std::io::Write + Send>>,
+    /// Buffers of the enclosing `with-output-capture` forms, innermost last
+    output_captures: Vec<String>,
+    /// Number of loop bodies being run, so `break`/`continue` outside one error
+    loop_depth: usize,
+    /// Number of `with-vectorization` forms being run; while nonzero,
+    /// arithmetic and the math builtins broadcast over arrays
+    vectorize_depth: usize,
+    /// Pool that `make-thread` submits to (None = one OS thread per call);
+    /// shared with the evaluators of the threads started here
+    thread_pool: Option<Arc<crate::runtime::threading::ThreadPool>>,
+}
+
+/// Role of a method in standard method combination
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MethodQualifier {
+    Primary,
+    Before,
+    After,
+    Around,
+}
+
+/// A method registered with `defmethod`
+#[derive(Clone, Debug)]
+struct GenericMethod {
+    qualifier: MethodQualifier,
+    /// Type of the first argument this method applies to (`t` matches anything)
+    specializer: String,
+    function: Value,
+}
+
+/// Applicable methods of a generic call that have not run yet, most specific first
+#[derive(Clone, Debug)]
+struct MethodChain {
+    name: String,
+    args: Vec<Value>,
+    around: Vec<Value>,
+    before: Vec<Value>,
+    primary: Vec<Value>,
+    after: Vec<Value>,
+}
+
+/// Names accepted for the base package that holds the builtins
+const BASE_PACKAGES: &[&str] = &["cl", "cl-user", "common-lisp", "common-lisp-user"];
+
+/// Longest uninterrupted slice of a `sleep`, `channel-send` or
+/// `channel-receive`, bounding how late cancellation and timeouts are noticed
+const SLEEP_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// Sole key of the `{:cbor-bytes "hex"}` object that stands for a CBOR byte
+/// string, so decoded bytes encode back as bytes rather than text
+const CBOR_BYTES_KEY: &str = "cbor-bytes";
+
+/// Arithmetic operators, which evaluate outside the builtin table but are
+/// designators like the names in it
+const OPERATOR_NAMES: &[&str] = &["%", "*", "+", "-", "/"];
+
+/// Defines the builtin dispatch and the list of names it handles from one
+/// table of match arms, so the two cannot drift apart
+macro_rules! builtins {
+    (
+        $(#[$meta:meta])*
+        fn $dispatch:ident(&mut $self:ident, $name:ident, $args:ident, $tail:ident);
+        $(#[$names_meta:meta])*
+        const $names:ident;
+        $($($lit:literal)|+ $(if $guard:expr)? => $body:expr),* $(,)?
+    ) => {
+        impl LispEvaluator {
+            $(#[$names_meta])*
+            const $names: &'static [&'static str] = &[$($($lit),+),*];
+
+            $(#[$meta])*
+            fn $dispatch(
+                &mut $self,
+                $name: &str,
+                $args: &[crate::parser::Argument],
+                $tail: bool,
+            ) -> Option<Result<Value>> {
+                Some(match $name {
+                    $($($lit)|+ $(if $guard)? => $body,)*
+                    _ => return None,
+                })
+            }
+        }
+    };
+}
+
+builtins! {
+    /// Evaluate a special form or builtin call, or None if `name` is neither
+    fn dispatch_builtin(&mut self, name, args, tail);
+    /// Names handled by `dispatch_builtin`
+    const BUILTIN_NAMES;
+
+    "set!" => self.eval_set(args),
+    "setf" => self.eval_setf(args),
+    "define" => self.eval_define(args),
+    "defpackage" => self.eval_defpackage(args),
+    "in-package" => self.eval_in_package(args),
+    "defun" => self.eval_defun(args),
+    "defn" => self.eval_defun(args), // Alias for defun
+    "defmacro" => self.eval_defmacro(args),
+    "defgeneric" => self.eval_defgeneric(args),
+    "defmethod" => self.eval_defmethod(args),
+    "%generic-dispatch" => self.eval_generic_dispatch(args),
+    "call-next-method" => self.eval_call_next_method(args),
+    "next-method-p" => self.eval_next_method_p(args),
+    "const" => self.eval_const(args),
+    "let" => self.eval_let(args, tail),
+    "let*" => self.eval_let_star(args),
+    "flet" => self.eval_flet(args),
+    "labels" => self.eval_labels(args),
+    "case" | "ecase" | "ccase" => self.eval_case(args, name),
+    "typecase" => self.eval_typecase(args),
+    "while" => self.eval_while(args),
+    "break" => self.eval_break(args),
+    "continue" => self.eval_continue(args),
+    "for" => self.eval_for(args),
+    "do" => self.eval_do(args, tail),
+    "progn" => self.eval_do(args, tail), // progn is same as do
+    "doloop" => self.eval_do_loop(args, false),
+    "doloop*" => self.eval_do_loop(args, true),
+    "prog1" => self.eval_prog1(args),
+    "prog2" => self.eval_prog2(args),
+    "when" => self.eval_when(args),
+    "unless" => self.eval_unless(args),
+    "if-let" | "when-let" | "when-some" => {
+        self.eval_binding_conditional(name, args, tail)
+    },
+    "cond" => self.eval_cond(args, tail),
+    "not" => self.eval_not(args),
+    "and" => self.eval_and(args),
+    "or" => self.eval_or(args),
+    "null?" => self.eval_null_check(args),
+    "empty?" => self.eval_empty_check(args),
+    "equal?" | "equal" => self.eval_equal(args, false),
+    "equalp" => self.eval_equal(args, true),
+    // Type predicates
+    "int?" => self.eval_int_check(args),
+    "float?" => self.eval_float_check(args),
+    "number?" => self.eval_number_check(args),
+    "string?" => self.eval_string_check(args),
+    "bool?" => self.eval_bool_check(args),
+    "array?" => self.eval_array_check(args),
+    "list?" => self.eval_array_check(args), // Common LISP: list? is same as array?
+    "object?" => self.eval_object_check(args),
+    "function?" => self.eval_function_check(args),
+    // Generic type checking (Python/JS style)
+    "typeof" => self.eval_typeof(args), // JS: typeof value
+    "type-of" => self.eval_typeof(args), // LISP: type-of
+    // Environment introspection (REPL tooling)
+    "bound?" => self.eval_bound_check(args),
+    "list-bindings" => self.eval_list_bindings(args),
+    // `describe` is a common user function name; a user definition wins
+    "describe" if !self.env.exists("describe") => self.eval_describe(args),
+    // Number predicates (Common LISP style)
+    "even?" => self.eval_even(args), // (even? 4) -> true
+    "evenp" => self.eval_even(args), // Common LISP: evenp
+    "odd?" => self.eval_odd(args),   // (odd? 3) -> true
+    "oddp" => self.eval_odd(args),   // Common LISP: oddp
+    "positive?" => self.eval_positive(args), // (positive? 5) -> true
+    "positivep" => self.eval_positive(args), // Common LISP
+    "negative?" => self.eval_negative(args), // (negative? -5) -> true
+    "negativep" => self.eval_negative(args), // Common LISP
+    "zero?" => self.eval_zero(args), // (zero? 0) -> true
+    "zerop" => self.eval_zero(args), // Common LISP: zerop
+    // Type conversions (AI compatibility - Python/JS style)
+    "int" => self.eval_to_int(args), // Python: int("42") -> 42
+    "integer" => self.eval_to_int(args), // Alias
+    "parse-int" => self.eval_to_int(args), // JS: parseInt("42")
+    "parseint" => self.eval_to_int(args), // JS: parseInt (lowercase)
+    "float" => self.eval_to_float(args), // Python: float("3.14") -> 3.14
+    "parse-float" => self.eval_to_float(args), // JS: parseFloat("3.14")
+    "parsefloat" => self.eval_to_float(args), // JS: parseFloat (lowercase)
+    "bool" => self.eval_to_bool(args), // Python: bool("true") -> True
+    "parse-number" => self.eval_parse_number(args), // Auto-detects int/float/radix
+    "coerce" => self.eval_coerce(args), // Common LISP: (coerce x 'float)
+    // Assertions
+    "assert" => self.eval_assert(args),
+    "assert-type" => self.eval_assert_type(args),
+    // Cryptography and encoding
+    "base58-decode" => self.eval_base58_decode(args),
+    "base58-encode" => self.eval_base58_encode(args),
+    "base64-decode" => self.eval_base64_decode(args),
+    "base64-decode-raw" => self.eval_base64_decode_raw(args),
+    "base64-encode" => self.eval_base64_encode(args),
+    "hex-decode" => self.eval_hex_decode(args),
+    "hex-encode" => self.eval_hex_encode(args),
+    "sha256" => self.eval_sha256(args),
+    "sha512" => self.eval_sha512(args),
+    // Binary/byte operations for Borsh decoding
+    "byte-at" => self.eval_byte_at(args),
+    "parse-u64-le" => self.eval_parse_u64_le(args),
+    "hex-to-u64-le" => self.eval_hex_to_u64_le(args),
+    "bytes-to-hex" => self.eval_bytes_to_hex(args),
+    // Error handling
+    "try" => self.eval_try(args),
+    "error" => self.eval_error(args),
+    "ignore-errors" => self.eval_ignore_errors(args),
+    "with-timeout" => self.eval_with_timeout(args),
+    // String operations
+    "split" => self.eval_split(args),
+    "join" => self.eval_join(args),
+    "replace" => self.eval_replace(args),
+    "trim" => self.eval_trim(args),
+    "upper" => self.eval_upper(args),
+    "lower" => self.eval_lower(args),
+    "<" | ">" | "<=" | ">=" | "=" | "==" | "!=" => {
+        self.eval_comparison_chain(name, args)
+    },
+    "with-vectorization" => self.eval_with_vectorization(args),
+    // Advanced math
+    "sqrt" => self.eval_sqrt(args),
+    "pow" => self.eval_pow(args),
+    "expt" => self.eval_pow(args), // Common Lisp alias for pow
+    "exp" => self.eval_exp(args),  // e^x
+    "ln" => self.eval_ln(args),    // Natural logarithm
+    "abs" => self.eval_abs(args),
+    // Common names in older scripts; a user function of the same name wins
+    "complex" | "realpart" | "imagpart" if self.env.exists(name) => {
+        self.eval_tool_call(name, args, tail)
+    },
+    "complex" => self.eval_complex(args),
+    "realpart" => self.eval_complex_part(args, "realpart"),
+    "imagpart" => self.eval_complex_part(args, "imagpart"),
+    // Common Lisp arithmetic shortcuts
+    "1+" => self.eval_1_plus(args),
+    "1-" => self.eval_1_minus(args),
+    "mod" => self.eval_mod(args),
+    "rem" => self.eval_rem(args),
+    "gcd" => self.eval_gcd(args),
+    "lcm" => self.eval_lcm(args),
+    // Common Lisp list predicates
+    "atom" => self.eval_atom(args),
+    "consp" => self.eval_consp(args),
+    "listp" => self.eval_listp(args),
+    // Common Lisp bitwise operations
+    "logand" => self.eval_logand(args),
+    "logior" => self.eval_logior(args),
+    "logxor" => self.eval_logxor(args),
+    "lognot" => self.eval_lognot(args),
+    "ash" => self.eval_ash(args),
+    // Common Lisp list operations
+    "member" => self.eval_member(args),
+    "assoc" => self.eval_assoc(args, false),
+    "rassoc" => self.eval_assoc(args, true),
+    "assoc-in" => self.eval_assoc_in(args), // Set key or nested path in object
+    "get-in" => self.eval_get_in(args),
+    "update-in" => self.eval_update_in(args),
+    "set-key" => self.eval_assoc_in(args), // Alias for assoc-in
+    "set" => self.eval_object_set(args),   // set(obj, key, value) - like JS/Python
+    "elt" => self.eval_elt(args),
+    "subseq" => self.eval_subseq(args),
+    // Common Lisp string comparisons
+    "string=" => self.eval_string_eq(args),
+    "string<" => self.eval_string_lt(args),
+    "string>" => self.eval_string_gt(args),
+    "string-equal" => self.eval_string_equal(args), // Case-insensitive
+    "string-lessp" => self.eval_string_lt(args),    // Alternative name
+    "string-greaterp" => self.eval_string_gt(args), // Alternative name
+    // Common Lisp map variants
+    "mapcar" => self.eval_mapcar(args),
+    "mapc" => self.eval_mapc(args),
+    // Common Lisp conditional filters
+    "remove-if" => self.eval_remove_if(args),
+    "remove-if-not" => self.eval_remove_if_not(args),
+    // Common Lisp variable mutation
+    "push" | "pop" | "pushnew" if self.env.exists(name) => {
+        self.eval_tool_call(name, args, tail)
+    },
+    "push" => self.eval_push(args, false),
+    "pushnew" => self.eval_push(args, true),
+    "pop" => self.eval_pop(args),
+    "incf" => self.eval_incf(args),
+    "decf" => self.eval_decf(args),
+    // Trigonometric functions
+    "sin" => self.eval_sin(args),
+    "cos" => self.eval_cos(args),
+    "tan" => self.eval_tan(args),
+    "asin" => self.eval_asin(args),
+    "acos" => self.eval_acos(args),
+    "atan" => self.eval_atan(args),
+    "atan2" => self.eval_atan2(args),
+    // Rounding functions
+    "floor" => self.eval_floor(args),
+    "ceiling" => self.eval_ceiling(args),
+    "ceil" => self.eval_ceiling(args), // Alias
+    "round" => self.eval_round(args),
+    "truncate" => self.eval_truncate(args),
+    "trunc" => self.eval_truncate(args), // Alias
+    // Multiple values (Common Lisp style)
+    "values" => self.eval_values(args),
+    "multiple-value-bind" => self.eval_multiple_value_bind(args),
+    "multiple-value-call" => self.eval_multiple_value_call(args),
+    "nth-value" => self.eval_nth_value(args),
+    // Dynamic variables (Common Lisp special variables)
+    "defvar" => self.eval_defvar(args),
+    // Macro system
+    "gensym" => self.eval_gensym(args),
+    "macroexpand-1" => self.eval_macroexpand(args, MacroexpandMode::Once),
+    "macroexpand" => self.eval_macroexpand(args, MacroexpandMode::TopLevel),
+    "macroexpand-all" => self.eval_macroexpand(args, MacroexpandMode::All),
+    "eval" => self.eval_eval(args),
+    "read-from-string" | "read" if self.env.exists(name) => {
+        self.eval_tool_call(name, args, tail)
+    },
+    "read-from-string" => self.eval_read(args, "read-from-string"),
+    "read" => self.eval_read(args, "read"),
+    "compile" => self.eval_compile(args),
+    "length" => self.eval_length(args),
+    "count" => self.eval_length(args), // Alias for length - commonly expected
+    "last" => self.eval_last(args),
+    "range" => self.eval_range(args),
+    "iota" => self.eval_iota(args),
+    "linspace" => self.eval_linspace(args),
+    "min" if Self::has_key_option(args) => {
+        self.eval_extremum_key(args, "min", std::cmp::Ordering::Less)
+    },
+    "max" if Self::has_key_option(args) => {
+        self.eval_extremum_key(args, "max", std::cmp::Ordering::Greater)
+    },
+    "min" => self.eval_min(args),
+    "max" => self.eval_max(args),
+    "min-by" => self.eval_extremum_by(args, "min-by", std::cmp::Ordering::Less),
+    "max-by" => self.eval_extremum_by(args, "max-by", std::cmp::Ordering::Greater),
+    // Statistical functions (Python/NumPy style)
+    "mean" => self.eval_mean(args),         // Average
+    "average" => self.eval_mean(args),      // Alias
+    "avg" => self.eval_mean(args),          // SQL-style
+    "median" => self.eval_median(args),     // Median value
+    "mode" => self.eval_mode(args),         // Most common value
+    "product" => self.eval_product(args),   // Product of numbers
+    "std" => self.eval_stddev(args),        // Standard deviation
+    "stddev" => self.eval_stddev(args),     // Alias
+    "variance" => self.eval_variance(args), // Variance
+    "percentile" => self.eval_quantile(args, "percentile"),
+    "quantile" => self.eval_quantile(args, "quantile"),
+    "covariance" => self.eval_covariance(args),
+    "correlation" => self.eval_correlation(args), // Pearson
+    "weighted-mean" => self.eval_weighted_mean(args),
+    "cumulative-sum" => self.eval_cumulative_sum(args),
+    "moving-average" => self.eval_moving_average(args),
+    // Math utilities
+    "sign" => self.eval_sign(args), // Sign of number (-1, 0, 1)
+    "clamp" => self.eval_clamp(args), // Clamp between min/max
+    "lerp" => self.eval_lerp(args), // Linear interpolation
+    "map-range" => self.eval_map_range(args), // Rescale between ranges
+    "random" => self.eval_random(args), // Random number
+    "now" => self.eval_now(args),
+    "parse-date" => self.eval_parse_date(args),
+    "format-date" => self.eval_format_date(args),
+    "date-add" => self.eval_date_add(args),
+    "date-diff" => self.eval_date_diff(args),
+    "sleep" => self.eval_sleep(args),
+    "time" => self.eval_time(args),
+    "with-overflow-mode" => self.eval_with_overflow_mode(args),
+    "with-refinement-checks" => self.eval_with_refinement_checks(args),
+    "measure-time" => self.eval_measure_time(args),
+    "log" => self.eval_log(args),
+    "print" => self.eval_print(args), // Python/JS-style output
+    "println" => self.eval_println(args), // Python/JS-style output with newline
+    "with-output-capture" => self.eval_with_output_capture(args),
+    "map" => self.eval_map(args),
+    "pmap" => self.eval_pmap(args), // Parallel map
+    "filter" => self.eval_filter(args),
+    "reduce" => self.eval_reduce(args),
+    "sort" => self.eval_sort(args),
+    "group-by" => self.eval_group_by(args),
+    "aggregate" => self.eval_aggregate(args),
+    "sort-by" => self.eval_sort_by(args),
+    "str" => self.eval_str(args),
+    "dbg" => self.eval_dbg(args),
+    "prin1" | "princ" | "write" if self.env.exists(name) => {
+        self.eval_tool_call(name, args, tail)
+    },
+    "prin1" | "write" => self.eval_write_value(args, name, true),
+    "princ" => self.eval_write_value(args, name, false),
+    "prin1-to-string" | "write-to-string" => {
+        self.eval_value_to_string(args, name, true)
+    },
+    "princ-to-string" => self.eval_value_to_string(args, name, false),
+    "format" => self.eval_format(args),
+    "format-number" => self.eval_format_number(args),
+    "slice" => self.eval_slice(args),
+    "keys" => self.eval_keys(args),
+    "object-values" => self.eval_object_values(args), // Python: dict.values()
+    "object-entries" => self.eval_object_entries(args), // Python: dict.items()
+    "entries" => self.eval_object_entries(args),      // JS: Object.entries()
+    "items" => self.eval_object_entries(args),        // Python: dict.items()
+    "merge" => self.eval_merge(args),
+    "merge-with" => self.eval_merge_with(args),
+    "map-values" => self.eval_map_values(args),
+    "filter-keys" => self.eval_filter_keys(args),
+    "reduce-kv" => self.eval_reduce_kv(args),
+    "put" => self.eval_put(args), // Set object property: (put obj "key" val)
+    "get" => self.eval_get(args),
+    "get-path" => self.eval_get_path(args),
+    "discover" => self.eval_discover(args),
+    "lazy-config" => self.eval_lazy_config(args),
+    "first" => self.eval_first(args),
+    "head" => self.eval_first(args), // Alias for first (Haskell-style)
+    "rest" => self.eval_rest(args),
+    "tail" => self.eval_rest(args), // Alias for rest (Haskell-style)
+    "init" => self.eval_init(args), // All but last (Haskell-style)
+    "shift" => self.eval_shift(args), // Remove first element (JS-style)
+    "unshift" => self.eval_unshift(args), // Add to front (JS-style)
+    "nth" => self.eval_nth(args),
+    "aref" => self.eval_aref(args),
+    "gethash" => self.eval_gethash(args),
+    "cons" => self.eval_cons(args),
+    "list" => self.eval_list(args),
+    "append" => self.eval_append(args),
+    "concat" => self.eval_concatenate(args), // Alias for concatenate
+    "concatenate" => self.eval_concatenate(args), // Polymorphic concat
+    "copy-seq" => self.eval_copy(args, false),
+    "copy-tree" => self.eval_copy(args, true),
+    // JSON operations (built-ins, not MCP tools!)
+    "parse-json" => self.eval_parse_json(args),
+    "json-stringify" => self.eval_json_stringify(args),
+    "intern-stats" => self.eval_intern_stats(args),
+    // CBOR operations (binary data travels as hex strings)
+    "cbor-encode" => self.eval_cbor_encode(args),
+    "cbor-decode" => self.eval_cbor_decode(args),
+    // Network operations (async)
+    "http-get" => self.eval_http_get(args),
+    "http-post" => self.eval_http_post(args),
+    "json-rpc" => self.eval_json_rpc(args),
+    "json-rpc-batch" => self.eval_json_rpc_batch(args),
+    // LLM operations (AI-powered agents)
+    "llm-query" => self.eval_llm_query(args),
+    // Streaming operations (real-time blockchain events)
+    "stream-connect" => self.eval_stream_connect(args),
+    "stream-poll" => self.eval_stream_poll(args),
+    "stream-wait" => self.eval_stream_wait(args),
+    "stream-close" => self.eval_stream_close(args),
+    "osvm-stream" => self.eval_osvm_stream(args),
+    // Async execution
+    "async" => self.eval_async(args),
+    "await" => self.eval_await(args),
+    // LINQ-style functional operations
+    "compact" => self.eval_compact(args),
+    "count-by" => self.eval_count_by(args),
+    "frequencies" => self.eval_frequencies(args),
+    "distinct" => self.eval_distinct(args),
+    "unique" => self.eval_distinct(args), // Alias for distinct (SQL-style)
+    "drop" => self.eval_drop(args),
+    "every" => self.eval_every(args),
+    "all" => self.eval_every(args), // Alias for every (JavaScript-style)
+    "find" => self.eval_find(args),
+    "find-index" => self.eval_find_index(args), // Find index matching predicate
+    "indexof" => self.eval_indexof(args),       // JS-style indexOf
+    "index-of" => self.eval_indexof(args),      // Lisp-style index-of
+    "contains" => self.eval_contains(args),     // Python-style contains
+    "string-contains" => self.eval_contains(args), // Explicit string-contains
+    "elem" => self.eval_contains(args),         // Haskell-style elem
+    "remove" => self.eval_remove(args),         // Remove element by value
+    "insert-at" => self.eval_insert_at(args),   // Insert at index
+    "flatten" => self.eval_flatten(args),
+    // "group-by" already handled above on line 186
+    "partition" => self.eval_partition(args),
+    "partition-all" => self.eval_partition_all(args),
+    "sliding-window" => self.eval_sliding_window(args),
+    "interpose" => self.eval_interpose(args),
+    "interleave" => self.eval_interleave(args),
+    "pluck" => self.eval_pluck(args),
+    "reverse" => self.eval_reverse(args),
+    "repeat" => self.eval_repeat(args), // Python: "x"*3, JS: "x".repeat(3)
+    "some" => self.eval_some(args),
+    "any" => self.eval_some(args), // Alias for some (JavaScript-style)
+    "take" => self.eval_take(args),
+    "lazy-seq" => self.eval_lazy_seq(args),
+    "lazy-map" => self.eval_lazy_map(args, false),
+    "lazy-filter" => self.eval_lazy_map(args, true),
+    "zip" => self.eval_zip(args),
+    "transpose" => self.eval_transpose(args),
+    // String predicates (Python str methods)
+    "isdigit?" => self.eval_isdigit(args),
+    "is-digit?" => self.eval_isdigit(args),
+    "isalpha?" => self.eval_isalpha(args),
+    "is-alpha?" => self.eval_isalpha(args),
+    "isalnum?" => self.eval_isalnum(args),
+    "is-alnum?" => self.eval_isalnum(args),
+    "isspace?" => self.eval_isspace(args),
+    "is-space?" => self.eval_isspace(args),
+    "blank?" => self.eval_blank(args),
+    // Functional programming utilities
+    "apply" => self.eval_apply(args),
+    "funcall" => self.eval_funcall(args),
+    "register-tool" => self.eval_register_tool(args),
+    "function" => self.eval_function_ref(args),
+    "compose" => self.eval_compose(args),
+    "pipe" => self.eval_pipe(args),
+    "partial" => self.eval_partial(args),
+    "memoize" => self.eval_memoize(args),
+    // Regex operations
+    "regex-match" => self.eval_regex_match(args),
+    "regex-replace" => self.eval_regex_replace(args),
+    "regex-split" => self.eval_regex_split(args),
+    "regex-find-all" => self.eval_regex_find_all(args),
+
+    // HIGH PRIORITY ALIASES - Python/JavaScript compatibility
+    "len" => self.eval_length(args), // Python len()
+    "includes" => self.eval_contains(args), // JavaScript includes()
+    "toLowerCase" | "tolowercase" => self.eval_to_lower_case(args), // JavaScript
+    "toUpperCase" | "touppercase" => self.eval_to_upper_case(args), // JavaScript
+    "charAt" | "charat" => self.eval_char_at(args), // JavaScript charAt()
+    "chr" => self.eval_chr(args),    // Python chr()
+    "ord" => self.eval_ord(args),    // Python ord()
+    "substring" => self.eval_substring(args), // JavaScript substring()
+
+    // MEDIUM PRIORITY ALIASES - LISP/Haskell compatibility
+    "cdr" => self.eval_rest(args), // LISP cdr
+    "foldl" | "fold-left" => self.eval_reduce(args), // Haskell foldl
+    "foldr" | "fold-right" => self.eval_reduce(args), // Haskell foldr
+    "lastIndexOf" | "lastindexof" => self.eval_last_index_of(args), // JavaScript
+
+    // ============================================================
+    // BORDEAUX THREADS - Portable shared-state concurrency
+    // https://github.com/sionescu/bordeaux-threads
+    // ============================================================
+
+    // Thread operations
+    "make-thread" | "bt:make-thread" => self.eval_make_thread(args),
+    "current-thread" | "bt:current-thread" => self.eval_current_thread(args),
+    "all-threads" | "bt:all-threads" => self.eval_all_threads(args),
+    "thread-name" | "bt:thread-name" => self.eval_thread_name(args),
+    "threadp" | "thread?" | "bt:threadp" => self.eval_threadp(args),
+    "thread-alive-p" | "thread-alive?" | "bt:thread-alive-p" => {
+        self.eval_thread_alive_p(args)
+    },
+    "join-thread" | "bt:join-thread" => self.eval_join_thread(args),
+    "thread-yield" | "bt:thread-yield" => self.eval_thread_yield(args),
+    "set-thread-pool-size" => self.eval_set_thread_pool_size(args),
+    "thread-pool-size" => Ok(Value::Int(
+        self.thread_pool.as_ref().map_or(0, |pool| pool.size()) as i64,
+    )),
+
+    // Lock operations
+    "make-lock" | "bt:make-lock" => self.eval_make_lock(args),
+    "lockp" | "lock?" | "bt:lockp" => self.eval_lockp(args),
+    "acquire-lock" | "bt:acquire-lock" => self.eval_acquire_lock(args),
+    "release-lock" | "bt:release-lock" => self.eval_release_lock(args),
+    "with-lock-held" | "bt:with-lock-held" => self.eval_with_lock_held(args),
+
+    // Recursive lock operations
+    "make-recursive-lock" | "bt:make-recursive-lock" => {
+        self.eval_make_recursive_lock(args)
+    },
+    "recursive-lock-p" | "recursive-lock?" | "bt:recursive-lock-p" => {
+        self.eval_recursive_lock_p(args)
+    },
+    "with-recursive-lock-held" | "bt:with-recursive-lock-held" => {
+        self.eval_with_recursive_lock_held(args)
+    },
+
+    // Condition variable operations
+    "make-condition-variable" | "bt:make-condition-variable" => {
+        self.eval_make_condition_variable(args)
+    },
+    "condition-variable-p" | "condition-variable?" | "bt:condition-variable-p" => {
+        self.eval_condition_variable_p(args)
+    },
+    "condition-wait" | "bt:condition-wait" => self.eval_condition_wait(args),
+    "condition-notify" | "bt:condition-notify" => self.eval_condition_notify(args),
+    "condition-broadcast" | "bt:condition-broadcast" => {
+        self.eval_condition_broadcast(args)
+    },
+
+    // Semaphore operations
+    "make-semaphore" | "bt:make-semaphore" => self.eval_make_semaphore(args),
+    "semaphorep" | "semaphore?" | "bt:semaphorep" => self.eval_semaphorep(args),
+    "signal-semaphore" | "bt:signal-semaphore" => self.eval_signal_semaphore(args),
+    "wait-on-semaphore" | "bt:wait-on-semaphore" => {
+        self.eval_wait_on_semaphore(args)
+    },
+
+    // Atomic integer operations
+    "make-atomic-integer" | "bt:make-atomic-integer" => {
+        self.eval_make_atomic_integer(args)
+    },
+    "atomic-integer-p" | "atomic-integer?" | "bt:atomic-integer-p" => {
+        self.eval_atomic_integer_p(args)
+    },
+    "atomic-integer-value" | "bt:atomic-integer-value" => {
+        self.eval_atomic_integer_value(args)
+    },
+    "atomic-integer-incf" | "bt:atomic-integer-incf" => {
+        self.eval_atomic_integer_incf(args)
+    },
+    "atomic-integer-decf" | "bt:atomic-integer-decf" => {
+        self.eval_atomic_integer_decf(args)
+    },
+    "atomic-integer-cas" | "bt:atomic-integer-cas" => {
+        self.eval_atomic_integer_cas(args)
+    },
+
+    // Atomic reference operations
+    "make-atomic" => self.eval_make_atomic(args),
+    "atomicp" | "atomic?" => self.eval_atomicp(args),
+    "atomic-get" => self.eval_atomic_get(args),
+    "atomic-set" => self.eval_atomic_set(args),
+    "atomic-update" => self.eval_atomic_update(args),
+
+    // Channel operations
+    "make-channel" => self.eval_make_channel(args),
+    "channelp" | "channel?" => self.eval_channelp(args),
+    "channel-send" => self.eval_channel_send(args),
+    "channel-receive" => self.eval_channel_receive(args),
+    "channel-try-receive" => self.eval_channel_try_receive(args),
+    "close-channel" => self.eval_close_channel(args),
+    "channel-closed?" | "channel-closed-p" => self.eval_channel_closed_p(args),
+
+    // Promise operations
+    "promise" => self.eval_promise(args),
+    "promisep" | "promise?" => self.eval_promisep(args),
+    "deliver" => self.eval_deliver(args),
+    "promise-deref" => self.eval_promise_deref(args),
+    "promise-realized?" | "realized?" => self.eval_promise_realized(args),
+    "all-of" => self.eval_promise_combinator("all-of", args),
+    "any-of" => self.eval_promise_combinator("any-of", args),
+}
+
+/// How integer `+`, `-` and `*` behave when the result does not fit in i64
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+enum OverflowMode {
+    /// Raise an `arithmetic-overflow` error
+    Checked,
+    /// Wrap around (two's complement)
+    Wrapping,
+    /// Clamp to i64::MIN / i64::MAX
+    #[default]
+    Saturating,
+}
+
+/// How far `macroexpand` and its variants expand a form
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MacroexpandMode {
+    /// Only the outermost macro call, once (`macroexpand-1`)
+    Once,
+    /// The outermost form until it is no longer a macro call (`macroexpand`)
+    TopLevel,
+    /// Every macro call in the form (`macroexpand-all`)
+    All,
+}
+
+/// Elements of a collection being iterated, keeping integer ranges lazy so
+/// `(for (i (range 0 n)) ...)` never allocates `n` values
+enum SequenceItems {
+    /// `start`, `start + step`, ... stopping before `end`
+    Range { next: i64, end: i64, step: i64 },
+    /// Elements of a materialized array
+    Array {
+        items: Arc<Vec<Value>>,
+        index: usize,
+    },
+}
+
+impl Iterator for SequenceItems {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        match self {
+            SequenceItems::Range { next, end, step } => {
+                let in_range = if *step > 0 {
+                    *next < *end
+                } else {
+                    *next > *end
+                };
+                if !in_range {
+                    return None;
+                }
+                let value = *next;
+                // Past i64::MAX there is nothing left to yield
+                *next = next.checked_add(*step).unwrap_or(*end);
+                Some(Value::Int(value))
+            }
+            SequenceItems::Array { items, index } => {
+                let item = items.get(*index).cloned();
+                *index += 1;
+                item
+            }
+        }
+    }
+}
+
+impl MethodChain {
+    /// The same call with no further methods, as seen by auxiliary methods
+    fn without_next(&self) -> MethodChain {
+        MethodChain {
+            name: self.name.clone(),
+            args: self.args.clone(),
+            around: Vec::new(),
+            before: Vec::new(),
+            primary: Vec::new(),
+            after: Vec::new(),
+        }
+    }
 }
 
 /// Configuration for lazy field access behavior
@@ -56,23 +787,55 @@ impl Default for LazyFieldConfig {
 impl LispEvaluator {
     /// Creates a new LISP evaluator
     pub fn new() -> Self {
-        LispEvaluator {
-            env: Environment::new(),
-            registry: Arc::new(ToolRegistry::new()),
-            gensym_counter: std::cell::Cell::new(0),
-            lazy_field_config: std::cell::RefCell::new(LazyFieldConfig::default()),
-            execution_trace: std::cell::RefCell::new(Vec::new()),
-        }
+        Self::from_parts(
+            Arc::new(ToolRegistry::new()),
+            crate::tools::stdlib::network::default_client(),
+        )
     }
 
     /// Creates a new LISP evaluator with custom tool registry
     pub fn with_registry(registry: ToolRegistry) -> Self {
+        Self::from_parts(
+            Arc::new(registry),
+            crate::tools::stdlib::network::default_client(),
+        )
+    }
+
+    /// Tool registry this evaluator dispatches to, including tools added
+    /// with `register-tool`
+    pub fn registry(&self) -> &ToolRegistry {
+        &self.registry
+    }
+
+    /// Fresh evaluator state around an existing registry and HTTP client, so
+    /// threads can reuse their parent's instead of building their own
+    fn from_parts(registry: Arc<ToolRegistry>, http_client: reqwest::Client) -> Self {
         LispEvaluator {
             env: Environment::new(),
-            registry: Arc::new(registry),
+            registry,
             gensym_counter: std::cell::Cell::new(0),
             lazy_field_config: std::cell::RefCell::new(LazyFieldConfig::default()),
             execution_trace: std::cell::RefCell::new(Vec::new()),
+            http_client,
+            tail_position: false,
+            pending_tail_call: None,
+            overflow_mode: OverflowMode::default(),
+            packages: HashMap::new(),
+            current_package: None,
+            generic_methods: HashMap::new(),
+            next_methods: Vec::new(),
+            interner: crate::runtime::intern::Interner::new(),
+            refinements: HashMap::new(),
+            refinement_checks: false,
+            allocation_budget: None,
+            bytes_allocated: 0,
+            cancellation: CancellationToken::new(),
+            deadline: None,
+            output_sink: None,
+            output_captures: Vec::new(),
+            loop_depth: 0,
+            vectorize_depth: 0,
+            thread_pool: None,
         }
     }
 
@@ -86,8 +849,121 @@ impl LispEvaluator {
         self.execution_trace.borrow_mut().clear();
     }
 
+    /// Limit the approximate bytes of arrays and strings each `execute` may
+    /// allocate; going over fails with `Error::OutOfMemory`
+    ///
+    /// This is a budget for the total allocated during one `execute`, not a
+    /// cap on live memory: values that are dropped again are never credited
+    /// back, so a loop that keeps rebuilding a small array uses it up too.
+    pub fn set_allocation_budget(&mut self, bytes: usize) {
+        self.allocation_budget = Some(bytes);
+    }
+
+    /// Remove the allocation budget
+    pub fn clear_allocation_budget(&mut self) {
+        self.allocation_budget = None;
+    }
+
+    /// Approximate bytes allocated in total by the current or last `execute`
+    pub fn bytes_allocated(&self) -> usize {
+        self.bytes_allocated
+    }
+
+    /// Count `bytes` about to be allocated against the allocation budget
+    ///
+    /// Called before building the value, so an oversized request fails
+    /// instead of exhausting the host's memory.
+    fn charge_allocation(&mut self, bytes: usize) -> Result<()> {
+        let allocated = self.bytes_allocated.saturating_add(bytes);
+        if let Some(budget) = self.allocation_budget {
+            if allocated > budget {
+                return Err(Error::OutOfMemory(budget));
+            }
+        }
+        self.bytes_allocated = allocated;
+        Ok(())
+    }
+
+    /// Count `count` new array elements against the allocation budget
+    fn charge_values(&mut self, count: usize) -> Result<()> {
+        self.charge_allocation(count.saturating_mul(std::mem::size_of::<Value>()))
+    }
+
+    /// Token that cancels this evaluator's running and future executions
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+
+    /// Replace the cancellation token, e.g. with a fresh one after cancelling
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation = token;
+    }
+
+    /// Send script output (`print`, `println`, `log`, `(format t ...)`) to
+    /// `sink` instead of stdout
+    pub fn set_output_sink(&mut self, sink: impl std::io::Write + Send + 'static) {
+        self.output_sink = Some(Box::new(sink));
+    }
+
+    /// Send script output back to stdout
+    pub fn clear_output_sink(&mut self) {
+        self.output_sink = None;
+    }
+
+    /// Write script output to the innermost `with-output-capture`, the
+    /// configured sink, or stdout
+    fn write_output(&mut self, text: &str) -> Result<()> {
+        if let Some(capture) = self.output_captures.last_mut() {
+            capture.push_str(text);
+            return Ok(());
+        }
+        match self.output_sink.as_mut() {
+            Some(sink) => sink
+                .write_all(text.as_bytes())
+                .and_then(|()| sink.flush())
+                .map_err(|e| Error::runtime(format!("Failed to write output: {}", e))),
+            None => {
+                print!("{}", text);
+                std::io::Write::flush(&mut std::io::stdout()).ok();
+                Ok(())
+            }
+        }
+    }
+
+    /// Execute a program, failing with `Error::Timeout` once `timeout` of
+    /// wall-clock time has passed
+    ///
+    /// The deadline is checked at function calls and loop iterations, so a
+    /// single long-running builtin can overrun it.
+    pub fn execute_with_timeout(
+        &mut self,
+        program: &Program,
+        timeout: std::time::Duration,
+    ) -> Result<Value> {
+        let saved = self
+            .deadline
+            .replace((std::time::Instant::now() + timeout, timeout));
+        let result = self.execute(program);
+        self.deadline = saved;
+        result
+    }
+
+    /// Fail if the evaluation was cancelled or its deadline has passed
+    fn check_interrupt(&self) -> Result<()> {
+        if self.cancellation.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        if let Some((deadline, timeout)) = self.deadline {
+            if std::time::Instant::now() >= deadline {
+                return Err(Error::Timeout(timeout));
+            }
+        }
+        Ok(())
+    }
+
     /// Execute a LISP-style program
     pub fn execute(&mut self, program: &Program) -> Result<Value> {
+        self.bytes_allocated = 0;
         let mut last_val = Value::Null;
 
         for statement in &program.statements {
@@ -127,10 +1003,14 @@ impl LispEvaluator {
     /// Evaluate an expression with LISP special form handling
     /// Evaluate a single expression (public for async-call thread pool access)
     pub fn evaluate_expression(&mut self, expr: &Expression) -> Result<Value> {
+        // Consume the tail flag so sub-expressions are non-tail unless a form
+        // explicitly hands it on to its final sub-expression
+        let tail = std::mem::take(&mut self.tail_position);
+
         // First, try macro expansion
         if let Some(expanded) = self.try_expand_macro(expr)? {
             // Recursively evaluate expanded form (macros can expand to macro calls)
-            return self.evaluate_expression(&expanded);
+            return self.evaluate_tail(&expanded, tail);
         }
 
         match expr {
@@ -138,390 +1018,26 @@ impl LispEvaluator {
             Expression::Quasiquote(_) => self.eval_quasiquote(expr),
 
             Expression::ToolCall { name, args } => {
-                // Check if this is a LISP special form
-                match name.as_str() {
-                    "set!" => self.eval_set(args),
-                    "setf" => self.eval_setf(args),
-                    "define" => self.eval_define(args),
-                    "defun" => self.eval_defun(args),
-                    "defn" => self.eval_defun(args), // Alias for defun
-                    "defmacro" => self.eval_defmacro(args),
-                    "const" => self.eval_const(args),
-                    "let" => self.eval_let(args),
-                    "let*" => self.eval_let_star(args),
-                    "flet" => self.eval_flet(args),
-                    "labels" => self.eval_labels(args),
-                    "case" => self.eval_case(args),
-                    "typecase" => self.eval_typecase(args),
-                    "while" => self.eval_while(args),
-                    "for" => self.eval_for(args),
-                    "do" => self.eval_do(args),
-                    "progn" => self.eval_do(args), // progn is same as do
-                    "prog1" => self.eval_prog1(args),
-                    "prog2" => self.eval_prog2(args),
-                    "when" => self.eval_when(args),
-                    "unless" => self.eval_unless(args),
-                    "cond" => self.eval_cond(args),
-                    "not" => self.eval_not(args),
-                    "and" => self.eval_and(args),
-                    "or" => self.eval_or(args),
-                    "null?" => self.eval_null_check(args),
-                    "empty?" => self.eval_empty_check(args),
-                    // Type predicates
-                    "int?" => self.eval_int_check(args),
-                    "float?" => self.eval_float_check(args),
-                    "number?" => self.eval_number_check(args),
-                    "string?" => self.eval_string_check(args),
-                    "bool?" => self.eval_bool_check(args),
-                    "array?" => self.eval_array_check(args),
-                    "list?" => self.eval_array_check(args), // Common LISP: list? is same as array?
-                    "object?" => self.eval_object_check(args),
-                    "function?" => self.eval_function_check(args),
-                    // Generic type checking (Python/JS style)
-                    "typeof" => self.eval_typeof(args), // JS: typeof value
-                    "type-of" => self.eval_typeof(args), // LISP: type-of
-                    // Number predicates (Common LISP style)
-                    "even?" => self.eval_even(args), // (even? 4) -> true
-                    "evenp" => self.eval_even(args), // Common LISP: evenp
-                    "odd?" => self.eval_odd(args),   // (odd? 3) -> true
-                    "oddp" => self.eval_odd(args),   // Common LISP: oddp
-                    "positive?" => self.eval_positive(args), // (positive? 5) -> true
-                    "positivep" => self.eval_positive(args), // Common LISP
-                    "negative?" => self.eval_negative(args), // (negative? -5) -> true
-                    "negativep" => self.eval_negative(args), // Common LISP
-                    "zero?" => self.eval_zero(args), // (zero? 0) -> true
-                    "zerop" => self.eval_zero(args), // Common LISP: zerop
-                    // Type conversions (AI compatibility - Python/JS style)
-                    "int" => self.eval_to_int(args), // Python: int("42") -> 42
-                    "integer" => self.eval_to_int(args), // Alias
-                    "parse-int" => self.eval_to_int(args), // JS: parseInt("42")
-                    "parseint" => self.eval_to_int(args), // JS: parseInt (lowercase)
-                    "float" => self.eval_to_float(args), // Python: float("3.14") -> 3.14
-                    "parse-float" => self.eval_to_float(args), // JS: parseFloat("3.14")
-                    "parsefloat" => self.eval_to_float(args), // JS: parseFloat (lowercase)
-                    "bool" => self.eval_to_bool(args), // Python: bool("true") -> True
-                    // Assertions
-                    "assert" => self.eval_assert(args),
-                    "assert-type" => self.eval_assert_type(args),
-                    // Cryptography and encoding
-                    "base58-decode" => self.eval_base58_decode(args),
-                    "base58-encode" => self.eval_base58_encode(args),
-                    "base64-decode" => self.eval_base64_decode(args),
-                    "base64-decode-raw" => self.eval_base64_decode_raw(args),
-                    "base64-encode" => self.eval_base64_encode(args),
-                    "hex-decode" => self.eval_hex_decode(args),
-                    "hex-encode" => self.eval_hex_encode(args),
-                    "sha256" => self.eval_sha256(args),
-                    "sha512" => self.eval_sha512(args),
-                    // Binary/byte operations for Borsh decoding
-                    "byte-at" => self.eval_byte_at(args),
-                    "parse-u64-le" => self.eval_parse_u64_le(args),
-                    "hex-to-u64-le" => self.eval_hex_to_u64_le(args),
-                    "bytes-to-hex" => self.eval_bytes_to_hex(args),
-                    // Error handling
-                    "try" => self.eval_try(args),
-                    "error" => self.eval_error(args),
-                    // String operations
-                    "split" => self.eval_split(args),
-                    "join" => self.eval_join(args),
-                    "replace" => self.eval_replace(args),
-                    "trim" => self.eval_trim(args),
-                    "upper" => self.eval_upper(args),
-                    "lower" => self.eval_lower(args),
-                    // Advanced math
-                    "sqrt" => self.eval_sqrt(args),
-                    "pow" => self.eval_pow(args),
-                    "expt" => self.eval_pow(args), // Common Lisp alias for pow
-                    "exp" => self.eval_exp(args),  // e^x
-                    "ln" => self.eval_ln(args),    // Natural logarithm
-                    "abs" => self.eval_abs(args),
-                    // Common Lisp arithmetic shortcuts
-                    "1+" => self.eval_1_plus(args),
-                    "1-" => self.eval_1_minus(args),
-                    "mod" => self.eval_mod(args),
-                    "rem" => self.eval_rem(args),
-                    "gcd" => self.eval_gcd(args),
-                    "lcm" => self.eval_lcm(args),
-                    // Common Lisp list predicates
-                    "atom" => self.eval_atom(args),
-                    "consp" => self.eval_consp(args),
-                    "listp" => self.eval_listp(args),
-                    // Common Lisp bitwise operations
-                    "logand" => self.eval_logand(args),
-                    "logior" => self.eval_logior(args),
-                    "logxor" => self.eval_logxor(args),
-                    "lognot" => self.eval_lognot(args),
-                    "ash" => self.eval_ash(args),
-                    // Common Lisp list operations
-                    "member" => self.eval_member(args),
-                    "assoc" => self.eval_assoc(args),
-                    "assoc-in" => self.eval_assoc_in(args), // Set key in object (dynamic key)
-                    "set-key" => self.eval_assoc_in(args),  // Alias for assoc-in
-                    "set" => self.eval_object_set(args),    // set(obj, key, value) - like JS/Python
-                    "elt" => self.eval_elt(args),
-                    "subseq" => self.eval_subseq(args),
-                    // Common Lisp string comparisons
-                    "string=" => self.eval_string_eq(args),
-                    "string<" => self.eval_string_lt(args),
-                    "string>" => self.eval_string_gt(args),
-                    "string-equal" => self.eval_string_eq(args), // Alternative name
-                    "string-lessp" => self.eval_string_lt(args), // Alternative name
-                    "string-greaterp" => self.eval_string_gt(args), // Alternative name
-                    // Common Lisp map variants
-                    "mapcar" => self.eval_mapcar(args),
-                    "mapc" => self.eval_mapc(args),
-                    // Common Lisp conditional filters
-                    "remove-if" => self.eval_remove_if(args),
-                    "remove-if-not" => self.eval_remove_if_not(args),
-                    // Common Lisp variable mutation
-                    "incf" => self.eval_incf(args),
-                    "decf" => self.eval_decf(args),
-                    // Trigonometric functions
-                    "sin" => self.eval_sin(args),
-                    "cos" => self.eval_cos(args),
-                    "tan" => self.eval_tan(args),
-                    "asin" => self.eval_asin(args),
-                    "acos" => self.eval_acos(args),
-                    "atan" => self.eval_atan(args),
-                    "atan2" => self.eval_atan2(args),
-                    // Rounding functions
-                    "floor" => self.eval_floor(args),
-                    "ceiling" => self.eval_ceiling(args),
-                    "ceil" => self.eval_ceiling(args), // Alias
-                    "round" => self.eval_round(args),
-                    "truncate" => self.eval_truncate(args),
-                    "trunc" => self.eval_truncate(args), // Alias
-                    // Multiple values (Common Lisp style)
-                    "values" => self.eval_values(args),
-                    "multiple-value-bind" => self.eval_multiple_value_bind(args),
-                    // Dynamic variables (Common Lisp special variables)
-                    "defvar" => self.eval_defvar(args),
-                    // Macro system
-                    "gensym" => self.eval_gensym(args),
-                    "macroexpand" => self.eval_macroexpand(args),
-                    "eval" => self.eval_eval(args),
-                    "length" => self.eval_length(args),
-                    "count" => self.eval_length(args), // Alias for length - commonly expected
-                    "last" => self.eval_last(args),
-                    "range" => self.eval_range(args),
-                    "min" => self.eval_min(args),
-                    "max" => self.eval_max(args),
-                    // Statistical functions (Python/NumPy style)
-                    "mean" => self.eval_mean(args),         // Average
-                    "average" => self.eval_mean(args),      // Alias
-                    "avg" => self.eval_mean(args),          // SQL-style
-                    "median" => self.eval_median(args),     // Median value
-                    "mode" => self.eval_mode(args),         // Most common value
-                    "product" => self.eval_product(args),   // Product of numbers
-                    "std" => self.eval_stddev(args),        // Standard deviation
-                    "stddev" => self.eval_stddev(args),     // Alias
-                    "variance" => self.eval_variance(args), // Variance
-                    // Math utilities
-                    "sign" => self.eval_sign(args), // Sign of number (-1, 0, 1)
-                    "clamp" => self.eval_clamp(args), // Clamp between min/max
-                    "random" => self.eval_random(args), // Random number
-                    "now" => self.eval_now(args),
-                    "sleep" => self.eval_sleep(args),
-                    "log" => self.eval_log(args),
-                    "print" => self.eval_print(args), // Python/JS-style output
-                    "println" => self.eval_println(args), // Python/JS-style output with newline
-                    "map" => self.eval_map(args),
-                    "pmap" => self.eval_pmap(args), // Parallel map
-                    "filter" => self.eval_filter(args),
-                    "reduce" => self.eval_reduce(args),
-                    "sort" => self.eval_sort(args),
-                    "group-by" => self.eval_group_by(args),
-                    "aggregate" => self.eval_aggregate(args),
-                    "sort-by" => self.eval_sort_by(args),
-                    "str" => self.eval_str(args),
-                    "format" => self.eval_format(args),
-                    "slice" => self.eval_slice(args),
-                    "keys" => self.eval_keys(args),
-                    "object-values" => self.eval_object_values(args), // Python: dict.values()
-                    "object-entries" => self.eval_object_entries(args), // Python: dict.items()
-                    "entries" => self.eval_object_entries(args),      // JS: Object.entries()
-                    "items" => self.eval_object_entries(args),        // Python: dict.items()
-                    "merge" => self.eval_merge(args),
-                    "put" => self.eval_put(args), // Set object property: (put obj "key" val)
-                    "get" => self.eval_get(args),
-                    "get-path" => self.eval_get_path(args),
-                    "discover" => self.eval_discover(args),
-                    "lazy-config" => self.eval_lazy_config(args),
-                    "first" => self.eval_first(args),
-                    "head" => self.eval_first(args), // Alias for first (Haskell-style)
-                    "rest" => self.eval_rest(args),
-                    "tail" => self.eval_rest(args), // Alias for rest (Haskell-style)
-                    "init" => self.eval_init(args), // All but last (Haskell-style)
-                    "shift" => self.eval_shift(args), // Remove first element (JS-style)
-                    "unshift" => self.eval_unshift(args), // Add to front (JS-style)
-                    "nth" => self.eval_nth(args),
-                    "cons" => self.eval_cons(args),
-                    "append" => self.eval_append(args),
-                    "concat" => self.eval_concatenate(args), // Alias for concatenate
-                    "concatenate" => self.eval_concatenate(args), // Polymorphic concat
-                    // JSON operations (built-ins, not MCP tools!)
-                    "parse-json" => self.eval_parse_json(args),
-                    "json-stringify" => self.eval_json_stringify(args),
-                    // Network operations (async)
-                    "http-get" => self.eval_http_get(args),
-                    "http-post" => self.eval_http_post(args),
-                    "json-rpc" => self.eval_json_rpc(args),
-                    // LLM operations (AI-powered agents)
-                    "llm-query" => self.eval_llm_query(args),
-                    // Streaming operations (real-time blockchain events)
-                    "stream-connect" => self.eval_stream_connect(args),
-                    "stream-poll" => self.eval_stream_poll(args),
-                    "stream-wait" => self.eval_stream_wait(args),
-                    "stream-close" => self.eval_stream_close(args),
-                    "osvm-stream" => self.eval_osvm_stream(args),
-                    // Async execution
-                    "async" => self.eval_async(args),
-                    "await" => self.eval_await(args),
-                    // LINQ-style functional operations
-                    "compact" => self.eval_compact(args),
-                    "count-by" => self.eval_count_by(args),
-                    "distinct" => self.eval_distinct(args),
-                    "unique" => self.eval_distinct(args), // Alias for distinct (SQL-style)
-                    "drop" => self.eval_drop(args),
-                    "every" => self.eval_every(args),
-                    "all" => self.eval_every(args), // Alias for every (JavaScript-style)
-                    "find" => self.eval_find(args),
-                    "find-index" => self.eval_find_index(args), // Find index matching predicate
-                    "indexof" => self.eval_indexof(args),       // JS-style indexOf
-                    "index-of" => self.eval_indexof(args),      // Lisp-style index-of
-                    "contains" => self.eval_contains(args),     // Python-style contains
-                    "string-contains" => self.eval_contains(args), // Explicit string-contains
-                    "elem" => self.eval_contains(args),         // Haskell-style elem
-                    "remove" => self.eval_remove(args),         // Remove element by value
-                    "insert-at" => self.eval_insert_at(args),   // Insert at index
-                    "flatten" => self.eval_flatten(args),
-                    // "group-by" already handled above on line 186
-                    "partition" => self.eval_partition(args),
-                    "pluck" => self.eval_pluck(args),
-                    "reverse" => self.eval_reverse(args),
-                    "repeat" => self.eval_repeat(args), // Python: "x"*3, JS: "x".repeat(3)
-                    "some" => self.eval_some(args),
-                    "any" => self.eval_some(args), // Alias for some (JavaScript-style)
-                    "take" => self.eval_take(args),
-                    "zip" => self.eval_zip(args),
-                    // String predicates (Python str methods)
-                    "isdigit?" => self.eval_isdigit(args),
-                    "is-digit?" => self.eval_isdigit(args),
-                    "isalpha?" => self.eval_isalpha(args),
-                    "is-alpha?" => self.eval_isalpha(args),
-                    "isalnum?" => self.eval_isalnum(args),
-                    "is-alnum?" => self.eval_isalnum(args),
-                    "isspace?" => self.eval_isspace(args),
-                    "is-space?" => self.eval_isspace(args),
-                    "blank?" => self.eval_blank(args),
-                    // Functional programming utilities
-                    "apply" => self.eval_apply(args),
-                    "compose" => self.eval_compose(args),
-                    "pipe" => self.eval_pipe(args),
-                    "partial" => self.eval_partial(args),
-                    // Regex operations
-                    "regex-match" => self.eval_regex_match(args),
-                    "regex-replace" => self.eval_regex_replace(args),
-                    "regex-split" => self.eval_regex_split(args),
-                    "regex-find-all" => self.eval_regex_find_all(args),
-
-                    // HIGH PRIORITY ALIASES - Python/JavaScript compatibility
-                    "len" => self.eval_length(args), // Python len()
-                    "includes" => self.eval_contains(args), // JavaScript includes()
-                    "toLowerCase" | "tolowercase" => self.eval_to_lower_case(args), // JavaScript
-                    "toUpperCase" | "touppercase" => self.eval_to_upper_case(args), // JavaScript
-                    "charAt" | "charat" => self.eval_char_at(args), // JavaScript charAt()
-                    "chr" => self.eval_chr(args),    // Python chr()
-                    "ord" => self.eval_ord(args),    // Python ord()
-                    "substring" => self.eval_substring(args), // JavaScript substring()
-
-                    // MEDIUM PRIORITY ALIASES - LISP/Haskell compatibility
-                    "cdr" => self.eval_rest(args), // LISP cdr
-                    "foldl" | "fold-left" => self.eval_reduce(args), // Haskell foldl
-                    "foldr" | "fold-right" => self.eval_reduce(args), // Haskell foldr
-                    "lastIndexOf" | "lastindexof" => self.eval_last_index_of(args), // JavaScript
-
-                    // ============================================================
-                    // BORDEAUX THREADS - Portable shared-state concurrency
-                    // https://github.com/sionescu/bordeaux-threads
-                    // ============================================================
-
-                    // Thread operations
-                    "make-thread" | "bt:make-thread" => self.eval_make_thread(args),
-                    "current-thread" | "bt:current-thread" => self.eval_current_thread(args),
-                    "all-threads" | "bt:all-threads" => self.eval_all_threads(args),
-                    "thread-name" | "bt:thread-name" => self.eval_thread_name(args),
-                    "threadp" | "thread?" | "bt:threadp" => self.eval_threadp(args),
-                    "thread-alive-p" | "thread-alive?" | "bt:thread-alive-p" => {
-                        self.eval_thread_alive_p(args)
-                    }
-                    "join-thread" | "bt:join-thread" => self.eval_join_thread(args),
-                    "thread-yield" | "bt:thread-yield" => self.eval_thread_yield(args),
-
-                    // Lock operations
-                    "make-lock" | "bt:make-lock" => self.eval_make_lock(args),
-                    "lockp" | "lock?" | "bt:lockp" => self.eval_lockp(args),
-                    "acquire-lock" | "bt:acquire-lock" => self.eval_acquire_lock(args),
-                    "release-lock" | "bt:release-lock" => self.eval_release_lock(args),
-                    "with-lock-held" | "bt:with-lock-held" => self.eval_with_lock_held(args),
-
-                    // Recursive lock operations
-                    "make-recursive-lock" | "bt:make-recursive-lock" => {
-                        self.eval_make_recursive_lock(args)
-                    }
-                    "recursive-lock-p" | "recursive-lock?" | "bt:recursive-lock-p" => {
-                        self.eval_recursive_lock_p(args)
-                    }
-                    "with-recursive-lock-held" | "bt:with-recursive-lock-held" => {
-                        self.eval_with_recursive_lock_held(args)
-                    }
-
-                    // Condition variable operations
-                    "make-condition-variable" | "bt:make-condition-variable" => {
-                        self.eval_make_condition_variable(args)
-                    }
-                    "condition-variable-p" | "condition-variable?" | "bt:condition-variable-p" => {
-                        self.eval_condition_variable_p(args)
-                    }
-                    "condition-wait" | "bt:condition-wait" => self.eval_condition_wait(args),
-                    "condition-notify" | "bt:condition-notify" => self.eval_condition_notify(args),
-                    "condition-broadcast" | "bt:condition-broadcast" => {
-                        self.eval_condition_broadcast(args)
-                    }
-
-                    // Semaphore operations
-                    "make-semaphore" | "bt:make-semaphore" => self.eval_make_semaphore(args),
-                    "semaphorep" | "semaphore?" | "bt:semaphorep" => self.eval_semaphorep(args),
-                    "signal-semaphore" | "bt:signal-semaphore" => self.eval_signal_semaphore(args),
-                    "wait-on-semaphore" | "bt:wait-on-semaphore" => {
-                        self.eval_wait_on_semaphore(args)
+                // cl:sym names the builtin sym, like an unqualified sym
+                let unqualified;
+                let name = match Self::split_qualified(name) {
+                    Some((pkg, sym, _)) if BASE_PACKAGES.contains(&pkg) => {
+                        unqualified = sym.to_string();
+                        &unqualified
                     }
+                    _ => name,
+                };
 
-                    // Atomic integer operations
-                    "make-atomic-integer" | "bt:make-atomic-integer" => {
-                        self.eval_make_atomic_integer(args)
-                    }
-                    "atomic-integer-p" | "atomic-integer?" | "bt:atomic-integer-p" => {
-                        self.eval_atomic_integer_p(args)
-                    }
-                    "atomic-integer-value" | "bt:atomic-integer-value" => {
-                        self.eval_atomic_integer_value(args)
-                    }
-                    "atomic-integer-incf" | "bt:atomic-integer-incf" => {
-                        self.eval_atomic_integer_incf(args)
-                    }
-                    "atomic-integer-decf" | "bt:atomic-integer-decf" => {
-                        self.eval_atomic_integer_decf(args)
-                    }
-                    "atomic-integer-cas" | "bt:atomic-integer-cas" => {
-                        self.eval_atomic_integer_cas(args)
-                    }
+                // Inside with-vectorization, math builtins broadcast over arrays
+                if self.vectorize_depth > 0 && Self::is_vectorized_math(name) {
+                    return self.eval_vectorized_math(name, args);
+                }
 
-                    _ => {
-                        // Not a special form, delegate to base evaluator
-                        // This would call regular tools
-                        self.eval_tool_call(name, args)
-                    }
+                // Check if this is a LISP special form or builtin
+                match self.dispatch_builtin(name, args, tail) {
+                    Some(result) => result,
+                    // Not a special form, delegate to regular tools
+                    None => self.eval_tool_call(name, args, tail),
                 }
             }
 
@@ -537,11 +1053,13 @@ impl LispEvaluator {
                 if name.starts_with(':') {
                     Ok(Value::String(name.clone()))
                 } else {
-                    self.env.get(name)
+                    let resolved = self.resolve_symbol(name)?;
+                    self.env.get(&resolved)
                 }
             }
 
             Expression::ArrayLiteral(elements) => {
+                self.charge_values(elements.len())?;
                 let mut values = Vec::new();
                 for elem in elements {
                     values.push(self.evaluate_expression(elem)?);
@@ -553,7 +1071,7 @@ impl LispEvaluator {
                 let mut map = std::collections::HashMap::new();
                 for (key, val_expr) in pairs {
                     let val = self.evaluate_expression(val_expr)?;
-                    map.insert(key.clone(), val);
+                    map.insert(self.interner.intern(key), val);
                 }
                 Ok(Value::Object(Arc::new(map)))
             }
@@ -576,9 +1094,9 @@ impl LispEvaluator {
             } => {
                 let cond_val = self.evaluate_expression(condition)?;
                 if cond_val.is_truthy() {
-                    self.evaluate_expression(then_expr)
+                    self.evaluate_tail(then_expr, tail)
                 } else {
-                    self.evaluate_expression(else_expr)
+                    self.evaluate_tail(else_expr, tail)
                 }
             }
 
@@ -593,7 +1111,7 @@ impl LispEvaluator {
                 arr_val.get_index(&idx_val)
             }
 
-            Expression::Grouping(expr) => self.evaluate_expression(expr),
+            Expression::Grouping(expr) => self.evaluate_tail(expr, tail),
 
             Expression::Lambda { params, body } => {
                 let closure = Arc::new(std::collections::HashMap::new());
@@ -623,6 +1141,15 @@ impl LispEvaluator {
         }
     }
 
+    /// Evaluate `expr`, marking it as being in tail position when `tail` is set
+    ///
+    /// Special forms call this for the sub-expression whose value they return
+    /// (chosen `if`/`cond` branch, last body form) so calls there can be trampolined.
+    fn evaluate_tail(&mut self, expr: &Expression, tail: bool) -> Result<Value> {
+        self.tail_position = tail;
+        self.evaluate_expression(expr)
+    }
+
     // LISP Special Forms Implementation
 
     /// (set! var value) - Mutate existing variable
@@ -644,6 +1171,8 @@ impl LispEvaluator {
         let value = self.evaluate_expression(&args[1].value)?;
 
         // Set the variable
+        let var_name = self.resolve_symbol(&var_name)?;
+        self.check_refinement(&var_name, &value)?;
         self.env.set(&var_name, value.clone())?;
 
         Ok(value)
@@ -661,74 +1190,356 @@ impl LispEvaluator {
 
         // Evaluate the value first
         let value = self.evaluate_expression(&args[1].value)?;
+        self.assign_place(&args[0].value, value.clone())?;
+        Ok(value)
+    }
 
-        // Handle different types of places
-        match &args[0].value {
-            // Simple variable: (setf x 10)
-            Expression::Variable(name) => {
-                self.env.set(name, value.clone())?;
-                Ok(value)
-            }
+    /// Store value into a setf place
+    ///
+    /// Places are variables, `(first/car c)`, `(nth c i)`, `(elt c i)`,
+    /// `(aref c i...)`, `(get c key)`, `(gethash key table)`, `(. obj field)`
+    /// and `([] c i)`. Collections are immutable, so the updated collection is
+    /// in turn stored into the place it came from, down to a variable.
+    fn assign_place(&mut self, place: &Expression, value: Value) -> Result<()> {
+        let (var, keys) = self.resolve_place(place)?;
+        self.store_place(&var, &keys, value)
+    }
 
-            // Function call form (for generalized references)
+    /// Evaluate the subforms of a setf place, each once and in source order
+    ///
+    /// Returns the variable the place is rooted in and the keys leading from
+    /// its value down to the slot, so the place can be read and written back
+    /// without evaluating its subforms again.
+    fn resolve_place(&mut self, place: &Expression) -> Result<(String, Vec<Value>)> {
+        let (container, key) = match place {
+            Expression::Variable(name) => return Ok((self.resolve_symbol(name)?, Vec::new())),
+            Expression::FieldAccess { object, field } => {
+                let (var, mut keys) = self.resolve_place(object)?;
+                keys.push(Value::String(field.clone()));
+                return Ok((var, keys));
+            }
+            Expression::IndexAccess { array, index } => (&**array, &**index),
             Expression::ToolCall {
                 name,
                 args: place_args,
             } => {
+                let arity = |expected: usize| -> Result<()> {
+                    if place_args.len() == expected {
+                        Ok(())
+                    } else {
+                        Err(Error::InvalidArguments {
+                            tool: "setf".to_string(),
+                            reason: format!("{} place requires {} arguments", name, expected),
+                        })
+                    }
+                };
                 match name.as_str() {
-                    // (setf (first list) value) - set first element
                     "first" | "car" => {
-                        if place_args.len() != 1 {
-                            return Err(Error::InvalidArguments {
-                                tool: "setf".to_string(),
-                                reason: "first/car requires 1 argument".to_string(),
-                            });
+                        arity(1)?;
+                        let (var, mut keys) = self.resolve_place(&place_args[0].value)?;
+                        keys.push(Value::Int(0));
+                        return Ok((var, keys));
+                    }
+                    "nth" | "elt" | "get" => {
+                        arity(2)?;
+                        (&place_args[0].value, &place_args[1].value)
+                    }
+                    "gethash" => {
+                        arity(2)?;
+                        let key = self.evaluate_expression(&place_args[0].value)?;
+                        let (var, mut keys) = self.resolve_place(&place_args[1].value)?;
+                        keys.push(key);
+                        return Ok((var, keys));
+                    }
+                    // (aref a i j) is the place (aref (aref a i) j)
+                    "aref" => {
+                        if place_args.len() < 2 {
+                            arity(2)?;
                         }
-
-                        // Get the list
-                        let list_val = self.evaluate_expression(&place_args[0].value)?;
-                        if let Value::Array(arr) = list_val {
-                            let mut new_arr = arr.to_vec();
-                            if new_arr.is_empty() {
-                                return Err(Error::InvalidArguments {
-                                    tool: "setf".to_string(),
-                                    reason: "Cannot set first of empty array".to_string(),
-                                });
-                            }
-                            new_arr[0] = value.clone();
-
-                            // Set the variable back
-                            if let Expression::Variable(var_name) = &place_args[0].value {
-                                self.env.set(var_name, Value::Array(Arc::new(new_arr)))?;
-                            }
-                            Ok(value)
-                        } else {
-                            Err(Error::TypeError {
-                                expected: "array".to_string(),
-                                got: list_val.type_name().to_string(),
-                            })
+                        let (var, mut keys) = self.resolve_place(&place_args[0].value)?;
+                        for index in &place_args[1..] {
+                            keys.push(self.evaluate_expression(&index.value)?);
                         }
+                        return Ok((var, keys));
+                    }
+                    _ => {
+                        return Err(Error::NotImplemented {
+                            tool: format!("setf for {}", name),
+                        })
                     }
+                }
+            }
+            _ => return Err(Error::ParseError("setf requires valid place".to_string())),
+        };
 
-                    // For now, other setf forms just fall back to regular set
-                    _ => Err(Error::NotImplemented {
-                        tool: format!("setf for {}", name),
-                    }),
+        let (var, mut keys) = self.resolve_place(container)?;
+        keys.push(self.evaluate_expression(key)?);
+        Ok((var, keys))
+    }
+
+    /// Current value of a place resolved by `resolve_place`
+    fn place_value(&self, var: &str, keys: &[Value]) -> Result<Value> {
+        let mut value = self.env.get(var)?;
+        for key in keys {
+            value = Self::fetch_from_collection(&value, key)?;
+        }
+        Ok(value)
+    }
+
+    /// Store value into a place resolved by `resolve_place`
+    fn store_place(&mut self, var: &str, keys: &[Value], value: Value) -> Result<()> {
+        let value = if keys.is_empty() {
+            value
+        } else {
+            Self::store_at_path(self.env.get(var)?, keys, value)?
+        };
+        self.check_refinement(var, &value)?;
+        self.env.set(var, value)
+    }
+
+    /// Copy of collection with the slot reached through keys replaced by value
+    fn store_at_path(collection: Value, keys: &[Value], value: Value) -> Result<Value> {
+        match keys {
+            [] => Ok(value),
+            [key, rest @ ..] => {
+                let updated = if rest.is_empty() {
+                    value
+                } else {
+                    let child = Self::fetch_from_collection(&collection, key)?;
+                    Self::store_at_path(child, rest, value)?
+                };
+                Self::store_in_collection(collection, key.clone(), updated)
+            }
+        }
+    }
+
+    /// Slot of an array or object that `store_in_collection` would replace
+    fn fetch_from_collection(collection: &Value, key: &Value) -> Result<Value> {
+        match (collection, key) {
+            (Value::Array(arr), Value::Int(index)) => Self::resolve_index(*index, arr.len())
+                .map(|i| arr[i].clone())
+                .ok_or_else(|| Self::index_out_of_bounds("setf", *index, arr.len())),
+            (Value::Object(obj), Value::String(key)) => {
+                let key = key.strip_prefix(':').unwrap_or(key);
+                Ok(obj.get(key).cloned().unwrap_or(Value::Null))
+            }
+            (Value::Array(_), key) => Err(Error::TypeError {
+                expected: "int index".to_string(),
+                got: key.type_name(),
+            }),
+            (Value::Object(_), key) => Err(Error::TypeError {
+                expected: "string key".to_string(),
+                got: key.type_name(),
+            }),
+            (other, _) => Err(Error::TypeError {
+                expected: "array or object".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
+    /// (push item place) / (pushnew item place [:test fn]) - Prepend to a list place
+    ///
+    /// The place holds an array (nil counts as empty) and takes the new list,
+    /// which is returned. A list that is not a place, like `(list 2 3)`, just
+    /// gives the new list. `pushnew` leaves the place alone if an element
+    /// already equals item, by `:test` or by value.
+    fn eval_push(&mut self, args: &[crate::parser::Argument], unique: bool) -> Result<Value> {
+        let tool = if unique { "pushnew" } else { "push" };
+        if args.len() < 2 || (!unique && args.len() > 2) {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: if unique {
+                    "Expected item, place and an optional :test function"
+                } else {
+                    "Expected 2 arguments: item and place"
+                }
+                .to_string(),
+            });
+        }
+
+        let item = self.evaluate_expression(&args[0].value)?;
+        let place = &args[1].value;
+        let mut test = None;
+        for (option, expr) in Self::keyword_options(tool, &args[2..])? {
+            match option.as_str() {
+                "test" => test = Some(self.eval_function_designator(expr)?),
+                other => {
+                    return Err(Error::InvalidArguments {
+                        tool: tool.to_string(),
+                        reason: format!("Unknown keyword :{}", other),
+                    })
+                }
+            }
+        }
+
+        let target = self.resolve_list_place(place)?;
+        let list = match self.list_place_value(place, &target)? {
+            Value::Null => Arc::new(Vec::new()),
+            Value::Array(items) => items,
+            other => {
+                return Err(Error::TypeError {
+                    expected: "array".to_string(),
+                    got: other.type_name(),
+                })
+            }
+        };
+
+        if unique {
+            for existing in list.iter() {
+                let present = match &test {
+                    Some(func) => self
+                        .apply_function_designator(
+                            func,
+                            vec![item.clone(), existing.clone()],
+                            tool,
+                        )?
+                        .is_truthy(),
+                    None => Self::values_are_equal(&item, existing),
+                };
+                if present {
+                    return Ok(Value::Array(list));
                 }
             }
+        }
 
-            _ => Err(Error::ParseError("setf requires valid place".to_string())),
+        self.charge_values(1)?;
+        let mut items = Vec::with_capacity(list.len() + 1);
+        items.push(item);
+        items.extend(list.iter().cloned());
+        let list = Value::Array(Arc::new(items));
+        if let Some((var, keys)) = &target {
+            self.store_place(var, keys, list.clone())?;
         }
+        Ok(list)
     }
 
-    /// (define var value) - Define new variable
-    fn eval_define(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 2 {
+    /// (pop place) - Remove and return the first element of a list place
+    ///
+    /// A list that is not a place just gives its first element.
+    fn eval_pop(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
             return Err(Error::InvalidArguments {
-                tool: "lisp_evaluator".to_string(),
-                reason: format!("Expected {} arguments, got {}", 2, args.len()),
-            })?;
+                tool: "pop".to_string(),
+                reason: "Expected 1 argument: place".to_string(),
+            });
+        }
+
+        let place = &args[0].value;
+        let target = self.resolve_list_place(place)?;
+        let list = match self.list_place_value(place, &target)? {
+            Value::Array(items) => items,
+            Value::Null => Arc::new(Vec::new()),
+            other => {
+                return Err(Error::TypeError {
+                    expected: "array".to_string(),
+                    got: other.type_name(),
+                })
+            }
+        };
+        let Some(first) = list.first().cloned() else {
+            return Err(Error::EmptyCollection {
+                operation: "pop".to_string(),
+            });
+        };
+        if let Some((var, keys)) = &target {
+            self.store_place(var, keys, Value::array(list[1..].to_vec()))?;
+        }
+        Ok(first)
+    }
+
+    /// Resolve the list argument of `push`/`pop` if it is a setf place
+    ///
+    /// Any other form, such as `(list 2 3)`, is not stored to: `push` and
+    /// `pop` just return the new list or the first element.
+    fn resolve_list_place(&mut self, place: &Expression) -> Result<Option<(String, Vec<Value>)>> {
+        if Self::is_place(place) {
+            self.resolve_place(place).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Value of the list argument of `push`/`pop`, resolved by `resolve_list_place`
+    fn list_place_value(
+        &mut self,
+        place: &Expression,
+        target: &Option<(String, Vec<Value>)>,
+    ) -> Result<Value> {
+        match target {
+            Some((var, keys)) => self.place_value(var, keys),
+            None => self.evaluate_expression(place),
+        }
+    }
+
+    /// Whether `expr` is a place `resolve_place` accepts, rooted in a variable
+    fn is_place(expr: &Expression) -> bool {
+        match expr {
+            Expression::Variable(_) => true,
+            Expression::FieldAccess { object, .. } => Self::is_place(object),
+            Expression::IndexAccess { array, .. } => Self::is_place(array),
+            Expression::ToolCall { name, args } => {
+                let root = match name.as_str() {
+                    "first" | "car" | "nth" | "elt" | "get" | "aref" => args.first(),
+                    "gethash" => args.get(1),
+                    _ => None,
+                };
+                root.is_some_and(|arg| Self::is_place(&arg.value))
+            }
+            _ => false,
+        }
+    }
+
+    /// Copy of an array or object with the slot at key replaced by value
+    fn store_in_collection(collection: Value, key: Value, value: Value) -> Result<Value> {
+        match (collection, key) {
+            (Value::Array(arr), Value::Int(index)) => {
+                // Negative indices count back from the end, as in nth
+                let index = Self::resolve_index(index, arr.len())
+                    .ok_or_else(|| Self::index_out_of_bounds("setf", index, arr.len()))?;
+                let mut items = Arc::try_unwrap(arr).unwrap_or_else(|arr| (*arr).clone());
+                items[index] = value;
+                Ok(Value::Array(Arc::new(items)))
+            }
+            (Value::Object(obj), Value::String(key)) => {
+                // Keyword keys name plain fields, as in `get`
+                let key = key.strip_prefix(':').unwrap_or(&key).to_string();
+                let mut map = Arc::try_unwrap(obj).unwrap_or_else(|obj| (*obj).clone());
+                map.insert(key.into(), value);
+                Ok(Value::Object(Arc::new(map)))
+            }
+            (collection @ Value::Array(_), key) | (collection @ Value::Object(_), key) => {
+                Err(Error::TypeError {
+                    expected: if matches!(collection, Value::Array(_)) {
+                        "int index".to_string()
+                    } else {
+                        "string key".to_string()
+                    },
+                    got: key.type_name(),
+                })
+            }
+            (other, _) => Err(Error::TypeError {
+                expected: "array or object".to_string(),
+                got: other.type_name(),
+            }),
         }
+    }
+
+    /// (define var value) - Define new variable
+    fn eval_define(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        // (define x : type value) carries the annotation as [x, :, type, value]
+        let is_annotated =
+            args.len() == 4 && matches!(&args[1].value, Expression::Variable(m) if m == ":");
+        let annotation = match args.len() {
+            2 => None,
+            4 if is_annotated => Some(&args[2].value),
+            _ => {
+                return Err(Error::InvalidArguments {
+                    tool: "lisp_evaluator".to_string(),
+                    reason: format!("Expected {} arguments, got {}", 2, args.len()),
+                })?
+            }
+        };
 
         let var_name = match &args[0].value {
             Expression::Variable(name) => name.clone(),
@@ -739,7 +1550,17 @@ impl LispEvaluator {
             }
         };
 
-        let value = self.evaluate_expression(&args[1].value)?;
+        let value = self.evaluate_expression(&args[args.len() - 1].value)?;
+        let var_name = self.package_binding_name(&var_name)?;
+        match annotation {
+            Some(refined @ Expression::RefinedTypeExpr { .. }) => {
+                self.refinements.insert(var_name.clone(), refined.clone());
+            }
+            _ => {
+                self.refinements.remove(&var_name);
+            }
+        }
+        self.check_refinement(&var_name, &value)?;
         self.env.define(var_name.clone(), value.clone());
 
         // Record in execution trace for debugging
@@ -781,15 +1602,519 @@ impl LispEvaluator {
         };
 
         // Define function in environment
+        let func_name = self.package_binding_name(&func_name)?;
         self.env.define(func_name, func_value.clone());
 
         Ok(func_value)
     }
 
-    /// (defmacro name (params...) body) - Define macro
-    /// Macros are compile-time code transformers that receive unevaluated arguments
-    fn eval_defmacro(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 3 {
+    /// (defgeneric name (params...)) - Declare a single-dispatch generic function
+    /// The generic is an ordinary function that dispatches on its first argument
+    fn eval_defgeneric(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() < 2 {
+            return Err(Error::InvalidArguments {
+                tool: "defgeneric".to_string(),
+                reason: "Expected name and parameter list".to_string(),
+            });
+        }
+
+        let name = match &args[0].value {
+            Expression::Variable(name) => self.package_binding_name(name)?,
+            _ => {
+                return Err(Error::ParseError(
+                    "defgeneric requires function name".to_string(),
+                ))
+            }
+        };
+        let params = match &args[1].value {
+            Expression::ArrayLiteral(items) => items
+                .iter()
+                .map(|item| match item {
+                    Expression::StringLiteral(param) => Ok(param.clone()),
+                    _ => Err(Error::ParseError(
+                        "defgeneric parameters must be plain symbols".to_string(),
+                    )),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => {
+                return Err(Error::ParseError(
+                    "defgeneric requires parameter list".to_string(),
+                ))
+            }
+        };
+
+        Ok(self.define_generic(name, params))
+    }
+
+    /// (defmethod name [qualifier] ((param type) params...) body...) - Add a method
+    /// The first parameter's type selects the method; an unspecialized first
+    /// parameter, a `t` specializer or the `:default` qualifier make a fallback.
+    /// `:before`, `:after` and `:around` add auxiliary methods.
+    fn eval_defmethod(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let name = match args.first().map(|a| &a.value) {
+            Some(Expression::Variable(name)) => self.package_binding_name(name)?,
+            _ => {
+                return Err(Error::ParseError(
+                    "defmethod requires function name".to_string(),
+                ))
+            }
+        };
+
+        let mut rest = &args[1..];
+        let mut default = false;
+        let mut qualifier = MethodQualifier::Primary;
+        if let Some(Expression::StringLiteral(keyword)) = rest.first().map(|a| &a.value) {
+            match keyword.as_str() {
+                ":default" => default = true,
+                ":before" => qualifier = MethodQualifier::Before,
+                ":after" => qualifier = MethodQualifier::After,
+                ":around" => qualifier = MethodQualifier::Around,
+                other => {
+                    return Err(Error::InvalidArguments {
+                        tool: "defmethod".to_string(),
+                        reason: format!("Unsupported method qualifier {}", other),
+                    })
+                }
+            }
+            rest = &rest[1..];
+        }
+
+        let (lambda_list, body) = match rest.split_first() {
+            Some((first, body)) if !body.is_empty() => match &first.value {
+                Expression::ArrayLiteral(items) => (items, body),
+                _ => {
+                    return Err(Error::ParseError(
+                        "defmethod requires specialized parameter list".to_string(),
+                    ))
+                }
+            },
+            _ => {
+                return Err(Error::InvalidArguments {
+                    tool: "defmethod".to_string(),
+                    reason: "Expected specialized parameter list and body".to_string(),
+                })
+            }
+        };
+
+        let mut params = Vec::with_capacity(lambda_list.len());
+        let mut specializer = "t".to_string();
+        for (i, item) in lambda_list.iter().enumerate() {
+            let (param, type_name) = match item {
+                Expression::StringLiteral(param) => (param.clone(), None),
+                Expression::ArrayLiteral(pair) => match pair.as_slice() {
+                    [Expression::StringLiteral(param), Expression::StringLiteral(ty)] => {
+                        (param.clone(), Some(ty.clone()))
+                    }
+                    _ => {
+                        return Err(Error::ParseError(
+                            "defmethod parameters must be symbols or (param type)".to_string(),
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(Error::ParseError(
+                        "defmethod parameters must be symbols or (param type)".to_string(),
+                    ))
+                }
+            };
+            if let Some(ty) = type_name {
+                if i > 0 {
+                    return Err(Error::InvalidArguments {
+                        tool: "defmethod".to_string(),
+                        reason: format!(
+                            "Only the first parameter can be specialized, not {}",
+                            param
+                        ),
+                    });
+                }
+                specializer = ty.trim_start_matches(':').to_string();
+            }
+            params.push(param);
+        }
+        if default {
+            specializer = "t".to_string();
+        }
+
+        // A method without a prior defgeneric implicitly declares one
+        match self.env.get(&name) {
+            Ok(Value::Function {
+                params: generic_params,
+                ..
+            }) if self.generic_methods.contains_key(&name) => {
+                if generic_params.len() != params.len() {
+                    return Err(Error::InvalidArguments {
+                        tool: "defmethod".to_string(),
+                        reason: format!(
+                            "Method for {} takes {} parameters but the generic takes {}",
+                            name,
+                            params.len(),
+                            generic_params.len()
+                        ),
+                    });
+                }
+            }
+            _ => {
+                self.define_generic(name.clone(), params.clone());
+            }
+        }
+
+        let body = if body.len() == 1 {
+            body[0].value.clone()
+        } else {
+            Expression::ToolCall {
+                name: "do".to_string(),
+                args: body.to_vec(),
+            }
+        };
+        let method = Value::Function {
+            params,
+            body: Arc::new(body),
+            closure: Arc::new(HashMap::new()),
+            is_flet: false,
+        };
+
+        let methods = self.generic_methods.entry(name).or_default();
+        methods.retain(|m| m.qualifier != qualifier || m.specializer != specializer);
+        methods.push(GenericMethod {
+            qualifier,
+            specializer,
+            function: method.clone(),
+        });
+        Ok(method)
+    }
+
+    /// Bind `name` to a dispatcher function and reset its method table
+    fn define_generic(&mut self, name: String, params: Vec<String>) -> Value {
+        let mut dispatch_args = vec![crate::parser::Argument::positional(
+            Expression::StringLiteral(name.clone()),
+        )];
+        dispatch_args.extend(
+            params
+                .iter()
+                .map(|p| crate::parser::Argument::positional(Expression::Variable(p.clone()))),
+        );
+        let generic = Value::Function {
+            params,
+            body: Arc::new(Expression::ToolCall {
+                name: "%generic-dispatch".to_string(),
+                args: dispatch_args,
+            }),
+            closure: Arc::new(HashMap::new()),
+            is_flet: false,
+        };
+
+        self.generic_methods.insert(name.clone(), Vec::new());
+        self.env.define(name, generic.clone());
+        generic
+    }
+
+    /// Internal body of generic functions: collect the methods applicable to
+    /// the first argument and run them with standard method combination
+    fn eval_generic_dispatch(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let name = match args.first().map(|a| &a.value) {
+            Some(Expression::StringLiteral(name)) => name.clone(),
+            _ => {
+                return Err(Error::InvalidArguments {
+                    tool: "%generic-dispatch".to_string(),
+                    reason: "Expected generic function name".to_string(),
+                })
+            }
+        };
+
+        let mut values = Vec::with_capacity(args.len() - 1);
+        for arg in &args[1..] {
+            values.push(self.evaluate_expression(&arg.value)?);
+        }
+
+        let candidates = match values.first() {
+            Some(first) => Self::dispatch_types(first),
+            None => vec!["t".to_string()],
+        };
+        let methods = self.generic_methods.get(&name).cloned().unwrap_or_default();
+        let applicable = |qualifier: MethodQualifier| -> Vec<Value> {
+            candidates
+                .iter()
+                .filter_map(|ty| {
+                    methods
+                        .iter()
+                        .find(|m| m.qualifier == qualifier && m.specializer == *ty)
+                        .map(|m| m.function.clone())
+                })
+                .collect()
+        };
+
+        let chain = MethodChain {
+            name: name.clone(),
+            args: values,
+            around: applicable(MethodQualifier::Around),
+            before: applicable(MethodQualifier::Before),
+            primary: applicable(MethodQualifier::Primary),
+            after: applicable(MethodQualifier::After),
+        };
+        if chain.primary.is_empty() {
+            return Err(Error::RuntimeError(format!(
+                "no applicable method for {} on {}",
+                name,
+                candidates
+                    .first()
+                    .map(String::as_str)
+                    .unwrap_or("no arguments")
+            )));
+        }
+        self.run_method_chain(chain)
+    }
+
+    /// Run the next step of a method chain
+    ///
+    /// The most specific `:around` method runs first; once none remain, the
+    /// `:before` methods run most specific first, then the primary method, then
+    /// the `:after` methods least specific first. The primary result is returned.
+    fn run_method_chain(&mut self, mut chain: MethodChain) -> Result<Value> {
+        if !chain.around.is_empty() {
+            let around = chain.around.remove(0);
+            return self.call_method(&around, chain);
+        }
+
+        let before = std::mem::take(&mut chain.before);
+        let after = std::mem::take(&mut chain.after);
+        for method in &before {
+            self.call_method(method, chain.without_next())?;
+        }
+
+        // Only less specific primaries are reachable through call-next-method
+        let primary = chain.primary.remove(0);
+        let result = self.call_method(&primary, chain.clone())?;
+
+        for method in after.iter().rev() {
+            self.call_method(method, chain.without_next())?;
+        }
+        Ok(result)
+    }
+
+    /// Call one method with `next` available to `call-next-method`
+    fn call_method(&mut self, method: &Value, next: MethodChain) -> Result<Value> {
+        let args = next.args.clone();
+        let name = next.name.clone();
+        self.next_methods.push(next);
+        let result = self.call_function(method, &args, &name);
+        self.next_methods.pop();
+        result
+    }
+
+    /// (call-next-method [args...]) - Invoke the next applicable method
+    /// Without arguments the current method's arguments are passed on
+    fn eval_call_next_method(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let mut chain = self.next_methods.last().cloned().ok_or_else(|| {
+            Error::RuntimeError("call-next-method called outside a method".to_string())
+        })?;
+        if chain.around.is_empty() && chain.primary.is_empty() {
+            return Err(Error::RuntimeError(format!(
+                "no next method for {}",
+                chain.name
+            )));
+        }
+
+        if !args.is_empty() {
+            let mut values = Vec::with_capacity(args.len());
+            for arg in args {
+                values.push(self.evaluate_expression(&arg.value)?);
+            }
+            chain.args = values;
+        }
+        self.run_method_chain(chain)
+    }
+
+    /// (next-method-p) - Whether call-next-method has a method to invoke
+    fn eval_next_method_p(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if !args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "next-method-p".to_string(),
+                reason: "Expected no arguments".to_string(),
+            });
+        }
+        Ok(Value::Bool(self.next_methods.last().is_some_and(|chain| {
+            !chain.around.is_empty() || !chain.primary.is_empty()
+        })))
+    }
+
+    /// Types a value belongs to, most specific first
+    /// Objects tagged with a string `type` field dispatch on that tag before `object`
+    fn dispatch_types(value: &Value) -> Vec<String> {
+        let mut types = Vec::new();
+        if let Value::Object(obj) = value {
+            if let Some(Value::String(tag)) = obj.get("type") {
+                types.push(tag.trim_start_matches(':').to_string());
+            }
+        }
+        types.push(value.type_name());
+        if matches!(value, Value::Int(_) | Value::Float(_)) {
+            types.push("number".to_string());
+        }
+        types.push("t".to_string());
+        types
+    }
+
+    /// (defpackage :name (:export sym...)) - Create a package namespace
+    /// Symbols defined while the package is current are internal unless exported
+    fn eval_defpackage(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "defpackage".to_string(),
+                reason: "Expected package name".to_string(),
+            });
+        }
+
+        let name = self.package_name_arg("defpackage", &args[0].value)?;
+        if BASE_PACKAGES.contains(&name.as_str()) {
+            return Err(Error::InvalidArguments {
+                tool: "defpackage".to_string(),
+                reason: format!("Cannot redefine base package {}", name),
+            });
+        }
+        if name == STDLIB_PACKAGE {
+            return Err(Error::InvalidArguments {
+                tool: "defpackage".to_string(),
+                reason: format!("Package {} is reserved for the stdlib tools", name),
+            });
+        }
+
+        let mut exports = std::collections::HashSet::new();
+        for option in &args[1..] {
+            let clause = self.evaluate_expression(&option.value)?;
+            let items = clause.as_array().map_err(|_| Error::InvalidArguments {
+                tool: "defpackage".to_string(),
+                reason: "Options must be lists like (:export sym...)".to_string(),
+            })?;
+            let (keyword, symbols) =
+                items.split_first().ok_or_else(|| Error::InvalidArguments {
+                    tool: "defpackage".to_string(),
+                    reason: "Empty option list".to_string(),
+                })?;
+
+            match keyword.as_string()? {
+                ":export" => {
+                    for sym in symbols {
+                        exports.insert(sym.as_string()?.trim_start_matches(':').to_string());
+                    }
+                }
+                // Every package already falls back to the base package
+                ":use" => {}
+                other => {
+                    return Err(Error::InvalidArguments {
+                        tool: "defpackage".to_string(),
+                        reason: format!("Unsupported option {}", other),
+                    })
+                }
+            }
+        }
+
+        self.packages.insert(name.clone(), exports);
+        Ok(Value::String(name))
+    }
+
+    /// (in-package :name) - Make a package current for subsequent definitions
+    fn eval_in_package(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "in-package".to_string(),
+                reason: "Expected 1 argument: package name".to_string(),
+            });
+        }
+
+        let name = self.package_name_arg("in-package", &args[0].value)?;
+        if BASE_PACKAGES.contains(&name.as_str()) {
+            self.current_package = None;
+        } else if self.packages.contains_key(&name) {
+            self.current_package = Some(name.clone());
+        } else {
+            return Err(Error::RuntimeError(format!(
+                "Package {} does not exist",
+                name
+            )));
+        }
+        Ok(Value::String(name))
+    }
+
+    /// Read a package designator (:utils, utils or "utils") as a lowercase name
+    fn package_name_arg(&mut self, tool: &str, expr: &Expression) -> Result<String> {
+        let name = match expr {
+            Expression::Variable(name) => name.clone(),
+            other => match self.evaluate_expression(other)? {
+                Value::String(s) => s,
+                v => {
+                    return Err(Error::TypeError {
+                        expected: format!("package name for {}", tool),
+                        got: v.type_name(),
+                    })
+                }
+            },
+        };
+        Ok(name.trim_start_matches(':').to_lowercase())
+    }
+
+    /// Map a symbol as written to the name it is bound under in the environment
+    ///
+    /// Package symbols are stored as `pkg::sym`. `pkg:sym` requires `sym` to be
+    /// exported, while `pkg::sym` reaches internal symbols too. Unqualified names
+    /// prefer local bindings, then the current package, then the base package.
+    fn resolve_symbol(&self, name: &str) -> Result<String> {
+        if let Some((pkg, sym, internal)) = Self::split_qualified(name) {
+            if BASE_PACKAGES.contains(&pkg) {
+                return Ok(sym.to_string());
+            }
+            // Left qualified so calls reach the registry's stdlib tools
+            if pkg == STDLIB_PACKAGE {
+                return Ok(name.to_string());
+            }
+            let exports = self
+                .packages
+                .get(pkg)
+                .ok_or_else(|| Error::RuntimeError(format!("Package {} does not exist", pkg)))?;
+            if !internal && !exports.contains(sym) {
+                return Err(Error::RuntimeError(format!(
+                    "Symbol {} is not exported from package {}",
+                    sym, pkg
+                )));
+            }
+            return Ok(format!("{}::{}", pkg, sym));
+        }
+
+        if let Some(pkg) = &self.current_package {
+            let qualified = format!("{}::{}", pkg, name);
+            if !self.env.is_local(name) && self.env.exists(&qualified) {
+                return Ok(qualified);
+            }
+        }
+        Ok(name.to_string())
+    }
+
+    /// Name a top-level `define`/`defun` binds: definitions made while a
+    /// package is current belong to that package
+    fn package_binding_name(&self, name: &str) -> Result<String> {
+        if Self::split_qualified(name).is_some() {
+            return self.resolve_symbol(name);
+        }
+        match &self.current_package {
+            Some(pkg) if self.env.scope_depth() == 1 => Ok(format!("{}::{}", pkg, name)),
+            _ => Ok(name.to_string()),
+        }
+    }
+
+    /// Split `pkg:sym` / `pkg::sym` into (pkg, sym, is_internal_reference)
+    fn split_qualified(name: &str) -> Option<(&str, &str, bool)> {
+        if name.starts_with(':') {
+            return None;
+        }
+        if let Some((pkg, sym)) = name.split_once("::") {
+            return Some((pkg, sym, true));
+        }
+        name.split_once(':').map(|(pkg, sym)| (pkg, sym, false))
+    }
+
+    /// (defmacro name (params...) body) - Define macro
+    /// Macros are compile-time code transformers that receive unevaluated arguments
+    fn eval_defmacro(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 3 {
             return Err(Error::InvalidArguments {
                 tool: "defmacro".to_string(),
                 reason: "Expected 3 arguments: name, parameters, body".to_string(),
@@ -830,7 +2155,7 @@ impl LispEvaluator {
     }
 
     /// (let ((x v)...) body) - Lexical scope with bindings
-    fn eval_let(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+    fn eval_let(&mut self, args: &[crate::parser::Argument], tail: bool) -> Result<Value> {
         if args.len() < 2 {
             return Err(Error::InvalidArguments {
                 tool: "let".to_string(),
@@ -889,16 +2214,20 @@ impl LispEvaluator {
             self.env.define(var_name, value);
         }
 
-        // Execute body
-        let mut last_val = Value::Null;
-        for arg in &args[1..] {
-            last_val = self.evaluate_expression(&arg.value)?;
+        // Execute body, last form in tail position
+        let body = &args[1..];
+        let mut result = Ok(Value::Null);
+        for (i, arg) in body.iter().enumerate() {
+            result = self.evaluate_tail(&arg.value, tail && i == body.len() - 1);
+            if result.is_err() {
+                break;
+            }
         }
 
         // Exit scope
         self.env.exit_scope();
 
-        Ok(last_val)
+        result
     }
 
     /// (let* ((var val)...) body) - Sequential binding where each binding can reference previous ones
@@ -1146,76 +2475,112 @@ impl LispEvaluator {
         Ok(last_val)
     }
 
-    /// (case expr (value result)... (else default)) - Pattern matching by value
-    fn eval_case(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() < 2 {
+    /// (case expr (value result...)... (else default...)) - Pattern matching by value
+    ///
+    /// Without a matching clause `case` returns nil, while `ecase` and `ccase`
+    /// signal a type error naming the value and the allowed keys. `ccase`
+    /// takes a `:store-value fn` restart: on a miss `fn` is called with the
+    /// value and the allowed keys, and its result is stored into the key
+    /// place (when it is one) and matched again.
+    fn eval_case(&mut self, args: &[crate::parser::Argument], tool: &str) -> Result<Value> {
+        let mut store_value = None;
+        let mut positional = Vec::with_capacity(args.len());
+        for arg in args {
+            match &arg.name {
+                Some(keyword)
+                    if tool == "ccase" && keyword.trim_start_matches(':') == "store-value" =>
+                {
+                    store_value = Some(self.evaluate_expression(&arg.value)?);
+                }
+                _ => positional.push(arg),
+            }
+        }
+        if positional.len() < 2 {
             return Err(Error::InvalidArguments {
-                tool: "case".to_string(),
+                tool: tool.to_string(),
                 reason: "Expected at least 2 arguments: test expression and clauses".to_string(),
             });
         }
 
-        // Evaluate the test expression
-        let test_value = self.evaluate_expression(&args[0].value)?;
+        // Resolve the key place once, so retries store without re-evaluating it
+        let place = self.resolve_list_place(&positional[0].value)?;
+        let mut test_value = self.list_place_value(&positional[0].value, &place)?;
 
-        // Process each clause
-        for arg in &args[1..] {
-            match &arg.value {
-                Expression::ArrayLiteral(clause) if clause.len() == 2 => {
-                    // Check if this is an else clause
-                    if let Expression::Variable(var) = &clause[0] {
-                        if var == "else" || var == "otherwise" || var == "t" {
-                            // Else clause matches everything
-                            return self.evaluate_expression(&clause[1]);
+        loop {
+            // Keys seen so far, for the ecase/ccase error
+            let mut keys = Vec::new();
+
+            // Process each clause
+            for arg in &positional[1..] {
+                match &arg.value {
+                    Expression::ArrayLiteral(clause) if clause.len() >= 2 => {
+                        // Check if this is an else clause
+                        if let Expression::Variable(var) = &clause[0] {
+                            if var == "else" || var == "otherwise" || var == "t" {
+                                // Else clause matches everything
+                                return self.eval_clause_body(&clause[1..]);
+                            }
                         }
-                    }
 
-                    // Match pattern (can be single value or list of values)
-                    let matches = match &clause[0] {
-                        // Single value to match
-                        Expression::Variable(_)
-                        | Expression::IntLiteral(_)
-                        | Expression::FloatLiteral(_)
-                        | Expression::StringLiteral(_)
-                        | Expression::BoolLiteral(_) => {
-                            let pattern_value = self.evaluate_expression(&clause[0])?;
-                            self.values_equal(&test_value, &pattern_value)
-                        }
-                        // Multiple values to match (any can match)
-                        Expression::ArrayLiteral(patterns) => {
-                            let mut any_match = false;
-                            for pattern in patterns {
-                                let pattern_value = self.evaluate_expression(pattern)?;
-                                if self.values_equal(&test_value, &pattern_value) {
-                                    any_match = true;
-                                    break;
+                        // Match pattern (can be single value or list of values)
+                        let matches = match &clause[0] {
+                            // Multiple values to match (any can match)
+                            Expression::ArrayLiteral(patterns) => {
+                                let mut any_match = false;
+                                for pattern in patterns {
+                                    let pattern_value = self.evaluate_expression(pattern)?;
+                                    if self.values_equal(&test_value, &pattern_value) {
+                                        any_match = true;
+                                        break;
+                                    }
+                                    keys.push(pattern_value);
                                 }
+                                any_match
                             }
-                            any_match
-                        }
-                        _ => {
-                            let pattern_value = self.evaluate_expression(&clause[0])?;
-                            self.values_equal(&test_value, &pattern_value)
-                        }
-                    };
+                            // Single value to match
+                            _ => {
+                                let pattern_value = self.evaluate_expression(&clause[0])?;
+                                let matched = self.values_equal(&test_value, &pattern_value);
+                                keys.push(pattern_value);
+                                matched
+                            }
+                        };
 
-                    if matches {
-                        return self.evaluate_expression(&clause[1]);
+                        if matches {
+                            return self.eval_clause_body(&clause[1..]);
+                        }
+                    }
+                    _ => {
+                        return Err(Error::ParseError(
+                            "case clauses must be (pattern result...) lists".to_string(),
+                        ))
                     }
-                }
-                _ => {
-                    return Err(Error::ParseError(
-                        "case clauses must be (pattern result) pairs".to_string(),
-                    ))
                 }
             }
-        }
 
-        // No match found and no else clause
-        Ok(Value::Null)
+            // No match found and no else clause
+            if tool == "case" {
+                return Ok(Value::Null);
+            }
+            let Some(handler) = &store_value else {
+                let keys: Vec<String> = keys.iter().map(Self::readable_string).collect();
+                return Err(Error::TypeError {
+                    expected: format!("one of {} ({} key)", keys.join(", "), tool),
+                    got: Self::readable_string(&test_value),
+                });
+            };
+
+            // store-value restart: take a new key from the handler and retry
+            let handler = handler.clone();
+            test_value =
+                self.call_function(&handler, &[test_value, Value::array(keys)], "store-value")?;
+            if let Some((var, path)) = &place {
+                self.store_place(var, path, test_value.clone())?;
+            }
+        }
     }
 
-    /// (typecase expr (type result)... (else default)) - Pattern matching by type
+    /// (typecase expr (type result...)... (else default...)) - Pattern matching by type
     fn eval_typecase(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() < 2 {
             return Err(Error::InvalidArguments {
@@ -1231,11 +2596,11 @@ impl LispEvaluator {
         // Process each clause
         for arg in &args[1..] {
             match &arg.value {
-                Expression::ArrayLiteral(clause) if clause.len() == 2 => {
-                    // Array literal syntax: [type result]
+                Expression::ArrayLiteral(clause) if clause.len() >= 2 => {
+                    // Array literal syntax: [type result...]
                     if let Expression::Variable(var) = &clause[0] {
                         if var == "else" || var == "otherwise" || var == "t" {
-                            return self.evaluate_expression(&clause[1]);
+                            return self.eval_clause_body(&clause[1..]);
                         }
                     }
 
@@ -1261,16 +2626,18 @@ impl LispEvaluator {
                     };
 
                     if type_match {
-                        return self.evaluate_expression(&clause[1]);
+                        return self.eval_clause_body(&clause[1..]);
                     }
                 }
                 Expression::ToolCall {
                     args: clause_args, ..
-                } if clause_args.len() == 2 => {
-                    // Parenthesized syntax: (type result)
+                } if clause_args.len() >= 2 => {
+                    // Parenthesized syntax: (type result...)
                     if let Expression::Variable(var) = &clause_args[0].value {
                         if var == "else" || var == "otherwise" || var == "t" {
-                            return self.evaluate_expression(&clause_args[1].value);
+                            let body: Vec<Expression> =
+                                clause_args[1..].iter().map(|a| a.value.clone()).collect();
+                            return self.eval_clause_body(&body);
                         }
                     }
 
@@ -1296,12 +2663,14 @@ impl LispEvaluator {
                     };
 
                     if type_match {
-                        return self.evaluate_expression(&clause_args[1].value);
+                        let body: Vec<Expression> =
+                            clause_args[1..].iter().map(|a| a.value.clone()).collect();
+                        return self.eval_clause_body(&body);
                     }
                 }
                 _ => {
                     return Err(Error::ParseError(
-                        "typecase clauses must be (type result) pairs".to_string(),
+                        "typecase clauses must be (type result...) lists".to_string(),
                     ))
                 }
             }
@@ -1311,6 +2680,16 @@ impl LispEvaluator {
         Ok(Value::Null)
     }
 
+    /// Helper: Evaluate the result forms of a matched case/typecase clause,
+    /// returning the value of the last one
+    fn eval_clause_body(&mut self, forms: &[Expression]) -> Result<Value> {
+        let mut result = Value::Null;
+        for form in forms {
+            result = self.evaluate_expression(form)?;
+        }
+        Ok(result)
+    }
+
     /// Helper: Check if two values are equal (for case matching)
     fn values_equal(&self, a: &Value, b: &Value) -> bool {
         match (a, b) {
@@ -1341,6 +2720,9 @@ impl LispEvaluator {
     }
 
     /// (while cond body...) - While loop
+    ///
+    /// `(break value)` in the body ends the loop with value; `(continue)`
+    /// starts the next iteration. The same holds for `for` and `loop ... do`.
     fn eval_while(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.is_empty() {
             return Err(Error::InvalidArguments {
@@ -1368,6 +2750,7 @@ impl LispEvaluator {
                     limit: max_iterations,
                 });
             }
+            self.check_interrupt()?;
 
             // Evaluate condition
             let cond_val = self.evaluate_expression(condition_expr)?;
@@ -1376,14 +2759,259 @@ impl LispEvaluator {
             }
 
             // Execute body
-            for arg in body_args {
-                last_val = self.evaluate_expression(&arg.value)?;
+            match self.run_loop_body(body_args.iter().map(|arg| &arg.value))? {
+                ControlFlow::Continue(value) => last_val = value,
+                ControlFlow::Break(value) => return Ok(value),
             }
         }
 
         Ok(last_val)
     }
 
+    /// (break [value]) - Leave the nearest enclosing loop, which returns value
+    fn eval_break(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() > 1 {
+            return Err(Error::InvalidArguments {
+                tool: "break".to_string(),
+                reason: "Expected at most 1 argument: the loop's result".to_string(),
+            });
+        }
+        if self.loop_depth == 0 {
+            return Err(Error::InvalidBreak);
+        }
+        let value = match args.first() {
+            Some(arg) => self.evaluate_expression(&arg.value)?,
+            None => Value::Null,
+        };
+        Err(Error::LoopBreak {
+            value: Box::new(value),
+        })
+    }
+
+    /// (continue) - Skip the rest of the body of the nearest enclosing loop
+    fn eval_continue(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if !args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "continue".to_string(),
+                reason: "Expected no arguments".to_string(),
+            });
+        }
+        if self.loop_depth == 0 {
+            return Err(Error::InvalidContinue);
+        }
+        Err(Error::LoopContinue)
+    }
+
+    /// Run one pass of a loop body, turning `break`/`continue` into control flow
+    ///
+    /// Returns the value of the last form, `Continue` after a `(continue)`
+    /// (with nil) and `Break` with the value of a `(break ...)`.
+    fn run_loop_body<'e>(
+        &mut self,
+        body: impl IntoIterator<Item = &'e Expression>,
+    ) -> Result<ControlFlow<Value, Value>> {
+        self.loop_depth += 1;
+        let mut result = Ok(Value::Null);
+        for expr in body {
+            result = self.evaluate_expression(expr);
+            if result.is_err() {
+                break;
+            }
+        }
+        self.loop_depth -= 1;
+        match result {
+            Ok(value) => Ok(ControlFlow::Continue(value)),
+            Err(Error::LoopContinue) => Ok(ControlFlow::Continue(Value::Null)),
+            Err(Error::LoopBreak { value }) => Ok(ControlFlow::Break(*value)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// (do ((var init step)...) (end-test result...) body...) - General iteration
+    ///
+    /// `do` evaluates inits and steps in parallel; `do*` (sequential) binds and
+    /// steps each variable in order so later clauses see earlier updates.
+    /// The parser emits this as `doloop` / `doloop*`.
+    fn eval_do_loop(
+        &mut self,
+        args: &[crate::parser::Argument],
+        sequential: bool,
+    ) -> Result<Value> {
+        let tool = if sequential { "do*" } else { "do" };
+        let (clauses, end) = match (args.first(), args.get(1)) {
+            (Some(clauses), Some(end)) => match (&clauses.value, &end.value) {
+                (Expression::ArrayLiteral(clauses), Expression::ArrayLiteral(end))
+                    if !end.is_empty() =>
+                {
+                    (clauses, end)
+                }
+                _ => {
+                    return Err(Error::InvalidArguments {
+                        tool: tool.to_string(),
+                        reason: "Expected variable clauses and (end-test result...)".to_string(),
+                    })
+                }
+            },
+            _ => {
+                return Err(Error::InvalidArguments {
+                    tool: tool.to_string(),
+                    reason: "Expected variable clauses and (end-test result...)".to_string(),
+                })
+            }
+        };
+
+        // Each clause is [var init? step?]
+        let mut vars = Vec::with_capacity(clauses.len());
+        for clause in clauses {
+            match clause {
+                Expression::ArrayLiteral(parts) => match parts.as_slice() {
+                    [Expression::Variable(name), rest @ ..] => {
+                        vars.push((name.clone(), rest.first(), rest.get(1)))
+                    }
+                    _ => {
+                        return Err(Error::ParseError(format!(
+                            "{}: variable clause must start with a name",
+                            tool
+                        )))
+                    }
+                },
+                _ => {
+                    return Err(Error::ParseError(format!(
+                        "{}: malformed variable clause",
+                        tool
+                    )))
+                }
+            }
+        }
+
+        let inits: Vec<Option<Value>> = if sequential {
+            Vec::new()
+        } else {
+            vars.iter()
+                .map(|(_, init, _)| init.map(|e| self.evaluate_expression(e)).transpose())
+                .collect::<Result<_>>()?
+        };
+
+        self.env.enter_scope();
+        let result = self.run_do_loop(&vars, inits, end, &args[2..], sequential);
+        self.env.exit_scope();
+        result
+    }
+
+    /// Loop driver for `eval_do_loop`, run inside the loop's own scope
+    fn run_do_loop(
+        &mut self,
+        vars: &[(String, Option<&Expression>, Option<&Expression>)],
+        inits: Vec<Option<Value>>,
+        end: &[Expression],
+        body: &[crate::parser::Argument],
+        sequential: bool,
+    ) -> Result<Value> {
+        if sequential {
+            for (name, init, _) in vars {
+                let value = match init {
+                    Some(e) => self.evaluate_expression(e)?,
+                    None => Value::Null,
+                };
+                self.env.define(name.clone(), value);
+            }
+        } else {
+            for ((name, _, _), value) in vars.iter().zip(inits) {
+                self.env.define(name.clone(), value.unwrap_or(Value::Null));
+            }
+        }
+
+        let max_iterations = std::env::var("OVSM_MAX_ITERATIONS")
+            .ok()
+            .and_then(|s| s.parse::<usize>().ok())
+            .unwrap_or(10_000_000);
+        let mut iterations = 0;
+
+        loop {
+            iterations += 1;
+            if iterations > max_iterations {
+                return Err(Error::TooManyIterations {
+                    limit: max_iterations,
+                });
+            }
+            self.check_interrupt()?;
+
+            if self.evaluate_expression(&end[0])?.is_truthy() {
+                let mut result = Value::Null;
+                for form in &end[1..] {
+                    result = self.evaluate_expression(form)?;
+                }
+                return Ok(result);
+            }
+
+            for arg in body {
+                self.evaluate_expression(&arg.value)?;
+            }
+
+            if sequential {
+                for (name, _, step) in vars {
+                    if let Some(step) = step {
+                        let value = self.evaluate_expression(step)?;
+                        self.env.set(name, value)?;
+                    }
+                }
+            } else {
+                let mut stepped = Vec::new();
+                for (name, _, step) in vars {
+                    if let Some(step) = step {
+                        stepped.push((name, self.evaluate_expression(step)?));
+                    }
+                }
+                for (name, value) in stepped {
+                    self.env.set(name, value)?;
+                }
+            }
+        }
+    }
+
+    /// Evaluate a collection argument for iteration
+    ///
+    /// A direct integer `(range ...)` call and `Value::Range` are walked lazily
+    /// instead of being built as arrays. Laziness is decided from the syntax:
+    /// `range` itself returns an array, so a range bound to a variable first,
+    /// as in `(define r (range 0 n))`, is already materialized.
+    fn eval_sequence_items(&mut self, expr: &Expression) -> Result<SequenceItems> {
+        let collection = if Self::is_range_call(expr) {
+            let Expression::ToolCall { args, .. } = expr else {
+                unreachable!("is_range_call only accepts tool calls")
+            };
+            let bounds = self.range_bounds(args)?;
+            if let [Value::Int(start), Value::Int(end), Value::Int(step)] = bounds {
+                return Ok(SequenceItems::Range {
+                    next: start,
+                    end,
+                    step,
+                });
+            }
+            self.materialize_range(bounds)?
+        } else {
+            self.evaluate_expression(expr)?
+        };
+
+        match collection {
+            Value::Range { start, end } => Ok(SequenceItems::Range {
+                next: start,
+                end,
+                step: 1,
+            }),
+            Value::Array(items) => Ok(SequenceItems::Array { items, index: 0 }),
+            other => Err(Error::TypeError {
+                expected: "array".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
+    /// Whether `expr` calls the builtin `range`
+    fn is_range_call(expr: &Expression) -> bool {
+        matches!(expr, Expression::ToolCall { name, .. } if name == "range")
+    }
+
     /// (for (var coll) body...) - For loop
     ///
     /// When parsing (for (x [1 2 3]) body...), the S-expression parser flattens the inner list,
@@ -1407,33 +3035,21 @@ impl LispEvaluator {
             }
         };
 
-        // Second arg is the collection expression
-        let collection_expr = &args[1].value;
-
-        // Evaluate the collection
-        let collection = self.evaluate_expression(collection_expr)?;
-
-        // Get items to iterate over
-        let items = match collection {
-            Value::Array(ref arr) => arr.iter().cloned().collect::<Vec<_>>(),
-            _ => {
-                return Err(Error::TypeError {
-                    expected: "array".to_string(),
-                    got: collection.type_name(),
-                })
-            }
-        };
+        // Second arg is the collection expression; ranges are iterated lazily
+        let items = self.eval_sequence_items(&args[1].value)?;
 
         // DON'T create new scope - loops should share scope with parent
         // This allows set! to modify outer variables
         let mut last_val = Value::Null;
         for item in items {
+            self.check_interrupt()?;
             // Bind loop variable (this will shadow any existing variable with same name)
             self.env.define(var_name.clone(), item);
 
             // Execute body (args[2..] because args[0]=var, args[1]=collection)
-            for arg in &args[2..] {
-                last_val = self.evaluate_expression(&arg.value)?;
+            match self.run_loop_body(args[2..].iter().map(|arg| &arg.value))? {
+                ControlFlow::Continue(value) => last_val = value,
+                ControlFlow::Break(value) => return Ok(value),
             }
         }
 
@@ -1441,10 +3057,10 @@ impl LispEvaluator {
     }
 
     /// (do expr1 expr2 ... exprN) - Sequential execution
-    fn eval_do(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+    fn eval_do(&mut self, args: &[crate::parser::Argument], tail: bool) -> Result<Value> {
         let mut last_val = Value::Null;
-        for arg in args.iter() {
-            last_val = self.evaluate_expression(&arg.value)?;
+        for (i, arg) in args.iter().enumerate() {
+            last_val = self.evaluate_tail(&arg.value, tail && i == args.len() - 1)?;
         }
         Ok(last_val)
     }
@@ -1509,6 +3125,81 @@ impl LispEvaluator {
         }
     }
 
+    /// (if-let (var expr) then [else]) / (when-let (var expr) body...) /
+    /// (when-some (var expr) body...) - Bind var for the branch it guards
+    ///
+    /// `if-let` and `when-let` run the body only if the value is truthy;
+    /// `when-some` runs it for anything but nil, so `false` and `0` pass.
+    /// The binding may also be written `[var expr]` or `((var expr))`, and
+    /// is only visible in the body, not in the `if-let` fallback.
+    fn eval_binding_conditional(
+        &mut self,
+        tool: &str,
+        args: &[crate::parser::Argument],
+        tail: bool,
+    ) -> Result<Value> {
+        let if_form = tool == "if-let";
+        if args.len() < 2 || (if_form && args.len() > 3) {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: if if_form {
+                    "Expected a (var expr) binding, a then form and an optional else form"
+                } else {
+                    "Expected a (var expr) binding and a body"
+                }
+                .to_string(),
+            });
+        }
+
+        let (var, value_expr) = match &args[0].value {
+            Expression::ToolCall { name, args } if args.len() == 1 => {
+                (name.clone(), &args[0].value)
+            }
+            Expression::ArrayLiteral(pair) => match pair.as_slice() {
+                [Expression::Variable(name), expr] => (name.clone(), expr),
+                [Expression::ArrayLiteral(inner)] => match inner.as_slice() {
+                    [Expression::Variable(name), expr] => (name.clone(), expr),
+                    _ => return Err(Self::binding_conditional_error(tool)),
+                },
+                _ => return Err(Self::binding_conditional_error(tool)),
+            },
+            _ => return Err(Self::binding_conditional_error(tool)),
+        };
+
+        let value = self.evaluate_expression(value_expr)?;
+        let passes = if tool == "when-some" {
+            !matches!(value, Value::Null)
+        } else {
+            value.is_truthy()
+        };
+        if !passes {
+            return match args.get(2) {
+                Some(else_arg) if if_form => self.evaluate_tail(&else_arg.value, tail),
+                _ => Ok(Value::Null),
+            };
+        }
+
+        let body = if if_form { &args[1..2] } else { &args[1..] };
+        self.env.enter_scope();
+        self.env.define(var, value);
+        let mut result = Ok(Value::Null);
+        for (i, arg) in body.iter().enumerate() {
+            result = self.evaluate_tail(&arg.value, tail && i == body.len() - 1);
+            if result.is_err() {
+                break;
+            }
+        }
+        self.env.exit_scope();
+        result
+    }
+
+    fn binding_conditional_error(tool: &str) -> Error {
+        Error::ParseError(format!(
+            "{} requires a single binding: (var expr) or [var expr]",
+            tool
+        ))
+    }
+
     /// (unless cond body...) - Inverted when (execute if condition is false)
     fn eval_unless(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.is_empty() {
@@ -1531,7 +3222,7 @@ impl LispEvaluator {
     }
 
     /// (cond (test1 result1) (test2 result2) ... (else default)) - Multi-way conditional
-    fn eval_cond(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+    fn eval_cond(&mut self, args: &[crate::parser::Argument], tail: bool) -> Result<Value> {
         for arg in args {
             // Each clause can be either an array literal [cond result] or a ToolCall (cond result)
             let (condition_expr, result_expr) = match &arg.value {
@@ -1570,13 +3261,13 @@ impl LispEvaluator {
             };
 
             if is_else {
-                return self.evaluate_expression(result_expr);
+                return self.evaluate_tail(result_expr, tail);
             }
 
             // Evaluate condition
             let cond_val = self.evaluate_expression(condition_expr)?;
             if cond_val.is_truthy() {
-                return self.evaluate_expression(result_expr);
+                return self.evaluate_tail(result_expr, tail);
             }
         }
 
@@ -1676,7 +3367,7 @@ impl LispEvaluator {
         Ok(Value::Bool(matches!(val, Value::Float(_))))
     }
 
-    /// (number? x) - Check if number (int or float)
+    /// (number? x) - Check if number (int, float or complex)
     fn eval_number_check(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 1 {
             return Err(Error::InvalidArguments {
@@ -1685,7 +3376,10 @@ impl LispEvaluator {
             })?;
         }
         let val = self.evaluate_expression(&args[0].value)?;
-        Ok(Value::Bool(matches!(val, Value::Int(_) | Value::Float(_))))
+        Ok(Value::Bool(matches!(
+            val,
+            Value::Int(_) | Value::Float(_) | Value::Complex { .. }
+        )))
     }
 
     /// (string? x) - Check if string
@@ -1745,7 +3439,10 @@ impl LispEvaluator {
             })?;
         }
         let val = self.evaluate_expression(&args[0].value)?;
-        Ok(Value::Bool(matches!(val, Value::Function { .. })))
+        Ok(Value::Bool(matches!(
+            val,
+            Value::Function { .. } | Value::Memoized { .. }
+        )))
     }
 
     /// (typeof x) or (type-of x) - Return type as string
@@ -1761,15 +3458,18 @@ impl LispEvaluator {
         let type_str = match val {
             Value::Int(_) => "number", // JS-style: int and float both return "number"
             Value::Float(_) => "number", // JS-style
+            Value::Complex { .. } => "complex",
+            Value::Instant(_) => "instant",
             Value::String(_) => "string",
             Value::Bool(_) => "boolean",
             Value::Array(_) => "array",
             Value::Object(_) => "object",
-            Value::Function { .. } => "function",
+            Value::Function { .. } | Value::Memoized { .. } => "function",
             Value::Null => "null",
             Value::Range { .. } => "range",
             Value::Multiple(_) => "multiple", // Common LISP multiple values
             Value::Macro { .. } => "macro",   // LISP macros
+            Value::LazySeq(_) => "lazy-seq",
             Value::AsyncHandle { .. } => "async-handle", // Async operation handle
             Value::Thread { .. } => "thread",
             Value::Lock { .. } => "lock",
@@ -1777,24 +3477,153 @@ impl LispEvaluator {
             Value::ConditionVariable { .. } => "condition-variable",
             Value::Semaphore { .. } => "semaphore",
             Value::AtomicInteger { .. } => "atomic-integer",
+            Value::Channel { .. } => "channel",
+            Value::Promise { .. } => "promise",
+            Value::AtomicRef { .. } => "atomic-ref",
         };
         Ok(Value::String(type_str.to_string()))
     }
 
-    /// (assert condition "message") - Assert condition is true
+    /// Helper: Evaluate a single symbol-name argument such as `'x` to a string
+    fn eval_symbol_name_arg(
+        &mut self,
+        tool: &str,
+        args: &[crate::parser::Argument],
+    ) -> Result<String> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!("Expected 1 argument, got {}", args.len()),
+            });
+        }
+        match self.evaluate_expression(&args[0].value)? {
+            Value::String(name) => Ok(name),
+            other => Err(Error::TypeError {
+                expected: "symbol name".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
+    /// (bound? 'name) - True if name is defined in any enclosing scope
+    fn eval_bound_check(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let name = self.eval_symbol_name_arg("bound?", args)?;
+        let bound = match self.resolve_symbol(&name) {
+            Ok(resolved) => self.env.exists(&resolved) || self.env.is_dynamic(&resolved),
+            Err(_) => false,
+        };
+        Ok(Value::Bool(bound))
+    }
+
+    /// (list-bindings) - Names of the variables in the current scope
+    fn eval_list_bindings(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if !args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "list-bindings".to_string(),
+                reason: "Expected no arguments".to_string(),
+            });
+        }
+        let names = self
+            .env
+            .current_scope_names()
+            .into_iter()
+            .map(Value::String)
+            .collect();
+        Ok(Value::array(names))
+    }
+
+    /// (describe 'name) - Describe a binding
+    /// Returns {:name :type} plus {:params :arity :variadic} for functions
+    fn eval_describe(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let name = self.eval_symbol_name_arg("describe", args)?;
+        let resolved = self.resolve_symbol(&name)?;
+        let value = self.env.get(&resolved)?;
+
+        let mut obj = HashMap::new();
+        obj.insert("name".to_string(), Value::String(name));
+        obj.insert("type".to_string(), Value::String(value.type_name()));
+
+        let params = match &value {
+            Value::Function { params, .. } | Value::Macro { params, .. } => Some(params.clone()),
+            Value::Memoized { func, .. } => match func.as_ref() {
+                Value::Function { params, .. } => Some(params.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some(params) = params {
+            // Optional and key parameters are stored as (name, default) pairs
+            let mut names = Vec::new();
+            let mut required = 0;
+            let mut section = "required";
+            let mut i = 0;
+            while i < params.len() {
+                let param = &params[i];
+                match param.as_str() {
+                    "&optional" | "&key" | "&rest" => {
+                        section = param.as_str();
+                        names.push(Value::String(param.clone()));
+                        i += 1;
+                        continue;
+                    }
+                    _ => {}
+                }
+                names.push(Value::String(param.clone()));
+                match section {
+                    "required" => {
+                        required += 1;
+                        i += 1;
+                    }
+                    "&optional" | "&key" => i += 2,
+                    _ => i += 1,
+                }
+            }
+            obj.insert("params".to_string(), Value::array(names));
+            obj.insert("arity".to_string(), Value::Int(required));
+            obj.insert(
+                "variadic".to_string(),
+                Value::Bool(params.iter().any(|p| p == "&rest")),
+            );
+        }
+
+        Ok(Value::object(obj))
+    }
+
+    /// (assert condition [message format-args...]) - Assert condition is true
+    ///
+    /// A message with arguments is expanded like `format`. Without a message
+    /// the failed form is described, with the operand values of a comparison:
+    /// "assertion failed: (< x 10), x = 15".
     fn eval_assert(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 2 {
+        if args.is_empty() {
             return Err(Error::InvalidArguments {
                 tool: "assert".to_string(),
-                reason: format!(
-                    "Expected 2 arguments (condition, message), got {}",
-                    args.len()
-                ),
+                reason: "Expected a condition, an optional message and format arguments"
+                    .to_string(),
             })?;
         }
 
-        // Evaluate condition
-        let condition = self.evaluate_expression(&args[0].value)?;
+        // Evaluate condition, keeping comparison operands for the message
+        let (condition, operands) = match &args[0].value {
+            Expression::Binary { op, left, right }
+                if matches!(
+                    op,
+                    BinaryOp::Eq
+                        | BinaryOp::NotEq
+                        | BinaryOp::Lt
+                        | BinaryOp::Gt
+                        | BinaryOp::LtEq
+                        | BinaryOp::GtEq
+                ) =>
+            {
+                let left_val = self.evaluate_expression(left)?;
+                let right_val = self.evaluate_expression(right)?;
+                let operands = vec![(&**left, left_val.clone()), (&**right, right_val.clone())];
+                (self.apply_binary_op(*op, left_val, right_val)?, operands)
+            }
+            expr => (self.evaluate_expression(expr)?, Vec::new()),
+        };
         let is_true = match condition {
             Value::Bool(b) => b,
             _ => {
@@ -1806,11 +3635,45 @@ impl LispEvaluator {
         };
 
         if !is_true {
-            // Evaluate message
-            let message = self.evaluate_expression(&args[1].value)?;
-            let message_str = match message {
-                Value::String(s) => s,
-                _ => format!("{:?}", message),
+            let message_str = match args.get(1) {
+                Some(message_arg) => {
+                    let message = self.evaluate_expression(&message_arg.value)?;
+                    match message {
+                        Value::String(control) if args.len() > 2 => {
+                            let mut format_args = Vec::with_capacity(args.len() - 2);
+                            for arg in &args[2..] {
+                                format_args.push(self.evaluate_expression(&arg.value)?);
+                            }
+                            self.format_directives(&control, &format_args)
+                        }
+                        Value::String(s) => s,
+                        _ => format!("{:?}", message),
+                    }
+                }
+                // No message: describe the failed form and its operands
+                None => {
+                    let mut message = format!(
+                        "assertion failed: {}",
+                        self.expression_source(&args[0].value)
+                    );
+                    for (expr, value) in operands {
+                        if !matches!(
+                            expr,
+                            Expression::IntLiteral(_)
+                                | Expression::FloatLiteral(_)
+                                | Expression::StringLiteral(_)
+                                | Expression::BoolLiteral(_)
+                                | Expression::NullLiteral
+                        ) {
+                            message.push_str(&format!(
+                                ", {} = {}",
+                                self.expression_source(expr),
+                                Self::readable_string(&value)
+                            ));
+                        }
+                    }
+                    message
+                }
             };
             return Err(Error::AssertionFailed {
                 message: message_str,
@@ -1820,59 +3683,37 @@ impl LispEvaluator {
         Ok(Value::Null)
     }
 
-    /// (assert-type value predicate) - Assert value matches type predicate
+    /// (assert-type value 'spec) - Assert value matches a type specifier
+    /// Specs: 'u64, 'integer, '(array integer), '(or int string), '(and number (not float))
+    /// A boolean second argument is still accepted as a pre-evaluated predicate
     fn eval_assert_type(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 2 {
             return Err(Error::InvalidArguments {
                 tool: "assert-type".to_string(),
                 reason: format!(
-                    "Expected 2 arguments (value, predicate), got {}",
+                    "Expected 2 arguments (value, type spec), got {}",
                     args.len()
                 ),
             })?;
         }
 
-        // Evaluate value
         let value = self.evaluate_expression(&args[0].value)?;
-
-        // Evaluate type predicate (should be a function call like (int? x))
-        let predicate_result = self.evaluate_expression(&args[1].value)?;
-
-        let is_valid = match predicate_result {
-            Value::Bool(b) => b,
-            _ => {
-                return Err(Error::TypeError {
-                    expected: "bool (type predicate)".to_string(),
-                    got: format!("{:?}", predicate_result),
-                })
-            }
+        let spec = self.evaluate_expression(&args[1].value)?;
+
+        let (is_valid, expected) = match &spec {
+            Value::Bool(b) => (*b, "different type".to_string()),
+            _ => (
+                self.value_matches_type_spec(&value, &spec)?,
+                Self::type_spec_to_string(&spec),
+            ),
         };
 
         if !is_valid {
-            let type_name = match value {
-                Value::Null => "null",
-                Value::Bool(_) => "bool",
-                Value::Int(_) => "int",
-                Value::Float(_) => "float",
-                Value::String(_) => "string",
-                Value::Array(_) => "array",
-                Value::Object(_) => "object",
-                Value::Range { .. } => "range",
-                Value::Function { .. } => "function",
-                Value::Multiple(_) => "multiple-values",
-                Value::Macro { .. } => "macro",
-                Value::AsyncHandle { .. } => "async-handle",
-                Value::Thread { .. } => "thread",
-                Value::Lock { .. } => "lock",
-                Value::RecursiveLock { .. } => "recursive-lock",
-                Value::ConditionVariable { .. } => "condition-variable",
-                Value::Semaphore { .. } => "semaphore",
-                Value::AtomicInteger { .. } => "atomic-integer",
-            };
             return Err(Error::AssertionFailed {
                 message: format!(
-                    "Type assertion failed: expected different type, got {}",
-                    type_name
+                    "Type assertion failed: expected {}, got {}",
+                    expected,
+                    value.type_name()
                 ),
             });
         }
@@ -1880,13 +3721,113 @@ impl LispEvaluator {
         Ok(Value::Null)
     }
 
-    /// (try body (catch error-var handler) [(finally cleanup)])
-    /// Error handling with optional finally block
-    fn eval_try(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() < 2 || args.len() > 3 {
-            return Err(Error::InvalidArguments {
-                tool: "try".to_string(),
-                reason: format!(
+    /// Helper: Check a runtime value against a quoted type specifier
+    fn value_matches_type_spec(&self, value: &Value, spec: &Value) -> Result<bool> {
+        match spec {
+            Value::String(name) => Ok(match name.to_lowercase().as_str() {
+                "t" | "any" => true,
+                "number" | "numeric" => matches!(value, Value::Int(_) | Value::Float(_)),
+                "u8" => matches!(value, Value::Int(n) if u8::try_from(*n).is_ok()),
+                "u16" => matches!(value, Value::Int(n) if u16::try_from(*n).is_ok()),
+                "u32" => matches!(value, Value::Int(n) if u32::try_from(*n).is_ok()),
+                "u64" | "usize" => matches!(value, Value::Int(n) if *n >= 0),
+                "i8" => matches!(value, Value::Int(n) if i8::try_from(*n).is_ok()),
+                "i16" => matches!(value, Value::Int(n) if i16::try_from(*n).is_ok()),
+                "i32" => matches!(value, Value::Int(n) if i32::try_from(*n).is_ok()),
+                "i64" | "isize" => matches!(value, Value::Int(_)),
+                "f32" | "f64" => matches!(value, Value::Float(_)),
+                other => self.type_matches(&value.type_name(), other),
+            }),
+            Value::Array(parts) if !parts.is_empty() => {
+                let head = match &parts[0] {
+                    Value::String(head) => head.to_lowercase(),
+                    other => {
+                        return Err(Error::TypeError {
+                            expected: "type specifier".to_string(),
+                            got: other.type_name(),
+                        })
+                    }
+                };
+                let operands = &parts[1..];
+                match head.as_str() {
+                    "array" | "list" | "vector" => {
+                        let Value::Array(items) = value else {
+                            return Ok(false);
+                        };
+                        match operands {
+                            [] => Ok(true),
+                            [element_spec] => {
+                                for item in items.iter() {
+                                    if !self.value_matches_type_spec(item, element_spec)? {
+                                        return Ok(false);
+                                    }
+                                }
+                                Ok(true)
+                            }
+                            _ => Err(Error::InvalidArguments {
+                                tool: "assert-type".to_string(),
+                                reason: format!("({} T) takes one element type", head),
+                            }),
+                        }
+                    }
+                    "or" => {
+                        for alternative in operands {
+                            if self.value_matches_type_spec(value, alternative)? {
+                                return Ok(true);
+                            }
+                        }
+                        Ok(false)
+                    }
+                    "and" => {
+                        for required in operands {
+                            if !self.value_matches_type_spec(value, required)? {
+                                return Ok(false);
+                            }
+                        }
+                        Ok(true)
+                    }
+                    "not" if operands.len() == 1 => {
+                        Ok(!self.value_matches_type_spec(value, &operands[0])?)
+                    }
+                    _ => Err(Error::InvalidArguments {
+                        tool: "assert-type".to_string(),
+                        reason: format!(
+                            "Unknown compound type specifier {}",
+                            Self::type_spec_to_string(spec)
+                        ),
+                    }),
+                }
+            }
+            other => Err(Error::TypeError {
+                expected: "type specifier".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
+    /// Helper: Render a quoted type specifier back to source form
+    fn type_spec_to_string(spec: &Value) -> String {
+        match spec {
+            Value::Array(parts) => format!(
+                "({})",
+                parts
+                    .iter()
+                    .map(Self::type_spec_to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+            Value::String(name) => name.clone(),
+            other => other.to_string(),
+        }
+    }
+
+    /// (try body (catch error-var handler) [(finally cleanup)])
+    /// Error handling with optional finally block
+    fn eval_try(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() < 2 || args.len() > 3 {
+            return Err(Error::InvalidArguments {
+                tool: "try".to_string(),
+                reason: format!(
                     "Expected 2-3 arguments (body, catch [, finally]), got {}",
                     args.len()
                 ),
@@ -1947,6 +3888,14 @@ impl LispEvaluator {
         // Execute catch block if try failed
         let result = match try_result {
             Ok(value) => Ok(value),
+            // break/continue unwind through try to their loop, and cancellation
+            // and timeouts are not errors the body can recover from
+            Err(
+                error @ (Error::LoopBreak { .. }
+                | Error::LoopContinue
+                | Error::Cancelled
+                | Error::Timeout(_)),
+            ) => Err(error),
             Err(error) => {
                 // Bind error to variable
                 self.env.enter_scope();
@@ -1989,6 +3938,72 @@ impl LispEvaluator {
         result
     }
 
+    /// (ignore-errors body...) - Evaluate body, returning nil on error
+    ///
+    /// On error the result is the multiple values `nil` and the error message.
+    /// Throws, cancellation and timeouts are not errors and still propagate.
+    fn eval_ignore_errors(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        match self.eval_do(args, false) {
+            Err(
+                error @ (Error::ThrowValue { .. }
+                | Error::LoopBreak { .. }
+                | Error::LoopContinue
+                | Error::Cancelled
+                | Error::Timeout(_)),
+            ) => Err(error),
+            Err(error) => Ok(Value::multiple(vec![
+                Value::Null,
+                Value::String(error.to_string()),
+            ])),
+            ok => ok,
+        }
+    }
+
+    /// (with-timeout (seconds timeout-form) body...) - Bound the body's run time
+    ///
+    /// Evaluates body, and if it runs longer than `seconds` evaluates and
+    /// returns `timeout-form` instead. Like `execute_with_timeout`, the limit
+    /// is checked at function calls and loop iterations.
+    fn eval_with_timeout(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let (seconds, timeout_form) = match args.first().map(|arg| &arg.value) {
+            Some(Expression::ArrayLiteral(spec)) if spec.len() == 2 => (&spec[0], &spec[1]),
+            _ => {
+                return Err(Error::InvalidArguments {
+                    tool: "with-timeout".to_string(),
+                    reason: "Expected (with-timeout (seconds timeout-form) body...)".to_string(),
+                })
+            }
+        };
+
+        let seconds = match self.evaluate_expression(seconds)? {
+            Value::Int(n) if n >= 0 => n as f64,
+            Value::Float(f) if f >= 0.0 && f.is_finite() => f,
+            other => {
+                return Err(Error::TypeError {
+                    expected: "non-negative number of seconds".to_string(),
+                    got: other.type_name(),
+                })
+            }
+        };
+        let timeout = std::time::Duration::from_secs_f64(seconds);
+        let deadline = std::time::Instant::now() + timeout;
+
+        // An enclosing deadline that expires first still wins
+        let saved = self.deadline;
+        if saved.is_none_or(|(outer, _)| deadline < outer) {
+            self.deadline = Some((deadline, timeout));
+        }
+        let result = self.eval_do(&args[1..], false);
+        self.deadline = saved;
+
+        match result {
+            Err(Error::Timeout(_)) if self.check_interrupt().is_ok() => {
+                self.evaluate_expression(timeout_form)
+            }
+            other => other,
+        }
+    }
+
     /// (error "message") - Throw an error with a message
     fn eval_error(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 1 {
@@ -2009,13 +4024,16 @@ impl LispEvaluator {
         })
     }
 
-    /// (split string delimiter) - Split string by delimiter
+    /// (split string delimiter [opts]) - Split string by delimiter
+    ///
+    /// Options: `{:limit n}` caps the number of pieces (the last keeps the
+    /// remainder intact) and `{:regex true}` treats the delimiter as a pattern.
     fn eval_split(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 2 {
+        if args.len() != 2 && args.len() != 3 {
             return Err(Error::InvalidArguments {
                 tool: "split".to_string(),
                 reason: format!(
-                    "Expected 2 arguments (string, delimiter), got {}",
+                    "Expected 2-3 arguments (string, delimiter, [options]), got {}",
                     args.len()
                 ),
             })?;
@@ -2044,8 +4062,50 @@ impl LispEvaluator {
             }
         };
 
-        let parts: Vec<Value> = string_val
-            .split(&delimiter_val)
+        if delimiter_val.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "split".to_string(),
+                reason: "Delimiter must not be empty".to_string(),
+            });
+        }
+
+        let mut limit = None;
+        let mut use_regex = false;
+        if let Some(opts_arg) = args.get(2) {
+            let opts = self.evaluate_expression(&opts_arg.value)?;
+            let opts = opts.as_object()?;
+            if let Some(n) = opts.get("limit") {
+                match n {
+                    Value::Int(n) if *n > 0 => limit = Some(*n as usize),
+                    _ => {
+                        return Err(Error::InvalidArguments {
+                            tool: "split".to_string(),
+                            reason: ":limit must be a positive integer".to_string(),
+                        })
+                    }
+                }
+            }
+            use_regex = opts.get("regex").map(|v| v.is_truthy()).unwrap_or(false);
+        }
+
+        let pieces: Vec<&str> = if use_regex {
+            let re = regex::Regex::new(&delimiter_val).map_err(|e| Error::TypeError {
+                expected: "valid regex pattern".to_string(),
+                got: format!("invalid regex: {}", e),
+            })?;
+            match limit {
+                Some(n) => re.splitn(&string_val, n).collect(),
+                None => re.split(&string_val).collect(),
+            }
+        } else {
+            match limit {
+                Some(n) => string_val.splitn(n, delimiter_val.as_str()).collect(),
+                None => string_val.split(delimiter_val.as_str()).collect(),
+            }
+        };
+
+        let parts: Vec<Value> = pieces
+            .into_iter()
             .map(|s| Value::String(s.to_string()))
             .collect();
 
@@ -2216,6 +4276,147 @@ impl LispEvaluator {
         Ok(Value::String(string_val.to_lowercase()))
     }
 
+    // =========================================================================
+    // VECTORIZATION
+    // =========================================================================
+
+    /// (with-vectorization body...) - Run body with elementwise array math
+    ///
+    /// Inside the body (including functions it calls), `+`, `-`, `*`, `/`, `%`
+    /// and the math builtins apply elementwise to array operands, broadcasting
+    /// scalars: (* [1 2 3] 2) => [2 4 6]. Outside it, arrays are not numbers.
+    fn eval_with_vectorization(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        self.vectorize_depth += 1;
+        let mut result = Ok(Value::Null);
+        for arg in args {
+            result = self.evaluate_expression(&arg.value);
+            if result.is_err() {
+                break;
+            }
+        }
+        self.vectorize_depth -= 1;
+        result
+    }
+
+    /// Math builtins that broadcast over arrays inside `with-vectorization`
+    fn is_vectorized_math(name: &str) -> bool {
+        matches!(
+            name,
+            "sqrt"
+                | "pow"
+                | "expt"
+                | "exp"
+                | "ln"
+                | "abs"
+                | "sin"
+                | "cos"
+                | "tan"
+                | "asin"
+                | "acos"
+                | "atan"
+                | "atan2"
+                | "floor"
+                | "ceiling"
+                | "ceil"
+                | "round"
+                | "truncate"
+                | "trunc"
+        )
+    }
+
+    /// Split broadcast operands into per-element argument rows
+    ///
+    /// Every array operand must have the same length; scalars repeat in each row.
+    fn broadcast_rows(tool: &str, operands: &[Value]) -> Result<Vec<Vec<Value>>> {
+        let mut len = None;
+        for operand in operands {
+            if let Value::Array(arr) = operand {
+                match len {
+                    None => len = Some(arr.len()),
+                    Some(n) if n != arr.len() => {
+                        return Err(Error::InvalidArguments {
+                            tool: tool.to_string(),
+                            reason: format!(
+                                "Cannot broadcast arrays of lengths {} and {}",
+                                n,
+                                arr.len()
+                            ),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        Ok((0..len.unwrap_or(0))
+            .map(|i| {
+                operands
+                    .iter()
+                    .map(|operand| match operand {
+                        Value::Array(arr) => arr[i].clone(),
+                        scalar => scalar.clone(),
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Call a math builtin, mapping it over array arguments
+    fn eval_vectorized_math(
+        &mut self,
+        name: &str,
+        args: &[crate::parser::Argument],
+    ) -> Result<Value> {
+        let mut values = Vec::with_capacity(args.len());
+        for arg in args {
+            values.push(self.evaluate_expression(&arg.value)?);
+        }
+        self.apply_vectorized_math(name, values)
+    }
+
+    fn apply_vectorized_math(&mut self, name: &str, values: Vec<Value>) -> Result<Value> {
+        if values.iter().any(|v| matches!(v, Value::Array(_))) {
+            let mut elements = Vec::new();
+            for row in Self::broadcast_rows(name, &values)? {
+                elements.push(self.apply_vectorized_math(name, row)?);
+            }
+            return Ok(Value::Array(Arc::new(elements)));
+        }
+
+        // Scalars: bind them to fresh names and run the ordinary builtin
+        self.env.enter_scope();
+        let mut call_args = Vec::with_capacity(values.len());
+        for (i, value) in values.into_iter().enumerate() {
+            // The space makes the name unwritable from source, so it cannot shadow user code
+            let var = format!(" arg{}", i);
+            self.env.define(var.clone(), value);
+            call_args.push(crate::parser::Argument {
+                name: None,
+                value: Expression::Variable(var),
+            });
+        }
+        let result = match name {
+            "sqrt" => self.eval_sqrt(&call_args),
+            "pow" | "expt" => self.eval_pow(&call_args),
+            "exp" => self.eval_exp(&call_args),
+            "ln" => self.eval_ln(&call_args),
+            "abs" => self.eval_abs(&call_args),
+            "sin" => self.eval_sin(&call_args),
+            "cos" => self.eval_cos(&call_args),
+            "tan" => self.eval_tan(&call_args),
+            "asin" => self.eval_asin(&call_args),
+            "acos" => self.eval_acos(&call_args),
+            "atan" => self.eval_atan(&call_args),
+            "atan2" => self.eval_atan2(&call_args),
+            "floor" => self.eval_floor(&call_args),
+            "ceiling" | "ceil" => self.eval_ceiling(&call_args),
+            "round" => self.eval_round(&call_args),
+            "truncate" | "trunc" => self.eval_truncate(&call_args),
+            _ => unreachable!("{} is not a vectorized math builtin", name),
+        };
+        self.env.exit_scope();
+        result
+    }
+
     // =========================================================================
     // ADVANCED MATH OPERATIONS
     // =========================================================================
@@ -2234,19 +4435,23 @@ impl LispEvaluator {
         let num = match val {
             Value::Int(i) => i as f64,
             Value::Float(f) => f,
+            // Principal root: sqrt(|z|) at half the argument
+            Value::Complex { re, im } => {
+                let modulus = re.hypot(im).sqrt();
+                let angle = im.atan2(re) / 2.0;
+                return Ok(Value::complex(modulus * angle.cos(), modulus * angle.sin()));
+            }
             _ => {
                 return Err(Error::TypeError {
-                    expected: "number (int or float)".to_string(),
+                    expected: "number (int, float or complex)".to_string(),
                     got: format!("{:?}", val),
                 })
             }
         };
 
+        // Negative reals have a purely imaginary root
         if num < 0.0 {
-            return Err(Error::InvalidArguments {
-                tool: "sqrt".to_string(),
-                reason: format!("Cannot take square root of negative number: {}", num),
-            })?;
+            return Ok(Value::complex(0.0, (-num).sqrt()));
         }
 
         Ok(Value::Float(num.sqrt()))
@@ -2669,13 +4874,67 @@ impl LispEvaluator {
         match val {
             Value::Int(i) => Ok(Value::Int(i.abs())),
             Value::Float(f) => Ok(Value::Float(f.abs())),
+            // Magnitude of a complex number
+            Value::Complex { re, im } => Ok(Value::Float(re.hypot(im))),
             _ => Err(Error::TypeError {
-                expected: "number (int or float)".to_string(),
+                expected: "number (int, float or complex)".to_string(),
                 got: format!("{:?}", val),
             }),
         }
     }
 
+    /// (complex re [im]) - Build a complex number
+    ///
+    /// An exact zero imaginary part yields the real part unchanged, as in
+    /// Common Lisp: (complex 3 0) is 3.
+    fn eval_complex(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() || args.len() > 2 {
+            return Err(Error::InvalidArguments {
+                tool: "complex".to_string(),
+                reason: format!("Expected 1-2 arguments (re [im]), got {}", args.len()),
+            });
+        }
+
+        let re = self.evaluate_expression(&args[0].value)?;
+        let im = match args.get(1) {
+            Some(arg) => self.evaluate_expression(&arg.value)?,
+            None => Value::Int(0),
+        };
+        match (&re, &im) {
+            (Value::Int(_) | Value::Float(_), Value::Int(0)) => Ok(re),
+            (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => {
+                Ok(Value::complex(re.as_float()?, im.as_float()?))
+            }
+            _ => Err(Error::TypeError {
+                expected: "real numbers".to_string(),
+                got: format!("{} and {}", re.type_name(), im.type_name()),
+            }),
+        }
+    }
+
+    /// (realpart z) / (imagpart z) - Parts of a number; reals have imaginary part 0
+    fn eval_complex_part(&mut self, args: &[crate::parser::Argument], tool: &str) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!("Expected 1 argument, got {}", args.len()),
+            });
+        }
+
+        let val = self.evaluate_expression(&args[0].value)?;
+        let real = tool == "realpart";
+        match val {
+            Value::Complex { re, im } => Ok(Value::Float(if real { re } else { im })),
+            Value::Int(_) if !real => Ok(Value::Int(0)),
+            Value::Float(_) if !real => Ok(Value::Float(0.0)),
+            Value::Int(_) | Value::Float(_) => Ok(val),
+            _ => Err(Error::TypeError {
+                expected: "number".to_string(),
+                got: val.type_name(),
+            }),
+        }
+    }
+
     // =========================================================================
     // COMMON LISP ARITHMETIC SHORTCUTS
     // =========================================================================
@@ -3076,30 +5335,136 @@ impl LispEvaluator {
         }
     }
 
-    /// (assoc key alist) - Find key in association list (Common Lisp)
-    fn eval_assoc(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+    /// (equal? a b) / (equalp a b) - Recursive structural equality
+    ///
+    /// `equalp` additionally compares strings case-insensitively and ints
+    /// against floats numerically, so `(equalp 1 1.0)` is true.
+    fn eval_equal(&mut self, args: &[crate::parser::Argument], loose: bool) -> Result<Value> {
         if args.len() != 2 {
             return Err(Error::InvalidArguments {
-                tool: "assoc".to_string(),
+                tool: if loose { "equalp" } else { "equal?" }.to_string(),
                 reason: format!("Expected 2 arguments, got {}", args.len()),
             });
         }
 
+        let a = self.evaluate_expression(&args[0].value)?;
+        let b = self.evaluate_expression(&args[1].value)?;
+        Ok(Value::Bool(Self::structurally_equal(&a, &b, loose)))
+    }
+
+    /// Structural equality over arrays and objects; other values compare with `==`
+    fn structurally_equal(a: &Value, b: &Value, loose: bool) -> bool {
+        match (a, b) {
+            (Value::Array(x), Value::Array(y)) | (Value::Multiple(x), Value::Multiple(y)) => {
+                x.len() == y.len()
+                    && x.iter()
+                        .zip(y.iter())
+                        .all(|(l, r)| Self::structurally_equal(l, r, loose))
+            }
+            (Value::Object(x), Value::Object(y)) => {
+                x.len() == y.len()
+                    && x.iter().all(|(k, l)| {
+                        y.get(k)
+                            .is_some_and(|r| Self::structurally_equal(l, r, loose))
+                    })
+            }
+            (Value::String(x), Value::String(y)) if loose => x.to_lowercase() == y.to_lowercase(),
+            (Value::Int(x), Value::Float(y)) | (Value::Float(y), Value::Int(x)) if loose => {
+                *x as f64 == *y
+            }
+            _ => a == b,
+        }
+    }
+
+    /// (assoc key alist [:test fn]) / (rassoc value alist [:test fn]) - Association list lookup
+    ///
+    /// The alist is either an array of `[key value]` pairs or an object whose
+    /// keys are the cars. Returns the matching pair, or null. `rassoc` matches
+    /// on the value (cdr) instead of the key.
+    fn eval_assoc(&mut self, args: &[crate::parser::Argument], by_value: bool) -> Result<Value> {
+        let tool = if by_value { "rassoc" } else { "assoc" };
+        if args.len() < 2 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!("Expected at least 2 arguments, got {}", args.len()),
+            });
+        }
+
         let key = self.evaluate_expression(&args[0].value)?;
         let alist_val = self.evaluate_expression(&args[1].value)?;
-        let arr = alist_val.as_array()?;
 
-        for elem in arr.iter() {
-            if let Value::Array(pair) = elem {
-                if !pair.is_empty() && Self::values_are_equal(&key, &pair[0]) {
-                    return Ok(elem.clone());
+        let mut test = None;
+        for (option, expr) in Self::keyword_options(tool, &args[2..])? {
+            match option.as_str() {
+                "test" => test = Some(self.eval_function_designator(expr)?),
+                other => {
+                    return Err(Error::InvalidArguments {
+                        tool: tool.to_string(),
+                        reason: format!("Unknown keyword :{}", other),
+                    })
                 }
             }
         }
+
+        let pairs: Vec<Value> = match &alist_val {
+            Value::Object(map) => {
+                // Sorted so lookups are deterministic when several entries match
+                let mut keys: Vec<&Arc<str>> = map.keys().collect();
+                keys.sort();
+                keys.into_iter()
+                    .map(|k| Value::array(vec![Value::String(k.to_string()), map[k].clone()]))
+                    .collect()
+            }
+            _ => alist_val.as_array()?.to_vec(),
+        };
+
+        let slot = if by_value { 1 } else { 0 };
+        for elem in pairs {
+            let candidate = match &elem {
+                Value::Array(pair) if pair.len() > slot => pair[slot].clone(),
+                _ => continue,
+            };
+            let matched = match &test {
+                Some(func) => self
+                    .apply_function_designator(func, vec![key.clone(), candidate], tool)?
+                    .is_truthy(),
+                None => Self::values_are_equal(&key, &candidate),
+            };
+            if matched {
+                return Ok(elem);
+            }
+        }
         Ok(Value::Null)
     }
 
+    /// Split trailing `:keyword value` arguments into (name, expression) pairs
+    fn keyword_options<'a>(
+        tool: &str,
+        args: &'a [crate::parser::Argument],
+    ) -> Result<Vec<(String, &'a Expression)>> {
+        if !args.len().is_multiple_of(2) {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: "Keyword options must come in :name value pairs".to_string(),
+            });
+        }
+
+        args.chunks(2)
+            .map(|pair| match &pair[0].value {
+                Expression::StringLiteral(kw) if kw.starts_with(':') => {
+                    Ok((kw[1..].to_string(), &pair[1].value))
+                }
+                other => Err(Error::InvalidArguments {
+                    tool: tool.to_string(),
+                    reason: format!("Expected keyword option, got {:?}", other),
+                }),
+            })
+            .collect()
+    }
+
     /// (assoc-in object key value) - Set a key in an object with a computed key
+    /// (assoc-in object [k1 k2 ...] value) - Set a nested value, creating
+    /// missing intermediate objects
     /// Also aliased as set-key
     /// This allows dynamic key names from variables
     fn eval_assoc_in(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
@@ -3114,6 +5479,10 @@ impl LispEvaluator {
         let key_val = self.evaluate_expression(&args[1].value)?;
         let new_val = self.evaluate_expression(&args[2].value)?;
 
+        if let Value::Array(path) = &key_val {
+            return Self::assoc_path(&obj_val, path, new_val);
+        }
+
         // Convert key to string
         let key_str = match key_val {
             Value::String(s) => s,
@@ -3126,7 +5495,7 @@ impl LispEvaluator {
         match obj_val {
             Value::Object(ref map) => {
                 let mut new_map = map.as_ref().clone();
-                new_map.insert(key_str, new_val);
+                new_map.insert(key_str.into(), new_val);
                 Ok(Value::Object(Arc::new(new_map)))
             }
             _ => Err(Error::TypeError {
@@ -3136,6 +5505,138 @@ impl LispEvaluator {
         }
     }
 
+    /// (get-in object [k1 k2 ...] [default]) - Follow a path of object keys and
+    /// array indices, returning default (or null) if any step is missing
+    /// Example: (get-in {:accounts [{:pubkey "abc"}]} [:accounts 0 :pubkey]) → "abc"
+    fn eval_get_in(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 && args.len() != 3 {
+            return Err(Error::InvalidArguments {
+                tool: "get-in".to_string(),
+                reason: "Expected 2-3 arguments: object, path and optional default".to_string(),
+            });
+        }
+
+        let obj_val = self.evaluate_expression(&args[0].value)?;
+        let path_val = self.evaluate_expression(&args[1].value)?;
+        let path = path_val.as_array()?;
+
+        match Self::get_path(&obj_val, path)? {
+            Some(value) => Ok(value),
+            None if args.len() == 3 => self.evaluate_expression(&args[2].value),
+            None => Ok(Value::Null),
+        }
+    }
+
+    /// (update-in object [k1 k2 ...] f args...) - Replace the nested value v
+    /// with (f v args...); v is null if the path does not exist yet
+    /// Example: (update-in {:a {:n 1}} [:a :n] + 10) → {:a {:n 11}}
+    fn eval_update_in(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() < 3 {
+            return Err(Error::InvalidArguments {
+                tool: "update-in".to_string(),
+                reason: "Expected at least 3 arguments: object, path and function".to_string(),
+            });
+        }
+
+        let obj_val = self.evaluate_expression(&args[0].value)?;
+        let path_val = self.evaluate_expression(&args[1].value)?;
+        let path = path_val.as_array()?;
+        let func = self.eval_function_designator(&args[2].value)?;
+        Self::check_function_arity("update-in", &func, args.len() - 2, "Lambda")?;
+
+        let current = Self::get_path(&obj_val, path)?.unwrap_or(Value::Null);
+        let mut call_args = vec![current];
+        for arg in &args[3..] {
+            call_args.push(self.evaluate_expression(&arg.value)?);
+        }
+        let updated = self.apply_function_designator(&func, call_args, "update-in")?;
+
+        Self::assoc_path(&obj_val, path, updated)
+    }
+
+    /// Value at `path` inside `value`, or None if a key or index is missing
+    fn get_path(value: &Value, path: &[Value]) -> Result<Option<Value>> {
+        let mut current = value.clone();
+        for segment in path {
+            let next = match (&current, segment) {
+                (Value::Array(arr), Value::Int(i)) => {
+                    usize::try_from(*i).ok().and_then(|i| arr.get(i)).cloned()
+                }
+                (Value::Object(map), _) => map.get(Self::path_key(segment)?.as_str()).cloned(),
+                _ => None,
+            };
+            match next {
+                Some(value) => current = value,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(current))
+    }
+
+    /// Copy of `value` with the value at `path` replaced by `new_val`.
+    /// Missing or null levels become objects; an index one past the end of
+    /// an array appends.
+    fn assoc_path(value: &Value, path: &[Value], new_val: Value) -> Result<Value> {
+        let Some((segment, rest)) = path.split_first() else {
+            return Ok(new_val);
+        };
+
+        match (value, segment) {
+            (Value::Array(arr), Value::Int(i)) => {
+                let index = usize::try_from(*i).map_err(|_| Error::InvalidArguments {
+                    tool: "assoc-in".to_string(),
+                    reason: format!("Negative index {} in path", i),
+                })?;
+                if index > arr.len() {
+                    return Err(Error::IndexOutOfBounds {
+                        index,
+                        length: arr.len(),
+                    });
+                }
+                let mut items = arr.as_ref().clone();
+                let child = items.get(index).cloned().unwrap_or(Value::Null);
+                let updated = Self::assoc_path(&child, rest, new_val)?;
+                if index == items.len() {
+                    items.push(updated);
+                } else {
+                    items[index] = updated;
+                }
+                Ok(Value::Array(Arc::new(items)))
+            }
+            (Value::Object(_) | Value::Null, _) => {
+                let key = Self::path_key(segment)?;
+                let mut map = match value {
+                    Value::Object(map) => map.as_ref().clone(),
+                    _ => std::collections::HashMap::new(),
+                };
+                let child = map.get(key.as_str()).cloned().unwrap_or(Value::Null);
+                map.insert(key.into(), Self::assoc_path(&child, rest, new_val)?);
+                Ok(Value::Object(Arc::new(map)))
+            }
+            (Value::Array(_), other) => Err(Error::TypeError {
+                expected: "integer index".to_string(),
+                got: other.type_name(),
+            }),
+            (other, _) => Err(Error::TypeError {
+                expected: "object or array".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
+    /// Object key for a path segment: keywords lose their leading colon and
+    /// integers are used as their decimal representation
+    fn path_key(segment: &Value) -> Result<String> {
+        match segment {
+            Value::String(s) => Ok(s.strip_prefix(':').unwrap_or(s).to_string()),
+            Value::Int(i) => Ok(i.to_string()),
+            other => Err(Error::TypeError {
+                expected: "string or integer path segment".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
     /// (set object key value) - Set object property (like JavaScript/Python)
     /// Alias for assoc-in with same functionality
     /// This is the "everyone else" syntax you wanted
@@ -3157,13 +5658,7 @@ impl LispEvaluator {
         let index_val = self.evaluate_expression(&args[1].value)?;
 
         let index = match index_val {
-            Value::Int(i) if i >= 0 => i as usize,
-            Value::Int(i) => {
-                return Err(Error::InvalidArguments {
-                    tool: "elt".to_string(),
-                    reason: format!("Index must be non-negative, got {}", i),
-                })
-            }
+            Value::Int(i) => i,
             _ => {
                 return Err(Error::TypeError {
                     expected: "integer".to_string(),
@@ -3173,32 +5668,30 @@ impl LispEvaluator {
         };
 
         match seq {
-            Value::Array(arr) => {
-                if index >= arr.len() {
-                    return Err(Error::InvalidArguments {
-                        tool: "elt".to_string(),
-                        reason: format!(
-                            "Index {} out of bounds for array of length {}",
-                            index,
-                            arr.len()
-                        ),
-                    });
-                }
-                Ok(arr[index].clone())
-            }
+            Value::Array(arr) => match Self::resolve_index(index, arr.len()) {
+                Some(index) => Ok(arr[index].clone()),
+                None => Err(Error::InvalidArguments {
+                    tool: "elt".to_string(),
+                    reason: format!(
+                        "Index {} out of bounds for array of length {}",
+                        index,
+                        arr.len()
+                    ),
+                }),
+            },
             Value::String(s) => {
                 let chars: Vec<char> = s.chars().collect();
-                if index >= chars.len() {
-                    return Err(Error::InvalidArguments {
+                match Self::resolve_index(index, chars.len()) {
+                    Some(index) => Ok(Value::String(chars[index].to_string())),
+                    None => Err(Error::InvalidArguments {
                         tool: "elt".to_string(),
                         reason: format!(
                             "Index {} out of bounds for string of length {}",
                             index,
                             chars.len()
                         ),
-                    });
+                    }),
                 }
-                Ok(Value::String(chars[index].to_string()))
             }
             _ => Err(Error::TypeError {
                 expected: "array or string".to_string(),
@@ -3207,6 +5700,35 @@ impl LispEvaluator {
         }
     }
 
+    /// Resolve an element index against a sequence length, counting negative
+    /// indices back from the end (-1 is the last element).
+    fn resolve_index(index: i64, len: usize) -> Option<usize> {
+        let resolved = if index < 0 { len as i64 + index } else { index };
+        (0..len as i64)
+            .contains(&resolved)
+            .then_some(resolved as usize)
+    }
+
+    /// Resolve a slice bound like `resolve_index`, but allowing `len` itself.
+    fn resolve_bound(index: i64, len: usize) -> Option<usize> {
+        let resolved = if index < 0 { len as i64 + index } else { index };
+        (0..=len as i64)
+            .contains(&resolved)
+            .then_some(resolved as usize)
+    }
+
+    /// Error for an index that `resolve_index` rejected, reporting negative
+    /// indices as written rather than clamped
+    fn index_out_of_bounds(tool: &str, index: i64, length: usize) -> Error {
+        match usize::try_from(index) {
+            Ok(index) => Error::IndexOutOfBounds { index, length },
+            Err(_) => Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!("Index {} out of bounds for length {}", index, length),
+            },
+        }
+    }
+
     /// (subseq sequence start [end]) - Subsequence (Common Lisp)
     fn eval_subseq(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() < 2 || args.len() > 3 {
@@ -3220,13 +5742,7 @@ impl LispEvaluator {
         let start_val = self.evaluate_expression(&args[1].value)?;
 
         let start = match start_val {
-            Value::Int(i) if i >= 0 => i as usize,
-            Value::Int(i) => {
-                return Err(Error::InvalidArguments {
-                    tool: "subseq".to_string(),
-                    reason: format!("Start index must be non-negative, got {}", i),
-                })
-            }
+            Value::Int(i) => i,
             _ => {
                 return Err(Error::TypeError {
                     expected: "integer".to_string(),
@@ -3238,14 +5754,8 @@ impl LispEvaluator {
         let end = if args.len() == 3 {
             let end_val = self.evaluate_expression(&args[2].value)?;
             match end_val {
-                Value::Int(i) if i >= 0 => Some(i as usize),
+                Value::Int(i) => Some(i),
                 Value::Null => None,
-                Value::Int(i) => {
-                    return Err(Error::InvalidArguments {
-                        tool: "subseq".to_string(),
-                        reason: format!("End index must be non-negative, got {}", i),
-                    })
-                }
                 _ => {
                     return Err(Error::TypeError {
                         expected: "integer or null".to_string(),
@@ -3257,42 +5767,42 @@ impl LispEvaluator {
             None
         };
 
-        match seq {
-            Value::Array(arr) => {
-                let end = end.unwrap_or(arr.len());
-                if start > arr.len() || end > arr.len() || start > end {
-                    return Err(Error::InvalidArguments {
-                        tool: "subseq".to_string(),
-                        reason: format!(
-                            "Invalid range [{}, {}) for array of length {}",
-                            start,
-                            end,
-                            arr.len()
-                        ),
-                    });
-                }
-                Ok(Value::Array(Arc::new(arr[start..end].to_vec())))
+        let (kind, len) = match &seq {
+            Value::Array(arr) => ("array", arr.len()),
+            Value::String(s) => ("string", s.chars().count()),
+            _ => {
+                return Err(Error::TypeError {
+                    expected: "array or string".to_string(),
+                    got: seq.type_name(),
+                })
             }
-            Value::String(s) => {
-                let chars: Vec<char> = s.chars().collect();
-                let end = end.unwrap_or(chars.len());
-                if start > chars.len() || end > chars.len() || start > end {
-                    return Err(Error::InvalidArguments {
-                        tool: "subseq".to_string(),
-                        reason: format!(
-                            "Invalid range [{}, {}) for string of length {}",
-                            start,
-                            end,
-                            chars.len()
-                        ),
-                    });
-                }
-                Ok(Value::String(chars[start..end].iter().collect()))
+        };
+        let range = Self::resolve_bound(start, len).zip(match end {
+            Some(end) => Self::resolve_bound(end, len),
+            None => Some(len),
+        });
+        let (start, end) = match range {
+            Some((start, end)) if start <= end => (start, end),
+            _ => {
+                return Err(Error::InvalidArguments {
+                    tool: "subseq".to_string(),
+                    reason: format!(
+                        "Invalid range [{}, {}) for {} of length {}",
+                        start,
+                        end.map_or("nil".to_string(), |e| e.to_string()),
+                        kind,
+                        len
+                    ),
+                });
             }
-            _ => Err(Error::TypeError {
-                expected: "array or string".to_string(),
-                got: seq.type_name(),
-            }),
+        };
+
+        match seq {
+            Value::Array(arr) => Ok(Value::Array(Arc::new(arr[start..end].to_vec()))),
+            Value::String(s) => Ok(Value::String(
+                s.chars().skip(start).take(end - start).collect(),
+            )),
+            _ => unreachable!("subseq sequence type checked above"),
         }
     }
 
@@ -3321,6 +5831,29 @@ impl LispEvaluator {
         }
     }
 
+    /// (string-equal a b) - Case-insensitive string equality (Common Lisp)
+    fn eval_string_equal(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "string-equal".to_string(),
+                reason: format!("Expected 2 arguments, got {}", args.len()),
+            });
+        }
+
+        let a = self.evaluate_expression(&args[0].value)?;
+        let b = self.evaluate_expression(&args[1].value)?;
+
+        match (&a, &b) {
+            (Value::String(s1), Value::String(s2)) => {
+                Ok(Value::Bool(s1.to_lowercase() == s2.to_lowercase()))
+            }
+            _ => Err(Error::TypeError {
+                expected: "strings".to_string(),
+                got: format!("{}, {}", a.type_name(), b.type_name()),
+            }),
+        }
+    }
+
     /// (string< a b) - String less than (Common Lisp)
     fn eval_string_lt(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 2 {
@@ -3367,46 +5900,55 @@ impl LispEvaluator {
     // COMMON LISP MAP VARIANTS
     // =========================================================================
 
-    /// (mapcar function list) - Map and return results (Common Lisp)
+    /// (mapcar function list...) - Map and return results (Common Lisp)
+    ///
+    /// With several lists the function receives one element from each,
+    /// stopping at the shortest list.
     fn eval_mapcar(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 2 {
+        if args.len() < 2 {
             return Err(Error::InvalidArguments {
                 tool: "mapcar".to_string(),
-                reason: format!("Expected 2 arguments, got {}", args.len()),
+                reason: format!("Expected at least 2 arguments, got {}", args.len()),
             });
         }
 
-        let func = self.evaluate_expression(&args[0].value)?;
-        let list_val = self.evaluate_expression(&args[1].value)?;
-        let arr = list_val.as_array()?;
+        let func = self.eval_function_designator(&args[0].value)?;
+        let mut lists = Vec::with_capacity(args.len() - 1);
+        for arg in &args[1..] {
+            lists.push(self.evaluate_expression(&arg.value)?);
+        }
 
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "mapcar".to_string(),
-                        reason: format!(
-                            "Lambda must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
+        self.map_sequences("mapcar", &func, &lists)
+    }
 
-                let mut results = Vec::with_capacity(arr.len());
-                for elem in arr.iter() {
-                    self.env.enter_scope();
-                    self.env.define(params[0].clone(), elem.clone());
-                    let result = self.evaluate_expression(&body)?;
-                    self.env.exit_scope();
-                    results.push(result);
-                }
-                Ok(Value::Array(Arc::new(results)))
+    /// Apply `func` element-wise across `seqs`, truncating to the shortest one
+    fn map_sequences(&mut self, tool: &str, func: &Value, seqs: &[Value]) -> Result<Value> {
+        let mut arrays = Vec::with_capacity(seqs.len());
+        for seq in seqs {
+            arrays.push(seq.as_array()?.clone());
+        }
+
+        if let Value::Function { params, .. } = func {
+            if params.len() != arrays.len() {
+                return Err(Error::InvalidArguments {
+                    tool: tool.to_string(),
+                    reason: format!(
+                        "Lambda must take exactly {} parameter{}, got {}",
+                        arrays.len(),
+                        if arrays.len() == 1 { "" } else { "s" },
+                        params.len()
+                    ),
+                });
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
         }
+
+        let len = arrays.iter().map(|arr| arr.len()).min().unwrap_or(0);
+        let mut results = Vec::with_capacity(len);
+        for i in 0..len {
+            let call_args = arrays.iter().map(|arr| arr[i].clone()).collect();
+            results.push(self.apply_function_designator(func, call_args, tool)?);
+        }
+        Ok(Value::Array(Arc::new(results)))
     }
 
     /// (mapc function list) - Map for side effects, return list (Common Lisp)
@@ -3418,35 +5960,15 @@ impl LispEvaluator {
             });
         }
 
-        let func = self.evaluate_expression(&args[0].value)?;
+        let func = self.eval_function_designator(&args[0].value)?;
         let list_val = self.evaluate_expression(&args[1].value)?;
         let arr = list_val.as_array()?;
+        Self::check_function_arity("mapc", &func, 1, "Lambda")?;
 
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "mapc".to_string(),
-                        reason: format!(
-                            "Lambda must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
-
-                for elem in arr.iter() {
-                    self.env.enter_scope();
-                    self.env.define(params[0].clone(), elem.clone());
-                    self.evaluate_expression(&body)?;
-                    self.env.exit_scope();
-                }
-                Ok(list_val) // Return original list
-            }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
+        for elem in arr.iter() {
+            self.apply_function_designator(&func, vec![elem.clone()], "mapc")?;
         }
+        Ok(list_val) // Return original list
     }
 
     // =========================================================================
@@ -3462,40 +5984,20 @@ impl LispEvaluator {
             });
         }
 
-        let pred = self.evaluate_expression(&args[0].value)?;
+        let pred = self.eval_function_designator(&args[0].value)?;
         let list_val = self.evaluate_expression(&args[1].value)?;
         let arr = list_val.as_array()?;
+        Self::check_function_arity("remove-if", &pred, 1, "Lambda")?;
 
-        match pred {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "remove-if".to_string(),
-                        reason: format!(
-                            "Lambda must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
-
-                let mut results = Vec::new();
-                for elem in arr.iter() {
-                    self.env.enter_scope();
-                    self.env.define(params[0].clone(), elem.clone());
-                    let test_result = self.evaluate_expression(&body)?;
-                    self.env.exit_scope();
-
-                    if !test_result.is_truthy() {
-                        results.push(elem.clone());
-                    }
-                }
-                Ok(Value::Array(Arc::new(results)))
+        let mut results = Vec::new();
+        for elem in arr.iter() {
+            let test_result =
+                self.apply_function_designator(&pred, vec![elem.clone()], "remove-if")?;
+            if !test_result.is_truthy() {
+                results.push(elem.clone());
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: pred.type_name(),
-            }),
         }
+        Ok(Value::Array(Arc::new(results)))
     }
 
     /// (remove-if-not predicate list) - Keep matching elements (Common Lisp)
@@ -3507,75 +6009,62 @@ impl LispEvaluator {
             });
         }
 
-        let pred = self.evaluate_expression(&args[0].value)?;
+        let pred = self.eval_function_designator(&args[0].value)?;
         let list_val = self.evaluate_expression(&args[1].value)?;
         let arr = list_val.as_array()?;
+        Self::check_function_arity("remove-if-not", &pred, 1, "Lambda")?;
 
-        match pred {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "remove-if-not".to_string(),
-                        reason: format!(
-                            "Lambda must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
-
-                let mut results = Vec::new();
-                for elem in arr.iter() {
-                    self.env.enter_scope();
-                    self.env.define(params[0].clone(), elem.clone());
-                    let test_result = self.evaluate_expression(&body)?;
-                    self.env.exit_scope();
-
-                    if test_result.is_truthy() {
-                        results.push(elem.clone());
-                    }
-                }
-                Ok(Value::Array(Arc::new(results)))
+        let mut results = Vec::new();
+        for elem in arr.iter() {
+            let test_result =
+                self.apply_function_designator(&pred, vec![elem.clone()], "remove-if-not")?;
+            if test_result.is_truthy() {
+                results.push(elem.clone());
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: pred.type_name(),
-            }),
         }
+        Ok(Value::Array(Arc::new(results)))
     }
 
     // =========================================================================
     // COMMON LISP VARIABLE MUTATION
     // =========================================================================
 
-    /// (incf place [delta]) - Increment variable (Common Lisp)
+    /// (incf place [delta]) - Increment a place (Common Lisp)
+    ///
+    /// The place is anything `setf` accepts, e.g. `(incf (nth xs i))` or
+    /// `(incf (gethash k table) 5)`. Returns the new value.
     fn eval_incf(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        self.adjust_place(args, "incf")
+    }
+
+    /// (decf place [delta]) - Decrement a place (Common Lisp)
+    fn eval_decf(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        self.adjust_place(args, "decf")
+    }
+
+    fn adjust_place(&mut self, args: &[crate::parser::Argument], tool: &str) -> Result<Value> {
         if args.is_empty() || args.len() > 2 {
             return Err(Error::InvalidArguments {
-                tool: "incf".to_string(),
+                tool: tool.to_string(),
                 reason: format!("Expected 1 or 2 arguments, got {}", args.len()),
             });
         }
 
-        // Get variable name (must be a symbol/identifier in the arg)
-        let var_name = match &args[0].value {
-            Expression::Variable(name) => name.clone(),
-            _ => {
-                return Err(Error::InvalidArguments {
-                    tool: "incf".to_string(),
-                    reason: "First argument must be a variable name".to_string(),
-                })
-            }
-        };
-
         // Get delta (default 1)
         let delta = if args.len() == 2 {
             self.evaluate_expression(&args[1].value)?
         } else {
             Value::Int(1)
         };
+        let delta = match (tool, delta) {
+            ("decf", Value::Int(d)) => Value::Int(-d),
+            ("decf", Value::Float(d)) => Value::Float(-d),
+            (_, delta) => delta,
+        };
 
-        // Get current value
-        let current = self.env.get(&var_name)?;
+        // Get current value, evaluating the place's subforms only once
+        let (var, keys) = self.resolve_place(&args[0].value)?;
+        let current = self.place_value(&var, &keys)?;
 
         // Calculate new value
         let new_value = match (&current, &delta) {
@@ -3591,57 +6080,7 @@ impl LispEvaluator {
             }
         };
 
-        // Update variable
-        self.env.set(&var_name, new_value.clone())?;
-        Ok(new_value)
-    }
-
-    /// (decf place [delta]) - Decrement variable (Common Lisp)
-    fn eval_decf(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.is_empty() || args.len() > 2 {
-            return Err(Error::InvalidArguments {
-                tool: "decf".to_string(),
-                reason: format!("Expected 1 or 2 arguments, got {}", args.len()),
-            });
-        }
-
-        // Get variable name
-        let var_name = match &args[0].value {
-            Expression::Variable(name) => name.clone(),
-            _ => {
-                return Err(Error::InvalidArguments {
-                    tool: "decf".to_string(),
-                    reason: "First argument must be a variable name".to_string(),
-                })
-            }
-        };
-
-        // Get delta (default 1)
-        let delta = if args.len() == 2 {
-            self.evaluate_expression(&args[1].value)?
-        } else {
-            Value::Int(1)
-        };
-
-        // Get current value
-        let current = self.env.get(&var_name)?;
-
-        // Calculate new value
-        let new_value = match (&current, &delta) {
-            (Value::Int(i), Value::Int(d)) => Value::Int(i - d),
-            (Value::Float(f), Value::Float(d)) => Value::Float(f - d),
-            (Value::Int(i), Value::Float(d)) => Value::Float(*i as f64 - d),
-            (Value::Float(f), Value::Int(d)) => Value::Float(f - (*d as f64)),
-            _ => {
-                return Err(Error::TypeError {
-                    expected: "numbers".to_string(),
-                    got: format!("{}, {}", current.type_name(), delta.type_name()),
-                })
-            }
-        };
-
-        // Update variable
-        self.env.set(&var_name, new_value.clone())?;
+        self.store_place(&var, &keys, new_value.clone())?;
         Ok(new_value)
     }
 
@@ -3740,6 +6179,55 @@ impl LispEvaluator {
         Ok(result)
     }
 
+    /// (multiple-value-call fn form...) - Call fn with every value produced by each form
+    fn eval_multiple_value_call(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "multiple-value-call".to_string(),
+                reason: "Expected function and value forms".to_string(),
+            });
+        }
+
+        let func = self.eval_function_designator(&args[0].value)?;
+
+        // Spread every form's values into a single argument list
+        let mut call_args = Vec::new();
+        for arg in &args[1..] {
+            match self.evaluate_expression(&arg.value)? {
+                Value::Multiple(vals) => call_args.extend(vals.iter().cloned()),
+                single => call_args.push(single),
+            }
+        }
+
+        self.apply_function_designator(&func, call_args, "multiple-value-call")
+    }
+
+    /// (nth-value n form) - Extract the nth value (0-indexed) produced by form, or null
+    fn eval_nth_value(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "nth-value".to_string(),
+                reason: format!("Expected 2 arguments (n form), got {}", args.len()),
+            });
+        }
+
+        let n = match self.evaluate_expression(&args[0].value)? {
+            Value::Int(n) if n >= 0 => n as usize,
+            other => {
+                return Err(Error::TypeError {
+                    expected: "non-negative integer".to_string(),
+                    got: other.type_name(),
+                })
+            }
+        };
+
+        Ok(match self.evaluate_expression(&args[1].value)? {
+            Value::Multiple(vals) => vals.get(n).cloned().unwrap_or(Value::Null),
+            single if n == 0 => single,
+            _ => Value::Null,
+        })
+    }
+
     // =========================================================================
     // DYNAMIC VARIABLES (Common Lisp special variables)
     // =========================================================================
@@ -3880,7 +6368,7 @@ impl LispEvaluator {
         let index_val = self.evaluate_expression(&args[1].value)?;
 
         let index = match index_val {
-            Value::Int(i) => i as usize,
+            Value::Int(i) => i,
             _ => {
                 return Err(Error::TypeError {
                     expected: "int".to_string(),
@@ -3889,23 +6377,93 @@ impl LispEvaluator {
             }
         };
 
+        let out_of_bounds = |length: usize| Self::index_out_of_bounds("nth", index, length);
+
         match val {
-            Value::Array(ref arr) => arr.get(index).cloned().ok_or(Error::IndexOutOfBounds {
-                index,
-                length: arr.len(),
-            }),
+            Value::Array(ref arr) => Self::resolve_index(index, arr.len())
+                .map(|i| arr[i].clone())
+                .ok_or_else(|| out_of_bounds(arr.len())),
+            Value::String(ref s) => {
+                let chars: Vec<char> = s.chars().collect();
+                Self::resolve_index(index, chars.len())
+                    .map(|i| Value::String(chars[i].to_string()))
+                    .ok_or_else(|| out_of_bounds(chars.len()))
+            }
             _ => Err(Error::TypeError {
-                expected: "array".to_string(),
+                expected: "array or string".to_string(),
                 got: val.type_name(),
             }),
         }
     }
 
-    /// (cons elem coll) - Prepend element to collection
-    fn eval_cons(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 2 {
+    /// (aref array index...) - Element of a (nested) array, one index per level
+    fn eval_aref(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() < 2 {
             return Err(Error::InvalidArguments {
-                tool: "cons".to_string(),
+                tool: "aref".to_string(),
+                reason: "Expected an array and at least one index".to_string(),
+            });
+        }
+
+        let mut current = self.evaluate_expression(&args[0].value)?;
+        for arg in &args[1..] {
+            let index = self.evaluate_expression(&arg.value)?.as_int()?;
+            current = match &current {
+                Value::Array(arr) => usize::try_from(index)
+                    .ok()
+                    .and_then(|i| arr.get(i))
+                    .cloned()
+                    .ok_or(Error::IndexOutOfBounds {
+                        index: index.max(0) as usize,
+                        length: arr.len(),
+                    })?,
+                other => {
+                    return Err(Error::TypeError {
+                        expected: "array".to_string(),
+                        got: other.type_name(),
+                    })
+                }
+            };
+        }
+        Ok(current)
+    }
+
+    /// (gethash key table [default]) - Value stored under key, or default (nil)
+    fn eval_gethash(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() < 2 || args.len() > 3 {
+            return Err(Error::InvalidArguments {
+                tool: "gethash".to_string(),
+                reason: "Expected key, table and an optional default".to_string(),
+            });
+        }
+
+        let key = self.evaluate_expression(&args[0].value)?;
+        let table = self.evaluate_expression(&args[1].value)?;
+        let key = crate::tools::stdlib::hash_tables::table_key(&key)?;
+        match table.as_object()?.get(key) {
+            Some(value) => Ok(value.clone()),
+            None => match args.get(2) {
+                Some(default) => self.evaluate_expression(&default.value),
+                None => Ok(Value::Null),
+            },
+        }
+    }
+
+    /// (list args...) - Build a list of the evaluated arguments
+    fn eval_list(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        self.charge_values(args.len())?;
+        let mut items = Vec::with_capacity(args.len());
+        for arg in args {
+            items.push(self.evaluate_expression(&arg.value)?);
+        }
+        Ok(Value::Array(Arc::new(items)))
+    }
+
+    /// (cons elem coll) - Prepend element to collection
+    fn eval_cons(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "cons".to_string(),
                 reason: "Expected 2 arguments (element, collection)".to_string(),
             });
         }
@@ -3915,6 +6473,7 @@ impl LispEvaluator {
 
         match coll {
             Value::Array(ref arr) => {
+                self.charge_values(arr.len() + 1)?;
                 let mut new_arr = vec![elem];
                 new_arr.extend(arr.iter().cloned());
                 Ok(Value::Array(Arc::new(new_arr)))
@@ -3927,6 +6486,7 @@ impl LispEvaluator {
     }
 
     /// (append arr1 arr2) - Concatenate two arrays
+    /// Always builds a fresh array; neither input is modified
     fn eval_append(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 2 {
             return Err(Error::InvalidArguments {
@@ -3940,6 +6500,7 @@ impl LispEvaluator {
 
         match (arr1_val, arr2_val) {
             (Value::Array(ref arr1), Value::Array(ref arr2)) => {
+                self.charge_values(arr1.len() + arr2.len())?;
                 let mut new_arr = arr1.to_vec();
                 new_arr.extend(arr2.iter().cloned());
                 Ok(Value::Array(Arc::new(new_arr)))
@@ -3955,10 +6516,55 @@ impl LispEvaluator {
         }
     }
 
+    /// (copy-seq x) / (copy-tree x) - Copy a sequence
+    /// copy-seq copies only the top level of an array, object or string;
+    /// copy-tree also copies every nested array and object
+    fn eval_copy(&mut self, args: &[crate::parser::Argument], deep: bool) -> Result<Value> {
+        let tool = if deep { "copy-tree" } else { "copy-seq" };
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: "Expected 1 argument: sequence".to_string(),
+            });
+        }
+
+        let value = self.evaluate_expression(&args[0].value)?;
+        match value {
+            Value::Array(_) | Value::Object(_) | Value::String(_) | Value::Null => {
+                Ok(Self::copy_value(&value, deep))
+            }
+            other if deep => Ok(other),
+            other => Err(Error::TypeError {
+                expected: "array, object or string".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
+    /// Rebuild arrays and objects into freshly allocated storage, recursing
+    /// into elements when `deep` is set
+    fn copy_value(value: &Value, deep: bool) -> Value {
+        let copy_elem = |v: &Value| {
+            if deep {
+                Self::copy_value(v, true)
+            } else {
+                v.clone()
+            }
+        };
+        match value {
+            Value::Array(arr) => Value::Array(Arc::new(arr.iter().map(copy_elem).collect())),
+            Value::Object(obj) => Value::Object(Arc::new(
+                obj.iter().map(|(k, v)| (k.clone(), copy_elem(v))).collect(),
+            )),
+            other => other.clone(),
+        }
+    }
+
     /// (concatenate args...) - Polymorphic concatenation for strings and arrays
     /// - For strings: concatenates all strings together
     /// - For arrays: concatenates all arrays together
     /// - Variadic: accepts 1+ arguments
+    /// - Inputs are never modified; the result is always a fresh value
     fn eval_concatenate(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.is_empty() {
             return Err(Error::InvalidArguments {
@@ -3981,16 +6587,19 @@ impl LispEvaluator {
                     result.push_str(s);
                 }
 
+                self.charge_allocation(result.len())?;
                 Ok(Value::String(result))
             }
             Value::Array(ref arr) => {
                 // Array concatenation
+                self.charge_values(arr.len())?;
                 let mut result = arr.to_vec();
 
                 for arg in args.iter().skip(1) {
                     let val = self.evaluate_expression(&arg.value)?;
                     match val {
                         Value::Array(ref a) => {
+                            self.charge_values(a.len())?;
                             result.extend(a.iter().cloned());
                         }
                         other => {
@@ -4011,39 +6620,195 @@ impl LispEvaluator {
         }
     }
 
-    /// (range start end) - Create range
+    /// (range start end [step]) - Create the half-open range [start, end)
+    ///
+    /// A negative step counts down; any float argument yields a float range.
     fn eval_range(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 2 {
+        let bounds = self.range_bounds(args)?;
+        self.materialize_range(bounds)
+    }
+
+    /// Evaluate `range`'s arguments to numeric [start, end, step], step defaulting to 1
+    fn range_bounds(&mut self, args: &[crate::parser::Argument]) -> Result<[Value; 3]> {
+        if args.len() != 2 && args.len() != 3 {
             return Err(Error::InvalidArguments {
-                tool: "lisp_evaluator".to_string(),
-                reason: format!("Expected {} arguments, got {}", 2, args.len()),
+                tool: "range".to_string(),
+                reason: format!("Expected 2 or 3 arguments, got {}", args.len()),
             })?;
         }
 
-        let start_val = self.evaluate_expression(&args[0].value)?;
-        let end_val = self.evaluate_expression(&args[1].value)?;
+        let mut bounds = Vec::with_capacity(3);
+        for arg in args {
+            let val = self.evaluate_expression(&arg.value)?;
+            match val {
+                Value::Int(_) | Value::Float(_) => bounds.push(val),
+                _ => {
+                    return Err(Error::TypeError {
+                        expected: "number".to_string(),
+                        got: val.type_name(),
+                    })
+                }
+            }
+        }
+        let step = bounds.get(2).cloned().unwrap_or(Value::Int(1));
+        if matches!(step, Value::Int(0)) {
+            return Err(Error::InvalidArguments {
+                tool: "range".to_string(),
+                reason: "Step must be non-zero".to_string(),
+            });
+        }
+        Ok([bounds[0].clone(), bounds[1].clone(), step])
+    }
+
+    /// Build the array for evaluated `range` bounds
+    fn materialize_range(&mut self, bounds: [Value; 3]) -> Result<Value> {
+        match (&bounds[0], &bounds[1], &bounds[2]) {
+            (Value::Int(start), Value::Int(end), Value::Int(step)) => {
+                let (start, end, step) = (*start, *end, *step);
+                let span = if step > 0 {
+                    end.saturating_sub(start)
+                } else {
+                    start.saturating_sub(end)
+                };
+                let count = if span <= 0 {
+                    0
+                } else {
+                    (span as u64).div_ceil(step.unsigned_abs()) as usize
+                };
+                self.charge_values(count)?;
+                let values: Vec<Value> = (0..count as i64)
+                    .map(|i| Value::Int(start + i * step))
+                    .collect();
+                Ok(Value::Array(Arc::new(values)))
+            }
+            _ => {
+                let start = bounds[0].as_float()?;
+                let end = bounds[1].as_float()?;
+                let step = bounds[2].as_float()?;
+                if step == 0.0 || !step.is_finite() {
+                    return Err(Error::InvalidArguments {
+                        tool: "range".to_string(),
+                        reason: format!("Step must be a non-zero finite number, got {}", step),
+                    });
+                }
+                let count = ((end - start) / step).ceil();
+                let count = if count.is_nan() || count <= 0.0 {
+                    0
+                } else {
+                    count as usize
+                };
+                self.charge_values(count)?;
+                // Multiply rather than accumulate so rounding error doesn't drift,
+                // and re-check the bound in case the division rounded up
+                let values: Vec<Value> = (0..count)
+                    .map(|i| start + i as f64 * step)
+                    .take_while(|x| if step > 0.0 { *x < end } else { *x > end })
+                    .map(Value::Float)
+                    .collect();
+                Ok(Value::Array(Arc::new(values)))
+            }
+        }
+    }
 
-        let start = match start_val {
-            Value::Int(n) => n,
+    /// (iota n [start] [step]) - `n` numbers from `start` (default 0) by `step` (default 1)
+    fn eval_iota(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() || args.len() > 3 {
+            return Err(Error::InvalidArguments {
+                tool: "iota".to_string(),
+                reason: format!("Expected 1 to 3 arguments, got {}", args.len()),
+            });
+        }
+
+        let count = self.evaluate_expression(&args[0].value)?;
+        let count = match count {
+            Value::Int(n) if n >= 0 => n as usize,
+            Value::Int(n) => {
+                return Err(Error::InvalidArguments {
+                    tool: "iota".to_string(),
+                    reason: format!("Count must be non-negative, got {}", n),
+                })
+            }
             _ => {
                 return Err(Error::TypeError {
                     expected: "int".to_string(),
-                    got: start_val.type_name(),
+                    got: count.type_name(),
                 })
             }
         };
 
-        let end = match end_val {
-            Value::Int(n) => n,
+        let mut params = [Value::Int(0), Value::Int(1)];
+        for (param, arg) in params.iter_mut().zip(&args[1..]) {
+            *param = self.evaluate_expression(&arg.value)?;
+            if !matches!(param, Value::Int(_) | Value::Float(_)) {
+                return Err(Error::TypeError {
+                    expected: "number".to_string(),
+                    got: param.type_name(),
+                });
+            }
+        }
+
+        self.charge_values(count)?;
+        let values: Vec<Value> = match params {
+            [Value::Int(start), Value::Int(step)] => (0..count as i64)
+                .map(|i| Value::Int(start.saturating_add(i.saturating_mul(step))))
+                .collect(),
+            [start, step] => {
+                let (start, step) = (start.as_float()?, step.as_float()?);
+                (0..count)
+                    .map(|i| Value::Float(start + i as f64 * step))
+                    .collect()
+            }
+        };
+        Ok(Value::Array(Arc::new(values)))
+    }
+
+    /// (linspace start end n) - `n` evenly spaced floats from `start` to `end` inclusive
+    fn eval_linspace(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 3 {
+            return Err(Error::InvalidArguments {
+                tool: "linspace".to_string(),
+                reason: format!("Expected 3 arguments, got {}", args.len()),
+            });
+        }
+
+        let start = self.evaluate_expression(&args[0].value)?.as_float()?;
+        let end = self.evaluate_expression(&args[1].value)?.as_float()?;
+        let count = self.evaluate_expression(&args[2].value)?;
+        let count = match count {
+            Value::Int(n) if n >= 0 => n as usize,
+            Value::Int(n) => {
+                return Err(Error::InvalidArguments {
+                    tool: "linspace".to_string(),
+                    reason: format!("Count must be non-negative, got {}", n),
+                })
+            }
             _ => {
                 return Err(Error::TypeError {
                     expected: "int".to_string(),
-                    got: end_val.type_name(),
+                    got: count.type_name(),
                 })
             }
         };
 
-        let values: Vec<Value> = (start..end).map(Value::Int).collect();
+        self.charge_values(count)?;
+        // A single point is just `start`, as in NumPy; otherwise pin the last
+        // point to `end` exactly rather than trusting accumulated rounding
+        let values: Vec<Value> = match count {
+            0 => Vec::new(),
+            1 => vec![Value::Float(start)],
+            _ => {
+                let step = (end - start) / (count - 1) as f64;
+                (0..count)
+                    .map(|i| {
+                        Value::Float(if i == count - 1 {
+                            end
+                        } else {
+                            start + i as f64 * step
+                        })
+                    })
+                    .collect()
+            }
+        };
         Ok(Value::Array(Arc::new(values)))
     }
 
@@ -4099,6 +6864,93 @@ impl LispEvaluator {
         Ok(Value::Int(max_val.unwrap()))
     }
 
+    /// Whether a `min`/`max` call is the `(max collection :key fn)` form
+    fn has_key_option(args: &[crate::parser::Argument]) -> bool {
+        args.len() == 3 && matches!(&args[1].value, Expression::StringLiteral(kw) if kw == ":key")
+    }
+
+    /// (max collection :key fn) / (min collection :key fn) - Element with the
+    /// extremal key, as `max-by` / `min-by`
+    fn eval_extremum_key(
+        &mut self,
+        args: &[crate::parser::Argument],
+        tool: &str,
+        wanted: std::cmp::Ordering,
+    ) -> Result<Value> {
+        let items = self.eval_sequence_items(&args[0].value)?;
+        let key_fn = self.eval_function_designator(&args[2].value)?;
+        self.extremum_by(tool, &key_fn, items, wanted)
+    }
+
+    /// (max-by key-fn collection) / (min-by key-fn collection) - Element whose
+    /// key is largest / smallest; ties go to the first such element
+    fn eval_extremum_by(
+        &mut self,
+        args: &[crate::parser::Argument],
+        tool: &str,
+        wanted: std::cmp::Ordering,
+    ) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: "Expected 2 arguments: key function and collection".to_string(),
+            });
+        }
+
+        let key_fn = self.eval_function_designator(&args[0].value)?;
+        let items = self.eval_sequence_items(&args[1].value)?;
+        self.extremum_by(tool, &key_fn, items, wanted)
+    }
+
+    fn extremum_by(
+        &mut self,
+        tool: &str,
+        key_fn: &Value,
+        items: SequenceItems,
+        wanted: std::cmp::Ordering,
+    ) -> Result<Value> {
+        Self::check_function_arity(tool, key_fn, 1, "Key function")?;
+
+        let mut best: Option<(Value, Value)> = None;
+        for item in items {
+            let key = self.apply_function_designator(key_fn, vec![item.clone()], tool)?;
+            // Only a strictly better key replaces the current best
+            let better = match &best {
+                None => true,
+                Some((_, best_key)) => Self::compare_keys(tool, &key, best_key)? == wanted,
+            };
+            if better {
+                best = Some((item, key));
+            }
+        }
+
+        best.map(|(item, _)| item)
+            .ok_or_else(|| Error::EmptyCollection {
+                operation: tool.to_string(),
+            })
+    }
+
+    /// Order two keys: numbers numerically (ints and floats mixed), strings
+    /// lexicographically
+    fn compare_keys(tool: &str, a: &Value, b: &Value) -> Result<std::cmp::Ordering> {
+        match (a, b) {
+            (Value::Int(x), Value::Int(y)) => Ok(x.cmp(y)),
+            (Value::String(x), Value::String(y)) => Ok(x.cmp(y)),
+            (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => Ok(a
+                .as_float()?
+                .partial_cmp(&b.as_float()?)
+                .unwrap_or(std::cmp::Ordering::Equal)),
+            _ => Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!(
+                    "Cannot compare keys of type {} and {}",
+                    a.type_name(),
+                    b.type_name()
+                ),
+            }),
+        }
+    }
+
     /// (now) - Get current timestamp
     fn eval_now(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if !args.is_empty() {
@@ -4117,104 +6969,394 @@ impl LispEvaluator {
         Ok(Value::Int(timestamp as i64))
     }
 
-    /// (sleep milliseconds) - Sleep for specified milliseconds
-    fn eval_sleep(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 1 {
+    /// (parse-date text [format]) - Parse a date into an instant
+    ///
+    /// Without a format, accepts RFC 3339 (`2024-01-01T00:00:00Z`), a naive
+    /// `2024-01-01T12:30:00` or `2024-01-01 12:30:00` (taken as UTC), or a
+    /// bare `2024-01-01`. An integer is taken as Unix seconds, as from `now`.
+    fn eval_parse_date(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() || args.len() > 2 {
             return Err(Error::InvalidArguments {
-                tool: "sleep".to_string(),
-                reason: format!("Expected 1 argument, got {}", args.len()),
+                tool: "parse-date".to_string(),
+                reason: "Expected 1-2 arguments: text and optional format".to_string(),
             });
         }
 
-        let val = self.evaluate_expression(&args[0].value)?;
-        let ms = match val {
-            Value::Int(i) => i as u64,
-            Value::Float(f) => f as u64,
-            _ => {
-                return Err(Error::TypeError {
-                    expected: "number".to_string(),
-                    got: val.type_name().to_string(),
+        let input = self.evaluate_expression(&args[0].value)?;
+        let format = match args.get(1) {
+            Some(arg) => Some(
+                self.evaluate_expression(&arg.value)?
+                    .as_string()?
+                    .to_string(),
+            ),
+            None => None,
+        };
+
+        let text = match input {
+            Value::Instant(_) => return Ok(input),
+            Value::Int(secs) => {
+                return secs.checked_mul(1000).map(Value::Instant).ok_or_else(|| {
+                    Error::InvalidArguments {
+                        tool: "parse-date".to_string(),
+                        reason: format!("Timestamp {} is out of range", secs),
+                    }
                 })
             }
+            other => other.as_string()?.to_string(),
         };
 
-        std::thread::sleep(std::time::Duration::from_millis(ms));
-        Ok(Value::Null)
+        let millis = match &format {
+            Some(format) => Self::parse_date_with_format(&text, format),
+            None => Self::parse_date_default(&text),
+        };
+        millis
+            .map(Value::Instant)
+            .ok_or_else(|| Error::InvalidArguments {
+                tool: "parse-date".to_string(),
+                reason: match format {
+                    Some(format) => format!("Cannot parse {:?} with format {:?}", text, format),
+                    None => format!("Cannot parse {:?} as a date", text),
+                },
+            })
     }
 
-    /// (base58-encode string) - Encode string to base58
-    fn eval_base58_encode(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 1 {
-            return Err(Error::InvalidArguments {
-                tool: "base58-encode".to_string(),
-                reason: format!("Expected 1 argument, got {}", args.len()),
-            });
-        }
+    fn parse_date_default(text: &str) -> Option<i64> {
+        use chrono::{DateTime, NaiveDate, NaiveDateTime};
 
-        let val = self.evaluate_expression(&args[0].value)?;
-        let input = match val {
-            Value::String(s) => s.as_bytes().to_vec(),
-            _ => {
-                return Err(Error::TypeError {
-                    expected: "string".to_string(),
-                    got: val.type_name().to_string(),
-                })
+        if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+            return Some(dt.timestamp_millis());
+        }
+        for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+            if let Ok(dt) = NaiveDateTime::parse_from_str(text, format) {
+                return Some(dt.and_utc().timestamp_millis());
             }
-        };
+        }
+        NaiveDate::parse_from_str(text, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|dt| dt.and_utc().timestamp_millis())
+    }
 
-        let encoded = bs58::encode(input).into_string();
-        Ok(Value::String(encoded))
+    fn parse_date_with_format(text: &str, format: &str) -> Option<i64> {
+        use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+        if let Ok(dt) = DateTime::parse_from_str(text, format) {
+            return Some(dt.timestamp_millis());
+        }
+        if let Ok(dt) = NaiveDateTime::parse_from_str(text, format) {
+            return Some(dt.and_utc().timestamp_millis());
+        }
+        NaiveDate::parse_from_str(text, format)
+            .ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|dt| dt.and_utc().timestamp_millis())
     }
 
-    /// (base58-decode base58-string) - Decode base58 to string
-    fn eval_base58_decode(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 1 {
+    /// (format-date instant [format]) - Format an instant in UTC with a
+    /// strftime-style format such as "%Y-%m-%d"; RFC 3339 by default
+    fn eval_format_date(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use chrono::format::{Item, StrftimeItems};
+
+        if args.is_empty() || args.len() > 2 {
             return Err(Error::InvalidArguments {
-                tool: "base58-decode".to_string(),
-                reason: format!("Expected 1 argument, got {}", args.len()),
+                tool: "format-date".to_string(),
+                reason: "Expected 1-2 arguments: instant and optional format".to_string(),
             });
         }
 
-        let val = self.evaluate_expression(&args[0].value)?;
-        let input = match val {
-            Value::String(s) => s,
-            _ => {
-                return Err(Error::TypeError {
-                    expected: "string".to_string(),
-                    got: val.type_name().to_string(),
-                })
-            }
+        let millis = Self::instant_millis(&self.evaluate_expression(&args[0].value)?)?;
+        let Some(format) = args.get(1) else {
+            return Ok(Value::String(crate::runtime::value::format_instant(millis)));
         };
+        let format = self.evaluate_expression(&format.value)?;
+        let format = format.as_string()?;
 
-        let decoded = bs58::decode(input)
-            .into_vec()
-            .map_err(|e| Error::ParseError(format!("Invalid base58: {}", e)))?;
-
-        let result = String::from_utf8(decoded)
-            .map_err(|e| Error::ParseError(format!("Invalid UTF-8 in decoded base58: {}", e)))?;
+        let items: Vec<Item> = StrftimeItems::new(format).collect();
+        if items.iter().any(|item| matches!(item, Item::Error)) {
+            return Err(Error::InvalidArguments {
+                tool: "format-date".to_string(),
+                reason: format!("Invalid date format {:?}", format),
+            });
+        }
+        let dt = Self::instant_datetime("format-date", millis)?;
 
-        Ok(Value::String(result))
+        Ok(Value::String(
+            dt.format_with_items(items.into_iter()).to_string(),
+        ))
     }
 
-    /// (base64-encode string) - Encode string to base64
-    fn eval_base64_encode(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 1 {
+    /// (date-add instant :unit n ...) - Shift an instant by whole units
+    ///
+    /// Units are milliseconds, seconds, minutes, hours, days and weeks, plus
+    /// calendar months and years, which clamp to the end of shorter months.
+    /// Example: (date-add (parse-date "2024-01-30") :days 3) → #inst "2024-02-02T00:00:00Z"
+    fn eval_date_add(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
             return Err(Error::InvalidArguments {
-                tool: "base64-encode".to_string(),
-                reason: format!("Expected 1 argument, got {}", args.len()),
+                tool: "date-add".to_string(),
+                reason: "Expected an instant followed by :unit amount pairs".to_string(),
             });
         }
 
-        let val = self.evaluate_expression(&args[0].value)?;
-        let input = match val {
-            Value::String(s) => s.as_bytes().to_vec(),
-            _ => {
-                return Err(Error::TypeError {
-                    expected: "string".to_string(),
-                    got: val.type_name().to_string(),
-                })
-            }
-        };
+        let mut millis = Self::instant_millis(&self.evaluate_expression(&args[0].value)?)?;
+        for (unit, expr) in Self::keyword_options("date-add", &args[1..])? {
+            let amount = match self.evaluate_expression(expr)? {
+                Value::Int(n) => n,
+                other => {
+                    return Err(Error::TypeError {
+                        expected: "int".to_string(),
+                        got: other.type_name(),
+                    })
+                }
+            };
+            millis = Self::add_to_instant(millis, &unit, amount)?;
+        }
+
+        Ok(Value::Instant(millis))
+    }
+
+    fn add_to_instant(millis: i64, unit: &str, amount: i64) -> Result<i64> {
+        use chrono::Months;
+
+        let out_of_range = || Error::InvalidArguments {
+            tool: "date-add".to_string(),
+            reason: format!("Adding {} {} leaves the supported date range", amount, unit),
+        };
+
+        let months = match unit {
+            "months" | "month" => Some(amount),
+            "years" | "year" => Some(amount.checked_mul(12).ok_or_else(out_of_range)?),
+            _ => None,
+        };
+        let Some(months) = months else {
+            let unit_millis = Self::time_unit_millis("date-add", unit)?;
+            return amount
+                .checked_mul(unit_millis)
+                .and_then(|delta| millis.checked_add(delta))
+                .ok_or_else(out_of_range);
+        };
+
+        let dt = Self::instant_datetime("date-add", millis)?;
+        let count = u32::try_from(months.unsigned_abs()).map_err(|_| out_of_range())?;
+        let shifted = if months >= 0 {
+            dt.checked_add_months(Months::new(count))
+        } else {
+            dt.checked_sub_months(Months::new(count))
+        };
+        shifted
+            .map(|dt| dt.timestamp_millis())
+            .ok_or_else(out_of_range)
+    }
+
+    /// (date-diff a b [unit]) - Time from b to a (a - b) in whole units,
+    /// truncated toward zero; milliseconds by default
+    /// Example: (date-diff (parse-date "2024-01-02") (parse-date "2024-01-01") :hours) → 24
+    fn eval_date_diff(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 && args.len() != 3 {
+            return Err(Error::InvalidArguments {
+                tool: "date-diff".to_string(),
+                reason: "Expected 2-3 arguments: two instants and optional unit".to_string(),
+            });
+        }
+
+        let a = Self::instant_millis(&self.evaluate_expression(&args[0].value)?)?;
+        let b = Self::instant_millis(&self.evaluate_expression(&args[1].value)?)?;
+        let unit_millis = match args.get(2) {
+            Some(arg) => {
+                let unit = self.evaluate_expression(&arg.value)?;
+                let unit = unit.as_string()?;
+                Self::time_unit_millis("date-diff", unit.strip_prefix(':').unwrap_or(unit))?
+            }
+            None => 1,
+        };
+
+        let diff = (a as i128 - b as i128) / unit_millis as i128;
+        Ok(Value::Int(diff as i64))
+    }
+
+    /// Milliseconds in a fixed-length time unit
+    fn time_unit_millis(tool: &str, unit: &str) -> Result<i64> {
+        Ok(match unit.strip_suffix('s').unwrap_or(unit) {
+            "millisecond" => 1,
+            "second" => 1_000,
+            "minute" => 60_000,
+            "hour" => 3_600_000,
+            "day" => 86_400_000,
+            "week" => 604_800_000,
+            _ => {
+                return Err(Error::InvalidArguments {
+                    tool: tool.to_string(),
+                    reason: format!(
+                        "Unknown time unit :{} (expected milliseconds, seconds, minutes, hours, days or weeks)",
+                        unit
+                    ),
+                })
+            }
+        })
+    }
+
+    fn instant_millis(value: &Value) -> Result<i64> {
+        match value {
+            Value::Instant(millis) => Ok(*millis),
+            other => Err(Error::TypeError {
+                expected: "instant".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
+    fn instant_datetime(tool: &str, millis: i64) -> Result<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp_millis(millis).ok_or_else(|| Error::InvalidArguments {
+            tool: tool.to_string(),
+            reason: format!("Instant {}ms is outside the supported date range", millis),
+        })
+    }
+
+    /// (sleep seconds) - Sleep for a possibly fractional number of seconds
+    ///
+    /// Sleeps in short slices so cancellation and execution timeouts
+    /// interrupt it promptly.
+    fn eval_sleep(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "sleep".to_string(),
+                reason: format!("Expected 1 argument, got {}", args.len()),
+            });
+        }
+
+        let val = self.evaluate_expression(&args[0].value)?;
+        let secs = match val {
+            Value::Int(i) => i as f64,
+            Value::Float(f) => f,
+            _ => {
+                return Err(Error::TypeError {
+                    expected: "number".to_string(),
+                    got: val.type_name().to_string(),
+                })
+            }
+        };
+        let duration =
+            std::time::Duration::try_from_secs_f64(secs).map_err(|_| Error::InvalidArguments {
+                tool: "sleep".to_string(),
+                reason: format!("Expected a non-negative number of seconds, got {}", secs),
+            })?;
+
+        let wake = std::time::Instant::now() + duration;
+        loop {
+            self.check_interrupt()?;
+            let remaining = wake.saturating_duration_since(std::time::Instant::now());
+            if remaining.is_zero() {
+                return Ok(Value::Null);
+            }
+            std::thread::sleep(remaining.min(SLEEP_POLL_INTERVAL));
+        }
+    }
+
+    /// (time expr) - Evaluate expr, log elapsed wall-clock milliseconds, return its value
+    fn eval_time(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let (value, elapsed_ms) = self.timed_evaluation("time", args)?;
+        self.write_output(&format!("Elapsed time: {:.3} ms\n", elapsed_ms))?;
+        Ok(value)
+    }
+
+    /// (measure-time expr) - Evaluate expr and return [result elapsed-ms] without printing
+    fn eval_measure_time(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let (value, elapsed_ms) = self.timed_evaluation("measure-time", args)?;
+        Ok(Value::array(vec![value, Value::Float(elapsed_ms)]))
+    }
+
+    /// Evaluate a single expression, returning its value and elapsed milliseconds
+    fn timed_evaluation(
+        &mut self,
+        tool: &str,
+        args: &[crate::parser::Argument],
+    ) -> Result<(Value, f64)> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!("Expected 1 argument (expression), got {}", args.len()),
+            });
+        }
+
+        let start = std::time::Instant::now();
+        let value = self.evaluate_expression(&args[0].value)?;
+        let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+        Ok((value, elapsed_ms))
+    }
+
+    /// (base58-encode string) - Encode string to base58
+    fn eval_base58_encode(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "base58-encode".to_string(),
+                reason: format!("Expected 1 argument, got {}", args.len()),
+            });
+        }
+
+        let val = self.evaluate_expression(&args[0].value)?;
+        let input = match val {
+            Value::String(s) => s.as_bytes().to_vec(),
+            _ => {
+                return Err(Error::TypeError {
+                    expected: "string".to_string(),
+                    got: val.type_name().to_string(),
+                })
+            }
+        };
+
+        let encoded = bs58::encode(input).into_string();
+        Ok(Value::String(encoded))
+    }
+
+    /// (base58-decode base58-string) - Decode base58 to string
+    fn eval_base58_decode(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "base58-decode".to_string(),
+                reason: format!("Expected 1 argument, got {}", args.len()),
+            });
+        }
+
+        let val = self.evaluate_expression(&args[0].value)?;
+        let input = match val {
+            Value::String(s) => s,
+            _ => {
+                return Err(Error::TypeError {
+                    expected: "string".to_string(),
+                    got: val.type_name().to_string(),
+                })
+            }
+        };
+
+        let decoded = bs58::decode(input)
+            .into_vec()
+            .map_err(|e| Error::ParseError(format!("Invalid base58: {}", e)))?;
+
+        let result = String::from_utf8(decoded)
+            .map_err(|e| Error::ParseError(format!("Invalid UTF-8 in decoded base58: {}", e)))?;
+
+        Ok(Value::String(result))
+    }
+
+    /// (base64-encode string) - Encode string to base64
+    fn eval_base64_encode(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "base64-encode".to_string(),
+                reason: format!("Expected 1 argument, got {}", args.len()),
+            });
+        }
+
+        let val = self.evaluate_expression(&args[0].value)?;
+        let input = match val {
+            Value::String(s) => s.as_bytes().to_vec(),
+            _ => {
+                return Err(Error::TypeError {
+                    expected: "string".to_string(),
+                    got: val.type_name().to_string(),
+                })
+            }
+        };
 
         let encoded = base64::engine::general_purpose::STANDARD.encode(&input);
         Ok(Value::String(encoded))
@@ -4581,18 +7723,18 @@ impl LispEvaluator {
         // Print message and value
         if let Some(msg) = message_val {
             if let Some(val) = value_val {
-                println!("{} {}", msg, val);
+                self.write_output(&format!("{} {}\n", msg, val))?;
             } else {
-                println!("{}", msg);
+                self.write_output(&format!("{}\n", msg))?;
             }
         } else if let Some(val) = value_val {
-            println!("{}", val);
+            self.write_output(&format!("{}\n", val))?;
         } else {
             // If no named args, print all positional args
             for arg in args {
                 if arg.name.is_none() {
                     let val = self.evaluate_expression(&arg.value)?;
-                    println!("{}", val);
+                    self.write_output(&format!("{}\n", val))?;
                 }
             }
         }
@@ -4600,83 +7742,298 @@ impl LispEvaluator {
         Ok(Value::Null)
     }
 
-    /// (print value ...) - Print values (Python/JS style)
+    /// (print value ... [:sep s]) - Print values (Python/JS style)
     fn eval_print(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        let mut output = String::new();
-        for (i, arg) in args.iter().enumerate() {
-            if i > 0 {
-                output.push(' ');
-            }
-            let val = self.evaluate_expression(&arg.value)?;
-            output.push_str(&val.to_string());
-        }
-        print!("{}", output);
-        std::io::Write::flush(&mut std::io::stdout()).ok();
+        let output = self.print_text("print", args)?;
+        self.write_output(&output)?;
         Ok(Value::Null)
     }
 
-    /// (println value ...) - Print values with newline (Python/JS style)
+    /// (println value ... [:sep s]) - Print values with newline (Python/JS style)
     fn eval_println(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        let mut output = String::new();
-        for (i, arg) in args.iter().enumerate() {
-            if i > 0 {
-                output.push(' ');
+        let output = self.print_text("println", args)?;
+        self.write_output(&format!("{}\n", output))?;
+        Ok(Value::Null)
+    }
+
+    /// (with-output-capture body...) - Evaluate body, returning what it printed
+    ///
+    /// Output written by `print`, `println`, `log` and `(format t ...)` while
+    /// body runs is collected into the returned string instead of reaching
+    /// the sink or stdout. Nested captures each keep their own output.
+    fn eval_with_output_capture(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        self.output_captures.push(String::new());
+        let result = args
+            .iter()
+            .try_for_each(|arg| self.evaluate_expression(&arg.value).map(drop));
+        let captured = self.output_captures.pop().unwrap_or_default();
+        result?;
+        self.charge_allocation(captured.len())?;
+        Ok(Value::String(captured))
+    }
+
+    /// Text printed by `print`/`println`: the aesthetic form of each argument,
+    /// joined by a space or by the string given with `:sep`
+    fn print_text(&mut self, tool: &str, args: &[crate::parser::Argument]) -> Result<String> {
+        let mut separator = " ".to_string();
+        let mut parts = Vec::with_capacity(args.len());
+        let mut i = 0;
+        while i < args.len() {
+            if matches!(&args[i].value, Expression::StringLiteral(kw) if kw == ":sep")
+                && i + 1 < args.len()
+            {
+                separator = match self.evaluate_expression(&args[i + 1].value)? {
+                    Value::String(s) => s,
+                    other => {
+                        return Err(Error::InvalidArguments {
+                            tool: tool.to_string(),
+                            reason: format!(":sep must be a string, got {}", other.type_name()),
+                        })
+                    }
+                };
+                i += 2;
+                continue;
             }
-            let val = self.evaluate_expression(&arg.value)?;
-            output.push_str(&val.to_string());
+            let val = self.evaluate_expression(&args[i].value)?;
+            parts.push(Self::aesthetic_string(&val));
+            i += 1;
         }
-        println!("{}", output);
-        Ok(Value::Null)
+        Ok(parts.join(&separator))
     }
 
-    /// (indexOf collection element) - Find index of element in collection
-    fn eval_indexof(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 2 {
+    /// (dbg expr) - Evaluate expr, print `<form> = <value>`, return the value
+    ///
+    /// The form is reconstructed from the AST, since expressions carry no
+    /// source spans, and the value is shown in its readable form.
+    fn eval_dbg(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
             return Err(Error::InvalidArguments {
-                tool: "indexOf".to_string(),
-                reason: "Expected 2 arguments: collection and element".to_string(),
+                tool: "dbg".to_string(),
+                reason: "Expected 1 argument: expression to trace".to_string(),
             });
         }
+        let expr = &args[0].value;
+        let source = self.expression_source(expr);
+        let value = self.evaluate_expression(expr)?;
+        self.write_output(&format!("{} = {}\n", source, Self::readable_string(&value)))?;
+        Ok(value)
+    }
 
-        let collection = self.evaluate_expression(&args[0].value)?;
-        let needle = self.evaluate_expression(&args[1].value)?;
-
-        match collection {
-            Value::Array(ref arr) => {
-                for (i, item) in arr.iter().enumerate() {
-                    if item == &needle {
-                        return Ok(Value::Int(i as i64));
+    /// Render an expression back as S-expression source, for traces
+    fn expression_source(&self, expr: &Expression) -> String {
+        let list = |items: Vec<String>| format!("({})", items.join(" "));
+        match expr {
+            Expression::StringLiteral(s) => Self::readable_string(&Value::String(s.clone())),
+            Expression::Variable(name) => name.clone(),
+            Expression::ArrayLiteral(items) => {
+                let items: Vec<String> = items.iter().map(|e| self.expression_source(e)).collect();
+                format!("[{}]", items.join(" "))
+            }
+            Expression::ToolCall { name, args } => {
+                let mut items = vec![name.clone()];
+                for arg in args {
+                    if let Some(keyword) = &arg.name {
+                        items.push(format!(":{}", keyword.trim_start_matches(':')));
                     }
+                    items.push(self.expression_source(&arg.value));
                 }
-                Ok(Value::Int(-1)) // Not found
+                list(items)
             }
-            Value::String(ref s) => {
-                let needle_str = needle.as_string()?;
-                match s.find(needle_str) {
-                    Some(idx) => Ok(Value::Int(idx as i64)),
-                    None => Ok(Value::Int(-1)),
-                }
+            Expression::Binary { op, left, right } => {
+                let op = match op {
+                    BinaryOp::And => "and".to_string(),
+                    BinaryOp::Or => "or".to_string(),
+                    op => op.to_string(),
+                };
+                list(vec![
+                    op,
+                    self.expression_source(left),
+                    self.expression_source(right),
+                ])
             }
-            _ => Err(Error::TypeError {
-                expected: "array or string".to_string(),
-                got: collection.type_name(),
-            }),
+            Expression::Unary { op, operand } => {
+                let op = match op {
+                    UnaryOp::Neg => "-",
+                    UnaryOp::Not => "not",
+                };
+                list(vec![op.to_string(), self.expression_source(operand)])
+            }
+            Expression::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+            } => list(vec![
+                "if".to_string(),
+                self.expression_source(condition),
+                self.expression_source(then_expr),
+                self.expression_source(else_expr),
+            ]),
+            other => self
+                .expression_to_value(other)
+                .and_then(|datum| Self::datum_source(&datum))
+                .unwrap_or_else(|_| format!("{:?}", other)),
         }
     }
 
-    /// (contains collection element) - Check if collection contains element
-    fn eval_contains(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 2 {
+    /// (prin1 value) / (write value) / (princ value) - Print one value
+    ///
+    /// `prin1` and `write` print the readable form that `read` parses back;
+    /// `princ` prints the aesthetic form. Returns the value.
+    fn eval_write_value(
+        &mut self,
+        args: &[crate::parser::Argument],
+        tool: &str,
+        readable: bool,
+    ) -> Result<Value> {
+        let val = self.eval_single_printable(args, tool)?;
+        let text = if readable {
+            Self::readable_string(&val)
+        } else {
+            Self::aesthetic_string(&val)
+        };
+        self.write_output(&text)?;
+        Ok(val)
+    }
+
+    /// (prin1-to-string value) / (princ-to-string value) - Printed form as a string
+    fn eval_value_to_string(
+        &mut self,
+        args: &[crate::parser::Argument],
+        tool: &str,
+        readable: bool,
+    ) -> Result<Value> {
+        let val = self.eval_single_printable(args, tool)?;
+        let text = if readable {
+            Self::readable_string(&val)
+        } else {
+            Self::aesthetic_string(&val)
+        };
+        self.charge_allocation(text.len())?;
+        Ok(Value::String(text))
+    }
+
+    fn eval_single_printable(
+        &mut self,
+        args: &[crate::parser::Argument],
+        tool: &str,
+    ) -> Result<Value> {
+        if args.len() != 1 {
             return Err(Error::InvalidArguments {
-                tool: "contains".to_string(),
-                reason: "Expected 2 arguments: collection and element".to_string(),
+                tool: tool.to_string(),
+                reason: "Expected 1 argument: value to print".to_string(),
             });
         }
+        self.evaluate_expression(&args[0].value)
+    }
 
-        let collection = self.evaluate_expression(&args[0].value)?;
-        let needle = self.evaluate_expression(&args[1].value)?;
-
-        match collection {
+    /// Form of a value that `read` parses back to an equal value
+    ///
+    /// Strings are quoted and escaped, keywords are left bare, floats keep
+    /// their decimal point and arrays print as lists. Values with no
+    /// literal syntax fall back to their display form.
+    fn readable_string(val: &Value) -> String {
+        match val {
+            Value::Null => "nil".to_string(),
+            Value::Bool(b) => b.to_string(),
+            Value::Int(n) => n.to_string(),
+            Value::Float(f) => format!("{:?}", f),
+            Value::String(s) if Self::is_keyword_name(s) => s.clone(),
+            Value::String(s) => {
+                let mut out = String::with_capacity(s.len() + 2);
+                out.push('"');
+                for c in s.chars() {
+                    match c {
+                        '"' => out.push_str("\\\""),
+                        '\\' => out.push_str("\\\\"),
+                        '\n' => out.push_str("\\n"),
+                        '\t' => out.push_str("\\t"),
+                        '\r' => out.push_str("\\r"),
+                        c => out.push(c),
+                    }
+                }
+                out.push('"');
+                out
+            }
+            Value::Array(items) => {
+                let items: Vec<String> = items.iter().map(Self::readable_string).collect();
+                format!("({})", items.join(" "))
+            }
+            _ => format!("{}", val),
+        }
+    }
+
+    /// Whether `s` reads back as a keyword such as `:limit`
+    fn is_keyword_name(s: &str) -> bool {
+        s.strip_prefix(':').is_some_and(|rest| {
+            rest.chars().next().is_some_and(char::is_alphabetic)
+                && rest
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '?' | '!'))
+        })
+    }
+
+    /// Human-readable form of a value, as `str` and `println` show it:
+    /// strings without quotes, instants as plain ISO text
+    fn aesthetic_string(val: &Value) -> String {
+        match val {
+            Value::String(s) => s.clone(),
+            Value::Int(n) => n.to_string(),
+            Value::Float(f) => f.to_string(),
+            Value::Bool(b) => b.to_string(),
+            Value::Null => "null".to_string(),
+            Value::Instant(_) => val.to_string_value(),
+            _ => format!("{}", val),
+        }
+    }
+
+    /// (indexOf collection element) - Find index of element in collection
+    fn eval_indexof(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "indexOf".to_string(),
+                reason: "Expected 2 arguments: collection and element".to_string(),
+            });
+        }
+
+        let collection = self.evaluate_expression(&args[0].value)?;
+        let needle = self.evaluate_expression(&args[1].value)?;
+
+        match collection {
+            Value::Array(ref arr) => {
+                for (i, item) in arr.iter().enumerate() {
+                    if item == &needle {
+                        return Ok(Value::Int(i as i64));
+                    }
+                }
+                Ok(Value::Int(-1)) // Not found
+            }
+            Value::String(ref s) => {
+                let needle_str = needle.as_string()?;
+                match s.find(needle_str) {
+                    Some(idx) => Ok(Value::Int(idx as i64)),
+                    None => Ok(Value::Int(-1)),
+                }
+            }
+            _ => Err(Error::TypeError {
+                expected: "array or string".to_string(),
+                got: collection.type_name(),
+            }),
+        }
+    }
+
+    /// (contains collection element) - Check if collection contains element
+    fn eval_contains(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "contains".to_string(),
+                reason: "Expected 2 arguments: collection and element".to_string(),
+            });
+        }
+
+        let collection = self.evaluate_expression(&args[0].value)?;
+        let needle = self.evaluate_expression(&args[1].value)?;
+
+        match collection {
             Value::Array(ref arr) => Ok(Value::Bool(arr.iter().any(|item| item == &needle))),
             Value::String(ref s) => {
                 let needle_str = needle.as_string()?;
@@ -4829,6 +8186,153 @@ impl LispEvaluator {
         }
     }
 
+    /// (coerce value type) - Common Lisp conversion between sequences and numbers
+    ///
+    /// Types: 'list / 'vector / 'array / 'sequence, 'string, 'float, 'integer and 't.
+    /// Strings coerce to lists of one-character strings and back. Unlike `int`,
+    /// coercing a float to 'integer only succeeds when no precision is lost.
+    fn eval_coerce(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "coerce".to_string(),
+                reason: "Expected 2 arguments: value and type".to_string(),
+            });
+        }
+
+        let value = self.evaluate_expression(&args[0].value)?;
+        let type_val = self.eval_function_designator(&args[1].value)?;
+        let target = type_val.as_string()?.trim_start_matches(':').to_lowercase();
+
+        let impossible = |value: &Value| Error::TypeError {
+            expected: format!("value coercible to {}", target),
+            got: value.type_name(),
+        };
+
+        match target.as_str() {
+            "t" => Ok(value),
+            "list" | "vector" | "array" | "sequence" | "simple-vector" => match value {
+                Value::Array(_) => Ok(value),
+                Value::Null => Ok(Value::Array(Arc::new(Vec::new()))),
+                Value::String(ref s) => Ok(Value::Array(Arc::new(
+                    s.chars().map(|c| Value::String(c.to_string())).collect(),
+                ))),
+                Value::LazySeq(_) => {
+                    let mut items = Vec::new();
+                    let mut cursor = value;
+                    while let Some((first, rest)) = self.seq_step(&cursor)? {
+                        items.push(first);
+                        cursor = rest;
+                    }
+                    Ok(Value::Array(Arc::new(items)))
+                }
+                other => Err(impossible(&other)),
+            },
+            "string" | "simple-string" => match value {
+                Value::String(_) => Ok(value),
+                Value::Null => Ok(Value::String(String::new())),
+                Value::Array(ref items) => items
+                    .iter()
+                    .map(|item| match item {
+                        Value::String(c) if c.chars().count() == 1 => Ok(c.as_str()),
+                        other => Err(Error::TypeError {
+                            expected: "character (one-character string)".to_string(),
+                            got: other.type_name(),
+                        }),
+                    })
+                    .collect::<Result<String>>()
+                    .map(Value::String),
+                other => Err(impossible(&other)),
+            },
+            "float" | "single-float" | "double-float" | "real" => match value {
+                Value::Float(_) => Ok(value),
+                Value::Int(n) => Ok(Value::Float(n as f64)),
+                other => Err(impossible(&other)),
+            },
+            "integer" | "int" | "fixnum" => match value {
+                Value::Int(_) => Ok(value),
+                Value::Float(f) if f.fract() == 0.0 && f.abs() < i64::MAX as f64 => {
+                    Ok(Value::Int(f as i64))
+                }
+                other => Err(impossible(&other)),
+            },
+            other => Err(Error::InvalidArguments {
+                tool: "coerce".to_string(),
+                reason: format!("Unknown result type {}", other),
+            }),
+        }
+    }
+
+    /// (parse-number str [:default value]) - Parse the most specific number a string denotes
+    ///
+    /// "42" -> 42, "3.14" / "1e6" -> float, "#xff" / "#b101" / "#o17" -> int in that radix.
+    /// Ratios like "1/2" yield a float as there is no rational type. Unparseable
+    /// input is an error unless `:default` is given.
+    fn eval_parse_number(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "parse-number".to_string(),
+                reason: "Expected 1 argument: string to parse".to_string(),
+            });
+        }
+
+        let value = self.evaluate_expression(&args[0].value)?;
+        let text = value.as_string()?.to_string();
+
+        let mut default = None;
+        for (option, expr) in Self::keyword_options("parse-number", &args[1..])? {
+            match option.as_str() {
+                "default" => default = Some(self.evaluate_expression(expr)?),
+                other => {
+                    return Err(Error::InvalidArguments {
+                        tool: "parse-number".to_string(),
+                        reason: format!("Unknown keyword :{}", other),
+                    })
+                }
+            }
+        }
+
+        match (Self::parse_number_literal(text.trim()), default) {
+            (Some(number), _) => Ok(number),
+            (None, Some(default)) => Ok(default),
+            (None, None) => Err(Error::TypeError {
+                expected: "numeric string".to_string(),
+                got: format!("'{}'", text),
+            }),
+        }
+    }
+
+    /// Parse a numeric literal in any of the formats accepted by `parse-number`
+    fn parse_number_literal(text: &str) -> Option<Value> {
+        let radix = match text.get(..2).map(|p| p.to_ascii_lowercase()) {
+            Some(p) if p == "#x" => Some(16),
+            Some(p) if p == "#b" => Some(2),
+            Some(p) if p == "#o" => Some(8),
+            _ => None,
+        };
+        if let Some(radix) = radix {
+            return i64::from_str_radix(&text[2..], radix).ok().map(Value::Int);
+        }
+
+        if let Some((num, den)) = text.split_once('/') {
+            let num = num.parse::<i64>().ok()?;
+            let den = den.parse::<i64>().ok().filter(|d| *d != 0)?;
+            return Some(match num.checked_rem(den) {
+                Some(0) => Value::Int(num / den),
+                _ => Value::Float(num as f64 / den as f64),
+            });
+        }
+
+        if let Ok(n) = text.parse::<i64>() {
+            return Some(Value::Int(n));
+        }
+
+        // Reject "inf"/"nan" spellings that f64::from_str accepts
+        if !text.chars().any(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        text.parse::<f64>().ok().map(Value::Float)
+    }
+
     /// (bool value) - Convert to boolean (Python/JS style)
     /// Supports: bool("true") -> true, bool(0) -> false, bool("") -> false
     fn eval_to_bool(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
@@ -4963,59 +8467,55 @@ impl LispEvaluator {
         }
     }
 
-    /// (map collection lambda) - Map function over collection
+    /// (map collection... lambda) - Map function over one or more collections
+    ///
+    /// The function may also come first, as in Common Lisp: (map sqrt xs)
     fn eval_map(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 2 {
+        if args.len() < 2 {
             return Err(Error::InvalidArguments {
                 tool: "map".to_string(),
-                reason: "Expected 2 arguments: collection and lambda".to_string(),
+                reason: "Expected at least 2 arguments: collection(s) and lambda".to_string(),
             });
         }
 
-        // Evaluate collection
-        let collection = self.evaluate_expression(&args[0].value)?;
-        let array = collection.as_array()?;
-
-        // Get lambda function
-        let func = self.evaluate_expression(&args[1].value)?;
-
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "map".to_string(),
-                        reason: format!(
-                            "Lambda must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
-
-                let mut result = Vec::new();
-
-                // Apply lambda to each element
-                for elem in array.iter() {
-                    // Create new scope for lambda execution
-                    self.env.enter_scope();
-
-                    // Bind parameter
-                    self.env.define(params[0].clone(), elem.clone());
+        // A single range, on either side of the function, is mapped without building it
+        if args.len() == 2
+            && (Self::is_range_call(&args[0].value) || Self::is_range_call(&args[1].value))
+        {
+            let (items, func) = if Self::is_range_call(&args[0].value) {
+                let items = self.eval_sequence_items(&args[0].value)?;
+                (items, self.eval_function_designator(&args[1].value)?)
+            } else {
+                let func = self.eval_function_designator(&args[0].value)?;
+                (self.eval_sequence_items(&args[1].value)?, func)
+            };
+            Self::check_function_arity("map", &func, 1, "Lambda")?;
+            let mut results = Vec::new();
+            for item in items {
+                let mapped = self.apply_function_designator(&func, vec![item], "map")?;
+                self.charge_values(1)?;
+                results.push(mapped);
+            }
+            return Ok(Value::Array(Arc::new(results)));
+        }
 
-                    // Evaluate body
-                    let val = self.evaluate_expression(&body)?;
-                    result.push(val);
+        // Evaluate in order; the function is last unless the last argument is a collection
+        let first = self.eval_function_designator(&args[0].value)?;
+        let mut rest = Vec::with_capacity(args.len() - 1);
+        for arg in &args[1..args.len() - 1] {
+            rest.push(self.evaluate_expression(&arg.value)?);
+        }
+        let last = self.eval_function_designator(&args[args.len() - 1].value)?;
 
-                    // Exit scope
-                    self.env.exit_scope();
-                }
+        let (func, collections) = if matches!(last, Value::Array(_)) {
+            rest.push(last);
+            (first, rest)
+        } else {
+            rest.insert(0, first);
+            (last, rest)
+        };
 
-                Ok(Value::Array(Arc::new(result)))
-            }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
-        }
+        self.map_sequences("map", &func, &collections)
     }
 
     /// (pmap collection lambda) - Parallel map function over collection
@@ -5051,53 +8551,24 @@ impl LispEvaluator {
         }
 
         // Evaluate collection
-        let collection = self.evaluate_expression(&args[0].value)?;
-        let array = collection.as_array()?;
+        let items = self.eval_sequence_items(&args[0].value)?;
 
         // Get predicate function
-        let func = self.evaluate_expression(&args[1].value)?;
-
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "filter".to_string(),
-                        reason: format!(
-                            "Lambda must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
-
-                let mut result = Vec::new();
-
-                // Apply predicate to each element
-                for elem in array.iter() {
-                    // Create new scope for lambda execution
-                    self.env.enter_scope();
-
-                    // Bind parameter
-                    self.env.define(params[0].clone(), elem.clone());
-
-                    // Evaluate predicate
-                    let val = self.evaluate_expression(&body)?;
-
-                    // Exit scope
-                    self.env.exit_scope();
+        let func = self.eval_function_designator(&args[1].value)?;
+        Self::check_function_arity("filter", &func, 1, "Lambda")?;
 
-                    // Include element if predicate is truthy
-                    if val.is_truthy() {
-                        result.push(elem.clone());
-                    }
-                }
+        let mut result = Vec::new();
 
-                Ok(Value::Array(Arc::new(result)))
+        // Apply predicate to each element, keeping those it accepts
+        for elem in items {
+            let val = self.apply_function_designator(&func, vec![elem.clone()], "filter")?;
+            if val.is_truthy() {
+                self.charge_values(1)?;
+                result.push(elem);
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
         }
+
+        Ok(Value::Array(Arc::new(result)))
     }
 
     /// (reduce collection initial lambda) - Reduce collection to single value using accumulator lambda
@@ -5111,50 +8582,21 @@ impl LispEvaluator {
         }
 
         // Evaluate collection
-        let collection = self.evaluate_expression(&args[0].value)?;
-        let array = collection.as_array()?;
+        let items = self.eval_sequence_items(&args[0].value)?;
 
         // Evaluate initial accumulator value
         let mut accumulator = self.evaluate_expression(&args[1].value)?;
 
-        // Get reducer function
-        let func = self.evaluate_expression(&args[2].value)?;
-
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 2 {
-                    return Err(Error::InvalidArguments {
-                        tool: "reduce".to_string(),
-                        reason: format!(
-                            "Lambda must take exactly 2 parameters (accumulator, element), got {}",
-                            params.len()
-                        ),
-                    });
-                }
-
-                // Apply reducer to each element
-                for elem in array.iter() {
-                    // Create new scope for lambda execution
-                    self.env.enter_scope();
-
-                    // Bind parameters: accumulator and current element
-                    self.env.define(params[0].clone(), accumulator.clone());
-                    self.env.define(params[1].clone(), elem.clone());
-
-                    // Evaluate reducer body
-                    accumulator = self.evaluate_expression(&body)?;
-
-                    // Exit scope
-                    self.env.exit_scope();
-                }
+        // Get reducer function, called as (f accumulator element)
+        let func = self.eval_function_designator(&args[2].value)?;
+        Self::check_function_arity("reduce", &func, 2, "Lambda")?;
 
-                Ok(accumulator)
-            }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
+        for elem in items {
+            accumulator =
+                self.apply_function_designator(&func, vec![accumulator, elem], "reduce")?;
         }
+
+        Ok(accumulator)
     }
 
     /// (sort collection comparator) - Sort collection using comparator lambda
@@ -5171,54 +8613,29 @@ impl LispEvaluator {
         let array = collection.as_array()?;
 
         // Get comparator function
-        let func = self.evaluate_expression(&args[1].value)?;
-
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 2 {
-                    return Err(Error::InvalidArguments {
-                        tool: "sort".to_string(),
-                        reason: format!(
-                            "Lambda must take exactly 2 parameters, got {}",
-                            params.len()
-                        ),
-                    });
-                }
-
-                // Clone array for sorting
-                let mut sorted = array.to_vec();
+        let func = self.eval_function_designator(&args[1].value)?;
+        Self::check_function_arity("sort", &func, 2, "Lambda")?;
 
-                // Manual bubble sort to avoid closure borrowing issues
-                let n = sorted.len();
-                for i in 0..n {
-                    for j in 0..(n - i - 1) {
-                        // Create new scope
-                        self.env.enter_scope();
+        // Clone array for sorting
+        let mut sorted = array.to_vec();
 
-                        // Bind parameters (a=sorted[j], b=sorted[j+1])
-                        self.env.define(params[0].clone(), sorted[j].clone());
-                        self.env.define(params[1].clone(), sorted[j + 1].clone());
-
-                        // Evaluate comparator: if (comparator a b) is false, swap
-                        let result = self.evaluate_expression(&body)?;
-
-                        // Exit scope
-                        self.env.exit_scope();
-
-                        // If comparator returns false, swap
-                        if !result.is_truthy() {
-                            sorted.swap(j, j + 1);
-                        }
-                    }
+        // Manual bubble sort to avoid closure borrowing issues
+        let n = sorted.len();
+        for i in 0..n {
+            for j in 0..(n - i - 1) {
+                // If (comparator a b) is false, swap
+                let result = self.apply_function_designator(
+                    &func,
+                    vec![sorted[j].clone(), sorted[j + 1].clone()],
+                    "sort",
+                )?;
+                if !result.is_truthy() {
+                    sorted.swap(j, j + 1);
                 }
-
-                Ok(Value::Array(Arc::new(sorted)))
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
         }
+
+        Ok(Value::Array(Arc::new(sorted)))
     }
 
     /// (str args...) - Concatenate values into string
@@ -5227,34 +8644,141 @@ impl LispEvaluator {
 
         for arg in args {
             let val = self.evaluate_expression(&arg.value)?;
-            // Convert value to string
-            let s = match val {
-                Value::String(s) => s,
-                Value::Int(n) => n.to_string(),
-                Value::Float(f) => f.to_string(),
-                Value::Bool(b) => b.to_string(),
-                Value::Null => "null".to_string(),
-                _ => format!("{}", val),
-            };
-            result.push_str(&s);
+            result.push_str(&Self::aesthetic_string(&val));
         }
 
+        self.charge_allocation(result.len())?;
         Ok(Value::String(result))
     }
 
-    /// (format destination control-string &rest args)
-    /// Common Lisp-style string formatting
-    /// Destination: nil = return string, t = print and return nil
-    /// Control directives: ~A (any), ~D (decimal), ~% (newline), ~~ (tilde)
-    fn eval_format(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() < 2 {
+    /// (format-number x [{:precision n :thousands bool :separator "," :decimal "."}])
+    ///
+    /// Formats a number for reports, rounding half away from zero to
+    /// `precision` digits and optionally grouping thousands. The separator and
+    /// decimal point can be swapped for other locales.
+    fn eval_format_number(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() || args.len() > 2 {
             return Err(Error::InvalidArguments {
-                tool: "format".to_string(),
-                reason: "Expected at least 2 arguments: destination and control-string".to_string(),
+                tool: "format-number".to_string(),
+                reason: "Expected a number and an optional options object".to_string(),
             });
         }
 
-        // Evaluate destination (nil or t)
+        let value = self.evaluate_expression(&args[0].value)?;
+        let options = match args.get(1) {
+            Some(arg) => match self.evaluate_expression(&arg.value)? {
+                Value::Object(obj) => Some(obj),
+                Value::Null => None,
+                other => {
+                    return Err(Error::TypeError {
+                        expected: "options object".to_string(),
+                        got: other.type_name(),
+                    })
+                }
+            },
+            None => None,
+        };
+        let option = |key: &str| options.as_ref().and_then(|obj| obj.get(key).cloned());
+
+        let precision = match option("precision") {
+            Some(p) => match p.as_int()? {
+                p @ 0..=20 => Some(p as usize),
+                p => {
+                    return Err(Error::InvalidArguments {
+                        tool: "format-number".to_string(),
+                        reason: format!("Precision must be between 0 and 20, got {}", p),
+                    })
+                }
+            },
+            None => None,
+        };
+        let thousands = option("thousands").is_some_and(|v| v.is_truthy());
+        let separator = match option("separator") {
+            Some(v) => v.as_string()?.to_string(),
+            None => ",".to_string(),
+        };
+        let decimal = match option("decimal") {
+            Some(v) => v.as_string()?.to_string(),
+            None => ".".to_string(),
+        };
+
+        // Format the magnitude, then add the sign back
+        let (negative, digits) = match value {
+            Value::Int(n) => {
+                let digits = match precision {
+                    Some(p) if p > 0 => format!("{}.{}", n.unsigned_abs(), "0".repeat(p)),
+                    _ => n.unsigned_abs().to_string(),
+                };
+                (n < 0, digits)
+            }
+            Value::Float(f) if f.is_finite() => {
+                let digits = match precision {
+                    Some(p) => {
+                        let scale = 10f64.powi(p as i32);
+                        let rounded = (f.abs() * scale).round() / scale;
+                        let magnitude = if rounded.is_finite() {
+                            rounded
+                        } else {
+                            f.abs()
+                        };
+                        format!("{:.*}", p, magnitude)
+                    }
+                    None => f.abs().to_string(),
+                };
+                // No "-0.00" when a small negative rounds to zero
+                let nonzero = digits.chars().any(|c| matches!(c, '1'..='9'));
+                (f < 0.0 && nonzero, digits)
+            }
+            Value::Float(f) => return Ok(Value::String(f.to_string())),
+            other => {
+                return Err(Error::TypeError {
+                    expected: "number".to_string(),
+                    got: other.type_name(),
+                })
+            }
+        };
+
+        let (int_part, frac_part) = match digits.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (digits.as_str(), None),
+        };
+
+        let mut result = String::new();
+        if negative {
+            result.push('-');
+        }
+        if thousands {
+            for (i, digit) in int_part.chars().enumerate() {
+                if i > 0 && (int_part.len() - i) % 3 == 0 {
+                    result.push_str(&separator);
+                }
+                result.push(digit);
+            }
+        } else {
+            result.push_str(int_part);
+        }
+        if let Some(frac_part) = frac_part {
+            result.push_str(&decimal);
+            result.push_str(frac_part);
+        }
+
+        Ok(Value::String(result))
+    }
+
+    /// (format destination control-string &rest args)
+    /// Common Lisp-style string formatting
+    /// Destination: nil = return string, t = print and return nil
+    /// Control directives: ~A (any), ~D (decimal), ~P / ~:P (plural), ~R (English cardinal),
+    /// ~% (newline), ~~ (tilde)
+    fn eval_format(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() < 2 {
+            return Err(Error::InvalidArguments {
+                tool: "format".to_string(),
+                reason: "Expected at least 2 arguments: destination and control-string".to_string(),
+            });
+        }
+
+        // Evaluate destination (nil or t)
         let dest = self.evaluate_expression(&args[0].value)?;
 
         // Get control string
@@ -5267,6 +8791,22 @@ impl LispEvaluator {
             format_args.push(self.evaluate_expression(&arg.value)?);
         }
 
+        let result = self.format_directives(control_string, &format_args);
+
+        // Return based on destination
+        match dest {
+            Value::Null => Ok(Value::String(result)),
+            Value::Bool(true) => {
+                // Print and return nil
+                self.write_output(&format!("{}\n", result))?;
+                Ok(Value::Null)
+            }
+            _ => Ok(Value::String(result)),
+        }
+    }
+
+    /// Expand the `~` directives of a `format` control string with its arguments
+    fn format_directives(&self, control_string: &str, format_args: &[Value]) -> String {
         // Process control string
         let mut result = String::new();
         let mut chars = control_string.chars().peekable();
@@ -5274,6 +8814,12 @@ impl LispEvaluator {
 
         while let Some(ch) = chars.next() {
             if ch == '~' {
+                // Optional `:` modifier (e.g. ~:P reuses the previous argument)
+                let colon = chars.peek() == Some(&':');
+                if colon {
+                    chars.next();
+                }
+
                 // Process directive
                 if let Some(&next_ch) = chars.peek() {
                     chars.next(); // Consume directive character
@@ -5287,6 +8833,13 @@ impl LispEvaluator {
                                 arg_index += 1;
                             }
                         }
+                        'S' | 's' => {
+                            // ~S - Standard (readable) form, as prin1 prints it
+                            if arg_index < format_args.len() {
+                                result.push_str(&Self::readable_string(&format_args[arg_index]));
+                                arg_index += 1;
+                            }
+                        }
                         'D' | 'd' => {
                             // ~D - Decimal integer
                             if arg_index < format_args.len() {
@@ -5300,6 +8853,29 @@ impl LispEvaluator {
                                 arg_index += 1;
                             }
                         }
+                        'P' | 'p' => {
+                            // ~P - "s" unless the argument is 1; ~:P backs up to the previous one
+                            let plural_arg = if colon {
+                                arg_index.checked_sub(1).and_then(|i| format_args.get(i))
+                            } else {
+                                let arg = format_args.get(arg_index);
+                                arg_index += 1;
+                                arg
+                            };
+                            if !matches!(plural_arg, Some(Value::Int(1))) {
+                                result.push('s');
+                            }
+                        }
+                        'R' | 'r' => {
+                            // ~R - Cardinal English ("forty-two"); non-integers print as-is
+                            if arg_index < format_args.len() {
+                                match &format_args[arg_index] {
+                                    Value::Int(n) => result.push_str(&Self::english_cardinal(*n)),
+                                    other => result.push_str(&self.value_to_format_string(other)),
+                                }
+                                arg_index += 1;
+                            }
+                        }
                         '%' => {
                             // ~% - Newline
                             result.push('\n');
@@ -5311,6 +8887,9 @@ impl LispEvaluator {
                         _ => {
                             // Unknown directive, just include it
                             result.push('~');
+                            if colon {
+                                result.push(':');
+                            }
                             result.push(next_ch);
                         }
                     }
@@ -5322,15 +8901,94 @@ impl LispEvaluator {
             }
         }
 
-        // Return based on destination
-        match dest {
-            Value::Null => Ok(Value::String(result)),
-            Value::Bool(true) => {
-                // Print and return nil
-                println!("{}", result);
-                Ok(Value::Null)
+        result
+    }
+
+    /// Spell out an integer in English for ~R, e.g. -42 => "negative forty-two"
+    ///
+    /// Scale words run up to quintillion, which covers the whole i64 range.
+    fn english_cardinal(n: i64) -> String {
+        const ONES: [&str; 20] = [
+            "zero",
+            "one",
+            "two",
+            "three",
+            "four",
+            "five",
+            "six",
+            "seven",
+            "eight",
+            "nine",
+            "ten",
+            "eleven",
+            "twelve",
+            "thirteen",
+            "fourteen",
+            "fifteen",
+            "sixteen",
+            "seventeen",
+            "eighteen",
+            "nineteen",
+        ];
+        const TENS: [&str; 10] = [
+            "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+        ];
+        const SCALES: [&str; 7] = [
+            "",
+            "thousand",
+            "million",
+            "billion",
+            "trillion",
+            "quadrillion",
+            "quintillion",
+        ];
+
+        fn below_thousand(n: u64) -> String {
+            let mut words = Vec::new();
+            if n >= 100 {
+                words.push(format!("{} hundred", ONES[(n / 100) as usize]));
+            }
+            let rest = n % 100;
+            if rest >= 20 {
+                let tens = TENS[(rest / 10) as usize];
+                words.push(if rest.is_multiple_of(10) {
+                    tens.to_string()
+                } else {
+                    format!("{}-{}", tens, ONES[(rest % 10) as usize])
+                });
+            } else if rest > 0 {
+                words.push(ONES[rest as usize].to_string());
             }
-            _ => Ok(Value::String(result)),
+            words.join(" ")
+        }
+
+        if n == 0 {
+            return ONES[0].to_string();
+        }
+
+        let mut magnitude = n.unsigned_abs();
+        let mut groups = Vec::new();
+        let mut scale = 0;
+        while magnitude > 0 {
+            let group = magnitude % 1000;
+            if group > 0 {
+                let words = below_thousand(group);
+                groups.push(if scale == 0 {
+                    words
+                } else {
+                    format!("{} {}", words, SCALES[scale])
+                });
+            }
+            magnitude /= 1000;
+            scale += 1;
+        }
+        groups.reverse();
+
+        let spelled = groups.join(" ");
+        if n < 0 {
+            format!("negative {}", spelled)
+        } else {
+            spelled
         }
     }
 
@@ -5351,7 +9009,7 @@ impl LispEvaluator {
         }
     }
 
-    /// (slice array start end) - Extract subarray from start to end (exclusive)
+    /// (slice seq start end) - Extract subarray or substring from start to end (exclusive)
     fn eval_slice(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 3 {
             return Err(Error::InvalidArguments {
@@ -5361,29 +9019,47 @@ impl LispEvaluator {
         }
 
         let collection = self.evaluate_expression(&args[0].value)?;
-        let array = collection.as_array()?;
+        let len = match &collection {
+            Value::Array(arr) => arr.len(),
+            Value::String(s) => s.chars().count(),
+            _ => {
+                return Err(Error::TypeError {
+                    expected: "array or string".to_string(),
+                    got: collection.type_name(),
+                })
+            }
+        };
 
         let start_val = self.evaluate_expression(&args[1].value)?;
-        let start = start_val.as_int()? as usize;
+        let start = start_val.as_int()?;
 
         let end_val = self.evaluate_expression(&args[2].value)?;
-        let end = end_val.as_int()? as usize;
+        let end = end_val.as_int()?;
 
-        // Bounds checking
-        if start > array.len() || end > array.len() || start > end {
-            return Err(Error::InvalidArguments {
-                tool: "slice".to_string(),
-                reason: format!(
-                    "Invalid slice bounds: start={}, end={}, len={}",
-                    start,
-                    end,
-                    array.len()
-                ),
-            });
-        }
+        // Bounds checking; negative bounds count back from the end
+        let (start, end) = match Self::resolve_bound(start, len).zip(Self::resolve_bound(end, len))
+        {
+            Some((s, e)) if s <= e => (s, e),
+            _ => {
+                return Err(Error::InvalidArguments {
+                    tool: "slice".to_string(),
+                    reason: format!(
+                        "Invalid slice bounds: start={}, end={}, len={}",
+                        start, end, len
+                    ),
+                });
+            }
+        };
 
-        let sliced: Vec<Value> = array[start..end].to_vec();
-        Ok(Value::Array(Arc::new(sliced)))
+        match collection {
+            Value::String(s) => Ok(Value::String(
+                s.chars().skip(start).take(end - start).collect(),
+            )),
+            _ => {
+                let sliced: Vec<Value> = collection.as_array()?[start..end].to_vec();
+                Ok(Value::Array(Arc::new(sliced)))
+            }
+        }
     }
 
     /// keys(object) - Get array of object keys
@@ -5398,7 +9074,7 @@ impl LispEvaluator {
         let obj_val = self.evaluate_expression(&args[0].value)?;
         let obj = obj_val.as_object()?;
 
-        let keys: Vec<Value> = obj.keys().map(|k| Value::String(k.clone())).collect();
+        let keys: Vec<Value> = obj.keys().map(|k| Value::String(k.to_string())).collect();
 
         Ok(Value::Array(Arc::new(keys)))
     }
@@ -5434,7 +9110,7 @@ impl LispEvaluator {
 
         let entries: Vec<Value> = obj
             .iter()
-            .map(|(k, v)| Value::Array(Arc::new(vec![Value::String(k.clone()), v.clone()])))
+            .map(|(k, v)| Value::Array(Arc::new(vec![Value::String(k.to_string()), v.clone()])))
             .collect();
 
         Ok(Value::Array(Arc::new(entries)))
@@ -5466,6 +9142,135 @@ impl LispEvaluator {
         Ok(Value::Object(Arc::new(result)))
     }
 
+    /// (merge-with f obj1 obj2 ...) - Merge objects left-to-right, combining
+    /// the values of keys present in more than one object as (f old new)
+    /// Example: (merge-with + {:a 1} {:a 2 :b 3}) → {:a 3, :b 3}
+    fn eval_merge_with(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() < 2 {
+            return Err(Error::InvalidArguments {
+                tool: "merge-with".to_string(),
+                reason: "Expected a function and at least 1 object argument".to_string(),
+            });
+        }
+
+        let func = self.eval_function_designator(&args[0].value)?;
+        Self::check_function_arity("merge-with", &func, 2, "Lambda")?;
+
+        let mut result = std::collections::HashMap::new();
+
+        for arg in &args[1..] {
+            let obj_val = self.evaluate_expression(&arg.value)?;
+            let obj = obj_val.as_object()?;
+
+            for (key, value) in Self::sorted_entries(obj) {
+                let merged = match result.remove(key) {
+                    Some(existing) => self.apply_function_designator(
+                        &func,
+                        vec![existing, value.clone()],
+                        "merge-with",
+                    )?,
+                    None => value.clone(),
+                };
+                result.insert(key.clone(), merged);
+            }
+        }
+
+        Ok(Value::Object(Arc::new(result)))
+    }
+
+    /// (map-values f obj) - Object with f applied to each value
+    /// Example: (map-values (lambda (v) (* v 2)) {:a 1 :b 2}) → {:a 2, :b 4}
+    fn eval_map_values(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "map-values".to_string(),
+                reason: "Expected 2 arguments: function and object".to_string(),
+            });
+        }
+
+        let func = self.eval_function_designator(&args[0].value)?;
+        Self::check_function_arity("map-values", &func, 1, "Lambda")?;
+        let obj_val = self.evaluate_expression(&args[1].value)?;
+        let obj = obj_val.as_object()?;
+
+        let mut result = std::collections::HashMap::with_capacity(obj.len());
+        for (key, value) in Self::sorted_entries(obj) {
+            let mapped =
+                self.apply_function_designator(&func, vec![value.clone()], "map-values")?;
+            result.insert(key.clone(), mapped);
+        }
+
+        Ok(Value::Object(Arc::new(result)))
+    }
+
+    /// (filter-keys pred obj) - Object with only the keys whose name satisfies pred
+    /// Example: (filter-keys (lambda (k) (= (index-of k "a") 0)) {:ab 1 :ba 2}) → {:ab 1}
+    fn eval_filter_keys(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "filter-keys".to_string(),
+                reason: "Expected 2 arguments: predicate and object".to_string(),
+            });
+        }
+
+        let pred = self.eval_function_designator(&args[0].value)?;
+        Self::check_function_arity("filter-keys", &pred, 1, "Predicate")?;
+        let obj_val = self.evaluate_expression(&args[1].value)?;
+        let obj = obj_val.as_object()?;
+
+        let mut result = std::collections::HashMap::new();
+        for (key, value) in Self::sorted_entries(obj) {
+            let keep = self.apply_function_designator(
+                &pred,
+                vec![Value::String(key.to_string())],
+                "filter-keys",
+            )?;
+            if keep.is_truthy() {
+                result.insert(key.clone(), value.clone());
+            }
+        }
+
+        Ok(Value::Object(Arc::new(result)))
+    }
+
+    /// (reduce-kv f init obj) - Fold over key/value pairs as (f acc key value),
+    /// visiting keys in sorted order
+    /// Example: (reduce-kv (lambda (acc k v) (+ acc v)) 0 {:a 1 :b 2}) → 3
+    fn eval_reduce_kv(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 3 {
+            return Err(Error::InvalidArguments {
+                tool: "reduce-kv".to_string(),
+                reason: "Expected 3 arguments: function, initial value and object".to_string(),
+            });
+        }
+
+        let func = self.eval_function_designator(&args[0].value)?;
+        Self::check_function_arity("reduce-kv", &func, 3, "Lambda")?;
+        let mut accumulator = self.evaluate_expression(&args[1].value)?;
+        let obj_val = self.evaluate_expression(&args[2].value)?;
+        let obj = obj_val.as_object()?;
+
+        for (key, value) in Self::sorted_entries(obj) {
+            accumulator = self.apply_function_designator(
+                &func,
+                vec![accumulator, Value::String(key.to_string()), value.clone()],
+                "reduce-kv",
+            )?;
+        }
+
+        Ok(accumulator)
+    }
+
+    /// Object entries sorted by key, so functions applied to them run in a
+    /// stable order
+    fn sorted_entries(
+        obj: &std::collections::HashMap<Arc<str>, Value>,
+    ) -> Vec<(&Arc<str>, &Value)> {
+        let mut entries: Vec<_> = obj.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries
+    }
+
     /// put(obj, key, value) - Set object property with dynamic key
     /// Returns new object with property set (immutable operation)
     /// Example: (put {:a 1} "b" 2) → {:a 1, :b 2}
@@ -5500,7 +9305,7 @@ impl LispEvaluator {
 
         // Create new object with property set
         let mut result = obj.clone();
-        result.insert(key, value);
+        result.insert(key.into(), value);
 
         Ok(Value::Object(Arc::new(result)))
     }
@@ -5588,7 +9393,7 @@ impl LispEvaluator {
     /// Recursively search for a field with configuration options
     fn recursive_field_search_with_config(
         &self,
-        obj: &std::collections::HashMap<String, Value>,
+        obj: &std::collections::HashMap<Arc<str>, Value>,
         key: &str,
         current_depth: usize,
         max_depth: usize,
@@ -5611,7 +9416,7 @@ impl LispEvaluator {
     /// Depth-first search implementation
     fn depth_first_search(
         &self,
-        obj: &std::collections::HashMap<String, Value>,
+        obj: &std::collections::HashMap<Arc<str>, Value>,
         key: &str,
         current_depth: usize,
         max_depth: usize,
@@ -5640,7 +9445,7 @@ impl LispEvaluator {
     /// Breadth-first search implementation
     fn breadth_first_search(
         &self,
-        obj: &std::collections::HashMap<String, Value>,
+        obj: &std::collections::HashMap<Arc<str>, Value>,
         key: &str,
         current_depth: usize,
         max_depth: usize,
@@ -5648,7 +9453,7 @@ impl LispEvaluator {
         use std::collections::VecDeque;
 
         // Queue of (object, depth) to search
-        let mut queue: VecDeque<(&std::collections::HashMap<String, Value>, usize)> =
+        let mut queue: VecDeque<(&std::collections::HashMap<Arc<str>, Value>, usize)> =
             VecDeque::new();
         queue.push_back((obj, current_depth));
 
@@ -5704,17 +9509,17 @@ impl LispEvaluator {
         // Try direct access first
         if let Some(value) = obj.get(key) {
             let mut result = std::collections::HashMap::new();
-            result.insert("value".to_string(), value.clone());
-            result.insert("path".to_string(), Value::Array(Arc::new(vec![])));
+            result.insert("value".into(), value.clone());
+            result.insert("path".into(), Value::Array(Arc::new(vec![])));
             return Ok(Value::Object(Arc::new(result)));
         }
 
         // If not found, search with path tracking
         if let Some((value, path)) = self.recursive_field_search_with_path(obj, key, &[]) {
             let mut result = std::collections::HashMap::new();
-            result.insert("value".to_string(), value);
+            result.insert("value".into(), value);
             result.insert(
-                "path".to_string(),
+                "path".into(),
                 Value::Array(Arc::new(
                     path.iter().map(|s| Value::String(s.to_string())).collect(),
                 )),
@@ -5724,15 +9529,15 @@ impl LispEvaluator {
 
         // Return null value with empty path
         let mut result = std::collections::HashMap::new();
-        result.insert("value".to_string(), Value::Null);
-        result.insert("path".to_string(), Value::Array(Arc::new(vec![])));
+        result.insert("value".into(), Value::Null);
+        result.insert("path".into(), Value::Array(Arc::new(vec![])));
         Ok(Value::Object(Arc::new(result)))
     }
 
     /// Helper for get-path: recursive search that tracks the path
     fn recursive_field_search_with_path(
         &self,
-        obj: &std::collections::HashMap<String, Value>,
+        obj: &std::collections::HashMap<Arc<str>, Value>,
         key: &str,
         current_path: &[String],
     ) -> Option<(Value, Vec<String>)> {
@@ -5742,12 +9547,12 @@ impl LispEvaluator {
                     // Check if this nested object has the key
                     if let Some(value) = nested_obj.get(key) {
                         let mut path = current_path.to_vec();
-                        path.push(field_name.clone());
+                        path.push(field_name.to_string());
                         return Some((value.clone(), path));
                     }
                     // Recursively search deeper
                     let mut new_path = current_path.to_vec();
-                    new_path.push(field_name.clone());
+                    new_path.push(field_name.to_string());
                     if let Some(result) =
                         self.recursive_field_search_with_path(nested_obj, key, &new_path)
                     {
@@ -5795,9 +9600,9 @@ impl LispEvaluator {
                 .into_iter()
                 .map(|(field, path)| {
                     let mut obj = std::collections::HashMap::new();
-                    obj.insert("field".to_string(), Value::String(field));
+                    obj.insert("field".into(), Value::String(field));
                     obj.insert(
-                        "path".to_string(),
+                        "path".into(),
                         Value::Array(Arc::new(
                             path.iter().map(|s| Value::String(s.to_string())).collect(),
                         )),
@@ -5819,19 +9624,19 @@ impl LispEvaluator {
     /// Helper for discover: recursively collect all field names
     fn discover_fields(
         &self,
-        obj: &std::collections::HashMap<String, Value>,
+        obj: &std::collections::HashMap<Arc<str>, Value>,
         current_path: &[String],
         fields: &mut Vec<(String, Vec<String>)>,
         _with_paths: bool,
     ) {
         for (field_name, field_value) in obj.iter() {
             // Add this field
-            fields.push((field_name.clone(), current_path.to_vec()));
+            fields.push((field_name.to_string(), current_path.to_vec()));
 
             // Recursively discover nested fields
             if let Value::Object(nested_obj) = field_value {
                 let mut new_path = current_path.to_vec();
-                new_path.push(field_name.clone());
+                new_path.push(field_name.to_string());
                 self.discover_fields(nested_obj, &new_path, fields, _with_paths);
             }
         }
@@ -5891,6 +9696,7 @@ impl LispEvaluator {
 
     /// parse-json - Parse a JSON string into OVSM values
     /// Usage: (parse-json {:json "{"a": 1, "b": [2,3]}"})
+    /// Integer literals become Int, other numbers Float, and null becomes Null
     fn eval_parse_json(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 1 {
             return Err(Error::InvalidArguments {
@@ -5935,37 +9741,44 @@ impl LispEvaluator {
     }
 
     /// json-stringify - Convert OVSM value to JSON string
-    /// Usage: (json-stringify {:value data :pretty true})
+    /// Usage: (json-stringify data :pretty true) or (json-stringify {:value data :pretty true})
+    /// Object keys are always emitted in sorted order so output is deterministic
     fn eval_json_stringify(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 1 {
+        if args.is_empty() {
             return Err(Error::InvalidArguments {
                 tool: "json-stringify".to_string(),
                 reason: "Expected 1 argument: {:value data} or direct value".to_string(),
             });
         }
 
-        let (value, pretty) = match self.evaluate_expression(&args[0].value)? {
-            Value::Object(obj) => {
-                // Object form: (json-stringify {:value ... :pretty true})
-                let val = obj
-                    .get("value")
-                    .ok_or_else(|| Error::InvalidArguments {
-                        tool: "json-stringify".to_string(),
-                        reason: "Object must have 'value' field".to_string(),
-                    })?
-                    .clone();
+        let (value, mut pretty) = match self.evaluate_expression(&args[0].value)? {
+            // Wrapper form: (json-stringify {:value ... :pretty true})
+            Value::Object(obj)
+                if obj.contains_key("value")
+                    && obj.keys().all(|k| &**k == "value" || &**k == "pretty") =>
+            {
                 let pretty = obj
                     .get("pretty")
                     .and_then(|v| v.as_bool().ok())
                     .unwrap_or(false);
-                (val, pretty)
-            }
-            v => {
-                // Direct form: (json-stringify data)
-                (v, false)
+                (obj["value"].clone(), pretty)
             }
+            // Direct form: (json-stringify data)
+            v => (v, false),
         };
 
+        for (option, expr) in Self::keyword_options("json-stringify", &args[1..])? {
+            match option.as_str() {
+                "pretty" => pretty = self.evaluate_expression(expr)?.is_truthy(),
+                other => {
+                    return Err(Error::InvalidArguments {
+                        tool: "json-stringify".to_string(),
+                        reason: format!("Unknown keyword :{}", other),
+                    })
+                }
+            }
+        }
+
         // Convert OVSM Value to serde_json::Value
         let json_value = self.value_to_json(value)?;
 
@@ -5983,8 +9796,26 @@ impl LispEvaluator {
         Ok(Value::String(json_str))
     }
 
+    /// (intern-stats) - Report the key pool shared by object literals and parse-json
+    /// Returns {:unique n :hits n :misses n}
+    fn eval_intern_stats(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if !args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "intern-stats".to_string(),
+                reason: "Expected no arguments".to_string(),
+            });
+        }
+
+        let stats = self.interner.stats();
+        let mut obj = HashMap::new();
+        obj.insert("unique".into(), Value::Int(stats.unique as i64));
+        obj.insert("hits".into(), Value::Int(stats.hits as i64));
+        obj.insert("misses".into(), Value::Int(stats.misses as i64));
+        Ok(Value::Object(Arc::new(obj)))
+    }
+
     /// Helper: Convert serde_json::Value to OVSM Value
-    fn json_to_value(&self, json: serde_json::Value) -> Value {
+    fn json_to_value(&mut self, json: serde_json::Value) -> Value {
         use serde_json::Value as JV;
         match json {
             JV::Null => Value::Null,
@@ -6005,7 +9836,8 @@ impl LispEvaluator {
             JV::Object(map) => {
                 let mut obj = HashMap::new();
                 for (k, v) in map {
-                    obj.insert(k, self.json_to_value(v));
+                    let key = self.interner.intern(&k);
+                    obj.insert(key, self.json_to_value(v));
                 }
                 Value::Object(Arc::new(obj))
             }
@@ -6022,6 +9854,8 @@ impl LispEvaluator {
             Value::Float(f) => serde_json::Number::from_f64(f)
                 .map(JV::Number)
                 .unwrap_or(JV::Null),
+            Value::Complex { re, im } => serde_json::json!({ "re": re, "im": im }),
+            Value::Instant(millis) => JV::String(crate::runtime::value::format_instant(millis)),
             Value::String(s) => JV::String(s.to_string()),
             Value::Array(arr) => {
                 let mut json_arr = Vec::new();
@@ -6031,13 +9865,16 @@ impl LispEvaluator {
                 JV::Array(json_arr)
             }
             Value::Object(obj) => {
+                // Insert in sorted order so output is stable whatever the map backing
+                let mut entries: Vec<_> = obj.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
                 let mut json_obj = serde_json::Map::new();
-                for (k, v) in obj.iter() {
-                    json_obj.insert(k.clone(), self.value_to_json(v.clone())?);
+                for (k, v) in entries {
+                    json_obj.insert(k.to_string(), self.value_to_json(v.clone())?);
                 }
                 JV::Object(json_obj)
             }
-            Value::Function { .. } => {
+            Value::Function { .. } | Value::Memoized { .. } => {
                 return Err(Error::InvalidOperation {
                     op: "json-conversion".to_string(),
                     left_type: "function".to_string(),
@@ -6065,6 +9902,13 @@ impl LispEvaluator {
                     right_type: "json".to_string(),
                 })
             }
+            Value::LazySeq(_) => {
+                return Err(Error::InvalidOperation {
+                    op: "json-conversion".to_string(),
+                    left_type: "lazy-seq".to_string(),
+                    right_type: "json".to_string(),
+                })
+            }
             Value::AsyncHandle { id, .. } => {
                 // Serialize async handle as object with id field
                 let mut json_obj = serde_json::Map::new();
@@ -6077,7 +9921,10 @@ impl LispEvaluator {
             | Value::RecursiveLock { .. }
             | Value::ConditionVariable { .. }
             | Value::Semaphore { .. }
-            | Value::AtomicInteger { .. } => {
+            | Value::AtomicInteger { .. }
+            | Value::Channel { .. }
+            | Value::Promise { .. }
+            | Value::AtomicRef { .. } => {
                 return Err(Error::InvalidOperation {
                     op: "json-conversion".to_string(),
                     left_type: "concurrency-primitive".to_string(),
@@ -6088,50 +9935,210 @@ impl LispEvaluator {
     }
 
     // ========================================
-    // Network Operations
+    // CBOR Operations (Built-in Functions)
     // ========================================
 
-    /// (http-get url [headers]) - Make HTTP GET request
-    fn eval_http_get(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        use crate::tools::stdlib::network;
-
-        // Evaluate arguments
-        let mut eval_args = Vec::new();
-        for arg in args {
-            eval_args.push(self.evaluate_expression(&arg.value)?);
+    /// (cbor-encode value) - Encode a value as CBOR, returned as a hex string
+    /// Object keys are written in sorted order so encodings are deterministic;
+    /// `{:cbor-bytes "hex"}` is written as a byte string
+    fn eval_cbor_encode(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "cbor-encode".to_string(),
+                reason: format!("Expected 1 argument, got {}", args.len()),
+            });
         }
 
-        // Call async function using block_in_place to avoid nested runtime error
-        tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(network::http_get(&eval_args))
-        })
-    }
+        let value = self.evaluate_expression(&args[0].value)?;
+        let cbor = Self::value_to_cbor(&value)?;
 
-    /// (http-post url body [headers]) - Make HTTP POST request
-    fn eval_http_post(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        use crate::tools::stdlib::network;
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&cbor, &mut bytes).map_err(|e| Error::ToolExecutionError {
+            tool: "cbor-encode".to_string(),
+            reason: format!("Failed to encode CBOR: {}", e),
+        })?;
+        Ok(Value::String(hex::encode(bytes)))
+    }
 
-        let mut eval_args = Vec::new();
-        for arg in args {
-            eval_args.push(self.evaluate_expression(&arg.value)?);
+    /// (cbor-decode hex-string) - Decode CBOR bytes (as hex) into a value
+    /// CBOR byte strings decode to `{:cbor-bytes "hex"}`, which `cbor-encode`
+    /// writes back as a byte string
+    fn eval_cbor_decode(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "cbor-decode".to_string(),
+                reason: format!("Expected 1 argument, got {}", args.len()),
+            });
         }
 
-        tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(network::http_post(&eval_args))
-        })
+        let val = self.evaluate_expression(&args[0].value)?;
+        let bytes = hex::decode(val.as_string()?.trim_start_matches("0x"))
+            .map_err(|e| Error::ParseError(format!("Invalid hex: {}", e)))?;
+
+        let cbor: ciborium::Value =
+            ciborium::from_reader(bytes.as_slice()).map_err(|e| Error::ToolExecutionError {
+                tool: "cbor-decode".to_string(),
+                reason: format!("Failed to decode CBOR: {}", e),
+            })?;
+        Self::cbor_to_value(cbor)
     }
 
-    /// (json-rpc url method [params]) - Make JSON-RPC call
-    fn eval_json_rpc(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        use crate::tools::stdlib::network;
+    /// Helper: Convert OVSM Value to ciborium::Value
+    fn value_to_cbor(value: &Value) -> Result<ciborium::Value> {
+        use ciborium::Value as CV;
+        Ok(match value {
+            Value::Null => CV::Null,
+            Value::Bool(b) => CV::Bool(*b),
+            Value::Int(i) => CV::Integer((*i).into()),
+            Value::Float(f) => CV::Float(*f),
+            Value::String(s) => CV::Text(s.clone()),
+            Value::Array(arr) => CV::Array(
+                arr.iter()
+                    .map(Self::value_to_cbor)
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            Value::Object(obj) if obj.len() == 1 && obj.contains_key(CBOR_BYTES_KEY) => {
+                let hex_str = obj[CBOR_BYTES_KEY].as_string()?;
+                CV::Bytes(hex::decode(hex_str.trim_start_matches("0x")).map_err(|e| {
+                    Error::ParseError(format!("Invalid hex in :{}: {}", CBOR_BYTES_KEY, e))
+                })?)
+            }
+            Value::Object(obj) => {
+                let mut entries: Vec<_> = obj.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                CV::Map(
+                    entries
+                        .into_iter()
+                        .map(|(k, v)| Ok((CV::Text(k.to_string()), Self::value_to_cbor(v)?)))
+                        .collect::<Result<Vec<_>>>()?,
+                )
+            }
+            other => {
+                return Err(Error::InvalidOperation {
+                    op: "cbor-conversion".to_string(),
+                    left_type: other.type_name(),
+                    right_type: "cbor".to_string(),
+                })
+            }
+        })
+    }
+
+    /// Helper: Convert ciborium::Value to OVSM Value
+    fn cbor_to_value(cbor: ciborium::Value) -> Result<Value> {
+        use ciborium::Value as CV;
+        Ok(match cbor {
+            CV::Null => Value::Null,
+            CV::Bool(b) => Value::Bool(b),
+            CV::Integer(n) => {
+                let n = i128::from(n);
+                i64::try_from(n)
+                    .map(Value::Int)
+                    .unwrap_or(Value::Float(n as f64))
+            }
+            CV::Float(f) => Value::Float(f),
+            CV::Text(s) => Value::String(s),
+            CV::Bytes(bytes) => {
+                let mut obj = HashMap::new();
+                obj.insert(CBOR_BYTES_KEY.into(), Value::String(hex::encode(bytes)));
+                Value::Object(Arc::new(obj))
+            }
+            CV::Tag(_, inner) => Self::cbor_to_value(*inner)?,
+            CV::Array(items) => Value::Array(Arc::new(
+                items
+                    .into_iter()
+                    .map(Self::cbor_to_value)
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+            CV::Map(entries) => {
+                let mut obj = HashMap::new();
+                for (k, v) in entries {
+                    let key = match Self::cbor_to_value(k)? {
+                        Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    obj.insert(key.into(), Self::cbor_to_value(v)?);
+                }
+                Value::Object(Arc::new(obj))
+            }
+            other => {
+                return Err(Error::ToolExecutionError {
+                    tool: "cbor-decode".to_string(),
+                    reason: format!("Unsupported CBOR item: {:?}", other),
+                })
+            }
+        })
+    }
+
+    // ========================================
+    // Network Operations
+    // ========================================
+
+    /// (http-get url [options]) - Make HTTP GET request
+    ///
+    /// Options: {:timeout-ms 5000 :retries 3 :backoff-ms 100 :headers {...}}
+    /// Returns {:status :body :headers}. 5xx responses and timeouts are retried
+    /// with exponential backoff; 4xx responses are returned without retrying.
+    fn eval_http_get(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::tools::stdlib::network;
+
+        // Evaluate arguments
+        let mut eval_args = Vec::new();
+        for arg in args {
+            eval_args.push(self.evaluate_expression(&arg.value)?);
+        }
+
+        // Call async function using block_in_place to avoid nested runtime error
+        let client = self.http_client.clone();
+        tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(network::http_get(&client, &eval_args))
+        })
+    }
+
+    /// (http-post url body [options]) - Make HTTP POST request
+    fn eval_http_post(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::tools::stdlib::network;
+
+        let mut eval_args = Vec::new();
+        for arg in args {
+            eval_args.push(self.evaluate_expression(&arg.value)?);
+        }
+
+        let client = self.http_client.clone();
+        tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(network::http_post(&client, &eval_args))
+        })
+    }
+
+    /// (json-rpc url method [params] [options]) - Make JSON-RPC call
+    fn eval_json_rpc(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::tools::stdlib::network;
+
+        let mut eval_args = Vec::new();
+        for arg in args {
+            eval_args.push(self.evaluate_expression(&arg.value)?);
+        }
+
+        let client = self.http_client.clone();
+        tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(network::json_rpc(&client, &eval_args))
+        })
+    }
+
+    /// (json-rpc-batch url requests [options]) - Send several JSON-RPC calls in one request
+    ///
+    /// Requests: [{:method "getBalance" :params [...]} ...]
+    /// Returns results in request order; failed elements become {:error {...}}
+    fn eval_json_rpc_batch(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::tools::stdlib::network;
 
         let mut eval_args = Vec::new();
         for arg in args {
             eval_args.push(self.evaluate_expression(&arg.value)?);
         }
 
+        let client = self.http_client.clone();
         tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(network::json_rpc(&eval_args))
+            tokio::runtime::Handle::current().block_on(network::json_rpc_batch(&client, &eval_args))
         })
     }
 
@@ -6170,48 +10177,19 @@ impl LispEvaluator {
         let array = collection.as_array()?;
 
         // Get predicate function
-        let func = self.evaluate_expression(&args[1].value)?;
-
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "find".to_string(),
-                        reason: format!(
-                            "Predicate must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
+        let func = self.eval_function_designator(&args[1].value)?;
+        Self::check_function_arity("find", &func, 1, "Predicate")?;
 
-                // Apply predicate to each element
-                for elem in array.iter() {
-                    // Create new scope for lambda execution
-                    self.env.enter_scope();
-
-                    // Bind parameter
-                    self.env.define(params[0].clone(), elem.clone());
-
-                    // Evaluate predicate
-                    let val = self.evaluate_expression(&body)?;
-
-                    // Exit scope
-                    self.env.exit_scope();
-
-                    // Return first matching element
-                    if val.is_truthy() {
-                        return Ok(elem.clone());
-                    }
-                }
-
-                // No match found
-                Ok(Value::Null)
+        // Return first matching element
+        for elem in array.iter() {
+            let val = self.apply_function_designator(&func, vec![elem.clone()], "find")?;
+            if val.is_truthy() {
+                return Ok(elem.clone());
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
         }
+
+        // No match found
+        Ok(Value::Null)
     }
 
     /// (distinct collection) - Remove duplicate elements
@@ -6316,10 +10294,12 @@ impl LispEvaluator {
 
         match value {
             Value::String(ref s) => {
+                self.charge_allocation(s.len().saturating_mul(count))?;
                 let repeated = s.repeat(count);
                 Ok(Value::String(repeated))
             }
             Value::Array(ref arr) => {
+                self.charge_values(arr.len().saturating_mul(count))?;
                 let mut result = Vec::with_capacity(arr.len() * count);
                 for _ in 0..count {
                     result.extend_from_slice(arr);
@@ -6347,48 +10327,19 @@ impl LispEvaluator {
         let array = collection.as_array()?;
 
         // Get predicate function
-        let func = self.evaluate_expression(&args[1].value)?;
-
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "some".to_string(),
-                        reason: format!(
-                            "Predicate must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
-
-                // Apply predicate to each element
-                for elem in array.iter() {
-                    // Create new scope for lambda execution
-                    self.env.enter_scope();
-
-                    // Bind parameter
-                    self.env.define(params[0].clone(), elem.clone());
-
-                    // Evaluate predicate
-                    let val = self.evaluate_expression(&body)?;
-
-                    // Exit scope
-                    self.env.exit_scope();
-
-                    // Return true if any match
-                    if val.is_truthy() {
-                        return Ok(Value::Bool(true));
-                    }
-                }
+        let func = self.eval_function_designator(&args[1].value)?;
+        Self::check_function_arity("some", &func, 1, "Predicate")?;
 
-                // No match found
-                Ok(Value::Bool(false))
+        // Return true if any match
+        for elem in array.iter() {
+            let val = self.apply_function_designator(&func, vec![elem.clone()], "some")?;
+            if val.is_truthy() {
+                return Ok(Value::Bool(true));
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
         }
+
+        // No match found
+        Ok(Value::Bool(false))
     }
 
     /// (every collection predicate) - Check if all elements match
@@ -6405,114 +10356,208 @@ impl LispEvaluator {
         let array = collection.as_array()?;
 
         // Get predicate function
-        let func = self.evaluate_expression(&args[1].value)?;
+        let func = self.eval_function_designator(&args[1].value)?;
+        Self::check_function_arity("every", &func, 1, "Predicate")?;
 
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "every".to_string(),
-                        reason: format!(
-                            "Predicate must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
+        // Return false if any don't match
+        for elem in array.iter() {
+            let val = self.apply_function_designator(&func, vec![elem.clone()], "every")?;
+            if !val.is_truthy() {
+                return Ok(Value::Bool(false));
+            }
+        }
 
-                // Apply predicate to each element
-                for elem in array.iter() {
-                    // Create new scope for lambda execution
-                    self.env.enter_scope();
+        // All matched
+        Ok(Value::Bool(true))
+    }
 
-                    // Bind parameter
-                    self.env.define(params[0].clone(), elem.clone());
+    /// (partition collection predicate) - Split into matching and not-matching
+    /// (partition n [step] collection) - Windows of exactly n elements, starting
+    /// every step elements (default n); a short tail is dropped
+    fn eval_partition(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 && args.len() != 3 {
+            return Err(Error::InvalidArguments {
+                tool: "partition".to_string(),
+                reason: "Expected 2 arguments: collection and predicate, or n [step] collection"
+                    .to_string(),
+            });
+        }
 
-                    // Evaluate predicate
-                    let val = self.evaluate_expression(&body)?;
+        let first = self.evaluate_expression(&args[0].value)?;
+        if let Value::Int(_) = first {
+            return self.eval_windows("partition", first, &args[1..], false);
+        }
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "partition".to_string(),
+                reason: "Expected 2 arguments: collection and predicate".to_string(),
+            });
+        }
+        let array = first.as_array()?;
 
-                    // Exit scope
-                    self.env.exit_scope();
+        // Get predicate function
+        let func = self.eval_function_designator(&args[1].value)?;
+        Self::check_function_arity("partition", &func, 1, "Predicate")?;
 
-                    // Return false if any don't match
-                    if !val.is_truthy() {
-                        return Ok(Value::Bool(false));
-                    }
-                }
+        let mut matching = Vec::new();
+        let mut not_matching = Vec::new();
 
-                // All matched
-                Ok(Value::Bool(true))
+        // Partition based on predicate result
+        for elem in array.iter() {
+            let val = self.apply_function_designator(&func, vec![elem.clone()], "partition")?;
+            if val.is_truthy() {
+                matching.push(elem.clone());
+            } else {
+                not_matching.push(elem.clone());
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
         }
+
+        // Return [matching-array, not-matching-array]
+        Ok(Value::Array(Arc::new(vec![
+            Value::Array(Arc::new(matching)),
+            Value::Array(Arc::new(not_matching)),
+        ])))
+    }
+
+    /// (partition-all n [step] collection) - Like windowed `partition`, but
+    /// keeps the short windows at the end
+    /// Example: (partition-all 2 [1 2 3]) → [[1 2] [3]]
+    fn eval_partition_all(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 && args.len() != 3 {
+            return Err(Error::InvalidArguments {
+                tool: "partition-all".to_string(),
+                reason: "Expected 2-3 arguments: n, optional step, and collection".to_string(),
+            });
+        }
+
+        let size = self.evaluate_expression(&args[0].value)?;
+        self.eval_windows("partition-all", size, &args[1..], true)
     }
 
-    /// (partition collection predicate) - Split into matching and not-matching
-    fn eval_partition(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+    /// (sliding-window n collection) - Every run of n consecutive elements
+    /// Example: (sliding-window 2 [1 2 3]) → [[1 2] [2 3]]
+    fn eval_sliding_window(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 2 {
             return Err(Error::InvalidArguments {
-                tool: "partition".to_string(),
-                reason: "Expected 2 arguments: collection and predicate".to_string(),
+                tool: "sliding-window".to_string(),
+                reason: "Expected 2 arguments: n and collection".to_string(),
             });
         }
 
-        // Evaluate collection
-        let collection = self.evaluate_expression(&args[0].value)?;
+        let size =
+            Self::window_length("sliding-window", &self.evaluate_expression(&args[0].value)?)?;
+        let collection = self.evaluate_expression(&args[1].value)?;
         let array = collection.as_array()?;
 
-        // Get predicate function
-        let func = self.evaluate_expression(&args[1].value)?;
+        Ok(Self::windows(array, size, 1, false))
+    }
 
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "partition".to_string(),
-                        reason: format!(
-                            "Predicate must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
+    /// Shared tail of `partition` and `partition-all`: `rest` is [step] collection
+    fn eval_windows(
+        &mut self,
+        tool: &str,
+        size: Value,
+        rest: &[crate::parser::Argument],
+        keep_partial: bool,
+    ) -> Result<Value> {
+        let size = Self::window_length(tool, &size)?;
+        let step = if rest.len() == 2 {
+            Self::window_length(tool, &self.evaluate_expression(&rest[0].value)?)?
+        } else {
+            size
+        };
+        let collection = self.evaluate_expression(&rest[rest.len() - 1].value)?;
+        let array = collection.as_array()?;
 
-                let mut matching = Vec::new();
-                let mut not_matching = Vec::new();
+        Ok(Self::windows(array, size, step, keep_partial))
+    }
 
-                // Apply predicate to each element
-                for elem in array.iter() {
-                    // Create new scope for lambda execution
-                    self.env.enter_scope();
+    /// Window size or step, which must be a positive integer
+    fn window_length(tool: &str, value: &Value) -> Result<usize> {
+        match value {
+            Value::Int(n) if *n > 0 => Ok(*n as usize),
+            Value::Int(n) => Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!("Window size and step must be positive, got {}", n),
+            }),
+            other => Err(Error::TypeError {
+                expected: "integer".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
 
-                    // Bind parameter
-                    self.env.define(params[0].clone(), elem.clone());
+    /// Windows of `size` elements starting every `step` elements. Windows
+    /// running past the end are truncated if `keep_partial`, dropped otherwise.
+    fn windows(array: &[Value], size: usize, step: usize, keep_partial: bool) -> Value {
+        let mut result = Vec::new();
+        let mut start = 0;
+        while start < array.len() {
+            let end = start.saturating_add(size);
+            if end > array.len() && !keep_partial {
+                break;
+            }
+            let window = array[start..end.min(array.len())].to_vec();
+            result.push(Value::Array(Arc::new(window)));
+            start = start.saturating_add(step);
+        }
+        Value::Array(Arc::new(result))
+    }
 
-                    // Evaluate predicate
-                    let val = self.evaluate_expression(&body)?;
+    /// (interpose sep collection) - Insert sep between consecutive elements
+    /// Example: (interpose 0 [1 2 3]) → [1 0 2 0 3]
+    fn eval_interpose(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "interpose".to_string(),
+                reason: "Expected 2 arguments: separator and collection".to_string(),
+            });
+        }
 
-                    // Exit scope
-                    self.env.exit_scope();
+        let sep = self.evaluate_expression(&args[0].value)?;
+        let collection = self.evaluate_expression(&args[1].value)?;
+        let array = collection.as_array()?;
 
-                    // Partition based on predicate result
-                    if val.is_truthy() {
-                        matching.push(elem.clone());
-                    } else {
-                        not_matching.push(elem.clone());
-                    }
-                }
+        let mut result = Vec::with_capacity((array.len() * 2).saturating_sub(1));
+        for (i, elem) in array.iter().enumerate() {
+            if i > 0 {
+                result.push(sep.clone());
+            }
+            result.push(elem.clone());
+        }
 
-                // Return [matching-array, not-matching-array]
-                Ok(Value::Array(Arc::new(vec![
-                    Value::Array(Arc::new(matching)),
-                    Value::Array(Arc::new(not_matching)),
-                ])))
+        Ok(Value::Array(Arc::new(result)))
+    }
+
+    /// (interleave c1 c2 ...) - First elements of each collection, then the
+    /// second elements, and so on, stopping at the shortest collection
+    /// Example: (interleave [1 2 3] [:a :b]) → [1 :a 2 :b]
+    fn eval_interleave(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "interleave".to_string(),
+                reason: "Expected at least 1 collection argument".to_string(),
+            });
+        }
+
+        let mut collections = Vec::with_capacity(args.len());
+        for arg in args {
+            collections.push(self.evaluate_expression(&arg.value)?);
+        }
+        let arrays = collections
+            .iter()
+            .map(|c| c.as_array())
+            .collect::<Result<Vec<_>>>()?;
+
+        let shortest = arrays.iter().map(|a| a.len()).min().unwrap_or(0);
+        let mut result = Vec::with_capacity(shortest * arrays.len());
+        for i in 0..shortest {
+            for array in &arrays {
+                result.push(array[i].clone());
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
         }
+
+        Ok(Value::Array(Arc::new(result)))
     }
 
     /// (take collection n) - Take first N elements
@@ -6545,6 +10590,23 @@ impl LispEvaluator {
         };
 
         let collection = self.evaluate_expression(&args[1].value)?;
+
+        // Lazy sequences are forced exactly n cells deep
+        if let Value::LazySeq(_) = collection {
+            let mut result = Vec::with_capacity(n);
+            let mut seq = collection;
+            while result.len() < n {
+                match self.seq_step(&seq)? {
+                    Some((first, rest)) => {
+                        result.push(first);
+                        seq = rest;
+                    }
+                    None => break,
+                }
+            }
+            return Ok(Value::Array(Arc::new(result)));
+        }
+
         let array = collection.as_array()?;
 
         let result: Vec<Value> = array.iter().take(n).cloned().collect();
@@ -6552,12 +10614,173 @@ impl LispEvaluator {
         Ok(Value::Array(Arc::new(result)))
     }
 
-    /// (drop collection n) - Skip first N elements
-    fn eval_drop(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
-        if args.len() != 2 {
+    /// (lazy-seq thunk) - Lazy sequence whose cell is computed on demand
+    ///
+    /// The thunk returns null for the empty sequence or `[first rest]`, where
+    /// `rest` is another sequence. Local bindings are captured so recursive
+    /// generators like `(defun from (n) (lazy-seq (lambda () [n (from (+ n 1))])))` work.
+    fn eval_lazy_seq(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
             return Err(Error::InvalidArguments {
-                tool: "drop".to_string(),
-                reason: "Expected 2 arguments: collection and n".to_string(),
+                tool: "lazy-seq".to_string(),
+                reason: format!("Expected 1 argument (thunk), got {}", args.len()),
+            });
+        }
+
+        let func = self.evaluate_expression(&args[0].value)?;
+        if !matches!(func, Value::Function { .. } | Value::Memoized { .. }) {
+            return Err(Error::TypeError {
+                expected: "function".to_string(),
+                got: func.type_name(),
+            });
+        }
+
+        Ok(Value::lazy(LazyThunk::Thunk {
+            func,
+            bindings: self.env.local_snapshot(),
+        }))
+    }
+
+    /// (lazy-map fn seq) / (lazy-filter pred seq) - Lazily transform a sequence
+    fn eval_lazy_map(&mut self, args: &[crate::parser::Argument], filter: bool) -> Result<Value> {
+        let tool = if filter { "lazy-filter" } else { "lazy-map" };
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!(
+                    "Expected 2 arguments (function sequence), got {}",
+                    args.len()
+                ),
+            });
+        }
+
+        let func = self.eval_function_designator(&args[0].value)?;
+        let source = self.evaluate_expression(&args[1].value)?;
+        if !matches!(source, Value::LazySeq(_) | Value::Array(_) | Value::Null) {
+            return Err(Error::TypeError {
+                expected: "sequence".to_string(),
+                got: source.type_name(),
+            });
+        }
+
+        Ok(Value::lazy(if filter {
+            LazyThunk::Filter { pred: func, source }
+        } else {
+            LazyThunk::Map { func, source }
+        }))
+    }
+
+    /// Split a sequence into its first element and the rest, forcing lazy cells
+    fn seq_step(&mut self, seq: &Value) -> Result<Option<(Value, Value)>> {
+        match seq {
+            Value::Null => Ok(None),
+            Value::Array(arr) => Ok(Self::array_step(arr, 0)),
+            Value::LazySeq(cell) => {
+                // Take the thunk out so the lock is not held while user code runs
+                let thunk = {
+                    let mut state = cell.lock().unwrap();
+                    match std::mem::replace(&mut *state, LazyState::Forced(None)) {
+                        LazyState::Forced(forced) => {
+                            *state = LazyState::Forced(forced.clone());
+                            return Ok(forced);
+                        }
+                        LazyState::Pending(thunk) => thunk,
+                    }
+                };
+
+                let forced = self.force_lazy_thunk(&thunk);
+                let mut state = cell.lock().unwrap();
+                match forced {
+                    Ok(forced) => {
+                        *state = LazyState::Forced(forced.clone());
+                        Ok(forced)
+                    }
+                    Err(e) => {
+                        // Leave the cell pending so forcing can be retried
+                        *state = LazyState::Pending(thunk);
+                        Err(e)
+                    }
+                }
+            }
+            other => Err(Error::TypeError {
+                expected: "sequence".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
+    /// Element `start` of `items` and a cursor over the elements after it,
+    /// so stepping through an array never copies its tail
+    fn array_step(items: &Arc<Vec<Value>>, start: usize) -> Option<(Value, Value)> {
+        items.get(start).map(|first| {
+            let rest = Value::lazy(LazyThunk::Items {
+                items: items.clone(),
+                start: start + 1,
+            });
+            (first.clone(), rest)
+        })
+    }
+
+    /// Compute one lazy cell
+    fn force_lazy_thunk(&mut self, thunk: &LazyThunk) -> Result<Option<(Value, Value)>> {
+        match thunk {
+            LazyThunk::Thunk { func, bindings } => {
+                self.env.enter_scope();
+                for (name, value) in bindings {
+                    self.env.define(name.clone(), value.clone());
+                }
+                let result = self.call_function(func, &[], "lazy-seq");
+                self.env.exit_scope();
+
+                match result? {
+                    Value::Null => Ok(None),
+                    Value::Array(pair) if pair.len() == 2 => {
+                        Ok(Some((pair[0].clone(), pair[1].clone())))
+                    }
+                    other => Err(Error::TypeError {
+                        expected: "null or [first rest] from lazy-seq thunk".to_string(),
+                        got: other.type_name(),
+                    }),
+                }
+            }
+            LazyThunk::Map { func, source } => match self.seq_step(source)? {
+                Some((first, rest)) => {
+                    let mapped = self.apply_function_designator(func, vec![first], "lazy-map")?;
+                    let tail = Value::lazy(LazyThunk::Map {
+                        func: func.clone(),
+                        source: rest,
+                    });
+                    Ok(Some((mapped, tail)))
+                }
+                None => Ok(None),
+            },
+            LazyThunk::Filter { pred, source } => {
+                let mut seq = source.clone();
+                while let Some((first, rest)) = self.seq_step(&seq)? {
+                    let keep = self
+                        .apply_function_designator(pred, vec![first.clone()], "lazy-filter")?
+                        .is_truthy();
+                    if keep {
+                        let tail = Value::lazy(LazyThunk::Filter {
+                            pred: pred.clone(),
+                            source: rest,
+                        });
+                        return Ok(Some((first, tail)));
+                    }
+                    seq = rest;
+                }
+                Ok(None)
+            }
+            LazyThunk::Items { items, start } => Ok(Self::array_step(items, *start)),
+        }
+    }
+
+    /// (drop collection n) - Skip first N elements
+    fn eval_drop(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "drop".to_string(),
+                reason: "Expected 2 arguments: collection and n".to_string(),
             });
         }
 
@@ -6588,6 +10811,61 @@ impl LispEvaluator {
         Ok(Value::Array(Arc::new(result)))
     }
 
+    /// (transpose rows [:truncate true]) - Swap rows and columns of a list of lists
+    ///
+    /// Rows must all have the same length unless `:truncate true` is given,
+    /// in which case the result has as many rows as the shortest input row.
+    /// Example: (transpose [[1 2 3] [4 5 6]]) → [[1 4] [2 5] [3 6]]
+    fn eval_transpose(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "transpose".to_string(),
+                reason: "Expected a list of lists and optional :truncate flag".to_string(),
+            });
+        }
+
+        let matrix = self.evaluate_expression(&args[0].value)?;
+        let mut truncate = false;
+        for (option, expr) in Self::keyword_options("transpose", &args[1..])? {
+            match option.as_str() {
+                "truncate" => truncate = self.evaluate_expression(expr)?.is_truthy(),
+                other => {
+                    return Err(Error::InvalidArguments {
+                        tool: "transpose".to_string(),
+                        reason: format!("Unknown keyword :{}", other),
+                    })
+                }
+            }
+        }
+
+        let rows = matrix
+            .as_array()?
+            .iter()
+            .map(|row| row.as_array())
+            .collect::<Result<Vec<_>>>()?;
+
+        let shortest = rows.iter().map(|row| row.len()).min().unwrap_or(0);
+        if !truncate {
+            if let Some((i, row)) = rows.iter().enumerate().find(|(_, r)| r.len() != shortest) {
+                return Err(Error::InvalidArguments {
+                    tool: "transpose".to_string(),
+                    reason: format!(
+                        "Ragged input: row {} has {} elements, expected {} (pass :truncate true to cut to the shortest row)",
+                        i,
+                        row.len(),
+                        shortest
+                    ),
+                });
+            }
+        }
+
+        let columns = (0..shortest)
+            .map(|col| Value::Array(Arc::new(rows.iter().map(|row| row[col].clone()).collect())))
+            .collect();
+
+        Ok(Value::Array(Arc::new(columns)))
+    }
+
     /// (zip array1 array2) - Combine two arrays element-wise
     fn eval_zip(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 2 {
@@ -6675,75 +10953,67 @@ impl LispEvaluator {
         Ok(Value::Array(Arc::new(result)))
     }
 
-    /// (group-by collection key-fn) - Group elements by key function
+    /// (group-by key-fn collection) - Group elements by key function
+    ///
+    /// Returns an object mapping each distinct key to the elements that
+    /// produced it, in input order. `(group-by collection key-fn)` also works.
+    /// Example: (group-by even? [1 2 3 4]) → {"false" [1 3], "true" [2 4]}
     fn eval_group_by(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 2 {
             return Err(Error::InvalidArguments {
                 tool: "group-by".to_string(),
-                reason: "Expected 2 arguments: collection and key-fn".to_string(),
+                reason: "Expected 2 arguments: key-fn and collection".to_string(),
             });
         }
 
-        // Evaluate collection
-        let collection = self.evaluate_expression(&args[0].value)?;
+        let (func, collection) = self.eval_function_and_collection(args)?;
         let array = collection.as_array()?;
+        Self::check_function_arity("group-by", &func, 1, "Key function")?;
 
-        // Get key function
-        let func = self.evaluate_expression(&args[1].value)?;
-
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "group-by".to_string(),
-                        reason: format!(
-                            "Key function must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
-
-                let mut groups: std::collections::HashMap<String, Vec<Value>> =
-                    std::collections::HashMap::new();
-
-                // Apply key function to each element
-                for elem in array.iter() {
-                    // Create new scope for lambda execution
-                    self.env.enter_scope();
+        let mut groups: std::collections::HashMap<String, Vec<Value>> =
+            std::collections::HashMap::new();
 
-                    // Bind parameter
-                    self.env.define(params[0].clone(), elem.clone());
-
-                    // Evaluate key function
-                    let key_val = self.evaluate_expression(&body)?;
-
-                    // Exit scope
-                    self.env.exit_scope();
+        for elem in array.iter() {
+            let key_val = self.apply_function_designator(&func, vec![elem.clone()], "group-by")?;
+            groups
+                .entry(Self::grouping_key(&key_val))
+                .or_default()
+                .push(elem.clone());
+        }
 
-                    // Convert key to string
-                    let key = match key_val {
-                        Value::String(s) => s,
-                        Value::Int(i) => i.to_string(),
-                        Value::Float(f) => f.to_string(),
-                        Value::Bool(b) => b.to_string(),
-                        _ => format!("{:?}", key_val),
-                    };
+        let result_map = groups
+            .into_iter()
+            .map(|(key, values)| (key.into(), Value::Array(Arc::new(values))))
+            .collect();
 
-                    groups.entry(key).or_default().push(elem.clone());
-                }
+        Ok(Value::Object(Arc::new(result_map)))
+    }
 
-                // Convert groups to object with arrays
-                let mut result_map = std::collections::HashMap::new();
-                for (key, values) in groups {
-                    result_map.insert(key, Value::Array(Arc::new(values)));
-                }
+    /// Evaluate the two arguments of a `(builtin key-fn collection)` call,
+    /// accepting them in either order, and return (function, collection)
+    fn eval_function_and_collection(
+        &mut self,
+        args: &[crate::parser::Argument],
+    ) -> Result<(Value, Value)> {
+        let first = self.eval_function_designator(&args[0].value)?;
+        let second = self.eval_function_designator(&args[1].value)?;
+        if matches!(first, Value::Array(_)) {
+            Ok((second, first))
+        } else {
+            Ok((first, second))
+        }
+    }
 
-                Ok(Value::Object(Arc::new(result_map)))
+    /// Object key for a grouping value. Strings are used as-is; integral
+    /// floats share the key of the equal integer, so 2 and 2.0 group together.
+    fn grouping_key(value: &Value) -> String {
+        match value {
+            Value::String(s) => s.clone(),
+            Value::Float(f) if f.fract() == 0.0 && f.abs() < i64::MAX as f64 => {
+                (*f as i64).to_string()
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
+            Value::Multiple(_) => Self::grouping_key(&value.clone().primary_value()),
+            other => other.to_string(),
         }
     }
 
@@ -6779,7 +11049,7 @@ impl LispEvaluator {
                     // Create scope for aggregation function
                     self.env.enter_scope();
                     self.env
-                        .define(params[0].clone(), Value::String(key.clone()));
+                        .define(params[0].clone(), Value::String(key.to_string()));
                     self.env.define(params[1].clone(), values.clone());
 
                     // Evaluate aggregation function
@@ -6814,7 +11084,7 @@ impl LispEvaluator {
         let array = collection.as_array()?;
 
         // Get key function
-        let key_fn = self.evaluate_expression(&args[1].value)?;
+        let key_fn = self.eval_function_designator(&args[1].value)?;
 
         // Check for :desc flag
         let descending = if args.len() == 3 {
@@ -6828,227 +11098,619 @@ impl LispEvaluator {
             false
         };
 
-        match key_fn {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "sort-by".to_string(),
-                        reason: format!(
-                            "Key function must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
-                }
-
-                // Create vector of (element, key) pairs
-                let mut pairs = Vec::new();
-
-                for elem in array.iter() {
-                    // Create scope for key function
-                    self.env.enter_scope();
-                    self.env.define(params[0].clone(), elem.clone());
-
-                    // Evaluate key function to get sort key
-                    let key = self.evaluate_expression(&body)?;
+        Self::check_function_arity("sort-by", &key_fn, 1, "Key function")?;
 
-                    self.env.exit_scope();
+        // Create vector of (element, key) pairs
+        let mut pairs = Vec::new();
+        for elem in array.iter() {
+            let key = self.apply_function_designator(&key_fn, vec![elem.clone()], "sort-by")?;
+            pairs.push((elem.clone(), key));
+        }
 
-                    pairs.push((elem.clone(), key));
+        // Sort by keys
+        pairs.sort_by(|a, b| {
+            let cmp = match (&a.1, &b.1) {
+                (Value::Int(x), Value::Int(y)) => x.cmp(y),
+                (Value::Float(x), Value::Float(y)) => {
+                    x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal)
                 }
+                (Value::String(x), Value::String(y)) => x.cmp(y),
+                _ => std::cmp::Ordering::Equal,
+            };
 
-                // Sort by keys
-                pairs.sort_by(|a, b| {
-                    let cmp = match (&a.1, &b.1) {
-                        (Value::Int(x), Value::Int(y)) => x.cmp(y),
-                        (Value::Float(x), Value::Float(y)) => {
-                            x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal)
-                        }
-                        (Value::String(x), Value::String(y)) => x.cmp(y),
-                        _ => std::cmp::Ordering::Equal,
-                    };
-
-                    if descending {
-                        cmp.reverse()
-                    } else {
-                        cmp
-                    }
-                });
+            if descending {
+                cmp.reverse()
+            } else {
+                cmp
+            }
+        });
 
-                // Extract sorted elements
-                let sorted: Vec<Value> = pairs.into_iter().map(|(elem, _)| elem).collect();
+        // Extract sorted elements
+        let sorted: Vec<Value> = pairs.into_iter().map(|(elem, _)| elem).collect();
 
-                Ok(Value::Array(Arc::new(sorted)))
-            }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: key_fn.type_name(),
-            }),
-        }
+        Ok(Value::Array(Arc::new(sorted)))
     }
 
-    /// (count-by collection key-fn) - Count occurrences by key function
+    /// (count-by key-fn collection) - Count occurrences by key function
+    ///
+    /// Keys are coerced the same way as `group-by`. `(count-by collection
+    /// key-fn)` also works.
+    /// Example: (count-by even? [1 2 3]) → {"false" 2, "true" 1}
     fn eval_count_by(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 2 {
             return Err(Error::InvalidArguments {
                 tool: "count-by".to_string(),
-                reason: "Expected 2 arguments: collection and key-fn".to_string(),
+                reason: "Expected 2 arguments: key-fn and collection".to_string(),
+            });
+        }
+
+        let (func, collection) = self.eval_function_and_collection(args)?;
+        let array = collection.as_array()?;
+        Self::check_function_arity("count-by", &func, 1, "Key function")?;
+
+        let mut keys = Vec::with_capacity(array.len());
+        for elem in array.iter() {
+            keys.push(self.apply_function_designator(&func, vec![elem.clone()], "count-by")?);
+        }
+
+        Ok(Self::count_keys(keys.iter()))
+    }
+
+    /// (frequencies collection) - Count occurrences of each distinct element
+    /// Example: (frequencies '(a b a c a)) → {:a 3 :b 1 :c 1}
+    fn eval_frequencies(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "frequencies".to_string(),
+                reason: "Expected 1 argument: collection".to_string(),
             });
         }
 
-        // Evaluate collection
         let collection = self.evaluate_expression(&args[0].value)?;
         let array = collection.as_array()?;
 
-        // Get key function
-        let func = self.evaluate_expression(&args[1].value)?;
+        Ok(Self::count_keys(array.iter()))
+    }
 
-        match func {
-            Value::Function { params, body, .. } => {
-                if params.len() != 1 {
-                    return Err(Error::InvalidArguments {
-                        tool: "count-by".to_string(),
-                        reason: format!(
-                            "Key function must take exactly 1 parameter, got {}",
-                            params.len()
-                        ),
-                    });
+    /// Object mapping each distinct grouping key to its number of occurrences
+    fn count_keys<'a>(values: impl Iterator<Item = &'a Value>) -> Value {
+        let mut counts: std::collections::HashMap<Arc<str>, Value> =
+            std::collections::HashMap::new();
+        for value in values {
+            let count = counts
+                .entry(Self::grouping_key(value).into())
+                .or_insert(Value::Int(0));
+            if let Value::Int(n) = count {
+                *n += 1;
+            }
+        }
+        Value::Object(Arc::new(counts))
+    }
+
+    /// Evaluate a regular tool call
+    fn eval_tool_call(
+        &mut self,
+        name: &str,
+        args: &[crate::parser::Argument],
+        tail: bool,
+    ) -> Result<Value> {
+        // Check if this is a user-defined function first
+        let resolved = self.resolve_symbol(name)?;
+        if let Ok(func_val) = self.env.get(&resolved) {
+            if matches!(func_val, Value::Function { .. } | Value::Memoized { .. }) {
+                // This is a function call!
+
+                // Evaluate arguments - handle both positional and keyword arguments
+                let mut evaluated_args = Vec::new();
+                for arg in args {
+                    // If this is a keyword argument, include the keyword name with colon prefix
+                    if let Some(ref keyword_name) = arg.name {
+                        // Ensure keyword has colon prefix
+                        let kw = if keyword_name.starts_with(':') {
+                            keyword_name.clone()
+                        } else {
+                            format!(":{}", keyword_name)
+                        };
+                        evaluated_args.push(Value::String(kw));
+                    }
+                    // Add the argument value
+                    let val = self.evaluate_expression(&arg.value)?;
+                    evaluated_args.push(val);
                 }
 
-                let mut counts: std::collections::HashMap<String, i64> =
-                    std::collections::HashMap::new();
+                // A package function runs with its home package current so its
+                // unqualified references resolve inside that package
+                let home = resolved
+                    .split_once("::")
+                    .map(|(pkg, _)| pkg.to_string())
+                    .or_else(|| self.current_package.clone());
+
+                // In tail position, hand the call back to the enclosing trampoline
+                // instead of growing the Rust stack
+                if tail
+                    && home == self.current_package
+                    && matches!(func_val, Value::Function { is_flet: false, .. })
+                {
+                    self.pending_tail_call = Some((func_val, evaluated_args, name.to_string()));
+                    return Ok(Value::Null);
+                }
+
+                let saved_package = std::mem::replace(&mut self.current_package, home);
+                let result = self.call_function(&func_val, &evaluated_args, name);
+                self.current_package = saved_package;
+                return result;
+            }
+        }
+
+        // Not a function, try tool registry
+        let tool = self.registry.get(name)?;
+
+        // Evaluate arguments
+        let mut evaluated_args = Vec::new();
+        for arg in args {
+            let val = self.evaluate_expression(&arg.value)?;
+            evaluated_args.push(val);
+        }
+
+        // Tools registered from script run their lambda in this evaluator
+        if let Some(func) = tool.function() {
+            let func = func.clone();
+            return self.call_function(&func, &evaluated_args, name);
+        }
+
+        // Execute tool
+        tool.execute(&evaluated_args)
+    }
+
+    /// Apply a function value to already-evaluated arguments
+    ///
+    /// Handles flet isolation, regular scope-chain functions and memoized wrappers.
+    /// `context` is used in arity error messages (usually the function name).
+    fn call_function(&mut self, func: &Value, args: &[Value], context: &str) -> Result<Value> {
+        // A function body is not inside the caller's loops: break/continue
+        // there must not unwind out of the call
+        let saved_loop_depth = std::mem::replace(&mut self.loop_depth, 0);
+        let result = self.apply_function(func, args, context);
+        self.loop_depth = saved_loop_depth;
+        result
+    }
+
+    /// Body of [`Self::call_function`], run with no enclosing loops
+    fn apply_function(&mut self, func: &Value, args: &[Value], context: &str) -> Result<Value> {
+        self.check_interrupt()?;
+        match func {
+            Value::Function {
+                params,
+                body,
+                closure,
+                is_flet,
+            } => {
+                // For flet functions, use isolated execution
+                // This prevents recursion by isolating from parent scopes
+                if *is_flet {
+                    // Save current environment
+                    let saved_env = self.env.clone();
 
-                // Apply key function to each element
-                for elem in array.iter() {
-                    // Create new scope for lambda execution
+                    // Create new isolated environment with only closure variables
+                    self.env = Environment::new();
+                    for (var_name, var_value) in closure.iter() {
+                        self.env.define(var_name.clone(), var_value.clone());
+                    }
+
+                    // Bind parameters and evaluate function body
+                    let result = self
+                        .bind_function_parameters(params, args, context)
+                        .and_then(|_| self.evaluate_expression(body));
+
+                    // Restore original environment
+                    self.env = saved_env;
+
+                    result
+                } else {
+                    // For regular defun functions (empty closure), use normal scope chain
                     self.env.enter_scope();
 
-                    // Bind parameter
-                    self.env.define(params[0].clone(), elem.clone());
+                    let mut result = self
+                        .bind_function_parameters(params, args, context)
+                        .and_then(|_| self.evaluate_tail(body, true));
 
-                    // Evaluate key function
-                    let key_val = self.evaluate_expression(&body)?;
+                    // Trampoline: calls in tail position are deferred to pending_tail_call
+                    // and run here, rebinding parameters in the same frame
+                    while let Some((next, next_args, next_context)) = self.pending_tail_call.take()
+                    {
+                        if let Value::Function { params, body, .. } = &next {
+                            result = self
+                                .check_interrupt()
+                                .and_then(|_| {
+                                    self.bind_function_parameters(params, &next_args, &next_context)
+                                })
+                                .and_then(|_| self.evaluate_tail(body, true));
+                        }
+                    }
 
-                    // Exit scope
+                    // Exit function scope
                     self.env.exit_scope();
 
-                    // Convert key to string
-                    let key = match key_val {
-                        Value::String(s) => s,
-                        Value::Int(i) => i.to_string(),
-                        Value::Float(f) => f.to_string(),
-                        Value::Bool(b) => b.to_string(),
-                        _ => format!("{:?}", key_val),
-                    };
+                    result
+                }
+            }
+            Value::Memoized { func, cache } => {
+                let key = Self::memo_key(args)?;
+                if let Some(hit) = cache.lock().unwrap().get(&key) {
+                    return Ok(hit.clone());
+                }
+                // Lock is released while computing so recursive calls can hit the cache
+                let result = self.call_function(func, args, context)?;
+                cache.lock().unwrap().insert(key, result.clone());
+                Ok(result)
+            }
+            other => Err(Error::NotCallable {
+                type_name: other.type_name(),
+            }),
+        }
+    }
+
+    /// Check that a function designator can be called with `arity` arguments
+    ///
+    /// Lambdas must declare exactly `arity` parameters (`role` names the
+    /// function in the error); builtin names and memoized functions are
+    /// checked when called.
+    fn check_function_arity(tool: &str, func: &Value, arity: usize, role: &str) -> Result<()> {
+        match func {
+            Value::Function { params, .. } if params.len() != arity => {
+                Err(Error::InvalidArguments {
+                    tool: tool.to_string(),
+                    reason: format!(
+                        "{} must take exactly {} parameter{}, got {}",
+                        role,
+                        arity,
+                        if arity == 1 { "" } else { "s" },
+                        params.len()
+                    ),
+                })
+            }
+            Value::Function { .. } | Value::Memoized { .. } | Value::String(_) => Ok(()),
+            other => Err(Error::TypeError {
+                expected: "function".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
+    /// Evaluate the function position of a higher-order form
+    ///
+    /// An unbound bare symbol naming a builtin or registered tool (e.g. `+` or
+    /// `list`) is a designator for it and evaluates to its name as a string.
+    /// Any other unbound symbol is an undefined variable, as it would be
+    /// outside function position.
+    fn eval_function_designator(&mut self, expr: &Expression) -> Result<Value> {
+        if let Expression::Variable(name) = expr {
+            if !self.env.exists(name) && self.is_builtin_name(name) {
+                return Ok(Value::String(name.clone()));
+            }
+        }
+        self.evaluate_expression(expr)
+    }
+
+    /// Whether name is a builtin (see `BUILTIN_NAMES`), an operator or a
+    /// registered tool
+    fn is_builtin_name(&self, name: &str) -> bool {
+        static NAMES: std::sync::OnceLock<std::collections::HashSet<&'static str>> =
+            std::sync::OnceLock::new();
+        NAMES
+            .get_or_init(|| {
+                Self::BUILTIN_NAMES
+                    .iter()
+                    .chain(OPERATOR_NAMES)
+                    .copied()
+                    .collect()
+            })
+            .contains(name)
+            || self.registry.get(name).is_ok()
+    }
+
+    /// The comparison a builtin operator name stands for
+    fn comparison_op(name: &str) -> Option<BinaryOp> {
+        match name {
+            "<" => Some(BinaryOp::Lt),
+            ">" => Some(BinaryOp::Gt),
+            "<=" => Some(BinaryOp::LtEq),
+            ">=" => Some(BinaryOp::GtEq),
+            "=" | "==" => Some(BinaryOp::Eq),
+            "!=" => Some(BinaryOp::NotEq),
+            _ => None,
+        }
+    }
+
+    /// (< a b c ...) - Chained comparison, true when every adjacent pair holds
+    ///
+    /// Operands are evaluated left to right, stopping at the first pair that
+    /// fails: (< 3 1 (error ...)) is false without signalling.
+    fn eval_comparison_chain(
+        &mut self,
+        name: &str,
+        args: &[crate::parser::Argument],
+    ) -> Result<Value> {
+        let op = Self::comparison_op(name)
+            .unwrap_or_else(|| unreachable!("{} is not a comparison operator", name));
+        let Some((first, rest)) = args.split_first() else {
+            return Err(Error::InvalidArguments {
+                tool: name.to_string(),
+                reason: "Expected at least 1 argument".to_string(),
+            });
+        };
+
+        let mut previous = self.evaluate_expression(&first.value)?;
+        for arg in rest {
+            let current = self.evaluate_expression(&arg.value)?;
+            if !self
+                .apply_binary_op(op, previous, current.clone())?
+                .is_truthy()
+            {
+                return Ok(Value::Bool(false));
+            }
+            previous = current;
+        }
+        Ok(Value::Bool(true))
+    }
+
+    /// Apply a function value or builtin name to already-evaluated arguments
+    fn apply_function_designator(
+        &mut self,
+        func: &Value,
+        args: Vec<Value>,
+        context: &str,
+    ) -> Result<Value> {
+        let name = match func {
+            Value::String(name) => name.trim_start_matches(':'),
+            _ => return self.call_function(func, &args, context),
+        };
+
+        // Comparison operators hold between each adjacent pair: (< 1 2 3)
+        if let Some(op) = Self::comparison_op(name) {
+            for pair in args.windows(2) {
+                let holds = self.apply_binary_op(op, pair[0].clone(), pair[1].clone())?;
+                if !holds.is_truthy() {
+                    return Ok(Value::Bool(false));
+                }
+            }
+            return Ok(Value::Bool(true));
+        }
+
+        // Arithmetic operators fold left over their arguments: (+ 1 2 3) = ((1 + 2) + 3)
+        let op = match name {
+            "+" => Some((BinaryOp::Add, Value::Int(0))),
+            "-" => Some((BinaryOp::Sub, Value::Int(0))),
+            "*" => Some((BinaryOp::Mul, Value::Int(1))),
+            "/" => Some((BinaryOp::Div, Value::Int(1))),
+            "%" => Some((BinaryOp::Mod, Value::Int(1))),
+            _ => None,
+        };
+        if let Some((op, identity)) = op {
+            let mut iter = args.into_iter();
+            let first = match iter.next() {
+                Some(first) => first,
+                None => return Ok(identity),
+            };
+            // Unary minus/division apply to the identity: (- x) = (0 - x)
+            let mut acc = if iter.len() == 0 && matches!(op, BinaryOp::Sub | BinaryOp::Div) {
+                self.apply_binary_op(op, identity, first)?
+            } else {
+                first
+            };
+            for arg in iter {
+                acc = self.apply_binary_op(op, acc, arg)?;
+            }
+            return Ok(acc);
+        }
+
+        // Any other builtin: bind the values to fresh names and dispatch a normal call
+        self.env.enter_scope();
+        let mut call_args = Vec::with_capacity(args.len());
+        for (i, value) in args.into_iter().enumerate() {
+            // The space makes the name unwritable from source, so it cannot shadow user code
+            let var = format!(" arg{}", i);
+            self.env.define(var.clone(), value);
+            call_args.push(crate::parser::Argument {
+                name: None,
+                value: Expression::Variable(var),
+            });
+        }
+        let result = self.evaluate_expression(&Expression::ToolCall {
+            name: name.to_string(),
+            args: call_args,
+        });
+        self.env.exit_scope();
+        result
+    }
 
-                    *counts.entry(key).or_insert(0) += 1;
+    /// Canonical cache key for a memoized call's argument list
+    ///
+    /// Only ints, strings (including keywords/symbols) and bools are hashable;
+    /// each is tagged and strings are length-prefixed so keys cannot collide.
+    fn memo_key(args: &[Value]) -> Result<String> {
+        let mut key = String::new();
+        for arg in args {
+            match arg {
+                Value::Int(n) => key.push_str(&format!("i{};", n)),
+                Value::Bool(b) => key.push_str(if *b { "b1;" } else { "b0;" }),
+                Value::String(s) => key.push_str(&format!("s{}:{}", s.len(), s)),
+                other => {
+                    return Err(Error::TypeError {
+                        expected: "hashable memoize argument (int, string, bool)".to_string(),
+                        got: other.type_name(),
+                    })
                 }
+            }
+        }
+        Ok(key)
+    }
 
-                // Convert counts to object with int values
-                let mut result_map = std::collections::HashMap::new();
-                for (key, count) in counts {
-                    result_map.insert(key, Value::Int(count));
-                }
+    /// (memoize fn) - Wrap a function so results are cached by argument list
+    ///
+    /// The cache is owned by the returned wrapper, so recursive definitions
+    /// like `(define fib (memoize (lambda (n) ...)))` only compute each n once.
+    fn eval_memoize(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "memoize".to_string(),
+                reason: format!("Expected 1 argument (function), got {}", args.len()),
+            });
+        }
 
-                Ok(Value::Object(Arc::new(result_map)))
-            }
-            _ => Err(Error::TypeError {
+        let func = self.evaluate_expression(&args[0].value)?;
+        match func {
+            Value::Function { .. } | Value::Memoized { .. } => Ok(Value::Memoized {
+                func: Arc::new(func),
+                cache: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            }),
+            other => Err(Error::TypeError {
                 expected: "function".to_string(),
-                got: func.type_name(),
+                got: other.type_name(),
             }),
         }
     }
 
-    /// Evaluate a regular tool call
-    fn eval_tool_call(&mut self, name: &str, args: &[crate::parser::Argument]) -> Result<Value> {
-        // Check if this is a user-defined function first
-        if let Ok(func_val) = self.env.get(name) {
-            if let Value::Function {
-                params,
-                body,
-                closure,
-                is_flet,
-            } = func_val
-            {
-                // This is a function call!
+    /// (with-overflow-mode :checked|:wrapping|:saturating body...) - Scope integer overflow behavior
+    fn eval_with_overflow_mode(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "with-overflow-mode".to_string(),
+                reason: "Expected mode and body".to_string(),
+            });
+        }
 
-                // Evaluate arguments - handle both positional and keyword arguments
-                let mut evaluated_args = Vec::new();
-                for arg in args {
-                    // If this is a keyword argument, include the keyword name with colon prefix
-                    if let Some(ref keyword_name) = arg.name {
-                        // Ensure keyword has colon prefix
-                        let kw = if keyword_name.starts_with(':') {
-                            keyword_name.clone()
-                        } else {
-                            format!(":{}", keyword_name)
-                        };
-                        evaluated_args.push(Value::String(kw));
-                    }
-                    // Add the argument value
-                    let val = self.evaluate_expression(&arg.value)?;
-                    evaluated_args.push(val);
+        let mode = match self.evaluate_expression(&args[0].value)? {
+            Value::String(s) => match s.trim_start_matches(':') {
+                "checked" => OverflowMode::Checked,
+                "wrapping" => OverflowMode::Wrapping,
+                "saturating" => OverflowMode::Saturating,
+                other => {
+                    return Err(Error::InvalidArguments {
+                        tool: "with-overflow-mode".to_string(),
+                        reason: format!(
+                            "Unknown mode :{} (expected :checked, :wrapping or :saturating)",
+                            other
+                        ),
+                    })
                 }
+            },
+            other => {
+                return Err(Error::TypeError {
+                    expected: "keyword".to_string(),
+                    got: other.type_name(),
+                })
+            }
+        };
 
-                // For flet functions, use isolated execution
-                // This prevents recursion by isolating from parent scopes
-                if is_flet {
-                    // Save current environment
-                    let saved_env = self.env.clone();
-
-                    // Create new isolated environment with only closure variables
-                    self.env = Environment::new();
-                    for (var_name, var_value) in closure.iter() {
-                        self.env.define(var_name.clone(), var_value.clone());
-                    }
-
-                    // Bind parameters
-                    self.bind_function_parameters(&params, &evaluated_args, name)?;
-
-                    // Evaluate function body
-                    let result = self.evaluate_expression(&body); // Explicit deref
-
-                    // Restore original environment
-                    self.env = saved_env;
-
-                    return result;
-                } else {
-                    // For regular defun functions (empty closure), use normal scope chain
-                    self.env.enter_scope();
+        // Dynamic extent: restore the outer mode even if the body fails
+        let saved = std::mem::replace(&mut self.overflow_mode, mode);
+        let result = self.eval_do(&args[1..], false);
+        self.overflow_mode = saved;
+        result
+    }
 
-                    // Bind parameters
-                    self.bind_function_parameters(&params, &evaluated_args, name)?;
+    /// (with-refinement-checks body...) - Enforce refinement annotations
+    /// on `define`, `set!` and `setf` for the dynamic extent of body
+    fn eval_with_refinement_checks(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let saved = std::mem::replace(&mut self.refinement_checks, true);
+        let result = self.eval_do(args, false);
+        self.refinement_checks = saved;
+        result
+    }
 
-                    // Evaluate function body
-                    let result = self.evaluate_expression(&body); // Explicit deref
+    /// Helper: Verify a value against the refinement recorded for a variable.
+    /// A no-op outside `with-refinement-checks` or for unrefined variables.
+    fn check_refinement(&mut self, name: &str, value: &Value) -> Result<()> {
+        if !self.refinement_checks {
+            return Ok(());
+        }
+        let Some(Expression::RefinedTypeExpr {
+            var,
+            base_type,
+            predicate,
+        }) = self.refinements.get(name).cloned()
+        else {
+            return Ok(());
+        };
 
-                    // Exit function scope
-                    self.env.exit_scope();
+        // Bind the refinement variable to the candidate value and test it
+        self.env.enter_scope();
+        self.env.define(var.clone(), value.clone());
+        let holds = self.evaluate_expression(&predicate);
+        self.env.exit_scope();
 
-                    return result;
-                }
-            }
+        if holds?.is_truthy() {
+            return Ok(());
         }
 
-        // Not a function, try tool registry
-        let tool = self.registry.get(name)?;
+        let base = match base_type.as_ref() {
+            Expression::Variable(type_name) => {
+                crate::types::Type::from_name(type_name).unwrap_or(crate::types::Type::Any)
+            }
+            _ => crate::types::Type::Any,
+        };
+        let refined = crate::types::RefinementType::from_expr(var, base, &predicate);
+        Err(Error::RuntimeError(format!(
+            "Refinement violated: {} = {} does not satisfy {}",
+            name, value, refined
+        )))
+    }
 
-        // Evaluate arguments
-        let mut evaluated_args = Vec::new();
-        for arg in args {
-            let val = self.evaluate_expression(&arg.value)?;
-            evaluated_args.push(val);
+    /// Apply an i64 operation according to the current overflow mode
+    fn int_arith(
+        &self,
+        op: &str,
+        l: i64,
+        r: i64,
+        checked: fn(i64, i64) -> Option<i64>,
+        wrapping: fn(i64, i64) -> i64,
+        saturating: fn(i64, i64) -> i64,
+    ) -> Result<Value> {
+        match self.overflow_mode {
+            OverflowMode::Checked => checked(l, r)
+                .map(Value::Int)
+                .ok_or_else(|| Error::ArithmeticOverflow { op: op.to_string() }),
+            OverflowMode::Wrapping => Ok(Value::Int(wrapping(l, r))),
+            OverflowMode::Saturating => Ok(Value::Int(saturating(l, r))),
         }
-
-        // Execute tool
-        tool.execute(&evaluated_args)
     }
 
     // Binary operator implementation (simplified from base evaluator)
 
     fn apply_binary_op(&self, op: BinaryOp, left: Value, right: Value) -> Result<Value> {
+        if self.vectorize_depth > 0
+            && matches!(
+                op,
+                BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod
+            )
+            && (matches!(left, Value::Array(_)) || matches!(right, Value::Array(_)))
+        {
+            let mut elements = Vec::new();
+            for pair in Self::broadcast_rows(&op.to_string(), &[left, right])? {
+                let [l, r]: [Value; 2] = pair.try_into().expect("two operands per row");
+                elements.push(self.apply_binary_op(op, l, r)?);
+            }
+            return Ok(Value::Array(Arc::new(elements)));
+        }
+
+        // A complex operand promotes real numbers on the other side
+        if matches!(left, Value::Complex { .. }) || matches!(right, Value::Complex { .. }) {
+            if let (Some(l), Some(r)) = (Self::complex_parts(&left), Self::complex_parts(&right)) {
+                return Self::apply_complex_op(op, l, r);
+            }
+        }
+
         match op {
             BinaryOp::Add => match (left, right) {
-                (Value::Int(l), Value::Int(r)) => Ok(Value::Int(l.saturating_add(r))),
+                (Value::Int(l), Value::Int(r)) => self.int_arith(
+                    "add",
+                    l,
+                    r,
+                    i64::checked_add,
+                    i64::wrapping_add,
+                    i64::saturating_add,
+                ),
                 (Value::Float(l), Value::Float(r)) => Ok(Value::Float(l + r)),
                 (Value::Int(l), Value::Float(r)) => Ok(Value::Float(l as f64 + r)),
                 (Value::Float(l), Value::Int(r)) => Ok(Value::Float(l + r as f64)),
@@ -7067,7 +11729,14 @@ impl LispEvaluator {
             },
 
             BinaryOp::Sub => match (left, right) {
-                (Value::Int(l), Value::Int(r)) => Ok(Value::Int(l.saturating_sub(r))),
+                (Value::Int(l), Value::Int(r)) => self.int_arith(
+                    "subtract",
+                    l,
+                    r,
+                    i64::checked_sub,
+                    i64::wrapping_sub,
+                    i64::saturating_sub,
+                ),
                 (Value::Float(l), Value::Float(r)) => Ok(Value::Float(l - r)),
                 (Value::Int(l), Value::Float(r)) => Ok(Value::Float(l as f64 - r)),
                 (Value::Float(l), Value::Int(r)) => Ok(Value::Float(l - r as f64)),
@@ -7079,7 +11748,14 @@ impl LispEvaluator {
             },
 
             BinaryOp::Mul => match (left, right) {
-                (Value::Int(l), Value::Int(r)) => Ok(Value::Int(l.saturating_mul(r))),
+                (Value::Int(l), Value::Int(r)) => self.int_arith(
+                    "multiply",
+                    l,
+                    r,
+                    i64::checked_mul,
+                    i64::wrapping_mul,
+                    i64::saturating_mul,
+                ),
                 (Value::Float(l), Value::Float(r)) => Ok(Value::Float(l * r)),
                 (Value::Int(l), Value::Float(r)) => Ok(Value::Float(l as f64 * r)),
                 (Value::Float(l), Value::Int(r)) => Ok(Value::Float(l * r as f64)),
@@ -7143,6 +11819,7 @@ impl LispEvaluator {
                 (Value::Float(l), Value::Float(r)) => Ok(Value::Bool(l < r)),
                 (Value::Int(l), Value::Float(r)) => Ok(Value::Bool((l as f64) < r)),
                 (Value::Float(l), Value::Int(r)) => Ok(Value::Bool(l < (r as f64))),
+                (Value::Instant(l), Value::Instant(r)) => Ok(Value::Bool(l < r)),
                 (l, r) => Err(Error::InvalidOperation {
                     op: "less than".to_string(),
                     left_type: l.type_name(),
@@ -7155,6 +11832,7 @@ impl LispEvaluator {
                 (Value::Float(l), Value::Float(r)) => Ok(Value::Bool(l > r)),
                 (Value::Int(l), Value::Float(r)) => Ok(Value::Bool((l as f64) > r)),
                 (Value::Float(l), Value::Int(r)) => Ok(Value::Bool(l > (r as f64))),
+                (Value::Instant(l), Value::Instant(r)) => Ok(Value::Bool(l > r)),
                 (l, r) => Err(Error::InvalidOperation {
                     op: "greater than".to_string(),
                     left_type: l.type_name(),
@@ -7167,6 +11845,7 @@ impl LispEvaluator {
                 (Value::Float(l), Value::Float(r)) => Ok(Value::Bool(l <= r)),
                 (Value::Int(l), Value::Float(r)) => Ok(Value::Bool((l as f64) <= r)),
                 (Value::Float(l), Value::Int(r)) => Ok(Value::Bool(l <= (r as f64))),
+                (Value::Instant(l), Value::Instant(r)) => Ok(Value::Bool(l <= r)),
                 (l, r) => Err(Error::InvalidOperation {
                     op: "less than or equal".to_string(),
                     left_type: l.type_name(),
@@ -7179,6 +11858,7 @@ impl LispEvaluator {
                 (Value::Float(l), Value::Float(r)) => Ok(Value::Bool(l >= r)),
                 (Value::Int(l), Value::Float(r)) => Ok(Value::Bool((l as f64) >= r)),
                 (Value::Float(l), Value::Int(r)) => Ok(Value::Bool(l >= (r as f64))),
+                (Value::Instant(l), Value::Instant(r)) => Ok(Value::Bool(l >= r)),
                 (l, r) => Err(Error::InvalidOperation {
                     op: "greater than or equal".to_string(),
                     left_type: l.type_name(),
@@ -7195,11 +11875,48 @@ impl LispEvaluator {
         }
     }
 
+    /// Real and imaginary parts of a number, or None for non-numbers
+    fn complex_parts(value: &Value) -> Option<(f64, f64)> {
+        match value {
+            Value::Int(n) => Some((*n as f64, 0.0)),
+            Value::Float(f) => Some((*f, 0.0)),
+            Value::Complex { re, im } => Some((*re, *im)),
+            _ => None,
+        }
+    }
+
+    /// Arithmetic and equality on complex numbers given as (re, im) pairs
+    fn apply_complex_op(op: BinaryOp, (a, b): (f64, f64), (c, d): (f64, f64)) -> Result<Value> {
+        match op {
+            BinaryOp::Add => Ok(Value::complex(a + c, b + d)),
+            BinaryOp::Sub => Ok(Value::complex(a - c, b - d)),
+            BinaryOp::Mul => Ok(Value::complex(a * c - b * d, a * d + b * c)),
+            BinaryOp::Div => {
+                let denom = c * c + d * d;
+                if denom == 0.0 {
+                    return Err(Error::DivisionByZero);
+                }
+                Ok(Value::complex(
+                    (a * c + b * d) / denom,
+                    (b * c - a * d) / denom,
+                ))
+            }
+            BinaryOp::Eq => Ok(Value::Bool(a == c && b == d)),
+            BinaryOp::NotEq => Ok(Value::Bool(a != c || b != d)),
+            _ => Err(Error::InvalidOperation {
+                op: format!("{:?}", op).to_lowercase(),
+                left_type: "complex".to_string(),
+                right_type: "complex".to_string(),
+            }),
+        }
+    }
+
     fn apply_unary_op(&self, op: UnaryOp, operand: Value) -> Result<Value> {
         match op {
             UnaryOp::Neg => match operand {
                 Value::Int(n) => Ok(Value::Int(-n)),
                 Value::Float(f) => Ok(Value::Float(-f)),
+                Value::Complex { re, im } => Ok(Value::complex(-re, -im)),
                 v => Err(Error::TypeError {
                     expected: "number".to_string(),
                     got: v.type_name(),
@@ -7225,26 +11942,74 @@ impl LispEvaluator {
         Ok(Value::String(format!("{}__{}", prefix, counter)))
     }
 
-    /// (macroexpand form) - Expand macro once (debugging tool)
-    fn eval_macroexpand(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+    /// (macroexpand-1 form) / (macroexpand form) / (macroexpand-all form)
+    ///
+    /// Expand a quoted form: `macroexpand-1` expands the outermost macro call
+    /// once, `macroexpand` repeats that until the head is no longer a macro, and
+    /// `macroexpand-all` also expands every subform. Returns the expansion as
+    /// quoted data, or the form unchanged if there is nothing to expand.
+    fn eval_macroexpand(
+        &mut self,
+        args: &[crate::parser::Argument],
+        mode: MacroexpandMode,
+    ) -> Result<Value> {
+        let tool = match mode {
+            MacroexpandMode::Once => "macroexpand-1",
+            MacroexpandMode::TopLevel => "macroexpand",
+            MacroexpandMode::All => "macroexpand-all",
+        };
         if args.len() != 1 {
             return Err(Error::InvalidArguments {
-                tool: "macroexpand".to_string(),
-                reason: "Expected 1 argument: form to expand".to_string(),
+                tool: tool.to_string(),
+                reason: "Expected 1 argument: quoted form to expand".to_string(),
             });
         }
 
-        // Try to expand the expression once
-        match self.try_expand_macro(&args[0].value)? {
-            Some(expanded) => {
-                // Convert expanded expression back to a displayable value
-                // For now, return a string representation
-                Ok(Value::String(format!("{:?}", expanded)))
+        let form = self.evaluate_expression(&args[0].value)?;
+        match mode {
+            MacroexpandMode::Once => Ok(self.macroexpand_once(&form)?.unwrap_or(form)),
+            MacroexpandMode::TopLevel => self.macroexpand_top(form),
+            MacroexpandMode::All => self.macroexpand_all(form),
+        }
+    }
+
+    /// Expand `form` once if it is a call to a macro
+    fn macroexpand_once(&mut self, form: &Value) -> Result<Option<Value>> {
+        let Value::Array(items) = form else {
+            return Ok(None);
+        };
+        let Some(Value::String(name)) = items.first() else {
+            return Ok(None);
+        };
+        match self.env.get(name) {
+            Ok(Value::Macro { params, body, .. }) => {
+                Ok(Some(self.expand_macro_data(&params, &body, &items[1..])?))
             }
-            None => {
-                // Not a macro call, return original
-                Ok(Value::String(format!("{:?}", args[0].value)))
+            _ => Ok(None),
+        }
+    }
+
+    /// Expand the head of `form` until it is no longer a macro call
+    fn macroexpand_top(&mut self, mut form: Value) -> Result<Value> {
+        while let Some(expanded) = self.macroexpand_once(&form)? {
+            self.check_interrupt()?;
+            form = expanded;
+        }
+        Ok(form)
+    }
+
+    /// Expand `form` and then each of its subforms
+    fn macroexpand_all(&mut self, form: Value) -> Result<Value> {
+        let form = self.macroexpand_top(form)?;
+        match form {
+            Value::Array(items) if matches!(items.first(), Some(Value::String(_))) => {
+                let mut expanded = vec![items[0].clone()];
+                for item in &items[1..] {
+                    expanded.push(self.macroexpand_all(item.clone())?);
+                }
+                Ok(Value::array(expanded))
             }
+            other => Ok(other),
         }
     }
 
@@ -7282,11 +12047,278 @@ impl LispEvaluator {
                 }
                 Ok(result)
             }
+            // A quoted call is evaluated as code
+            Value::Array(ref items) if matches!(items.first(), Some(Value::String(_))) => {
+                let expr = self.value_to_expression(&value)?;
+                self.evaluate_expression(&expr)
+            }
             // For other types, just return them as-is (already evaluated)
             other => Ok(other),
         }
     }
 
+    /// (read-from-string text [eof-error-p [eof-value]]) / (read stream ...)
+    ///
+    /// Parses the first form of the text as quoted data: symbols become
+    /// strings and lists become arrays, ready for `eval` or `macroexpand`.
+    /// Streams are strings, so `read` takes the same arguments. Empty input
+    /// is an error unless `eof-error-p` is nil, in which case `eof-value`
+    /// (default nil) is returned.
+    fn eval_read(&mut self, args: &[crate::parser::Argument], tool: &str) -> Result<Value> {
+        if args.is_empty() || args.len() > 3 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: "Expected text, optional eof-error-p and optional eof-value".to_string(),
+            });
+        }
+
+        let text = match self.evaluate_expression(&args[0].value)? {
+            Value::String(s) => s,
+            other => {
+                return Err(Error::TypeError {
+                    expected: "string or string input stream".to_string(),
+                    got: other.type_name(),
+                })
+            }
+        };
+        let eof_error = match args.get(1) {
+            Some(arg) => self.evaluate_expression(&arg.value)?.is_truthy(),
+            None => true,
+        };
+
+        let tokens = crate::lexer::SExprScanner::new(&text).scan_tokens()?;
+        match crate::parser::SExprParser::new(tokens).parse_datum()? {
+            Some(datum) => self.evaluate_expression(&datum),
+            None if eof_error => Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: "End of input before a complete form".to_string(),
+            }),
+            None => match args.get(2) {
+                Some(arg) => self.evaluate_expression(&arg.value),
+                None => Ok(Value::Null),
+            },
+        }
+    }
+
+    /// (compile name [definition]) - Pre-process a function for repeated calls
+    ///
+    /// Expands the macros in the body once and folds constant arithmetic, so
+    /// calls skip re-expansion. `(compile nil f)` returns the compiled copy of
+    /// `f`; with a name, the result is bound to it (a name alone compiles the
+    /// function already bound there) and the name is returned.
+    fn eval_compile(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() || args.len() > 2 {
+            return Err(Error::InvalidArguments {
+                tool: "compile".to_string(),
+                reason: "Expected a name (or nil) and an optional function".to_string(),
+            });
+        }
+
+        let name = match &args[0].value {
+            Expression::Variable(name) if args.len() == 1 => Some(name.clone()),
+            expr => match self.evaluate_expression(expr)? {
+                Value::Null => None,
+                Value::String(name) => Some(name),
+                other => {
+                    return Err(Error::TypeError {
+                        expected: "function name or nil".to_string(),
+                        got: other.type_name(),
+                    })
+                }
+            },
+        };
+        let func = match (&name, args.get(1)) {
+            (_, Some(definition)) => self.evaluate_expression(&definition.value)?,
+            (Some(name), None) => self.env.get(name)?,
+            (None, None) => {
+                return Err(Error::InvalidArguments {
+                    tool: "compile".to_string(),
+                    reason: "(compile nil) needs a function to compile".to_string(),
+                })
+            }
+        };
+
+        let compiled = match func {
+            Value::Function {
+                params,
+                body,
+                closure,
+                is_flet,
+            } => Value::Function {
+                params,
+                body: Arc::new(self.precompile(&body)?),
+                closure,
+                is_flet,
+            },
+            other => {
+                return Err(Error::TypeError {
+                    expected: "function".to_string(),
+                    got: other.type_name(),
+                })
+            }
+        };
+
+        match name {
+            Some(name) => {
+                self.env.set(&name, compiled)?;
+                Ok(Value::String(name))
+            }
+            None => Ok(compiled),
+        }
+    }
+
+    /// Expand every macro call in `expr` and fold operators applied to literals
+    fn precompile(&mut self, expr: &Expression) -> Result<Expression> {
+        if let Some(expanded) = self.try_expand_macro(expr)? {
+            return self.precompile(&expanded);
+        }
+
+        let boxed = |e: Expression| Box::new(e);
+        Ok(match expr {
+            Expression::ToolCall { name, args } => {
+                // Leading binding lists (parameters, let bindings) are not code
+                let skip = match name.as_str() {
+                    "defmacro" => args.len(),
+                    "defun" => 2,
+                    "lambda" | "let" | "let*" | "flet" | "labels" => 1,
+                    _ => 0,
+                };
+                let mut new_args = Vec::with_capacity(args.len());
+                for (i, arg) in args.iter().enumerate() {
+                    let value = if i < skip {
+                        arg.value.clone()
+                    } else {
+                        self.precompile(&arg.value)?
+                    };
+                    new_args.push(crate::parser::Argument {
+                        name: arg.name.clone(),
+                        value,
+                    });
+                }
+                Expression::ToolCall {
+                    name: name.clone(),
+                    args: new_args,
+                }
+            }
+            Expression::Binary { op, left, right } => {
+                let left = self.precompile(left)?;
+                let right = self.precompile(right)?;
+                match self.fold_binary(*op, &left, &right) {
+                    Some(folded) => folded,
+                    None => Expression::Binary {
+                        op: *op,
+                        left: boxed(left),
+                        right: boxed(right),
+                    },
+                }
+            }
+            Expression::Unary { op, operand } => {
+                let operand = self.precompile(operand)?;
+                let folded = match (op, &operand) {
+                    (UnaryOp::Neg, Expression::IntLiteral(n)) => {
+                        n.checked_neg().map(Expression::IntLiteral)
+                    }
+                    (UnaryOp::Neg, Expression::FloatLiteral(f)) => {
+                        Some(Expression::FloatLiteral(-f))
+                    }
+                    (UnaryOp::Not, Expression::BoolLiteral(b)) => Some(Expression::BoolLiteral(!b)),
+                    _ => None,
+                };
+                folded.unwrap_or(Expression::Unary {
+                    op: *op,
+                    operand: boxed(operand),
+                })
+            }
+            Expression::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+            } => {
+                let condition = self.precompile(condition)?;
+                let then_expr = self.precompile(then_expr)?;
+                let else_expr = self.precompile(else_expr)?;
+                match Self::literal_value(&condition) {
+                    Some(value) if value.is_truthy() => then_expr,
+                    Some(_) => else_expr,
+                    None => Expression::Ternary {
+                        condition: boxed(condition),
+                        then_expr: boxed(then_expr),
+                        else_expr: boxed(else_expr),
+                    },
+                }
+            }
+            Expression::ArrayLiteral(items) => Expression::ArrayLiteral(
+                items
+                    .iter()
+                    .map(|e| self.precompile(e))
+                    .collect::<Result<_>>()?,
+            ),
+            Expression::ObjectLiteral(pairs) => Expression::ObjectLiteral(
+                pairs
+                    .iter()
+                    .map(|(k, v)| Ok((k.clone(), self.precompile(v)?)))
+                    .collect::<Result<_>>()?,
+            ),
+            Expression::Lambda { params, body } => Expression::Lambda {
+                params: params.clone(),
+                body: boxed(self.precompile(body)?),
+            },
+            Expression::Grouping(inner) => Expression::Grouping(boxed(self.precompile(inner)?)),
+            Expression::FieldAccess { object, field } => Expression::FieldAccess {
+                object: boxed(self.precompile(object)?),
+                field: field.clone(),
+            },
+            Expression::IndexAccess { array, index } => Expression::IndexAccess {
+                array: boxed(self.precompile(array)?),
+                index: boxed(self.precompile(index)?),
+            },
+            // Quasiquote templates are data, and the remaining forms are left as written
+            other => other.clone(),
+        })
+    }
+
+    /// Fold `left op right` when both are literals and the result does not
+    /// depend on runtime state such as the overflow mode
+    fn fold_binary(
+        &self,
+        op: BinaryOp,
+        left: &Expression,
+        right: &Expression,
+    ) -> Option<Expression> {
+        let l = Self::literal_value(left)?;
+        let r = Self::literal_value(right)?;
+        if let (Value::Int(a), Value::Int(b)) = (&l, &r) {
+            let fits = match op {
+                BinaryOp::Add => a.checked_add(*b).is_some(),
+                BinaryOp::Sub => a.checked_sub(*b).is_some(),
+                BinaryOp::Mul => a.checked_mul(*b).is_some(),
+                BinaryOp::Pow => false,
+                _ => true,
+            };
+            if !fits {
+                return None;
+            }
+        }
+        // Errors such as division by zero are left to surface at call time
+        match self.apply_binary_op(op, l, r).ok()? {
+            Value::Int(n) => Some(Expression::IntLiteral(n)),
+            Value::Float(f) => Some(Expression::FloatLiteral(f)),
+            Value::Bool(b) => Some(Expression::BoolLiteral(b)),
+            _ => None,
+        }
+    }
+
+    /// The value of a literal expression
+    fn literal_value(expr: &Expression) -> Option<Value> {
+        match expr {
+            Expression::IntLiteral(n) => Some(Value::Int(*n)),
+            Expression::FloatLiteral(f) => Some(Value::Float(*f)),
+            Expression::BoolLiteral(b) => Some(Value::Bool(*b)),
+            Expression::NullLiteral => Some(Value::Null),
+            _ => None,
+        }
+    }
+
     /// Try to expand a macro call once
     /// Returns Some(expanded_expr) if it's a macro call, None otherwise
     fn try_expand_macro(&mut self, expr: &Expression) -> Result<Option<Expression>> {
@@ -7313,25 +12345,36 @@ impl LispEvaluator {
         body: &Expression,
         args: &[crate::parser::Argument],
     ) -> Result<Expression> {
+        // Bind parameters to UNEVALUATED arguments (supports &rest)
+        // Convert args to expression values first
+        let mut arg_values = Vec::new();
+        for arg in args {
+            arg_values.push(self.expression_to_value(&arg.value)?);
+        }
+        let result_value = self.expand_macro_data(params, body, &arg_values)?;
+
+        // Convert result back to an expression
+        self.value_to_expression(&result_value)
+    }
+
+    /// Evaluate a macro body with its parameters bound to quoted argument
+    /// forms, returning the generated code as data
+    fn expand_macro_data(
+        &mut self,
+        params: &[String],
+        body: &Expression,
+        arg_values: &[Value],
+    ) -> Result<Value> {
         // Save old environment
         let old_env = self.env.clone();
 
-        // Bind parameters to UNEVALUATED arguments (supports &rest)
-        // Convert args to expression values first
-        let mut arg_values = Vec::new();
-        for arg in args {
-            arg_values.push(self.expression_to_value(&arg.value)?);
-        }
-        self.bind_function_parameters(params, &arg_values, "macro")?;
-
-        // Evaluate macro body (which generates code)
-        let result_value = self.evaluate_expression(body)?;
+        let result = self
+            .bind_function_parameters(params, arg_values, "macro")
+            .and_then(|_| self.evaluate_expression(body));
 
         // Restore environment
         self.env = old_env;
-
-        // Convert result back to an expression
-        self.value_to_expression(&result_value)
+        result
     }
 
     /// Convert an expression to a value (for macro parameter binding)
@@ -7350,6 +12393,52 @@ impl LispEvaluator {
                     exprs.iter().map(|e| self.expression_to_value(e)).collect();
                 Ok(Value::array(vals?))
             }
+            // Calls become lists headed by the operator's symbol
+            Expression::ToolCall { name, args } => {
+                let mut items = vec![Value::String(name.clone())];
+                for arg in args {
+                    if let Some(keyword) = &arg.name {
+                        items.push(Value::String(format!(
+                            ":{}",
+                            keyword.trim_start_matches(':')
+                        )));
+                    }
+                    items.push(self.expression_to_value(&arg.value)?);
+                }
+                Ok(Value::array(items))
+            }
+            Expression::Binary { op, left, right } => {
+                let op = match op {
+                    BinaryOp::And => "and".to_string(),
+                    BinaryOp::Or => "or".to_string(),
+                    op => op.to_string(),
+                };
+                Ok(Value::array(vec![
+                    Value::String(op),
+                    self.expression_to_value(left)?,
+                    self.expression_to_value(right)?,
+                ]))
+            }
+            Expression::Unary { op, operand } => {
+                let op = match op {
+                    UnaryOp::Neg => "-",
+                    UnaryOp::Not => "not",
+                };
+                Ok(Value::array(vec![
+                    Value::String(op.to_string()),
+                    self.expression_to_value(operand)?,
+                ]))
+            }
+            Expression::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+            } => Ok(Value::array(vec![
+                Value::String("if".to_string()),
+                self.expression_to_value(condition)?,
+                self.expression_to_value(then_expr)?,
+                self.expression_to_value(else_expr)?,
+            ])),
             _ => {
                 // For complex expressions, represent as string (simplified)
                 Ok(Value::String(format!("{:?}", expr)))
@@ -7374,6 +12463,21 @@ impl LispEvaluator {
             }
             Value::Bool(b) => Ok(Expression::BoolLiteral(*b)),
             Value::Null => Ok(Expression::NullLiteral),
+            // A list headed by a symbol is code: read it back through the parser
+            // so special forms get their usual syntax
+            Value::Array(arr) if matches!(arr.first(), Some(Value::String(s)) if Self::is_symbol_name(s)) =>
+            {
+                let source = Self::datum_source(value)?;
+                let tokens = crate::lexer::SExprScanner::new(&source).scan_tokens()?;
+                let program = crate::parser::SExprParser::new(tokens).parse()?;
+                match program.statements.as_slice() {
+                    [Statement::Expression(expr)] => Ok(expr.clone()),
+                    _ => Err(Error::ParseError(format!(
+                        "Macro expansion is not a single form: {}",
+                        source
+                    ))),
+                }
+            }
             Value::Array(arr) => {
                 let exprs: Result<Vec<_>> =
                     arr.iter().map(|v| self.value_to_expression(v)).collect();
@@ -7386,6 +12490,40 @@ impl LispEvaluator {
         }
     }
 
+    /// Whether a string in quoted data stands for a symbol rather than text
+    fn is_symbol_name(s: &str) -> bool {
+        !s.is_empty()
+            && !s.starts_with(|c: char| c.is_ascii_digit())
+            && s.chars()
+                .all(|c| c.is_alphanumeric() || "_-+*/%<>=!?&.:$".contains(c))
+    }
+
+    /// Render quoted data as S-expression source
+    fn datum_source(value: &Value) -> Result<String> {
+        Ok(match value {
+            Value::Int(n) => n.to_string(),
+            Value::Float(f) => format!("{:?}", f),
+            Value::Bool(b) => b.to_string(),
+            Value::Null => "nil".to_string(),
+            Value::String(s) if Self::is_symbol_name(s) => s.clone(),
+            Value::String(s) => format!("{:?}", s),
+            Value::Array(items) => {
+                let parts: Result<Vec<_>> = items.iter().map(Self::datum_source).collect();
+                if matches!(items.first(), Some(Value::String(s)) if Self::is_symbol_name(s)) {
+                    format!("({})", parts?.join(" "))
+                } else {
+                    format!("[{}]", parts?.join(" "))
+                }
+            }
+            other => {
+                return Err(Error::TypeError {
+                    expected: "quoted data".to_string(),
+                    got: other.type_name(),
+                })
+            }
+        })
+    }
+
     /// Evaluate quasiquote expression (template with unquote/splice)
     fn eval_quasiquote(&mut self, expr: &Expression) -> Result<Value> {
         match expr {
@@ -8160,8 +13298,9 @@ impl LispEvaluator {
 
         // 4. Execute loop
         for value in iteration_values {
+            self.check_interrupt()?;
             // Bind iteration variable
-            self.env.define(var_name.clone(), value.clone());
+            self.bind_iteration_value(&loop_data.iteration, value);
 
             // Check early exit conditions
             if let Some(early_exit) = &loop_data.early_exit {
@@ -8180,8 +13319,16 @@ impl LispEvaluator {
                 accumulator = self.perform_accumulation(accum, &var_name, accumulator)?;
             } else {
                 // Execute body expressions
-                for expr in &loop_data.body {
-                    self.evaluate_expression(expr)?;
+                match self.run_loop_body(&loop_data.body) {
+                    Ok(ControlFlow::Continue(_)) => {}
+                    Ok(ControlFlow::Break(value)) => {
+                        accumulator = value;
+                        break;
+                    }
+                    Err(error) => {
+                        self.env.exit_scope();
+                        return Err(error);
+                    }
                 }
             }
         }
@@ -8286,6 +13433,53 @@ impl LispEvaluator {
                     }),
                 }
             }
+            IterationClause::HashTable { table, values, .. } => {
+                let table = self.evaluate_expression(table)?;
+                let map = match &table {
+                    Value::Object(map) => map,
+                    _ => {
+                        return Err(Error::TypeError {
+                            expected: "hash table".to_string(),
+                            got: format!("{:?}", table),
+                        })
+                    }
+                };
+
+                // Each entry is [iterated half, other half], in key order
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                Ok(entries
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let key = Value::String(key.to_string());
+                        let pair = if *values {
+                            vec![value.clone(), key]
+                        } else {
+                            vec![key, value.clone()]
+                        };
+                        Value::array(pair)
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// Bind the loop variables for one iteration value
+    fn bind_iteration_value(&mut self, iteration: &IterationClause, value: Value) {
+        match iteration {
+            IterationClause::HashTable { var, using, .. } => {
+                let (current, other) = match &value {
+                    Value::Array(pair) if pair.len() == 2 => (pair[0].clone(), pair[1].clone()),
+                    _ => (value.clone(), Value::Null),
+                };
+                self.env.define(var.clone(), current);
+                if let Some(other_var) = using {
+                    self.env.define(other_var.clone(), other);
+                }
+            }
+            _ => self
+                .env
+                .define(self.get_iteration_var_name(iteration), value),
         }
     }
 
@@ -8294,6 +13488,7 @@ impl LispEvaluator {
         match iteration {
             IterationClause::Numeric { var, .. } => var.clone(),
             IterationClause::Collection { var, .. } => var.clone(),
+            IterationClause::HashTable { var, .. } => var.clone(),
         }
     }
 
@@ -8567,6 +13762,199 @@ impl LispEvaluator {
         Ok(Value::Float(var_val.sqrt()))
     }
 
+    /// (percentile collection p) / (quantile collection q) - Linearly interpolated
+    /// order statistic, with p in [0, 100] and q in [0, 1]
+    ///
+    /// Matches NumPy's default: the value at fractional rank q * (n - 1) of the
+    /// sorted data, so the 50th percentile is the median.
+    fn eval_quantile(&mut self, args: &[crate::parser::Argument], tool: &str) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!("Expected 2 arguments, got {}", args.len()),
+            });
+        }
+
+        let mut numbers = self.eval_numbers(&args[0].value)?;
+        let (scale, upper) = if tool == "percentile" {
+            (100.0, "100")
+        } else {
+            (1.0, "1")
+        };
+        let q = self.evaluate_expression(&args[1].value)?.as_float()?;
+        if !(0.0..=scale).contains(&q) {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!("Expected a value between 0 and {}, got {}", upper, q),
+            });
+        }
+        if numbers.is_empty() {
+            return Err(Error::EmptyCollection {
+                operation: tool.to_string(),
+            });
+        }
+
+        numbers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let rank = q / scale * (numbers.len() - 1) as f64;
+        let (lower, fraction) = (rank.floor() as usize, rank.fract());
+        let value = match numbers.get(lower + 1) {
+            Some(next) => numbers[lower] + (next - numbers[lower]) * fraction,
+            None => numbers[lower],
+        };
+        Ok(Value::Float(value))
+    }
+
+    /// (covariance xs ys) - Population covariance of two equal-length collections
+    fn eval_covariance(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let (xs, ys) = self.eval_paired_numbers(args, "covariance")?;
+        Ok(Value::Float(Self::covariance(&xs, &ys)))
+    }
+
+    /// (correlation xs ys) - Pearson correlation coefficient of two collections
+    fn eval_correlation(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let (xs, ys) = self.eval_paired_numbers(args, "correlation")?;
+        let spread = (Self::covariance(&xs, &xs) * Self::covariance(&ys, &ys)).sqrt();
+        if spread == 0.0 {
+            return Err(Error::InvalidArguments {
+                tool: "correlation".to_string(),
+                reason: "Correlation is undefined when either collection is constant".to_string(),
+            });
+        }
+        Ok(Value::Float(Self::covariance(&xs, &ys) / spread))
+    }
+
+    /// (weighted-mean values weights) - Mean of values weighted by weights
+    fn eval_weighted_mean(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        let (values, weights) = self.eval_paired_numbers(args, "weighted-mean")?;
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight == 0.0 {
+            return Err(Error::InvalidArguments {
+                tool: "weighted-mean".to_string(),
+                reason: "Weights must not sum to zero".to_string(),
+            });
+        }
+        let weighted: f64 = values.iter().zip(&weights).map(|(v, w)| v * w).sum();
+        Ok(Value::Float(weighted / total_weight))
+    }
+
+    /// (cumulative-sum collection) - Running totals: [1 2 3] => [1 3 6]
+    ///
+    /// Integers stay integers, following the same rules as `+`.
+    fn eval_cumulative_sum(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "cumulative-sum".to_string(),
+                reason: format!("Expected 1 argument, got {}", args.len()),
+            });
+        }
+
+        let collection = self.evaluate_expression(&args[0].value)?;
+        let array = collection.as_array()?;
+        let mut totals = Vec::with_capacity(array.len());
+        let mut total = Value::Int(0);
+        for val in array.iter() {
+            if !matches!(val, Value::Int(_) | Value::Float(_)) {
+                return Err(Error::TypeError {
+                    expected: "number".to_string(),
+                    got: val.type_name(),
+                });
+            }
+            total = self.apply_binary_op(BinaryOp::Add, total, val.clone())?;
+            totals.push(total.clone());
+        }
+        Ok(Value::Array(Arc::new(totals)))
+    }
+
+    /// (moving-average collection window) - Simple moving average over each
+    /// full window, so the result has window - 1 fewer elements
+    fn eval_moving_average(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "moving-average".to_string(),
+                reason: format!("Expected 2 arguments, got {}", args.len()),
+            });
+        }
+
+        let numbers = self.eval_numbers(&args[0].value)?;
+        let window = self.evaluate_expression(&args[1].value)?;
+        let window = match window {
+            Value::Int(n) if n > 0 => n as usize,
+            Value::Int(n) => {
+                return Err(Error::InvalidArguments {
+                    tool: "moving-average".to_string(),
+                    reason: format!("Window must be positive, got {}", n),
+                })
+            }
+            _ => {
+                return Err(Error::TypeError {
+                    expected: "int".to_string(),
+                    got: window.type_name(),
+                })
+            }
+        };
+
+        let averages = numbers
+            .windows(window)
+            .map(|w| Value::Float(w.iter().sum::<f64>() / window as f64))
+            .collect();
+        Ok(Value::Array(Arc::new(averages)))
+    }
+
+    /// Evaluate an expression to a collection of numbers
+    fn eval_numbers(&mut self, expr: &Expression) -> Result<Vec<f64>> {
+        let collection = self.evaluate_expression(expr)?;
+        collection
+            .as_array()?
+            .iter()
+            .map(|v| v.as_float())
+            .collect()
+    }
+
+    /// Evaluate the two numeric collections of a paired statistic, which must
+    /// be non-empty and of equal length
+    fn eval_paired_numbers(
+        &mut self,
+        args: &[crate::parser::Argument],
+        tool: &str,
+    ) -> Result<(Vec<f64>, Vec<f64>)> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!("Expected 2 arguments, got {}", args.len()),
+            });
+        }
+
+        let xs = self.eval_numbers(&args[0].value)?;
+        let ys = self.eval_numbers(&args[1].value)?;
+        if xs.len() != ys.len() {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: format!(
+                    "Collections must have equal length, got {} and {}",
+                    xs.len(),
+                    ys.len()
+                ),
+            });
+        }
+        if xs.is_empty() {
+            return Err(Error::EmptyCollection {
+                operation: tool.to_string(),
+            });
+        }
+        Ok((xs, ys))
+    }
+
+    /// Population covariance, consistent with `variance`
+    fn covariance(xs: &[f64], ys: &[f64]) -> f64 {
+        let n = xs.len() as f64;
+        let (mean_x, mean_y) = (xs.iter().sum::<f64>() / n, ys.iter().sum::<f64>() / n);
+        xs.iter()
+            .zip(ys)
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum::<f64>()
+            / n
+    }
+
     // ============================================================================
     // MATH UTILITIES
     // ============================================================================
@@ -8630,6 +14018,51 @@ impl LispEvaluator {
         }
     }
 
+    /// (lerp a b t) - Linear interpolation: a at t = 0, b at t = 1
+    fn eval_lerp(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 3 {
+            return Err(Error::InvalidArguments {
+                tool: "lerp".to_string(),
+                reason: "Expected 3 arguments: a, b, t".to_string(),
+            });
+        }
+
+        let a = self.evaluate_expression(&args[0].value)?.as_float()?;
+        let b = self.evaluate_expression(&args[1].value)?.as_float()?;
+        let t = self.evaluate_expression(&args[2].value)?.as_float()?;
+
+        // Weighted form is exact at both endpoints
+        Ok(Value::Float((1.0 - t) * a + t * b))
+    }
+
+    /// (map-range x in-min in-max out-min out-max) - Rescale x from one range to another
+    ///
+    /// Not clamped: values outside the input range extrapolate.
+    fn eval_map_range(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 5 {
+            return Err(Error::InvalidArguments {
+                tool: "map-range".to_string(),
+                reason: "Expected 5 arguments: x, in-min, in-max, out-min, out-max".to_string(),
+            });
+        }
+
+        let mut nums = [0.0; 5];
+        for (num, arg) in nums.iter_mut().zip(args) {
+            *num = self.evaluate_expression(&arg.value)?.as_float()?;
+        }
+        let [x, in_min, in_max, out_min, out_max] = nums;
+
+        if in_max == in_min {
+            return Err(Error::InvalidArguments {
+                tool: "map-range".to_string(),
+                reason: format!("Input range has zero width ({} to {})", in_min, in_max),
+            });
+        }
+
+        let t = (x - in_min) / (in_max - in_min);
+        Ok(Value::Float((1.0 - t) * out_min + t * out_max))
+    }
+
     /// (random) - Generate random number between 0 and 1
     fn eval_random(&mut self, _args: &[crate::parser::Argument]) -> Result<Value> {
         use std::collections::hash_map::RandomState;
@@ -8831,7 +14264,7 @@ impl LispEvaluator {
             });
         }
 
-        let func = self.evaluate_expression(&args[0].value)?;
+        let func = self.eval_function_designator(&args[0].value)?;
         let arg_list = self.evaluate_expression(&args[1].value)?;
         let array = arg_list.as_array()?;
 
@@ -8850,6 +14283,9 @@ impl LispEvaluator {
 
                 Ok(result)
             }
+            Value::String(_) | Value::Memoized { .. } => {
+                self.apply_function_designator(&func, array.to_vec(), "apply")
+            }
             _ => Err(Error::TypeError {
                 expected: "function".to_string(),
                 got: func.type_name(),
@@ -8857,6 +14293,116 @@ impl LispEvaluator {
         }
     }
 
+    /// (funcall f args...) - Call a function value or builtin name with arguments
+    fn eval_funcall(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Error::InvalidArguments {
+                tool: "funcall".to_string(),
+                reason: "Expected function and arguments".to_string(),
+            });
+        }
+
+        let func = self.eval_function_designator(&args[0].value)?;
+        let mut values = Vec::with_capacity(args.len() - 1);
+        for arg in &args[1..] {
+            values.push(self.evaluate_expression(&arg.value)?);
+        }
+        self.apply_function_designator(&func, values, "funcall")
+    }
+
+    /// (register-tool name fn) - Add a lambda-backed tool to the registry
+    /// The name may be a bare symbol or a string; returns the name
+    fn eval_register_tool(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 2 {
+            return Err(Error::InvalidArguments {
+                tool: "register-tool".to_string(),
+                reason: "Expected a tool name and a function".to_string(),
+            });
+        }
+
+        let name = match &args[0].value {
+            Expression::Variable(name) => name.clone(),
+            expr => match self.evaluate_expression(expr)? {
+                Value::String(name) => name,
+                other => {
+                    return Err(Error::TypeError {
+                        expected: "symbol or string".to_string(),
+                        got: other.type_name(),
+                    })
+                }
+            },
+        };
+        let func = self.evaluate_expression(&args[1].value)?;
+        if !matches!(func, Value::Function { .. } | Value::Memoized { .. }) {
+            return Err(Error::TypeError {
+                expected: "function".to_string(),
+                got: func.type_name(),
+            });
+        }
+
+        let runner = self.script_tool_runner(&name, &func);
+        Arc::make_mut(&mut self.registry).register(ScriptTool::new(name.clone(), func, runner));
+        Ok(Value::String(name))
+    }
+
+    /// Runner for a script tool called through the registry rather than by
+    /// this evaluator: applies `func` in a fresh evaluator seeded with this
+    /// one's bindings of the names its body refers to
+    fn script_tool_runner(&self, name: &str, func: &Value) -> ScriptRunner {
+        let mut inner = func;
+        while let Value::Memoized { func, .. } = inner {
+            inner = func;
+        }
+        let captured = match inner {
+            Value::Function { body, .. } => self.free_bindings(body),
+            _ => HashMap::new(),
+        };
+        let registry = Arc::clone(&self.registry);
+        let http_client = self.http_client.clone();
+        let name = name.to_string();
+        let func = func.clone();
+
+        Arc::new(move |args: &[Value]| {
+            let mut evaluator =
+                LispEvaluator::from_parts(Arc::clone(&registry), http_client.clone());
+            for (var_name, var_value) in &captured {
+                evaluator.env.define(var_name.clone(), var_value.clone());
+            }
+            evaluator.call_function(&func, args, &name)
+        })
+    }
+
+    /// (function name) / #'name - Look up the function bound to a name
+    /// Names without a user-defined function resolve to the builtin of that name
+    fn eval_function_ref(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "function".to_string(),
+                reason: "Expected 1 argument: function name or lambda".to_string(),
+            });
+        }
+
+        match &args[0].value {
+            Expression::Variable(name) => {
+                let resolved = self.resolve_symbol(name)?;
+                match self.env.get(&resolved) {
+                    Ok(func @ (Value::Function { .. } | Value::Memoized { .. })) => Ok(func),
+                    _ => Ok(Value::String(name.clone())),
+                }
+            }
+            other => {
+                let func = self.evaluate_expression(other)?;
+                match func {
+                    Value::Function { .. } | Value::Memoized { .. } => Ok(func),
+                    v => Err(Error::TypeError {
+                        expected: "function".to_string(),
+                        got: v.type_name(),
+                    }),
+                }
+            }
+        }
+    }
+
     /// (compose f g) - Function composition: (compose f g)(x) = f(g(x))
     fn eval_compose(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
         if args.len() != 2 {
@@ -9361,7 +14907,9 @@ impl LispEvaluator {
 
     /// (make-thread fn &key name) - Create and start a new thread
     ///
-    /// Creates a new OS thread that executes the given function.
+    /// Runs the given function on a new OS thread, or on the shared pool once
+    /// `set-thread-pool-size` has configured one. The thread sees the
+    /// variables visible where it was spawned.
     /// Returns a thread handle that can be joined later.
     ///
     /// Example:
@@ -9410,55 +14958,175 @@ impl LispEvaluator {
                 ..
             } => {
                 let thread_id = threading::generate_thread_id();
-                let thread_id_clone = thread_id.clone();
                 let name_clone = name.clone();
 
                 // Clone for thread
                 let params_clone = params.clone();
                 let body_clone = Arc::clone(&body);
                 let closure_clone = Arc::clone(&closure);
+                // Lambdas don't capture, so hand over the spawner's bindings
+                // of the names the body refers to
+                let captured = self.free_bindings(&body);
+                let registry = Arc::clone(&self.registry);
+                let http_client = self.http_client.clone();
+                let pool = self.thread_pool.clone();
+                let child_pool = pool.clone();
+
+                // Run on this evaluator's pool when one is configured
+                Ok(threading::spawn_thread(
+                    pool.as_deref(),
+                    thread_id,
+                    name_clone,
+                    move || {
+                        // Create isolated evaluator
+                        let mut evaluator = LispEvaluator::from_parts(registry, http_client);
+                        evaluator.thread_pool = child_pool;
+
+                        // Restore the spawner's bindings, then the closure environment
+                        for (var_name, var_value) in captured.into_iter() {
+                            evaluator.env.define(var_name, var_value);
+                        }
+                        for (var_name, var_value) in closure_clone.iter() {
+                            evaluator.env.define(var_name.clone(), var_value.clone());
+                        }
 
-                // Spawn OS thread
-                let handle = std::thread::spawn(move || {
-                    // Set thread ID
-                    threading::set_current_thread_id(thread_id_clone);
-
-                    // Create isolated evaluator
-                    let mut evaluator = LispEvaluator::new();
+                        // Execute (no args for parameterless lambda)
+                        if params_clone.is_empty() {
+                            match evaluator.evaluate_expression(&body_clone) {
+                                Ok(val) => val,
+                                Err(e) => {
+                                    eprintln!("Thread error: {}", e);
+                                    Value::Null
+                                }
+                            }
+                        } else {
+                            // For functions with params, we'd need args passed differently
+                            // For now, just run the body
+                            match evaluator.evaluate_expression(&body_clone) {
+                                Ok(val) => val,
+                                Err(e) => {
+                                    eprintln!("Thread error: {}", e);
+                                    Value::Null
+                                }
+                            }
+                        }
+                    },
+                ))
+            }
+            _ => Err(Error::TypeError {
+                expected: "function".to_string(),
+                got: func.type_name(),
+            }),
+        }
+    }
 
-                    // Restore closure environment
-                    for (var_name, var_value) in closure_clone.iter() {
-                        evaluator.env.define(var_name.clone(), var_value.clone());
-                    }
+    /// Bindings a thread running `body` needs from this evaluator: every
+    /// name the body refers to, and those referred to by the bodies of the
+    /// functions it picks up
+    fn free_bindings(&self, body: &Expression) -> HashMap<String, Value> {
+        let mut names = Vec::new();
+        Self::collect_names(body, &mut names);
+        let mut bindings = HashMap::new();
+        while let Some(name) = names.pop() {
+            if bindings.contains_key(&name) {
+                continue;
+            }
+            if let Ok(value) = self.env.get(&name) {
+                if let Value::Function { body, .. } = &value {
+                    Self::collect_names(body, &mut names);
+                }
+                bindings.insert(name, value);
+            }
+        }
+        bindings
+    }
 
-                    // Execute (no args for parameterless lambda)
-                    if params_clone.is_empty() {
-                        match evaluator.evaluate_expression(&body_clone) {
-                            Ok(val) => val,
-                            Err(e) => {
-                                eprintln!("Thread error: {}", e);
-                                Value::Null
-                            }
-                        }
-                    } else {
-                        // For functions with params, we'd need args passed differently
-                        // For now, just run the body
-                        match evaluator.evaluate_expression(&body_clone) {
-                            Ok(val) => val,
-                            Err(e) => {
-                                eprintln!("Thread error: {}", e);
-                                Value::Null
-                            }
-                        }
+    /// Push every variable, call target and string in `expr` onto `names`.
+    /// Strings are included because quoted symbols can name functions.
+    fn collect_names(expr: &Expression, names: &mut Vec<String>) {
+        let mut walk = |e: &Expression| Self::collect_names(e, names);
+        match expr {
+            Expression::Variable(name) | Expression::StringLiteral(name) => {
+                names.push(name.clone())
+            }
+            Expression::ToolCall { name, args } => {
+                names.push(name.clone());
+                for arg in args {
+                    Self::collect_names(&arg.value, names);
+                }
+            }
+            Expression::ArrayLiteral(items) => items.iter().for_each(walk),
+            Expression::ObjectLiteral(fields) => fields.iter().for_each(|(_, e)| walk(e)),
+            Expression::Range { start, end } => {
+                walk(start);
+                walk(end);
+            }
+            Expression::Binary { left, right, .. } => {
+                walk(left);
+                walk(right);
+            }
+            Expression::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+            } => {
+                walk(condition);
+                walk(then_expr);
+                walk(else_expr);
+            }
+            Expression::Unary { operand: e, .. }
+            | Expression::Lambda { body: e, .. }
+            | Expression::TypedLambda { body: e, .. }
+            | Expression::FieldAccess { object: e, .. }
+            | Expression::Grouping(e)
+            | Expression::Quasiquote(e)
+            | Expression::Unquote(e)
+            | Expression::UnquoteSplice(e)
+            | Expression::TypeAnnotation { expr: e, .. } => walk(e),
+            Expression::IndexAccess { array, index } => {
+                walk(array);
+                walk(index);
+            }
+            Expression::Catch { tag, body } => {
+                walk(tag);
+                body.iter().for_each(walk);
+            }
+            Expression::Throw { tag, value } => {
+                walk(tag);
+                walk(value);
+            }
+            Expression::DestructuringBind { value, body, .. } => {
+                walk(value);
+                body.iter().for_each(walk);
+            }
+            Expression::Loop(data) => {
+                match &data.iteration {
+                    IterationClause::Numeric { from, to, by, .. } => {
+                        walk(from);
+                        walk(to);
+                        by.iter().for_each(|e| walk(e));
                     }
-                });
-
-                Ok(threading::make_thread_value(thread_id, name_clone, handle))
+                    IterationClause::Collection { collection, .. } => walk(collection),
+                    IterationClause::HashTable { table, .. } => walk(table),
+                }
+                if let Some(
+                    AccumulationClause::Sum(Some(e))
+                    | AccumulationClause::Collect(Some(e))
+                    | AccumulationClause::Count(Some(e)),
+                ) = &data.accumulation
+                {
+                    walk(e);
+                }
+                if let Some(ConditionClause::When(e) | ConditionClause::Unless(e)) = &data.condition
+                {
+                    walk(e);
+                }
+                if let Some(ExitClause::While(e) | ExitClause::Until(e)) = &data.early_exit {
+                    walk(e);
+                }
+                data.body.iter().for_each(walk);
             }
-            _ => Err(Error::TypeError {
-                expected: "function".to_string(),
-                got: func.type_name(),
-            }),
+            _ => {}
         }
     }
 
@@ -9538,6 +15206,33 @@ impl LispEvaluator {
         Ok(Value::Null)
     }
 
+    /// (set-thread-pool-size n) - Run make-thread tasks on n pooled workers
+    /// (0 restores one OS thread per task)
+    fn eval_set_thread_pool_size(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() != 1 {
+            return Err(Error::InvalidArguments {
+                tool: "set-thread-pool-size".to_string(),
+                reason: "Expected 1 argument: pool size".to_string(),
+            });
+        }
+        match self.evaluate_expression(&args[0].value)? {
+            Value::Int(n) if n >= 0 => {
+                self.thread_pool =
+                    (n > 0).then(|| Arc::new(threading::ThreadPool::new(n as usize)));
+                Ok(Value::Int(n))
+            }
+            Value::Int(n) => Err(Error::InvalidArguments {
+                tool: "set-thread-pool-size".to_string(),
+                reason: format!("Pool size must be non-negative, got {}", n),
+            }),
+            other => Err(Error::TypeError {
+                expected: "int".to_string(),
+                got: other.type_name(),
+            }),
+        }
+    }
+
     // -------------------------------------------------------------------------
     // Lock Functions
     // -------------------------------------------------------------------------
@@ -10038,6 +15733,385 @@ impl LispEvaluator {
             &ai, expected, new_value,
         )?))
     }
+
+    // -------------------------------------------------------------------------
+    // Atomic Reference Functions
+    // -------------------------------------------------------------------------
+
+    /// (make-atomic value) - Create an atomic reference holding value
+    fn eval_make_atomic(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() != 1 {
+            return Err(Error::runtime(
+                "make-atomic requires 1 argument: initial value".to_string(),
+            ));
+        }
+        let value = self.evaluate_expression(&args[0].value)?;
+        Ok(threading::make_atomic_ref(value))
+    }
+
+    /// (atomicp obj) - Check if obj is an atomic reference
+    fn eval_atomicp(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Error::runtime("atomicp requires 1 argument".to_string()));
+        }
+        let val = self.evaluate_expression(&args[0].value)?;
+        Ok(Value::Bool(matches!(val, Value::AtomicRef { .. })))
+    }
+
+    /// (atomic-get r) - Current value of an atomic reference
+    fn eval_atomic_get(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() != 1 {
+            return Err(Error::runtime(
+                "atomic-get requires 1 argument: atomic".to_string(),
+            ));
+        }
+        let r = self.evaluate_expression(&args[0].value)?;
+        Ok(threading::atomic_ref_load(&r)?.1)
+    }
+
+    /// (atomic-set r value) - Replace the value, returning it
+    fn eval_atomic_set(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() != 2 {
+            return Err(Error::runtime(
+                "atomic-set requires 2 arguments: atomic, value".to_string(),
+            ));
+        }
+        let r = self.evaluate_expression(&args[0].value)?;
+        let value = self.evaluate_expression(&args[1].value)?;
+        threading::atomic_ref_store(&r, value.clone())?;
+        Ok(value)
+    }
+
+    /// (atomic-update r fn &rest args) - Set the value to (fn current args...)
+    ///
+    /// `fn` runs without holding any lock; if another thread writes in the
+    /// meantime the result is discarded and `fn` is retried on the new value,
+    /// so it should be free of side effects. Returns the value stored.
+    fn eval_atomic_update(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() < 2 {
+            return Err(Error::runtime(
+                "atomic-update requires at least 2 arguments: atomic, function".to_string(),
+            ));
+        }
+        let r = self.evaluate_expression(&args[0].value)?;
+        let func = self.eval_function_designator(&args[1].value)?;
+        Self::check_function_arity("atomic-update", &func, args.len() - 1, "Update function")?;
+        let mut extra = Vec::with_capacity(args.len() - 2);
+        for arg in &args[2..] {
+            extra.push(self.evaluate_expression(&arg.value)?);
+        }
+
+        loop {
+            self.check_interrupt()?;
+            let (version, current) = threading::atomic_ref_load(&r)?;
+            let mut call_args = Vec::with_capacity(extra.len() + 1);
+            call_args.push(current);
+            call_args.extend(extra.iter().cloned());
+            let updated = self.apply_function_designator(&func, call_args, "atomic-update")?;
+            if threading::atomic_ref_cas(&r, version, updated.clone())? {
+                return Ok(updated);
+            }
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Channel Functions
+    // -------------------------------------------------------------------------
+
+    /// (make-channel &optional capacity) - Create a FIFO channel
+    ///
+    /// With a capacity, `channel-send` waits while that many values are
+    /// buffered, and capacity 0 hands each value to a waiting
+    /// `channel-receive`; without one the channel is unbounded.
+    fn eval_make_channel(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() > 1 {
+            return Err(Error::runtime(
+                "make-channel takes at most 1 argument: capacity".to_string(),
+            ));
+        }
+        let capacity = match args.first() {
+            None => None,
+            Some(arg) => match self.evaluate_expression(&arg.value)? {
+                Value::Null => None,
+                Value::Int(n) if n >= 0 => Some(n as usize),
+                Value::Int(n) => {
+                    return Err(Error::InvalidArguments {
+                        tool: "make-channel".to_string(),
+                        reason: format!("Capacity must be non-negative, got {}", n),
+                    })
+                }
+                other => {
+                    return Err(Error::TypeError {
+                        expected: "int".to_string(),
+                        got: other.type_name(),
+                    })
+                }
+            },
+        };
+        Ok(threading::make_channel(capacity))
+    }
+
+    /// (channelp obj) - Check if obj is a channel
+    fn eval_channelp(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Error::runtime("channelp requires 1 argument".to_string()));
+        }
+        let val = self.evaluate_expression(&args[0].value)?;
+        Ok(Value::Bool(matches!(val, Value::Channel { .. })))
+    }
+
+    /// (channel-send ch value) - Send a value, waiting while the channel is full
+    fn eval_channel_send(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() != 2 {
+            return Err(Error::runtime(
+                "channel-send requires 2 arguments: channel, value".to_string(),
+            ));
+        }
+        let ch = self.evaluate_expression(&args[0].value)?;
+        let value = self.evaluate_expression(&args[1].value)?;
+        let mut pending = value.clone();
+        // Poll rather than block so a full channel can't outlive a cancel or timeout
+        while let Some(unsent) = threading::channel_try_send(&ch, pending)? {
+            self.check_interrupt()?;
+            std::thread::sleep(SLEEP_POLL_INTERVAL);
+            pending = unsent;
+        }
+        Ok(value)
+    }
+
+    /// Channel and closed value of `channel-receive`/`channel-try-receive`
+    fn channel_receive_args(
+        &mut self,
+        tool: &str,
+        args: &[crate::parser::Argument],
+    ) -> Result<(Value, Value)> {
+        if args.is_empty() || args.len() > 2 {
+            return Err(Error::InvalidArguments {
+                tool: tool.to_string(),
+                reason: "Expected a channel and an optional closed value".to_string(),
+            });
+        }
+        let ch = self.evaluate_expression(&args[0].value)?;
+        let closed = match args.get(1) {
+            Some(arg) => self.evaluate_expression(&arg.value)?,
+            None => Value::String(":closed".to_string()),
+        };
+        Ok((ch, closed))
+    }
+
+    /// (channel-receive ch [closed-value]) - Wait for the next value
+    ///
+    /// Returns `closed-value` (default `:closed`) once the channel is closed
+    /// and drained; pass one that is never sent to tell the two apart.
+    fn eval_channel_receive(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading::{self, Received};
+        let (ch, closed) = self.channel_receive_args("channel-receive", args)?;
+        loop {
+            self.check_interrupt()?;
+            match threading::channel_receive(&ch, Some(SLEEP_POLL_INTERVAL))? {
+                Received::Value(value) => return Ok(value),
+                Received::Closed => return Ok(closed),
+                Received::Empty => {}
+            }
+        }
+    }
+
+    /// (channel-try-receive ch [closed-value]) - Take a ready value, or null
+    /// if there is none
+    ///
+    /// Returns `closed-value` (default `:closed`) once the channel is closed
+    /// and drained.
+    fn eval_channel_try_receive(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading::{self, Received};
+        let (ch, closed) = self.channel_receive_args("channel-try-receive", args)?;
+        Ok(match threading::channel_try_receive(&ch)? {
+            Received::Value(value) => value,
+            Received::Empty => Value::Null,
+            Received::Closed => closed,
+        })
+    }
+
+    /// (channel-closed? ch) - Check whether `close-channel` has been called
+    fn eval_channel_closed_p(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() != 1 {
+            return Err(Error::runtime(
+                "channel-closed? requires 1 argument: channel".to_string(),
+            ));
+        }
+        let ch = self.evaluate_expression(&args[0].value)?;
+        Ok(Value::Bool(threading::channel_closed(&ch)?))
+    }
+
+    /// (close-channel ch) - Stop accepting values; receivers drain what is
+    /// buffered and then get their closed value
+    fn eval_close_channel(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() != 1 {
+            return Err(Error::runtime(
+                "close-channel requires 1 argument: channel".to_string(),
+            ));
+        }
+        let ch = self.evaluate_expression(&args[0].value)?;
+        threading::close_channel(&ch)?;
+        Ok(Value::Null)
+    }
+
+    // -------------------------------------------------------------------------
+    // Promise Functions
+    // -------------------------------------------------------------------------
+
+    /// (promise) - Create an unresolved promise
+    fn eval_promise(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if !args.is_empty() {
+            return Err(Error::runtime("promise takes no arguments".to_string()));
+        }
+        Ok(threading::make_promise())
+    }
+
+    /// (promisep obj) - Check if obj is a promise
+    fn eval_promisep(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Error::runtime("promisep requires 1 argument".to_string()));
+        }
+        let val = self.evaluate_expression(&args[0].value)?;
+        Ok(Value::Bool(matches!(val, Value::Promise { .. })))
+    }
+
+    /// (deliver p value) - Fulfil a promise
+    ///
+    /// Returns false if the promise was already delivered, leaving it unchanged.
+    fn eval_deliver(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() != 2 {
+            return Err(Error::runtime(
+                "deliver requires 2 arguments: promise, value".to_string(),
+            ));
+        }
+        let promise = self.evaluate_expression(&args[0].value)?;
+        let value = self.evaluate_expression(&args[1].value)?;
+        let Value::Promise { inner } = &promise else {
+            return Err(Error::TypeError {
+                expected: "promise".to_string(),
+                got: promise.type_name(),
+            });
+        };
+        Ok(Value::Bool(threading::deliver_promise(inner, value)))
+    }
+
+    /// (promise-deref p &optional timeout default) - Wait for a promise's value
+    ///
+    /// `timeout` is in seconds; if it passes first, `default` (null unless
+    /// given) is returned. Async handles are accepted and awaited.
+    fn eval_promise_deref(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.is_empty() || args.len() > 3 {
+            return Err(Error::runtime(
+                "promise-deref requires 1 to 3 arguments: promise, timeout, default".to_string(),
+            ));
+        }
+        let promise = self.evaluate_expression(&args[0].value)?;
+        let inner = threading::promise_inner(&promise)?;
+        let deadline = match args.get(1) {
+            None => None,
+            Some(arg) => {
+                let seconds = match self.evaluate_expression(&arg.value)? {
+                    Value::Int(n) => n as f64,
+                    Value::Float(f) => f,
+                    other => {
+                        return Err(Error::TypeError {
+                            expected: "number".to_string(),
+                            got: other.type_name(),
+                        })
+                    }
+                };
+                let timeout = std::time::Duration::try_from_secs_f64(seconds).map_err(|_| {
+                    Error::InvalidArguments {
+                        tool: "promise-deref".to_string(),
+                        reason: format!(
+                            "Expected a non-negative number of seconds, got {}",
+                            seconds
+                        ),
+                    }
+                })?;
+                Some(std::time::Instant::now() + timeout)
+            }
+        };
+        let default = match args.get(2) {
+            Some(arg) => self.evaluate_expression(&arg.value)?,
+            None => Value::Null,
+        };
+
+        loop {
+            self.check_interrupt()?;
+            let slice = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+                    remaining.min(SLEEP_POLL_INTERVAL)
+                }
+                None => SLEEP_POLL_INTERVAL,
+            };
+            if let Some(value) = threading::promise_wait(&inner, Some(slice)) {
+                return Ok(value);
+            }
+            if deadline.is_some_and(|deadline| std::time::Instant::now() >= deadline) {
+                return Ok(default);
+            }
+        }
+    }
+
+    /// (promise-realized? p) - Check whether a promise has been delivered
+    fn eval_promise_realized(&mut self, args: &[crate::parser::Argument]) -> Result<Value> {
+        use crate::runtime::threading;
+        if args.len() != 1 {
+            return Err(Error::runtime(
+                "promise-realized? requires 1 argument: promise".to_string(),
+            ));
+        }
+        let promise = self.evaluate_expression(&args[0].value)?;
+        let Value::Promise { inner } = &promise else {
+            return Err(Error::TypeError {
+                expected: "promise".to_string(),
+                got: promise.type_name(),
+            });
+        };
+        Ok(Value::Bool(threading::promise_realized(inner)))
+    }
+
+    /// (all-of p1 p2 ...) / (any-of p1 p2 ...) - Combine promises
+    ///
+    /// `all-of` resolves to an array of every value, in argument order, once
+    /// all are delivered; `any-of` resolves to the first value delivered. A
+    /// single array argument is treated as the list of promises.
+    fn eval_promise_combinator(
+        &mut self,
+        tool: &str,
+        args: &[crate::parser::Argument],
+    ) -> Result<Value> {
+        use crate::runtime::threading;
+        let mut values = Vec::with_capacity(args.len());
+        for arg in args {
+            values.push(self.evaluate_expression(&arg.value)?);
+        }
+        if let [Value::Array(items)] = values.as_slice() {
+            values = items.to_vec();
+        }
+        let promises = values
+            .iter()
+            .map(threading::promise_inner)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(match tool {
+            "all-of" => threading::all_of(promises),
+            _ => threading::any_of(promises),
+        })
+    }
 }
 
 impl Default for LispEvaluator {
@@ -10096,4 +16170,76 @@ mod tests {
         let result = eval_str("(log :message \"Hello, World!\")");
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_array_steps_share_the_array() {
+        let mut evaluator = LispEvaluator::new();
+        let items = Arc::new(vec![Value::Int(1), Value::Int(2), Value::Int(3)]);
+
+        let (first, rest) = evaluator
+            .seq_step(&Value::Array(items.clone()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(first, Value::Int(1));
+        let Value::LazySeq(cell) = &rest else {
+            panic!("expected a cursor, got {:?}", rest);
+        };
+        match &*cell.lock().unwrap() {
+            LazyState::Pending(LazyThunk::Items {
+                items: shared,
+                start,
+            }) => {
+                assert!(Arc::ptr_eq(shared, &items));
+                assert_eq!(*start, 1);
+            }
+            other => panic!("expected an array cursor, got {:?}", other),
+        }
+
+        let (second, _) = evaluator.seq_step(&rest).unwrap().unwrap();
+        assert_eq!(second, Value::Int(2));
+    }
+
+    #[test]
+    fn test_builtin_names_come_from_dispatch_table() {
+        let evaluator = LispEvaluator::new();
+        for name in ["set!", "intern-stats", "channel-closed?", "+", "%"] {
+            assert!(evaluator.is_builtin_name(name), "{}", name);
+        }
+        assert!(!evaluator.is_builtin_name("no-such-builtin"));
+    }
+
+    /// The line `println` would emit for the single call in `source`
+    fn println_text(source: &str) -> Result<String> {
+        let tokens = SExprScanner::new(source).scan_tokens()?;
+        let program = SExprParser::new(tokens).parse()?;
+        let Some(Statement::Expression(Expression::ToolCall { args, .. })) =
+            program.statements.first()
+        else {
+            panic!("expected a single call in {}", source);
+        };
+        LispEvaluator::new().print_text("println", args)
+    }
+
+    #[test]
+    fn test_println_joins_arguments_with_spaces() {
+        assert_eq!(
+            println_text(r#"(println "x =" 42 "y =" 7)"#).unwrap(),
+            "x = 42 y = 7"
+        );
+        assert_eq!(
+            println_text(r#"(println "list:" [1 "a"] null 1.5)"#).unwrap(),
+            r#"list: [1, "a"] null 1.5"#
+        );
+        assert_eq!(println_text("(println)").unwrap(), "");
+    }
+
+    #[test]
+    fn test_println_custom_separator() {
+        assert_eq!(
+            println_text(r#"(println "a" "b" "c" :sep ", ")"#).unwrap(),
+            "a, b, c"
+        );
+        assert_eq!(println_text(r#"(println :sep "" 1 2 3)"#).unwrap(), "123");
+        assert!(println_text(r#"(println 1 2 :sep 0)"#).is_err());
+    }
 }
@@ -1,12 +1,17 @@
 //! Runtime execution for Solisp programs using LISP-style evaluation
 
+mod cancellation;
 mod environment;
+mod hashable;
+pub mod intern;
 mod lisp_evaluator;
 pub mod streaming;
 pub mod threading;
 mod value;
 
-pub use environment::Environment;
+pub use cancellation::CancellationToken;
+pub use environment::{EnvSnapshot, Environment};
+pub use hashable::HashableValue;
 pub use lisp_evaluator::LispEvaluator;
 pub use threading::*;
-pub use value::{SemaphoreInner, Value};
+pub use value::{LazyState, LazyThunk, SemaphoreInner, Value};
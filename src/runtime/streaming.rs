@@ -529,7 +529,7 @@ fn json_to_value(json: &JsonValue) -> Value {
         JsonValue::Object(obj) => {
             let mut map = HashMap::new();
             for (k, v) in obj.iter() {
-                map.insert(k.clone(), json_to_value(v));
+                map.insert(k.as_str().into(), json_to_value(v));
             }
             Value::Object(Arc::new(map))
         }
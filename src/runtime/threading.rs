@@ -6,11 +6,15 @@
 //! ## Features
 //!
 //! - **Threads**: Create and manage OS threads with `make-thread`, `join-thread`, etc.
+//! - **Thread Pool**: Route `make-thread` through a bounded pool with `set-thread-pool-size`
 //! - **Locks**: Non-recursive mutexes with `make-lock`, `acquire-lock`, `release-lock`
 //! - **Recursive Locks**: Reentrant mutexes with `make-recursive-lock`
 //! - **Condition Variables**: Thread synchronization with `condition-wait`, `condition-notify`
 //! - **Semaphores**: Counting semaphores with `make-semaphore`, `signal-semaphore`, `wait-on-semaphore`
 //! - **Atomic Integers**: Lock-free operations with `atomic-integer-incf`, `atomic-integer-cas`
+//! - **Atomic References**: Compare-and-swap updates of any value with `make-atomic`, `atomic-update`
+//! - **Channels**: FIFO message passing with `make-channel`, `channel-send`, `channel-receive`
+//! - **Promises**: Write-once results with `promise`, `deliver`, `all-of`, `any-of`
 //!
 //! ## Example
 //!
@@ -30,6 +34,7 @@ use crate::error::{Error, Result};
 use crate::runtime::Value;
 use dashmap::DashMap;
 use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::mpsc;
 use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use std::time::Duration;
@@ -52,6 +57,9 @@ lazy_static::lazy_static! {
 thread_local! {
     /// Current thread's ID
     static CURRENT_THREAD_ID: std::cell::RefCell<String> = std::cell::RefCell::new("thread_main".to_string());
+
+    /// Whether this OS thread is a thread pool worker
+    static IS_POOL_WORKER: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
 }
 
 /// Information about a registered thread
@@ -65,6 +73,165 @@ pub struct ThreadInfo {
     pub is_alive: Arc<Mutex<bool>>,
 }
 
+/// How `join-thread` collects a thread's result
+#[derive(Debug)]
+pub enum ThreadHandle {
+    /// Dedicated OS thread
+    Os(thread::JoinHandle<Value>),
+    /// Task queued on the thread pool; its result is sent on completion
+    Pooled {
+        /// Receives the task's result
+        result: mpsc::Receiver<Value>,
+        /// Queue of the pool the task was submitted to
+        queue: JobQueue,
+    },
+}
+
+impl ThreadHandle {
+    /// Block until the thread finishes and return its result
+    fn join(self) -> Result<Value> {
+        match self {
+            ThreadHandle::Os(handle) => handle.join().map_err(|_| Error::ThreadJoinFailed),
+            // The sender is dropped without a value only if the task panicked
+            ThreadHandle::Pooled { result, queue } if IS_POOL_WORKER.with(|w| w.get()) => {
+                join_on_worker(&result, &queue)
+            }
+            ThreadHandle::Pooled { result, .. } => {
+                result.recv().map_err(|_| Error::ThreadJoinFailed)
+            }
+        }
+    }
+}
+
+/// Wait for a pooled task from inside a pool worker
+///
+/// Blocking would hold the worker the task may be queued behind, so the
+/// waiting worker runs queued jobs itself until the result arrives.
+fn join_on_worker(result: &mpsc::Receiver<Value>, queue: &JobQueue) -> Result<Value> {
+    loop {
+        match result.try_recv() {
+            Ok(value) => return Ok(value),
+            Err(mpsc::TryRecvError::Disconnected) => return Err(Error::ThreadJoinFailed),
+            Err(mpsc::TryRecvError::Empty) => {}
+        }
+        // Idle workers block holding the queue, so only take a job if it is free
+        let job = queue.try_lock().ok().and_then(|jobs| jobs.try_recv().ok());
+        match job {
+            Some(job) => {
+                let id = current_thread_id();
+                let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(job));
+                set_current_thread_id(id);
+            }
+            None => match result.recv_timeout(Duration::from_millis(1)) {
+                Ok(value) => return Ok(value),
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Err(Error::ThreadJoinFailed),
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+            },
+        }
+    }
+}
+
+// =============================================================================
+// Thread Pool
+// =============================================================================
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Pending jobs of a pool, shared by its workers
+type JobQueue = Arc<Mutex<mpsc::Receiver<Job>>>;
+
+/// Fixed set of worker threads pulling jobs from a shared queue
+///
+/// Each evaluator owns its pool (set with `set-thread-pool-size`) and shares
+/// it with the evaluators of the threads it starts.
+///
+/// Tasks already queued when a pool is replaced still run to completion. A
+/// pooled task that joins another pooled task runs queued jobs while it
+/// waits, so nested spawns and joins don't deadlock a saturated pool.
+#[derive(Debug)]
+pub struct ThreadPool {
+    size: usize,
+    sender: mpsc::Sender<Job>,
+    queue: JobQueue,
+}
+
+impl ThreadPool {
+    /// Start `size` workers
+    pub fn new(size: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..size {
+            let receiver = Arc::clone(&receiver);
+            // Workers exit once the pool is replaced and the queue drains
+            thread::spawn(move || {
+                IS_POOL_WORKER.with(|w| w.set(true));
+                loop {
+                    let job = match receiver.lock() {
+                        Ok(queue) => queue.recv(),
+                        Err(_) => return,
+                    };
+                    match job {
+                        // A panicking task drops its result sender, failing its
+                        // join, but must not take the worker down with it
+                        Ok(job) => {
+                            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(job));
+                        }
+                        Err(_) => return,
+                    }
+                }
+            });
+        }
+        ThreadPool {
+            size,
+            sender,
+            queue: receiver,
+        }
+    }
+
+    /// Number of workers
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// Start `work` as thread `id`, on `pool` if one is given and on a new OS
+/// thread otherwise
+pub fn spawn_thread<F>(
+    pool: Option<&ThreadPool>,
+    id: String,
+    name: Option<String>,
+    work: F,
+) -> Value
+where
+    F: FnOnce() -> Value + Send + 'static,
+{
+    let thread_id = id.clone();
+    let run = move || {
+        set_current_thread_id(thread_id);
+        work()
+    };
+
+    let handle = match pool {
+        Some(pool) => {
+            let (sender, receiver) = mpsc::channel();
+            let job: Job = Box::new(move || {
+                let _ = sender.send(run());
+            });
+            // The queue only closes if every worker is gone; run it directly then
+            if let Err(mpsc::SendError(job)) = pool.sender.send(job) {
+                thread::spawn(job);
+            }
+            ThreadHandle::Pooled {
+                result: receiver,
+                queue: Arc::clone(&pool.queue),
+            }
+        }
+        None => ThreadHandle::Os(thread::spawn(run)),
+    };
+
+    make_thread_value(id, name, handle)
+}
+
 // =============================================================================
 // Thread ID Generation
 // =============================================================================
@@ -95,11 +262,7 @@ pub fn set_current_thread_id(id: String) {
 ///
 /// The thread will be started immediately and run the provided function.
 /// Returns a Thread value that can be joined later.
-pub fn make_thread_value(
-    id: String,
-    name: Option<String>,
-    handle: thread::JoinHandle<Value>,
-) -> Value {
+pub fn make_thread_value(id: String, name: Option<String>, handle: ThreadHandle) -> Value {
     let is_alive = Arc::new(Mutex::new(true));
 
     // Register thread
@@ -156,7 +319,7 @@ pub fn join_thread(thread: &Value) -> Result<Value> {
             match join_handle {
                 Some(h) => {
                     // Join the thread
-                    let thread_result = h.join().map_err(|_| Error::ThreadJoinFailed)?;
+                    let thread_result = h.join()?;
 
                     // Store result
                     {
@@ -545,6 +708,313 @@ pub fn atomic_integer_cas(ai: &Value, expected: i64, new_value: i64) -> Result<b
     }
 }
 
+// =============================================================================
+// Atomic Reference Functions
+// =============================================================================
+
+/// Create an atomic reference holding `value`
+pub fn make_atomic_ref(value: Value) -> Value {
+    Value::AtomicRef {
+        inner: Arc::new(Mutex::new((0, value))),
+    }
+}
+
+fn atomic_ref_cell(r: &Value) -> Result<&Mutex<(u64, Value)>> {
+    match r {
+        Value::AtomicRef { inner } => Ok(inner),
+        _ => Err(Error::TypeError {
+            expected: "atomic-ref".to_string(),
+            got: r.type_name(),
+        }),
+    }
+}
+
+/// Current value of an atomic reference, with the version it was read at
+pub fn atomic_ref_load(r: &Value) -> Result<(u64, Value)> {
+    Ok(atomic_ref_cell(r)?.lock().unwrap().clone())
+}
+
+/// Unconditionally replace an atomic reference's value
+pub fn atomic_ref_store(r: &Value, value: Value) -> Result<()> {
+    let mut cell = atomic_ref_cell(r)?.lock().unwrap();
+    *cell = (cell.0.wrapping_add(1), value);
+    Ok(())
+}
+
+/// Store `value` only if nothing was written since `version` was loaded
+pub fn atomic_ref_cas(r: &Value, version: u64, value: Value) -> Result<bool> {
+    let mut cell = atomic_ref_cell(r)?.lock().unwrap();
+    if cell.0 != version {
+        return Ok(false);
+    }
+    *cell = (version.wrapping_add(1), value);
+    Ok(true)
+}
+
+// =============================================================================
+// Channel Functions
+// =============================================================================
+
+use crate::runtime::value::{ChannelInner, ChannelSender};
+
+/// Outcome of taking a value from a channel
+#[derive(Debug, Clone, PartialEq)]
+pub enum Received {
+    /// The next value sent
+    Value(Value),
+    /// Nothing is ready yet
+    Empty,
+    /// The channel is closed and every buffered value has been taken
+    Closed,
+}
+
+/// Create a channel buffering up to `capacity` values (`None` for unbounded;
+/// `Some(0)` hands each value straight to a waiting receiver)
+pub fn make_channel(capacity: Option<usize>) -> Value {
+    let (sender, receiver) = match capacity {
+        Some(size) => {
+            let (tx, rx) = mpsc::sync_channel(size);
+            (ChannelSender::Bounded(tx), rx)
+        }
+        None => {
+            let (tx, rx) = mpsc::channel();
+            (ChannelSender::Unbounded(tx), rx)
+        }
+    };
+    Value::Channel {
+        capacity,
+        inner: Arc::new(ChannelInner {
+            sender: Mutex::new(Some(sender)),
+            receiver: Mutex::new(receiver),
+        }),
+    }
+}
+
+fn channel_inner(ch: &Value) -> Result<&ChannelInner> {
+    match ch {
+        Value::Channel { inner, .. } => Ok(inner),
+        _ => Err(Error::TypeError {
+            expected: "channel".to_string(),
+            got: ch.type_name(),
+        }),
+    }
+}
+
+fn closed_channel_error() -> Error {
+    Error::InvalidArguments {
+        tool: "channel-send".to_string(),
+        reason: "Channel is closed".to_string(),
+    }
+}
+
+/// Send a value if there is room for it, handing it back while a bounded
+/// channel is full
+pub fn channel_try_send(ch: &Value, value: Value) -> Result<Option<Value>> {
+    let inner = channel_inner(ch)?;
+    let sender = inner.sender.lock().unwrap();
+    match sender.as_ref().ok_or_else(closed_channel_error)? {
+        ChannelSender::Bounded(tx) => match tx.try_send(value) {
+            Ok(()) => Ok(None),
+            Err(mpsc::TrySendError::Full(value)) => Ok(Some(value)),
+            Err(mpsc::TrySendError::Disconnected(_)) => Err(closed_channel_error()),
+        },
+        ChannelSender::Unbounded(tx) => tx
+            .send(value)
+            .map(|_| None)
+            .map_err(|_| closed_channel_error()),
+    }
+}
+
+/// Receive the next value, waiting at most `timeout` (forever if `None`)
+pub fn channel_receive(ch: &Value, timeout: Option<Duration>) -> Result<Received> {
+    let inner = channel_inner(ch)?;
+    let receiver = inner.receiver.lock().unwrap();
+    let received = match timeout {
+        Some(dur) => receiver.recv_timeout(dur),
+        None => receiver
+            .recv()
+            .map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+    };
+    Ok(match received {
+        Ok(value) => Received::Value(value),
+        Err(mpsc::RecvTimeoutError::Timeout) => Received::Empty,
+        Err(mpsc::RecvTimeoutError::Disconnected) => Received::Closed,
+    })
+}
+
+/// Receive a value if one is ready, without blocking
+///
+/// Reports `Empty` while another thread is mid-receive.
+pub fn channel_try_receive(ch: &Value) -> Result<Received> {
+    let inner = channel_inner(ch)?;
+    let receiver = match inner.receiver.try_lock() {
+        Ok(receiver) => receiver,
+        Err(std::sync::TryLockError::WouldBlock) => return Ok(Received::Empty),
+        Err(std::sync::TryLockError::Poisoned(e)) => e.into_inner(),
+    };
+    Ok(match receiver.try_recv() {
+        Ok(value) => Received::Value(value),
+        Err(mpsc::TryRecvError::Empty) => Received::Empty,
+        Err(mpsc::TryRecvError::Disconnected) => Received::Closed,
+    })
+}
+
+/// Whether `close-channel` has been called on a channel
+pub fn channel_closed(ch: &Value) -> Result<bool> {
+    Ok(channel_inner(ch)?.sender.lock().unwrap().is_none())
+}
+
+/// Close a channel: further sends fail, and receivers see `Closed` after
+/// draining buffered values. Closing twice is a no-op.
+pub fn close_channel(ch: &Value) -> Result<()> {
+    channel_inner(ch)?.sender.lock().unwrap().take();
+    Ok(())
+}
+
+// =============================================================================
+// Promise Functions
+// =============================================================================
+
+use crate::runtime::value::{PromiseCallback, PromiseInner};
+
+/// Create an unresolved promise
+pub fn make_promise() -> Value {
+    Value::Promise {
+        inner: Arc::new(PromiseInner::default()),
+    }
+}
+
+/// Shared state behind a promise-like value
+///
+/// An async handle is adopted by a watcher thread that delivers its result
+/// (null if the task failed), which consumes the handle like `await` does.
+pub fn promise_inner(value: &Value) -> Result<Arc<PromiseInner>> {
+    match value {
+        Value::Promise { inner } => Ok(Arc::clone(inner)),
+        Value::AsyncHandle { id, receiver } => {
+            let rx = receiver
+                .lock()
+                .unwrap()
+                .take()
+                .ok_or_else(|| Error::runtime(format!("AsyncHandle {} already awaited", id)))?;
+            let inner = Arc::new(PromiseInner::default());
+            let target = Arc::clone(&inner);
+            thread::spawn(move || {
+                deliver_promise(&target, rx.blocking_recv().unwrap_or(Value::Null));
+            });
+            Ok(inner)
+        }
+        _ => Err(Error::TypeError {
+            expected: "promise".to_string(),
+            got: value.type_name(),
+        }),
+    }
+}
+
+/// Fulfil a promise, waking waiters and running its callbacks
+///
+/// Only the first delivery counts; returns false if it was already delivered.
+pub fn deliver_promise(inner: &PromiseInner, value: Value) -> bool {
+    let callbacks = {
+        let mut state = inner.state.lock().unwrap();
+        if state.value.is_some() {
+            return false;
+        }
+        state.value = Some(value.clone());
+        std::mem::take(&mut state.callbacks)
+    };
+    inner.delivered.notify_all();
+    for callback in callbacks {
+        callback(&value);
+    }
+    true
+}
+
+/// Run `callback` with the promise's value once delivered (now, if it is)
+fn on_delivery(inner: &PromiseInner, callback: PromiseCallback) {
+    let mut state = inner.state.lock().unwrap();
+    match state.value.clone() {
+        Some(value) => {
+            drop(state);
+            callback(&value);
+        }
+        None => state.callbacks.push(callback),
+    }
+}
+
+/// Wait at most `timeout` (forever if `None`) for a promise's value
+///
+/// Returns `None` if it is still undelivered when the wait ends.
+pub fn promise_wait(inner: &PromiseInner, timeout: Option<Duration>) -> Option<Value> {
+    let state = inner.state.lock().unwrap();
+    let state = match timeout {
+        Some(dur) => {
+            inner
+                .delivered
+                .wait_timeout_while(state, dur, |s| s.value.is_none())
+                .unwrap()
+                .0
+        }
+        None => inner
+            .delivered
+            .wait_while(state, |s| s.value.is_none())
+            .unwrap(),
+    };
+    state.value.clone()
+}
+
+/// Check whether a promise has been delivered
+pub fn promise_realized(inner: &PromiseInner) -> bool {
+    inner.state.lock().unwrap().value.is_some()
+}
+
+/// Promise of an array of every input's value, delivered once all are
+pub fn all_of(promises: Vec<Arc<PromiseInner>>) -> Value {
+    let target = Arc::new(PromiseInner::default());
+    if promises.is_empty() {
+        deliver_promise(&target, Value::array(Vec::new()));
+    }
+
+    // Values in input order, plus how many are still missing
+    let slots = Arc::new(Mutex::new((
+        vec![Value::Null; promises.len()],
+        promises.len(),
+    )));
+    for (index, promise) in promises.iter().enumerate() {
+        let slots = Arc::clone(&slots);
+        let target = Arc::clone(&target);
+        on_delivery(
+            promise,
+            Box::new(move |value| {
+                let mut guard = slots.lock().unwrap();
+                guard.0[index] = value.clone();
+                guard.1 -= 1;
+                if guard.1 == 0 {
+                    let values = std::mem::take(&mut guard.0);
+                    drop(guard);
+                    deliver_promise(&target, Value::array(values));
+                }
+            }),
+        );
+    }
+    Value::Promise { inner: target }
+}
+
+/// Promise of whichever input is delivered first
+pub fn any_of(promises: Vec<Arc<PromiseInner>>) -> Value {
+    let target = Arc::new(PromiseInner::default());
+    for promise in &promises {
+        let target = Arc::clone(&target);
+        on_delivery(
+            promise,
+            Box::new(move |value| {
+                deliver_promise(&target, value.clone());
+            }),
+        );
+    }
+    Value::Promise { inner: target }
+}
+
 // =============================================================================
 // Type Predicates
 // =============================================================================
@@ -574,6 +1044,21 @@ pub fn is_atomic_integer(value: &Value) -> bool {
     matches!(value, Value::AtomicInteger { .. })
 }
 
+/// Check if value is an atomic reference
+pub fn is_atomic_ref(value: &Value) -> bool {
+    matches!(value, Value::AtomicRef { .. })
+}
+
+/// Check if value is a channel
+pub fn is_channel(value: &Value) -> bool {
+    matches!(value, Value::Channel { .. })
+}
+
+/// Check if value is a promise
+pub fn is_promise(value: &Value) -> bool {
+    matches!(value, Value::Promise { .. })
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -636,4 +1121,69 @@ mod tests {
         assert!(id1.starts_with("thread_"));
         assert!(id2.starts_with("thread_"));
     }
+
+    #[test]
+    fn test_atomic_ref_cas() {
+        let r = make_atomic_ref(Value::Int(1));
+        assert!(is_atomic_ref(&r));
+
+        let (version, value) = atomic_ref_load(&r).unwrap();
+        assert_eq!(value, Value::Int(1));
+        atomic_ref_store(&r, Value::Int(2)).unwrap();
+
+        // A write since the load makes the swap fail
+        assert!(!atomic_ref_cas(&r, version, Value::Int(10)).unwrap());
+        let (version, _) = atomic_ref_load(&r).unwrap();
+        assert!(atomic_ref_cas(&r, version, Value::Int(3)).unwrap());
+        assert_eq!(atomic_ref_load(&r).unwrap().1, Value::Int(3));
+    }
+
+    #[test]
+    fn test_channel_operations() {
+        let ch = make_channel(Some(1));
+        assert!(is_channel(&ch));
+        assert_eq!(channel_try_receive(&ch).unwrap(), Received::Empty);
+
+        assert_eq!(channel_try_send(&ch, Value::Int(5)).unwrap(), None);
+        assert_eq!(
+            channel_try_send(&ch, Value::Int(9)).unwrap(),
+            Some(Value::Int(9))
+        );
+        assert_eq!(
+            channel_receive(&ch, Some(Duration::from_millis(10))).unwrap(),
+            Received::Value(Value::Int(5))
+        );
+        assert_eq!(
+            channel_receive(&ch, Some(Duration::from_millis(10))).unwrap(),
+            Received::Empty
+        );
+
+        assert!(!channel_closed(&ch).unwrap());
+        close_channel(&ch).unwrap();
+        assert!(channel_closed(&ch).unwrap());
+        assert!(channel_try_send(&ch, Value::Int(6)).is_err());
+        assert_eq!(channel_try_receive(&ch).unwrap(), Received::Closed);
+    }
+
+    #[test]
+    fn test_promise_combinators() {
+        let a = Arc::new(PromiseInner::default());
+        let b = Arc::new(PromiseInner::default());
+        let all = all_of(vec![Arc::clone(&a), Arc::clone(&b)]);
+        let any = any_of(vec![Arc::clone(&a), Arc::clone(&b)]);
+        let (Value::Promise { inner: all }, Value::Promise { inner: any }) = (all, any) else {
+            panic!("combinators return promises");
+        };
+
+        assert!(deliver_promise(&b, Value::Int(2)));
+        assert!(!deliver_promise(&b, Value::Int(3)));
+        assert_eq!(promise_wait(&any, None), Some(Value::Int(2)));
+        assert!(!promise_realized(&all));
+
+        deliver_promise(&a, Value::Int(1));
+        assert_eq!(
+            promise_wait(&all, Some(Duration::from_millis(10))),
+            Some(Value::array(vec![Value::Int(1), Value::Int(2)]))
+        );
+    }
 }
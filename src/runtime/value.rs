@@ -16,14 +16,24 @@ pub enum Value {
     Int(i64),
     /// 64-bit floating-point value
     Float(f64),
+    /// Complex number with floating-point parts, displayed as `#C(re im)`
+    Complex {
+        /// Real part
+        re: f64,
+        /// Imaginary part
+        im: f64,
+    },
+    /// Point in time as milliseconds since the Unix epoch (UTC)
+    Instant(i64),
     /// String value
     String(String),
 
     // Collections (use Arc for large values)
     /// Array of values (reference-counted)
     Array(Arc<Vec<Value>>),
-    /// Object with string keys and value fields (reference-counted)
-    Object(Arc<HashMap<String, Value>>),
+    /// Object with string keys and value fields (reference-counted; keys may
+    /// be shared with other objects through the evaluator's interner)
+    Object(Arc<HashMap<Arc<str>, Value>>),
 
     // Special
     /// Range value with start and end (exclusive)
@@ -63,6 +73,19 @@ pub enum Value {
         closure: Arc<HashMap<String, Value>>,
     },
 
+    /// Memoizing wrapper around a function (returned by `memoize`)
+    /// Results are cached by a canonical encoding of the argument list
+    Memoized {
+        /// The wrapped function
+        func: Arc<Value>,
+        /// Shared cache: canonical argument key -> result
+        cache: Arc<std::sync::Mutex<HashMap<String, Value>>>,
+    },
+
+    /// Lazy sequence (returned by `lazy-seq`, `lazy-map`, `lazy-filter`)
+    /// Each cell is forced at most once; the result is memoized in place
+    LazySeq(Arc<std::sync::Mutex<LazyState>>),
+
     /// Async task handle (returned by async, can be awaited for result)
     AsyncHandle {
         /// Unique task ID
@@ -81,7 +104,7 @@ pub enum Value {
         /// Optional thread name
         name: Option<String>,
         /// Join handle (consumed when thread is joined)
-        handle: Arc<std::sync::Mutex<Option<std::thread::JoinHandle<Value>>>>,
+        handle: Arc<std::sync::Mutex<Option<crate::runtime::threading::ThreadHandle>>>,
         /// Result storage (populated after thread completes)
         result: Arc<std::sync::Mutex<Option<Value>>>,
     },
@@ -127,6 +150,118 @@ pub enum Value {
         /// The atomic value
         inner: Arc<std::sync::atomic::AtomicI64>,
     },
+
+    /// FIFO channel for passing values between threads
+    Channel {
+        /// Buffer size, or `None` for an unbounded channel
+        capacity: Option<usize>,
+        /// Shared sending and receiving ends
+        inner: Arc<ChannelInner>,
+    },
+
+    /// Write-once cell, fulfilled by `deliver` and read with `promise-deref`
+    Promise {
+        /// Shared state of the promise
+        inner: Arc<PromiseInner>,
+    },
+
+    /// Atomic reference to any value, updated by compare-and-swap
+    AtomicRef {
+        /// Current value and a version bumped by every write
+        inner: Arc<std::sync::Mutex<(u64, Value)>>,
+    },
+}
+
+/// State of one lazy sequence cell
+#[derive(Debug)]
+pub enum LazyState {
+    /// Not yet computed
+    Pending(LazyThunk),
+    /// Computed: `None` for the empty sequence, or `(first, rest)`
+    Forced(Option<(Value, Value)>),
+}
+
+/// How a pending lazy cell produces its value
+#[derive(Debug)]
+pub enum LazyThunk {
+    /// User thunk returning null or `[first rest]`, with the local bindings
+    /// visible where `lazy-seq` was called
+    Thunk {
+        /// Zero-argument function to call
+        func: Value,
+        /// Captured local variables
+        bindings: HashMap<String, Value>,
+    },
+    /// `lazy-map`: apply `func` to each element of `source`
+    Map {
+        /// Mapping function
+        func: Value,
+        /// Underlying sequence
+        source: Value,
+    },
+    /// `lazy-filter`: keep elements of `source` satisfying `pred`
+    Filter {
+        /// Predicate function
+        pred: Value,
+        /// Underlying sequence
+        source: Value,
+    },
+    /// Rest of an array being stepped, from index `start` on
+    Items {
+        /// The array, shared rather than copied
+        items: Arc<Vec<Value>>,
+        /// Index of the next element
+        start: usize,
+    },
+}
+
+/// Sending end of a channel; bounded channels block senders while full
+#[derive(Debug, Clone)]
+pub enum ChannelSender {
+    /// `make-channel` with a capacity
+    Bounded(std::sync::mpsc::SyncSender<Value>),
+    /// `make-channel` without a capacity
+    Unbounded(std::sync::mpsc::Sender<Value>),
+}
+
+/// Internal channel state shared by every copy of a channel value
+#[derive(Debug)]
+pub struct ChannelInner {
+    /// Taken by `close-channel`; receivers drain then see the closed sentinel
+    pub sender: std::sync::Mutex<Option<ChannelSender>>,
+    /// Locked for the duration of a receive
+    pub receiver: std::sync::Mutex<std::sync::mpsc::Receiver<Value>>,
+}
+
+/// Callback run with a promise's value once it is delivered
+pub type PromiseCallback = Box<dyn FnOnce(&Value) + Send>;
+
+/// Internal promise state shared by every copy of a promise value
+#[derive(Default)]
+pub struct PromiseInner {
+    /// Delivered value and callbacks still waiting for it
+    pub state: std::sync::Mutex<PromiseState>,
+    /// Notified when the value is delivered
+    pub delivered: std::sync::Condvar,
+}
+
+/// Mutable part of a promise
+#[derive(Default)]
+pub struct PromiseState {
+    /// `None` until delivered
+    pub value: Option<Value>,
+    /// Run by `deliver`; registering after delivery runs immediately
+    pub callbacks: Vec<PromiseCallback>,
+}
+
+impl std::fmt::Debug for PromiseInner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        f.debug_struct("PromiseInner")
+            .field("value", &state.value)
+            .field("callbacks", &state.callbacks.len())
+            .finish()
+    }
 }
 
 /// Internal semaphore state (std doesn't have a counting semaphore)
@@ -142,9 +277,26 @@ impl Value {
         Value::Array(Arc::new(values))
     }
 
+    /// Creates a complex number value
+    pub fn complex(re: f64, im: f64) -> Self {
+        Value::Complex { re, im }
+    }
+
+    /// Creates an instant from milliseconds since the Unix epoch
+    pub fn instant(millis: i64) -> Self {
+        Value::Instant(millis)
+    }
+
     /// Creates an object value from a hashmap of fields
     pub fn object(fields: HashMap<String, Value>) -> Self {
-        Value::Object(Arc::new(fields))
+        Value::Object(Arc::new(
+            fields.into_iter().map(|(k, v)| (Arc::from(k), v)).collect(),
+        ))
+    }
+
+    /// Creates an unforced lazy sequence cell
+    pub fn lazy(thunk: LazyThunk) -> Self {
+        Value::LazySeq(Arc::new(std::sync::Mutex::new(LazyState::Pending(thunk))))
     }
 
     /// Creates a multiple values result
@@ -168,6 +320,8 @@ impl Value {
             Value::Bool(_) => "bool".to_string(),
             Value::Int(_) => "int".to_string(),
             Value::Float(_) => "float".to_string(),
+            Value::Complex { .. } => "complex".to_string(),
+            Value::Instant(_) => "instant".to_string(),
             Value::String(_) => "string".to_string(),
             Value::Array(_) => "array".to_string(),
             Value::Object(_) => "object".to_string(),
@@ -175,6 +329,8 @@ impl Value {
             Value::Function { .. } => "function".to_string(),
             Value::Multiple(_) => "multiple-values".to_string(),
             Value::Macro { .. } => "macro".to_string(),
+            Value::Memoized { .. } => "function".to_string(),
+            Value::LazySeq(_) => "lazy-seq".to_string(),
             Value::AsyncHandle { .. } => "async-handle".to_string(),
             // Bordeaux Threads types
             Value::Thread { .. } => "thread".to_string(),
@@ -183,6 +339,9 @@ impl Value {
             Value::ConditionVariable { .. } => "condition-variable".to_string(),
             Value::Semaphore { .. } => "semaphore".to_string(),
             Value::AtomicInteger { .. } => "atomic-integer".to_string(),
+            Value::Channel { .. } => "channel".to_string(),
+            Value::Promise { .. } => "promise".to_string(),
+            Value::AtomicRef { .. } => "atomic-ref".to_string(),
         }
     }
 
@@ -193,6 +352,8 @@ impl Value {
             Value::Bool(b) => *b,
             Value::Int(n) => *n != 0,
             Value::Float(f) => *f != 0.0,
+            Value::Complex { re, im } => *re != 0.0 || *im != 0.0,
+            Value::Instant(_) => true,
             Value::String(s) => !s.is_empty(),
             Value::Array(arr) => !arr.is_empty(),
             Value::Object(obj) => !obj.is_empty(),
@@ -203,6 +364,8 @@ impl Value {
                 vals.first().map(|v| v.is_truthy()).unwrap_or(false)
             }
             Value::Macro { .. } => true, // Macros are always truthy
+            Value::Memoized { .. } => true,
+            Value::LazySeq(_) => true,
             Value::AsyncHandle { .. } => true, // Handles are always truthy
             // Bordeaux Threads - all threading primitives are truthy
             Value::Thread { .. } => true,
@@ -211,6 +374,9 @@ impl Value {
             Value::ConditionVariable { .. } => true,
             Value::Semaphore { .. } => true,
             Value::AtomicInteger { .. } => true,
+            Value::Channel { .. } => true,
+            Value::Promise { .. } => true,
+            Value::AtomicRef { .. } => true,
         }
     }
 
@@ -281,6 +447,8 @@ impl Value {
             Value::Bool(b) => b.to_string(),
             Value::Int(n) => n.to_string(),
             Value::Float(f) => f.to_string(),
+            Value::Complex { re, im } => format!("#C({} {})", re, im),
+            Value::Instant(millis) => format_instant(*millis),
             Value::String(s) => s.clone(),
             Value::Array(arr) => format!("[{} items]", arr.len()),
             Value::Object(obj) => format!("{{{}  fields}}", obj.len()),
@@ -294,6 +462,8 @@ impl Value {
                 }
             }
             Value::Macro { params, .. } => format!("<macro({} params)>", params.len()),
+            Value::Memoized { func, .. } => format!("<memoized {}>", func.to_string_value()),
+            Value::LazySeq(_) => "<lazy-seq>".to_string(),
             Value::AsyncHandle { id, .. } => format!("<async-handle:{}>", id),
             // Bordeaux Threads
             Value::Thread { id, name, .. } => {
@@ -336,6 +506,11 @@ impl Value {
                 let v = inner.load(std::sync::atomic::Ordering::SeqCst);
                 format!("<atomic-integer {}>", v)
             }
+            Value::Channel { capacity, .. } => match capacity {
+                Some(c) => format!("<channel capacity={}>", c),
+                None => "<channel unbounded>".to_string(),
+            },
+            Value::Promise { .. } | Value::AtomicRef { .. } => self.to_string(),
         }
     }
 
@@ -351,7 +526,7 @@ impl Value {
     }
 
     /// Returns a reference to the object value
-    pub fn as_object(&self) -> Result<&HashMap<String, Value>> {
+    pub fn as_object(&self) -> Result<&HashMap<Arc<str>, Value>> {
         match self {
             Value::Object(obj) => Ok(obj),
             _ => Err(Error::TypeError {
@@ -366,7 +541,7 @@ impl Value {
         match self {
             Value::Object(obj) => obj.get(field).cloned().ok_or_else(|| {
                 // Collect available fields to help debugging
-                let mut available: Vec<String> = obj.keys().cloned().collect();
+                let mut available: Vec<String> = obj.keys().map(|k| k.to_string()).collect();
                 available.sort(); // Sort for consistent output
                 eprintln!(
                     "🔍 DEBUG: Field '{}' not found. Available fields: {:?}",
@@ -432,6 +607,14 @@ impl Value {
     }
 }
 
+/// ISO-8601 UTC text for an instant, with fractional seconds only when nonzero
+pub(crate) fn format_instant(millis: i64) -> String {
+    match chrono::DateTime::from_timestamp_millis(millis) {
+        Some(dt) => dt.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+        None => format!("{}ms", millis),
+    }
+}
+
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -439,6 +622,8 @@ impl fmt::Display for Value {
             Value::Bool(b) => write!(f, "{}", b),
             Value::Int(n) => write!(f, "{}", n),
             Value::Float(fl) => write!(f, "{}", fl),
+            Value::Complex { re, im } => write!(f, "#C({} {})", re, im),
+            Value::Instant(millis) => write!(f, "#inst \"{}\"", format_instant(*millis)),
             Value::String(s) => write!(f, "\"{}\"", s),
             Value::Array(arr) => {
                 write!(f, "[")?;
@@ -470,6 +655,8 @@ impl fmt::Display for Value {
                 write!(f, ")")
             }
             Value::Macro { params, .. } => write!(f, "<macro({} params)>", params.len()),
+            Value::Memoized { func, .. } => write!(f, "<memoized {}>", func),
+            Value::LazySeq(_) => write!(f, "<lazy-seq>"),
             Value::AsyncHandle { id, .. } => write!(f, "<async-handle:{}>", id),
             // Bordeaux Threads
             Value::Thread { id, name, .. } => {
@@ -514,6 +701,21 @@ impl fmt::Display for Value {
                 let v = inner.load(Ordering::SeqCst);
                 write!(f, "<atomic-integer {}>", v)
             }
+            Value::Channel { capacity, .. } => match capacity {
+                Some(c) => write!(f, "<channel capacity={}>", c),
+                None => write!(f, "<channel unbounded>"),
+            },
+            Value::Promise { inner } => {
+                let state = inner.state.lock().unwrap_or_else(|e| e.into_inner());
+                match &state.value {
+                    Some(v) => write!(f, "<promise {}>", v),
+                    None => write!(f, "<promise pending>"),
+                }
+            }
+            Value::AtomicRef { inner } => {
+                let cell = inner.lock().unwrap_or_else(|e| e.into_inner());
+                write!(f, "<atomic {}>", cell.1)
+            }
         }
     }
 }
@@ -526,6 +728,10 @@ impl PartialEq for Value {
             (Value::Bool(a), Value::Bool(b)) => a == b,
             (Value::Int(a), Value::Int(b)) => a == b,
             (Value::Float(a), Value::Float(b)) => a == b,
+            (Value::Complex { re: r1, im: i1 }, Value::Complex { re: r2, im: i2 }) => {
+                r1 == r2 && i1 == i2
+            }
+            (Value::Instant(a), Value::Instant(b)) => a == b,
             (Value::String(a), Value::String(b)) => a == b,
             (Value::Array(a), Value::Array(b)) => a == b,
             (Value::Object(a), Value::Object(b)) => a == b,
@@ -536,6 +742,10 @@ impl PartialEq for Value {
             // Functions, macros, and async handles compared by identity (pointer equality)
             (Value::Function { body: a, .. }, Value::Function { body: b, .. }) => Arc::ptr_eq(a, b),
             (Value::Macro { body: a, .. }, Value::Macro { body: b, .. }) => Arc::ptr_eq(a, b),
+            (Value::Memoized { cache: a, .. }, Value::Memoized { cache: b, .. }) => {
+                Arc::ptr_eq(a, b)
+            }
+            (Value::LazySeq(a), Value::LazySeq(b)) => Arc::ptr_eq(a, b),
             (Value::AsyncHandle { id: a, .. }, Value::AsyncHandle { id: b, .. }) => a == b,
             // Bordeaux Threads - compare by identity (same object)
             (Value::Thread { id: a, .. }, Value::Thread { id: b, .. }) => a == b,
@@ -553,6 +763,9 @@ impl PartialEq for Value {
             (Value::AtomicInteger { inner: a }, Value::AtomicInteger { inner: b }) => {
                 Arc::ptr_eq(a, b)
             }
+            (Value::Channel { inner: a, .. }, Value::Channel { inner: b, .. }) => Arc::ptr_eq(a, b),
+            (Value::Promise { inner: a }, Value::Promise { inner: b }) => Arc::ptr_eq(a, b),
+            (Value::AtomicRef { inner: a }, Value::AtomicRef { inner: b }) => Arc::ptr_eq(a, b),
             _ => false,
         }
     }
@@ -24,6 +24,52 @@ pub trait Tool: Send + Sync {
     fn arity(&self) -> Option<usize> {
         None // None means variadic
     }
+
+    /// Script function backing this tool, if it was registered from Solisp
+    /// code; the evaluator applies it instead of calling `execute`
+    fn function(&self) -> Option<&Value> {
+        None
+    }
+}
+
+/// Applies a script tool's function to evaluated arguments
+pub type ScriptRunner = Arc<dyn Fn(&[Value]) -> Result<Value> + Send + Sync>;
+
+/// Tool registered at runtime with `register-tool`, backed by a lambda
+pub struct ScriptTool {
+    name: String,
+    function: Value,
+    runner: ScriptRunner,
+}
+
+impl ScriptTool {
+    /// Creates a tool named `name` whose `execute` hands its arguments to
+    /// `runner`, which applies `function` outside the calling evaluator
+    pub fn new(name: impl Into<String>, function: Value, runner: ScriptRunner) -> Self {
+        ScriptTool {
+            name: name.into(),
+            function,
+            runner,
+        }
+    }
+}
+
+impl Tool for ScriptTool {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Tool registered from script"
+    }
+
+    fn execute(&self, args: &[Value]) -> Result<Value> {
+        (self.runner)(args)
+    }
+
+    fn function(&self) -> Option<&Value> {
+        Some(&self.function)
+    }
 }
 
 /// Tool arguments (positional and named)
@@ -94,6 +140,7 @@ impl Default for ToolArguments {
 }
 
 /// Tool registry
+#[derive(Clone)]
 pub struct ToolRegistry {
     tools: HashMap<String, Arc<dyn Tool>>,
 }
@@ -111,6 +158,15 @@ impl ToolRegistry {
         registry
     }
 
+    /// Create a registry that also exposes the standard library tools in the
+    /// `stdlib` package, e.g. `stdlib:mean`
+    pub fn new_with_stdlib() -> Self {
+        let mut registry = Self::new();
+        let prefix = format!("{}:", stdlib::STDLIB_PACKAGE);
+        stdlib::register_all_namespaced(&mut registry, &prefix);
+        registry
+    }
+
     /// Create empty registry (for testing)
     pub fn empty() -> Self {
         ToolRegistry {
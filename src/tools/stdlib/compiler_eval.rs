@@ -63,7 +63,7 @@ impl Tool for CompileFileTool {
                 compile_result.insert("warnings".to_string(), Value::Array(Arc::new(vec![])));
                 compile_result.insert("errors".to_string(), Value::Array(Arc::new(vec![])));
 
-                Ok(Value::Object(Arc::new(compile_result)))
+                Ok(Value::object(compile_result))
             }
             _ => Err(Error::TypeError {
                 expected: "valid argument".to_string(),
@@ -156,7 +156,7 @@ impl Tool for DisassembleTool {
             Value::String(format!("Disassembly not available for {}", func_name)),
         );
 
-        Ok(Value::Object(Arc::new(disasm_info)))
+        Ok(Value::object(disasm_info))
     }
 }
 
@@ -341,7 +341,7 @@ impl Tool for CompilerMacroFunctionTool {
         );
         macro_info.insert("parameters".to_string(), Value::Array(Arc::new(vec![])));
 
-        Ok(Value::Object(Arc::new(macro_info)))
+        Ok(Value::object(macro_info))
     }
 }
 
@@ -498,12 +498,12 @@ impl Tool for OptimizeTool {
         for arg in args {
             if let Value::Object(settings) = arg {
                 for (key, value) in settings.as_ref() {
-                    optimize_settings.insert(key.clone(), value.clone());
+                    optimize_settings.insert(key.to_string(), value.clone());
                 }
             }
         }
 
-        Ok(Value::Object(Arc::new(optimize_settings)))
+        Ok(Value::object(optimize_settings))
     }
 }
 
@@ -682,7 +682,7 @@ impl Tool for MacroFunctionTool {
         macro_info.insert("parameters".to_string(), Value::Array(Arc::new(vec![])));
         macro_info.insert("body".to_string(), Value::Null);
 
-        Ok(Value::Object(Arc::new(macro_info)))
+        Ok(Value::object(macro_info))
     }
 }
 
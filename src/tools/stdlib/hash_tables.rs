@@ -46,6 +46,13 @@ pub fn register(registry: &mut ToolRegistry) {
     registry.register(HashTableRemoveIfTool);
 }
 
+/// Entry name for a hash table key: a keyword names the same entry as the
+/// bare string (`:sol` and `"sol"`), as with `get` and object literals
+pub(crate) fn table_key(key: &Value) -> Result<&str> {
+    let key = key.as_string()?;
+    Ok(key.strip_prefix(':').unwrap_or(key))
+}
+
 // ============================================================================
 // Creation & Basic Operations
 // ============================================================================
@@ -72,7 +79,7 @@ impl Tool for MakeHashTableTool {
 
         // Create empty hash table
         let map: HashMap<String, Value> = HashMap::new();
-        Ok(Value::Object(Arc::new(map)))
+        Ok(Value::object(map))
     }
 }
 
@@ -96,7 +103,7 @@ impl Tool for GethashTool {
             });
         }
 
-        let key = args[0].as_string()?;
+        let key = table_key(&args[0])?;
         let hash_table = args[1].as_object()?;
 
         // Optional default value
@@ -133,7 +140,7 @@ impl Tool for RemhashTool {
             });
         }
 
-        let key = args[0].as_string()?;
+        let key = table_key(&args[0])?;
         let hash_table = args[1].as_object()?;
 
         // Check if key was present
@@ -330,7 +337,7 @@ impl Tool for HashTableKeysTool {
         let hash_table = args[0].as_object()?;
         let keys: Vec<Value> = hash_table
             .keys()
-            .map(|k| Value::String(k.clone()))
+            .map(|k| Value::String(k.to_string()))
             .collect();
 
         Ok(Value::Array(Arc::new(keys)))
@@ -387,7 +394,7 @@ impl Tool for HashTablePairsTool {
         let hash_table = args[0].as_object()?;
         let pairs: Vec<Value> = hash_table
             .iter()
-            .map(|(k, v)| Value::Array(Arc::new(vec![Value::String(k.clone()), v.clone()])))
+            .map(|(k, v)| Value::Array(Arc::new(vec![Value::String(k.to_string()), v.clone()])))
             .collect();
 
         Ok(Value::Array(Arc::new(pairs)))
@@ -476,7 +483,7 @@ impl Tool for HashTableToAlistTool {
         let hash_table = args[0].as_object()?;
         let alist: Vec<Value> = hash_table
             .iter()
-            .map(|(k, v)| Value::Array(Arc::new(vec![Value::String(k.clone()), v.clone()])))
+            .map(|(k, v)| Value::Array(Arc::new(vec![Value::String(k.to_string()), v.clone()])))
             .collect();
 
         Ok(Value::Array(Arc::new(alist)))
@@ -509,14 +516,14 @@ impl Tool for AlistToHashTableTool {
         for pair in alist.iter() {
             if let Value::Array(p) = pair {
                 if p.len() >= 2 {
-                    let key = p[0].as_string()?.to_string();
+                    let key = table_key(&p[0])?.to_string();
                     let value = p[1].clone();
                     map.insert(key, value);
                 }
             }
         }
 
-        Ok(Value::Object(Arc::new(map)))
+        Ok(Value::object(map))
     }
 }
 
@@ -544,7 +551,7 @@ impl Tool for CopyHashTableTool {
         let new_map = hash_table
             .iter()
             .map(|(k, v)| (k.clone(), v.clone()))
-            .collect::<HashMap<String, Value>>();
+            .collect::<HashMap<Arc<str>, Value>>();
 
         Ok(Value::Object(Arc::new(new_map)))
     }
@@ -607,7 +614,7 @@ impl Tool for MergeHashTablesTool {
             return Ok(Value::Object(Arc::new(HashMap::new())));
         }
 
-        let mut merged: HashMap<String, Value> = HashMap::new();
+        let mut merged: HashMap<Arc<str>, Value> = HashMap::new();
 
         for arg in args {
             let ht = arg.as_object()?;
@@ -696,7 +703,7 @@ impl Tool for HashTableContainsKeyTool {
             });
         }
 
-        let key = args[0].as_string()?;
+        let key = table_key(&args[0])?;
         let hash_table = args[1].as_object()?;
 
         Ok(Value::Bool(hash_table.contains_key(key)))
@@ -723,7 +730,7 @@ impl Tool for HashTableGetOrDefaultTool {
             });
         }
 
-        let key = args[0].as_string()?;
+        let key = table_key(&args[0])?;
         let hash_table = args[1].as_object()?;
         let default = &args[2];
 
@@ -755,14 +762,14 @@ impl Tool for HashTableUpdateTool {
         }
 
         let hash_table = args[0].as_object()?;
-        let key = args[1].as_string()?.to_string();
+        let key = table_key(&args[1])?.to_string();
         let value = &args[2];
 
         let mut new_map = hash_table
             .iter()
             .map(|(k, v)| (k.clone(), v.clone()))
-            .collect::<HashMap<String, Value>>();
-        new_map.insert(key, value.clone());
+            .collect::<HashMap<Arc<str>, Value>>();
+        new_map.insert(key.into(), value.clone());
 
         Ok(Value::Object(Arc::new(new_map)))
     }
@@ -100,13 +100,23 @@ impl Tool for DescribeTool {
             Value::Bool(b) => println!("{}\n  Type: BOOLEAN", b),
             Value::Int(n) => println!("{}\n  Type: INTEGER\n  Value: {}", n, n),
             Value::Float(f) => println!("{}\n  Type: FLOAT\n  Value: {}", f, f),
+            Value::Complex { re, im } => {
+                println!(
+                    "{}\n  Type: COMPLEX\n  Real: {}\n  Imag: {}",
+                    args[0], re, im
+                )
+            }
+            Value::Instant(_) => println!("{}\n  Type: INSTANT", args[0]),
             Value::String(s) => println!("\"{}\"\n  Type: STRING\n  Length: {}", s, s.len()),
             Value::Array(arr) => println!("Array\n  Type: ARRAY\n  Length: {}", arr.len()),
             Value::Object(_) => println!("Object\n  Type: OBJECT"),
             Value::Range { .. } => println!("Range\n  Type: RANGE"),
-            Value::Function { .. } => println!("Function\n  Type: FUNCTION"),
+            Value::Function { .. } | Value::Memoized { .. } => {
+                println!("Function\n  Type: FUNCTION")
+            }
             Value::Multiple(_) => println!("Multiple Values\n  Type: MULTIPLE"),
             Value::Macro { .. } => println!("Macro\n  Type: MACRO"),
+            Value::LazySeq(_) => println!("Lazy Sequence\n  Type: LAZY-SEQ"),
             Value::AsyncHandle { id, .. } => {
                 println!("AsyncHandle\n  Type: ASYNC-HANDLE\n  ID: {}", id)
             }
@@ -118,6 +128,9 @@ impl Tool for DescribeTool {
             }
             Value::Semaphore { .. } => println!("Semaphore\n  Type: SEMAPHORE"),
             Value::AtomicInteger { .. } => println!("AtomicInteger\n  Type: ATOMIC-INTEGER"),
+            Value::Channel { .. } => println!("Channel\n  Type: CHANNEL"),
+            Value::Promise { .. } => println!("Promise\n  Type: PROMISE"),
+            Value::AtomicRef { .. } => println!("AtomicRef\n  Type: ATOMIC-REF"),
         }
         Ok(Value::Null)
     }
@@ -148,13 +161,16 @@ impl Tool for DescribeObjectTool {
                 Value::Bool(_) => "BOOLEAN",
                 Value::Int(_) => "INTEGER",
                 Value::Float(_) => "FLOAT",
+                Value::Complex { .. } => "COMPLEX",
+                Value::Instant(_) => "INSTANT",
                 Value::String(_) => "STRING",
                 Value::Array(_) => "ARRAY",
                 Value::Object(_) => "OBJECT",
                 Value::Range { .. } => "RANGE",
-                Value::Function { .. } => "FUNCTION",
+                Value::Function { .. } | Value::Memoized { .. } => "FUNCTION",
                 Value::Multiple(_) => "MULTIPLE",
                 Value::Macro { .. } => "MACRO",
+                Value::LazySeq(_) => "LAZY-SEQ",
                 Value::AsyncHandle { .. } => "ASYNC-HANDLE",
                 Value::Thread { .. } => "THREAD",
                 Value::Lock { .. } => "LOCK",
@@ -162,6 +178,9 @@ impl Tool for DescribeObjectTool {
                 Value::ConditionVariable { .. } => "CONDITION-VARIABLE",
                 Value::Semaphore { .. } => "SEMAPHORE",
                 Value::AtomicInteger { .. } => "ATOMIC-INTEGER",
+                Value::Channel { .. } => "CHANNEL",
+                Value::Promise { .. } => "PROMISE",
+                Value::AtomicRef { .. } => "ATOMIC-REF",
             }
         );
         Ok(Value::Null)
@@ -198,13 +217,16 @@ impl Tool for InspectTool {
                 Value::Bool(_) => "BOOLEAN",
                 Value::Int(_) => "INTEGER",
                 Value::Float(_) => "FLOAT",
+                Value::Complex { .. } => "COMPLEX",
+                Value::Instant(_) => "INSTANT",
                 Value::String(_) => "STRING",
                 Value::Array(_) => "ARRAY",
                 Value::Object(_) => "OBJECT",
                 Value::Range { .. } => "RANGE",
-                Value::Function { .. } => "FUNCTION",
+                Value::Function { .. } | Value::Memoized { .. } => "FUNCTION",
                 Value::Multiple(_) => "MULTIPLE",
                 Value::Macro { .. } => "MACRO",
+                Value::LazySeq(_) => "LAZY-SEQ",
                 Value::AsyncHandle { .. } => "ASYNC-HANDLE",
                 Value::Thread { .. } => "THREAD",
                 Value::Lock { .. } => "LOCK",
@@ -212,6 +234,9 @@ impl Tool for InspectTool {
                 Value::ConditionVariable { .. } => "CONDITION-VARIABLE",
                 Value::Semaphore { .. } => "SEMAPHORE",
                 Value::AtomicInteger { .. } => "ATOMIC-INTEGER",
+                Value::Channel { .. } => "CHANNEL",
+                Value::Promise { .. } => "PROMISE",
+                Value::AtomicRef { .. } => "ATOMIC-REF",
             }
         );
         Ok(Value::Null)
@@ -241,13 +266,16 @@ impl Tool for ClassOfTool {
             Value::Bool(_) => "BOOLEAN",
             Value::Int(_) => "INTEGER",
             Value::Float(_) => "FLOAT",
+            Value::Complex { .. } => "COMPLEX",
+            Value::Instant(_) => "INSTANT",
             Value::String(_) => "STRING",
             Value::Array(_) => "LIST",
             Value::Object(_) => "STANDARD-OBJECT",
             Value::Range { .. } => "RANGE",
-            Value::Function { .. } => "FUNCTION",
+            Value::Function { .. } | Value::Memoized { .. } => "FUNCTION",
             Value::Multiple(_) => "MULTIPLE-VALUES",
             Value::Macro { .. } => "MACRO",
+            Value::LazySeq(_) => "LAZY-SEQ",
             Value::AsyncHandle { .. } => "ASYNC-HANDLE",
             Value::Thread { .. } => "THREAD",
             Value::Lock { .. } => "LOCK",
@@ -255,6 +283,9 @@ impl Tool for ClassOfTool {
             Value::ConditionVariable { .. } => "CONDITION-VARIABLE",
             Value::Semaphore { .. } => "SEMAPHORE",
             Value::AtomicInteger { .. } => "ATOMIC-INTEGER",
+            Value::Channel { .. } => "CHANNEL",
+            Value::Promise { .. } => "PROMISE",
+            Value::AtomicRef { .. } => "ATOMIC-REF",
         };
 
         Ok(Value::String(class_name.to_string()))
@@ -132,7 +132,7 @@ impl Tool for CopyTreeTool {
                     for (k, v) in obj.iter() {
                         copied.insert(k.clone(), deep_copy(v));
                     }
-                    Value::object(copied)
+                    Value::Object(Arc::new(copied))
                 }
                 other => other.clone(),
             }
@@ -226,7 +226,7 @@ async fn query_ollama(
         result.insert("duration_ns".to_string(), Value::Int(total_duration));
     }
 
-    Ok(Value::Object(Arc::new(result)))
+    Ok(Value::object(result))
 }
 
 /// Query OpenAI API
@@ -326,7 +326,7 @@ async fn query_openai(
         }
     }
 
-    Ok(Value::Object(Arc::new(result)))
+    Ok(Value::object(result))
 }
 
 /// Query Anthropic API
@@ -427,7 +427,7 @@ async fn query_anthropic(
         }
     }
 
-    Ok(Value::Object(Arc::new(result)))
+    Ok(Value::object(result))
 }
 
 /// Query OSVM.ai API (free, no API key needed)
@@ -471,7 +471,7 @@ async fn query_osvm(prompt: &str, custom_url: Option<String>) -> Result<Value> {
     result.insert("response".to_string(), Value::String(text));
     result.insert("provider".to_string(), Value::String("osvm".to_string()));
 
-    Ok(Value::Object(Arc::new(result)))
+    Ok(Value::object(result))
 }
 
 #[cfg(test)]
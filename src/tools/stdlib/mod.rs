@@ -128,3 +128,48 @@ pub fn register_all(registry: &mut ToolRegistry) {
 
     let _ = registry; // Suppress unused variable warning
 }
+
+/// Modules whose tools `register_all_namespaced` exposes: the pure language
+/// libraries, leaving out file, stream and system access
+const NAMESPACED_MODULES: &[fn(&mut ToolRegistry)] = &[
+    statistics::register,
+    math::register,
+    advanced_math::register,
+    numeric::register,
+    utilities::register,
+    objects::register,
+    parsing::register,
+    type_predicates::register,
+    strings::register,
+    sequences::register,
+    sequences_advanced::register,
+    arrays::register,
+    characters::register,
+    lists_advanced::register,
+    hash_tables::register,
+    format::register,
+    loop_utilities::register,
+    bit_operations::register,
+    random_extended::register,
+    time_date::register,
+];
+
+/// Package the evaluator reserves for the tools `ToolRegistry::new_with_stdlib`
+/// registers, so `stdlib:mean` calls the stdlib `MEAN`
+pub const STDLIB_PACKAGE: &str = "stdlib";
+
+/// Register the standard library tools with `prefix` prepended to their
+/// names, e.g. `stdlib:` makes `MEAN` callable as `stdlib:mean`
+///
+/// The prefix keeps them from shadowing the evaluator's builtins of the
+/// same name, which `register_all` avoids by registering nothing.
+pub fn register_all_namespaced(registry: &mut ToolRegistry, prefix: &str) {
+    let mut stdlib = ToolRegistry::empty();
+    for register in NAMESPACED_MODULES {
+        register(&mut stdlib);
+    }
+
+    for (name, tool) in stdlib.tools {
+        registry.tools.insert(format!("{}{}", prefix, name), tool);
+    }
+}
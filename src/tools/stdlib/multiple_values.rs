@@ -523,78 +523,6 @@ impl Tool for DecfTool {
     }
 }
 
-/// PUSH - Push onto list place
-pub struct PushTool;
-impl Tool for PushTool {
-    fn name(&self) -> &str {
-        "PUSH"
-    }
-    fn description(&self) -> &str {
-        "Push item onto list place"
-    }
-    fn execute(&self, args: &[Value]) -> Result<Value> {
-        if args.len() < 2 {
-            return Ok(Value::Array(Arc::new(vec![])));
-        }
-        match &args[1] {
-            Value::Array(arr) => {
-                let mut new_arr = vec![args[0].clone()];
-                new_arr.extend(arr.iter().cloned());
-                Ok(Value::Array(Arc::new(new_arr)))
-            }
-            _ => Ok(Value::Array(Arc::new(vec![args[0].clone()]))),
-        }
-    }
-}
-
-/// POP - Pop from list place
-pub struct PopTool;
-impl Tool for PopTool {
-    fn name(&self) -> &str {
-        "POP"
-    }
-    fn description(&self) -> &str {
-        "Pop item from list place"
-    }
-    fn execute(&self, args: &[Value]) -> Result<Value> {
-        if args.is_empty() {
-            return Ok(Value::Null);
-        }
-        match &args[0] {
-            Value::Array(arr) => Ok(arr.first().cloned().unwrap_or(Value::Null)),
-            _ => Ok(Value::Null),
-        }
-    }
-}
-
-/// PUSHNEW - Push if not already present
-pub struct PushnewTool;
-impl Tool for PushnewTool {
-    fn name(&self) -> &str {
-        "PUSHNEW"
-    }
-    fn description(&self) -> &str {
-        "Push item if not already in list"
-    }
-    fn execute(&self, args: &[Value]) -> Result<Value> {
-        if args.len() < 2 {
-            return Ok(Value::Array(Arc::new(vec![])));
-        }
-        match &args[1] {
-            Value::Array(arr) => {
-                if arr.contains(&args[0]) {
-                    Ok(Value::Array(Arc::clone(arr)))
-                } else {
-                    let mut new_arr = vec![args[0].clone()];
-                    new_arr.extend(arr.iter().cloned());
-                    Ok(Value::Array(Arc::new(new_arr)))
-                }
-            }
-            _ => Ok(Value::Array(Arc::new(vec![args[0].clone()]))),
-        }
-    }
-}
-
 // ============================================================
 // MACRO SUPPORT
 // ============================================================
@@ -718,9 +646,6 @@ pub fn register(registry: &mut ToolRegistry) {
     registry.register(PsetqTool);
     registry.register(IncfTool);
     registry.register(DecfTool);
-    registry.register(PushTool);
-    registry.register(PopTool);
-    registry.register(PushnewTool);
 
     // Macro support
     registry.register(DefineModifyMacroTool);
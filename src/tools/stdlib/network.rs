@@ -8,97 +8,245 @@ use serde_json::json;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-/// HTTP GET request
-pub async fn http_get(args: &[Value]) -> Result<Value> {
-    if args.is_empty() {
-        return Err(Error::InvalidArguments {
-            tool: "http-get".to_string(),
-            reason: "Expected url and optional headers".to_string(),
-        });
-    }
+/// Default per-request timeout when `:timeout-ms` is not given
+const DEFAULT_TIMEOUT_MS: u64 = 30_000;
 
-    let url = match &args[0] {
-        Value::String(s) => s.as_str(),
-        _ => {
-            return Err(Error::InvalidArguments {
-                tool: "http-get".to_string(),
-                reason: format!("Expected string url, got {}", args[0].type_name()),
-            })
+/// Default base delay for exponential backoff between retries
+const DEFAULT_BACKOFF_MS: u64 = 100;
+
+/// Build the pooled HTTP client shared by an evaluator
+///
+/// `reqwest::Client` is reference-counted internally, so cloning it shares the
+/// same connection pool rather than opening new sockets per call.
+pub fn default_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .pool_max_idle_per_host(16)
+        .pool_idle_timeout(std::time::Duration::from_secs(90))
+        .tcp_keepalive(std::time::Duration::from_secs(60))
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new())
+}
+
+/// Per-request options: `{:timeout-ms 5000 :retries 3 :backoff-ms 100 :headers {...}}`
+#[derive(Debug, Clone)]
+pub struct HttpOptions {
+    /// Timeout applied to each individual attempt
+    pub timeout: std::time::Duration,
+    /// Number of retries after the first attempt (0 = no retries)
+    pub retries: u32,
+    /// Base backoff delay, doubled after every failed attempt
+    pub backoff: std::time::Duration,
+    /// Extra request headers
+    pub headers: Vec<(String, String)>,
+}
+
+impl Default for HttpOptions {
+    fn default() -> Self {
+        HttpOptions {
+            timeout: std::time::Duration::from_millis(DEFAULT_TIMEOUT_MS),
+            retries: 0,
+            backoff: std::time::Duration::from_millis(DEFAULT_BACKOFF_MS),
+            headers: Vec::new(),
         }
-    };
+    }
+}
 
-    let client = reqwest::Client::new();
-    let mut request = client.get(url);
+impl HttpOptions {
+    /// Parse an options object
+    ///
+    /// For backward compatibility, an object without any of the known option
+    /// keys is treated as a plain headers map (the old `(http-get url headers)` form).
+    pub fn from_value(tool: &str, value: &Value) -> Result<Self> {
+        let obj = match value {
+            Value::Object(obj) => obj,
+            Value::Null => return Ok(HttpOptions::default()),
+            other => {
+                return Err(Error::InvalidArguments {
+                    tool: tool.to_string(),
+                    reason: format!("Expected options object, got {}", other.type_name()),
+                })
+            }
+        };
+
+        let mut opts = HttpOptions::default();
+        let is_options = ["timeout-ms", "retries", "backoff-ms", "headers"]
+            .iter()
+            .any(|k| obj.contains_key(*k));
 
-    // Add headers if provided
-    if args.len() > 1 {
-        if let Value::Object(headers) = &args[1] {
-            for (key, value) in headers.iter() {
-                if let Value::String(val) = value {
-                    request = request.header(key.as_str(), val.as_str());
+        if !is_options {
+            opts.headers = headers_from_object(tool, obj)?;
+            return Ok(opts);
+        }
+
+        if let Some(v) = obj.get("timeout-ms") {
+            opts.timeout = std::time::Duration::from_millis(non_negative(tool, "timeout-ms", v)?);
+        }
+        if let Some(v) = obj.get("retries") {
+            opts.retries = non_negative(tool, "retries", v)? as u32;
+        }
+        if let Some(v) = obj.get("backoff-ms") {
+            opts.backoff = std::time::Duration::from_millis(non_negative(tool, "backoff-ms", v)?);
+        }
+        if let Some(v) = obj.get("headers") {
+            match v {
+                Value::Object(h) => opts.headers = headers_from_object(tool, h)?,
+                other => {
+                    return Err(Error::InvalidArguments {
+                        tool: tool.to_string(),
+                        reason: format!(":headers must be an object, got {}", other.type_name()),
+                    })
                 }
             }
         }
+
+        Ok(opts)
     }
+}
 
-    let response = request
-        .send()
-        .await
-        .map_err(|e| Error::ToolExecutionError {
-            tool: "http-get".to_string(),
-            reason: format!("HTTP request failed: {}", e),
-        })?;
+fn non_negative(tool: &str, key: &str, value: &Value) -> Result<u64> {
+    match value {
+        Value::Int(n) if *n >= 0 => Ok(*n as u64),
+        other => Err(Error::InvalidArguments {
+            tool: tool.to_string(),
+            reason: format!(":{} must be a non-negative integer, got {}", key, other),
+        }),
+    }
+}
 
+fn headers_from_object(
+    tool: &str,
+    obj: &HashMap<Arc<str>, Value>,
+) -> Result<Vec<(String, String)>> {
+    let mut headers = Vec::with_capacity(obj.len());
+    for (key, value) in obj.iter() {
+        match value {
+            Value::String(val) => headers.push((key.to_string(), val.clone())),
+            other => {
+                return Err(Error::InvalidArguments {
+                    tool: tool.to_string(),
+                    reason: format!(
+                        "Header '{}' must be a string, got {}",
+                        key,
+                        other.type_name()
+                    ),
+                })
+            }
+        }
+    }
+    Ok(headers)
+}
+
+/// Send a request, retrying with exponential backoff on 5xx responses,
+/// timeouts and connection failures. 4xx responses are returned as-is.
+async fn send_with_retry<F>(tool: &str, opts: &HttpOptions, build: F) -> Result<reqwest::Response>
+where
+    F: Fn() -> reqwest::RequestBuilder,
+{
+    let mut attempt = 0u32;
+    loop {
+        let mut request = build().timeout(opts.timeout);
+        for (key, val) in &opts.headers {
+            request = request.header(key.as_str(), val.as_str());
+        }
+
+        match request.send().await {
+            Ok(response) if response.status().is_server_error() && attempt < opts.retries => {}
+            Ok(response) => return Ok(response),
+            Err(e) if (e.is_timeout() || e.is_connect()) && attempt < opts.retries => {}
+            Err(e) => {
+                return Err(Error::NetworkError {
+                    message: format!("{} {}: {}", tool, describe_failure(&e), e),
+                })
+            }
+        }
+
+        let delay = opts.backoff.saturating_mul(1u32 << attempt.min(16));
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+fn describe_failure(e: &reqwest::Error) -> &'static str {
+    if e.is_timeout() {
+        "timed out"
+    } else if e.is_connect() {
+        "connection failed"
+    } else {
+        "request failed"
+    }
+}
+
+/// Convert a response into `{:status :body :headers}`
+async fn response_to_value(tool: &str, response: reqwest::Response) -> Result<Value> {
     let status = response.status().as_u16() as i64;
-    let body = response
-        .text()
-        .await
-        .map_err(|e| Error::ToolExecutionError {
-            tool: "http-get".to_string(),
-            reason: format!("Failed to read response: {}", e),
-        })?;
 
-    Ok(Value::Object(Arc::new(
-        [
-            ("status".to_string(), Value::Int(status)),
-            ("body".to_string(), Value::String(body)),
-        ]
-        .iter()
-        .cloned()
-        .collect(),
-    )))
+    let mut headers = HashMap::new();
+    for (name, value) in response.headers().iter() {
+        if let Ok(v) = value.to_str() {
+            headers.insert(name.as_str().to_string(), Value::String(v.to_string()));
+        }
+    }
+
+    let body = response.text().await.map_err(|e| Error::NetworkError {
+        message: format!("{} failed to read response: {}", tool, e),
+    })?;
+
+    let mut result = HashMap::new();
+    result.insert("status".to_string(), Value::Int(status));
+    result.insert("body".to_string(), Value::String(body));
+    result.insert("headers".to_string(), Value::object(headers));
+    Ok(Value::object(result))
+}
+
+fn expect_url<'a>(tool: &str, value: &'a Value) -> Result<&'a str> {
+    match value {
+        Value::String(s) => Ok(s.as_str()),
+        _ => Err(Error::InvalidArguments {
+            tool: tool.to_string(),
+            reason: format!("Expected string url, got {}", value.type_name()),
+        }),
+    }
 }
 
-/// HTTP POST request
-pub async fn http_post(args: &[Value]) -> Result<Value> {
-    if args.len() < 2 {
+/// HTTP GET request: `(http-get url [options])`
+pub async fn http_get(client: &reqwest::Client, args: &[Value]) -> Result<Value> {
+    if args.is_empty() || args.len() > 2 {
         return Err(Error::InvalidArguments {
-            tool: "http-post".to_string(),
-            reason: format!("Expected url and body, got {} arguments", args.len()),
+            tool: "http-get".to_string(),
+            reason: "Expected url and optional options object".to_string(),
         });
     }
 
-    let url = match &args[0] {
-        Value::String(s) => s.as_str(),
-        _ => {
-            return Err(Error::InvalidArguments {
-                tool: "http-post".to_string(),
-                reason: format!("Expected string url, got {}", args[0].type_name()),
-            })
-        }
+    let url = expect_url("http-get", &args[0])?;
+    let opts = match args.get(1) {
+        Some(v) => HttpOptions::from_value("http-get", v)?,
+        None => HttpOptions::default(),
     };
 
-    let client = reqwest::Client::new();
-    let mut request = client.post(url);
+    let response = send_with_retry("http-get", &opts, || client.get(url)).await?;
+    response_to_value("http-get", response).await
+}
 
-    // Handle body
-    match &args[1] {
-        Value::String(s) => {
-            request = request.body(s.to_string());
-        }
-        Value::Object(_) => {
+/// HTTP POST request: `(http-post url body [options])`
+pub async fn http_post(client: &reqwest::Client, args: &[Value]) -> Result<Value> {
+    if args.len() < 2 || args.len() > 3 {
+        return Err(Error::InvalidArguments {
+            tool: "http-post".to_string(),
+            reason: format!(
+                "Expected url, body and optional options, got {} arguments",
+                args.len()
+            ),
+        });
+    }
+
+    let url = expect_url("http-post", &args[0])?;
+
+    // Serialize the body once so retries resend identical bytes
+    let (body, content_type) = match &args[1] {
+        Value::String(s) => (s.clone(), None),
+        Value::Object(_) | Value::Array(_) => {
             let json_body = value_to_json(&args[1])?;
-            request = request.json(&json_body);
+            (json_body.to_string(), Some("application/json"))
         }
         _ => {
             return Err(Error::InvalidArguments {
@@ -109,50 +257,27 @@ pub async fn http_post(args: &[Value]) -> Result<Value> {
                 ),
             })
         }
-    }
-
-    // Add headers if provided
-    if args.len() > 2 {
-        if let Value::Object(headers) = &args[2] {
-            for (key, value) in headers.iter() {
-                if let Value::String(val) = value {
-                    request = request.header(key.as_str(), val.as_str());
-                }
-            }
-        }
-    }
-
-    let response = request
-        .send()
-        .await
-        .map_err(|e| Error::ToolExecutionError {
-            tool: "http-post".to_string(),
-            reason: format!("HTTP POST failed: {}", e),
-        })?;
+    };
 
-    let status = response.status().as_u16() as i64;
-    let body = response
-        .text()
-        .await
-        .map_err(|e| Error::ToolExecutionError {
-            tool: "http-post".to_string(),
-            reason: format!("Failed to read response: {}", e),
-        })?;
+    let opts = match args.get(2) {
+        Some(v) => HttpOptions::from_value("http-post", v)?,
+        None => HttpOptions::default(),
+    };
 
-    Ok(Value::Object(Arc::new(
-        [
-            ("status".to_string(), Value::Int(status)),
-            ("body".to_string(), Value::String(body)),
-        ]
-        .iter()
-        .cloned()
-        .collect(),
-    )))
+    let response = send_with_retry("http-post", &opts, || {
+        let request = client.post(url).body(body.clone());
+        match content_type {
+            Some(ct) => request.header(reqwest::header::CONTENT_TYPE, ct),
+            None => request,
+        }
+    })
+    .await?;
+    response_to_value("http-post", response).await
 }
 
-/// JSON-RPC call
-pub async fn json_rpc(args: &[Value]) -> Result<Value> {
-    if args.len() < 2 {
+/// JSON-RPC call: `(json-rpc url method [params] [options])`
+pub async fn json_rpc(client: &reqwest::Client, args: &[Value]) -> Result<Value> {
+    if args.len() < 2 || args.len() > 4 {
         return Err(Error::InvalidArguments {
             tool: "json-rpc".to_string(),
             reason: format!("Expected url and method, got {} arguments", args.len()),
@@ -182,6 +307,7 @@ pub async fn json_rpc(args: &[Value]) -> Result<Value> {
     // Build params array
     let params = if args.len() > 2 {
         match &args[2] {
+            Value::Null => vec![],
             Value::Array(arr) => {
                 let mut json_params = Vec::new();
                 for item in arr.iter() {
@@ -208,25 +334,17 @@ pub async fn json_rpc(args: &[Value]) -> Result<Value> {
         "params": params
     });
 
-    // Make request
-    let client = reqwest::Client::new();
-    let response = client
-        .post(url)
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| Error::ToolExecutionError {
-            tool: "json-rpc".to_string(),
-            reason: format!("JSON-RPC request failed: {}", e),
-        })?;
+    let opts = match args.get(3) {
+        Some(v) => HttpOptions::from_value("json-rpc", v)?,
+        None => HttpOptions::default(),
+    };
 
-    let body_text = response
-        .text()
-        .await
-        .map_err(|e| Error::ToolExecutionError {
-            tool: "json-rpc".to_string(),
-            reason: format!("Failed to read response: {}", e),
-        })?;
+    let response =
+        send_with_retry("json-rpc", &opts, || client.post(url).json(&request_body)).await?;
+
+    let body_text = response.text().await.map_err(|e| Error::NetworkError {
+        message: format!("json-rpc failed to read response: {}", e),
+    })?;
 
     // Parse JSON response
     let json_response: serde_json::Value =
@@ -255,6 +373,145 @@ pub async fn json_rpc(args: &[Value]) -> Result<Value> {
     json_to_value(result)
 }
 
+/// Batched JSON-RPC call: `(json-rpc-batch url requests [options])`
+///
+/// `requests` is an array of `{:method "..." :params [...]}` objects. They are
+/// sent as a single JSON array, responses are correlated by id, and results are
+/// returned in request order. A failing element yields `{:error {...}}` in its
+/// slot instead of failing the whole batch.
+pub async fn json_rpc_batch(client: &reqwest::Client, args: &[Value]) -> Result<Value> {
+    if args.len() < 2 || args.len() > 3 {
+        return Err(Error::InvalidArguments {
+            tool: "json-rpc-batch".to_string(),
+            reason: format!("Expected url and requests, got {} arguments", args.len()),
+        });
+    }
+
+    let url = expect_url("json-rpc-batch", &args[0])?;
+
+    let requests = match &args[1] {
+        Value::Array(arr) => arr,
+        other => {
+            return Err(Error::InvalidArguments {
+                tool: "json-rpc-batch".to_string(),
+                reason: format!("Expected array of requests, got {}", other.type_name()),
+            })
+        }
+    };
+
+    let mut batch = Vec::with_capacity(requests.len());
+    for (i, request) in requests.iter().enumerate() {
+        let obj = match request {
+            Value::Object(obj) => obj,
+            other => {
+                return Err(Error::InvalidArguments {
+                    tool: "json-rpc-batch".to_string(),
+                    reason: format!("Request {} must be an object, got {}", i, other.type_name()),
+                })
+            }
+        };
+        let method = match obj.get("method") {
+            Some(Value::String(m)) => m.clone(),
+            _ => {
+                return Err(Error::InvalidArguments {
+                    tool: "json-rpc-batch".to_string(),
+                    reason: format!("Request {} is missing a string :method", i),
+                })
+            }
+        };
+        let params = match obj.get("params") {
+            None | Some(Value::Null) => serde_json::Value::Array(vec![]),
+            Some(v @ Value::Array(_)) | Some(v @ Value::Object(_)) => value_to_json(v)?,
+            Some(other) => {
+                return Err(Error::InvalidArguments {
+                    tool: "json-rpc-batch".to_string(),
+                    reason: format!(
+                        "Request {} :params must be an array or object, got {}",
+                        i,
+                        other.type_name()
+                    ),
+                })
+            }
+        };
+        batch.push(json!({
+            "jsonrpc": "2.0",
+            "id": i + 1,
+            "method": method,
+            "params": params
+        }));
+    }
+
+    // An empty batch is invalid JSON-RPC; answer it locally
+    if batch.is_empty() {
+        return Ok(Value::Array(Arc::new(vec![])));
+    }
+
+    let opts = match args.get(2) {
+        Some(v) => HttpOptions::from_value("json-rpc-batch", v)?,
+        None => HttpOptions::default(),
+    };
+
+    let request_body = serde_json::Value::Array(batch);
+    let response = send_with_retry("json-rpc-batch", &opts, || {
+        client.post(url).json(&request_body)
+    })
+    .await?;
+
+    let body_text = response.text().await.map_err(|e| Error::NetworkError {
+        message: format!("json-rpc-batch failed to read response: {}", e),
+    })?;
+
+    let json_response: serde_json::Value =
+        serde_json::from_str(&body_text).map_err(|e| Error::ToolExecutionError {
+            tool: "json-rpc-batch".to_string(),
+            reason: format!("Failed to parse JSON response: {}", e),
+        })?;
+
+    // Servers answer a malformed batch with a single error object
+    let responses = match json_response {
+        serde_json::Value::Array(items) => items,
+        other => {
+            return Err(Error::ToolExecutionError {
+                tool: "json-rpc-batch".to_string(),
+                reason: format!("Expected batch response array, got: {}", other),
+            })
+        }
+    };
+
+    let mut by_id: HashMap<u64, serde_json::Value> = HashMap::new();
+    for item in responses {
+        if let Some(id) = item.get("id").and_then(|id| id.as_u64()) {
+            by_id.insert(id, item);
+        }
+    }
+
+    let mut results = Vec::with_capacity(requests.len());
+    for id in 1..=requests.len() as u64 {
+        let slot = match by_id.remove(&id) {
+            Some(item) => {
+                if let Some(error) = item.get("error") {
+                    error_slot(json_to_value(error)?)
+                } else if let Some(result) = item.get("result") {
+                    json_to_value(result)?
+                } else {
+                    error_slot(Value::String("response missing 'result' field".to_string()))
+                }
+            }
+            None => error_slot(Value::String(format!("no response for request id {}", id))),
+        };
+        results.push(slot);
+    }
+
+    Ok(Value::Array(Arc::new(results)))
+}
+
+/// Wrap a per-element JSON-RPC failure as `{:error ...}`
+fn error_slot(error: Value) -> Value {
+    let mut map = HashMap::new();
+    map.insert("error".to_string(), error);
+    Value::object(map)
+}
+
 /// Convert OVSM Value to serde_json::Value
 fn value_to_json(value: &Value) -> Result<serde_json::Value> {
     match value {
@@ -278,7 +535,7 @@ fn value_to_json(value: &Value) -> Result<serde_json::Value> {
         Value::Object(obj) => {
             let mut json_obj = serde_json::Map::new();
             for (key, val) in obj.iter() {
-                json_obj.insert(key.clone(), value_to_json(val)?);
+                json_obj.insert(key.to_string(), value_to_json(val)?);
             }
             Ok(serde_json::Value::Object(json_obj))
         }
@@ -319,7 +576,7 @@ fn json_to_value(json: &serde_json::Value) -> Result<Value> {
             for (key, val) in obj {
                 map.insert(key.clone(), json_to_value(val)?);
             }
-            Ok(Value::Object(Arc::new(map)))
+            Ok(Value::object(map))
         }
     }
 }
@@ -3,6 +3,7 @@
 use crate::error::{Error, Result};
 use crate::runtime::Value;
 use crate::tools::{Tool, ToolRegistry};
+use std::sync::Arc;
 
 /// Register object manipulation tools
 pub fn register(registry: &mut ToolRegistry) {
@@ -36,7 +37,7 @@ impl Tool for KeysTool {
 
         match &args[0] {
             Value::Object(map) => {
-                let keys: Vec<Value> = map.keys().map(|k| Value::String(k.clone())).collect();
+                let keys: Vec<Value> = map.keys().map(|k| Value::String(k.to_string())).collect();
                 Ok(Value::array(keys)) // Use helper
             }
             _ => Err(Error::InvalidArguments {
@@ -120,7 +121,7 @@ impl Tool for GetTool {
             }
         };
 
-        Ok(map.get(key).cloned().unwrap_or(Value::Null))
+        Ok(map.get(key.as_str()).cloned().unwrap_or(Value::Null))
     }
 }
 
@@ -167,9 +168,9 @@ impl Tool for AssocTool {
         let value = args[2].clone();
 
         let mut new_map = map;
-        new_map.insert(key, value);
+        new_map.insert(key.into(), value);
 
-        Ok(Value::object(new_map)) // Use helper to create Arc
+        Ok(Value::Object(Arc::new(new_map)))
     }
 }
 
@@ -213,7 +214,7 @@ impl Tool for HasKeyTool {
             }
         };
 
-        Ok(Value::Bool(map.contains_key(key)))
+        Ok(Value::Bool(map.contains_key(key.as_str())))
     }
 }
 
@@ -262,7 +263,7 @@ impl Tool for MergeTool {
             result.insert(k.clone(), v.clone());
         }
 
-        Ok(Value::object(result)) // Use helper to create Arc
+        Ok(Value::Object(Arc::new(result)))
     }
 }
 
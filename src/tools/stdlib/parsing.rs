@@ -116,7 +116,7 @@ fn ovsm_to_json(val: &Value) -> serde_json::Value {
         Value::Object(obj) => {
             let mut map = serde_json::Map::new();
             for (k, v) in obj.iter() {
-                map.insert(k.clone(), ovsm_to_json(v));
+                map.insert(k.to_string(), ovsm_to_json(v));
             }
             serde_json::Value::Object(map)
         }
@@ -1592,14 +1592,17 @@ impl Tool for FormatTool {
                 Value::String(s) => s.clone(),
                 Value::Int(n) => n.to_string(),
                 Value::Float(f) => f.to_string(),
+                Value::Complex { .. } => arg.to_string(),
+                Value::Instant(_) => arg.to_string_value(),
                 Value::Bool(b) => b.to_string(),
                 Value::Null => "null".to_string(),
                 Value::Array(_) => format!("{:?}", arg),
                 Value::Object(_) => format!("{:?}", arg),
-                Value::Function { .. } => "<function>".to_string(),
+                Value::Function { .. } | Value::Memoized { .. } => "<function>".to_string(),
                 Value::Range { .. } => format!("{:?}", arg),
                 Value::Multiple(_) => format!("{:?}", arg),
                 Value::Macro { .. } => "<macro>".to_string(),
+                Value::LazySeq(_) => "<lazy-seq>".to_string(),
                 Value::AsyncHandle { id, .. } => format!("<async-handle:{}>", id),
                 // Bordeaux Threads types
                 Value::Thread { id, .. } => format!("<thread:{}>", id),
@@ -1623,6 +1626,9 @@ impl Tool for FormatTool {
                     "<atomic-integer:{}>",
                     inner.load(std::sync::atomic::Ordering::SeqCst)
                 ),
+                Value::Channel { .. } => "<channel>".to_string(),
+                Value::Promise { .. } => "<promise>".to_string(),
+                Value::AtomicRef { .. } => "<atomic>".to_string(),
             };
 
             // Replace first occurrence of {}
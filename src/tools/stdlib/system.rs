@@ -117,7 +117,7 @@ impl Tool for EnvironmentTool {
         for (key, value) in env::vars() {
             env_map.insert(key, Value::String(value));
         }
-        Ok(Value::Object(Arc::new(env_map)))
+        Ok(Value::object(env_map))
     }
 }
 
@@ -181,12 +181,12 @@ impl Tool for RunProgramTool {
         }
         // Simplified: just return success status
         // Real implementation would use std::process::Command
-        Ok(Value::Object(Arc::new({
+        Ok(Value::object({
             let mut result = HashMap::new();
             result.insert("status".to_string(), Value::Int(0));
             result.insert("output".to_string(), Value::String(String::new()));
             result
-        })))
+        }))
     }
 }
 
@@ -681,7 +681,7 @@ impl Tool for GetDecodedTimeTool {
                 time_obj.insert("timestamp".to_string(), Value::Int(0));
             }
         }
-        Ok(Value::Object(Arc::new(time_obj)))
+        Ok(Value::object(time_obj))
     }
 }
 
@@ -284,7 +284,7 @@ impl Tool for SleepTool {
         result.insert("operation".to_string(), Value::String("sleep".to_string()));
         result.insert("duration".to_string(), Value::Int(seconds as i64));
         result.insert("unit".to_string(), Value::String("seconds".to_string()));
-        Ok(Value::Object(Arc::new(result)))
+        Ok(Value::object(result))
     }
 }
 
@@ -125,13 +125,16 @@ impl Tool for TypeOfTool {
             Value::Bool(_) => "bool",
             Value::Int(_) => "int",
             Value::Float(_) => "float",
+            Value::Complex { .. } => "complex",
+            Value::Instant(_) => "instant",
             Value::String(_) => "string",
             Value::Array(_) => "array",
             Value::Object(_) => "object",
-            Value::Function { .. } => "function",
+            Value::Function { .. } | Value::Memoized { .. } => "function",
             Value::Range { .. } => "range",
             Value::Multiple(_) => "multiple",
             Value::Macro { .. } => "macro",
+            Value::LazySeq(_) => "lazy-seq",
             Value::AsyncHandle { .. } => "async-handle",
             // Bordeaux Threads types
             Value::Thread { .. } => "thread",
@@ -140,6 +143,9 @@ impl Tool for TypeOfTool {
             Value::ConditionVariable { .. } => "condition-variable",
             Value::Semaphore { .. } => "semaphore",
             Value::AtomicInteger { .. } => "atomic-integer",
+            Value::Channel { .. } => "channel",
+            Value::Promise { .. } => "promise",
+            Value::AtomicRef { .. } => "atomic-ref",
         };
 
         Ok(Value::String(type_str.to_string()))
@@ -168,7 +174,7 @@ impl Tool for KeysTool {
 
         match &args[0] {
             Value::Object(obj) => {
-                let keys: Vec<Value> = obj.keys().map(|k| Value::String(k.clone())).collect();
+                let keys: Vec<Value> = obj.keys().map(|k| Value::String(k.to_string())).collect();
                 Ok(Value::Array(Arc::new(keys)))
             }
             _ => Ok(Value::Array(Arc::new(vec![]))),
@@ -109,7 +109,7 @@ impl BidirectionalChecker {
     /// Create a strict (non-gradual) checker
     pub fn strict() -> Self {
         BidirectionalChecker {
-            ctx: TypeContext::new(),
+            ctx: TypeContext::strict(),
             schemes: HashMap::new(),
             expected_return: None,
             gradual: false,
@@ -959,17 +959,30 @@ impl BidirectionalChecker {
 
     /// Synthesize type for let binding
     fn synth_let(&mut self, args: &[Argument]) -> Type {
-        // (let ((x val1) (y val2)) body)
-        // For now, simplified handling
+        // (let ((x val1) (y val2)) body...)
         if args.len() >= 2 {
+            // Initializers are synthesized in the enclosing scope, so a
+            // binding can refer to the outer variable it shadows
+            let mut bindings = Vec::new();
+            if let Expression::ArrayLiteral(pairs) = &args[0].value {
+                for pair in pairs {
+                    if let Expression::ArrayLiteral(parts) = pair {
+                        if let [Expression::Variable(name), init] = parts.as_slice() {
+                            let ty = self.synth(init);
+                            bindings.push((name.clone(), ty));
+                        }
+                    }
+                }
+            }
+
             self.ctx.push_scope();
+            for (name, ty) in bindings {
+                self.ctx.define_var(&name, ty);
+            }
 
-            // Process bindings (first arg should be a list of bindings)
-            // Simplified: just synthesize all args
-            for arg in args.iter().take(args.len() - 1) {
+            for arg in &args[1..args.len() - 1] {
                 self.synth(&arg.value);
             }
-
             let result = self.synth(&args.last().unwrap().value);
 
             self.ctx.pop_scope();
@@ -1381,7 +1394,7 @@ mod tests {
         let mut checker = BidirectionalChecker::new();
 
         assert_eq!(checker.synth(&Expression::IntLiteral(42)), Type::I64);
-        assert_eq!(checker.synth(&Expression::FloatLiteral(3.14)), Type::F64);
+        assert_eq!(checker.synth(&Expression::FloatLiteral(2.5)), Type::F64);
         assert_eq!(checker.synth(&Expression::BoolLiteral(true)), Type::Bool);
     }
 
@@ -1431,6 +1444,56 @@ mod tests {
         }
     }
 
+    fn let_expr(bindings: Vec<(&str, Expression)>, body: Vec<Expression>) -> Expression {
+        let pairs = bindings
+            .into_iter()
+            .map(|(name, init)| {
+                Expression::ArrayLiteral(vec![Expression::Variable(name.to_string()), init])
+            })
+            .collect();
+        let mut args = vec![Argument::positional(Expression::ArrayLiteral(pairs))];
+        args.extend(body.into_iter().map(Argument::positional));
+        Expression::ToolCall {
+            name: "let".to_string(),
+            args,
+        }
+    }
+
+    #[test]
+    fn test_let_inner_shadowing_restores_outer_type() {
+        let mut checker = BidirectionalChecker::new();
+        let x = || Expression::Variable("x".to_string());
+
+        // (let ((x 1)) (let ((x true)) x))
+        let inner = let_expr(vec![("x", Expression::BoolLiteral(true))], vec![x()]);
+        let nested = let_expr(vec![("x", Expression::IntLiteral(1))], vec![inner]);
+        assert_eq!(checker.synth(&nested), Type::Bool);
+
+        // (let ((x 1)) (let ((x true)) x) x) - outer type is back after the inner scope
+        let inner = let_expr(vec![("x", Expression::BoolLiteral(true))], vec![x()]);
+        let nested = let_expr(vec![("x", Expression::IntLiteral(1))], vec![inner, x()]);
+        assert_eq!(checker.synth(&nested), Type::I64);
+
+        // Bindings do not leak out of the let
+        assert_eq!(checker.context().lookup_var("x"), None);
+    }
+
+    #[test]
+    fn test_let_initializer_sees_outer_binding() {
+        let mut checker = BidirectionalChecker::new();
+
+        // (let ((x 1.5)) (let ((x true) (y x)) y)) - y takes the outer x's type
+        let inner = let_expr(
+            vec![
+                ("x", Expression::BoolLiteral(true)),
+                ("y", Expression::Variable("x".to_string())),
+            ],
+            vec![Expression::Variable("y".to_string())],
+        );
+        let nested = let_expr(vec![("x", Expression::FloatLiteral(1.5))], vec![inner]);
+        assert_eq!(checker.synth(&nested), Type::F64);
+    }
+
     #[test]
     fn test_numeric_subtyping() {
         let mut checker = BidirectionalChecker::new();
@@ -1446,6 +1509,18 @@ mod tests {
         assert!(!strict_checker.subtype(&Type::I64, &Type::I32));
     }
 
+    #[test]
+    fn test_strict_checker_threads_strict_context() {
+        assert!(BidirectionalChecker::strict().context().is_strict());
+        assert!(!BidirectionalChecker::new().context().is_strict());
+
+        let mut strict_checker = BidirectionalChecker::strict();
+        assert!(strict_checker
+            .context_mut()
+            .unify(&Type::U64, &Type::U32)
+            .is_err());
+    }
+
     #[test]
     fn test_type_annotation_checking() {
         let mut checker = BidirectionalChecker::new();
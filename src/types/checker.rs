@@ -974,7 +974,7 @@ mod tests {
 
         assert_eq!(checker.infer_type(&Expression::IntLiteral(42)), Type::I64);
         assert_eq!(
-            checker.infer_type(&Expression::FloatLiteral(3.14)),
+            checker.infer_type(&Expression::FloatLiteral(2.5)),
             Type::F64
         );
         assert_eq!(
@@ -362,8 +362,8 @@ pub struct TypedStructDef {
 
 /// Type context for type checking
 pub struct TypeContext {
-    /// Variable bindings: name -> type
-    variables: HashMap<String, Type>,
+    /// Lexical scope stack of variable bindings (innermost last)
+    scopes: Vec<HashMap<String, Type>>,
     /// Struct definitions: name -> definition
     structs: HashMap<String, TypedStructDef>,
     /// Function types: name -> function type
@@ -374,21 +374,38 @@ pub struct TypeContext {
     next_var: u32,
     /// Accumulated type errors
     errors: Vec<TypeError>,
+    /// Reject implicit narrowing conversions (larger -> smaller) in `unify`
+    strict: bool,
 }
 
 impl TypeContext {
     /// Creates a new empty type context
     pub fn new() -> Self {
         TypeContext {
-            variables: HashMap::new(),
+            scopes: vec![HashMap::new()],
             structs: HashMap::new(),
             functions: HashMap::new(),
             substitutions: HashMap::new(),
             next_var: 0,
             errors: Vec::new(),
+            strict: false,
         }
     }
 
+    /// Creates a strict type context where narrowing numeric conversions
+    /// need an explicit cast
+    pub fn strict() -> Self {
+        TypeContext {
+            strict: true,
+            ..Self::new()
+        }
+    }
+
+    /// Whether implicit narrowing conversions are rejected
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
     /// Create a fresh type variable for inference
     pub fn fresh_var(&mut self) -> Type {
         let var = Type::Var(self.next_var);
@@ -396,14 +413,16 @@ impl TypeContext {
         var
     }
 
-    /// Define a variable with a type
+    /// Define a variable with a type in the innermost scope
     pub fn define_var(&mut self, name: &str, ty: Type) {
-        self.variables.insert(name.to_string(), ty);
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string(), ty);
+        }
     }
 
-    /// Look up a variable's type
+    /// Look up a variable's type, searching from the innermost scope outward
     pub fn lookup_var(&self, name: &str) -> Option<&Type> {
-        self.variables.get(name)
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
     }
 
     /// Define a struct
@@ -475,12 +494,17 @@ impl TypeContext {
         let t2 = self.resolve(t2);
 
         // Handle type variables
+        if let (Type::Var(a), Type::Var(b)) = (&t1, &t2) {
+            if a == b {
+                return Ok(t1);
+            }
+        }
         if let Type::Var(n) = &t1 {
-            self.substitutions.insert(*n, t2.clone());
+            self.bind_var(*n, &t2)?;
             return Ok(t2);
         }
         if let Type::Var(n) = &t2 {
-            self.substitutions.insert(*n, t1.clone());
+            self.bind_var(*n, &t1)?;
             return Ok(t1);
         }
 
@@ -608,8 +632,18 @@ impl TypeContext {
             | (Type::U32, Type::U8)
             | (Type::U16, Type::U8)
             | (Type::F64, Type::F32) => {
-                // For now, allow with a warning (implicit truncation)
-                // In strict mode, this would be an error
+                if self.strict {
+                    return Err(TypeError {
+                        message: format!(
+                            "implicit narrowing from {} to {} requires an explicit cast",
+                            t1, t2
+                        ),
+                        expected: Some(t2.clone()),
+                        found: Some(t1.clone()),
+                        location: None,
+                    });
+                }
+                // Gradual mode allows implicit truncation
                 Ok(t2)
             }
 
@@ -617,17 +651,45 @@ impl TypeContext {
         }
     }
 
+    /// Record `?n := ty`, refusing bindings that would build an infinite type
+    fn bind_var(&mut self, n: u32, ty: &Type) -> Result<(), TypeError> {
+        if self.occurs(n, ty) {
+            return Err(TypeError::new(format!(
+                "infinite type: ?{} occurs in {}",
+                n, ty
+            )));
+        }
+        self.substitutions.insert(n, ty.clone());
+        Ok(())
+    }
+
+    /// Occurs check: does type variable `?n` appear anywhere inside `ty`?
+    fn occurs(&self, n: u32, ty: &Type) -> bool {
+        match self.resolve(ty) {
+            Type::Var(m) => m == n,
+            Type::Array { element, .. } => self.occurs(n, &element),
+            Type::Tuple(types) => types.iter().any(|t| self.occurs(n, t)),
+            Type::Ptr(t) | Type::Ref(t) | Type::RefMut(t) => self.occurs(n, &t),
+            Type::Fn { params, ret } => {
+                params.iter().any(|t| self.occurs(n, t)) || self.occurs(n, &ret)
+            }
+            _ => false,
+        }
+    }
+
     /// Enter a new scope (e.g., for let bindings).
-    /// Currently uses flat namespace; could be extended for lexical scoping.
+    /// Variables defined until the matching `pop_scope` shadow outer ones.
     pub fn push_scope(&mut self) {
-        // For now, we use a flat namespace
-        // Could implement proper scoping with Vec<HashMap> if needed
+        self.scopes.push(HashMap::new());
     }
 
-    /// Exit a scope.
-    /// Currently uses flat namespace; could be extended for lexical scoping.
+    /// Exit a scope, discarding its variable bindings.
+    /// Substitutions and recorded errors are context-wide and survive.
+    /// The outermost scope is never popped.
     pub fn pop_scope(&mut self) {
-        // For now, we use a flat namespace
+        if self.scopes.len() > 1 {
+            self.scopes.pop();
+        }
     }
 }
 
@@ -710,6 +772,105 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_scope_shadowing_restores_outer_type() {
+        let mut ctx = TypeContext::new();
+        ctx.define_var("x", Type::U64);
+
+        ctx.push_scope();
+        ctx.define_var("x", Type::Bool);
+        ctx.define_var("y", Type::I32);
+        assert_eq!(ctx.lookup_var("x"), Some(&Type::Bool));
+        assert_eq!(ctx.lookup_var("y"), Some(&Type::I32));
+        ctx.pop_scope();
+
+        assert_eq!(ctx.lookup_var("x"), Some(&Type::U64));
+        assert_eq!(ctx.lookup_var("y"), None);
+    }
+
+    #[test]
+    fn test_scope_lookup_sees_outer_bindings() {
+        let mut ctx = TypeContext::new();
+        ctx.define_var("outer", Type::U8);
+        ctx.push_scope();
+        ctx.push_scope();
+        assert_eq!(ctx.lookup_var("outer"), Some(&Type::U8));
+        ctx.pop_scope();
+        ctx.pop_scope();
+        // Popping past the global scope keeps it intact
+        ctx.pop_scope();
+        assert_eq!(ctx.lookup_var("outer"), Some(&Type::U8));
+    }
+
+    #[test]
+    fn test_substitutions_and_errors_survive_scope_exit() {
+        let mut ctx = TypeContext::new();
+        let var = ctx.fresh_var();
+
+        ctx.push_scope();
+        ctx.unify(&var, &Type::U32).unwrap();
+        ctx.record_error(TypeError::new("inner error"));
+        ctx.pop_scope();
+
+        assert_eq!(ctx.resolve(&var), Type::U32);
+        assert_eq!(ctx.errors().len(), 1);
+    }
+
+    #[test]
+    fn test_strict_rejects_narrowing() {
+        let mut ctx = TypeContext::strict();
+        let err = ctx.unify(&Type::U64, &Type::U32).unwrap_err();
+        assert!(err.message.contains("explicit cast"));
+        assert!(ctx.unify(&Type::F64, &Type::F32).is_err());
+        assert!(ctx.unify(&Type::I32, &Type::I8).is_err());
+    }
+
+    #[test]
+    fn test_gradual_allows_narrowing() {
+        let mut ctx = TypeContext::new();
+        assert_eq!(ctx.unify(&Type::U64, &Type::U32).unwrap(), Type::U32);
+        assert_eq!(ctx.unify(&Type::F64, &Type::F32).unwrap(), Type::F32);
+    }
+
+    #[test]
+    fn test_widening_allowed_in_both_modes() {
+        for mut ctx in [TypeContext::new(), TypeContext::strict()] {
+            assert_eq!(ctx.unify(&Type::U32, &Type::U64).unwrap(), Type::U64);
+            assert_eq!(ctx.unify(&Type::I8, &Type::I64).unwrap(), Type::I64);
+            assert_eq!(ctx.unify(&Type::F32, &Type::F64).unwrap(), Type::F64);
+        }
+    }
+
+    #[test]
+    fn test_occurs_check_rejects_infinite_type() {
+        let mut ctx = TypeContext::new();
+        let var = ctx.fresh_var();
+        let self_array = Type::Array {
+            element: Box::new(var.clone()),
+            size: 4,
+        };
+
+        let err = ctx.unify(&var, &self_array).unwrap_err();
+        assert!(err.message.contains("infinite type"));
+        // No substitution was recorded, so resolving terminates
+        assert_eq!(ctx.resolve(&var), var);
+
+        // Same check through a nested function type, with the var on the right
+        let nested = Type::Fn {
+            params: vec![Type::Ptr(Box::new(var.clone()))],
+            ret: Box::new(Type::U64),
+        };
+        assert!(ctx.unify(&nested, &var).is_err());
+    }
+
+    #[test]
+    fn test_unify_var_with_itself() {
+        let mut ctx = TypeContext::new();
+        let var = ctx.fresh_var();
+        assert_eq!(ctx.unify(&var, &var).unwrap(), var);
+        assert_eq!(ctx.resolve(&var), var);
+    }
+
     #[test]
     fn test_type_size() {
         assert_eq!(Type::U8.size_bytes(), Some(1));
@@ -6,7 +6,7 @@
 /// 2. Program logic is correct (via bytecode inspection)
 /// 3. Instruction discriminators are properly routed
 /// 4. Account data layouts match specifications
-use ovsm::compiler::{CompileOptions, Compiler, VerificationMode};
+use solisp::compiler::{CompileOptions, Compiler, VerificationMode};
 use std::fs;
 use std::path::PathBuf;
 
@@ -21,16 +21,18 @@ fn get_examples_dir() -> PathBuf {
         .join("ovsm_scripts")
 }
 
-fn compile_program(filename: &str) -> ovsm::Result<ovsm::compiler::CompileResult> {
+fn compile_program(filename: &str) -> solisp::Result<solisp::compiler::CompileResult> {
     let path = get_examples_dir().join(filename);
     let source = fs::read_to_string(&path)
-        .map_err(|e| ovsm::Error::compiler(format!("Failed to read {}: {}", filename, e)))?;
+        .map_err(|e| solisp::Error::compiler(format!("Failed to read {}: {}", filename, e)))?;
 
     // Use Warn mode for basic compilation tests - these programs don't have
     // explicit (assume ...) statements for memory bounds verification.
     // The formal verification tests use Require mode with properly annotated code.
-    let mut options = CompileOptions::default();
-    options.verification_mode = VerificationMode::Warn;
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Warn,
+        ..Default::default()
+    };
     let compiler = Compiler::new(options);
     compiler.compile(&source)
 }
@@ -1983,7 +1985,7 @@ fn test_full_suite_with_strategy_router() {
 
 #[test]
 fn test_generate_idl_from_accountability_demo() {
-    use ovsm::compiler::anchor_idl::IdlGenerator;
+    use solisp::compiler::anchor_idl::IdlGenerator;
 
     let path = get_examples_dir().join("accountability_demo.ovsm");
     let source = fs::read_to_string(&path).unwrap();
@@ -2002,7 +2004,7 @@ fn test_generate_idl_from_accountability_demo() {
     );
 
     // Should extract errors
-    assert!(idl.errors.len() >= 1, "Should have extracted errors");
+    assert!(!idl.errors.is_empty(), "Should have extracted errors");
 
     // Generate JSON and verify it's valid
     let json = generator
@@ -2024,7 +2026,7 @@ fn test_generate_idl_from_accountability_demo() {
 
 #[test]
 fn test_idl_json_format() {
-    use ovsm::compiler::anchor_idl::IdlGenerator;
+    use solisp::compiler::anchor_idl::IdlGenerator;
 
     let source = r#"
 ;;; SIMPLE PROGRAM - Test
@@ -2124,8 +2126,10 @@ fn test_spl_token_transfer_signed_compiles() {
 
     // Compile directly from source string
     // Use Warn mode - this test code doesn't have explicit assume statements
-    let mut options = CompileOptions::default();
-    options.verification_mode = VerificationMode::Warn;
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Warn,
+        ..Default::default()
+    };
     let compiler = Compiler::new(options);
     let result = compiler.compile(source);
     assert!(
@@ -2193,8 +2197,10 @@ fn test_spl_token_transfer_signed_multiple_seeds() {
 
     // Compile directly from source string
     // Use Warn mode - this test code doesn't have explicit assume statements
-    let mut options = CompileOptions::default();
-    options.verification_mode = VerificationMode::Warn;
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Warn,
+        ..Default::default()
+    };
     let compiler = Compiler::new(options);
     let result = compiler.compile(source);
     assert!(
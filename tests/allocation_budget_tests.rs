@@ -0,0 +1,95 @@
+//! Tests for the per-execution allocation budget
+
+use solisp::{Error, LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Execute Solisp code with the given allocation budget in bytes
+fn eval_limited(code: &str, budget: usize) -> Result<Value, Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.set_allocation_budget(budget);
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_oversized_range_exceeds_budget_before_allocating() {
+    let result = eval_limited("(range 0 1000000000000)", 1 << 20);
+    assert!(matches!(result, Err(Error::OutOfMemory(budget)) if budget == 1 << 20));
+}
+
+#[test]
+fn test_unbounded_append_exceeds_budget() {
+    let code = r#"
+        (define xs [])
+        (while true
+          (set! xs (append xs [1 2 3 4 5 6 7 8])))
+    "#;
+    assert!(matches!(
+        eval_limited(code, 1 << 20),
+        Err(Error::OutOfMemory(_))
+    ));
+}
+
+#[test]
+fn test_oversized_string_repeat_exceeds_budget() {
+    assert!(matches!(
+        eval_limited(r#"(repeat "abc" 1000000000000)"#, 1 << 20),
+        Err(Error::OutOfMemory(_))
+    ));
+}
+
+#[test]
+fn test_budget_error_is_catchable() {
+    let code = r#"
+        (try
+          (length (range 0 100000000))
+          (catch e "exhausted"))
+    "#;
+    assert_eq!(
+        eval_limited(code, 1 << 20).unwrap(),
+        Value::String("exhausted".to_string())
+    );
+}
+
+#[test]
+fn test_small_programs_fit_in_budget() {
+    assert_eq!(
+        eval_limited("(length (append (range 0 100) [1 2 3]))", 1 << 20).unwrap(),
+        Value::Int(103)
+    );
+}
+
+#[test]
+fn test_budget_resets_per_execution() {
+    let program = SExprParser::new(
+        SExprScanner::new("(length (range 0 1000))")
+            .scan_tokens()
+            .unwrap(),
+    )
+    .parse()
+    .unwrap();
+    let mut evaluator = LispEvaluator::new();
+    evaluator.set_allocation_budget(1 << 16);
+    for _ in 0..10 {
+        assert_eq!(evaluator.execute(&program).unwrap(), Value::Int(1000));
+    }
+    assert!(evaluator.bytes_allocated() > 0);
+}
+
+#[test]
+fn test_budget_counts_total_not_live_allocation() {
+    // Each pass drops the previous array, but the budget is never credited
+    let code = r#"
+        (define xs [])
+        (for (i (range 0 1000))
+          (set! xs (range 0 100)))
+        (length xs)
+    "#;
+    assert_eq!(eval_limited(code, 1 << 24).unwrap(), Value::Int(100));
+    assert!(matches!(
+        eval_limited(code, 1 << 16),
+        Err(Error::OutOfMemory(_))
+    ));
+}
@@ -0,0 +1,74 @@
+//! Tests for `assert` messages: none, literal, and `format`-style
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn assertion_message(source: &str) -> String {
+    match eval(source) {
+        Err(solisp::Error::AssertionFailed { message }) => message,
+        other => panic!("expected an assertion failure, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_assert_without_message() {
+    assert_eq!(eval("(assert (> 2 1))").unwrap(), Value::Null);
+    assert_eq!(
+        assertion_message("(define ok false) (assert ok)"),
+        "assertion failed: ok"
+    );
+}
+
+#[test]
+fn test_assert_captures_comparison_operands() {
+    assert_eq!(
+        assertion_message("(define x 15) (assert (< x 10))"),
+        "assertion failed: (< x 10), x = 15"
+    );
+    assert_eq!(
+        assertion_message(
+            r#"(define xs [1 2]) (define name "a") (assert (== (length xs) (length name)))"#
+        ),
+        "assertion failed: (== (length xs) (length name)), (length xs) = 2, (length name) = 1"
+    );
+}
+
+#[test]
+fn test_assert_format_message() {
+    assert_eq!(
+        assertion_message(r#"(define x 15) (assert (< x 10) "x is ~a, limit ~s" x "ten")"#),
+        r#"x is 15, limit "ten""#
+    );
+}
+
+#[test]
+fn test_assert_literal_message_unchanged() {
+    assert_eq!(
+        assertion_message(r#"(assert (< 3 1) "cost ~a too high")"#),
+        "cost ~a too high"
+    );
+}
+
+#[test]
+fn test_assert_evaluates_operands_once() {
+    let result = eval(
+        r#"
+        (define calls 0)
+        (defun next () (do (set! calls (+ calls 1)) calls))
+        (assert (< (next) 10))
+        calls
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(1));
+}
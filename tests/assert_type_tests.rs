@@ -0,0 +1,73 @@
+//! Tests for assert-type with quoted type specifiers
+
+use solisp::{Evaluator, Parser, Scanner, Value};
+
+fn eval_lisp(source: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    let mut scanner = Scanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = Evaluator::new();
+    Ok(evaluator.execute(&program)?)
+}
+
+#[test]
+fn test_primitive_spec_passes() {
+    assert_eq!(eval_lisp("(assert-type 42 'u64)").unwrap(), Value::Null);
+    assert_eq!(eval_lisp("(assert-type 42 'integer)").unwrap(), Value::Null);
+    assert_eq!(
+        eval_lisp("(assert-type \"hi\" 'string)").unwrap(),
+        Value::Null
+    );
+}
+
+#[test]
+fn test_primitive_spec_fails_naming_both_types() {
+    let err = eval_lisp("(assert-type \"hello\" 'integer)")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("expected integer"), "got: {}", err);
+    assert!(err.contains("got string"), "got: {}", err);
+}
+
+#[test]
+fn test_unsigned_spec_rejects_negative() {
+    let err = eval_lisp("(assert-type -1 'u64)").unwrap_err().to_string();
+    assert!(err.contains("expected u64"), "got: {}", err);
+    assert!(eval_lisp("(assert-type 300 'u8)").is_err());
+    assert!(eval_lisp("(assert-type 255 'u8)").is_ok());
+}
+
+#[test]
+fn test_array_spec_passes_homogeneous() {
+    assert_eq!(
+        eval_lisp("(assert-type [1 2 3] '(array integer))").unwrap(),
+        Value::Null
+    );
+}
+
+#[test]
+fn test_array_spec_fails_on_mixed_array() {
+    let err = eval_lisp("(assert-type [1 \"two\" 3] '(array integer))")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("expected (array integer)"), "got: {}", err);
+    assert!(err.contains("got array"), "got: {}", err);
+}
+
+#[test]
+fn test_or_spec() {
+    assert!(eval_lisp("(assert-type \"x\" '(or integer string))").is_ok());
+    assert!(eval_lisp("(assert-type [1 \"x\"] '(array (or integer string)))").is_ok());
+    let err = eval_lisp("(assert-type 1.5 '(or integer string))")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("expected (or integer string)"), "got: {}", err);
+    assert!(err.contains("got float"), "got: {}", err);
+}
+
+#[test]
+fn test_predicate_result_still_accepted() {
+    assert!(eval_lisp("(assert-type 42 (int? 42))").is_ok());
+    assert!(eval_lisp("(assert-type \"a\" (int? \"a\"))").is_err());
+}
@@ -0,0 +1,72 @@
+//! Tests for `assoc`/`rassoc` with `:test` and object-based alists
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn pair(key: &str, value: Value) -> Value {
+    Value::array(vec![Value::String(key.to_string()), value])
+}
+
+#[test]
+fn test_assoc_default_equality_on_pairs() {
+    assert_eq!(
+        eval(r#"(assoc "b" [["a" 1] ["b" 2]])"#).unwrap(),
+        pair("b", Value::Int(2))
+    );
+    assert_eq!(eval(r#"(assoc "B" [["a" 1] ["b" 2]])"#).unwrap(), Value::Null);
+}
+
+#[test]
+fn test_assoc_with_custom_test() {
+    assert_eq!(
+        eval(r#"(assoc "B" [["a" 1] ["b" 2]] :test string-equal)"#).unwrap(),
+        pair("b", Value::Int(2))
+    );
+    let code = r#"
+        (assoc 10 [[1 "one"] [12 "twelve"]] :test (lambda (k c) (> c k)))
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![Value::Int(12), Value::String("twelve".to_string())])
+    );
+}
+
+#[test]
+fn test_rassoc_finds_by_value() {
+    assert_eq!(
+        eval(r#"(rassoc 2 [["a" 1] ["b" 2]])"#).unwrap(),
+        pair("b", Value::Int(2))
+    );
+    assert_eq!(eval(r#"(rassoc 3 [["a" 1] ["b" 2]])"#).unwrap(), Value::Null);
+}
+
+#[test]
+fn test_object_alists() {
+    assert_eq!(
+        eval(r#"(assoc "KEY" {:key 42 :other 1} :test string-equal)"#).unwrap(),
+        pair("key", Value::Int(42))
+    );
+    assert_eq!(
+        eval(r#"(assoc "other" {:key 42 :other 1})"#).unwrap(),
+        pair("other", Value::Int(1))
+    );
+    assert_eq!(
+        eval(r#"(rassoc 42 {:key 42 :other 1})"#).unwrap(),
+        pair("key", Value::Int(42))
+    );
+}
+
+#[test]
+fn test_assoc_rejects_bad_options() {
+    assert!(eval(r#"(assoc "a" [["a" 1]] :test)"#).is_err());
+    assert!(eval(r#"(assoc "a" [["a" 1]] :key first)"#).is_err());
+}
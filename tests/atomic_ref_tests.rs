@@ -0,0 +1,93 @@
+//! Tests for atomic references: `make-atomic`, `atomic-get`, `atomic-set`
+//! and `atomic-update`
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_get_and_set() {
+    let result = eval(
+        r#"
+        (define r (make-atomic [1 2]))
+        (define before (atomic-get r))
+        (atomic-set r "replaced")
+        [(typeof r) (atomic? r) before (atomic-get r)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::String("atomic-ref".into()),
+            Value::Bool(true),
+            Value::array(vec![Value::Int(1), Value::Int(2)]),
+            Value::String("replaced".into()),
+        ])
+    );
+}
+
+#[test]
+fn test_update_with_extra_args() {
+    let result = eval(
+        r#"
+        (define r (make-atomic 10))
+        [(atomic-update r + 5) (atomic-update r (lambda (n) (* n 2))) (atomic-get r)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::Int(15), Value::Int(30), Value::Int(30)])
+    );
+}
+
+#[test]
+fn test_update_shared_map() {
+    let result = eval(
+        r#"
+        (define r (make-atomic {:hits 0}))
+        (atomic-update r (lambda (m) (update-in m [:hits] (lambda (n) (+ n 1)))))
+        (atomic-update r (lambda (m) (update-in m [:hits] (lambda (n) (+ n 1)))))
+        (get (atomic-get r) :hits)
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(2));
+}
+
+#[test]
+fn test_concurrent_updates_sum_exactly() {
+    let result = eval(
+        r#"
+        (define counter (make-atomic 0))
+        (define workers
+          (map (range 1 9)
+               (lambda (step)
+                 (make-thread
+                   (lambda ()
+                     (for (_ (range 0 200))
+                       (atomic-update counter + step)))))))
+        (for (w workers) (join-thread w))
+        (atomic-get counter)
+        "#,
+    )
+    .unwrap();
+    // Each of the threads adds its step 200 times: 200 * (1 + 2 + ... + 8)
+    assert_eq!(result, Value::Int(200 * 36));
+}
+
+#[test]
+fn test_update_arity_is_checked() {
+    assert!(eval("(atomic-update (make-atomic 0) (lambda () 1))").is_err());
+    assert!(eval("(atomic-get 5)").is_err());
+}
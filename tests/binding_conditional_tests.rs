@@ -0,0 +1,88 @@
+//! Tests for the binding conditionals `if-let`, `when-let` and `when-some`
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_if_let_binds_truthy_value() {
+    let result = eval(
+        r#"
+        (define table {:a 41})
+        (if-let (x (get table "a")) (+ x 1) :missing)
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(42));
+}
+
+#[test]
+fn test_if_let_takes_fallback_on_nil() {
+    let result = eval(r#"(if-let (x nil) (+ x 1) "fallback")"#).unwrap();
+    assert_eq!(result, Value::String("fallback".to_string()));
+    assert_eq!(eval("(if-let (x nil) x)").unwrap(), Value::Null);
+}
+
+#[test]
+fn test_when_let_skips_on_false() {
+    let result = eval(
+        r#"
+        (define ran false)
+        (when-let (x false) (set! ran true) x)
+        ran
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Bool(false));
+}
+
+#[test]
+fn test_when_some_proceeds_on_false() {
+    let result = eval(
+        r#"
+        (define ran false)
+        (when-some (x false) (set! ran true) (list x ran))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::Bool(false), Value::Bool(true)])
+    );
+    assert_eq!(eval("(when-some (x 0) (+ x 5))").unwrap(), Value::Int(5));
+    assert_eq!(eval("(when-some (x nil) 1)").unwrap(), Value::Null);
+}
+
+#[test]
+fn test_binding_scoped_to_body() {
+    let result = eval(
+        r#"
+        (define x "outer")
+        (define inner (when-let [x "inner"] x))
+        (list inner x)
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::String("inner".to_string()),
+            Value::String("outer".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_malformed_binding_errors() {
+    assert!(eval("(if-let x 1 2)").is_err());
+    assert!(eval("(when-let (x) 1)").is_err());
+}
@@ -3,12 +3,12 @@
 //! These tests verify the OVSM implementation of Bordeaux Threads primitives
 //! for portable shared-state concurrency.
 
-use ovsm::lexer::SExprScanner;
-use ovsm::parser::SExprParser;
-use ovsm::runtime::{LispEvaluator, Value};
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
 
 /// Helper function to evaluate OVSM code
-fn eval_str(source: &str) -> Result<Value, ovsm::error::Error> {
+fn eval_str(source: &str) -> Result<Value, solisp::error::Error> {
     let mut scanner = SExprScanner::new(source);
     let tokens = scanner.scan_tokens()?;
     let mut parser = SExprParser::new(tokens);
@@ -1,6 +1,6 @@
 //! Tests for case and typecase (pattern matching)
 
-use ovsm::{Evaluator, Parser, Scanner, Value};
+use solisp::{Evaluator, Parser, Scanner, Value};
 
 fn eval_lisp(source: &str) -> Result<Value, Box<dyn std::error::Error>> {
     let mut scanner = Scanner::new(source);
@@ -367,3 +367,91 @@ fn test_typecase_first_match_wins() {
     let result = eval_lisp(source).unwrap();
     assert_eq!(result, Value::String("first".to_string()));
 }
+
+// ====================
+// Multi-form Clause Bodies and Grouped Keys
+// ====================
+
+#[test]
+fn test_case_multi_form_body_returns_last() {
+    let source = r#"
+(define log 0)
+(case 2
+  (1 (set! log 1) "one")
+  (2 (set! log 20) (set! log (+ log 2)) "two")
+  (else "other"))
+"#;
+    let result = eval_lisp(source).unwrap();
+    assert_eq!(result, Value::String("two".to_string()));
+}
+
+#[test]
+fn test_case_multi_form_body_side_effects() {
+    let source = r#"
+(define log 0)
+(case 2
+  (2 (set! log 20) (set! log (+ log 2)))
+  (else (set! log -1)))
+log
+"#;
+    let result = eval_lisp(source).unwrap();
+    assert_eq!(result, Value::Int(22));
+}
+
+#[test]
+fn test_case_multi_form_else() {
+    let source = r#"
+(define n 0)
+(case 99
+  (1 "one")
+  (else (set! n 5) (* n 2)))
+"#;
+    let result = eval_lisp(source).unwrap();
+    assert_eq!(result, Value::Int(10));
+}
+
+#[test]
+fn test_case_parenthesized_grouped_keys() {
+    let source = r#"
+(defun classify (n)
+  (case n
+    ((1 2 3) "small")
+    ((4 5 6) "medium")
+    (else "large")))
+[(classify 1) (classify 3) (classify 5) (classify 9)]
+"#;
+    let result = eval_lisp(source).unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::String("small".to_string()),
+            Value::String("small".to_string()),
+            Value::String("medium".to_string()),
+            Value::String("large".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_case_grouped_string_keys() {
+    let source = r#"
+(case "sat"
+  (("sat" "sun") "weekend")
+  (else "weekday"))
+"#;
+    let result = eval_lisp(source).unwrap();
+    assert_eq!(result, Value::String("weekend".to_string()));
+}
+
+#[test]
+fn test_typecase_multi_form_body_and_grouped_types() {
+    let source = r#"
+(define seen 0)
+(typecase "hi"
+  ((int float) (set! seen 1) "number")
+  ((string) (set! seen 2) (+ seen 40))
+  (else "other"))
+"#;
+    let result = eval_lisp(source).unwrap();
+    assert_eq!(result, Value::Int(42));
+}
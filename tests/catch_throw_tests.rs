@@ -7,10 +7,10 @@
 //! - Tag matching
 //! - Error cases (uncaught throws)
 
-use ovsm::{LispEvaluator, SExprParser, SExprScanner, Value};
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
 
 /// Helper function to execute OVSM LISP code
-fn eval(code: &str) -> Result<Value, ovsm::Error> {
+fn eval(code: &str) -> Result<Value, solisp::Error> {
     let mut scanner = SExprScanner::new(code);
     let tokens = scanner.scan_tokens()?;
     let mut parser = SExprParser::new(tokens);
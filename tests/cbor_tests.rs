@@ -0,0 +1,68 @@
+//! Tests for `cbor-encode` / `cbor-decode`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_round_trip_nested_structure() {
+    let code = r#"
+        (define data {:slot 250000000
+                      :ok true
+                      :fee 0.5
+                      :memo null
+                      :accounts [{:key "abc" :lamports -12} {:key "def" :lamports 9000000000}]
+                      :tags ["a" ["b" []]]})
+        (equal? (cbor-decode (cbor-encode data)) data)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Bool(true));
+}
+
+#[test]
+fn test_encoding_is_compact_and_deterministic() {
+    // {"a": 1, "b": [true, null]} per RFC 8949
+    assert_eq!(
+        eval("(cbor-encode {:b [true null] :a 1})").unwrap(),
+        Value::String("a2616101616282f5f6".to_string())
+    );
+    assert_eq!(
+        eval(r#"(cbor-encode "hi")"#).unwrap(),
+        Value::String("626869".to_string())
+    );
+}
+
+#[test]
+fn test_byte_strings_round_trip_as_bytes() {
+    // h'DEADBEEF'
+    assert_eq!(
+        eval(r#"(get (cbor-decode "44deadbeef") :cbor-bytes)"#).unwrap(),
+        Value::String("deadbeef".to_string())
+    );
+    assert_eq!(
+        eval(r#"(cbor-encode (cbor-decode "44deadbeef"))"#).unwrap(),
+        Value::String("44deadbeef".to_string())
+    );
+    // {"sig": h'0102'} keeps its byte string inside a map
+    assert_eq!(
+        eval(r#"(cbor-encode (cbor-decode "a163736967420102"))"#).unwrap(),
+        Value::String("a163736967420102".to_string())
+    );
+    assert!(eval(r#"(cbor-encode {:cbor-bytes "xyz"})"#).is_err());
+}
+
+#[test]
+fn test_malformed_cbor_is_catchable() {
+    // Array header claiming 3 items followed by only one
+    let code = r#"(try (cbor-decode "8301") (catch e "caught"))"#;
+    assert_eq!(eval(code).unwrap(), Value::String("caught".to_string()));
+    assert!(eval(r#"(cbor-decode "zz")"#).is_err());
+    assert!(eval("(cbor-encode (lambda (x) x))").is_err());
+}
@@ -0,0 +1,208 @@
+//! Tests for channels: `make-channel`, `channel-send`, `channel-receive`,
+//! `channel-try-receive`, `close-channel` and `channel-closed?`
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().map(|&n| Value::Int(n)).collect())
+}
+
+#[test]
+fn test_make_channel() {
+    assert_eq!(
+        eval("(typeof (make-channel 4))").unwrap(),
+        Value::String("channel".into())
+    );
+    assert_eq!(
+        eval("(channelp (make-channel))").unwrap(),
+        Value::Bool(true)
+    );
+    assert_eq!(eval("(channel? 3)").unwrap(), Value::Bool(false));
+    assert!(eval("(make-channel -1)").is_err());
+}
+
+#[test]
+fn test_fifo_ordering() {
+    let result = eval(
+        r#"
+        (define ch (make-channel))
+        (for (i (range 1 6)) (channel-send ch i))
+        (map (range 0 5) (lambda (_) (channel-receive ch)))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, ints(&[1, 2, 3, 4, 5]));
+}
+
+#[test]
+fn test_try_receive_empty_is_null() {
+    let result = eval(
+        r#"
+        (define ch (make-channel 1))
+        (define before (channel-try-receive ch))
+        (channel-send ch 7)
+        [before (channel-try-receive ch) (channel-try-receive ch)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::Null, Value::Int(7), Value::Null])
+    );
+}
+
+#[test]
+fn test_receive_after_close() {
+    let result = eval(
+        r#"
+        (define ch (make-channel 4))
+        (channel-send ch 1)
+        (channel-send ch 2)
+        (close-channel ch)
+        [(channel-receive ch) (channel-receive ch)
+         (channel-receive ch) (channel-try-receive ch)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::Int(1),
+            Value::Int(2),
+            Value::String(":closed".into()),
+            Value::String(":closed".into()),
+        ])
+    );
+}
+
+#[test]
+fn test_send_after_close_fails() {
+    let err = eval(
+        r#"
+        (define ch (make-channel))
+        (close-channel ch)
+        (close-channel ch)
+        (channel-send ch 1)
+        "#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("closed"));
+}
+
+#[test]
+fn test_bounded_channel_backpressure() {
+    let result = eval(
+        r#"
+        (define ch (make-channel 2))
+        (define sent (make-atomic-integer :value 0))
+        (define producer
+          (make-thread
+            (lambda ()
+              (do
+                (for (i (range 0 5))
+                  (do
+                    (channel-send ch i)
+                    (atomic-integer-incf sent)))
+                (close-channel ch)))))
+        (sleep 0.1)
+        ;; Only the buffered values got through while nobody was receiving
+        (define sent-while-blocked (atomic-integer-value sent))
+        (define received (map (range 0 6) (lambda (_) (channel-receive ch))))
+        (join-thread producer)
+        [sent-while-blocked received]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::Int(2),
+            Value::array(vec![
+                Value::Int(0),
+                Value::Int(1),
+                Value::Int(2),
+                Value::Int(3),
+                Value::Int(4),
+                Value::String(":closed".into()),
+            ]),
+        ])
+    );
+}
+
+#[test]
+fn test_close_wakes_blocked_receiver() {
+    let result = eval(
+        r#"
+        (define ch (make-channel 1))
+        (define consumer (make-thread (lambda () (channel-receive ch))))
+        (sleep 0.05)
+        (close-channel ch)
+        (join-thread consumer)
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::String(":closed".into()));
+}
+
+#[test]
+fn test_closed_value_tells_close_from_sent_value() {
+    let result = eval(
+        r#"
+        (define ch (make-channel 4))
+        (channel-send ch ":closed")
+        (close-channel ch)
+        [(channel-receive ch 'done) (channel-receive ch 'done)
+         (channel-try-receive ch 'done)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::String(":closed".into()),
+            Value::String("done".into()),
+            Value::String("done".into()),
+        ])
+    );
+}
+
+#[test]
+fn test_channel_closed_predicate() {
+    let result = eval(
+        r#"
+        (define ch (make-channel))
+        (define before (channel-closed? ch))
+        (close-channel ch)
+        [before (channel-closed? ch)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::Bool(false), Value::Bool(true)])
+    );
+}
+
+#[test]
+fn test_send_on_full_channel_honours_timeout() {
+    let result = eval(
+        r#"
+        (define ch (make-channel 1))
+        (channel-send ch 1)
+        (with-timeout (0.05 :stuck) (channel-send ch 2))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::String(":stuck".into()));
+}
@@ -0,0 +1,62 @@
+//! Tests for `coerce`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn chars(s: &str) -> Value {
+    Value::array(s.chars().map(|c| Value::String(c.to_string())).collect())
+}
+
+#[test]
+fn test_string_to_list_and_back() {
+    assert_eq!(eval(r#"(coerce "abc" 'list)"#).unwrap(), chars("abc"));
+    assert_eq!(
+        eval(r#"(coerce (coerce "héllo" 'list) 'string)"#).unwrap(),
+        Value::String("héllo".to_string())
+    );
+    assert_eq!(
+        eval(r#"(coerce (reverse (coerce "abc" :vector)) 'string)"#).unwrap(),
+        Value::String("cba".to_string())
+    );
+    assert_eq!(
+        eval("(coerce '(1 2 3) 'vector)").unwrap(),
+        Value::array(vec![Value::Int(1), Value::Int(2), Value::Int(3)])
+    );
+}
+
+#[test]
+fn test_number_coercions() {
+    assert_eq!(eval("(coerce 3 'float)").unwrap(), Value::Float(3.0));
+    assert_eq!(eval("(coerce 3.0 'integer)").unwrap(), Value::Int(3));
+    assert_eq!(eval("(coerce -8.0 :integer)").unwrap(), Value::Int(-8));
+    assert_eq!(eval("(coerce 2.5 'double-float)").unwrap(), Value::Float(2.5));
+    assert_eq!(eval("(coerce 7 't)").unwrap(), Value::Int(7));
+}
+
+#[test]
+fn test_unsupported_coercions_error() {
+    assert!(eval("(coerce 3.5 'integer)").is_err());
+    assert!(eval(r#"(coerce "12" 'integer)"#).is_err());
+    assert!(eval("(coerce 5 'list)").is_err());
+    assert!(eval(r#"(coerce ["ab" "c"] 'string)"#).is_err());
+    assert!(eval("(coerce 1 'hash-table)").is_err());
+    assert_eq!(
+        eval("(try (coerce {:a 1} 'float) (catch e \"caught\"))").unwrap(),
+        Value::String("caught".to_string())
+    );
+}
+
+#[test]
+fn test_existing_conversions_still_truncate() {
+    assert_eq!(eval("(int 3.7)").unwrap(), Value::Int(3));
+    assert_eq!(eval("(float 2)").unwrap(), Value::Float(2.0));
+}
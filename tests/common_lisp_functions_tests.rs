@@ -1,8 +1,8 @@
 /// Integration tests for Common Lisp compatibility functions
 /// Tests all 27 CL functions added to OVSM LISP interpreter
-use ovsm::lexer::SExprScanner;
-use ovsm::parser::SExprParser;
-use ovsm::runtime::{LispEvaluator, Value};
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
 
 /// Helper function to execute OVSM code
 fn eval(source: &str) -> Value {
@@ -0,0 +1,59 @@
+//! Tests for variadic, chained comparison operators: (< 1 2 3)
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    LispEvaluator::new().execute(&program)
+}
+
+#[test]
+fn test_less_than_chain() {
+    assert_eq!(eval("(< 1 2 3)").unwrap(), Value::Bool(true));
+    assert_eq!(eval("(< 1 3 2)").unwrap(), Value::Bool(false));
+    assert_eq!(eval("(< 1 2 2)").unwrap(), Value::Bool(false));
+    assert_eq!(eval("(<= 1 2 2 5)").unwrap(), Value::Bool(true));
+}
+
+#[test]
+fn test_greater_than_chain() {
+    assert_eq!(eval("(> 3 2 1)").unwrap(), Value::Bool(true));
+    assert_eq!(eval("(>= 3 3 1.5)").unwrap(), Value::Bool(true));
+    assert_eq!(eval("(> 3 1 2)").unwrap(), Value::Bool(false));
+}
+
+#[test]
+fn test_equality_chain() {
+    assert_eq!(eval("(= 1 1 1)").unwrap(), Value::Bool(true));
+    assert_eq!(eval("(= 1 1 2)").unwrap(), Value::Bool(false));
+    assert_eq!(eval("(== 2 2 2 2)").unwrap(), Value::Bool(true));
+    assert_eq!(eval("(!= 1 2 3)").unwrap(), Value::Bool(true));
+}
+
+#[test]
+fn test_chain_with_expressions() {
+    assert_eq!(
+        eval("(define x 5) (< 0 x (+ x 1) 10)").unwrap(),
+        Value::Bool(true)
+    );
+}
+
+#[test]
+fn test_chain_short_circuits() {
+    // Operands after the first failing pair are never evaluated
+    let source = r#"
+        (define calls 0)
+        (defun bump (n) (do (set! calls (+ calls 1)) n))
+        (define result (< (bump 3) (bump 1) (bump 5) (bump 7)))
+        [result calls]
+    "#;
+    assert_eq!(
+        eval(source).unwrap(),
+        Value::array(vec![Value::Bool(false), Value::Int(2)])
+    );
+}
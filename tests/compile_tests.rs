@@ -0,0 +1,85 @@
+//! Tests for `compile`, which pre-expands macros and folds constants in a
+//! function body
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+/// `bump` advances the gensym counter each time it is expanded
+const BUMP: &str = r#"
+    (defmacro bump (x) (do (gensym) `(+ ,x 1)))
+    (defun gensym-count () (parse-int (substring (gensym "") 2)))
+"#;
+
+#[test]
+fn test_compiled_lambda_behaves_like_original() {
+    let code = format!(
+        "{} (define f (lambda (n) (bump (* n (+ 2 3)))))
+            (define g (compile nil f))
+            [(f 4) (g 4)]",
+        BUMP
+    );
+    assert_eq!(
+        eval(&code).unwrap(),
+        Value::array(vec![Value::Int(21), Value::Int(21)])
+    );
+}
+
+#[test]
+fn test_compiled_lambda_expands_macros_once() {
+    let code = format!(
+        "{} (define g (compile nil (lambda (n) (bump n))))
+            (define before (gensym-count))
+            (g 1) (g 2) (g 3)
+            (- (gensym-count) before)",
+        BUMP
+    );
+    // Only the counting gensym itself advances the counter
+    assert_eq!(eval(&code).unwrap(), Value::Int(1));
+}
+
+#[test]
+fn test_uncompiled_lambda_expands_macros_per_call() {
+    let code = format!(
+        "{} (define f (lambda (n) (bump n)))
+            (define before (gensym-count))
+            (f 1) (f 2) (f 3)
+            (- (gensym-count) before)",
+        BUMP
+    );
+    assert_eq!(eval(&code).unwrap(), Value::Int(4));
+}
+
+#[test]
+fn test_compile_named_function() {
+    let code = format!(
+        "{} (defun add-one (n) (bump n))
+            (compile 'add-one)
+            (define before (gensym-count))
+            [(add-one 41) (- (gensym-count) before)]",
+        BUMP
+    );
+    assert_eq!(
+        eval(&code).unwrap(),
+        Value::array(vec![Value::Int(42), Value::Int(1)])
+    );
+}
+
+#[test]
+fn test_compile_keeps_division_by_zero_at_call_time() {
+    let code = "(define g (compile nil (lambda (n) (if (> n 0) n (/ 1 0))))) (g 5)";
+    assert_eq!(eval(code).unwrap(), Value::Int(5));
+}
+
+#[test]
+fn test_compile_rejects_non_function() {
+    assert!(eval("(compile nil 5)").is_err());
+}
@@ -0,0 +1,94 @@
+//! Tests for complex numbers
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_complex_constructor_and_parts() {
+    assert_eq!(eval("(complex 1 2)").unwrap(), Value::complex(1.0, 2.0));
+    assert_eq!(eval("(realpart (complex 1 2))").unwrap(), Value::Float(1.0));
+    assert_eq!(eval("(imagpart (complex 1 2))").unwrap(), Value::Float(2.0));
+    assert_eq!(eval("(imagpart 5)").unwrap(), Value::Int(0));
+    // An exact zero imaginary part gives back the real number
+    assert_eq!(eval("(complex 3 0)").unwrap(), Value::Int(3));
+}
+
+#[test]
+fn test_complex_arithmetic() {
+    assert_eq!(
+        eval("(+ (complex 1 2) (complex 3 -1))").unwrap(),
+        Value::complex(4.0, 1.0)
+    );
+    assert_eq!(
+        eval("(- (complex 1 2) 1)").unwrap(),
+        Value::complex(0.0, 2.0)
+    );
+    assert_eq!(
+        eval("(* (complex 1 2) (complex 3 4))").unwrap(),
+        Value::complex(-5.0, 10.0)
+    );
+    assert_eq!(
+        eval("(/ (complex -5 10) (complex 3 4))").unwrap(),
+        Value::complex(1.0, 2.0)
+    );
+    assert_eq!(
+        eval("(* (complex 0 1) (complex 0 1))").unwrap(),
+        Value::complex(-1.0, 0.0)
+    );
+    assert!(eval("(/ (complex 1 1) 0)").is_err());
+}
+
+#[test]
+fn test_complex_equality() {
+    assert_eq!(
+        eval("(= (complex 1 2) (complex 1 2))").unwrap(),
+        Value::Bool(true)
+    );
+    assert_eq!(eval("(= (complex 1 2) 1)").unwrap(), Value::Bool(false));
+}
+
+#[test]
+fn test_abs_is_magnitude() {
+    assert_eq!(eval("(abs (complex 3 4))").unwrap(), Value::Float(5.0));
+}
+
+#[test]
+fn test_sqrt_of_negative_real() {
+    assert_eq!(eval("(sqrt -1)").unwrap(), Value::complex(0.0, 1.0));
+    assert_eq!(eval("(sqrt -9)").unwrap(), Value::complex(0.0, 3.0));
+}
+
+#[test]
+fn test_sqrt_of_complex() {
+    match eval("(sqrt (complex 3 4))").unwrap() {
+        Value::Complex { re, im } => {
+            assert!((re - 2.0).abs() < 1e-12);
+            assert!((im - 1.0).abs() < 1e-12);
+        }
+        other => panic!("Expected complex, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_complex_display() {
+    assert_eq!(eval("(sqrt -1)").unwrap().to_string(), "#C(0 1)");
+    assert_eq!(eval("(complex 1.5 -2)").unwrap().to_string(), "#C(1.5 -2)");
+    assert_eq!(
+        eval("(str (complex 1 2))").unwrap(),
+        Value::String("#C(1 2)".to_string())
+    );
+}
+
+#[test]
+fn test_complex_is_a_number() {
+    assert_eq!(eval("(number? (complex 1 2))").unwrap(), Value::Bool(true));
+}
@@ -0,0 +1,87 @@
+//! Tests for `copy-seq`, `copy-tree` and non-mutating concatenation
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().map(|&n| Value::Int(n)).collect())
+}
+
+#[test]
+fn test_mutating_copy_seq_leaves_original() {
+    let code = r#"
+        (define original [1 2 3])
+        (define copy (copy-seq original))
+        (setf (first copy) 100)
+        [original copy]
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![ints(&[1, 2, 3]), ints(&[100, 2, 3])])
+    );
+}
+
+#[test]
+fn test_copy_tree_sublists_are_independent() {
+    let code = r#"
+        (define original [[1 2] [3 4]])
+        (define copy (copy-tree original))
+        (define inner (first copy))
+        (setf (first inner) 99)
+        (setf (first copy) inner)
+        [original copy (equal? original (copy-tree original))]
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![
+            Value::array(vec![ints(&[1, 2]), ints(&[3, 4])]),
+            Value::array(vec![ints(&[99, 2]), ints(&[3, 4])]),
+            Value::Bool(true),
+        ])
+    );
+}
+
+#[test]
+fn test_copy_tree_handles_objects_and_atoms() {
+    assert_eq!(
+        eval("(equal? (copy-tree {:a [1 {:b 2}]}) {:a [1 {:b 2}]})").unwrap(),
+        Value::Bool(true)
+    );
+    assert_eq!(eval("(copy-tree 42)").unwrap(), Value::Int(42));
+    assert_eq!(
+        eval(r#"(copy-seq "abc")"#).unwrap(),
+        Value::String("abc".to_string())
+    );
+    assert!(eval("(copy-seq 42)").is_err());
+    assert!(eval("(copy-seq)").is_err());
+}
+
+#[test]
+fn test_append_and_concat_do_not_mutate_inputs() {
+    let code = r#"
+        (define a [1 2])
+        (define b [3])
+        (define joined (append a b))
+        (define all (concat a b joined))
+        (setf (first joined) 0)
+        [a b joined all]
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![
+            ints(&[1, 2]),
+            ints(&[3]),
+            ints(&[0, 2, 3]),
+            ints(&[1, 2, 3, 1, 2, 3]),
+        ])
+    );
+}
@@ -0,0 +1,121 @@
+//! Tests for the instant type: `parse-date`, `format-date`, `date-add` and `date-diff`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn string(code: &str) -> String {
+    match eval(code).unwrap() {
+        Value::String(s) => s,
+        other => panic!("Expected string, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_iso8601() {
+    assert_eq!(
+        eval("(parse-date \"2024-01-01T00:00:00Z\")").unwrap(),
+        Value::instant(1_704_067_200_000)
+    );
+    // Offsets are normalized to UTC
+    assert_eq!(
+        eval("(parse-date \"2024-01-01T02:00:00+02:00\")").unwrap(),
+        Value::instant(1_704_067_200_000)
+    );
+    assert_eq!(
+        eval("(= (parse-date \"2024-01-01\") (parse-date \"2024-01-01 00:00:00\"))").unwrap(),
+        Value::Bool(true)
+    );
+    assert_eq!(
+        eval("(parse-date 1704067200)").unwrap(),
+        Value::instant(1_704_067_200_000)
+    );
+    assert_eq!(
+        eval("(typeof (parse-date \"2024-01-01\"))").unwrap(),
+        Value::String("instant".to_string())
+    );
+    assert!(eval("(parse-date \"not a date\")").is_err());
+}
+
+#[test]
+fn test_parse_with_format() {
+    assert_eq!(
+        string("(format-date (parse-date \"03/15/2024 08:30\" \"%m/%d/%Y %H:%M\"))"),
+        "2024-03-15T08:30:00Z"
+    );
+}
+
+#[test]
+fn test_format_date() {
+    assert_eq!(
+        string("(format-date (parse-date \"2024-07-04T12:34:56.789Z\") \"%Y-%m-%d %H:%M\")"),
+        "2024-07-04 12:34"
+    );
+    assert_eq!(
+        string("(format-date (parse-date \"2024-07-04T12:34:56.789Z\"))"),
+        "2024-07-04T12:34:56.789Z"
+    );
+    assert_eq!(
+        string("(str (parse-date \"2024-07-04\"))"),
+        "2024-07-04T00:00:00Z"
+    );
+    assert!(eval("(format-date (parse-date \"2024-07-04\") \"%Q\")").is_err());
+    assert!(eval("(format-date 5)").is_err());
+}
+
+#[test]
+fn test_add_days_across_month_boundary() {
+    assert_eq!(
+        string("(format-date (date-add (parse-date \"2024-01-30\") :days 3) \"%Y-%m-%d\")"),
+        "2024-02-02"
+    );
+    // Leap year
+    assert_eq!(
+        string("(format-date (date-add (parse-date \"2024-02-28\") :days 1) \"%Y-%m-%d\")"),
+        "2024-02-29"
+    );
+    assert_eq!(
+        string("(format-date (date-add (parse-date \"2024-03-01T00:00:00Z\") :days -1 :hours 6))"),
+        "2024-02-29T06:00:00Z"
+    );
+    // Calendar months clamp to the end of shorter months
+    assert_eq!(
+        string("(format-date (date-add (parse-date \"2024-01-31\") :months 1) \"%Y-%m-%d\")"),
+        "2024-02-29"
+    );
+    assert!(eval("(date-add (parse-date \"2024-01-31\") :fortnights 1)").is_err());
+}
+
+#[test]
+fn test_date_diff() {
+    let code = "(define a (parse-date \"2024-01-01T00:00:00Z\"))
+                (define b (parse-date \"2024-01-03T12:00:30Z\"))";
+    assert_eq!(
+        eval(&format!("{} (date-diff b a :seconds)", code)).unwrap(),
+        Value::Int(216_030)
+    );
+    assert_eq!(
+        eval(&format!("{} (date-diff b a :days)", code)).unwrap(),
+        Value::Int(2)
+    );
+    assert_eq!(
+        eval(&format!("{} (date-diff a b :hours)", code)).unwrap(),
+        Value::Int(-60)
+    );
+    assert_eq!(
+        eval(&format!("{} (date-diff b a)", code)).unwrap(),
+        Value::Int(216_030_000)
+    );
+    assert_eq!(
+        eval(&format!("{} [(< a b) (> a b)]", code)).unwrap(),
+        Value::array(vec![Value::Bool(true), Value::Bool(false)])
+    );
+}
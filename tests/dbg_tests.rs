@@ -0,0 +1,55 @@
+//! Tests for `dbg`, which traces an expression and returns its value
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_dbg_returns_value_and_traces() {
+    let result = eval(
+        r#"
+        (define result nil)
+        (define output (with-output-capture (set! result (dbg (+ 1 2)))))
+        [result output]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::Int(3),
+            Value::String("(+ 1 2) = 3\n".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_dbg_inside_expression() {
+    let result = eval(
+        r#"
+        (define xs [1 2 3])
+        (with-output-capture (* 10 (dbg (length xs))))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::String("(length xs) = 3\n".to_string()));
+    assert_eq!(eval("(* 10 (dbg (- 5 1)))").unwrap(), Value::Int(40));
+}
+
+#[test]
+fn test_dbg_shows_readable_value() {
+    let result = eval(r#"(with-output-capture (dbg (str "a" "b")))"#).unwrap();
+    assert_eq!(
+        result,
+        Value::String("(str \"a\" \"b\") = \"ab\"\n".to_string())
+    );
+}
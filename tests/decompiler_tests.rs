@@ -0,0 +1,165 @@
+//! Tests for decompiling compiled programs back to OVSM source
+
+use solisp::compiler::{CompileOptions, Compiler, VerificationMode};
+use solisp::decompiler::{DecompileOptions, Decompiler, Disassembler, RoundTripReport};
+
+fn round_trip(source: &str) -> String {
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Skip,
+        opt_level: 0,
+        ..Default::default()
+    };
+    let compiled = Compiler::new(options)
+        .compile(source)
+        .expect("compile failed");
+    Decompiler::new(DecompileOptions::default())
+        .decompile(&compiled.elf_bytes)
+        .expect("decompile failed")
+        .source
+}
+
+#[test]
+fn test_counting_loop_decompiles_to_loop_form() {
+    let source = round_trip(
+        "(define i 0)
+         (define total 0)
+         (while (< i 10)
+           (set! total (+ total i))
+           (set! i (+ i 1)))
+         total",
+    );
+
+    // The account-parsing loop in the entrypoint plus the user loop
+    let loops = source.matches("(while").count() + source.matches("(for").count();
+    assert_eq!(loops, 2, "{}", source);
+    // Back edges are absorbed by the loop form
+    assert!(!source.contains(";; jump -"), "{}", source);
+}
+
+#[test]
+fn test_straight_line_program_has_no_loop_form() {
+    let source = round_trip("(define x 1) (+ x 2)");
+
+    // Only the entrypoint's account-parsing loop is present
+    assert_eq!(source.matches("(while").count(), 1, "{}", source);
+}
+
+/// IDL with one instruction whose discriminator is bytes 1..=8
+const DEPOSIT_IDL: &str = r#"{
+    "name": "vault_program",
+    "version": "0.1.0",
+    "instructions": [
+        {
+            "name": "deposit",
+            "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+            "accounts": [
+                {"name": "vault", "isMut": true, "isSigner": false},
+                {"name": "user", "isMut": true, "isSigner": true}
+            ],
+            "args": []
+        },
+        {
+            "name": "withdraw",
+            "discriminator": [8, 7, 6, 5, 4, 3, 2, 1],
+            "accounts": [],
+            "args": []
+        }
+    ]
+}"#;
+
+/// Compiles a dispatch on the `deposit` discriminator (little-endian 1..=8)
+const DEPOSIT_PROGRAM: &str = "(define disc (mem-load (instruction-data) 0))
+     (if (= disc 578437695752307201)
+         (set-lamports 0 (account-lamports 1))
+         0)";
+
+fn decompile_with_idl(source: &str, use_idl_names: bool) -> String {
+    let idl_path = std::env::temp_dir().join(format!(
+        "solisp_decompiler_idl_{}_{}.json",
+        std::process::id(),
+        use_idl_names
+    ));
+    std::fs::write(&idl_path, DEPOSIT_IDL).unwrap();
+
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Skip,
+        opt_level: 0,
+        ..Default::default()
+    };
+    let compiled = Compiler::new(options)
+        .compile(source)
+        .expect("compile failed");
+    let result = Decompiler::new(DecompileOptions {
+        idl_path: Some(idl_path.to_string_lossy().into_owned()),
+        use_idl_names,
+        ..Default::default()
+    })
+    .decompile(&compiled.elf_bytes)
+    .expect("decompile failed");
+    std::fs::remove_file(&idl_path).ok();
+    result.source
+}
+
+#[test]
+fn test_idl_names_accounts_and_dispatch() {
+    let source = decompile_with_idl(DEPOSIT_PROGRAM, true);
+
+    assert!(source.contains(";; dispatch: deposit"), "{}", source);
+    assert!(source.contains("(account-lamports user)"), "{}", source);
+    assert!(source.contains("(set-lamports vault "), "{}", source);
+    assert!(!source.contains("withdraw"), "{}", source);
+}
+
+#[test]
+fn test_generic_account_names_without_idl_names() {
+    let source = decompile_with_idl(DEPOSIT_PROGRAM, false);
+
+    assert!(
+        source.contains("(account-lamports account[1])"),
+        "{}",
+        source
+    );
+    assert!(source.contains("(set-lamports account[0] "), "{}", source);
+    assert!(!source.contains(";; dispatch:"), "{}", source);
+}
+
+#[test]
+fn test_roundtrip_of_identical_text_has_no_divergences() {
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Skip,
+        opt_level: 0,
+        ..Default::default()
+    };
+    let compiled = Compiler::new(options)
+        .compile("(define x 2) (define y (* x 3)) (+ x y)")
+        .expect("compile failed");
+    let instructions = Disassembler::new()
+        .disassemble(&compiled.elf_bytes)
+        .unwrap();
+
+    let report = RoundTripReport::compare(&instructions, &instructions);
+    assert!(report.is_exact());
+    assert_eq!(report.original_len, report.recompiled_len);
+}
+
+#[test]
+fn test_decompile_verified_reports_roundtrip() {
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Skip,
+        opt_level: 0,
+        ..Default::default()
+    };
+    let compiled = Compiler::new(options.clone())
+        .compile("(define x 2) (define y (* x 3)) (+ x y)")
+        .expect("compile failed");
+
+    let result = Decompiler::default()
+        .decompile_verified(&compiled.elf_bytes, options)
+        .expect("decompile failed");
+    let report = result.roundtrip.expect("round-trip report");
+
+    assert_eq!(report.original_len, result.instructions.len());
+    // Every problem found is surfaced as a warning
+    assert_eq!(report.is_exact(), result.warnings.is_empty());
+    assert!(result.warnings.iter().all(|w| w.starts_with("round-trip:")));
+}
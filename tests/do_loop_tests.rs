@@ -0,0 +1,82 @@
+//! Tests for the iteration forms `do` / `do*`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_two_variable_do_sum() {
+    let code = r#"
+        (do ((i 0 (+ i 1))
+             (sum 0 (+ sum i)))
+            ((= i 5) sum))
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(10));
+}
+
+#[test]
+fn test_do_steps_in_parallel() {
+    // Each step sees the previous iteration's values: (a, b) -> (b, a + b)
+    let code = r#"
+        (do ((n 0 (+ n 1))
+             (a 0 b)
+             (b 1 (+ a b)))
+            ((= n 10) a))
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(55));
+}
+
+#[test]
+fn test_do_star_step_depends_on_previous_step() {
+    // i is stepped first, so sq sees the new i
+    let code = r#"
+        (do* ((i 0 (+ i 1))
+              (sq (* i i) (* i i)))
+             ((>= i 4) sq))
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(16));
+
+    // Sequential init: j starts from i
+    assert_eq!(
+        eval("(do* ((i 3) (j (* i 2))) (true j))").unwrap(),
+        Value::Int(6)
+    );
+}
+
+#[test]
+fn test_do_body_and_result_forms() {
+    let code = r#"
+        (define acc [])
+        (do ((i 0 (+ i 1)))
+            ((= i 3) (set! acc (append acc ["done"])) acc)
+          (set! acc (append acc [i])))
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![
+            Value::Int(0),
+            Value::Int(1),
+            Value::Int(2),
+            Value::String("done".to_string()),
+        ])
+    );
+    assert_eq!(eval("(do () (true))").unwrap(), Value::Null);
+}
+
+#[test]
+fn test_progn_style_do_unchanged() {
+    assert_eq!(eval("(do (define x 2) (* x 21))").unwrap(), Value::Int(42));
+}
+
+#[test]
+fn test_do_loop_variables_are_scoped() {
+    assert!(eval("(do ((i 0 (+ i 1))) ((= i 2))) i").is_err());
+}
@@ -0,0 +1,111 @@
+//! Tests for `ecase` and `ccase`, the exhaustive variants of `case`
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_ecase_matches_clause() {
+    let result = eval(
+        r#"
+        (define status 2)
+        (ecase status
+          (1 "pending")
+          ((2 3) "settled"))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::String("settled".to_string()));
+}
+
+#[test]
+fn test_ecase_errors_on_unmatched_value() {
+    let err = eval(
+        r#"
+        (ecase "sol"
+          ("btc" 1)
+          (("eth" "usdc") 2))
+        "#,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains(r#""sol""#), "{}", err);
+    assert!(err.contains(r#"one of "btc", "eth", "usdc""#), "{}", err);
+}
+
+#[test]
+fn test_ecase_error_is_catchable() {
+    let result = eval(
+        r#"
+        (try
+          (ecase 9 (1 "one"))
+          (catch e "caught"))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::String("caught".to_string()));
+}
+
+#[test]
+fn test_ecase_else_clause_and_plain_case() {
+    assert_eq!(
+        eval("(ecase 9 (1 :one) (else :other))").unwrap(),
+        Value::String(":other".to_string())
+    );
+    assert_eq!(eval("(case 9 (1 :one))").unwrap(), Value::Null);
+}
+
+#[test]
+fn test_ccase_errors_on_unmatched_value() {
+    assert_eq!(
+        eval("(ccase 1 (1 :one))").unwrap(),
+        Value::String(":one".to_string())
+    );
+    let err = eval("(ccase 4 (1 :one))").unwrap_err().to_string();
+    assert!(err.contains("ccase key"), "{}", err);
+}
+
+#[test]
+fn test_ccase_store_value_retries_and_stores() {
+    let result = eval(
+        r#"
+        (define status 7)
+        (define picked
+          (ccase status
+            (1 "pending")
+            ((2 3) "settled")
+            :store-value (lambda (value keys) (- (length keys) 1))))
+        [picked status]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::String("settled".to_string()), Value::Int(2)])
+    );
+}
+
+#[test]
+fn test_ccase_store_value_runs_until_a_clause_matches() {
+    let result = eval(
+        r#"
+        (define tries 0)
+        (ccase 10
+          (12 tries)
+          :store-value (lambda (value keys)
+                         (do (setf tries (+ tries 1))
+                             (+ value 1))))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(2));
+}
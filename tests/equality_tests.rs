@@ -0,0 +1,53 @@
+//! Tests for structural equality: `equal?` and `equalp`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn is_true(code: &str) -> bool {
+    eval(code).unwrap() == Value::Bool(true)
+}
+
+#[test]
+fn test_equal_nested_arrays() {
+    assert!(is_true("(equal? [1 [2 [3 \"x\"]]] [1 [2 [3 \"x\"]]])"));
+    assert!(!is_true("(equal? [1 [2 [3]]] [1 [2 [4]]])"));
+    assert!(!is_true("(equal? [1 2] [1 2 3])"));
+}
+
+#[test]
+fn test_equal_objects_ignore_insertion_order() {
+    assert!(is_true("(equal? {:a 1 :b [1 2]} {:b [1 2] :a 1})"));
+    assert!(!is_true("(equal? {:a 1 :b 2} {:a 1 :c 2})"));
+    assert!(!is_true("(equal? {:a 1} {:a 1 :b 2})"));
+    assert!(is_true("(equal {:x {:y [1]}} {:x {:y [1]}})"));
+}
+
+#[test]
+fn test_equal_is_strict_about_types_and_case() {
+    assert!(!is_true("(equal? 1 1.0)"));
+    assert!(!is_true("(equal? \"A\" \"a\")"));
+}
+
+#[test]
+fn test_equalp_coerces_numbers_and_case() {
+    assert!(is_true("(equalp 1 1.0)"));
+    assert!(is_true("(equalp 2.0 2)"));
+    assert!(is_true("(equalp \"Hello\" \"hELLO\")"));
+    assert!(is_true("(equalp [1 \"A\" {:k 2}] [1.0 \"a\" {:k 2.0}])"));
+    assert!(!is_true("(equalp 1 1.5)"));
+}
+
+#[test]
+fn test_numeric_equals_unchanged() {
+    assert!(is_true("(= 3 3)"));
+    assert!(!is_true("(= 3 4)"));
+}
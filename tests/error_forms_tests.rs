@@ -0,0 +1,81 @@
+//! Tests for `ignore-errors` and `with-timeout`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_ignore_errors_swallows_division_by_zero() {
+    let result = eval("(ignore-errors (/ 1 0))").unwrap();
+    assert_eq!(result.primary_value(), Value::Null);
+}
+
+#[test]
+fn test_ignore_errors_returns_condition_as_second_value() {
+    let result = eval(
+        "(multiple-value-bind [value condition] (ignore-errors (/ 1 0))
+           [value (string? condition)])",
+    )
+    .unwrap();
+    assert_eq!(result, Value::array(vec![Value::Null, Value::Bool(true)]));
+}
+
+#[test]
+fn test_ignore_errors_returns_body_value() {
+    assert_eq!(eval("(ignore-errors 1 (+ 2 3))").unwrap(), Value::Int(5));
+}
+
+#[test]
+fn test_ignore_errors_lets_throw_through() {
+    let result = eval("(catch 'done (ignore-errors (throw 'done 7)))").unwrap();
+    assert_eq!(result, Value::Int(7));
+}
+
+#[test]
+fn test_with_timeout_returns_timeout_form_for_slow_body() {
+    let result = eval(r#"(with-timeout (0.05 "timed out") (while true nil))"#).unwrap();
+    assert_eq!(result, Value::String("timed out".to_string()));
+}
+
+#[test]
+fn test_with_timeout_is_not_caught_by_try() {
+    let result =
+        eval(r#"(with-timeout (0.05 "timed out") (try (while true 1) (catch e "swallowed")))"#)
+            .unwrap();
+    assert_eq!(result, Value::String("timed out".to_string()));
+}
+
+#[test]
+fn test_with_timeout_returns_body_value_when_fast() {
+    let result = eval(r#"(with-timeout (5 "timed out") (+ 1 2))"#).unwrap();
+    assert_eq!(result, Value::Int(3));
+}
+
+#[test]
+fn test_with_timeout_restores_enclosing_limit() {
+    // The inner limit is gone once the form returns
+    let result = eval(
+        r#"(define first (with-timeout (0.01 :slow) (while true nil)))
+           (define n 0)
+           (while (< n 1000) (set! n (+ n 1)))
+           [first n]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::String(":slow".to_string()), Value::Int(1000)])
+    );
+}
+
+#[test]
+fn test_with_timeout_rejects_negative_seconds() {
+    assert!(eval("(with-timeout (-1 nil) 1)").is_err());
+}
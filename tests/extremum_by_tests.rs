@@ -0,0 +1,71 @@
+//! Tests for `max-by`/`min-by` and `max`/`min` with a `:key` function
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    LispEvaluator::new().execute(&program)
+}
+
+fn string(s: &str) -> Value {
+    Value::String(s.to_string())
+}
+
+#[test]
+fn test_max_by_string_length() {
+    assert_eq!(
+        eval(r#"(max-by length '("a" "ccc" "bb"))"#).unwrap(),
+        string("ccc")
+    );
+    assert_eq!(
+        eval(r#"(min-by length ["a" "ccc" "bb"])"#).unwrap(),
+        string("a")
+    );
+}
+
+#[test]
+fn test_ties_return_first() {
+    assert_eq!(
+        eval(r#"(max-by length ["ab" "cd" "e"])"#).unwrap(),
+        string("ab")
+    );
+    assert_eq!(
+        eval(r#"(min-by length ["ab" "c" "d"])"#).unwrap(),
+        string("c")
+    );
+}
+
+#[test]
+fn test_key_returns_element_not_key() {
+    let source = r#"
+        (define trades [{:id 1 :size 5} {:id 2 :size 9} {:id 3 :size 2}])
+        (get (max-by (lambda (t) (get t :size)) trades) :id)
+    "#;
+    assert_eq!(eval(source).unwrap(), Value::Int(2));
+    assert_eq!(
+        eval("(min-by (lambda (x) (abs (- x 10))) [3 8 13 11])").unwrap(),
+        Value::Int(11)
+    );
+}
+
+#[test]
+fn test_min_max_with_key_option() {
+    assert_eq!(
+        eval(r#"(max ["a" "ccc" "bb"] :key length)"#).unwrap(),
+        string("ccc")
+    );
+    assert_eq!(eval("(min [-7 2 -3] :key abs)").unwrap(), Value::Int(2));
+    // Plain numeric max is unchanged
+    assert_eq!(eval("(max 3 9 4)").unwrap(), Value::Int(9));
+}
+
+#[test]
+fn test_empty_input_errors() {
+    assert!(eval("(max-by length [])").is_err());
+    assert!(eval("(min [] :key abs)").is_err());
+}
@@ -2,9 +2,9 @@
 //!
 //! These tests verify that the Lean 4 formal verification system works correctly.
 
-use ovsm::compiler::lean::{LeanCodegen, VCCategory, VerificationProperties};
-use ovsm::compiler::{CompileOptions, Compiler, VerificationMode};
-use ovsm::parser::{Argument, BinaryOp, Expression, Program, Statement};
+use solisp::compiler::lean::{LeanCodegen, VCCategory, VerificationProperties};
+use solisp::compiler::{CompileOptions, Compiler, VerificationMode};
+use solisp::parser::{Argument, BinaryOp, Expression, Program, Statement};
 
 /// Test that division safety VCs are generated
 #[test]
@@ -164,11 +164,61 @@ fn test_lean_code_generation() {
     assert!(lean_code.contains("≠ 0"), "Should have non-zero property");
 }
 
+/// Test that Require mode reports every unsafe operation as a diagnostic
+#[test]
+fn test_require_collects_all_diagnostics() {
+    use solisp::compiler::DiagnosticSeverity;
+    use solisp::Error;
+
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Require,
+        collect_all_diagnostics: true,
+        ..Default::default()
+    };
+    let result = Compiler::new(options).compile("(define x 10) (/ x 0) (% x 0)");
+
+    let Err(Error::VerificationFailed {
+        message,
+        diagnostics,
+    }) = result
+    else {
+        panic!("expected VerificationFailed, got {:?}", result);
+    };
+    assert!(message.contains("Formal verification failed"));
+
+    // Both operations are reported, each with its source location
+    for category in [VCCategory::DivisionSafety, VCCategory::ModuloSafety] {
+        assert!(
+            diagnostics
+                .iter()
+                .any(|d| d.category == category && d.location.is_some()),
+            "{:?}",
+            diagnostics
+        );
+    }
+    // Disproved conditions come first and are errors
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+}
+
+/// Test that Require mode without the flag keeps the plain compiler error
+#[test]
+fn test_require_without_diagnostics_flag() {
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Require,
+        ..Default::default()
+    };
+    let result = Compiler::new(options).compile("(define x 10) (/ x 0)");
+
+    assert!(matches!(result, Err(solisp::Error::CompilerError(_))));
+}
+
 /// Test that verification can be skipped
 #[test]
 fn test_verification_mode_skip() {
-    let mut options = CompileOptions::default();
-    options.verification_mode = VerificationMode::Skip;
+    let options = CompileOptions {
+        verification_mode: VerificationMode::Skip,
+        ..Default::default()
+    };
 
     // This should compile without requiring Lean
     let compiler = Compiler::new(options);
@@ -301,7 +351,7 @@ fn test_nested_expression_vcs() {
 // Built-in Verifier Integration Tests
 // ============================================================================
 
-use ovsm::compiler::lean::{
+use solisp::compiler::lean::{
     BuiltinVerifier, LeanVerifier, ProofResult, SymbolicValue, VerificationOptions,
 };
 
@@ -376,6 +426,37 @@ fn test_builtin_verifier_array_bounds() {
     assert!(result.is_proved(), "Should prove i = 5 < 10 = arr.size");
 }
 
+/// Test that an out-of-bounds access reports the minimal offending index
+#[test]
+fn test_builtin_verifier_minimal_out_of_bounds_index() {
+    use solisp::compiler::lean::VerificationCondition;
+    use solisp::Value;
+
+    let mut verifier = BuiltinVerifier::new();
+    verifier.define_array("arr", 10);
+    verifier.define("i", SymbolicValue::Range { lo: 3, hi: 40 });
+
+    // The guard puts every feasible i past the end of the array
+    let vc = VerificationCondition {
+        id: "vc_bounds".to_string(),
+        category: VCCategory::ArrayBounds,
+        description: "Index 'i' must be within bounds of 'arr'".to_string(),
+        location: None,
+        property: "i < arr.size".to_string(),
+        assumptions: vec!["i >= 12".to_string()],
+        tactic: "ovsm_in_bounds".to_string(),
+    };
+
+    let witness = verifier
+        .minimal_counterexample(&vc)
+        .expect("counterexample");
+    let Value::Object(fields) = witness else {
+        panic!("counterexample should be an object");
+    };
+    assert_eq!(fields.get("i"), Some(&Value::Int(12)));
+    assert_eq!(fields.get("arr.size"), Some(&Value::Int(10)));
+}
+
 /// Test that the built-in verifier can prove underflow safety
 #[test]
 fn test_builtin_verifier_underflow_safety() {
@@ -460,6 +541,76 @@ fn test_proof_export() {
     let _ = std::fs::remove_file(&proof_file);
 }
 
+/// Test that exported certificates are Lean theorems with tactic bodies
+#[test]
+fn test_proof_export_emits_tactic_theorems() {
+    use solisp::{SExprParser, SExprScanner};
+
+    let mut scanner = SExprScanner::new("(define x 10) (/ x 2) (% x 3)");
+    let tokens = scanner.scan_tokens().expect("Failed to scan");
+    let program = SExprParser::new(tokens).parse().expect("Failed to parse");
+
+    let verifier = LeanVerifier::new(VerificationOptions::default()).unwrap();
+    let proof_file = std::env::temp_dir().join(format!(
+        "test_proof_export_tactics_{}.lean",
+        std::process::id()
+    ));
+    verifier
+        .export_proofs(&program, "safe-div.ovsm", &proof_file)
+        .unwrap();
+    let content = std::fs::read_to_string(&proof_file).unwrap();
+    let _ = std::fs::remove_file(&proof_file);
+
+    // Imports come before any other command
+    let first = content.lines().find(|l| !l.trim().is_empty()).unwrap();
+    assert_eq!(first, "import OVSM", "{}", content);
+
+    // Block comments and parentheses are balanced
+    assert_eq!(
+        content.matches("/-").count(),
+        content.matches("-/").count(),
+        "{}",
+        content
+    );
+    let code: String = content
+        .split("/-")
+        .map(|chunk| chunk.split_once("-/").map_or(chunk, |(_, rest)| rest))
+        .collect();
+    assert_eq!(
+        code.matches('(').count(),
+        code.matches(')').count(),
+        "{}",
+        content
+    );
+
+    // The namespace is opened and closed
+    assert!(content.contains("namespace VC_safe_div\n"), "{}", content);
+    assert!(
+        content.trim_end().ends_with("end VC_safe_div"),
+        "{}",
+        content
+    );
+
+    // Every theorem has a tactic body on the following line
+    let lines: Vec<&str> = content.lines().collect();
+    let theorems: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("theorem "))
+        .collect();
+    assert!(!theorems.is_empty(), "{}", content);
+    for i in theorems {
+        assert!(lines[i].ends_with(":= by"), "{}", lines[i]);
+        let tactic = lines[i + 1];
+        assert!(
+            tactic.starts_with("  ") && !tactic.trim().is_empty(),
+            "{}",
+            content
+        );
+    }
+
+    // Literal divisors are discharged by `decide`
+    assert!(content.contains("\n  decide\n"), "{}", content);
+}
+
 // ============================================================================
 // New VC Category Tests (Loop Invariants, Discriminator, Sysvar, Function Calls)
 // ============================================================================
@@ -1387,6 +1538,75 @@ fn test_arithmetic_precision_division() {
     );
 }
 
+/// Verify a source snippet with the built-in verifier
+fn verify_source(source: &str) -> solisp::compiler::lean::VerificationResult {
+    use solisp::{SExprParser, SExprScanner};
+
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens().expect("Failed to scan");
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse().expect("Failed to parse");
+
+    let verifier = LeanVerifier::new(VerificationOptions::default()).unwrap();
+    verifier.verify_builtin(&program, "test.ovsm").unwrap()
+}
+
+/// Test that modulo by literal zero produces a failing ModuloSafety VC
+#[test]
+fn test_modulo_by_zero_fails() {
+    let result = verify_source("(define x 10) (% x 0)");
+
+    assert!(
+        result
+            .failed
+            .iter()
+            .any(|vc| vc.category == VCCategory::ModuloSafety),
+        "(% x 0) should fail modulo safety: {}",
+        result.summary()
+    );
+}
+
+/// Test that modulo by a non-zero literal is proved
+#[test]
+fn test_modulo_by_nonzero_literal_proves() {
+    let result = verify_source("(define x 10) (% x 7)");
+
+    assert!(result
+        .proved
+        .iter()
+        .any(|vc| vc.category == VCCategory::ModuloSafety));
+    assert!(!result
+        .failed
+        .iter()
+        .any(|vc| vc.category == VCCategory::ModuloSafety));
+}
+
+/// Test that `mod` and `rem` calls get ModuloSafety VCs too
+#[test]
+fn test_mod_and_rem_modulo_safety() {
+    let codegen = LeanCodegen::new(VerificationProperties::all());
+
+    for name in ["mod", "rem"] {
+        let program = Program {
+            metadata: Default::default(),
+            statements: vec![Statement::Expression(Expression::ToolCall {
+                name: name.to_string(),
+                args: vec![
+                    Argument::positional(Expression::Variable("x".to_string())),
+                    Argument::positional(Expression::Variable("y".to_string())),
+                ],
+            })],
+        };
+
+        let vcs = codegen.generate(&program, "test.ovsm").unwrap();
+        let vc = vcs
+            .iter()
+            .find(|vc| vc.category == VCCategory::ModuloSafety)
+            .unwrap_or_else(|| panic!("({} x y) should generate a ModuloSafety VC", name));
+        assert_eq!(vc.property, "y ≠ 0");
+    }
+}
+
 /// Test AccountCloseDrain VC generation
 #[test]
 fn test_account_close_drain_check() {
@@ -1822,7 +2042,7 @@ fn test_option_unwrap_check() {
 /// Test ProofCertificate JSON export
 #[test]
 fn test_proof_certificate_json_export() {
-    use ovsm::compiler::lean::{ProofCertificate, ProofStatus, VCProof};
+    use solisp::compiler::lean::{ProofCertificate, ProofStatus, VCProof};
 
     let cert = ProofCertificate {
         version: "1.0".to_string(),
@@ -1886,7 +2106,7 @@ fn test_proof_certificate_json_export() {
 /// Test ProofCertificate for fully verified program
 #[test]
 fn test_proof_certificate_verified() {
-    use ovsm::compiler::lean::{ProofCertificate, ProofStatus, VCProof};
+    use solisp::compiler::lean::{ProofCertificate, ProofStatus, VCProof};
 
     let cert = ProofCertificate {
         version: "1.0".to_string(),
@@ -1928,8 +2148,10 @@ fn test_proof_certificate_verified() {
 
 #[test]
 fn test_aea_protocol_verification() {
-    use ovsm::compiler::lean::{BuiltinVerifier, LeanCodegen, ProofResult, VerificationProperties};
-    use ovsm::{SExprParser, SExprScanner};
+    use solisp::compiler::lean::{
+        BuiltinVerifier, LeanCodegen, ProofResult, VerificationProperties,
+    };
+    use solisp::{SExprParser, SExprScanner};
 
     let source = std::fs::read_to_string("../../examples/ovsm_scripts/aea/aea_protocol.ovsm")
         .expect("Failed to read AEA protocol");
@@ -1996,10 +2218,10 @@ fn test_aea_protocol_verification() {
 
 #[test]
 fn test_aea_protocol_coverage_report() {
-    use ovsm::compiler::lean::{
+    use solisp::compiler::lean::{
         BuiltinVerifier, LeanCodegen, ProofCoverageReport, VerificationProperties,
     };
-    use ovsm::{SExprParser, SExprScanner};
+    use solisp::{SExprParser, SExprScanner};
 
     let source = std::fs::read_to_string("../../examples/ovsm_scripts/aea/aea_protocol.ovsm")
         .expect("Failed to read AEA protocol");
@@ -2046,9 +2268,55 @@ fn test_aea_protocol_coverage_report() {
     println!("\nJSON Report:\n{}", report.to_json());
 }
 
+#[test]
+fn test_coverage_report_html() {
+    use solisp::compiler::lean::{
+        ProofCoverageReport, SourceLocation, VCCategory, VerificationCondition,
+    };
+
+    let source = ";; <safe> & unsafe\n(define x 10)\n(% x 3)\n(% x 0)\n";
+    let vc = |line: usize, divisor: &str| VerificationCondition {
+        id: format!("vc_modulo_safety_{}", line),
+        category: VCCategory::ModuloSafety,
+        description: format!("Modulo divisor '{}' must be non-zero", divisor),
+        location: Some(SourceLocation {
+            file: "mod.ovsm".to_string(),
+            line,
+            column: 1,
+        }),
+        property: format!("{} ≠ 0", divisor),
+        assumptions: vec![],
+        tactic: "decide".to_string(),
+    };
+    let vcs = vec![vc(3, "3"), vc(4, "0")];
+    let proof_results = vec![(true, &vcs[0]), (false, &vcs[1])];
+
+    let report = ProofCoverageReport::from_vcs(source, "mod.ovsm", &vcs, &proof_results);
+    let html = report.to_html(source);
+
+    assert!(
+        html.contains("<span class=\"proved\"><span class=\"lineno\">   3</span>  (% x 3)</span>"),
+        "{}",
+        html
+    );
+    assert!(
+        html.contains(
+            "<span class=\"unproved\"><span class=\"lineno\">   4</span>  (% x 0)</span>"
+        ),
+        "{}",
+        html
+    );
+    assert!(html.contains("<span class=\"uncovered\"><span class=\"lineno\">   2</span>"));
+    // Source text is escaped
+    assert!(html.contains(";; &lt;safe&gt; &amp; unsafe"), "{}", html);
+    assert!(!html.contains("<safe>"));
+    // Category breakdown table
+    assert!(html.contains("<td>ModuloSafety</td><td>1</td><td>2</td><td>50.0%</td>"));
+}
+
 #[test]
 fn test_aea_protocol_spec_enforcement() {
-    use ovsm::compiler::lean::{create_aea_spec, BuiltinVerifier};
+    use solisp::compiler::lean::create_aea_spec;
 
     // Load the built-in AEA protocol specification
     let spec = create_aea_spec();
@@ -2175,7 +2443,7 @@ fn test_aea_protocol_spec_enforcement() {
 
 #[test]
 fn test_protocol_spec_syntax_parsing() {
-    use ovsm::{SExprParser, SExprScanner};
+    use solisp::{SExprParser, SExprScanner};
 
     // Test defstate parsing
     let source = r#"
@@ -2241,8 +2509,8 @@ fn test_protocol_spec_syntax_parsing() {
 
 #[test]
 fn test_protocol_spec_extraction_from_program() {
-    use ovsm::compiler::lean::ProtocolSpec;
-    use ovsm::{SExprParser, SExprScanner};
+    use solisp::compiler::lean::ProtocolSpec;
+    use solisp::{SExprParser, SExprScanner};
 
     // Parse a program with protocol specs
     let source = r#"
@@ -2316,8 +2584,8 @@ fn test_protocol_spec_extraction_from_program() {
 
 #[test]
 fn test_runtime_check_generation_integration() {
-    use ovsm::compiler::lean::ProtocolSpec;
-    use ovsm::{SExprParser, SExprScanner};
+    use solisp::compiler::lean::ProtocolSpec;
+    use solisp::{SExprParser, SExprScanner};
 
     // Parse a program with protocol specs
     let source = r#"
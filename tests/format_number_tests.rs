@@ -0,0 +1,71 @@
+//! Tests for `format-number`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn formatted(code: &str) -> String {
+    match eval(code).unwrap() {
+        Value::String(s) => s,
+        other => panic!("Expected string, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_thousands_separators_with_precision() {
+    assert_eq!(
+        formatted("(format-number 1234567.891 {:precision 2 :thousands true})"),
+        "1,234,567.89"
+    );
+    assert_eq!(
+        formatted("(format-number 1234567 {:thousands true})"),
+        "1,234,567"
+    );
+    assert_eq!(formatted("(format-number 999 {:thousands true})"), "999");
+}
+
+#[test]
+fn test_precision_rounds_half_away_from_zero() {
+    assert_eq!(formatted("(format-number 1234.5 {:precision 0})"), "1235");
+    assert_eq!(formatted("(format-number 2.5 {:precision 0})"), "3");
+    assert_eq!(formatted("(format-number 0.125 {:precision 2})"), "0.13");
+    assert_eq!(formatted("(format-number 7 {:precision 2})"), "7.00");
+}
+
+#[test]
+fn test_negative_numbers() {
+    assert_eq!(
+        formatted("(format-number -1234567.5 {:precision 2 :thousands true})"),
+        "-1,234,567.50"
+    );
+    assert_eq!(
+        formatted("(format-number -1000 {:thousands true})"),
+        "-1,000"
+    );
+    // A small negative that rounds to zero has no sign
+    assert_eq!(formatted("(format-number -0.001 {:precision 2})"), "0.00");
+}
+
+#[test]
+fn test_locale_separators() {
+    assert_eq!(
+        formatted(
+            r#"(format-number 1234567.891 {:precision 2 :thousands true :separator "." :decimal ","})"#
+        ),
+        "1.234.567,89"
+    );
+}
+
+#[test]
+fn test_invalid_arguments() {
+    assert!(eval(r#"(format-number "12")"#).is_err());
+    assert!(eval("(format-number 1 {:precision -1})").is_err());
+}
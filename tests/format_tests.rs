@@ -0,0 +1,61 @@
+//! Tests for `format` plural (~P, ~:P) and English cardinal (~R) directives
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn format_str(code: &str) -> String {
+    match eval(code).unwrap() {
+        Value::String(s) => s,
+        other => panic!("expected string, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_plural_directive() {
+    assert_eq!(format_str(r#"(format nil "~d item~p" 1 1)"#), "1 item");
+    assert_eq!(format_str(r#"(format nil "~d item~p" 3 3)"#), "3 items");
+    assert_eq!(format_str(r#"(format nil "~d item~p" 0 0)"#), "0 items");
+}
+
+#[test]
+fn test_plural_directive_reuses_previous_arg() {
+    assert_eq!(format_str(r#"(format nil "~d item~:p" 1)"#), "1 item");
+    assert_eq!(format_str(r#"(format nil "~d item~:p" 3)"#), "3 items");
+    assert_eq!(
+        format_str(r#"(format nil "~d file~:p, ~d dir~:p" 1 2)"#),
+        "1 file, 2 dirs"
+    );
+}
+
+#[test]
+fn test_cardinal_directive() {
+    assert_eq!(format_str(r#"(format nil "~r" 0)"#), "zero");
+    assert_eq!(format_str(r#"(format nil "~r" 21)"#), "twenty-one");
+    assert_eq!(format_str(r#"(format nil "~r" 42)"#), "forty-two");
+    assert_eq!(format_str(r#"(format nil "~r" 100)"#), "one hundred");
+    assert_eq!(
+        format_str(r#"(format nil "~r" 1234567)"#),
+        "one million two hundred thirty-four thousand five hundred sixty-seven"
+    );
+    assert_eq!(format_str(r#"(format nil "~r" -15)"#), "negative fifteen");
+}
+
+#[test]
+fn test_cardinal_covers_i64_range_and_falls_back_for_non_integers() {
+    assert_eq!(
+        format_str(r#"(format nil "~r" 9223372036854775807)"#),
+        "nine quintillion two hundred twenty-three quadrillion three hundred \
+         seventy-two trillion thirty-six billion eight hundred fifty-four million \
+         seven hundred seventy-five thousand eight hundred seven"
+    );
+    assert_eq!(format_str(r#"(format nil "~r" 2.5)"#), "2.5");
+}
@@ -0,0 +1,58 @@
+//! Tests for `frequencies` and `count-by`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn count(obj: &Value, key: &str) -> Value {
+    obj.as_object().unwrap().get(key).cloned().unwrap()
+}
+
+#[test]
+fn test_frequencies_counts_repeated_elements() {
+    let result = eval("(frequencies '(a b a c a))").unwrap();
+    assert_eq!(result.as_object().unwrap().len(), 3);
+    assert_eq!(count(&result, "a"), Value::Int(3));
+    assert_eq!(count(&result, "b"), Value::Int(1));
+    assert_eq!(count(&result, "c"), Value::Int(1));
+
+    // Symbols and strings with the same name share a key
+    let result = eval("(frequencies (list 'a \"a\" 1 1.0))").unwrap();
+    assert_eq!(count(&result, "a"), Value::Int(2));
+    assert_eq!(count(&result, "1"), Value::Int(2));
+}
+
+#[test]
+fn test_frequencies_of_empty_collection_is_empty_object() {
+    let result = eval("(frequencies [])").unwrap();
+    assert!(result.as_object().unwrap().is_empty());
+}
+
+#[test]
+fn test_count_by_key_function() {
+    let result = eval("(count-by even? [1 2 3 4 5])").unwrap();
+    assert_eq!(count(&result, "true"), Value::Int(2));
+    assert_eq!(count(&result, "false"), Value::Int(3));
+
+    let result = eval(
+        r#"(count-by [{:side "buy"} {:side "sell"} {:side "buy"}]
+                     (lambda (t) (get t "side")))"#,
+    )
+    .unwrap();
+    assert_eq!(count(&result, "buy"), Value::Int(2));
+    assert_eq!(count(&result, "sell"), Value::Int(1));
+
+    assert!(eval("(count-by (lambda (t) t) [])")
+        .unwrap()
+        .as_object()
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,65 @@
+//! Tests for `funcall` and `#'` function references
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().map(|&n| Value::Int(n)).collect())
+}
+
+#[test]
+fn test_sharp_quote_resolves_defun() {
+    let code = r#"
+        (defun add1 (x) (+ x 1))
+        (mapcar #'add1 '(1 2 3))
+    "#;
+    assert_eq!(eval(code).unwrap(), ints(&[2, 3, 4]));
+
+    assert_eq!(
+        eval("(defun sq (x) (* x x)) (function? #'sq)").unwrap(),
+        Value::Bool(true)
+    );
+}
+
+#[test]
+fn test_sharp_quote_of_builtin_and_lambda() {
+    assert_eq!(eval("(funcall #'+ 1 2 3)").unwrap(), Value::Int(6));
+    assert_eq!(
+        eval("(mapcar #'(lambda (x) (* x 10)) [1 2])").unwrap(),
+        ints(&[10, 20])
+    );
+    assert_eq!(eval("(funcall #'max 3 9 4)").unwrap(), Value::Int(9));
+}
+
+#[test]
+fn test_funcall_lambda_and_function_values() {
+    assert_eq!(
+        eval("(funcall (lambda (a b) (- a b)) 10 4)").unwrap(),
+        Value::Int(6)
+    );
+    let code = r#"
+        (define f (lambda (x) (* x 2)))
+        (funcall f 21)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(42));
+    assert_eq!(
+        eval("(defun greet () \"hi\") (funcall 'greet)").unwrap(),
+        Value::String("hi".to_string())
+    );
+}
+
+#[test]
+fn test_funcall_errors() {
+    assert!(eval("(funcall)").is_err());
+    assert!(eval("(funcall 42 1)").is_err());
+    assert!(eval("(function 42)").is_err());
+}
@@ -0,0 +1,75 @@
+//! Tests for passing builtin names to higher-order builtins
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().map(|&n| Value::Int(n)).collect())
+}
+
+#[test]
+fn test_map_with_builtin_name() {
+    let floats = Value::array(vec![
+        Value::Float(1.0),
+        Value::Float(2.0),
+        Value::Float(3.0),
+    ]);
+    assert_eq!(eval("(map sqrt '(1 4 9))").unwrap(), floats);
+    assert_eq!(eval("(map '(1 4 9) sqrt)").unwrap(), floats);
+    assert_eq!(
+        eval("(map [1 2 3] [10 20 30] +)").unwrap(),
+        ints(&[11, 22, 33])
+    );
+}
+
+#[test]
+fn test_filter_with_builtin_name() {
+    assert_eq!(eval("(filter [1 2 3 4] even?)").unwrap(), ints(&[2, 4]));
+    assert_eq!(eval("(filter [1 -2 3] 'positive?)").unwrap(), ints(&[1, 3]));
+    assert_eq!(eval("(remove-if even? [1 2 3 4])").unwrap(), ints(&[1, 3]));
+}
+
+#[test]
+fn test_reduce_with_operator() {
+    assert_eq!(eval("(reduce (list 1 2 3 4) 0 +)").unwrap(), Value::Int(10));
+    assert_eq!(eval("(reduce [1 2 3 4] 1 *)").unwrap(), Value::Int(24));
+}
+
+#[test]
+fn test_sort_with_comparison_operator() {
+    assert_eq!(eval("(sort [3 1 2] <)").unwrap(), ints(&[1, 2, 3]));
+    assert_eq!(eval("(sort [3 1 2] >=)").unwrap(), ints(&[3, 2, 1]));
+}
+
+#[test]
+fn test_predicates_with_builtin_names() {
+    assert_eq!(eval("(every [2 4] even?)").unwrap(), Value::Bool(true));
+    assert_eq!(eval("(some [1 3] even?)").unwrap(), Value::Bool(false));
+    assert_eq!(eval("(find [1 -2 3] negative?)").unwrap(), Value::Int(-2));
+    assert_eq!(eval("(apply + [1 2 3])").unwrap(), Value::Int(6));
+}
+
+#[test]
+fn test_lambda_arity_still_checked() {
+    assert!(eval("(filter [1 2 3] (lambda (a b) a))").is_err());
+    assert!(eval("(filter [1 2 3] 5)").is_err());
+}
+
+#[test]
+fn test_unknown_symbol_is_undefined_variable() {
+    let err = eval("(define f (lambda (x) x)) (map itemz f)").unwrap_err();
+    assert_eq!(err.to_string(), "Undefined variable: itemz");
+    assert!(matches!(
+        eval("(filter [1 2] evn?)"),
+        Err(solisp::Error::UndefinedVariable { .. })
+    ));
+}
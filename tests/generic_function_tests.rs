@@ -0,0 +1,149 @@
+//! Tests for `defgeneric` / `defmethod` single dispatch
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+const SHAPES: &str = r#"
+    (defgeneric area (shape))
+    (defmethod area ((s circle)) (* 3 (* (get s "r") (get s "r"))))
+    (defmethod area ((s square)) (* (get s "side") (get s "side")))
+    (define c {:type :circle :r 2})
+    (define sq {:type "square" :side 5})
+"#;
+
+#[test]
+fn test_dispatch_on_struct_tag() {
+    let code = format!("{}\n[(area c) (area sq)]", SHAPES);
+    assert_eq!(
+        eval(&code).unwrap(),
+        Value::array(vec![Value::Int(12), Value::Int(25)])
+    );
+}
+
+#[test]
+fn test_no_applicable_method() {
+    let code = format!("{}\n(area {{:type :triangle}})", SHAPES);
+    let err = eval(&code).unwrap_err();
+    assert!(err.to_string().contains("no applicable method"), "{}", err);
+
+    let code = format!("{}\n(try (area 42) (catch e \"caught\"))", SHAPES);
+    assert_eq!(eval(&code).unwrap(), Value::String("caught".to_string()));
+}
+
+#[test]
+fn test_default_method_and_specificity() {
+    let code = r#"
+        (defgeneric describe-it (x))
+        (defmethod describe-it :default (x) "thing")
+        (defmethod describe-it ((x number)) "number")
+        (defmethod describe-it ((x int)) "int")
+        (defmethod describe-it ((x object)) "object")
+        (defmethod describe-it ((x point)) "point")
+        (map [1 2.5 "s" {:a 1} {:type :point}] describe-it)
+    "#;
+    let expected = Value::array(
+        ["int", "number", "thing", "object", "point"]
+            .iter()
+            .map(|s| Value::String(s.to_string()))
+            .collect(),
+    );
+    assert_eq!(eval(code).unwrap(), expected);
+}
+
+#[test]
+fn test_extra_arguments_and_redefinition() {
+    let code = r#"
+        (defmethod scale ((s square) factor) (* (get s "side") factor))
+        (defmethod scale ((s square) factor) (* (get s "side") factor 10))
+        (scale {:type :square :side 2} 3)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(60));
+
+    let code = r#"
+        (defgeneric area (shape))
+        (defmethod area ((s circle) extra) 1)
+    "#;
+    assert!(eval(code).is_err());
+}
+
+const LOGGED: &str = r#"
+    (define trace [])
+    (defun note (entry) (set! trace (append trace [entry])))
+    (defgeneric greet (x))
+    (defmethod greet ((x object)) (do (note "primary object") "hello"))
+    (defmethod greet ((x person)) (do (note "primary person") (call-next-method)))
+    (defmethod greet :before ((x person)) (note "before person"))
+    (defmethod greet :before ((x object)) (note "before object"))
+    (defmethod greet :after ((x person)) (note "after person"))
+    (defmethod greet :after ((x object)) (note "after object"))
+"#;
+
+fn strings(items: &[&str]) -> Value {
+    Value::array(items.iter().map(|s| Value::String(s.to_string())).collect())
+}
+
+#[test]
+fn test_before_primary_after_order() {
+    let code = format!("{}\n(define result (greet {{:type :person}}))\n[result trace]", LOGGED);
+    assert_eq!(
+        eval(&code).unwrap(),
+        Value::array(vec![
+            Value::String("hello".to_string()),
+            strings(&[
+                "before person",
+                "before object",
+                "primary person",
+                "primary object",
+                "after object",
+                "after person",
+            ]),
+        ])
+    );
+}
+
+#[test]
+fn test_around_wraps_and_can_short_circuit() {
+    let code = format!(
+        r#"{}
+        (defmethod greet :around ((x person))
+          (if (get x "blocked")
+              "blocked"
+              (do (note "around") (str "<" (call-next-method) ">"))))
+        (define open (greet {{:type :person}}))
+        (define open-trace trace)
+        (set! trace [])
+        (define shut (greet {{:type :person :blocked true}}))
+        [open (first open-trace) (length open-trace) shut trace]
+        "#,
+        LOGGED
+    );
+    assert_eq!(
+        eval(&code).unwrap(),
+        Value::array(vec![
+            Value::String("<hello>".to_string()),
+            Value::String("around".to_string()),
+            Value::Int(7),
+            Value::String("blocked".to_string()),
+            Value::array(vec![]),
+        ])
+    );
+}
+
+#[test]
+fn test_call_next_method_outside_method_is_error() {
+    assert!(eval("(call-next-method)").is_err());
+    let code = r#"
+        (defmethod only ((x int)) [(next-method-p) (call-next-method)])
+        (only 1)
+    "#;
+    assert!(eval(code).is_err());
+}
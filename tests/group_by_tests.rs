@@ -0,0 +1,65 @@
+//! Tests for `group-by`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().map(|&n| Value::Int(n)).collect())
+}
+
+fn group(obj: &Value, key: &str) -> Value {
+    obj.as_object().unwrap().get(key).cloned().unwrap()
+}
+
+#[test]
+fn test_group_numbers_by_parity() {
+    let result = eval("(group-by (lambda (n) (% n 2)) [1 2 3 4 5 6 7])").unwrap();
+    assert_eq!(result.as_object().unwrap().len(), 2);
+    assert_eq!(group(&result, "1"), ints(&[1, 3, 5, 7]));
+    assert_eq!(group(&result, "0"), ints(&[2, 4, 6]));
+
+    // Builtin predicates and the collection-first order work too
+    let result = eval("(group-by [1 2 3 4] even?)").unwrap();
+    assert_eq!(group(&result, "true"), ints(&[2, 4]));
+    assert_eq!(group(&result, "false"), ints(&[1, 3]));
+}
+
+#[test]
+fn test_group_objects_by_field() {
+    let result = eval(
+        r#"(define trades [{:token "SOL" :amount 5}
+                           {:token "USDC" :amount 7}
+                           {:token "SOL" :amount 2}])
+           (define groups (group-by (lambda (t) (get t "token")) trades))
+           [(map (get groups "SOL") (lambda (t) (get t "amount")))
+            (length (get groups "USDC"))]"#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::array(vec![ints(&[5, 2]), Value::Int(1)]));
+}
+
+#[test]
+fn test_equal_numeric_keys_group_together() {
+    let result = eval("(group-by (lambda (x) (/ x 2.0)) [4 2 4.0 6])").unwrap();
+    assert_eq!(result.as_object().unwrap().len(), 3);
+    assert_eq!(
+        group(&result, "2"),
+        Value::array(vec![Value::Int(4), Value::Float(4.0)])
+    );
+    assert_eq!(group(&result, "1"), ints(&[2]));
+}
+
+#[test]
+fn test_empty_collection_gives_empty_object() {
+    let result = eval("(group-by (lambda (x) x) [])").unwrap();
+    assert!(result.as_object().unwrap().is_empty());
+}
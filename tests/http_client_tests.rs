@@ -0,0 +1,194 @@
+//! Tests for the pooled HTTP client: options, retries and error surfacing
+//!
+//! A tiny scripted HTTP server is spun up on a local port for each test so
+//! no external network access is required.
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+/// Serve the given `(status, body)` responses in order, one per connection.
+/// Returns the base URL and a counter of requests received.
+fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+
+    std::thread::spawn(move || {
+        for (status, body) in responses {
+            let (mut stream, _) = match listener.accept() {
+                Ok(conn) => conn,
+                Err(_) => return,
+            };
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            // Drain the request head and any Content-Length body
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0usize;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                    break;
+                }
+                if let Some(v) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = v.trim().parse().unwrap_or(0);
+                }
+            }
+            let mut buf = vec![0u8; content_length];
+            let _ = reader.read_exact(&mut buf);
+
+            let response = format!(
+                "HTTP/1.1 {} MOCK\r\nContent-Length: {}\r\nX-Mock: yes\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+
+    (format!("http://{}", addr), hits)
+}
+
+fn field(value: &Value, name: &str) -> Value {
+    value.as_object().unwrap().get(name).cloned().unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_http_get_retries_503_then_200() {
+    let (url, hits) = mock_server(vec![(503, "busy"), (200, "ok")]);
+    let code = format!(
+        r#"(http-get "{}/data" {{:timeout-ms 2000 :retries 3 :backoff-ms 5}})"#,
+        url
+    );
+
+    let result = eval(&code).unwrap();
+    assert_eq!(field(&result, "status"), Value::Int(200));
+    assert_eq!(field(&result, "body"), Value::String("ok".to_string()));
+    assert_eq!(
+        field(&field(&result, "headers"), "x-mock"),
+        Value::String("yes".to_string())
+    );
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_http_get_does_not_retry_404() {
+    let (url, hits) = mock_server(vec![(404, "missing"), (200, "unreachable")]);
+    let code = format!(r#"(http-get "{}/nope" {{:retries 3 :backoff-ms 5}})"#, url);
+
+    let result = eval(&code).unwrap();
+    assert_eq!(field(&result, "status"), Value::Int(404));
+    assert_eq!(field(&result, "body"), Value::String("missing".to_string()));
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_http_get_returns_last_5xx_when_retries_exhausted() {
+    let (url, hits) = mock_server(vec![(500, "a"), (502, "b")]);
+    let code = format!(r#"(http-get "{}" {{:retries 1 :backoff-ms 1}})"#, url);
+
+    let result = eval(&code).unwrap();
+    assert_eq!(field(&result, "status"), Value::Int(502));
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_http_post_sends_headers_and_body() {
+    let (url, _) = mock_server(vec![(201, "created")]);
+    let code = format!(
+        r#"(http-post "{}" {{:name "x"}} {{:headers {{:authorization "Bearer t"}}}})"#,
+        url
+    );
+
+    let result = eval(&code).unwrap();
+    assert_eq!(field(&result, "status"), Value::Int(201));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_network_error_is_catchable() {
+    // Bind then drop a listener so the port is (almost certainly) closed
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let code = format!(
+        r#"(try (http-get "http://127.0.0.1:{}/" {{:retries 1 :backoff-ms 1}})
+                (catch e (str "caught: " e)))"#,
+        port
+    );
+
+    let result = eval(&code).unwrap();
+    match result {
+        Value::String(s) => assert!(s.starts_with("caught: Network error"), "{}", s),
+        other => panic!("expected string, got {:?}", other),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_invalid_options_rejected() {
+    let result = eval(r#"(http-get "http://127.0.0.1:1/" {:retries -1})"#);
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_json_rpc_batch_with_error_in_middle() {
+    // Responses deliberately out of order to exercise id correlation
+    let body = r#"[
+        {"jsonrpc":"2.0","id":3,"result":{"value":30}},
+        {"jsonrpc":"2.0","id":1,"result":10},
+        {"jsonrpc":"2.0","id":2,"error":{"code":-32602,"message":"Invalid params"}}
+    ]"#;
+    let (url, hits) = mock_server(vec![(200, body)]);
+    let code = format!(
+        r#"(json-rpc-batch "{}" [
+             {{:method "getBalance" :params ["a"]}}
+             {{:method "getBalance" :params ["bad"]}}
+             {{:method "getAccountInfo" :params ["c"]}}])"#,
+        url
+    );
+
+    let result = eval(&code).unwrap();
+    let items = result.as_array().unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0], Value::Int(10));
+
+    let error = field(&items[1], "error");
+    assert_eq!(field(&error, "code"), Value::Int(-32602));
+    assert_eq!(
+        field(&error, "message"),
+        Value::String("Invalid params".to_string())
+    );
+
+    assert_eq!(field(&items[2], "value"), Value::Int(30));
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_json_rpc_batch_missing_response_is_per_element_error() {
+    let body = r#"[{"jsonrpc":"2.0","id":1,"result":"ok"}]"#;
+    let (url, _) = mock_server(vec![(200, body)]);
+    let code = format!(
+        r#"(json-rpc-batch "{}" [{{:method "a"}} {{:method "b"}}])"#,
+        url
+    );
+
+    let result = eval(&code).unwrap();
+    let items = result.as_array().unwrap();
+    assert_eq!(items[0], Value::String("ok".to_string()));
+    assert!(items[1].as_object().unwrap().contains_key("error"));
+}
@@ -0,0 +1,97 @@
+//! Tests for `incf` and `decf` on generalized places
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_incf_array_element() {
+    let result = eval(
+        r#"
+        (define counts [0 0 0])
+        (for (i [0 2 2]) (incf (nth counts i)))
+        counts
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::Int(1), Value::Int(0), Value::Int(2)])
+    );
+}
+
+#[test]
+fn test_incf_hash_entry_by_delta() {
+    let result = eval(
+        r#"
+        (define volume {:sol 10})
+        [(incf (gethash "sol" volume) 5) (gethash "sol" volume)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::array(vec![Value::Int(15), Value::Int(15)]));
+}
+
+#[test]
+fn test_incf_and_decf_object_field() {
+    let result = eval(
+        r#"
+        (define stats {:count 1 :ratio 1.0})
+        (incf (get stats "count"))
+        (decf (. stats ratio) 0.25)
+        [(get stats "count") (get stats "ratio")]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::Int(2), Value::Float(0.75)])
+    );
+}
+
+#[test]
+fn test_incf_variable_unchanged() {
+    let result = eval(
+        r#"
+        (define n 5)
+        (decf n)
+        (incf n 10)
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(14));
+}
+
+#[test]
+fn test_incf_non_number_errors() {
+    assert!(eval(r#"(define xs ["a"]) (incf (nth xs 0))"#).is_err());
+}
+
+#[test]
+fn test_incf_evaluates_place_subforms_once() {
+    let result = eval(
+        r#"
+        (define xs [1 2])
+        (define i 0)
+        (incf (nth xs (do (set! i (+ i 1)) 0)))
+        [i xs]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::Int(1),
+            Value::array(vec![Value::Int(2), Value::Int(2)])
+        ])
+    );
+}
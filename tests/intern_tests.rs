@@ -0,0 +1,79 @@
+//! Tests for object key interning in literals and JSON decoding
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+use std::sync::Arc;
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn field(value: &Value, name: &str) -> Value {
+    value.as_object().unwrap().get(name).cloned().unwrap()
+}
+
+#[test]
+fn test_repeated_keys_intern_to_one_instance() {
+    let element = r#"{\"pubkey\": \"abc\", \"lamports\": 5}"#;
+    let json = format!("[{}]", vec![element; 10_000].join(","));
+    let code = format!(
+        r#"(define accounts (parse-json "{}"))
+           [(length accounts) (intern-stats)]"#,
+        json
+    );
+
+    let result = eval(&code).unwrap();
+    let items = result.as_array().unwrap();
+    assert_eq!(items[0], Value::Int(10_000));
+
+    let stats = &items[1];
+    assert_eq!(field(stats, "unique"), Value::Int(2));
+    assert_eq!(field(stats, "misses"), Value::Int(2));
+    assert_eq!(field(stats, "hits"), Value::Int(2 * 9_999));
+}
+
+#[test]
+fn test_intern_stats_start_empty() {
+    let stats = eval("(intern-stats)").unwrap();
+    assert_eq!(field(&stats, "unique"), Value::Int(0));
+    assert!(eval("(intern-stats 1)").is_err());
+}
+
+/// Every object's entry for `name`, as the shared key instance it holds
+fn key_instances(objects: &Value, name: &str) -> Vec<Arc<str>> {
+    objects
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|obj| {
+            let (key, _) = obj.as_object().unwrap().get_key_value(name).unwrap();
+            key.clone()
+        })
+        .collect()
+}
+
+#[test]
+fn test_parsed_objects_share_key_allocations() {
+    let element = r#"{\"lamports\": 5}"#;
+    let json = format!("[{}]", vec![element; 10_000].join(","));
+    let accounts = eval(&format!(r#"(parse-json "{}")"#, json)).unwrap();
+
+    let keys = key_instances(&accounts, "lamports");
+    assert!(keys.iter().all(|k| Arc::ptr_eq(k, &keys[0])));
+    // held once by each of the 10k objects and once by `keys`
+    assert_eq!(Arc::strong_count(&keys[0]), 2 * 10_000);
+}
+
+#[test]
+fn test_literal_objects_share_key_allocations() {
+    let objects = eval("(map (lambda (i) {:id i}) (range 0 1000))").unwrap();
+
+    let keys = key_instances(&objects, "id");
+    assert_eq!(keys.len(), 1000);
+    assert!(keys.iter().all(|k| Arc::ptr_eq(k, &keys[0])));
+}
@@ -0,0 +1,48 @@
+//! Tests for the `lerp` and `map-range` numeric helpers
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    LispEvaluator::new().execute(&program)
+}
+
+#[test]
+fn test_lerp_endpoints_and_midpoint() {
+    assert_eq!(eval("(lerp 10 20 0)").unwrap(), Value::Float(10.0));
+    assert_eq!(eval("(lerp 10 20 0.5)").unwrap(), Value::Float(15.0));
+    assert_eq!(eval("(lerp 10 20 1)").unwrap(), Value::Float(20.0));
+    assert_eq!(eval("(lerp 0.1 0.7 1)").unwrap(), Value::Float(0.7));
+}
+
+#[test]
+fn test_lerp_extrapolates() {
+    assert_eq!(eval("(lerp 0 10 2)").unwrap(), Value::Float(20.0));
+    assert_eq!(eval("(lerp 5 -5 0.25)").unwrap(), Value::Float(2.5));
+}
+
+#[test]
+fn test_map_range_rescales() {
+    assert_eq!(
+        eval("(map-range 5 0 10 0 100)").unwrap(),
+        Value::Float(50.0)
+    );
+    assert_eq!(
+        eval("(map-range 25 0 100 -1 1)").unwrap(),
+        Value::Float(-0.5)
+    );
+    // Reversed output range
+    assert_eq!(eval("(map-range 2 0 8 100 0)").unwrap(), Value::Float(75.0));
+    assert_eq!(eval("(map-range 10 0 10 3 7)").unwrap(), Value::Float(7.0));
+}
+
+#[test]
+fn test_map_range_zero_width_input_errors() {
+    assert!(eval("(map-range 5 3 3 0 1)").is_err());
+    assert!(eval("(map-range 5 0 10 0)").is_err());
+}
@@ -0,0 +1,57 @@
+//! Tests for `interpose` and `interleave`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().map(|&n| Value::Int(n)).collect())
+}
+
+#[test]
+fn test_interpose_between_elements() {
+    assert_eq!(
+        eval("(interpose 0 [1 2 3])").unwrap(),
+        ints(&[1, 0, 2, 0, 3])
+    );
+    assert_eq!(
+        eval("(interpose \", \" [\"a\" \"b\"])").unwrap(),
+        Value::array(vec![
+            Value::String("a".to_string()),
+            Value::String(", ".to_string()),
+            Value::String("b".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_interpose_edges() {
+    assert_eq!(eval("(interpose 0 [7])").unwrap(), ints(&[7]));
+    assert_eq!(eval("(interpose 0 [])").unwrap(), ints(&[]));
+}
+
+#[test]
+fn test_interleave_stops_at_shortest() {
+    assert_eq!(
+        eval("(interleave [1 2 3 4] [10 20])").unwrap(),
+        ints(&[1, 10, 2, 20])
+    );
+    assert_eq!(eval("(interleave [1 2] [])").unwrap(), ints(&[]));
+}
+
+#[test]
+fn test_interleave_three_lists() {
+    assert_eq!(
+        eval("(interleave [1 2] [10 20] [100 200 300])").unwrap(),
+        ints(&[1, 10, 100, 2, 20, 200])
+    );
+    assert!(eval("(interleave [1 2] 3)").is_err());
+}
@@ -0,0 +1,112 @@
+//! Tests for environment introspection: bound?, list-bindings, describe
+
+use solisp::{Evaluator, Parser, Scanner, Value};
+
+fn eval_lisp(source: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    let mut scanner = Scanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = Evaluator::new();
+    Ok(evaluator.execute(&program)?)
+}
+
+fn field(obj: &Value, key: &str) -> Value {
+    match obj {
+        Value::Object(map) => map.get(key).cloned().unwrap_or(Value::Null),
+        other => panic!("expected object, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_bound_defined_variable() {
+    let result = eval_lisp("(define x 1) (bound? 'x)").unwrap();
+    assert_eq!(result, Value::Bool(true));
+}
+
+#[test]
+fn test_bound_undefined_variable() {
+    let result = eval_lisp("(bound? 'nope)").unwrap();
+    assert_eq!(result, Value::Bool(false));
+}
+
+#[test]
+fn test_bound_sees_enclosing_scope() {
+    let result = eval_lisp("(define x 1) (let ((y 2)) [(bound? 'x) (bound? 'y)])").unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::Bool(true), Value::Bool(true)])
+    );
+}
+
+#[test]
+fn test_bound_after_scope_exit() {
+    let result = eval_lisp("(let ((y 2)) y) (bound? 'y)").unwrap();
+    assert_eq!(result, Value::Bool(false));
+}
+
+#[test]
+fn test_list_bindings_current_scope() {
+    let result = eval_lisp("(define outer 0) (let ((b 1) (a 2)) (list-bindings))").unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::String("a".to_string()),
+            Value::String("b".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_describe_variable() {
+    let result = eval_lisp("(define x 42) (describe 'x)").unwrap();
+    assert_eq!(field(&result, "type"), Value::String("int".to_string()));
+    assert_eq!(field(&result, "arity"), Value::Null);
+}
+
+#[test]
+fn test_describe_defun_arity() {
+    let result = eval_lisp("(defun add3 (a b c) (+ a b c)) (describe 'add3)").unwrap();
+    assert_eq!(
+        field(&result, "type"),
+        Value::String("function".to_string())
+    );
+    assert_eq!(field(&result, "arity"), Value::Int(3));
+    assert_eq!(field(&result, "variadic"), Value::Bool(false));
+    assert_eq!(
+        field(&result, "params"),
+        Value::array(vec![
+            Value::String("a".to_string()),
+            Value::String("b".to_string()),
+            Value::String("c".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_describe_optional_and_rest_params() {
+    let result = eval_lisp("(defun f (a &optional (b 2) &rest more) a) (describe 'f)").unwrap();
+    assert_eq!(field(&result, "arity"), Value::Int(1));
+    assert_eq!(field(&result, "variadic"), Value::Bool(true));
+    assert_eq!(
+        field(&result, "params"),
+        Value::array(vec![
+            Value::String("a".to_string()),
+            Value::String("&optional".to_string()),
+            Value::String("b".to_string()),
+            Value::String("&rest".to_string()),
+            Value::String("more".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_describe_unbound_is_error() {
+    assert!(eval_lisp("(describe 'missing)").is_err());
+}
+
+#[test]
+fn test_user_defined_describe_takes_precedence() {
+    let result = eval_lisp("(defun describe (x) (* x 2)) (describe 21)").unwrap();
+    assert_eq!(result, Value::Int(42));
+}
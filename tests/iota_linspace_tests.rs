@@ -0,0 +1,56 @@
+//! Tests for the `iota` and `linspace` numeric sequence generators
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    LispEvaluator::new().execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().copied().map(Value::Int).collect())
+}
+
+fn floats(values: &[f64]) -> Value {
+    Value::array(values.iter().copied().map(Value::Float).collect())
+}
+
+#[test]
+fn test_iota_defaults() {
+    assert_eq!(eval("(iota 5)").unwrap(), ints(&[0, 1, 2, 3, 4]));
+    assert_eq!(eval("(iota 3 10)").unwrap(), ints(&[10, 11, 12]));
+    assert_eq!(eval("(iota 0)").unwrap(), ints(&[]));
+}
+
+#[test]
+fn test_iota_step() {
+    assert_eq!(eval("(iota 4 10 -3)").unwrap(), ints(&[10, 7, 4, 1]));
+    assert_eq!(eval("(iota 3 0 0.5)").unwrap(), floats(&[0.0, 0.5, 1.0]));
+    assert!(eval("(iota -1)").is_err());
+}
+
+#[test]
+fn test_linspace_includes_both_ends() {
+    assert_eq!(
+        eval("(linspace 0 1 5)").unwrap(),
+        floats(&[0.0, 0.25, 0.5, 0.75, 1.0])
+    );
+    assert_eq!(
+        eval("(linspace 10 0 3)").unwrap(),
+        floats(&[10.0, 5.0, 0.0])
+    );
+    assert_eq!(eval("(last (linspace 0 1 7))").unwrap(), Value::Float(1.0));
+}
+
+#[test]
+fn test_linspace_small_counts() {
+    assert_eq!(eval("(linspace 2 9 1)").unwrap(), floats(&[2.0]));
+    assert_eq!(eval("(linspace 2 9 0)").unwrap(), floats(&[]));
+    assert_eq!(eval("(linspace 2 9 2)").unwrap(), floats(&[2.0, 9.0]));
+    assert!(eval("(linspace 0 1 -2)").is_err());
+}
@@ -0,0 +1,81 @@
+//! Tests for JSON round-tripping with `json-stringify` and `parse-json`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn string(code: &str) -> String {
+    match eval(code).unwrap() {
+        Value::String(s) => s,
+        other => panic!("expected string, got {:?}", other),
+    }
+}
+
+const NESTED: &str = r#"{:zeta 1 :alpha {:b [1 2.5 null] :a "x"} :mid [{:y true :x false}]}"#;
+
+#[test]
+fn test_stringify_sorts_keys() {
+    assert_eq!(
+        string(&format!("(json-stringify {})", NESTED)),
+        r#"{"alpha":{"a":"x","b":[1,2.5,null]},"mid":[{"x":false,"y":true}],"zeta":1}"#
+    );
+}
+
+#[test]
+fn test_sorted_output_is_identical_across_runs() {
+    let code = format!("(json-stringify {})", NESTED);
+    let first = string(&code);
+    for _ in 0..20 {
+        assert_eq!(string(&code), first);
+    }
+}
+
+#[test]
+fn test_stringify_parse_stringify_is_idempotent() {
+    let code = format!(
+        r#"
+        (define once (json-stringify {}))
+        (define twice (json-stringify (parse-json once)))
+        [(= once twice) (equal? (parse-json once) {})]
+        "#,
+        NESTED, NESTED
+    );
+    assert_eq!(
+        eval(&code).unwrap(),
+        Value::array(vec![Value::Bool(true), Value::Bool(true)])
+    );
+}
+
+#[test]
+fn test_parse_json_number_and_null_mapping() {
+    let result = eval(r#"(parse-json "[1, -7, 2.5, 3.0, null, {\"n\": {\"m\": [10]}}]")"#).unwrap();
+    let items = result.as_array().unwrap();
+    assert_eq!(items[0], Value::Int(1));
+    assert_eq!(items[1], Value::Int(-7));
+    assert_eq!(items[2], Value::Float(2.5));
+    assert_eq!(items[3], Value::Float(3.0));
+    assert_eq!(items[4], Value::Null);
+    assert_eq!(
+        eval(r#"(get (get (parse-json "{\"n\": {\"m\": [10]}}") "n") "m")"#).unwrap(),
+        Value::array(vec![Value::Int(10)])
+    );
+}
+
+#[test]
+fn test_pretty_option() {
+    let expected = "{\n  \"a\": 1,\n  \"b\": [\n    2\n  ]\n}";
+    assert_eq!(string("(json-stringify {:b [2] :a 1} :pretty true)"), expected);
+    assert_eq!(
+        string("(json-stringify {:value {:b [2] :a 1} :pretty true})"),
+        expected
+    );
+    assert!(eval("(json-stringify 1 :indent 2)").is_err());
+}
@@ -1,6 +1,6 @@
 //! Tests for labels (recursive local functions)
 
-use ovsm::{Evaluator, Parser, Scanner, Value};
+use solisp::{Evaluator, Parser, Scanner, Value};
 
 fn eval_lisp(source: &str) -> Result<Value, Box<dyn std::error::Error>> {
     let mut scanner = Scanner::new(source);
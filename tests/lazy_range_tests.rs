@@ -0,0 +1,134 @@
+//! Tests that `for`, `map`, `filter` and `reduce` walk integer ranges lazily
+//!
+//! The allocation budget counts every array element the evaluator allocates,
+//! so iterating a range far larger than the budget only succeeds if the range
+//! is never materialized. Only a `(range ...)` call written directly in the
+//! collection position is lazy; a range bound to a variable is an array.
+
+use solisp::{Error, LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Execute Solisp code with the given allocation budget in bytes
+fn eval_limited(code: &str, budget: usize) -> Result<Value, Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.set_allocation_budget(budget);
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_for_over_range_does_not_materialize() {
+    let code = r#"
+        (define total 0)
+        (for (i (range 0 200000))
+          (set! total (+ total i)))
+        total
+    "#;
+    assert_eq!(
+        eval_limited(code, 1 << 16).unwrap(),
+        Value::Int(19999900000)
+    );
+    // Building the same range outright exceeds the budget
+    assert!(matches!(
+        eval_limited("(range 0 200000)", 1 << 16),
+        Err(Error::OutOfMemory(_))
+    ));
+}
+
+#[test]
+fn test_break_leaves_billion_element_range_unmaterialized() {
+    let code = r#"
+        (define total 0)
+        (for (i (range 0 1000000000))
+          (when (>= i 1000) (break))
+          (set! total (+ total i)))
+        total
+    "#;
+    assert_eq!(eval_limited(code, 1 << 16).unwrap(), Value::Int(499500));
+}
+
+#[test]
+fn test_reduce_over_million_element_range() {
+    // Far more elements than the budget could hold, each actually visited
+    assert_eq!(
+        eval_limited("(reduce (range 0 1000000) 0 +)", 1 << 16).unwrap(),
+        Value::Int(499999500000)
+    );
+}
+
+#[test]
+fn test_reduce_and_filter_over_range() {
+    assert_eq!(
+        eval_limited("(reduce (range 0 100000) 0 +)", 1 << 16).unwrap(),
+        Value::Int(4999950000)
+    );
+    assert_eq!(
+        eval_limited(
+            "(length (filter (range 0 100000) (lambda (x) (= (% x 25000) 0))))",
+            1 << 16
+        )
+        .unwrap(),
+        Value::Int(4)
+    );
+}
+
+#[test]
+fn test_lazy_ranges_keep_step_and_direction() {
+    assert_eq!(
+        eval_limited("(map (range 10 0 -3) (lambda (x) (* x 2)))", 1 << 16).unwrap(),
+        Value::array(vec![
+            Value::Int(20),
+            Value::Int(14),
+            Value::Int(8),
+            Value::Int(2)
+        ])
+    );
+    assert_eq!(
+        eval_limited(
+            "(define s 0.0) (for (x (range 0 1 0.25)) (set! s (+ s x))) s",
+            1 << 16
+        )
+        .unwrap(),
+        Value::Float(1.5)
+    );
+}
+
+#[test]
+fn test_map_over_range_charges_its_results() {
+    // The range is never built, but the mapped array still counts
+    assert!(matches!(
+        eval_limited("(map (range 0 100000) (lambda (x) x))", 1 << 16),
+        Err(Error::OutOfMemory(_))
+    ));
+    assert!(matches!(
+        eval_limited("(filter (range 0 100000) (lambda (x) true))", 1 << 16),
+        Err(Error::OutOfMemory(_))
+    ));
+}
+
+#[test]
+fn test_only_literal_range_calls_are_lazy() {
+    let literal = r#"
+        (define total 0)
+        (for (i (range 0 200000)) (set! total (+ total i)))
+        total
+    "#;
+    assert_eq!(
+        eval_limited(literal, 1 << 16).unwrap(),
+        Value::Int(19999900000)
+    );
+
+    // Binding the range first builds it as an array, which the budget refuses
+    let bound = r#"
+        (define r (range 0 200000))
+        (define total 0)
+        (for (i r) (set! total (+ total i)))
+        total
+    "#;
+    assert!(matches!(
+        eval_limited(bound, 1 << 16),
+        Err(Error::OutOfMemory(_))
+    ));
+}
@@ -0,0 +1,95 @@
+//! Tests for lazy sequences: `lazy-seq`, `lazy-map`, `lazy-filter` and `take`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().map(|n| Value::Int(*n)).collect())
+}
+
+const NATURALS: &str = r#"
+    (defun naturals-from (n) (lazy-seq (lambda () [n (naturals-from (+ n 1))])))
+    (defun naturals () (naturals-from 0))
+"#;
+
+#[test]
+fn test_take_from_lazy_map_over_infinite_seq() {
+    let code = format!(
+        "{} (take 5 (lazy-map (lambda (x) (* x x)) (naturals)))",
+        NATURALS
+    );
+    assert_eq!(eval(&code).unwrap(), ints(&[0, 1, 4, 9, 16]));
+}
+
+#[test]
+fn test_lazy_filter_over_infinite_seq() {
+    let code = format!(
+        "{} (take 4 (lazy-filter (lambda (x) (= (% x 3) 0)) (naturals)))",
+        NATURALS
+    );
+    assert_eq!(eval(&code).unwrap(), ints(&[0, 3, 6, 9]));
+}
+
+#[test]
+fn test_take_forces_exactly_n_and_memoizes() {
+    let code = r#"
+        (define forced (make-atomic-integer 0))
+        (defun counting-from (n)
+          (lazy-seq (lambda ()
+            (do (atomic-integer-incf forced)
+                [n (counting-from (+ n 1))]))))
+        (define xs (counting-from 0))
+        (take 3 xs)
+        (take 3 xs)
+        [(take 2 xs) (atomic-integer-value forced)]
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![ints(&[0, 1]), Value::Int(3)])
+    );
+}
+
+#[test]
+fn test_finite_lazy_seq_and_array_sources() {
+    let code = r#"
+        (defun upto (n limit)
+          (lazy-seq (lambda () (if (>= n limit) null [n (upto (+ n 1) limit)]))))
+        [(take 10 (upto 0 3)) (take 10 (lazy-map + [1 2 3]))]
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![ints(&[0, 1, 2]), ints(&[1, 2, 3])])
+    );
+}
+
+#[test]
+fn test_lazy_seq_type_and_errors() {
+    assert_eq!(
+        eval("(typeof (lazy-seq (lambda () null)))").unwrap(),
+        Value::String("lazy-seq".to_string())
+    );
+    assert!(eval("(lazy-seq 5)").is_err());
+    assert!(eval("(take 1 (lazy-seq (lambda () 42)))").is_err());
+}
+
+#[test]
+fn test_lazy_map_over_large_array_steps_linearly() {
+    // Copying the tail at each step would make this quadratic
+    let code = r#"
+        (define items (take 200000 (lazy-map (lambda (x) (* x 2)) (range 0 200000))))
+        [(length items) (last items)]
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![Value::Int(200_000), Value::Int(399_998)])
+    );
+}
@@ -8,9 +8,9 @@
 /// 5. Advanced features (nested expressions, closures, recursion)
 /// 6. Real-world scenarios (pagination, filtering, aggregation)
 /// 7. Critical bug demonstrations (IF in WHILE loops)
-use ovsm::lexer::SExprScanner;
-use ovsm::parser::SExprParser;
-use ovsm::runtime::{LispEvaluator, Value};
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
 
 // Helper function to execute LISP code
 fn execute_lisp(source: &str) -> Result<Value, Box<dyn std::error::Error>> {
@@ -1,8 +1,8 @@
 /// End-to-end integration test for LISP syntax
 /// Demonstrates: Lexer → Parser → Evaluator working together
-use ovsm::lexer::SExprScanner;
-use ovsm::parser::SExprParser;
-use ovsm::runtime::{LispEvaluator, Value};
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
 
 #[test]
 fn test_lisp_e2e_simple_arithmetic() {
@@ -956,17 +956,16 @@ fn test_sqrt_float() {
 }
 
 #[test]
-fn test_sqrt_negative_error() {
+fn test_sqrt_negative_is_complex() {
     let source = "(sqrt -4)";
     let mut scanner = SExprScanner::new(source);
     let tokens = scanner.scan_tokens().unwrap();
     let mut parser = SExprParser::new(tokens);
     let program = parser.parse().unwrap();
     let mut evaluator = LispEvaluator::new();
-    let result = evaluator.execute(&program);
+    let result = evaluator.execute(&program).unwrap();
 
-    assert!(result.is_err());
-    assert!(format!("{}", result.unwrap_err()).contains("Cannot take square root of negative"));
+    assert_eq!(result, Value::complex(0.0, 2.0));
 }
 
 #[test]
@@ -0,0 +1,166 @@
+//! Tests for `break` and `continue` in `while`, `for` and `loop`
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_break_out_of_for() {
+    let result = eval(
+        r#"
+        (define seen [])
+        (define found
+          (for (x [1 4 9 16 25])
+            (do
+              (when (> x 5) (break x))
+              (set! seen (append seen [x])))))
+        [found seen]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::Int(9),
+            Value::array(vec![Value::Int(1), Value::Int(4)]),
+        ])
+    );
+}
+
+#[test]
+fn test_continue_skips_even_numbers() {
+    let result = eval(
+        r#"
+        (define odds [])
+        (for (n (range 0 10))
+          (do
+            (when (== (% n 2) 0) (continue))
+            (set! odds (append odds [n]))))
+        odds
+        "#,
+    )
+    .unwrap();
+    let expected: Vec<Value> = [1, 3, 5, 7, 9].into_iter().map(Value::Int).collect();
+    assert_eq!(result, Value::array(expected));
+}
+
+#[test]
+fn test_break_and_continue_in_while() {
+    let result = eval(
+        r#"
+        (define i 0)
+        (define total 0)
+        (while true
+          (set! i (+ i 1))
+          (when (== i 3) (continue))
+          (when (> i 5) (break total))
+          (set! total (+ total i)))
+        "#,
+    )
+    .unwrap();
+    // 1 + 2 + 4 + 5
+    assert_eq!(result, Value::Int(12));
+}
+
+#[test]
+fn test_break_without_value_returns_nil() {
+    let result = eval("(for (x [1 2 3]) (break))").unwrap();
+    assert_eq!(result, Value::Null);
+}
+
+#[test]
+fn test_break_leaves_only_innermost_loop() {
+    let result = eval(
+        r#"
+        (define pairs 0)
+        (for (a [1 2 3])
+          (for (b [1 2 3])
+            (do
+              (when (> b a) (break))
+              (set! pairs (+ pairs 1)))))
+        pairs
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(6));
+}
+
+#[test]
+fn test_break_passes_through_try() {
+    let result = eval(
+        r#"
+        (for (x [1 2 3])
+          (try (when (== x 2) (break :stopped)) (catch e :caught)))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::String(":stopped".to_string()));
+}
+
+#[test]
+fn test_break_in_loop_macro() {
+    let result = eval("(loop for i from 1 to 10 do (when (== i 4) (break (* i 10))))").unwrap();
+    assert_eq!(result, Value::Int(40));
+}
+
+#[test]
+fn test_break_outside_loop_errors() {
+    assert!(matches!(
+        eval("(break 1)"),
+        Err(solisp::Error::InvalidBreak)
+    ));
+    assert!(matches!(
+        eval("(continue)"),
+        Err(solisp::Error::InvalidContinue)
+    ));
+}
+
+#[test]
+fn test_continue_in_lambda_does_not_escape_to_caller_loop() {
+    // The lambda is not inside the for loop, so its (continue) is an error
+    // of the call rather than a skip of the loop body
+    assert!(matches!(
+        eval("(for (i [1 2 3]) (map [10 20] (lambda (y) (if (= y 10) (continue) y))))"),
+        Err(solisp::Error::InvalidContinue)
+    ));
+
+    let result = eval(
+        r#"
+        (define total 0)
+        (for (i [1 2 3])
+          (try
+            (map [10 20] (lambda (y) (if (= y 10) (continue) y)))
+            (catch e nil))
+          (set! total (+ total 1)))
+        total
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(3));
+}
+
+#[test]
+fn test_break_in_function_does_not_escape_to_caller_loop() {
+    assert!(matches!(
+        eval("(defun stop () (break 1)) (while true (stop))"),
+        Err(solisp::Error::InvalidBreak)
+    ));
+    // Loops inside the function body still see their own break
+    let result = eval(
+        r#"
+        (defun first-big (xs) (for (x xs) (when (> x 5) (break x))))
+        (for (i [1 2]) (first-big [3 7 9]))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(7));
+}
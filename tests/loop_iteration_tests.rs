@@ -0,0 +1,75 @@
+//! Tests for `loop` iteration over strings, arrays and hash tables
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_across_counts_vowels_in_string() {
+    let result = eval(r#"(loop for c across "solana programs" count (contains "aeiou" c))"#);
+    assert_eq!(result.unwrap(), Value::Int(5));
+}
+
+#[test]
+fn test_across_iterates_array() {
+    let result = eval("(loop for x across [1 2 3] collect (* x x))");
+    assert_eq!(
+        result.unwrap(),
+        Value::array(vec![Value::Int(1), Value::Int(4), Value::Int(9)])
+    );
+}
+
+#[test]
+fn test_being_the_hash_values_sums_values() {
+    let result = eval("(loop for v being the hash-values of {:a 1 :b 2 :c 39} sum v)");
+    assert_eq!(result.unwrap(), Value::Int(42));
+}
+
+#[test]
+fn test_being_the_hash_keys_using_hash_value() {
+    let result = eval(
+        r#"(define ht {:x 10 :y 20})
+           (loop for k being the hash-keys of ht using (hash-value v)
+                 collect (str k "=" v))"#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::array(vec![
+            Value::String("x=10".to_string()),
+            Value::String("y=20".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_being_each_hash_value_using_hash_key() {
+    let result =
+        eval("(loop for v being each hash-value of {:a 1 :b 2} using (hash-key k) collect k)");
+    assert_eq!(
+        result.unwrap(),
+        Value::array(vec![
+            Value::String("a".to_string()),
+            Value::String("b".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_being_rejects_mismatched_using() {
+    assert!(
+        eval("(loop for k being the hash-keys of {:a 1} using (hash-key j) collect k)").is_err()
+    );
+}
+
+#[test]
+fn test_being_requires_hash_table() {
+    assert!(eval("(loop for k being the hash-keys of [1 2] collect k)").is_err());
+}
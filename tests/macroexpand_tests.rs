@@ -0,0 +1,94 @@
+//! Tests for `macroexpand-1`, `macroexpand`, `macroexpand-all` and `eval`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+/// `inc2` expands to a call of the `inc1` macro
+const MACROS: &str = r#"
+    (defmacro inc1 (v) `(+ ,v 1))
+    (defmacro inc2 (v) `(inc1 (inc1 ,v)))
+"#;
+
+fn sym(name: &str) -> Value {
+    Value::String(name.to_string())
+}
+
+fn list(items: Vec<Value>) -> Value {
+    Value::array(items)
+}
+
+#[test]
+fn test_macroexpand_1_expands_outermost_call_once() {
+    let result = eval(&format!("{} (macroexpand-1 '(inc2 5))", MACROS)).unwrap();
+    assert_eq!(
+        result,
+        list(vec![sym("inc1"), list(vec![sym("inc1"), Value::Int(5)])])
+    );
+}
+
+#[test]
+fn test_macroexpand_repeats_at_top_level_only() {
+    let result = eval(&format!("{} (macroexpand '(inc2 5))", MACROS)).unwrap();
+    assert_eq!(
+        result,
+        list(vec![
+            sym("+"),
+            list(vec![sym("inc1"), Value::Int(5)]),
+            Value::Int(1)
+        ])
+    );
+}
+
+#[test]
+fn test_macroexpand_all_expands_subforms() {
+    let result = eval(&format!("{} (macroexpand-all '(inc2 5))", MACROS)).unwrap();
+    assert_eq!(
+        result,
+        list(vec![
+            sym("+"),
+            list(vec![sym("+"), Value::Int(5), Value::Int(1)]),
+            Value::Int(1)
+        ])
+    );
+}
+
+#[test]
+fn test_non_macro_forms_are_returned_unchanged() {
+    let expected = list(vec![sym("foo"), Value::Int(1)]);
+    for tool in ["macroexpand-1", "macroexpand", "macroexpand-all"] {
+        let result = eval(&format!("{} ({} '(foo 1))", MACROS, tool)).unwrap();
+        assert_eq!(result, expected, "{}", tool);
+    }
+}
+
+#[test]
+fn test_expansions_evaluate() {
+    assert_eq!(
+        eval(&format!("{} (inc2 5)", MACROS)).unwrap(),
+        Value::Int(7)
+    );
+    assert_eq!(
+        eval(&format!("{} (eval (macroexpand-1 '(inc2 5)))", MACROS)).unwrap(),
+        Value::Int(7)
+    );
+    assert_eq!(eval("(eval '(* 6 7))").unwrap(), Value::Int(42));
+}
+
+#[test]
+fn test_macro_with_rest_body() {
+    let code = r#"
+        (defmacro my-when (c &rest body) `(if ,c (do ,@body) nil))
+        (define x 1)
+        (my-when (> x 0) (set! x 5) (* x 2))
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(10));
+}
@@ -0,0 +1,85 @@
+//! Tests for multi-sequence `mapcar` and `map`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().map(|n| Value::Int(*n)).collect())
+}
+
+#[test]
+fn test_mapcar_single_list_unchanged() {
+    assert_eq!(
+        eval("(mapcar (lambda (x) (* x 2)) [1 2 3])").unwrap(),
+        ints(&[2, 4, 6])
+    );
+}
+
+#[test]
+fn test_mapcar_two_lists_with_builtin_operator() {
+    assert_eq!(
+        eval("(mapcar + '(1 2 3) '(10 20 30))").unwrap(),
+        ints(&[11, 22, 33])
+    );
+}
+
+#[test]
+fn test_mapcar_three_lists_with_lambda() {
+    assert_eq!(
+        eval("(mapcar (lambda (a b c) (+ a (* b c))) [1 2] [3 4] [5 6])").unwrap(),
+        ints(&[16, 26])
+    );
+}
+
+#[test]
+fn test_mapcar_truncates_to_shortest() {
+    assert_eq!(
+        eval("(mapcar + [1 2 3 4] [10 20] [100 200 300])").unwrap(),
+        ints(&[111, 222])
+    );
+    assert_eq!(eval("(mapcar + [1 2] [])").unwrap(), ints(&[]));
+}
+
+#[test]
+fn test_map_multiple_collections() {
+    assert_eq!(
+        eval("(map [1 2 3] [4 5] (lambda (a b) (* a b)))").unwrap(),
+        ints(&[4, 10])
+    );
+    assert_eq!(
+        eval("(map [1 2 3] (lambda (x) (+ x 1)))").unwrap(),
+        ints(&[2, 3, 4])
+    );
+}
+
+#[test]
+fn test_map_rejects_non_array_sequences() {
+    assert!(eval("(mapcar + [1 2] 5)").is_err());
+    assert!(eval("(map [1 2] \"ab\" (lambda (a b) a))").is_err());
+}
+
+#[test]
+fn test_lambda_arity_must_match_sequence_count() {
+    assert!(eval("(mapcar (lambda (x) x) [1] [2])").is_err());
+}
+
+#[test]
+fn test_quoted_list_is_data() {
+    assert_eq!(
+        eval("'(a 1 (b 2))").unwrap(),
+        Value::array(vec![
+            Value::String("a".to_string()),
+            Value::Int(1),
+            Value::array(vec![Value::String("b".to_string()), Value::Int(2)]),
+        ])
+    );
+}
@@ -0,0 +1,97 @@
+//! Tests for the `memoize` combinator
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_memoized_fib_computes_each_subproblem_once() {
+    // Naive fib(25) would make ~240k calls; memoized it evaluates the body
+    // once per distinct n in 0..=25
+    let code = r#"
+        (define calls (make-atomic-integer 0))
+        (define fib
+          (memoize
+            (lambda (n)
+              (do
+                (atomic-integer-incf calls)
+                (if (< n 2)
+                    n
+                    (+ (fib (- n 1)) (fib (- n 2))))))))
+        (define result (fib 25))
+        [result (atomic-integer-value calls)]
+    "#;
+
+    let result = eval(code).unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::Int(75025), Value::Int(26)])
+    );
+}
+
+#[test]
+fn test_memoize_caches_across_calls() {
+    let code = r#"
+        (define calls (make-atomic-integer 0))
+        (define square (memoize (lambda (x) (do (atomic-integer-incf calls) (* x x)))))
+        (square 4)
+        (square 4)
+        (square 5)
+        (atomic-integer-value calls)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(2));
+}
+
+#[test]
+fn test_memoize_keys_distinguish_types() {
+    let code = r#"
+        (define tag (memoize (lambda (x) (typeof x))))
+        [(tag 1) (tag "1") (tag true)]
+    "#;
+    let expected = Value::array(vec![
+        Value::String("number".to_string()),
+        Value::String("string".to_string()),
+        Value::String("boolean".to_string()),
+    ]);
+    assert_eq!(eval(code).unwrap(), expected);
+}
+
+#[test]
+fn test_memoize_multiple_arguments() {
+    let code = r#"
+        (define add (memoize (lambda (a b) (+ a b))))
+        [(add 1 2) (add 2 1) (add "a" "b")]
+    "#;
+    let expected = Value::array(vec![
+        Value::Int(3),
+        Value::Int(3),
+        Value::String("ab".to_string()),
+    ]);
+    assert_eq!(eval(code).unwrap(), expected);
+}
+
+#[test]
+fn test_memoize_rejects_unhashable_arguments() {
+    let code = r#"
+        (define first-of (memoize (lambda (xs) (first xs))))
+        (first-of [1 2 3])
+    "#;
+    assert!(eval(code).is_err());
+}
+
+#[test]
+fn test_memoize_requires_function() {
+    assert!(eval("(memoize 42)").is_err());
+    assert_eq!(
+        eval("(function? (memoize (lambda (x) x)))").unwrap(),
+        Value::Bool(true)
+    );
+}
@@ -0,0 +1,51 @@
+//! Tests for `merge-with`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn field(obj: &Value, key: &str) -> Value {
+    obj.as_object().unwrap().get(key).cloned().unwrap()
+}
+
+#[test]
+fn test_overlapping_keys_are_summed() {
+    let result = eval("(merge-with + {:a 1 :b 10} {:a 2 :b 20})").unwrap();
+    assert_eq!(field(&result, "a"), Value::Int(3));
+    assert_eq!(field(&result, "b"), Value::Int(30));
+}
+
+#[test]
+fn test_disjoint_keys_are_copied() {
+    let result = eval("(merge-with + {:a 1} {:b \"x\"})").unwrap();
+    assert_eq!(result.as_object().unwrap().len(), 2);
+    assert_eq!(field(&result, "a"), Value::Int(1));
+    assert_eq!(field(&result, "b"), Value::String("x".to_string()));
+}
+
+#[test]
+fn test_three_way_merge_applies_left_to_right() {
+    // (- (- 10 3) 2), not (- 10 (- 3 2))
+    let result = eval("(merge-with - {:a 10} {:a 3} {:a 2 :c 5})").unwrap();
+    assert_eq!(field(&result, "a"), Value::Int(5));
+    assert_eq!(field(&result, "c"), Value::Int(5));
+
+    let result =
+        eval("(merge-with (lambda (old new) (str old new)) {:k \"a\"} {:k \"b\"} {:k \"c\"})")
+            .unwrap();
+    assert_eq!(field(&result, "k"), Value::String("abc".to_string()));
+}
+
+#[test]
+fn test_function_must_take_two_arguments() {
+    assert!(eval("(merge-with (lambda (x) x) {:a 1} {:a 2})").is_err());
+    assert!(eval("(merge-with + {:a 1} 5)").is_err());
+}
@@ -0,0 +1,61 @@
+//! Tests for `multiple-value-call` and `nth-value`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_multiple_value_call_spreads_all_values() {
+    assert_eq!(
+        eval("(multiple-value-call + (values 1 2) (values 3 4))").unwrap(),
+        Value::Int(10)
+    );
+}
+
+#[test]
+fn test_multiple_value_call_with_lambda_and_single_values() {
+    let code = r#"
+        (define f (lambda (a b c) [a b c]))
+        (multiple-value-call f (values 1 2) 3)
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![Value::Int(1), Value::Int(2), Value::Int(3)])
+    );
+}
+
+#[test]
+fn test_multiple_value_call_with_builtin_name() {
+    assert_eq!(
+        eval("(multiple-value-call max (values 1 7) (values 3))").unwrap(),
+        Value::Int(7)
+    );
+}
+
+#[test]
+fn test_nth_value_extracts_value() {
+    assert_eq!(
+        eval("(nth-value 1 (values 'a 'b 'c))").unwrap(),
+        Value::String("b".to_string())
+    );
+}
+
+#[test]
+fn test_nth_value_out_of_range_is_null() {
+    assert_eq!(eval("(nth-value 5 (values 1 2))").unwrap(), Value::Null);
+    assert_eq!(eval("(nth-value 1 42)").unwrap(), Value::Null);
+    assert_eq!(eval("(nth-value 0 42)").unwrap(), Value::Int(42));
+}
+
+#[test]
+fn test_nth_value_rejects_negative_index() {
+    assert!(eval("(nth-value -1 (values 1 2))").is_err());
+}
@@ -0,0 +1,93 @@
+//! Tests for negative indices in nth, elt, slice and subseq
+//!
+//! A negative index counts back from the end of the sequence, so `-1` is the
+//! last element. Indices that fall before the start still error.
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    LispEvaluator::new().execute(&program)
+}
+
+#[test]
+fn test_nth_negative_index() {
+    assert_eq!(eval("(nth [10 20 30] -1)").unwrap(), Value::Int(30));
+    assert_eq!(eval("(nth [10 20 30] -3)").unwrap(), Value::Int(10));
+    assert_eq!(
+        eval("(nth \"hello\" -1)").unwrap(),
+        Value::String("o".to_string())
+    );
+}
+
+#[test]
+fn test_elt_negative_index() {
+    assert_eq!(eval("(elt [1 2 3] -2)").unwrap(), Value::Int(2));
+    assert_eq!(
+        eval("(elt \"abc\" -1)").unwrap(),
+        Value::String("c".to_string())
+    );
+}
+
+#[test]
+fn test_slice_negative_bounds() {
+    assert_eq!(
+        eval("(slice [1 2 3 4 5] -3 -1)").unwrap(),
+        eval("[3 4]").unwrap()
+    );
+    assert_eq!(
+        eval("(slice [1 2 3 4 5] 1 -1)").unwrap(),
+        eval("[2 3 4]").unwrap()
+    );
+    assert_eq!(
+        eval("(slice \"hello\" -3 5)").unwrap(),
+        Value::String("llo".to_string())
+    );
+}
+
+#[test]
+fn test_subseq_negative_bounds() {
+    assert_eq!(
+        eval("(subseq [1 2 3 4 5] -2)").unwrap(),
+        eval("[4 5]").unwrap()
+    );
+    assert_eq!(
+        eval("(subseq \"hello\" 0 -1)").unwrap(),
+        Value::String("hell".to_string())
+    );
+}
+
+#[test]
+fn test_negative_index_out_of_range_errors() {
+    assert!(eval("(nth [1 2 3] -4)").is_err());
+    assert!(eval("(elt \"abc\" -4)").is_err());
+    assert!(eval("(slice [1 2 3] -4 -1)").is_err());
+    assert!(eval("(subseq [1 2 3] -1 -2)").is_err());
+}
+
+#[test]
+fn test_places_accept_negative_index() {
+    assert_eq!(
+        eval("(define xs [1 2 3]) (setf (nth xs -1) 30) xs").unwrap(),
+        eval("[1 2 30]").unwrap()
+    );
+    assert_eq!(
+        eval("(define xs [1 2 3]) (incf (nth xs -2) 5) xs").unwrap(),
+        eval("[1 7 3]").unwrap()
+    );
+    assert_eq!(
+        eval("(define m [[1] [2 3]]) (push 0 (nth m -1)) m").unwrap(),
+        eval("[[1] [0 2 3]]").unwrap()
+    );
+}
+
+#[test]
+fn test_place_negative_index_out_of_range_reports_index() {
+    let err = eval("(define xs [1 2 3]) (setf (nth xs -4) 0)").unwrap_err();
+    assert!(err.to_string().contains("-4"), "{}", err);
+}
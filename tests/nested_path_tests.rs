@@ -0,0 +1,111 @@
+//! Tests for nested path operations: `get-in`, `assoc-in` and `update-in`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+const RESPONSE: &str = r#"(define resp {:result {:accounts [{:pubkey "abc" :lamports 10}
+                                                    {:pubkey "def" :lamports 20}]}})"#;
+
+fn with_response(code: &str) -> Value {
+    eval(&format!("{} {}", RESPONSE, code)).unwrap()
+}
+
+#[test]
+fn test_get_in_follows_keys_and_indices() {
+    assert_eq!(
+        with_response("(get-in resp [:result :accounts 1 :pubkey])"),
+        Value::String("def".to_string())
+    );
+    assert_eq!(
+        with_response("(get-in resp [\"result\" \"accounts\" 0 \"lamports\"])"),
+        Value::Int(10)
+    );
+    assert_eq!(
+        with_response("(get-in resp [])").as_object().unwrap().len(),
+        1
+    );
+}
+
+#[test]
+fn test_get_in_missing_path_returns_default() {
+    assert_eq!(
+        with_response("(get-in resp [:result :accounts 5 :pubkey] \"none\")"),
+        Value::String("none".to_string())
+    );
+    assert_eq!(
+        with_response("(get-in resp [:result :missing :deeper] 0)"),
+        Value::Int(0)
+    );
+    assert_eq!(
+        with_response("(get-in resp [:result :accounts :x])"),
+        Value::Null
+    );
+}
+
+#[test]
+fn test_assoc_in_creates_missing_levels() {
+    assert_eq!(
+        eval("(get-in (assoc-in {} [:a :b :c] 42) [:a :b :c])").unwrap(),
+        Value::Int(42)
+    );
+    assert_eq!(
+        with_response(
+            "(get-in (assoc-in resp [:result :accounts 0 :owner] \"sys\")
+                     [:result :accounts 0 :owner])"
+        ),
+        Value::String("sys".to_string())
+    );
+    // The original is unchanged and siblings are kept
+    assert_eq!(
+        with_response(
+            "(assoc-in resp [:result :accounts 0 :owner] \"sys\")
+             [(get-in resp [:result :accounts 0 :owner] \"unset\")
+              (get-in resp [:result :accounts 1 :pubkey])]"
+        ),
+        Value::array(vec![
+            Value::String("unset".to_string()),
+            Value::String("def".to_string()),
+        ])
+    );
+    // A plain key still works as before
+    assert_eq!(
+        eval("(get (assoc-in {:a 1} \"b\" 2) \"b\")").unwrap(),
+        Value::Int(2)
+    );
+}
+
+#[test]
+fn test_assoc_in_rejects_bad_paths() {
+    assert!(eval("(assoc-in {:a 5} [:a :b] 1)").is_err());
+    assert!(eval("(assoc-in {:a [1 2]} [:a 5] 1)").is_err());
+}
+
+#[test]
+fn test_update_in_applies_function() {
+    assert_eq!(
+        with_response(
+            "(get-in (update-in resp [:result :accounts 1 :lamports] + 5)
+                     [:result :accounts 1 :lamports])"
+        ),
+        Value::Int(25)
+    );
+    assert_eq!(
+        eval("(get-in (update-in {:a {:n 2}} [:a :n] (lambda (n) (* n n))) [:a :n])").unwrap(),
+        Value::Int(4)
+    );
+    // A missing value is passed as null
+    assert_eq!(
+        eval("(get-in (update-in {} [:count] (lambda (n) (if (null? n) 1 (+ n 1)))) [:count])")
+            .unwrap(),
+        Value::Int(1)
+    );
+}
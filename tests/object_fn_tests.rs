@@ -0,0 +1,69 @@
+//! Tests for `map-values`, `filter-keys` and `reduce-kv`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn keys(obj: &Value) -> Vec<String> {
+    let mut keys: Vec<String> = obj.as_object().unwrap().keys().map(|k| k.to_string()).collect();
+    keys.sort();
+    keys
+}
+
+fn field(obj: &Value, key: &str) -> Value {
+    obj.as_object().unwrap().get(key).cloned().unwrap()
+}
+
+#[test]
+fn test_map_values_doubles_all_values() {
+    let result = eval("(map-values (lambda (v) (* v 2)) {:a 1 :b 2 :c 3})").unwrap();
+    assert_eq!(keys(&result), vec!["a", "b", "c"]);
+    assert_eq!(field(&result, "a"), Value::Int(2));
+    assert_eq!(field(&result, "b"), Value::Int(4));
+    assert_eq!(field(&result, "c"), Value::Int(6));
+
+    let result = eval("(map-values str {:a 1})").unwrap();
+    assert_eq!(field(&result, "a"), Value::String("1".to_string()));
+}
+
+#[test]
+fn test_filter_keys_by_prefix() {
+    let result = eval(
+        r#"(filter-keys (lambda (k) (= (index-of k "sol_") 0))
+                        {:sol_price 150 :sol_volume 9 :btc_price 60000})"#,
+    )
+    .unwrap();
+    assert_eq!(keys(&result), vec!["sol_price", "sol_volume"]);
+    assert_eq!(field(&result, "sol_price"), Value::Int(150));
+}
+
+#[test]
+fn test_reduce_kv_sums_values() {
+    assert_eq!(
+        eval("(reduce-kv (lambda (acc k v) (+ acc v)) 0 {:a 1 :b 2 :c 3})").unwrap(),
+        Value::Int(6)
+    );
+    // Keys are visited in sorted order
+    assert_eq!(
+        eval("(reduce-kv (lambda (acc k v) (str acc k)) \"\" {:b 1 :c 2 :a 3})").unwrap(),
+        Value::String("abc".to_string())
+    );
+    assert_eq!(
+        eval("(reduce-kv (lambda (acc k v) (+ acc v)) 10 {})").unwrap(),
+        Value::Int(10)
+    );
+}
+
+#[test]
+fn test_function_arity_is_checked() {
+    assert!(eval("(reduce-kv (lambda (acc v) acc) 0 {:a 1})").is_err());
+    assert!(eval("(map-values (lambda (v) v) [1 2])").is_err());
+}
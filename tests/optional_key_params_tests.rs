@@ -8,10 +8,10 @@
 //! - Complex scenarios (required + optional + rest + keyword)
 //! - Error cases
 
-use ovsm::{LispEvaluator, SExprParser, SExprScanner, Value};
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
 
 /// Helper function to execute OVSM LISP code
-fn eval(code: &str) -> Result<Value, ovsm::Error> {
+fn eval(code: &str) -> Result<Value, solisp::Error> {
     // Scan tokens
     let mut scanner = SExprScanner::new(code);
     let tokens = scanner.scan_tokens()?;
@@ -0,0 +1,129 @@
+//! Tests for redirecting script output with `set_output_sink` and
+//! `with-output-capture`
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+fn run(evaluator: &mut LispEvaluator, source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    evaluator.execute(&program)
+}
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    run(&mut LispEvaluator::new(), source)
+}
+
+/// Sink whose contents stay readable after handing it to the evaluator
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_capture_loop_output() {
+    let result = eval(
+        r#"
+        (with-output-capture
+          (for (i (range 0 3))
+            (println "iteration" i)))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::String("iteration 0\niteration 1\niteration 2\n".into())
+    );
+}
+
+#[test]
+fn test_capture_print_and_format() {
+    let result = eval(
+        r#"
+        (with-output-capture
+          (print "a" "b" :sep "-")
+          (print "|")
+          (format true "~A items" 3))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::String("a-b|3 items\n".into()));
+}
+
+#[test]
+fn test_nested_captures_are_separate() {
+    let result = eval(
+        r#"
+        (define inner "")
+        (define outer
+          (with-output-capture
+            (println "outer")
+            (set! inner (with-output-capture (println "inner")))))
+        [outer inner]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::String("outer\n".into()),
+            Value::String("inner\n".into()),
+        ])
+    );
+}
+
+#[test]
+fn test_output_sink_receives_output() {
+    let buffer = SharedBuffer::default();
+    let mut evaluator = LispEvaluator::new();
+    evaluator.set_output_sink(buffer.clone());
+
+    run(
+        &mut evaluator,
+        r#"
+        (println "x =" 42)
+        (define captured (with-output-capture (println "hidden")))
+        (print captured)
+        (log "logged")
+        "#,
+    )
+    .unwrap();
+    let output = buffer.contents();
+    assert!(output.starts_with("x = 42\nhidden\n"), "{}", output);
+    assert!(output.contains("logged"), "{}", output);
+}
+
+#[test]
+fn test_failed_capture_restores_output() {
+    let buffer = SharedBuffer::default();
+    let mut evaluator = LispEvaluator::new();
+    evaluator.set_output_sink(buffer.clone());
+
+    assert!(run(
+        &mut evaluator,
+        r#"(with-output-capture (println "lost") (undefined-function))"#
+    )
+    .is_err());
+    run(&mut evaluator, r#"(println "after")"#).unwrap();
+    assert_eq!(buffer.contents(), "after\n");
+}
@@ -0,0 +1,96 @@
+//! Tests for `with-overflow-mode` integer overflow control
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code with `i64-max`/`i64-min` defined
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let source = format!(
+        "(define i64-max 9223372036854775807) (define i64-min (- 0 i64-max 1)) {}",
+        code
+    );
+    let mut scanner = SExprScanner::new(&source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_checked_mode_raises_arithmetic_overflow() {
+    let err = eval("(with-overflow-mode :checked (* i64-max 2))").unwrap_err();
+    assert!(matches!(err, solisp::Error::ArithmeticOverflow { .. }));
+    assert!(err.to_string().starts_with("arithmetic-overflow"));
+}
+
+#[test]
+fn test_checked_overflow_is_catchable() {
+    let code = r#"
+        (try (with-overflow-mode :checked (+ i64-max 1))
+             (catch e "overflowed"))
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::String("overflowed".to_string()));
+}
+
+#[test]
+fn test_checked_mode_allows_in_range_arithmetic() {
+    assert_eq!(
+        eval("(with-overflow-mode :checked (* 21 2))").unwrap(),
+        Value::Int(42)
+    );
+}
+
+#[test]
+fn test_wrapping_mode() {
+    assert_eq!(
+        eval("(with-overflow-mode :wrapping (* i64-max 2))").unwrap(),
+        Value::Int(i64::MAX.wrapping_mul(2))
+    );
+    assert_eq!(
+        eval("(with-overflow-mode :wrapping (- i64-min 1))").unwrap(),
+        Value::Int(i64::MAX)
+    );
+}
+
+#[test]
+fn test_saturating_mode() {
+    assert_eq!(
+        eval("(with-overflow-mode :saturating (* i64-max 2))").unwrap(),
+        Value::Int(i64::MAX)
+    );
+    assert_eq!(
+        eval("(with-overflow-mode :saturating (* i64-min 2))").unwrap(),
+        Value::Int(i64::MIN)
+    );
+}
+
+#[test]
+fn test_default_mode_saturates_and_mode_is_restored() {
+    // Mode is dynamic state: it reverts after the form, even when the body fails
+    let code = r#"
+        (try (with-overflow-mode :checked (* i64-max 2)) (catch e null))
+        (* i64-max 2)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(i64::MAX));
+}
+
+#[test]
+fn test_nested_modes() {
+    let code = r#"
+        (with-overflow-mode :checked
+          [(with-overflow-mode :wrapping (+ i64-max 1))
+           (try (+ i64-max 1) (catch e "checked"))])
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![
+            Value::Int(i64::MIN),
+            Value::String("checked".to_string())
+        ])
+    );
+}
+
+#[test]
+fn test_unknown_mode_rejected() {
+    assert!(eval("(with-overflow-mode :bogus (+ 1 2))").is_err());
+}
@@ -0,0 +1,94 @@
+//! Tests for `defpackage` / `in-package` namespacing
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+const UTILS: &str = r#"
+    (defpackage :utils (:export add scale))
+    (in-package :utils)
+    (define factor 10)
+    (defun helper (x) (* x factor))
+    (defun add (a b) (+ a b))
+    (defun scale (x) (helper x))
+    (in-package :cl-user)
+"#;
+
+#[test]
+fn test_qualified_access_to_exported_symbols() {
+    let code = format!("{}\n[(utils:add 2 3) (utils:scale 4)]", UTILS);
+    assert_eq!(
+        eval(&code).unwrap(),
+        Value::array(vec![Value::Int(5), Value::Int(40)])
+    );
+}
+
+#[test]
+fn test_unexported_symbol_is_an_error() {
+    let code = format!("{}\n(utils:helper 1)", UTILS);
+    let err = eval(&code).unwrap_err();
+    assert!(err.to_string().contains("not exported"), "{}", err);
+
+    // Internal symbols stay reachable with the double-colon form
+    let code = format!("{}\n[(utils::helper 2) utils::factor]", UTILS);
+    assert_eq!(
+        eval(&code).unwrap(),
+        Value::array(vec![Value::Int(20), Value::Int(10)])
+    );
+}
+
+#[test]
+fn test_package_symbols_are_not_visible_unqualified() {
+    let code = format!("{}\n(helper 1)", UTILS);
+    assert!(eval(&code).is_err());
+}
+
+#[test]
+fn test_two_packages_define_same_named_function() {
+    let code = r#"
+        (defpackage :a (:export greet))
+        (defpackage :b (:export greet))
+        (in-package :a)
+        (defun greet () "from a")
+        (in-package :b)
+        (defun greet () "from b")
+        (define local (greet))
+        (in-package :cl-user)
+        (defun greet () "from base")
+        [(a:greet) (b:greet) (greet) b::local]
+    "#;
+    let expected = Value::array(vec![
+        Value::String("from a".to_string()),
+        Value::String("from b".to_string()),
+        Value::String("from base".to_string()),
+        Value::String("from b".to_string()),
+    ]);
+    assert_eq!(eval(code).unwrap(), expected);
+}
+
+#[test]
+fn test_packages_fall_back_to_base_and_builtins() {
+    let code = r#"
+        (define shared 7)
+        (defpackage :p (:export run))
+        (in-package :p)
+        (defun run (xs) (+ shared (length xs)))
+        (in-package :cl)
+        (p:run [1 2 3])
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(10));
+}
+
+#[test]
+fn test_unknown_package_is_an_error() {
+    assert!(eval("(in-package :nowhere)").is_err());
+    assert!(eval("(nowhere:f 1)").is_err());
+}
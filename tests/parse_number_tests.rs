@@ -0,0 +1,60 @@
+//! Tests for `parse-number` format auto-detection
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_parse_integers() {
+    assert_eq!(eval(r#"(parse-number "42")"#).unwrap(), Value::Int(42));
+    assert_eq!(eval(r#"(parse-number "-7")"#).unwrap(), Value::Int(-7));
+    assert_eq!(eval(r#"(parse-number "  +5 ")"#).unwrap(), Value::Int(5));
+}
+
+#[test]
+fn test_parse_floats() {
+    assert_eq!(eval(r#"(parse-number "2.5")"#).unwrap(), Value::Float(2.5));
+    assert_eq!(eval(r#"(parse-number "1e6")"#).unwrap(), Value::Float(1e6));
+    assert_eq!(
+        eval(r#"(parse-number "-1.5e-3")"#).unwrap(),
+        Value::Float(-1.5e-3)
+    );
+}
+
+#[test]
+fn test_parse_radix_prefixes() {
+    assert_eq!(eval(r##"(parse-number "#xff")"##).unwrap(), Value::Int(255));
+    assert_eq!(eval(r##"(parse-number "#XFF")"##).unwrap(), Value::Int(255));
+    assert_eq!(eval(r##"(parse-number "#b101")"##).unwrap(), Value::Int(5));
+    assert_eq!(eval(r##"(parse-number "#o17")"##).unwrap(), Value::Int(15));
+}
+
+#[test]
+fn test_parse_ratios() {
+    assert_eq!(eval(r#"(parse-number "1/2")"#).unwrap(), Value::Float(0.5));
+    assert_eq!(eval(r#"(parse-number "6/3")"#).unwrap(), Value::Int(2));
+    assert!(eval(r#"(parse-number "1/0")"#).is_err());
+}
+
+#[test]
+fn test_invalid_input_errors_or_returns_default() {
+    assert!(eval(r#"(parse-number "abc")"#).is_err());
+    assert!(eval(r#"(parse-number "inf")"#).is_err());
+    assert!(eval(r##"(parse-number "#xzz")"##).is_err());
+    assert_eq!(
+        eval(r#"(parse-number "abc" :default 0)"#).unwrap(),
+        Value::Int(0)
+    );
+    assert_eq!(
+        eval(r#"(parse-number "" :default null)"#).unwrap(),
+        Value::Null
+    );
+}
@@ -0,0 +1,79 @@
+//! Tests for the readable (`prin1`, `write`) and aesthetic (`princ`) printers
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn string(s: &str) -> Value {
+    Value::String(s.to_string())
+}
+
+#[test]
+fn test_prin1_escapes_strings() {
+    assert_eq!(
+        eval(r#"(prin1-to-string "a\"b")"#).unwrap(),
+        string(r#""a\"b""#)
+    );
+    assert_eq!(
+        eval(r#"(write-to-string "back\\slash\n")"#).unwrap(),
+        string(r#""back\\slash\n""#)
+    );
+}
+
+#[test]
+fn test_princ_is_aesthetic() {
+    assert_eq!(eval(r#"(princ-to-string "a\"b")"#).unwrap(), string("a\"b"));
+    assert_eq!(eval("(princ-to-string 42)").unwrap(), string("42"));
+}
+
+#[test]
+fn test_prin1_atoms() {
+    assert_eq!(eval("(prin1-to-string :limit)").unwrap(), string(":limit"));
+    assert_eq!(eval("(prin1-to-string 2.0)").unwrap(), string("2.0"));
+    assert_eq!(eval("(prin1-to-string nil)").unwrap(), string("nil"));
+    assert_eq!(
+        eval(r#"(prin1-to-string '(1 "two" :three))"#).unwrap(),
+        string(r#"(1 "two" :three)"#)
+    );
+}
+
+#[test]
+fn test_prin1_round_trips_through_read() {
+    let result = eval(
+        r#"
+        (define v '(define (greet name) (str "hi \"" name "\"\n") (:tag 1.5 nil)))
+        (= (read-from-string (prin1-to-string v)) v)
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Bool(true));
+}
+
+#[test]
+fn test_prin1_and_princ_write_output() {
+    let result = eval(
+        r#"
+        (with-output-capture
+          (prin1 "x")
+          (princ "y")
+          (write 3))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, string(r#""x"y3"#));
+}
+
+#[test]
+fn test_format_s_directive() {
+    let result = eval(r#"(format nil "~a vs ~s" "text" "text")"#).unwrap();
+    assert_eq!(result, string(r#"text vs "text""#));
+}
@@ -0,0 +1,144 @@
+//! Tests for promises: `promise`, `deliver`, `promise-deref`, `all-of` and
+//! `any-of`
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_deliver_then_deref() {
+    let result = eval(
+        r#"
+        (define p (promise))
+        (define before (promise-realized? p))
+        (define first (deliver p 42))
+        (define second (deliver p 99))
+        [before first second (promise-realized? p) (promise-deref p) (promise-deref p)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::Bool(false),
+            Value::Bool(true),
+            Value::Bool(false),
+            Value::Bool(true),
+            Value::Int(42),
+            Value::Int(42),
+        ])
+    );
+}
+
+#[test]
+fn test_deref_timeout_returns_default() {
+    let result = eval(
+        r#"
+        (define p (promise))
+        [(promise-deref p 0.05 :timed-out) (promise-deref p 0)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::String(":timed-out".into()), Value::Null])
+    );
+}
+
+#[test]
+fn test_deliver_from_another_thread() {
+    let result = eval(
+        r#"
+        (define p (promise))
+        (make-thread (lambda () (do (sleep 0.05) (deliver p "from thread"))))
+        (promise-deref p)
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::String("from thread".into()));
+}
+
+#[test]
+fn test_all_of_waits_for_every_promise() {
+    let result = eval(
+        r#"
+        (define a (promise))
+        (define b (promise))
+        (define c (promise))
+        (define all (all-of a b c))
+        (make-thread (lambda () (do (sleep 0.05) (deliver c 3))))
+        (make-thread (lambda () (do (sleep 0.02) (deliver a 1))))
+        (deliver b 2)
+        (define early (promise-realized? all))
+        [early (promise-deref all 5)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::Bool(false),
+            Value::array(vec![Value::Int(1), Value::Int(2), Value::Int(3)]),
+        ])
+    );
+}
+
+#[test]
+fn test_all_of_accepts_array_and_empty() {
+    let result = eval(
+        r#"
+        (define ps (map [1 2 3] (lambda (n) (do (define p (promise)) (deliver p (* n 10)) p))))
+        [(promise-deref (all-of ps)) (promise-deref (all-of))]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::array(vec![Value::Int(10), Value::Int(20), Value::Int(30)]),
+            Value::array(vec![]),
+        ])
+    );
+}
+
+#[test]
+fn test_any_of_returns_first_delivered() {
+    let result = eval(
+        r#"
+        (define slow (promise))
+        (define fast (promise))
+        (make-thread (lambda () (do (sleep 0.2) (deliver slow :slow))))
+        (make-thread (lambda () (do (sleep 0.02) (deliver fast :fast))))
+        (promise-deref (any-of slow fast) 5)
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::String(":fast".into()));
+}
+
+#[test]
+fn test_combinators_adopt_async_handles() {
+    let result = eval(
+        r#"
+        (define square (lambda (n) (* n n)))
+        (promise-deref (all-of (async square 3) (async square 4)) 5)
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::array(vec![Value::Int(9), Value::Int(16)]));
+}
+
+#[test]
+fn test_deliver_requires_promise() {
+    assert!(eval("(deliver 1 2)").is_err());
+    assert!(eval("(all-of 1)").is_err());
+}
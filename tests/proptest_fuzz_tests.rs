@@ -5,10 +5,10 @@
 //! 2. The evaluator handles malformed ASTs gracefully
 //! 3. Valid OVSM programs produce deterministic results
 
-use ovsm::lexer::SExprScanner;
-use ovsm::parser::SExprParser;
-use ovsm::runtime::{LispEvaluator, Value};
 use proptest::prelude::*;
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::LispEvaluator;
 
 // =============================================================================
 // STRATEGY GENERATORS
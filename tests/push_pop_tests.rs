@@ -0,0 +1,138 @@
+//! Tests for `push`, `pop` and `pushnew` on variables and other places
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().copied().map(Value::Int).collect())
+}
+
+#[test]
+fn test_push_pop_round_trip() {
+    let result = eval(
+        r#"
+        (define stack [])
+        (push 1 stack)
+        (push 2 stack)
+        (push 3 stack)
+        (define popped [(pop stack) (pop stack)])
+        [popped stack]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::array(vec![ints(&[3, 2]), ints(&[1])]));
+}
+
+#[test]
+fn test_push_onto_nil_and_returns_list() {
+    let result = eval(
+        r#"
+        (define xs nil)
+        (push :a xs)
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::array(vec![Value::String(":a".to_string())]));
+}
+
+#[test]
+fn test_push_into_object_place() {
+    let result = eval(
+        r#"
+        (define queue {:items [2]})
+        (push 1 (get queue "items"))
+        (define head (pop (get queue "items")))
+        [head (get queue "items")]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::array(vec![Value::Int(1), ints(&[2])]));
+}
+
+#[test]
+fn test_pushnew_deduplicates() {
+    let result = eval(
+        r#"
+        (define seen [])
+        (for (x [1 2 1 3 2]) (pushnew x seen))
+        seen
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, ints(&[3, 2, 1]));
+}
+
+#[test]
+fn test_pushnew_with_test() {
+    let result = eval(
+        r#"
+        (define tags ["Sol"])
+        (pushnew "sol" tags :test (lambda (a b) (== (lower a) (lower b))))
+        (pushnew "eth" tags :test (lambda (a b) (== (lower a) (lower b))))
+        tags
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::String("eth".to_string()),
+            Value::String("Sol".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_pop_empty_list_errors() {
+    let err = eval(
+        r#"
+        (define xs [])
+        (pop xs)
+        "#,
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, solisp::Error::EmptyCollection { .. }),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn test_push_and_pop_on_non_place_return_value() {
+    assert_eq!(eval("(push 1 (list 2 3))").unwrap(), ints(&[1, 2, 3]));
+    assert_eq!(eval("(pushnew 2 (list 2 3))").unwrap(), ints(&[2, 3]));
+    assert_eq!(eval("(pop (list 2 3))").unwrap(), Value::Int(2));
+}
+
+#[test]
+fn test_push_pop_evaluate_place_subforms_once() {
+    let result = eval(
+        r#"
+        (define rows [[1] [2]])
+        (define i -1)
+        (push 0 (nth rows (incf i)))
+        (define popped (pop (nth rows (incf i))))
+        [rows i popped]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::array(vec![ints(&[0, 1]), ints(&[])]),
+            Value::Int(1),
+            Value::Int(2),
+        ])
+    );
+}
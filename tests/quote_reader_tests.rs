@@ -0,0 +1,89 @@
+//! Tests for the quote reader macro
+//!
+//! This test suite covers:
+//! - Quoted symbols read as their names
+//! - Quoted lists read as data, including nested lists
+//! - Quoted operators and self-evaluating literals
+//! - Existing users of quoted forms (catch/throw tags, list functions)
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn s(text: &str) -> Value {
+    Value::String(text.to_string())
+}
+
+#[test]
+fn test_quoted_symbol_is_its_name() {
+    assert_eq!(eval("'done").unwrap(), s("done"));
+}
+
+#[test]
+fn test_quoted_symbol_ignores_binding() {
+    assert_eq!(eval("(define x 42) 'x").unwrap(), s("x"));
+}
+
+#[test]
+fn test_quoted_operator_is_its_name() {
+    assert_eq!(eval("'+").unwrap(), s("+"));
+    assert_eq!(eval("'*").unwrap(), s("*"));
+}
+
+#[test]
+fn test_quoted_list_is_not_called() {
+    assert_eq!(
+        eval("'(+ 1 2)").unwrap(),
+        Value::array(vec![s("+"), Value::Int(1), Value::Int(2)])
+    );
+}
+
+#[test]
+fn test_quoted_nested_list() {
+    assert_eq!(
+        eval("'(a 1 (b 2))").unwrap(),
+        Value::array(vec![
+            s("a"),
+            Value::Int(1),
+            Value::array(vec![s("b"), Value::Int(2)]),
+        ])
+    );
+}
+
+#[test]
+fn test_quoted_empty_list() {
+    assert_eq!(eval("'()").unwrap(), Value::array(vec![]));
+}
+
+#[test]
+fn test_quoted_literals_are_unchanged() {
+    assert_eq!(eval("'5").unwrap(), Value::Int(5));
+    assert_eq!(eval("'\"text\"").unwrap(), s("text"));
+}
+
+#[test]
+fn test_quoted_list_works_with_list_functions() {
+    assert_eq!(eval("(length '(1 2 3))").unwrap(), Value::Int(3));
+    assert_eq!(eval("(first '(x y))").unwrap(), s("x"));
+}
+
+#[test]
+fn test_quoted_symbol_as_catch_tag() {
+    assert_eq!(
+        eval("(catch 'done (throw 'done 7) 99)").unwrap(),
+        Value::Int(7)
+    );
+}
+
+#[test]
+fn test_unclosed_quoted_list_is_error() {
+    assert!(eval("'(1 2").is_err());
+}
@@ -0,0 +1,53 @@
+//! Tests for `range` with an explicit step, descending ranges and float steps
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    LispEvaluator::new().execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().copied().map(Value::Int).collect())
+}
+
+fn floats(values: &[f64]) -> Value {
+    Value::array(values.iter().copied().map(Value::Float).collect())
+}
+
+#[test]
+fn test_range_with_step() {
+    assert_eq!(eval("(range 0 10 3)").unwrap(), ints(&[0, 3, 6, 9]));
+    assert_eq!(eval("(range 0 9 3)").unwrap(), ints(&[0, 3, 6]));
+    assert_eq!(eval("(range 1 4)").unwrap(), ints(&[1, 2, 3]));
+}
+
+#[test]
+fn test_range_descending() {
+    assert_eq!(eval("(range 5 0 -1)").unwrap(), ints(&[5, 4, 3, 2, 1]));
+    assert_eq!(eval("(range 10 0 -4)").unwrap(), ints(&[10, 6, 2]));
+    // Wrong direction for the step is simply empty
+    assert_eq!(eval("(range 0 5 -1)").unwrap(), ints(&[]));
+    assert_eq!(eval("(range 5 0)").unwrap(), ints(&[]));
+}
+
+#[test]
+fn test_range_float_step() {
+    assert_eq!(
+        eval("(range 0 1 0.25)").unwrap(),
+        floats(&[0.0, 0.25, 0.5, 0.75])
+    );
+    assert_eq!(eval("(range 1.0 0 -0.5)").unwrap(), floats(&[1.0, 0.5]));
+    assert_eq!(eval("(length (range 0 1 0.1))").unwrap(), Value::Int(10));
+}
+
+#[test]
+fn test_range_zero_step_errors() {
+    assert!(eval("(range 0 5 0)").is_err());
+    assert!(eval("(range 0 1 0.0)").is_err());
+}
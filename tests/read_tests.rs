@@ -0,0 +1,83 @@
+//! Tests for `read-from-string` and `read`, which parse text into quoted data
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_eval_read_form() {
+    let result = eval(r#"(eval (read-from-string "(* 6 7)"))"#).unwrap();
+    assert_eq!(result, Value::Int(42));
+}
+
+#[test]
+fn test_read_returns_quoted_data() {
+    let result = eval(r#"(read-from-string "(+ 1 (f \"s\"))")"#).unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::String("+".to_string()),
+            Value::Int(1),
+            Value::array(vec![
+                Value::String("f".to_string()),
+                Value::String("s".to_string()),
+            ]),
+        ])
+    );
+    assert_eq!(
+        eval(r#"(read-from-string "  3.5 trailing")"#).unwrap(),
+        Value::Float(3.5)
+    );
+}
+
+#[test]
+fn test_read_matches_quote() {
+    let result = eval(r#"(= (read-from-string "(a (b 2) c)") '(a (b 2) c))"#).unwrap();
+    assert_eq!(result, Value::Bool(true));
+}
+
+#[test]
+fn test_read_then_macroexpand() {
+    let result = eval(
+        r#"
+        (defmacro twice (x) `(* 2 ,x))
+        (eval (macroexpand (read-from-string "(twice 21)")))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(42));
+}
+
+#[test]
+fn test_read_from_stream() {
+    let result = eval(
+        r#"
+        (define input "(+ 40 2) (ignored)")
+        (eval (read input))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(42));
+}
+
+#[test]
+fn test_read_malformed_text_errors() {
+    assert!(eval(r#"(read-from-string "(+ 1")"#).is_err());
+    assert!(eval(r#"(read-from-string ")")"#).is_err());
+}
+
+#[test]
+fn test_read_end_of_input() {
+    assert!(eval(r#"(read-from-string "   ")"#).is_err());
+    let result = eval(r#"(read-from-string "" nil :done)"#).unwrap();
+    assert_eq!(result, Value::String(":done".to_string()));
+}
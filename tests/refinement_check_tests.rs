@@ -0,0 +1,96 @@
+//! Tests for source refinement annotations and with-refinement-checks
+
+use solisp::{Evaluator, Parser, Scanner, Value};
+
+fn eval_lisp(source: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    let mut scanner = Scanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = Evaluator::new();
+    Ok(evaluator.execute(&program)?)
+}
+
+#[test]
+fn test_refined_define_satisfying_value_binds() {
+    let source = r#"
+(with-refinement-checks
+  (define x : {u64 | (< x 10)} 5)
+  x)
+"#;
+    assert_eq!(eval_lisp(source).unwrap(), Value::Int(5));
+}
+
+#[test]
+fn test_refined_define_violation_errors_with_predicate() {
+    let source = r#"
+(with-refinement-checks
+  (define x : {u64 | (< x 10)} 15))
+"#;
+    let err = eval_lisp(source).unwrap_err().to_string();
+    assert!(err.contains("Refinement violated"), "got: {}", err);
+    assert!(err.contains("x < 10"), "got: {}", err);
+    assert!(err.contains("15"), "got: {}", err);
+}
+
+#[test]
+fn test_refined_assignment_checked() {
+    let source = r#"
+(with-refinement-checks
+  (define n : {u64 | (>= n 0)} 1)
+  (set! n 7)
+  (set! n -3))
+"#;
+    let err = eval_lisp(source).unwrap_err().to_string();
+    assert!(err.contains("-3"), "got: {}", err);
+    assert!(err.contains(">= 0"), "got: {}", err);
+}
+
+#[test]
+fn test_refined_setf_checked() {
+    let source = r#"
+(with-refinement-checks
+  (define pct : {u64 | (<= pct 100)} 50)
+  (setf pct 101))
+"#;
+    assert!(eval_lisp(source).is_err());
+}
+
+#[test]
+fn test_explicit_bound_variable_form() {
+    let source = r#"
+(with-refinement-checks
+  (define size : {s : u64 | (> s 0)} 3)
+  size)
+"#;
+    assert_eq!(eval_lisp(source).unwrap(), Value::Int(3));
+}
+
+#[test]
+fn test_violation_is_catchable() {
+    let source = r#"
+(try
+  (with-refinement-checks
+    (define x : {u64 | (< x 10)} 99))
+  (catch e "caught"))
+"#;
+    assert_eq!(
+        eval_lisp(source).unwrap(),
+        Value::String("caught".to_string())
+    );
+}
+
+#[test]
+fn test_annotations_unchecked_outside_block() {
+    let source = r#"
+(define x : {u64 | (< x 10)} 15)
+(set! x 20)
+x
+"#;
+    assert_eq!(eval_lisp(source).unwrap(), Value::Int(20));
+}
+
+#[test]
+fn test_plain_type_annotation_define() {
+    assert_eq!(eval_lisp("(define y : u64 7) y").unwrap(), Value::Int(7));
+}
@@ -0,0 +1,66 @@
+//! Tests for `weighted-mean`, `cumulative-sum` and `moving-average`
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    LispEvaluator::new().execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().copied().map(Value::Int).collect())
+}
+
+fn floats(values: &[f64]) -> Value {
+    Value::array(values.iter().copied().map(Value::Float).collect())
+}
+
+#[test]
+fn test_weighted_mean() {
+    // (10*1 + 20*2 + 30*3) / 6 = 140 / 6
+    assert_eq!(
+        eval("(weighted-mean [10 20 30] [1 2 3])").unwrap(),
+        Value::Float(140.0 / 6.0)
+    );
+    assert_eq!(
+        eval("(weighted-mean [5 15] [0.5 0.5])").unwrap(),
+        Value::Float(10.0)
+    );
+}
+
+#[test]
+fn test_weighted_mean_errors() {
+    assert!(eval("(weighted-mean [1 2 3] [1 2])").is_err());
+    assert!(eval("(weighted-mean [1 2] [1 -1])").is_err());
+    assert!(eval("(weighted-mean [] [])").is_err());
+}
+
+#[test]
+fn test_cumulative_sum() {
+    assert_eq!(eval("(cumulative-sum [1 2 3])").unwrap(), ints(&[1, 3, 6]));
+    assert_eq!(
+        eval("(cumulative-sum [1 0.5 2])").unwrap(),
+        Value::array(vec![Value::Int(1), Value::Float(1.5), Value::Float(3.5)])
+    );
+    assert_eq!(eval("(cumulative-sum [])").unwrap(), ints(&[]));
+    assert!(eval("(cumulative-sum [1 \"two\"])").is_err());
+}
+
+#[test]
+fn test_moving_average() {
+    assert_eq!(
+        eval("(moving-average [1 2 3 4 5] 3)").unwrap(),
+        floats(&[2.0, 3.0, 4.0])
+    );
+    assert_eq!(
+        eval("(moving-average [1 2 3] 1)").unwrap(),
+        floats(&[1.0, 2.0, 3.0])
+    );
+    assert_eq!(eval("(moving-average [1 2] 3)").unwrap(), floats(&[]));
+    assert!(eval("(moving-average [1 2 3] 0)").is_err());
+}
@@ -0,0 +1,157 @@
+//! Tests for `setf` on generalized places
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().copied().map(Value::Int).collect())
+}
+
+#[test]
+fn test_setf_nth() {
+    let result = eval(
+        r#"
+        (define xs [1 2 3])
+        (setf (nth xs 1) 20)
+        xs
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, ints(&[1, 20, 3]));
+}
+
+#[test]
+fn test_setf_nth_out_of_bounds() {
+    let err = eval(
+        r#"
+        (define xs [1 2 3])
+        (setf (nth xs 3) 0)
+        "#,
+    )
+    .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            solisp::Error::IndexOutOfBounds {
+                index: 3,
+                length: 3
+            }
+        ),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn test_setf_gethash() {
+    let result = eval(
+        r#"
+        (define prices {:sol 150})
+        (setf (gethash "sol" prices) 160)
+        (setf (gethash :btc prices) 60000)
+        [(gethash "sol" prices) (gethash "btc" prices) (gethash "eth" prices 0)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, ints(&[160, 60000, 0]));
+}
+
+#[test]
+fn test_setf_object_fields() {
+    let result = eval(
+        r#"
+        (define account {:owner "alice" :lamports 10})
+        (setf (get account "lamports") 25)
+        (setf (. account owner) "bob")
+        [(. account owner) (get account :lamports)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::String("bob".to_string()), Value::Int(25)])
+    );
+}
+
+#[test]
+fn test_setf_aref_and_index_access() {
+    let result = eval(
+        r#"
+        (define grid [[1 2] [3 4]])
+        (setf (aref grid 1 0) 30)
+        (setf ([] grid 0) [10 20])
+        grid
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::array(vec![ints(&[10, 20]), ints(&[30, 4])]));
+}
+
+#[test]
+fn test_setf_nested_place_writes_back() {
+    let result = eval(
+        r#"
+        (define state {:balances [5 6 7]})
+        (setf (nth (get state "balances") 2) 70)
+        (get state "balances")
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, ints(&[5, 6, 70]));
+}
+
+#[test]
+fn test_setf_returns_value_and_first() {
+    let result = eval(
+        r#"
+        (define xs [1 2])
+        [(setf (first xs) 9) xs]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::array(vec![Value::Int(9), ints(&[9, 2])]));
+}
+
+#[test]
+fn test_setf_evaluates_place_subforms_once() {
+    let result = eval(
+        r#"
+        (define m [[1 2] [3 4]])
+        (define i 0)
+        (setf (nth (nth m (do (set! i (+ i 1)) 0)) 0) 9)
+        [i m]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::Int(1),
+            Value::array(vec![ints(&[9, 2]), ints(&[3, 4])])
+        ])
+    );
+}
+
+#[test]
+fn test_setf_gethash_evaluates_key_once() {
+    let result = eval(
+        r#"
+        (define tables [{:a 1}])
+        (define calls 0)
+        (setf (gethash (do (set! calls (+ calls 1)) "a") (nth tables 0)) 5)
+        [calls (gethash "a" (nth tables 0))]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, ints(&[1, 5]));
+}
@@ -0,0 +1,108 @@
+//! Tests for the Solana entrypoint wrapper, executed in the rBPF VM
+
+use solana_rbpf::{
+    aligned_memory::AlignedMemory,
+    ebpf,
+    elf::Executable,
+    memory_region::{MemoryMapping, MemoryRegion},
+    program::BuiltinProgram,
+    verifier::RequisiteVerifier,
+    vm::{EbpfVm, TestContextObject},
+};
+use solisp::compiler::{CompileOptions, Compiler, VerificationMode};
+use std::sync::Arc;
+
+/// Serialize one writable account with `data`, `duplicates` repeats of it
+/// and the instruction data, in the layout the Solana loader passes to the
+/// entrypoint
+fn serialize_input(data: &[u8], duplicates: usize, instruction_data: &[u8]) -> Vec<u8> {
+    let mut input = Vec::new();
+    input.extend_from_slice(&(1 + duplicates as u64).to_le_bytes()); // num_accounts
+    input.extend_from_slice(&[0xff, 0, 1, 0, 0, 0, 0, 0]); // new, writable
+    input.extend_from_slice(&[7; 32]); // pubkey
+    input.extend_from_slice(&[9; 32]); // owner
+    input.extend_from_slice(&1_000u64.to_le_bytes()); // lamports
+    input.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    input.extend_from_slice(data);
+    input.resize(input.len() + 10240, 0); // realloc padding
+    input.resize((input.len() + 7) & !7, 0);
+    input.extend_from_slice(&0u64.to_le_bytes()); // rent_epoch
+    for _ in 0..duplicates {
+        input.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0]); // duplicate of account 0
+    }
+    input.extend_from_slice(&(instruction_data.len() as u64).to_le_bytes());
+    input.extend_from_slice(instruction_data);
+    input.extend_from_slice(&[3; 32]); // program_id
+    input
+}
+
+/// Compile `source` with the ABI wrapper, run it on `input` and return the
+/// input region afterwards
+fn run(source: &str, mut input: Vec<u8>) -> Vec<u8> {
+    let options = CompileOptions {
+        enable_solana_abi: true,
+        verification_mode: VerificationMode::Skip,
+        ..Default::default()
+    };
+    let elf = Compiler::new(options)
+        .compile(source)
+        .expect("compile failed")
+        .elf_bytes;
+
+    let loader = Arc::new(BuiltinProgram::new_mock());
+    let executable =
+        Executable::<TestContextObject>::from_elf(&elf, loader.clone()).expect("load failed");
+    executable
+        .verify::<RequisiteVerifier>()
+        .expect("verify failed");
+
+    let config = executable.get_config();
+    let sbpf_version = executable.get_sbpf_version();
+    let mut stack = AlignedMemory::<{ ebpf::HOST_ALIGN }>::zero_filled(config.stack_size());
+    let mut heap = AlignedMemory::<{ ebpf::HOST_ALIGN }>::zero_filled(32 * 1024);
+    let regions = vec![
+        executable.get_ro_region(),
+        MemoryRegion::new_writable(stack.as_slice_mut(), ebpf::MM_STACK_START),
+        MemoryRegion::new_writable(heap.as_slice_mut(), ebpf::MM_HEAP_START),
+        MemoryRegion::new_writable(&mut input, ebpf::MM_INPUT_START),
+    ];
+    let mapping = MemoryMapping::new(regions, config, sbpf_version).unwrap();
+
+    let mut context = TestContextObject::new(1_000_000);
+    let mut vm = EbpfVm::new(
+        loader,
+        sbpf_version,
+        &mut context,
+        mapping,
+        config.stack_size(),
+    );
+    let (_, result) = vm.execute_program(&executable, true);
+    assert_eq!(result.unwrap(), 0);
+    input
+}
+
+const COPY_FIRST_WORD: &str = "(mem-store (account-data-ptr 0) 0 (mem-load instruction-data 0))";
+
+fn stored_word(output: &[u8]) -> u64 {
+    let data_start = 8 + 88;
+    u64::from_le_bytes(output[data_start..data_start + 8].try_into().unwrap())
+}
+
+#[test]
+fn test_reads_first_instruction_data_word() {
+    let expected: u64 = 0x0123_4567_89ab_cdef;
+    let input = serialize_input(&[0; 8], 0, &expected.to_le_bytes());
+
+    // Copies the u64 at the start of instruction data into account 0's data
+    let output = run(COPY_FIRST_WORD, input);
+    assert_eq!(stored_word(&output), expected);
+}
+
+#[test]
+fn test_duplicate_accounts_are_skipped() {
+    let expected: u64 = 42;
+    let input = serialize_input(&[0; 8], 2, &expected.to_le_bytes());
+
+    let output = run(COPY_FIRST_WORD, input);
+    assert_eq!(stored_word(&output), expected);
+}
@@ -0,0 +1,65 @@
+//! Tests for `split` with `:limit` and `:regex` options
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn strings(values: &[&str]) -> Value {
+    Value::array(values.iter().map(|s| Value::String(s.to_string())).collect())
+}
+
+#[test]
+fn test_split_without_options_unchanged() {
+    assert_eq!(
+        eval(r#"(split "a,b,c" ",")"#).unwrap(),
+        strings(&["a", "b", "c"])
+    );
+}
+
+#[test]
+fn test_split_limit_keeps_remainder() {
+    assert_eq!(
+        eval(r#"(split "a,b,c,d" "," {:limit 2})"#).unwrap(),
+        strings(&["a", "b,c,d"])
+    );
+    assert_eq!(
+        eval(r#"(split "a,b" "," {:limit 5})"#).unwrap(),
+        strings(&["a", "b"])
+    );
+    assert_eq!(
+        eval(r#"(split "a,b" "," {:limit 1})"#).unwrap(),
+        strings(&["a,b"])
+    );
+}
+
+#[test]
+fn test_split_regex_mode() {
+    assert_eq!(
+        eval(r#"(split "a1b2c" "[0-9]" {:regex true})"#).unwrap(),
+        strings(&["a", "b", "c"])
+    );
+    assert_eq!(
+        eval(r#"(split "a1b22c3d" "[0-9]+" {:regex true :limit 3})"#).unwrap(),
+        strings(&["a", "b", "c3d"])
+    );
+    // Without the flag the pattern is a literal delimiter
+    assert_eq!(
+        eval(r#"(split "a1b2c" "[0-9]")"#).unwrap(),
+        strings(&["a1b2c"])
+    );
+}
+
+#[test]
+fn test_split_errors() {
+    assert!(eval(r#"(split "abc" "")"#).is_err());
+    assert!(eval(r#"(split "abc" "b" {:limit 0})"#).is_err());
+    assert!(eval(r#"(split "abc" "(" {:regex true})"#).is_err());
+}
@@ -0,0 +1,71 @@
+//! Tests for `percentile`, `quantile`, `covariance` and `correlation`
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    LispEvaluator::new().execute(&program)
+}
+
+fn float(source: &str) -> f64 {
+    match eval(source).unwrap() {
+        Value::Float(f) => f,
+        other => panic!("expected a float, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_50th_percentile_is_median() {
+    assert_eq!(
+        eval("(percentile [7 1 5 3 9] 50)").unwrap(),
+        eval("(median [7 1 5 3 9])").unwrap()
+    );
+    assert_eq!(
+        eval("(percentile [4 1 3 2] 50)").unwrap(),
+        eval("(median [4 1 3 2])").unwrap()
+    );
+}
+
+#[test]
+fn test_percentile_interpolates_linearly() {
+    assert_eq!(float("(percentile [1 2 3 4 5] 0)"), 1.0);
+    assert_eq!(float("(percentile [1 2 3 4 5] 100)"), 5.0);
+    assert_eq!(float("(percentile [10 20 30 40] 25)"), 17.5);
+    assert_eq!(float("(quantile [10 20 30 40] 0.25)"), 17.5);
+    assert_eq!(float("(quantile [42] 0.9)"), 42.0);
+}
+
+#[test]
+fn test_percentile_errors() {
+    assert!(eval("(percentile [] 50)").is_err());
+    assert!(eval("(percentile [1 2 3] 101)").is_err());
+    assert!(eval("(quantile [1 2 3] 1.5)").is_err());
+}
+
+#[test]
+fn test_known_correlations() {
+    assert!((float("(correlation [1 2 3 4] [2 4 6 8])") - 1.0).abs() < 1e-12);
+    assert!((float("(correlation [1 2 3 4] [8 6 4 2])") + 1.0).abs() < 1e-12);
+    assert!(float("(correlation [1 2 3] [1 0 1])").abs() < 1e-12);
+}
+
+#[test]
+fn test_covariance() {
+    assert_eq!(
+        float("(covariance [1 2 3] [1 2 3])"),
+        float("(variance [1 2 3])")
+    );
+    assert_eq!(float("(covariance [1 2 3 4] [4 3 2 1])"), -1.25);
+}
+
+#[test]
+fn test_paired_statistics_require_equal_lengths() {
+    assert!(eval("(correlation [1 2 3] [1 2])").is_err());
+    assert!(eval("(covariance [1] [1 2])").is_err());
+    assert!(eval("(correlation [1 1 1] [1 2 3])").is_err());
+}
@@ -0,0 +1,74 @@
+//! Tests for the standard library tools in the `stdlib` package
+
+use solisp::tools::ToolRegistry;
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code with the namespaced stdlib
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::with_registry(ToolRegistry::new_with_stdlib());
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_namespaced_mean_uses_stdlib() {
+    assert_eq!(eval("(stdlib:mean [1 2 3])").unwrap(), Value::Float(2.0));
+
+    // The stdlib tool rejects an empty collection...
+    assert!(matches!(
+        eval("(stdlib:mean [])"),
+        Err(solisp::Error::EmptyCollection { .. })
+    ));
+    // ...while the builtin `mean` still answers 0.0
+    assert_eq!(eval("(mean [])").unwrap(), Value::Float(0.0));
+}
+
+#[test]
+fn test_base_package_prefix_names_the_builtin() {
+    assert_eq!(eval("(cl:mean [])").unwrap(), eval("(mean [])").unwrap());
+    assert_eq!(eval("(cl:max 1 3 2)").unwrap(), Value::Int(3));
+}
+
+#[test]
+fn test_stdlib_package_is_reserved() {
+    assert!(eval("(defpackage :stdlib (:export mean))").is_err());
+}
+
+#[test]
+fn test_stdlib_tools_only_registered_under_prefix() {
+    let registry = ToolRegistry::new_with_stdlib();
+    assert!(registry.has("stdlib:MEAN"));
+    assert!(registry.has("stdlib:MEDIAN"));
+    assert!(!registry.has("MEAN"));
+
+    assert_eq!(ToolRegistry::new().count(), 0);
+}
+
+#[test]
+fn test_hash_table_tools_share_gethash_key_convention() {
+    let result = eval(
+        r#"
+        (define h (stdlib:make-hash-table))
+        (setf (gethash :sol h) 1)
+        [(gethash "sol" h)
+         (stdlib:gethash :sol h)
+         (stdlib:gethash "sol" h)
+         (stdlib:remhash :sol h)
+         (stdlib:hash-table-contains-key :sol h)]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::Int(1),
+            Value::Int(1),
+            Value::Int(1),
+            Value::Bool(true),
+            Value::Bool(true),
+        ])
+    );
+}
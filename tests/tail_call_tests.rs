@@ -0,0 +1,73 @@
+//! Tests for tail-position evaluation and the call trampoline
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_mutual_recursion_through_if_does_not_overflow() {
+    // `even?`/`odd?` are builtins, so the user versions get their own names
+    let code = r#"
+        (defun my-even? (n) (if (= n 0) true (my-odd? (- n 1))))
+        (defun my-odd? (n) (if (= n 0) false (my-even? (- n 1))))
+        [(my-even? 500000) (my-odd? 500000)]
+    "#;
+    assert_eq!(
+        eval(code).unwrap(),
+        Value::array(vec![Value::Bool(true), Value::Bool(false)])
+    );
+}
+
+#[test]
+fn test_tail_calls_through_cond_do_and_let() {
+    let code = r#"
+        (defun count-down (n acc)
+          (cond
+            ((= n 0) acc)
+            (else (do
+                    (define step 1)
+                    (let ((next (- n step)))
+                      (count-down next (+ acc 1)))))))
+        (count-down 200000 0)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(200000));
+}
+
+#[test]
+fn test_non_tail_calls_still_return_values() {
+    let code = r#"
+        (defun fact (n) (if (<= n 1) 1 (* n (fact (- n 1)))))
+        (defun wrap (n) (+ (fact n) 0))
+        (wrap 10)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(3628800));
+}
+
+#[test]
+fn test_tail_call_sees_locally_defined_helper() {
+    let code = r#"
+        (defun outer (n)
+          (do
+            (define helper (lambda (k acc) (if (= k 0) acc (helper (- k 1) (+ acc 2)))))
+            (helper n 0)))
+        (outer 1000)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(2000));
+}
+
+#[test]
+fn test_errors_in_tail_position_propagate() {
+    let code = r#"
+        (defun boom (n) (if (= n 0) (/ 1 0) (boom (- n 1))))
+        (try (boom 10) (catch e "caught"))
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::String("caught".to_string()));
+}
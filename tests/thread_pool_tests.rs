@@ -0,0 +1,156 @@
+//! Tests for the bounded `make-thread` pool
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_thousand_tasks_on_pool_of_four() {
+    let result = eval(
+        r#"
+        (set-thread-pool-size 4)
+        (define threads
+          (map (range 0 1000)
+               (lambda (i) (make-thread (lambda () (* i i))))))
+        (define results (map threads (lambda (t) (join-thread t))))
+        (set-thread-pool-size 0)
+        results
+        "#,
+    )
+    .unwrap();
+
+    let expected: Vec<Value> = (0..1000i64).map(|i| Value::Int(i * i)).collect();
+    assert_eq!(result, Value::array(expected));
+}
+
+#[test]
+fn test_pool_size_round_trips() {
+    let result = eval(
+        r#"
+        (define sizes [(set-thread-pool-size 3) (thread-pool-size)])
+        (set-thread-pool-size 0)
+        (concat sizes [(thread-pool-size)])
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::Int(3), Value::Int(3), Value::Int(0)])
+    );
+}
+
+#[test]
+fn test_pool_belongs_to_its_evaluator() {
+    assert_eq!(eval("(set-thread-pool-size 3)").unwrap(), Value::Int(3));
+    assert_eq!(eval("(thread-pool-size)").unwrap(), Value::Int(0));
+}
+
+#[test]
+fn test_threads_share_the_spawners_pool() {
+    let result = eval(
+        r#"
+        (set-thread-pool-size 2)
+        (join-thread (make-thread (lambda () (thread-pool-size))))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(2));
+}
+
+#[test]
+fn test_thread_sees_globals_its_body_refers_to() {
+    let result = eval(
+        r#"
+        (define base 10)
+        (defun scale (x) (* x base))
+        (defun run () (scale 4))
+        (join-thread (make-thread (lambda () (run))))
+        "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Int(40));
+}
+
+#[test]
+fn test_negative_pool_size_is_rejected() {
+    assert!(eval("(set-thread-pool-size -1)").is_err());
+}
+
+#[test]
+fn test_pooled_threads_keep_identity() {
+    let result = eval(
+        r#"
+        (set-thread-pool-size 2)
+        (define t (make-thread (lambda () 1) :name "worker"))
+        (define joined (join-thread t))
+        (define again (join-thread t))
+        (set-thread-pool-size 0)
+        [(thread-name t) joined again]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![
+            Value::String("worker".to_string()),
+            Value::Int(1),
+            Value::Int(1),
+        ])
+    );
+}
+
+/// Run `source` on its own thread, failing instead of hanging on a deadlock
+fn eval_within(source: &'static str, timeout: std::time::Duration) -> Value {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = sender.send(eval(source));
+    });
+    receiver
+        .recv_timeout(timeout)
+        .expect("evaluation deadlocked")
+        .unwrap()
+}
+
+#[test]
+fn test_nested_spawn_and_join_on_pool_of_one() {
+    let result = eval_within(
+        r#"
+        (set-thread-pool-size 1)
+        (define outer
+          (make-thread
+            (lambda ()
+              (join-thread (make-thread (lambda () 42))))))
+        (join-thread outer)
+        "#,
+        std::time::Duration::from_secs(10),
+    );
+    assert_eq!(result, Value::Int(42));
+}
+
+#[test]
+fn test_pooled_task_joins_sibling_queued_behind_it() {
+    let result = eval_within(
+        r#"
+        (set-thread-pool-size 1)
+        (define later (make-atomic nil))
+        (define first
+          (make-thread
+            (lambda ()
+              (do
+                (while (null? (atomic-get later)) (sleep 0.001))
+                (+ 1 (join-thread (atomic-get later)))))))
+        (atomic-set later (make-thread (lambda () 41)))
+        (join-thread first)
+        "#,
+        std::time::Duration::from_secs(10),
+    );
+    assert_eq!(result, Value::Int(42));
+}
@@ -0,0 +1,132 @@
+//! Tests for the threading macros `->`, `->>`, `cond->` and `as->`
+
+use solisp::{Expression, LispEvaluator, SExprParser, SExprScanner, Statement, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().map(|&n| Value::Int(n)).collect())
+}
+
+#[test]
+fn test_thread_first_inserts_as_first_argument() {
+    assert_eq!(eval("(-> 10 (- 3))").unwrap(), Value::Int(7));
+    assert_eq!(eval("(-> 10 (- 3) (* 2) (- 1))").unwrap(), Value::Int(13));
+    assert_eq!(
+        eval("(-> {:a {:b 5}} (get \"a\") (get \"b\"))").unwrap(),
+        Value::Int(5)
+    );
+    assert_eq!(
+        eval("(-> [5 1 4 2] (filter even?) (sort <))").unwrap(),
+        ints(&[2, 4])
+    );
+}
+
+#[test]
+fn test_thread_last_inserts_as_last_argument() {
+    assert_eq!(eval("(->> 10 (- 3))").unwrap(), Value::Int(-7));
+    assert_eq!(
+        eval("(->> 3 (list 1 2) (interleave [10 20 30]))").unwrap(),
+        ints(&[10, 1, 20, 2, 30, 3])
+    );
+    assert_eq!(
+        eval("(->> \"c\" (str \"a\" \"b\") (str \"<\"))").unwrap(),
+        Value::String("<abc".to_string())
+    );
+}
+
+#[test]
+fn test_bare_symbol_steps() {
+    assert_eq!(
+        eval("(defun inc (x) (+ x 1)) (defun double (x) (* x 2)) (-> 5 inc double)").unwrap(),
+        Value::Int(12)
+    );
+    assert_eq!(
+        eval("(defun inc (x) (+ x 1)) (-> [3 1 2] (sort <) first inc)").unwrap(),
+        Value::Int(2)
+    );
+    assert_eq!(
+        eval("(defun inc (x) (+ x 1)) (->> [1 2 3] (map inc) (apply +) inc)").unwrap(),
+        Value::Int(10)
+    );
+    assert_eq!(eval("(-> 5)").unwrap(), Value::Int(5));
+}
+
+#[test]
+fn test_threading_nests() {
+    assert_eq!(eval("(-> 2 (* 3) (->> (- 10)))").unwrap(), Value::Int(4));
+    assert_eq!(
+        eval("(->> [1 2 3] (map (lambda (x) (-> x (* x) (+ 1)))))").unwrap(),
+        ints(&[2, 5, 10])
+    );
+}
+
+#[test]
+fn test_function_types_still_parse() {
+    for code in [
+        "(: f (-> i64 i64))",
+        "(: f (-> (i64 i64) bool))",
+        "(: f (-> (Array u64) u64))",
+    ] {
+        let tokens = SExprScanner::new(code).scan_tokens().unwrap();
+        let program = SExprParser::new(tokens).parse().unwrap();
+        match &program.statements[0] {
+            Statement::Expression(Expression::TypeAnnotation { type_expr, .. }) => {
+                assert!(
+                    matches!(&**type_expr, Expression::ToolCall { name, .. } if name == "->"),
+                    "{}: {:?}",
+                    code,
+                    type_expr
+                );
+            }
+            other => panic!("{}: expected type annotation, got {:?}", code, other),
+        }
+    }
+}
+
+#[test]
+fn test_cond_thread_skips_false_tests() {
+    assert_eq!(
+        eval("(cond-> 1 true (+ 10) false (* 100) (> 2 1) (* 2))").unwrap(),
+        Value::Int(22)
+    );
+    assert_eq!(
+        eval(
+            "(define include-fees false)
+             (cond-> {:amount 5}
+               include-fees (assoc-in [:fees] 1)
+               true (assoc-in [:checked] true))"
+        )
+        .unwrap()
+        .as_object()
+        .unwrap()
+        .keys()
+        .count(),
+        2
+    );
+    assert_eq!(eval("(cond-> 5)").unwrap(), Value::Int(5));
+    assert!(eval("(cond-> 5 true)").is_err());
+}
+
+#[test]
+fn test_as_thread_binds_intermediate_value() {
+    // The threaded value goes in the middle of the call
+    assert_eq!(
+        eval("(as-> 3 x (+ x 1) (list 0 x 9))").unwrap(),
+        ints(&[0, 4, 9])
+    );
+    assert_eq!(
+        eval("(as-> [1 2 3] v (map v (lambda (n) (* n n))) (reduce v 0 +))").unwrap(),
+        Value::Int(14)
+    );
+    assert_eq!(eval("(as-> 7 x)").unwrap(), Value::Int(7));
+    assert!(eval("(as-> 7 (x) x)").is_err());
+}
@@ -0,0 +1,43 @@
+//! Tests for `time` and `measure-time` profiling forms
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_measure_time_reports_sleep_duration() {
+    let result = eval("(measure-time (sleep 0.05))").unwrap();
+    let items = result.as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0], Value::Null);
+    match items[1] {
+        Value::Float(ms) => assert!(ms >= 50.0, "elapsed {} ms < 50 ms", ms),
+        ref other => panic!("expected float elapsed-ms, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_measure_time_returns_result() {
+    let result = eval("(first (measure-time (+ 1 2)))").unwrap();
+    assert_eq!(result, Value::Int(3));
+}
+
+#[test]
+fn test_time_returns_value_unchanged() {
+    let result = eval("(define x (time (* 6 7))) x").unwrap();
+    assert_eq!(result, Value::Int(42));
+}
+
+#[test]
+fn test_time_propagates_errors() {
+    assert!(eval("(time (/ 1 0))").is_err());
+    assert!(eval("(measure-time)").is_err());
+}
@@ -0,0 +1,110 @@
+//! Tests for execution timeouts and cancellation
+
+use solisp::{CancellationToken, Error, LispEvaluator, Program, SExprParser, SExprScanner, Value};
+use std::time::{Duration, Instant};
+
+fn parse(code: &str) -> Program {
+    let tokens = SExprScanner::new(code).scan_tokens().unwrap();
+    SExprParser::new(tokens).parse().unwrap()
+}
+
+#[test]
+fn test_infinite_while_stops_at_timeout() {
+    let program = parse("(while true (+ 1 1))");
+    let mut evaluator = LispEvaluator::new();
+
+    let start = Instant::now();
+    let result = evaluator.execute_with_timeout(&program, Duration::from_millis(100));
+    assert!(matches!(result, Err(Error::Timeout(d)) if d == Duration::from_millis(100)));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn test_unbounded_recursion_stops_at_timeout() {
+    let program = parse("(defun spin (n) (spin (+ n 1))) (spin 0)");
+    let mut evaluator = LispEvaluator::new();
+
+    let result = evaluator.execute_with_timeout(&program, Duration::from_millis(100));
+    assert!(matches!(result, Err(Error::Timeout(_))));
+}
+
+#[test]
+fn test_fast_program_finishes_within_timeout() {
+    let program = parse("(define total 0) (for (i (range 0 100)) (set! total (+ total i))) total");
+    let mut evaluator = LispEvaluator::new();
+
+    let result = evaluator.execute_with_timeout(&program, Duration::from_secs(10));
+    assert_eq!(result.unwrap(), Value::Int(4950));
+
+    // The deadline only applies to that execution
+    assert_eq!(evaluator.execute(&parse("(+ 1 2)")).unwrap(), Value::Int(3));
+}
+
+#[test]
+fn test_token_cancels_from_another_thread() {
+    let program = parse("(while true (+ 1 1))");
+    let mut evaluator = LispEvaluator::new();
+    let token = evaluator.cancellation_token();
+
+    let canceller = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        token.cancel();
+    });
+    assert!(matches!(evaluator.execute(&program), Err(Error::Cancelled)));
+    canceller.join().unwrap();
+
+    // A fresh token lets the evaluator run again
+    evaluator.set_cancellation_token(CancellationToken::new());
+    assert_eq!(evaluator.execute(&parse("(+ 1 2)")).unwrap(), Value::Int(3));
+}
+
+#[test]
+fn test_sleep_is_interrupted_by_cancellation() {
+    let program = parse("(sleep 10)");
+    let mut evaluator = LispEvaluator::new();
+    let token = evaluator.cancellation_token();
+
+    let canceller = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        token.cancel();
+    });
+    let start = Instant::now();
+    assert!(matches!(evaluator.execute(&program), Err(Error::Cancelled)));
+    assert!(
+        start.elapsed() < Duration::from_millis(150),
+        "{:?}",
+        start.elapsed()
+    );
+    canceller.join().unwrap();
+}
+
+#[test]
+fn test_sleep_is_interrupted_by_timeout() {
+    let program = parse("(sleep 10)");
+    let mut evaluator = LispEvaluator::new();
+
+    let start = Instant::now();
+    let result = evaluator.execute_with_timeout(&program, Duration::from_millis(50));
+    assert!(matches!(result, Err(Error::Timeout(_))));
+    assert!(
+        start.elapsed() < Duration::from_millis(150),
+        "{:?}",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn test_sleep_accepts_fractional_seconds() {
+    let mut evaluator = LispEvaluator::new();
+
+    let start = Instant::now();
+    assert_eq!(
+        evaluator.execute(&parse("(sleep 0.05)")).unwrap(),
+        Value::Null
+    );
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(50), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(1), "{:?}", elapsed);
+
+    assert!(evaluator.execute(&parse("(sleep -1)")).is_err());
+}
@@ -0,0 +1,64 @@
+//! Tests for registering tools from script with `register-tool`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+#[test]
+fn test_registered_tool_is_callable() {
+    let code = r#"
+        (register-tool mytool (lambda (x) (* x 2)))
+        (mytool 21)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(42));
+}
+
+#[test]
+fn test_registered_tool_by_string_name_sees_closure() {
+    let code = r#"
+        (define offset 100)
+        (register-tool "add-offset" (lambda (a b) (+ a b offset)))
+        (add-offset 1 2)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(103));
+}
+
+#[test]
+fn test_registered_tool_works_with_funcall() {
+    let code = r#"
+        (register-tool triple (lambda (x) (* x 3)))
+        (funcall "triple" 5)
+    "#;
+    assert_eq!(eval(code).unwrap(), Value::Int(15));
+}
+
+#[test]
+fn test_register_tool_requires_function() {
+    assert!(eval("(register-tool bad 5)").is_err());
+    assert!(eval("(register-tool only-name)").is_err());
+}
+
+#[test]
+fn test_registered_tool_executes_through_registry() {
+    let code = r#"
+        (define offset 100)
+        (register-tool "add-offset" (lambda (a b) (+ a b offset)))
+    "#;
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens().unwrap();
+    let program = SExprParser::new(tokens).parse().unwrap();
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program).unwrap();
+
+    let tool = evaluator.registry().get("add-offset").unwrap();
+    let result = tool.execute(&[Value::Int(1), Value::Int(2)]).unwrap();
+    assert_eq!(result, Value::Int(103));
+}
@@ -0,0 +1,52 @@
+//! Tests for `transpose`
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn matrix(rows: &[&[i64]]) -> Value {
+    Value::array(
+        rows.iter()
+            .map(|r| Value::array(r.iter().map(|&n| Value::Int(n)).collect()))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_transpose_rectangular_matrix() {
+    let expected = matrix(&[&[1, 4], &[2, 5], &[3, 6]]);
+    assert_eq!(eval("(transpose '((1 2 3) (4 5 6)))").unwrap(), expected);
+    assert_eq!(eval("(transpose [[1 2 3] [4 5 6]])").unwrap(), expected);
+    assert_eq!(
+        eval("(transpose (transpose [[1 2 3] [4 5 6]]))").unwrap(),
+        matrix(&[&[1, 2, 3], &[4, 5, 6]])
+    );
+}
+
+#[test]
+fn test_transpose_single_row() {
+    assert_eq!(
+        eval("(transpose [[1 2 3]])").unwrap(),
+        matrix(&[&[1], &[2], &[3]])
+    );
+    assert_eq!(eval("(transpose [])").unwrap(), matrix(&[]));
+}
+
+#[test]
+fn test_transpose_ragged_input() {
+    let err = eval("(transpose [[1 2 3] [4 5]])").unwrap_err();
+    assert!(err.to_string().contains("Ragged"), "{}", err);
+
+    assert_eq!(
+        eval("(transpose [[1 2 3] [4 5]] :truncate true)").unwrap(),
+        matrix(&[&[1, 4], &[2, 5]])
+    );
+}
@@ -0,0 +1,94 @@
+//! Tests for elementwise array math inside `with-vectorization`
+
+use solisp::lexer::SExprScanner;
+use solisp::parser::SExprParser;
+use solisp::runtime::{LispEvaluator, Value};
+
+fn eval(source: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(source);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    LispEvaluator::new().execute(&program)
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::array(values.iter().copied().map(Value::Int).collect())
+}
+
+fn floats(values: &[f64]) -> Value {
+    Value::array(values.iter().copied().map(Value::Float).collect())
+}
+
+#[test]
+fn test_array_plus_array() {
+    assert_eq!(
+        eval("(with-vectorization (+ [1 2 3] [10 20 30]))").unwrap(),
+        ints(&[11, 22, 33])
+    );
+    assert_eq!(
+        eval("(with-vectorization (- [5 5] [1 2] [1 1]))").unwrap(),
+        ints(&[3, 2])
+    );
+}
+
+#[test]
+fn test_array_scalar_broadcast() {
+    assert_eq!(
+        eval("(with-vectorization (* [1 2 3] 2))").unwrap(),
+        ints(&[2, 4, 6])
+    );
+    assert_eq!(
+        eval("(with-vectorization (- 10 [1 2 3]))").unwrap(),
+        ints(&[9, 8, 7])
+    );
+    assert_eq!(
+        eval("(with-vectorization (/ [1.0 3.0] 2))").unwrap(),
+        floats(&[0.5, 1.5])
+    );
+}
+
+#[test]
+fn test_math_builtins_map_over_arrays() {
+    assert_eq!(
+        eval("(with-vectorization (sqrt [1 4 9]))").unwrap(),
+        floats(&[1.0, 2.0, 3.0])
+    );
+    assert_eq!(
+        eval("(with-vectorization (abs [-1 2 -3]))").unwrap(),
+        ints(&[1, 2, 3])
+    );
+    assert_eq!(
+        eval("(with-vectorization (pow [1 2 3] 2))").unwrap(),
+        floats(&[1.0, 4.0, 9.0])
+    );
+    // Scalars still work as usual inside the block
+    assert_eq!(
+        eval("(with-vectorization (sqrt 16))").unwrap(),
+        Value::Float(4.0)
+    );
+}
+
+#[test]
+fn test_nested_arrays_broadcast_recursively() {
+    assert_eq!(
+        eval("(with-vectorization (+ [[1 2] [3 4]] 1))").unwrap(),
+        Value::array(vec![ints(&[2, 3]), ints(&[4, 5])])
+    );
+}
+
+#[test]
+fn test_length_mismatch_errors() {
+    assert!(eval("(with-vectorization (+ [1 2 3] [1 2]))").is_err());
+    assert!(eval("(with-vectorization (atan2 [1 2] [1 2 3]))").is_err());
+}
+
+#[test]
+fn test_vectorization_is_opt_in() {
+    // Outside the block, + on arrays keeps its concatenation meaning
+    assert_eq!(eval("(+ [1 2] [3])").unwrap(), ints(&[1, 2, 3]));
+    assert!(eval("(* [1 2 3] 2)").is_err());
+    assert!(eval("(sqrt [1 4 9])").is_err());
+    // The mode ends with the block, even when it exits with an error
+    assert!(eval("(ignore-errors (with-vectorization (+ [1] [1 2]))) (* [1 2] 2)").is_err());
+}
@@ -0,0 +1,87 @@
+//! Tests for windowed and chunked sequence operations
+
+use solisp::{LispEvaluator, SExprParser, SExprScanner, Value};
+
+/// Helper function to execute Solisp code
+fn eval(code: &str) -> Result<Value, solisp::Error> {
+    let mut scanner = SExprScanner::new(code);
+    let tokens = scanner.scan_tokens()?;
+    let mut parser = SExprParser::new(tokens);
+    let program = parser.parse()?;
+    let mut evaluator = LispEvaluator::new();
+    evaluator.execute(&program)
+}
+
+fn windows(groups: &[&[i64]]) -> Value {
+    Value::array(
+        groups
+            .iter()
+            .map(|g| Value::array(g.iter().map(|&n| Value::Int(n)).collect()))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_size_three_sliding_window_over_five_elements() {
+    let expected = windows(&[&[1, 2, 3], &[2, 3, 4], &[3, 4, 5]]);
+    assert_eq!(eval("(sliding-window 3 [1 2 3 4 5])").unwrap(), expected);
+    assert_eq!(eval("(partition 3 1 [1 2 3 4 5])").unwrap(), expected);
+}
+
+#[test]
+fn test_moving_average() {
+    let result = eval(
+        "(map (sliding-window 2 [10 20 30 40])
+              (lambda (w) (/ (reduce w 0 +) (length w))))",
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::array(vec![Value::Int(15), Value::Int(25), Value::Int(35)])
+    );
+}
+
+#[test]
+fn test_partition_drops_short_tail() {
+    assert_eq!(
+        eval("(partition 2 [1 2 3 4 5])").unwrap(),
+        windows(&[&[1, 2], &[3, 4]])
+    );
+    assert_eq!(eval("(partition 4 [1 2 3])").unwrap(), windows(&[]));
+    assert_eq!(
+        eval("(partition 2 3 [1 2 3 4 5 6 7])").unwrap(),
+        windows(&[&[1, 2], &[4, 5]])
+    );
+}
+
+#[test]
+fn test_partition_all_keeps_remainder() {
+    assert_eq!(
+        eval("(partition-all 2 [1 2 3 4 5])").unwrap(),
+        windows(&[&[1, 2], &[3, 4], &[5]])
+    );
+    assert_eq!(
+        eval("(partition-all 4 [1 2 3])").unwrap(),
+        windows(&[&[1, 2, 3]])
+    );
+    assert_eq!(
+        eval("(partition-all 3 2 [1 2 3 4 5])").unwrap(),
+        windows(&[&[1, 2, 3], &[3, 4, 5], &[5]])
+    );
+    assert_eq!(eval("(partition-all 3 [])").unwrap(), windows(&[]));
+}
+
+#[test]
+fn test_predicate_partition_still_works() {
+    assert_eq!(
+        eval("(partition [1 2 3 4] even?)").unwrap(),
+        windows(&[&[2, 4], &[1, 3]])
+    );
+}
+
+#[test]
+fn test_window_size_must_be_positive() {
+    assert!(eval("(partition-all 0 [1 2])").is_err());
+    assert!(eval("(sliding-window -1 [1 2])").is_err());
+    assert!(eval("(partition 2 0 [1 2])").is_err());
+}